@@ -0,0 +1,63 @@
+#![allow(missing_docs)]
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use iced::widget::markdown;
+use iced::Theme;
+
+criterion_main!(benches);
+criterion_group!(benches, markdown_benchmark);
+
+#[allow(unused_results)]
+pub fn markdown_benchmark(c: &mut Criterion) {
+    let palette = Theme::Light.palette();
+    let source = document(2_000_000);
+    let content = markdown::Content::parse(&source, palette);
+
+    c.bench_function("markdown - parse (2 MB)", |b| {
+        b.iter(|| markdown::Content::parse(&source, palette));
+    });
+
+    c.bench_function("markdown - push paragraph (2 MB)", |b| {
+        b.iter_batched_ref(
+            || content.clone(),
+            |content| content.push_str("\n\nA new **paragraph** arrives.\n"),
+            BatchSize::LargeInput,
+        );
+    });
+
+    let middle = source.len() / 2;
+    let middle = source[middle..].find("\n\n").unwrap() + middle;
+    let edited = format!("{} Edited.{}", &source[..middle], &source[middle..]);
+
+    c.bench_function("markdown - edit middle (2 MB)", |b| {
+        b.iter_batched_ref(
+            || content.clone(),
+            |content| content.update(&edited),
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+/// Generates a Markdown document of roughly the given length, in bytes.
+fn document(length: usize) -> String {
+    let mut document = String::with_capacity(length + 1_000);
+    let mut section = 0;
+
+    while document.len() < length {
+        section += 1;
+
+        document.push_str(&format!(
+            "## Section {section}\n\n\
+            Lorem ipsum dolor sit amet, *consectetur* adipiscing elit. \
+            Sed do eiusmod tempor incididunt ut `labore` et dolore magna \
+            aliqua. See [the first section](#section-1).\n\n\
+            - Ut enim ad minim veniam\n\
+            - Quis nostrud **exercitation** ullamco\n\n\
+            ```rust\n\
+            fn section() -> usize {{\n    {section}\n}}\n\
+            ```\n\n"
+        ));
+    }
+
+    document
+}
@@ -21,6 +21,15 @@ pub fn wgpu_benchmark(c: &mut Criterion) {
         benchmark(b, |_| scene(1_000));
     });
 
+    let path = coastline(200_000);
+
+    c.bench_function("wgpu - zoomed out polyline", |b| {
+        benchmark(b, |_| polyline(&path, None));
+    });
+    c.bench_function("wgpu - zoomed out polyline (level of detail)", |b| {
+        benchmark(b, |_| polyline(&path, Some(0.5)));
+    });
+
     c.bench_function("wgpu - layered text (light)", |b| {
         benchmark(b, |_| layered_text(10));
     });
@@ -199,6 +208,73 @@ fn scene<'a, Message: 'a>(n: usize) -> Element<'a, Message, Theme, Renderer> {
         .into()
 }
 
+/// Builds a long, jagged polyline; like the coastline of a map.
+fn coastline(n: usize) -> canvas::Path {
+    canvas::Path::new(|builder| {
+        let mut point = Point::ORIGIN;
+
+        builder.move_to(point);
+
+        for i in 0..n {
+            let angle =
+                (i as f32 * 0.37).sin() * 2.0 + (i as f32 * 0.011).cos();
+
+            point = Point::new(point.x + angle.cos(), point.y + angle.sin());
+            builder.line_to(point);
+        }
+    })
+}
+
+fn polyline<'a, Message: 'a>(
+    path: &'a canvas::Path,
+    level_of_detail: Option<f32>,
+) -> Element<'a, Message, Theme, Renderer> {
+    struct Polyline<'a> {
+        path: &'a canvas::Path,
+        level_of_detail: Option<f32>,
+    }
+
+    impl<'a, Message, Theme> canvas::Program<Message, Theme, Renderer>
+        for Polyline<'a>
+    {
+        type State = ();
+
+        fn draw(
+            &self,
+            _state: &Self::State,
+            renderer: &Renderer,
+            _theme: &Theme,
+            bounds: Rectangle,
+            _cursor: mouse::Cursor,
+        ) -> Vec<canvas::Geometry<Renderer>> {
+            let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+            // The whole path spans only a few dozens of pixels
+            frame.scale(0.001);
+
+            frame.stroke(
+                self.path,
+                canvas::Stroke {
+                    level_of_detail: self.level_of_detail,
+                    ..canvas::Stroke::default()
+                        .with_color(Color::WHITE)
+                        .with_width(1_000.0)
+                },
+            );
+
+            vec![frame.into_geometry()]
+        }
+    }
+
+    canvas(Polyline {
+        path,
+        level_of_detail,
+    })
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
 fn layered_text<'a, Message: 'a>(
     n: usize,
 ) -> Element<'a, Message, Theme, Renderer> {
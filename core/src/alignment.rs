@@ -1,4 +1,11 @@
 //! Align and position widgets.
+use std::cell::Cell;
+
+thread_local! {
+    static DIRECTION: Cell<Direction> = const {
+        Cell::new(Direction::LeftToRight)
+    };
+}
 
 /// Alignment on the axis of a container.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -11,14 +18,26 @@ pub enum Alignment {
 
     /// Align at the end of the axis.
     End,
+
+    /// Align the text baselines of the contents.
+    ///
+    /// Only a horizontal flex layout, like a `Row`, can line up baselines.
+    /// Contents without a baseline are aligned at the end of the axis; and
+    /// any other layout—like a `Column`, a `Container`, or
+    /// [`Node::align`]—treats this alignment as [`Alignment::Start`].
+    ///
+    /// [`Node::align`]: crate::layout::Node::align
+    Baseline,
 }
 
 impl From<Horizontal> for Alignment {
     fn from(horizontal: Horizontal) -> Self {
-        match horizontal {
-            Horizontal::Left => Self::Start,
+        match horizontal.resolve() {
+            Horizontal::Left | Horizontal::Start | Horizontal::Justify => {
+                Self::Start
+            }
             Horizontal::Center => Self::Center,
-            Horizontal::Right => Self::End,
+            Horizontal::Right | Horizontal::End => Self::End,
         }
     }
 }
@@ -44,14 +63,47 @@ pub enum Horizontal {
 
     /// Align right
     Right,
+
+    /// Justify to both edges
+    ///
+    /// The spaces of every line of text, except the last one of each
+    /// paragraph, are stretched so the line fills the available width.
+    /// Anything else is aligned to the left.
+    Justify,
+
+    /// Align at the start of the [`Direction`]; left, unless the
+    /// direction is right-to-left.
+    Start,
+
+    /// Align at the end of the [`Direction`]; right, unless the
+    /// direction is right-to-left.
+    End,
+}
+
+impl Horizontal {
+    /// Resolves the [`Horizontal::Start`] and [`Horizontal::End`] alignments
+    /// to a physical side, using the current [`direction`].
+    ///
+    /// Any other alignment is returned as is.
+    pub fn resolve(self) -> Self {
+        match (self, direction()) {
+            (Self::Start, Direction::LeftToRight)
+            | (Self::End, Direction::RightToLeft) => Self::Left,
+            (Self::End, Direction::LeftToRight)
+            | (Self::Start, Direction::RightToLeft) => Self::Right,
+            (alignment, _) => alignment,
+        }
+    }
 }
 
+/// [`Alignment::Baseline`] is converted to [`Horizontal::Start`], since
+/// baselines cannot be lined up horizontally.
 impl From<Alignment> for Horizontal {
     fn from(alignment: Alignment) -> Self {
         match alignment {
-            Alignment::Start => Self::Left,
+            Alignment::Start | Alignment::Baseline => Self::Start,
             Alignment::Center => Self::Center,
-            Alignment::End => Self::Right,
+            Alignment::End => Self::End,
         }
     }
 }
@@ -69,12 +121,65 @@ pub enum Vertical {
     Bottom,
 }
 
+/// [`Alignment::Baseline`] is converted to [`Vertical::Top`]; only a
+/// layout aware of the baselines of its contents, like a `Row`, can line
+/// them up.
 impl From<Alignment> for Vertical {
     fn from(alignment: Alignment) -> Self {
         match alignment {
-            Alignment::Start => Self::Top,
+            Alignment::Start | Alignment::Baseline => Self::Top,
             Alignment::Center => Self::Center,
             Alignment::End => Self::Bottom,
         }
     }
 }
+
+/// The horizontal direction of the contents of a user interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Contents flow from left to right.
+    #[default]
+    LeftToRight,
+
+    /// Contents flow from right to left.
+    RightToLeft,
+}
+
+/// Returns the current [`Direction`] of the user interface.
+///
+/// By default, it is [`Direction::LeftToRight`].
+pub fn direction() -> Direction {
+    DIRECTION.with(Cell::get)
+}
+
+/// Sets the current [`Direction`] of the user interface.
+///
+/// Logical alignments, like [`Horizontal::Start`], are resolved with it
+/// during layout.
+pub fn set_direction(direction: Direction) {
+    DIRECTION.with(|current| current.set(direction));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Padding;
+
+    #[test]
+    fn logical_alignments_follow_the_direction() {
+        assert_eq!(Horizontal::Start.resolve(), Horizontal::Left);
+        assert_eq!(Alignment::from(Horizontal::End), Alignment::End);
+        assert_eq!(Padding::ZERO.left(5).right(15).start(), 5.0);
+
+        set_direction(Direction::RightToLeft);
+
+        assert_eq!(Horizontal::Start.resolve(), Horizontal::Right);
+        assert_eq!(Horizontal::End.resolve(), Horizontal::Left);
+        assert_eq!(Horizontal::Center.resolve(), Horizontal::Center);
+        assert_eq!(Alignment::from(Horizontal::Start), Alignment::End);
+        assert_eq!(Alignment::from(Horizontal::Left), Alignment::Start);
+        assert_eq!(Padding::ZERO.left(5).right(15).start(), 15.0);
+
+        set_direction(Direction::LeftToRight);
+    }
+}
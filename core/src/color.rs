@@ -1,6 +1,10 @@
 use palette::rgb::{Srgb, Srgba};
 
 /// A color in the `sRGB` color space.
+///
+/// The RGB components of a [`Color`] may go above `1.0` to describe
+/// emissive colors; which will look brighter than white on displays that
+/// support HDR, and will be tone-mapped everywhere else.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Color {
     /// Red component, 0.0 - 1.0
@@ -151,6 +155,16 @@ impl Color {
         Color::new(1.0f32 - self.r, 1.0f32 - self.g, 1.0f32 - self.b, self.a)
     }
 
+    /// Scales the light emitted by the [`Color`] by the given intensity,
+    /// in linear space.
+    ///
+    /// An intensity above `1.0` can produce an emissive color.
+    pub fn with_intensity(self, intensity: f32) -> Color {
+        let [r, g, b, a] = self.into_linear();
+
+        Color::from_linear_rgba(r * intensity, g * intensity, b * intensity, a)
+    }
+
     /// Scales the alpha channel of the [`Color`] by the given factor.
     pub fn scale_alpha(self, factor: f32) -> Color {
         Self {
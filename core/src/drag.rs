@@ -0,0 +1,46 @@
+//! Drag data between widgets.
+use std::any::{self, Any};
+use std::fmt;
+use std::rc::Rc;
+
+/// The data carried by a drag.
+///
+/// A [`Payload`] can hold a value of any type. Widgets accepting a drop
+/// can check its type with [`Payload::is`] before obtaining the value.
+#[derive(Clone)]
+pub struct Payload {
+    value: Rc<dyn Any>,
+    type_name: &'static str,
+}
+
+impl Payload {
+    /// Creates a new [`Payload`] holding the given value.
+    pub fn new<T: Any + Clone>(value: T) -> Self {
+        Self {
+            value: Rc::new(value),
+            type_name: any::type_name::<T>(),
+        }
+    }
+
+    /// Returns true if the [`Payload`] holds a value of type `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    /// Returns a reference to the value of the [`Payload`], if it is of
+    /// type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    /// Returns true if both payloads were created from the same value.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Payload").field(&self.type_name).finish()
+    }
+}
@@ -37,6 +37,23 @@ impl<'a, Message, Theme, Renderer> Element<'a, Message, Theme, Renderer> {
         }
     }
 
+    /// Creates a new [`Element`] containing the given [`AnyWidget`].
+    ///
+    /// This is useful to display widgets compiled separately from the
+    /// application; like plugins loaded from a dynamic library.
+    ///
+    /// [`AnyWidget`]: widget::AnyWidget
+    pub fn boxed_dyn(
+        widget: Box<dyn widget::AnyWidget<Message, Theme> + 'a>,
+    ) -> Self
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: crate::Renderer + renderer::AnyRenderer,
+    {
+        Self::new(widget::any::Dyn::new(widget))
+    }
+
     /// Returns a reference to the [`Widget`] of the [`Element`],
     pub fn as_widget(&self) -> &dyn Widget<Message, Theme, Renderer> {
         self.widget.as_ref()
@@ -205,6 +222,10 @@ impl<'a, Message, Theme, Renderer> Element<'a, Message, Theme, Renderer> {
     /// The [`Renderer`] will explain the layout of the [`Element`] graphically.
     /// This can be very useful for debugging your layout!
     ///
+    /// Any layout with contents that collapsed to no space is marked with a
+    /// hatched red rectangle. See [`layout::diagnostics`] for the warnings
+    /// logged in these cases.
+    ///
     /// [`Renderer`]: crate::Renderer
     pub fn explain<C: Into<Color>>(
         self,
@@ -321,7 +342,8 @@ where
         viewport: &Rectangle,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         let status = self.widget.on_event(
             tree,
@@ -370,12 +392,13 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, B, Theme, Renderer>> {
         let mapper = &self.mapper;
 
         self.widget
-            .overlay(tree, layout, renderer, translation)
+            .overlay(tree, layout, renderer, viewport, translation)
             .map(move |overlay| overlay.map(mapper))
     }
 }
@@ -491,11 +514,90 @@ where
                 Color::TRANSPARENT,
             );
 
+            if is_collapsed(layout) {
+                mark_collapsed(renderer, layout.bounds());
+            }
+
             for child in layout.children() {
                 explain_layout(renderer, color, child);
             }
         }
 
+        /// Returns true if a layout has contents, but no space to show them.
+        fn is_collapsed(layout: Layout<'_>) -> bool {
+            let bounds = layout.bounds();
+
+            (bounds.width == 0.0 || bounds.height == 0.0)
+                && layout.children().next().is_some()
+        }
+
+        /// Draws a hatched red rectangle where a collapsed layout is; large
+        /// enough to be noticed.
+        fn mark_collapsed<Renderer: crate::Renderer>(
+            renderer: &mut Renderer,
+            bounds: Rectangle,
+        ) {
+            const SIZE: f32 = 8.0;
+            const GAP: f32 = 4.0;
+
+            let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+            let width = bounds.width.max(SIZE);
+            let height = bounds.height.max(SIZE);
+
+            let marker = Rectangle {
+                x: bounds.x - (width - bounds.width) / 2.0,
+                y: bounds.y - (height - bounds.height) / 2.0,
+                width,
+                height,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: marker,
+                    border: Border {
+                        color: red,
+                        width: 1.0,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color { a: 0.2, ..red },
+            );
+
+            // Stripes across the longest side of the marker
+            let is_wide = marker.width >= marker.height;
+            let length = if is_wide { marker.width } else { marker.height };
+
+            let mut offset = GAP;
+
+            while offset < length {
+                let stripe = if is_wide {
+                    Rectangle {
+                        x: marker.x + offset,
+                        width: 1.0,
+                        ..marker
+                    }
+                } else {
+                    Rectangle {
+                        y: marker.y + offset,
+                        height: 1.0,
+                        ..marker
+                    }
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: stripe,
+                        ..renderer::Quad::default()
+                    },
+                    red,
+                );
+
+                offset += GAP;
+            }
+        }
+
         self.element
             .widget
             .draw(state, renderer, theme, style, layout, cursor, viewport);
@@ -521,10 +623,15 @@ where
         state: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        self.element
-            .widget
-            .overlay(state, layout, renderer, translation)
+        self.element.widget.overlay(
+            state,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
     }
 }
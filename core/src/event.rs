@@ -1,8 +1,10 @@
 //! Handle events of a user interface.
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
 use crate::window;
+use crate::Transformation;
 
 /// A user interface event.
 ///
@@ -23,6 +25,51 @@ pub enum Event {
 
     /// A touch event
     Touch(touch::Event),
+
+    /// An input method event
+    InputMethod(input_method::Event),
+}
+
+impl Event {
+    /// Applies the given [`Transformation`] to the positions contained in the
+    /// [`Event`].
+    ///
+    /// Widgets transforming their contents can use this to map the events
+    /// they receive into the local coordinates of their children.
+    pub fn transform(self, transformation: Transformation) -> Self {
+        match self {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: position * transformation,
+                })
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                Event::Touch(touch::Event::FingerPressed {
+                    id,
+                    position: position * transformation,
+                })
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                Event::Touch(touch::Event::FingerMoved {
+                    id,
+                    position: position * transformation,
+                })
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                Event::Touch(touch::Event::FingerLifted {
+                    id,
+                    position: position * transformation,
+                })
+            }
+            Event::Touch(touch::Event::FingerLost { id, position }) => {
+                Event::Touch(touch::Event::FingerLost {
+                    id,
+                    position: position * transformation,
+                })
+            }
+            event => event,
+        }
+    }
 }
 
 /// The status of an [`Event`] after being processed.
@@ -12,6 +12,8 @@ pub struct Font {
     pub stretch: Stretch,
     /// The [`Style`] of the [`Font`].
     pub style: Style,
+    /// The default OpenType [`Feature`]s of the [`Font`].
+    pub features: &'static [Feature],
 }
 
 impl Font {
@@ -21,6 +23,7 @@ impl Font {
         weight: Weight::Normal,
         stretch: Stretch::Normal,
         style: Style::Normal,
+        features: &[],
     };
 
     /// A monospaced font with normal [`Weight`].
@@ -37,6 +40,40 @@ impl Font {
             ..Self::DEFAULT
         }
     }
+
+    /// Sets the default OpenType [`Feature`]s of the [`Font`].
+    pub const fn features(self, features: &'static [Feature]) -> Self {
+        Font { features, ..self }
+    }
+}
+
+/// An OpenType feature of a [`Font`]; like tabular figures or ligatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Feature {
+    /// The four byte tag of the [`Feature`]; like `b"tnum"`.
+    pub tag: [u8; 4],
+    /// The value of the [`Feature`].
+    ///
+    /// `0` disables the [`Feature`] and `1` enables it. Some features take
+    /// other values; like the index of an alternate glyph.
+    pub value: u32,
+}
+
+impl Feature {
+    /// Creates a new [`Feature`] with the given tag and value.
+    pub const fn new(tag: &[u8; 4], value: u32) -> Self {
+        Self { tag: *tag, value }
+    }
+
+    /// Creates a new [`Feature`] that enables the given tag.
+    pub const fn enable(tag: &[u8; 4]) -> Self {
+        Self::new(tag, 1)
+    }
+
+    /// Creates a new [`Feature`] that disables the given tag.
+    pub const fn disable(tag: &[u8; 4]) -> Self {
+        Self::new(tag, 0)
+    }
 }
 
 /// A font family.
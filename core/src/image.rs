@@ -1,4 +1,7 @@
 //! Load and draw raster graphics.
+#[cfg(feature = "ktx2")]
+pub mod ktx2;
+
 pub use bytes::Bytes;
 
 use crate::{Radians, Rectangle, Size};
@@ -6,6 +9,7 @@ use crate::{Radians, Rectangle, Size};
 use rustc_hash::FxHasher;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, Weak};
 
 /// A handle of some image data.
 #[derive(Clone, PartialEq, Eq)]
@@ -16,14 +20,14 @@ pub enum Handle {
     /// Use [`from_path`] to create this variant.
     ///
     /// [`from_path`]: Self::from_path
-    Path(Id, PathBuf),
+    Path(Id, PathBuf, ColorHandling),
 
     /// A handle pointing to some encoded image bytes in-memory.
     ///
     /// Use [`from_bytes`] to create this variant.
     ///
     /// [`from_bytes`]: Self::from_bytes
-    Bytes(Id, Bytes),
+    Bytes(Id, Bytes, ColorHandling),
 
     /// A handle pointing to decoded image pixels in RGBA format.
     ///
@@ -40,6 +44,23 @@ pub enum Handle {
         /// The pixels.
         pixels: Bytes,
     },
+
+    /// A handle pointing to RGBA pixels that can be partially updated after
+    /// creation; like the frames of a video feed.
+    ///
+    /// Use [`from_rgba_dynamic`] to create this variant and [`update_region`]
+    /// to update its pixels.
+    ///
+    /// [`from_rgba_dynamic`]: Self::from_rgba_dynamic
+    Dynamic(Id, Dynamic),
+
+    /// A handle pointing to pre-compressed texture data; which can be
+    /// uploaded to the GPU as it is.
+    ///
+    /// Use [`from_compressed`] to create this variant.
+    ///
+    /// [`from_compressed`]: Self::from_compressed
+    Compressed(Id, Compressed),
 }
 
 impl Handle {
@@ -49,7 +70,11 @@ impl Handle {
     pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
         let path = path.into();
 
-        Self::Path(Id::path(&path), path)
+        Self::Path(
+            Id::path(&path, ColorHandling::default()),
+            path,
+            ColorHandling::default(),
+        )
     }
 
     /// Creates an image [`Handle`] containing the encoded image data directly.
@@ -59,7 +84,7 @@ impl Handle {
     /// This is useful if you already have your image loaded in-memory, maybe
     /// because you downloaded or generated it procedurally.
     pub fn from_bytes(bytes: impl Into<Bytes>) -> Handle {
-        Self::Bytes(Id::unique(), bytes.into())
+        Self::Bytes(Id::unique(), bytes.into(), ColorHandling::default())
     }
 
     /// Creates an image [`Handle`] containing the decoded image pixels directly.
@@ -82,12 +107,95 @@ impl Handle {
         }
     }
 
+    /// Creates a dynamic image [`Handle`] of the given size, with all of its
+    /// pixels transparent.
+    ///
+    /// Its pixels can be updated with [`update_region`] without allocating a
+    /// new image. Renderers may keep it in a dedicated texture, which is
+    /// freed once every clone of the [`Handle`] is dropped.
+    pub fn from_rgba_dynamic(size: Size<u32>) -> Handle {
+        Self::Dynamic(Id::unique(), Dynamic::new(size))
+    }
+
+    /// Creates an image [`Handle`] containing texture data compressed with
+    /// the given [`Format`].
+    ///
+    /// The `bytes` must contain the mip levels of the image one after the
+    /// other, starting with the full `size`; each level being half the size
+    /// of the previous one. Any incomplete trailing level is ignored.
+    ///
+    /// Like any other image, the texels are assumed to be in sRGB.
+    ///
+    /// Renderers upload the data directly if the GPU supports the [`Format`];
+    /// otherwise, the image is not drawn and an error is logged.
+    pub fn from_compressed(
+        bytes: impl Into<Bytes>,
+        format: Format,
+        size: Size<u32>,
+    ) -> Handle {
+        Self::Compressed(
+            Id::unique(),
+            Compressed::new(format, size, bytes.into()),
+        )
+    }
+
+    /// Creates a compressed image [`Handle`] from the bytes of a KTX2
+    /// container.
+    ///
+    /// Only 2D textures with a single layer and face and no
+    /// supercompression are supported.
+    #[cfg(feature = "ktx2")]
+    pub fn from_ktx2(bytes: impl Into<Bytes>) -> Result<Handle, ktx2::Error> {
+        let (format, size, levels) = ktx2::parse(bytes.into())?;
+
+        Ok(Self::Compressed(
+            Id::unique(),
+            Compressed {
+                format,
+                size,
+                levels,
+            },
+        ))
+    }
+
+    /// Sets the [`ColorHandling`] of the encoded image of the [`Handle`].
+    ///
+    /// Images that have already been decoded—like the ones created with
+    /// [`from_rgba`]—are assumed to be in sRGB and are left untouched.
+    ///
+    /// [`from_rgba`]: Self::from_rgba
+    pub fn color_handling(self, color_handling: ColorHandling) -> Self {
+        match self {
+            Self::Path(_, path, _) => Self::Path(
+                Id::path(&path, color_handling),
+                path,
+                color_handling,
+            ),
+            Self::Bytes(id, bytes, current) => {
+                // The same bytes decode to different pixels, so they cannot
+                // share the same identifier
+                let id = if current == color_handling {
+                    id
+                } else {
+                    Id::unique()
+                };
+
+                Self::Bytes(id, bytes, color_handling)
+            }
+            Self::Rgba { .. } | Self::Dynamic(..) | Self::Compressed(..) => {
+                self
+            }
+        }
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> Id {
         match self {
-            Handle::Path(id, _)
-            | Handle::Bytes(id, _)
-            | Handle::Rgba { id, .. } => *id,
+            Handle::Path(id, ..)
+            | Handle::Bytes(id, ..)
+            | Handle::Rgba { id, .. }
+            | Handle::Dynamic(id, _)
+            | Handle::Compressed(id, _) => *id,
         }
     }
 }
@@ -104,13 +212,355 @@ where
 impl std::fmt::Debug for Handle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Path(_, path) => write!(f, "Path({path:?})"),
-            Self::Bytes(_, _) => write!(f, "Bytes(...)"),
+            Self::Path(_, path, _) => write!(f, "Path({path:?})"),
+            Self::Bytes(..) => write!(f, "Bytes(...)"),
             Self::Rgba { width, height, .. } => {
                 write!(f, "Pixels({width} * {height})")
             }
+            Self::Dynamic(_, dynamic) => {
+                let Size { width, height } = dynamic.size();
+
+                write!(f, "Dynamic({width} * {height})")
+            }
+            Self::Compressed(_, compressed) => {
+                let Size { width, height } = compressed.size();
+
+                write!(
+                    f,
+                    "Compressed({:?}, {width} * {height})",
+                    compressed.format()
+                )
+            }
+        }
+    }
+}
+
+/// Updates the pixels of a dynamic image [`Handle`] inside the given region.
+///
+/// The `pixels` must be in RGBA format; therefore, their length must be
+/// `region.width * region.height * 4`.
+///
+/// Only the updated regions are uploaded again by renderers the next time the
+/// image is drawn.
+pub fn update_region(
+    handle: &Handle,
+    region: Rectangle<u32>,
+    pixels: &[u8],
+) -> Result<(), Error> {
+    match handle {
+        Handle::Dynamic(_, dynamic) => dynamic.update(region, pixels),
+        _ => Err(Error::NotDynamic),
+    }
+}
+
+/// The pixels of a dynamic image [`Handle`].
+#[derive(Clone)]
+pub struct Dynamic {
+    size: Size<u32>,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    pixels: Vec<u8>,
+    dirty: Option<Rectangle<u32>>,
+    version: u64,
+}
+
+impl Dynamic {
+    fn new(size: Size<u32>) -> Self {
+        Self {
+            size,
+            state: Arc::new(Mutex::new(State {
+                pixels: vec![0; size.width as usize * size.height as usize * 4],
+                dirty: Some(Rectangle::with_size(size)),
+                version: 0,
+            })),
+        }
+    }
+
+    /// Returns the size of the [`Dynamic`] pixels.
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Returns the version of the [`Dynamic`] pixels, which increases with
+    /// every update.
+    pub fn version(&self) -> u64 {
+        self.lock().version
+    }
+
+    /// Returns a copy of all the [`Dynamic`] pixels.
+    pub fn pixels(&self) -> Bytes {
+        Bytes::from(self.lock().pixels.clone())
+    }
+
+    /// Takes the region updated since the last call, if any; calling the
+    /// given closure with it and all of the [`Dynamic`] pixels.
+    pub fn take_dirty<T>(
+        &self,
+        f: impl FnOnce(Rectangle<u32>, &[u8]) -> T,
+    ) -> Option<T> {
+        let mut state = self.lock();
+        let dirty = state.dirty.take()?;
+
+        Some(f(dirty, &state.pixels))
+    }
+
+    /// Creates a [`WeakDynamic`] reference to the [`Dynamic`] pixels.
+    pub fn downgrade(&self) -> WeakDynamic {
+        WeakDynamic(Arc::downgrade(&self.state))
+    }
+
+    fn update(
+        &self,
+        region: Rectangle<u32>,
+        pixels: &[u8],
+    ) -> Result<(), Error> {
+        let is_within = region
+            .x
+            .checked_add(region.width)
+            .is_some_and(|right| right <= self.size.width)
+            && region
+                .y
+                .checked_add(region.height)
+                .is_some_and(|bottom| bottom <= self.size.height);
+
+        if !is_within {
+            return Err(Error::OutOfBounds {
+                region,
+                size: self.size,
+            });
+        }
+
+        let row = region.width as usize * 4;
+        let expected = row * region.height as usize;
+
+        if pixels.len() != expected {
+            return Err(Error::InvalidLength {
+                expected,
+                actual: pixels.len(),
+            });
+        }
+
+        if expected == 0 {
+            return Ok(());
+        }
+
+        let stride = self.size.width as usize * 4;
+        let mut state = self.lock();
+
+        for (i, source) in pixels.chunks_exact(row).enumerate() {
+            let offset =
+                (region.y as usize + i) * stride + region.x as usize * 4;
+
+            state.pixels[offset..offset + row].copy_from_slice(source);
+        }
+
+        state.dirty = Some(match state.dirty {
+            Some(dirty) => {
+                let x = dirty.x.min(region.x);
+                let y = dirty.y.min(region.y);
+
+                Rectangle {
+                    x,
+                    y,
+                    width: (dirty.x + dirty.width).max(region.x + region.width)
+                        - x,
+                    height: (dirty.y + dirty.height)
+                        .max(region.y + region.height)
+                        - y,
+                }
+            }
+            None => region,
+        });
+
+        state.version += 1;
+
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl PartialEq for Dynamic {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for Dynamic {}
+
+impl std::fmt::Debug for Dynamic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dynamic").field("size", &self.size).finish()
+    }
+}
+
+/// The texture data of a compressed image [`Handle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compressed {
+    format: Format,
+    size: Size<u32>,
+    levels: Vec<Bytes>,
+}
+
+impl Compressed {
+    fn new(format: Format, size: Size<u32>, mut bytes: Bytes) -> Self {
+        let mut levels = Vec::new();
+
+        for level in 0..format.level_count(size) {
+            let length = format.level_length(size, level);
+
+            if length > bytes.len() {
+                break;
+            }
+
+            levels.push(bytes.split_to(length));
+        }
+
+        Self {
+            format,
+            size,
+            levels,
         }
     }
+
+    /// Returns the [`Format`] of the [`Compressed`] data.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns the size of the full mip level of the [`Compressed`] data.
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Returns the data of each mip level, starting with the full one.
+    pub fn levels(&self) -> &[Bytes] {
+        &self.levels
+    }
+}
+
+/// The block compression format of a compressed image [`Handle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// BC1; also known as DXT1. 4x4 blocks of 8 bytes with 1-bit alpha.
+    Bc1,
+    /// BC2; also known as DXT3. 4x4 blocks of 16 bytes with explicit alpha.
+    Bc2,
+    /// BC3; also known as DXT5. 4x4 blocks of 16 bytes with interpolated
+    /// alpha.
+    Bc3,
+    /// BC7. 4x4 blocks of 16 bytes with high quality color and alpha.
+    Bc7,
+    /// ETC2. 4x4 blocks of 8 bytes without alpha.
+    Etc2Rgb8,
+    /// ETC2. 4x4 blocks of 8 bytes with 1-bit alpha.
+    Etc2Rgb8A1,
+    /// ETC2 with EAC alpha. 4x4 blocks of 16 bytes.
+    Etc2Rgba8,
+    /// ASTC with 4x4 blocks of 16 bytes.
+    Astc4x4,
+    /// ASTC with 5x5 blocks of 16 bytes.
+    Astc5x5,
+    /// ASTC with 6x6 blocks of 16 bytes.
+    Astc6x6,
+    /// ASTC with 8x8 blocks of 16 bytes.
+    Astc8x8,
+    /// ASTC with 10x10 blocks of 16 bytes.
+    Astc10x10,
+    /// ASTC with 12x12 blocks of 16 bytes.
+    Astc12x12,
+}
+
+impl Format {
+    /// Returns the size of a block of the [`Format`], in texels.
+    pub fn block_size(self) -> Size<u32> {
+        let side = match self {
+            Self::Bc1
+            | Self::Bc2
+            | Self::Bc3
+            | Self::Bc7
+            | Self::Etc2Rgb8
+            | Self::Etc2Rgb8A1
+            | Self::Etc2Rgba8
+            | Self::Astc4x4 => 4,
+            Self::Astc5x5 => 5,
+            Self::Astc6x6 => 6,
+            Self::Astc8x8 => 8,
+            Self::Astc10x10 => 10,
+            Self::Astc12x12 => 12,
+        };
+
+        Size::new(side, side)
+    }
+
+    /// Returns the amount of bytes of a block of the [`Format`].
+    pub fn block_length(self) -> usize {
+        match self {
+            Self::Bc1 | Self::Etc2Rgb8 | Self::Etc2Rgb8A1 => 8,
+            _ => 16,
+        }
+    }
+
+    /// Returns the amount of mip levels of a full chain for an image of the
+    /// given size.
+    pub fn level_count(self, size: Size<u32>) -> u32 {
+        u32::BITS - size.width.max(size.height).max(1).leading_zeros()
+    }
+
+    /// Returns the amount of bytes of the given mip level of an image of the
+    /// given size.
+    pub fn level_length(self, size: Size<u32>, level: u32) -> usize {
+        let width = size.width.checked_shr(level).unwrap_or(0).max(1);
+        let height = size.height.checked_shr(level).unwrap_or(0).max(1);
+        let block = self.block_size();
+
+        (width.div_ceil(block.width) as usize)
+            .saturating_mul(height.div_ceil(block.height) as usize)
+            .saturating_mul(self.block_length())
+    }
+}
+
+/// A reference to some [`Dynamic`] pixels that does not keep them alive.
+#[derive(Debug, Clone)]
+pub struct WeakDynamic(Weak<Mutex<State>>);
+
+impl WeakDynamic {
+    /// Returns true if every [`Handle`] of the [`Dynamic`] pixels has been
+    /// dropped.
+    pub fn is_dropped(&self) -> bool {
+        self.0.strong_count() == 0
+    }
+}
+
+/// An error produced when updating the pixels of a dynamic image [`Handle`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The [`Handle`] was not created with [`Handle::from_rgba_dynamic`].
+    #[error("the image handle is not dynamic")]
+    NotDynamic,
+    /// The updated region does not fit in the image.
+    #[error("the region {region:?} does not fit in an image of {size:?}")]
+    OutOfBounds {
+        /// The updated region.
+        region: Rectangle<u32>,
+        /// The size of the image.
+        size: Size<u32>,
+    },
+    /// The length of the pixels does not match the updated region.
+    #[error("expected {expected} bytes of RGBA pixels, but got {actual}")]
+    InvalidLength {
+        /// The expected length, in bytes.
+        expected: usize,
+        /// The actual length, in bytes.
+        actual: usize,
+    },
 }
 
 /// The unique identifier of some [`Handle`] data.
@@ -132,11 +582,15 @@ impl Id {
         Self(_Id::Unique(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed)))
     }
 
-    fn path(path: impl AsRef<Path>) -> Self {
+    fn path(path: impl AsRef<Path>, color_handling: ColorHandling) -> Self {
         let hash = {
             let mut hasher = FxHasher::default();
             path.as_ref().hash(&mut hasher);
 
+            if color_handling != ColorHandling::default() {
+                color_handling.hash(&mut hasher);
+            }
+
             hasher.finish()
         };
 
@@ -144,6 +598,22 @@ impl Id {
     }
 }
 
+/// How the colors of an encoded image with an embedded ICC profile are
+/// handled when decoding it.
+///
+/// Images without a profile are always assumed to be in sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorHandling {
+    /// The embedded profile is ignored and the pixels are used as they are.
+    Ignore,
+    /// The pixels are converted from the embedded profile to sRGB.
+    ///
+    /// The conversion only happens if the renderer supports it; for instance,
+    /// when the `icc` feature is enabled.
+    #[default]
+    ConvertToSrgb,
+}
+
 /// Image filtering strategy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum FilterMethod {
@@ -177,3 +647,93 @@ pub trait Renderer: crate::Renderer {
         opacity: f32,
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_region_rejects_regions_out_of_bounds() {
+        let handle = Handle::from_rgba_dynamic(Size::new(4, 4));
+
+        let region = Rectangle {
+            x: 2,
+            y: 0,
+            width: 3,
+            height: 1,
+        };
+
+        assert_eq!(
+            update_region(&handle, region, &[0; 12]),
+            Err(Error::OutOfBounds {
+                region,
+                size: Size::new(4, 4),
+            })
+        );
+    }
+
+    #[test]
+    fn update_region_merges_dirty_regions() {
+        let handle = Handle::from_rgba_dynamic(Size::new(4, 4));
+
+        let Handle::Dynamic(_, dynamic) = &handle else {
+            unreachable!()
+        };
+
+        let _ = dynamic.take_dirty(|_, _| ());
+
+        let pixel = |x, y| Rectangle {
+            x,
+            y,
+            width: 1,
+            height: 1,
+        };
+
+        update_region(&handle, pixel(0, 1), &[255; 4]).unwrap();
+        update_region(&handle, pixel(2, 3), &[255; 4]).unwrap();
+
+        assert_eq!(dynamic.version(), 2);
+        assert_eq!(
+            dynamic.take_dirty(|dirty, pixels| (dirty, pixels[4 * 4])),
+            Some((
+                Rectangle {
+                    x: 0,
+                    y: 1,
+                    width: 3,
+                    height: 3,
+                },
+                255
+            ))
+        );
+        assert_eq!(dynamic.take_dirty(|dirty, _| dirty), None);
+    }
+
+    #[test]
+    fn from_compressed_splits_mip_levels() {
+        let size = Size::new(16, 8);
+
+        // 4x2 + 2x1 + 1x1 + 1x1 + 1x1 blocks, plus an incomplete block
+        let bytes = vec![0; (8 + 2 + 1 + 1 + 1) * 16 + 7];
+
+        let Handle::Compressed(_, compressed) =
+            Handle::from_compressed(bytes, Format::Bc7, size)
+        else {
+            unreachable!()
+        };
+
+        let lengths: Vec<_> =
+            compressed.levels().iter().map(Bytes::len).collect();
+
+        assert_eq!(Format::Bc7.level_count(size), 5);
+        assert_eq!(lengths, [128, 32, 16, 16, 16]);
+    }
+
+    #[test]
+    fn level_lengths_never_overflow() {
+        let size = Size::new(u32::MAX, u32::MAX);
+
+        assert_eq!(Format::Bc7.level_length(size, 32), 16);
+        assert_eq!(Format::Bc7.level_length(size, u32::MAX), 16);
+        assert_eq!(Format::Bc1.level_count(size), 32);
+    }
+}
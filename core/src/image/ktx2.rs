@@ -0,0 +1,282 @@
+//! Read compressed images from KTX2 containers.
+use crate::image::{Bytes, Format};
+use crate::Size;
+
+/// The identifier every KTX2 container starts with.
+const IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+/// The length of the header and the index of a KTX2 container, in bytes.
+const HEADER_LENGTH: usize = 80;
+
+/// The length of an entry of the level index, in bytes.
+const LEVEL_LENGTH: usize = 24;
+
+/// An error produced when reading a KTX2 container.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The bytes are not a KTX2 container.
+    #[error("the data is not a KTX2 container")]
+    NotKtx2,
+    /// The container ends before some of its data.
+    #[error("the KTX2 container is truncated")]
+    Truncated,
+    /// The texture format of the container is not supported.
+    #[error("the Vulkan format {0} is not a supported compressed format")]
+    UnsupportedFormat(u32),
+    /// The container is not a single 2D texture.
+    #[error(
+        "only 2D textures with a single layer and face are supported; \
+        got a {depth}x{layers}x{faces} (depth x layers x faces) texture"
+    )]
+    UnsupportedShape {
+        /// The depth of the texture.
+        depth: u32,
+        /// The number of array layers of the texture.
+        layers: u32,
+        /// The number of cubemap faces of the texture.
+        faces: u32,
+    },
+    /// The size of the texture is zero.
+    #[error("the size {0:?} of the texture is invalid")]
+    InvalidSize(Size<u32>),
+    /// The container has more mip levels than the size of its texture
+    /// allows.
+    #[error("the texture has {levels} mip levels, but at most {max} fit")]
+    TooManyLevels {
+        /// The amount of mip levels of the container.
+        levels: u32,
+        /// The amount of mip levels of a full chain of the texture.
+        max: u32,
+    },
+    /// The data of the container is supercompressed.
+    #[error("supercompression scheme {0} is not supported")]
+    Supercompressed(u32),
+    /// A mip level does not have the length its size requires.
+    #[error(
+        "mip level {level} has {actual} bytes, but {expected} were expected"
+    )]
+    InvalidLevel {
+        /// The mip level.
+        level: u32,
+        /// The expected length, in bytes.
+        expected: usize,
+        /// The actual length, in bytes.
+        actual: usize,
+    },
+}
+
+/// Parses a KTX2 container; returning its [`Format`], size, and the data of
+/// each mip level, starting with the full one.
+pub(crate) fn parse(
+    bytes: Bytes,
+) -> Result<(Format, Size<u32>, Vec<Bytes>), Error> {
+    if bytes.len() < IDENTIFIER.len() || bytes[..IDENTIFIER.len()] != IDENTIFIER
+    {
+        return Err(Error::NotKtx2);
+    }
+
+    if bytes.len() < HEADER_LENGTH {
+        return Err(Error::Truncated);
+    }
+
+    let u32_at = |offset: usize| {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    };
+
+    let vk_format = u32_at(12);
+    let size = Size::new(u32_at(20), u32_at(24));
+    let depth = u32_at(28);
+    let layers = u32_at(32);
+    let faces = u32_at(36);
+    let level_count = u32_at(40).max(1);
+    let supercompression = u32_at(44);
+
+    let format =
+        format(vk_format).ok_or(Error::UnsupportedFormat(vk_format))?;
+
+    if size.height == 0 || depth > 1 || layers > 1 || faces != 1 {
+        return Err(Error::UnsupportedShape {
+            depth,
+            layers,
+            faces,
+        });
+    }
+
+    if size.width == 0 {
+        return Err(Error::InvalidSize(size));
+    }
+
+    let max_levels = format.level_count(size);
+
+    if level_count > max_levels {
+        return Err(Error::TooManyLevels {
+            levels: level_count,
+            max: max_levels,
+        });
+    }
+
+    if supercompression != 0 {
+        return Err(Error::Supercompressed(supercompression));
+    }
+
+    (0..level_count)
+        .map(|level| {
+            let entry = HEADER_LENGTH + level as usize * LEVEL_LENGTH;
+
+            let index = bytes
+                .get(entry..entry + LEVEL_LENGTH)
+                .ok_or(Error::Truncated)?;
+
+            let u64_at = |offset: usize| {
+                u64::from_le_bytes(
+                    index[offset..offset + 8].try_into().unwrap(),
+                )
+            };
+
+            let offset =
+                usize::try_from(u64_at(0)).map_err(|_| Error::Truncated)?;
+            let length =
+                usize::try_from(u64_at(8)).map_err(|_| Error::Truncated)?;
+
+            let expected = format.level_length(size, level);
+
+            if length != expected {
+                return Err(Error::InvalidLevel {
+                    level,
+                    expected,
+                    actual: length,
+                });
+            }
+
+            let end = offset.checked_add(length).ok_or(Error::Truncated)?;
+
+            if end > bytes.len() {
+                return Err(Error::Truncated);
+            }
+
+            Ok(bytes.slice(offset..end))
+        })
+        .collect::<Result<_, _>>()
+        .map(|levels| (format, size, levels))
+}
+
+/// Returns the [`Format`] of the given `VkFormat`, if supported.
+///
+/// Both the UNORM and sRGB variants are accepted, since the texels of every
+/// image are assumed to be in sRGB.
+fn format(vk_format: u32) -> Option<Format> {
+    Some(match vk_format {
+        131..=134 => Format::Bc1,
+        135 | 136 => Format::Bc2,
+        137 | 138 => Format::Bc3,
+        145 | 146 => Format::Bc7,
+        147 | 148 => Format::Etc2Rgb8,
+        149 | 150 => Format::Etc2Rgb8A1,
+        151 | 152 => Format::Etc2Rgba8,
+        157 | 158 => Format::Astc4x4,
+        161 | 162 => Format::Astc5x5,
+        165 | 166 => Format::Astc6x6,
+        171 | 172 => Format::Astc8x8,
+        179 | 180 => Format::Astc10x10,
+        183 | 184 => Format::Astc12x12,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a KTX2 container with the given `VkFormat`, size, and levels.
+    fn container(vk_format: u32, size: Size<u32>, levels: &[&[u8]]) -> Bytes {
+        let mut header = IDENTIFIER.to_vec();
+
+        for value in [vk_format, 1, size.width, size.height, 0, 0, 1]
+            .into_iter()
+            .chain([levels.len() as u32, 0])
+        {
+            header.extend(value.to_le_bytes());
+        }
+
+        header.resize(HEADER_LENGTH, 0);
+
+        let mut offset = HEADER_LENGTH + levels.len() * LEVEL_LENGTH;
+        let mut data = Vec::new();
+
+        for level in levels {
+            for value in [offset, level.len(), level.len()] {
+                header.extend((value as u64).to_le_bytes());
+            }
+
+            offset += level.len();
+            data.extend_from_slice(level);
+        }
+
+        header.extend(data);
+        header.into()
+    }
+
+    #[test]
+    fn levels_are_read_in_order() {
+        let bytes = container(146, Size::new(8, 4), &[&[1; 32], &[2; 16]]);
+
+        let (format, size, levels) = parse(bytes).unwrap();
+
+        assert_eq!(format, Format::Bc7);
+        assert_eq!(size, Size::new(8, 4));
+        assert_eq!(levels, [&[1; 32][..], &[2; 16][..]]);
+    }
+
+    #[test]
+    fn invalid_containers_are_rejected() {
+        assert_eq!(parse(Bytes::from_static(b"PNG")), Err(Error::NotKtx2));
+
+        assert_eq!(
+            parse(container(37, Size::new(4, 4), &[&[0; 64]])),
+            Err(Error::UnsupportedFormat(37))
+        );
+
+        assert_eq!(
+            parse(container(133, Size::new(8, 8), &[&[0; 8]])),
+            Err(Error::InvalidLevel {
+                level: 0,
+                expected: 32,
+                actual: 8,
+            })
+        );
+
+        let mut truncated =
+            container(133, Size::new(4, 4), &[&[0; 8]]).to_vec();
+        truncated.truncate(truncated.len() - 1);
+
+        assert_eq!(parse(truncated.into()), Err(Error::Truncated));
+    }
+
+    #[test]
+    fn zero_widths_are_rejected() {
+        assert_eq!(
+            parse(container(146, Size::new(0, 4), &[&[0; 16]])),
+            Err(Error::InvalidSize(Size::new(0, 4)))
+        );
+    }
+
+    #[test]
+    fn levels_beyond_a_full_chain_are_rejected() {
+        // 4x4, 2x2, and 1x1 are the only levels of a 4x4 texture
+        assert_eq!(
+            parse(container(146, Size::new(4, 4), &[&[0; 16]; 4])),
+            Err(Error::TooManyLevels { levels: 4, max: 3 })
+        );
+
+        // Large level counts are rejected before reading any level
+        let mut header = container(146, Size::new(4, 4), &[&[0; 16]]).to_vec();
+        header[40..44].copy_from_slice(&40_u32.to_le_bytes());
+
+        assert_eq!(
+            parse(header.into()),
+            Err(Error::TooManyLevels { levels: 40, max: 3 })
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Listen to the events of input methods, like the ones used to type
+//! Japanese or Chinese text.
+use std::ops::Range;
+
+/// An input method event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The input method was enabled.
+    Opened,
+
+    /// Some text is being composed, but it has not been committed yet.
+    ///
+    /// The range is the byte position of the cursor in the text, if any.
+    /// An empty text means the composition was cleared.
+    Preedit(String, Option<Range<usize>>),
+
+    /// Some composed text was committed.
+    Commit(String),
+
+    /// The input method was disabled.
+    Closed,
+}
@@ -2,10 +2,14 @@
 pub mod key;
 
 mod event;
+mod input_mode;
 mod location;
 mod modifiers;
+mod sticky;
 
 pub use event::Event;
+pub use input_mode::InputMode;
 pub use key::Key;
 pub use location::Location;
 pub use modifiers::Modifiers;
+pub use sticky::StickyModifiers;
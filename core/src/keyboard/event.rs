@@ -38,4 +38,9 @@ pub enum Event {
 
     /// The keyboard modifiers have changed.
     ModifiersChanged(Modifiers),
+
+    /// The latched modifiers have changed.
+    ///
+    /// It is only produced when sticky modifiers are enabled.
+    ModifiersLatched(Modifiers),
 }
@@ -0,0 +1,72 @@
+/// The kind of text a field expects.
+///
+/// Widgets use it to filter the characters typed into them and as a hint
+/// for the platform to show the most fitting soft keyboard.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum InputMode {
+    /// Any text.
+    #[default]
+    Text,
+    /// Digits only.
+    Numeric,
+    /// Digits with an optional sign and decimal separator.
+    Decimal,
+    /// An email address.
+    Email,
+    /// A URL.
+    Url,
+    /// A telephone number.
+    Tel,
+}
+
+impl InputMode {
+    /// The decimal separators accepted by [`InputMode::Decimal`].
+    ///
+    /// They are all normalized into a `'.'`.
+    pub const DECIMAL_SEPARATORS: [char; 3] = ['.', ',', '\u{066B}'];
+
+    /// Filters the given character typed into a field with the
+    /// [`InputMode`]; returning the character that should be inserted, if
+    /// any.
+    pub fn filter(self, c: char) -> Option<char> {
+        match self {
+            InputMode::Text => Some(c),
+            InputMode::Numeric => c.is_ascii_digit().then_some(c),
+            InputMode::Decimal => {
+                if Self::DECIMAL_SEPARATORS.contains(&c) {
+                    Some('.')
+                } else {
+                    (c.is_ascii_digit() || c == '-' || c == '+').then_some(c)
+                }
+            }
+            InputMode::Email | InputMode::Url => {
+                (!c.is_whitespace()).then_some(c)
+            }
+            InputMode::Tel => (c.is_ascii_digit()
+                || matches!(c, '+' | '-' | '(' | ')' | ' ' | '*' | '#' | '.'))
+            .then_some(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_normalizes_separators() {
+        for separator in InputMode::DECIMAL_SEPARATORS {
+            assert_eq!(InputMode::Decimal.filter(separator), Some('.'));
+        }
+
+        assert_eq!(InputMode::Decimal.filter('7'), Some('7'));
+        assert_eq!(InputMode::Decimal.filter('e'), None);
+    }
+
+    #[test]
+    fn numeric_only_accepts_digits() {
+        assert_eq!(InputMode::Numeric.filter('4'), Some('4'));
+        assert_eq!(InputMode::Numeric.filter('-'), None);
+        assert_eq!(InputMode::Numeric.filter('.'), None);
+    }
+}
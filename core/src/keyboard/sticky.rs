@@ -0,0 +1,273 @@
+use crate::keyboard::key::{self, Key};
+use crate::keyboard::{self, Modifiers};
+use crate::mouse;
+use crate::Event;
+
+/// The sticky modifiers of a keyboard; an accessibility aid for users who
+/// cannot hold a modifier while pressing another key.
+///
+/// A modifier that is pressed and released on its own is latched, and it
+/// applies to the next key press or mouse click as if it was held. Tapping
+/// a latched modifier again releases it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StickyModifiers {
+    held: Modifiers,
+    tapped: Modifiers,
+    latched: Modifiers,
+    is_used: bool,
+}
+
+impl StickyModifiers {
+    /// Creates new [`StickyModifiers`] with nothing latched.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the currently latched [`Modifiers`].
+    pub fn latched(&self) -> Modifiers {
+        self.latched
+    }
+
+    /// Processes an [`Event`] and returns the events that should be handled
+    /// in its place.
+    ///
+    /// The latched [`Modifiers`] are added to any keyboard event. A
+    /// [`keyboard::Event::ModifiersLatched`] is produced whenever they
+    /// change; together with a [`keyboard::Event::ModifiersChanged`], so
+    /// widgets keeping track of the modifiers see them too.
+    pub fn process(&mut self, event: Event) -> Vec<Event> {
+        let latched = self.latched;
+
+        let event = match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                let released = self.held.difference(modifiers);
+
+                self.tapped |= modifiers.difference(self.held);
+                self.latched.toggle(self.tapped & released);
+                self.tapped.remove(released);
+                self.held = modifiers;
+
+                Event::Keyboard(keyboard::Event::ModifiersChanged(
+                    modifiers | self.latched,
+                ))
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                location,
+                modifiers,
+                text,
+            }) => {
+                if !is_modifier(&key) {
+                    self.tapped = Modifiers::empty();
+                    self.is_used = !self.latched.is_empty();
+                }
+
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key,
+                    location,
+                    modifiers: modifiers | self.latched,
+                    text,
+                })
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location,
+                modifiers,
+            }) => {
+                let modifiers = modifiers | self.latched;
+
+                if !is_modifier(&key) {
+                    self.release();
+                }
+
+                Event::Keyboard(keyboard::Event::KeyReleased {
+                    key,
+                    location,
+                    modifiers,
+                })
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                self.tapped = Modifiers::empty();
+                self.is_used = !self.latched.is_empty();
+
+                event
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(_)) => {
+                self.release();
+
+                event
+            }
+            _ => event,
+        };
+
+        if self.latched == latched {
+            return vec![event];
+        }
+
+        let mut events = vec![event];
+
+        if !matches!(
+            events[0],
+            Event::Keyboard(keyboard::Event::ModifiersChanged(_))
+        ) {
+            events.push(Event::Keyboard(keyboard::Event::ModifiersChanged(
+                self.held | self.latched,
+            )));
+        }
+
+        events.push(Event::Keyboard(keyboard::Event::ModifiersLatched(
+            self.latched,
+        )));
+
+        events
+    }
+
+    /// Releases the latched modifiers, if they were used.
+    fn release(&mut self) {
+        if self.is_used {
+            self.latched = Modifiers::empty();
+            self.is_used = false;
+        }
+    }
+}
+
+fn is_modifier(key: &Key) -> bool {
+    matches!(
+        key,
+        Key::Named(
+            key::Named::Shift
+                | key::Named::Control
+                | key::Named::Alt
+                | key::Named::Super
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::Location;
+
+    fn modifiers(modifiers: Modifiers) -> Event {
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
+    }
+
+    fn latched(modifiers: Modifiers) -> Event {
+        Event::Keyboard(keyboard::Event::ModifiersLatched(modifiers))
+    }
+
+    fn press(key: &str, modifiers: Modifiers) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: Key::Character(key.into()),
+            location: Location::Standard,
+            modifiers,
+            text: None,
+        })
+    }
+
+    fn release(key: &str, modifiers: Modifiers) -> Event {
+        Event::Keyboard(keyboard::Event::KeyReleased {
+            key: Key::Character(key.into()),
+            location: Location::Standard,
+            modifiers,
+        })
+    }
+
+    /// Taps the given modifier; returning all the resulting events.
+    fn tap(sticky: &mut StickyModifiers, modifier: Modifiers) -> Vec<Event> {
+        let mut events = sticky.process(modifiers(sticky.held | modifier));
+        events.extend(sticky.process(modifiers(sticky.held - modifier)));
+        events
+    }
+
+    #[test]
+    fn tapped_modifier_latches_for_the_next_key() {
+        let mut sticky = StickyModifiers::new();
+
+        assert_eq!(
+            tap(&mut sticky, Modifiers::CTRL),
+            vec![
+                modifiers(Modifiers::CTRL),
+                modifiers(Modifiers::CTRL),
+                latched(Modifiers::CTRL),
+            ]
+        );
+
+        assert_eq!(
+            sticky.process(press("c", Modifiers::empty())),
+            vec![press("c", Modifiers::CTRL)]
+        );
+
+        assert_eq!(
+            sticky.process(release("c", Modifiers::empty())),
+            vec![
+                release("c", Modifiers::CTRL),
+                modifiers(Modifiers::empty()),
+                latched(Modifiers::empty()),
+            ]
+        );
+
+        assert_eq!(
+            sticky.process(press("c", Modifiers::empty())),
+            vec![press("c", Modifiers::empty())]
+        );
+    }
+
+    #[test]
+    fn held_modifiers_do_not_latch() {
+        let mut sticky = StickyModifiers::new();
+
+        let _ = sticky.process(modifiers(Modifiers::SHIFT));
+        let _ = sticky.process(press("a", Modifiers::SHIFT));
+        let _ = sticky.process(release("a", Modifiers::SHIFT));
+
+        assert_eq!(
+            sticky.process(modifiers(Modifiers::empty())),
+            vec![modifiers(Modifiers::empty())]
+        );
+        assert_eq!(sticky.latched(), Modifiers::empty());
+    }
+
+    #[test]
+    fn tapping_a_latched_modifier_releases_it() {
+        let mut sticky = StickyModifiers::new();
+
+        let _ = tap(&mut sticky, Modifiers::SHIFT);
+        let _ = tap(&mut sticky, Modifiers::ALT);
+        assert_eq!(sticky.latched(), Modifiers::SHIFT | Modifiers::ALT);
+
+        assert_eq!(
+            tap(&mut sticky, Modifiers::SHIFT).last(),
+            Some(&latched(Modifiers::ALT))
+        );
+
+        assert_eq!(
+            sticky.process(press("x", Modifiers::empty())),
+            vec![press("x", Modifiers::ALT)]
+        );
+    }
+
+    #[test]
+    fn latched_modifiers_apply_to_mouse_clicks() {
+        let mut sticky = StickyModifiers::new();
+        let click = mouse::Event::ButtonPressed(mouse::Button::Left);
+        let unclick = mouse::Event::ButtonReleased(mouse::Button::Left);
+
+        let _ = tap(&mut sticky, Modifiers::SHIFT);
+
+        assert_eq!(
+            sticky.process(Event::Mouse(click)),
+            vec![Event::Mouse(click)]
+        );
+        assert_eq!(sticky.latched(), Modifiers::SHIFT);
+
+        assert_eq!(
+            sticky.process(Event::Mouse(unclick)),
+            vec![
+                Event::Mouse(unclick),
+                modifiers(Modifiers::empty()),
+                latched(Modifiers::empty()),
+            ]
+        );
+    }
+}
@@ -1,7 +1,23 @@
 //! Position your widgets properly.
+//!
+//! # Minimum and maximum sizes
+//! Container-like widgets—like `Container`, `Button`, `Row`, `Column`,
+//! `Scrollable`, and `Stack`—can bound their size with `min_width`,
+//! `min_height`, `max_width`, and `max_height`. The bounds are applied
+//! with [`Limits::constrain`] and resolve as follows:
+//!
+//! | [`Length`]             | Resolved size                                  |
+//! |------------------------|------------------------------------------------|
+//! | [`Length::Fill`]       | The available space, at most the maximum       |
+//! | [`Length::Shrink`]     | The size of the contents, at least the minimum |
+//! | [`Length::Fixed`]      | The given amount, clamped to both bounds       |
+//!
+//! The minimum wins when it is greater than the maximum; and both bounds
+//! are always limited by the space offered by the parent.
 mod limits;
 mod node;
 
+pub mod diagnostics;
 pub mod flex;
 
 pub use limits::Limits;
@@ -142,11 +158,13 @@ pub fn contained(
 
     let limits = limits.width(width).height(height);
     let content = f(&limits);
+    let baseline = content.parent_baseline();
 
     Node::with_children(
         limits.resolve(width, height, content.size()),
         vec![content],
     )
+    .with_baseline(baseline)
 }
 
 /// Computes the [`Node`] that fits the [`Limits`] given some width, height, and
@@ -183,8 +201,9 @@ pub fn positioned(
         .shrink(padding)
         .resolve(width, height, content.size());
 
-    Node::with_children(
-        size.expand(padding),
-        vec![position(content.move_to((padding.left, padding.top)), size)],
-    )
+    let content = position(content.move_to((padding.left, padding.top)), size);
+    let baseline = content.parent_baseline();
+
+    Node::with_children(size.expand(padding), vec![content])
+        .with_baseline(baseline)
 }
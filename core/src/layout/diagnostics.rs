@@ -0,0 +1,213 @@
+//! Detect degenerate layouts while debugging.
+//!
+//! Some combinations of lengths resolve without any error, but produce
+//! layouts that are most likely not intended; like a [`Length::Fill`] child
+//! inside a [`Length::Shrink`] column, which collapses to nothing.
+//!
+//! In debug builds, these resolutions are logged as warnings, once per
+//! unique site until the next [`reset`]. In release builds, nothing is
+//! checked.
+//!
+//! [`Length::Fill`]: crate::Length::Fill
+//! [`Length::Shrink`]: crate::Length::Shrink
+use crate::layout::flex::Axis;
+use crate::layout::Limits;
+
+use rustc_hash::FxHashSet;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// The maximum amount of [`Diagnostic`] reports kept until they are taken.
+const MAX_DIAGNOSTICS: usize = 100;
+
+thread_local! {
+    static PATH: RefCell<Vec<(Axis, usize)>> = const {
+        RefCell::new(Vec::new())
+    };
+
+    static REPORTED: RefCell<FxHashSet<(String, &'static str)>> =
+        RefCell::new(FxHashSet::default());
+
+    static DIAGNOSTICS: RefCell<VecDeque<Diagnostic>> = const {
+        RefCell::new(VecDeque::new())
+    };
+}
+
+/// A degenerate layout resolution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// The path of the widget in the layout; like `column[2] > row[0]`.
+    ///
+    /// Every segment is the index of an item in a flex layout.
+    pub path: String,
+
+    /// The kind of [`Diagnostic`].
+    pub kind: Kind,
+}
+
+/// The kind of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    /// An item fills the main axis of a flex layout that shrinks; so there is
+    /// no space to fill and the item collapses.
+    FillInShrink {
+        /// The main axis of the flex layout.
+        axis: Axis,
+        /// The [`Limits`] given to the item.
+        limits: Limits,
+    },
+
+    /// A fill length was resolved against an infinite maximum.
+    InfiniteFill {
+        /// The [`Limits`] the length was resolved against.
+        limits: Limits,
+    },
+
+    /// An item with contents received no space in the main axis of a flex
+    /// layout.
+    Collapsed {
+        /// The main axis of the flex layout.
+        axis: Axis,
+        /// The [`Limits`] given to the item.
+        limits: Limits,
+    },
+}
+
+impl Kind {
+    fn name(&self) -> &'static str {
+        match self {
+            Kind::FillInShrink { .. } => "fill in shrink",
+            Kind::InfiniteFill { .. } => "infinite fill",
+            Kind::Collapsed { .. } => "collapsed",
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            Kind::FillInShrink { axis, limits } => write!(
+                f,
+                "{path}: fills the {axis} axis of a flex layout that shrinks \
+                 and collapses (limits: {limits:?})",
+                path = self.path,
+                axis = name(axis),
+            ),
+            Kind::InfiniteFill { limits } => write!(
+                f,
+                "{path}: fills an infinite space (limits: {limits:?})",
+                path = self.path,
+            ),
+            Kind::Collapsed { axis, limits } => write!(
+                f,
+                "{path}: has contents but no space in the {axis} axis \
+                 (limits: {limits:?})",
+                path = self.path,
+                axis = name(axis),
+            ),
+        }
+    }
+}
+
+/// Returns the [`Diagnostic`] reports of the current thread since the last
+/// call, in order.
+///
+/// Every site is reported only once until the next [`reset`]; even if it
+/// is laid out again. Only the latest reports are kept.
+pub fn take() -> Vec<Diagnostic> {
+    DIAGNOSTICS.with(|diagnostics| {
+        Vec::from(std::mem::take(&mut *diagnostics.borrow_mut()))
+    })
+}
+
+/// Forgets the sites reported in the current thread; so they are reported
+/// again the next time they are laid out.
+///
+/// It is called every time a user interface is built.
+pub fn reset() {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    REPORTED.with(|reported| reported.borrow_mut().clear());
+}
+
+/// Runs the given closure while laying out the item with the given index
+/// of a flex layout.
+pub(crate) fn item<T>(axis: Axis, index: usize, f: impl FnOnce() -> T) -> T {
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let _ = PATH.with(|path| path.borrow_mut().pop());
+        }
+    }
+
+    if !cfg!(debug_assertions) {
+        return f();
+    }
+
+    PATH.with(|path| path.borrow_mut().push((axis, index)));
+
+    let _guard = Guard;
+
+    f()
+}
+
+/// Reports a [`Diagnostic`] of the given [`Kind`] at the current path,
+/// unless it was already reported.
+pub(crate) fn report(kind: Kind) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    let path = PATH.with(|path| {
+        let path = path.borrow();
+
+        if path.is_empty() {
+            return String::from("root");
+        }
+
+        path.iter()
+            .map(|(axis, index)| format!("{}[{index}]", flex(axis)))
+            .collect::<Vec<_>>()
+            .join(" > ")
+    });
+
+    let is_new = REPORTED.with(|reported| {
+        reported.borrow_mut().insert((path.clone(), kind.name()))
+    });
+
+    if !is_new {
+        return;
+    }
+
+    let diagnostic = Diagnostic { path, kind };
+
+    log::warn!("Degenerate layout at {diagnostic}");
+
+    DIAGNOSTICS.with(|diagnostics| {
+        let mut diagnostics = diagnostics.borrow_mut();
+
+        if diagnostics.len() == MAX_DIAGNOSTICS {
+            let _ = diagnostics.pop_front();
+        }
+
+        diagnostics.push_back(diagnostic);
+    });
+}
+
+fn flex(axis: &Axis) -> &'static str {
+    match axis {
+        Axis::Horizontal => "row",
+        Axis::Vertical => "column",
+    }
+}
+
+fn name(axis: &Axis) -> &'static str {
+    match axis {
+        Axis::Horizontal => "horizontal",
+        Axis::Vertical => "vertical",
+    }
+}
@@ -18,12 +18,13 @@
 // limitations under the License.
 use crate::Element;
 
+use crate::layout::diagnostics::{self, Kind};
 use crate::layout::{Limits, Node};
 use crate::widget;
 use crate::{Alignment, Length, Padding, Point, Size};
 
 /// The main axis of a flex layout.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Axis {
     /// The horizontal axis
     Horizontal,
@@ -78,7 +79,6 @@ where
     let total_spacing = spacing * items.len().saturating_sub(1) as f32;
     let max_cross = axis.cross(limits.max());
 
-    let mut fill_main_sum = 0;
     let mut cross = match axis {
         Axis::Vertical if width == Length::Shrink => 0.0,
         Axis::Horizontal if height == Length::Shrink => 0.0,
@@ -110,30 +110,53 @@ where
             let child_limits =
                 Limits::new(Size::ZERO, Size::new(max_width, max_height));
 
-            let layout =
-                child.as_widget().layout(tree, renderer, &child_limits);
+            let layout = diagnostics::item(axis, i, || {
+                let layout =
+                    child.as_widget().layout(tree, renderer, &child_limits);
+
+                if available <= 0.0 && collapses(axis, &layout, tree) {
+                    diagnostics::report(Kind::Collapsed {
+                        axis,
+                        limits: child_limits,
+                    });
+                }
+
+                layout
+            });
+
             let size = layout.size();
 
             available -= axis.main(size);
             cross = cross.max(axis.cross(size));
 
             nodes[i] = layout;
-        } else {
-            fill_main_sum += fill_main_factor;
         }
     }
 
-    let remaining = match axis {
-        Axis::Horizontal => match width {
-            Length::Shrink => 0.0,
-            _ => available.max(0.0),
-        },
-        Axis::Vertical => match height {
-            Length::Shrink => 0.0,
-            _ => available.max(0.0),
-        },
+    let (main_length, _) = axis.pack(width, height);
+
+    let remaining = match main_length {
+        Length::Shrink => 0.0,
+        _ => available.max(0.0),
     };
 
+    let fills: Vec<Fill> = items
+        .iter()
+        .map(|child| {
+            let size = child.as_widget().size();
+            let (main, _) = axis.pack(size.width, size.height);
+            let (min, max) = main.fill_range();
+
+            Fill {
+                factor: main.fill_factor(),
+                min,
+                max,
+            }
+        })
+        .collect();
+
+    let shares = distribute(remaining, &fills);
+
     for (i, (child, tree)) in items.iter().zip(trees).enumerate() {
         let (fill_main_factor, fill_cross_factor) = {
             let size = child.as_widget().size();
@@ -142,8 +165,7 @@ where
         };
 
         if fill_main_factor != 0 {
-            let max_main =
-                remaining * fill_main_factor as f32 / fill_main_sum as f32;
+            let max_main = shares[i];
 
             let min_main = if max_main.is_infinite() {
                 0.0
@@ -166,8 +188,30 @@ where
                 Size::new(max_width, max_height),
             );
 
-            let layout =
-                child.as_widget().layout(tree, renderer, &child_limits);
+            let layout = diagnostics::item(axis, i, || {
+                if main_length == Length::Shrink {
+                    diagnostics::report(Kind::FillInShrink {
+                        axis,
+                        limits: child_limits,
+                    });
+                }
+
+                let layout =
+                    child.as_widget().layout(tree, renderer, &child_limits);
+
+                if main_length != Length::Shrink
+                    && max_main == 0.0
+                    && collapses(axis, &layout, tree)
+                {
+                    diagnostics::report(Kind::Collapsed {
+                        axis,
+                        limits: child_limits,
+                    });
+                }
+
+                layout
+            });
+
             cross = cross.max(axis.cross(layout.size()));
 
             nodes[i] = layout;
@@ -177,6 +221,21 @@ where
     let pad = axis.pack(padding.left, padding.top);
     let mut main = pad.0;
 
+    // Lining up baselines may push some items down, growing the cross axis
+    let ascent = match (&axis, align_items) {
+        (Axis::Horizontal, Alignment::Baseline) => {
+            let ascent = nodes.iter().map(baseline).fold(0.0, f32::max);
+
+            cross = nodes
+                .iter()
+                .map(|node| ascent - baseline(node) + node.size().height)
+                .fold(cross, f32::max);
+
+            Some(ascent)
+        }
+        _ => None,
+    };
+
     for (i, node) in nodes.iter_mut().enumerate() {
         if i > 0 {
             main += spacing;
@@ -188,11 +247,17 @@ where
 
         match axis {
             Axis::Horizontal => {
-                node.align_mut(
-                    Alignment::Start,
-                    align_items,
-                    Size::new(0.0, cross),
-                );
+                if let Some(ascent) = ascent {
+                    let offset = ascent - baseline(node);
+
+                    node.move_to_mut(Point::new(x, y + offset));
+                } else {
+                    node.align_mut(
+                        Alignment::Start,
+                        align_items,
+                        Size::new(0.0, cross),
+                    );
+                }
             }
             Axis::Vertical => {
                 node.align_mut(
@@ -215,5 +280,266 @@ where
         Size::new(intrinsic_width, intrinsic_height),
     );
 
-    Node::with_children(size.expand(padding), nodes)
+    let baseline = nodes.iter().find_map(Node::parent_baseline);
+
+    Node::with_children(size.expand(padding), nodes).with_baseline(baseline)
+}
+
+/// The fill requirements of an item in a flex layout.
+#[derive(Debug, Clone, Copy)]
+struct Fill {
+    factor: u16,
+    min: f32,
+    max: f32,
+}
+
+/// Distributes the remaining space of a flex layout among the items that
+/// fill, proportionally to their fill factors and honoring their limits.
+///
+/// Any item whose share falls outside its limits is clamped and frozen, and
+/// the rest of the space is distributed again among the other items. Since
+/// every pass freezes at least one item, this takes a bounded amount of
+/// passes.
+///
+/// Minimums always win; so the resulting shares may add up to more than
+/// the remaining space.
+fn distribute(remaining: f32, items: &[Fill]) -> Vec<f32> {
+    let mut shares = vec![0.0; items.len()];
+    let mut frozen: Vec<bool> =
+        items.iter().map(|item| item.factor == 0).collect();
+    let mut frozen_space = 0.0;
+
+    for _ in 0..=items.len() {
+        let free = remaining - frozen_space;
+        let factors: u32 = items
+            .iter()
+            .zip(&frozen)
+            .filter(|(_, frozen)| !**frozen)
+            .map(|(item, _)| u32::from(item.factor))
+            .sum();
+
+        if factors == 0 {
+            break;
+        }
+
+        let mut violation = 0.0;
+
+        for (i, item) in items.iter().enumerate() {
+            if frozen[i] {
+                continue;
+            }
+
+            let target = free * f32::from(item.factor) / factors as f32;
+            let clamped = target.min(item.max).max(item.min);
+
+            if clamped != target {
+                violation += clamped - target;
+            }
+
+            shares[i] = clamped;
+        }
+
+        if violation == 0.0 {
+            break;
+        }
+
+        // Freeze the items violating the limits that matter the most, like
+        // the CSS flexible box layout does
+        for (i, item) in items.iter().enumerate() {
+            if frozen[i] {
+                continue;
+            }
+
+            let target = free * f32::from(item.factor) / factors as f32;
+
+            let is_violating = if violation > 0.0 {
+                shares[i] > target
+            } else {
+                shares[i] < target
+            };
+
+            if is_violating {
+                frozen[i] = true;
+                frozen_space += shares[i];
+            }
+        }
+    }
+
+    shares
+}
+
+/// Returns true if the [`Node`] of an item has no space in the main axis,
+/// even though the item has contents.
+fn collapses(axis: Axis, node: &Node, tree: &widget::Tree) -> bool {
+    axis.main(node.size()) == 0.0
+        && (!node.children().is_empty() || !tree.children.is_empty())
+}
+
+/// Returns the baseline of a [`Node`] for the purposes of alignment; which
+/// is its bottom if it has no text.
+fn baseline(node: &Node) -> f32 {
+    node.baseline().unwrap_or(node.size().height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::layout::{self, Layout};
+    use crate::mouse;
+    use crate::renderer;
+    use crate::widget::{Tree, Widget};
+    use crate::{Pixels, Rectangle};
+
+    struct Block {
+        width: Length,
+    }
+
+    impl Widget<(), (), ()> for Block {
+        fn size(&self) -> Size<Length> {
+            Size::new(self.width, Length::Fixed(10.0))
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &Limits,
+        ) -> Node {
+            layout::atomic(limits, self.width, Length::Fixed(10.0))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn limited(min: Option<f32>, max: Option<f32>) -> Length {
+        Length::FillLimited {
+            portion: 1,
+            min: min.map(Pixels),
+            max: max.map(Pixels),
+        }
+    }
+
+    fn row(width: f32, lengths: &[Length]) -> Vec<f32> {
+        let items: Vec<Element<'_, (), (), ()>> = lengths
+            .iter()
+            .map(|&width| Element::new(Block { width }))
+            .collect();
+
+        let mut trees: Vec<Tree> =
+            items.iter().map(|_| Tree::empty()).collect();
+
+        let node = resolve(
+            Axis::Horizontal,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(width, 100.0)),
+            Length::Fill,
+            Length::Shrink,
+            Padding::ZERO,
+            0.0,
+            Alignment::Start,
+            &items,
+            &mut trees,
+        );
+
+        node.children()
+            .iter()
+            .map(|child| child.size().width)
+            .collect()
+    }
+
+    #[test]
+    fn clamped_space_is_redistributed() {
+        assert_eq!(
+            row(900.0, &[limited(None, Some(100.0)), Length::Fill]),
+            [100.0, 800.0]
+        );
+
+        assert_eq!(
+            row(900.0, &[limited(Some(600.0), None), Length::Fill]),
+            [600.0, 300.0]
+        );
+    }
+
+    #[test]
+    fn multiple_clamped_children_compete_for_space() {
+        assert_eq!(
+            row(
+                900.0,
+                &[
+                    limited(None, Some(100.0)),
+                    limited(None, Some(200.0)),
+                    Length::FillPortion(2),
+                    Length::Fill,
+                ]
+            ),
+            [100.0, 200.0, 400.0, 200.0]
+        );
+
+        // Once the first child is frozen at its maximum, there is enough
+        // space left for the second one to reach its minimum
+        assert_eq!(
+            row(
+                600.0,
+                &[
+                    limited(None, Some(100.0)),
+                    limited(Some(250.0), None),
+                    limited(None, Some(400.0)),
+                ]
+            ),
+            [100.0, 250.0, 250.0]
+        );
+    }
+
+    #[test]
+    fn fixed_children_are_left_alone() {
+        assert_eq!(
+            row(500.0, &[Length::Fixed(200.0), limited(None, Some(100.0))]),
+            [200.0, 100.0]
+        );
+    }
+
+    #[test]
+    fn minimums_win_when_space_is_not_enough() {
+        assert_eq!(
+            row(
+                300.0,
+                &[
+                    limited(Some(200.0), None),
+                    limited(Some(200.0), None),
+                    Length::Fill,
+                ]
+            ),
+            [200.0, 200.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn distribution_is_bounded_when_nothing_can_fill() {
+        let fills = [
+            Fill {
+                factor: 1,
+                min: 10.0,
+                max: 10.0,
+            },
+            Fill {
+                factor: 0,
+                min: 0.0,
+                max: f32::INFINITY,
+            },
+        ];
+
+        assert_eq!(distribute(0.0, &fills), [10.0, 0.0]);
+        assert_eq!(distribute(f32::INFINITY, &fills), [10.0, 0.0]);
+    }
 }
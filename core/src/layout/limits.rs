@@ -1,4 +1,5 @@
 #![allow(clippy::manual_clamp)]
+use crate::layout::diagnostics::{self, Kind};
 use crate::{Length, Size};
 
 /// A set of size constraints for layouting.
@@ -34,6 +35,11 @@ impl Limits {
     pub fn width(mut self, width: impl Into<Length>) -> Limits {
         match width.into() {
             Length::Shrink | Length::Fill | Length::FillPortion(_) => {}
+            length @ Length::FillLimited { .. } => {
+                let (min, max) = length.fill_range();
+
+                self = self.max_width(max).min_width(min);
+            }
             Length::Fixed(amount) => {
                 let new_width = amount.min(self.max.width).max(self.min.width);
 
@@ -49,6 +55,11 @@ impl Limits {
     pub fn height(mut self, height: impl Into<Length>) -> Limits {
         match height.into() {
             Length::Shrink | Length::Fill | Length::FillPortion(_) => {}
+            length @ Length::FillLimited { .. } => {
+                let (min, max) = length.fill_range();
+
+                self = self.max_height(max).min_height(min);
+            }
             Length::Fixed(amount) => {
                 let new_height =
                     amount.min(self.max.height).max(self.min.height);
@@ -89,6 +100,18 @@ impl Limits {
         self
     }
 
+    /// Applies the minimum and maximum [`Size`] of a widget to the current
+    /// [`Limits`].
+    ///
+    /// The minimum wins over the maximum when they conflict. Both are still
+    /// bounded by the current [`Limits`].
+    pub fn constrain(self, min: Size, max: Size) -> Limits {
+        self.min_width(min.width)
+            .max_width(max.width.max(min.width))
+            .min_height(min.height)
+            .max_height(max.height.max(min.height))
+    }
+
     /// Shrinks the current [`Limits`] by the given [`Size`].
     pub fn shrink(&self, size: impl Into<Size>) -> Limits {
         let size = size.into();
@@ -123,8 +146,19 @@ impl Limits {
         height: impl Into<Length>,
         intrinsic_size: Size,
     ) -> Size {
-        let width = match width.into() {
-            Length::Fill | Length::FillPortion(_) => self.max.width,
+        let width = width.into();
+        let height = height.into();
+
+        if (width.fill_factor() != 0 && self.max.width.is_infinite())
+            || (height.fill_factor() != 0 && self.max.height.is_infinite())
+        {
+            diagnostics::report(Kind::InfiniteFill { limits: *self });
+        }
+
+        let width = match width {
+            Length::Fill
+            | Length::FillPortion(_)
+            | Length::FillLimited { .. } => self.max.width,
             Length::Fixed(amount) => {
                 amount.min(self.max.width).max(self.min.width)
             }
@@ -133,8 +167,10 @@ impl Limits {
             }
         };
 
-        let height = match height.into() {
-            Length::Fill | Length::FillPortion(_) => self.max.height,
+        let height = match height {
+            Length::Fill
+            | Length::FillPortion(_)
+            | Length::FillLimited { .. } => self.max.height,
             Length::Fixed(amount) => {
                 amount.min(self.max.height).max(self.min.height)
             }
@@ -5,6 +5,7 @@ use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    baseline: Option<f32>,
 }
 
 impl Node {
@@ -23,15 +24,26 @@ impl Node {
                 height: size.height,
             },
             children,
+            baseline: None,
         }
     }
 
     /// Creates a new [`Node`] that wraps a single child with some [`Padding`].
+    ///
+    /// The [`Node`] inherits the baseline of its child.
     pub fn container(child: Self, padding: Padding) -> Self {
-        Self::with_children(
-            child.bounds.size().expand(padding),
-            vec![child.move_to(Point::new(padding.left, padding.top))],
-        )
+        let child = child.move_to(Point::new(padding.left, padding.top));
+        let baseline = child.parent_baseline();
+
+        Self::with_children(child.bounds.size().expand(padding), vec![child])
+            .with_baseline(baseline)
+    }
+
+    /// Sets the baseline of the [`Node`]; that is, the distance from its
+    /// top to the baseline of the first line of text it contains, if any.
+    pub fn with_baseline(mut self, baseline: Option<f32>) -> Self {
+        self.baseline = baseline;
+        self
     }
 
     /// Returns the [`Size`] of the [`Node`].
@@ -44,12 +56,26 @@ impl Node {
         self.bounds
     }
 
+    /// Returns the baseline of the [`Node`], relative to its top.
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
+    /// Returns the baseline of the [`Node`] relative to the top of its
+    /// parent, assuming it is already positioned.
+    pub fn parent_baseline(&self) -> Option<f32> {
+        self.baseline.map(|baseline| self.bounds.y + baseline)
+    }
+
     /// Returns the children of the [`Node`].
     pub fn children(&self) -> &[Node] {
         &self.children
     }
 
     /// Aligns the [`Node`] in the given space.
+    ///
+    /// A [`Node`] knows nothing about the baselines of its siblings; so
+    /// [`Alignment::Baseline`] aligns it like [`Alignment::Start`].
     pub fn align(
         mut self,
         horizontal_alignment: Alignment,
@@ -68,7 +94,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -78,7 +104,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
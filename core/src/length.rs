@@ -15,6 +15,21 @@ pub enum Length {
     /// `Length::Fill` is equivalent to `Length::FillPortion(1)`.
     FillPortion(u16),
 
+    /// Fill a portion of the remaining space, like [`Length::FillPortion`],
+    /// but never less than `min` nor more than `max`.
+    ///
+    /// In a flex layout, like a `Row` or a `Column`, any space a clamped
+    /// element cannot take is redistributed among the other elements that
+    /// fill.
+    FillLimited {
+        /// The portion of the remaining space to fill.
+        portion: u16,
+        /// The minimum amount of space to fill, if any.
+        min: Option<Pixels>,
+        /// The maximum amount of space to fill, if any.
+        max: Option<Pixels>,
+    },
+
     /// Fill the least amount of space
     Shrink,
 
@@ -32,13 +47,14 @@ impl Length {
         match self {
             Length::Fill => 1,
             Length::FillPortion(factor) => *factor,
+            Length::FillLimited { portion, .. } => *portion,
             Length::Shrink => 0,
             Length::Fixed(_) => 0,
         }
     }
 
-    /// Returns `true` iff the [`Length`] is either [`Length::Fill`] or
-    // [`Length::FillPortion`].
+    /// Returns `true` iff the [`Length`] is either [`Length::Fill`],
+    /// [`Length::FillPortion`], or [`Length::FillLimited`].
     pub fn is_fill(&self) -> bool {
         self.fill_factor() != 0
     }
@@ -50,16 +66,35 @@ impl Length {
     /// - [`Length::Fill`] otherwise.
     pub fn fluid(&self) -> Self {
         match self {
-            Length::Fill | Length::FillPortion(_) => Length::Fill,
+            Length::Fill
+            | Length::FillPortion(_)
+            | Length::FillLimited { .. } => Length::Fill,
             Length::Shrink | Length::Fixed(_) => Length::Shrink,
         }
     }
 
+    /// Returns the minimum and maximum amount of space the [`Length`] may
+    /// fill; which only constrains a [`Length::FillLimited`].
+    pub fn fill_range(&self) -> (f32, f32) {
+        match self {
+            Length::FillLimited { min, max, .. } => (
+                min.map_or(0.0, f32::from),
+                max.map_or(f32::INFINITY, f32::from),
+            ),
+            _ => (0.0, f32::INFINITY),
+        }
+    }
+
     /// Adapts the [`Length`] so it can contain the other [`Length`] and
     /// match its fluidity.
     pub fn enclose(self, other: Length) -> Self {
         match (self, other) {
-            (Length::Shrink, Length::Fill | Length::FillPortion(_)) => other,
+            (
+                Length::Shrink,
+                Length::Fill
+                | Length::FillPortion(_)
+                | Length::FillLimited { .. },
+            ) => other,
             _ => self,
         }
     }
@@ -12,16 +12,19 @@
 pub mod alignment;
 pub mod border;
 pub mod clipboard;
+pub mod drag;
 pub mod event;
 pub mod font;
 pub mod gradient;
 pub mod image;
+pub mod input_method;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
 pub mod overlay;
 pub mod padding;
 pub mod renderer;
+pub mod settings;
 pub mod svg;
 pub mod text;
 pub mod theme;
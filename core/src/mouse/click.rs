@@ -1,4 +1,5 @@
 //! Track mouse clicks.
+use crate::settings::Interaction;
 use crate::time::Instant;
 use crate::Point;
 
@@ -36,6 +37,9 @@ impl Kind {
 impl Click {
     /// Creates a new [`Click`] with the given position and previous last
     /// [`Click`].
+    ///
+    /// Consecutive clicks must happen within the [`Interaction::double_click`]
+    /// time of the current thread.
     pub fn new(position: Point, previous: Option<Click>) -> Click {
         let time = Instant::now();
 
@@ -67,15 +71,45 @@ impl Click {
     }
 
     fn is_consecutive(&self, new_position: Point, time: Instant) -> bool {
-        let duration = if time > self.time {
-            Some(time - self.time)
-        } else {
-            None
-        };
-
         self.position == new_position
-            && duration
-                .map(|duration| duration.as_millis() <= 300)
-                .unwrap_or(false)
+            && time
+                .checked_duration_since(self.time)
+                .is_some_and(|duration| {
+                    duration < Interaction::current().double_click
+                })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::Duration;
+
+    #[test]
+    fn clicks_are_consecutive_within_the_double_click_time() {
+        let position = Point::new(10.0, 10.0);
+
+        Interaction {
+            double_click: Duration::from_secs(60),
+            ..Interaction::default()
+        }
+        .apply();
+
+        let first = Click::new(position, None);
+        let second = Click::new(position, Some(first));
+
+        assert!(matches!(second.kind(), Kind::Double));
+
+        Interaction {
+            double_click: Duration::ZERO,
+            ..Interaction::default()
+        }
+        .apply();
+
+        let third = Click::new(position, Some(second));
+
+        assert!(matches!(third.kind(), Kind::Single));
+
+        Interaction::default().apply();
     }
 }
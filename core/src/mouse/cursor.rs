@@ -1,4 +1,4 @@
-use crate::{Point, Rectangle, Vector};
+use crate::{Point, Rectangle, Transformation, Vector};
 
 /// The mouse cursor state.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -6,6 +6,19 @@ pub enum Cursor {
     /// The cursor has a defined position.
     Available(Point),
 
+    /// The cursor has a defined position in the local coordinates of a
+    /// transformed widget.
+    ///
+    /// Widgets applying a [`Transformation`] to their contents produce this
+    /// variant by mapping the [`Cursor`]; see [`Cursor::window_position`].
+    Transformed {
+        /// The position of the cursor in local coordinates.
+        position: Point,
+
+        /// The position of the cursor in window coordinates.
+        window: Point,
+    },
+
     /// The cursor is currently unavailable (i.e. out of bounds or busy).
     #[default]
     Unavailable,
@@ -13,9 +26,29 @@ pub enum Cursor {
 
 impl Cursor {
     /// Returns the absolute position of the [`Cursor`], if available.
+    ///
+    /// The position is in the local coordinates of the widget receiving the
+    /// [`Cursor`]; which are only different from window coordinates inside of
+    /// a transformation.
     pub fn position(self) -> Option<Point> {
         match self {
-            Cursor::Available(position) => Some(position),
+            Cursor::Available(position)
+            | Cursor::Transformed { position, .. } => Some(position),
+            Cursor::Unavailable => None,
+        }
+    }
+
+    /// Returns the position of the [`Cursor`] in window coordinates, if
+    /// available.
+    ///
+    /// Unlike [`Cursor::position`], this position is not affected by the
+    /// transformations applied to the widget receiving the [`Cursor`].
+    pub fn window_position(self) -> Option<Point> {
+        match self {
+            Cursor::Available(position)
+            | Cursor::Transformed {
+                window: position, ..
+            } => Some(position),
             Cursor::Unavailable => None,
         }
     }
@@ -50,3 +83,58 @@ impl Cursor {
         self.position_over(bounds).is_some()
     }
 }
+
+impl std::ops::Mul<Transformation> for Cursor {
+    type Output = Self;
+
+    fn mul(self, transformation: Transformation) -> Self {
+        match self {
+            Cursor::Available(position) => Cursor::Transformed {
+                position: position * transformation,
+                window: position,
+            },
+            Cursor::Transformed { position, window } => Cursor::Transformed {
+                position: position * transformation,
+                window,
+            },
+            Cursor::Unavailable => Cursor::Unavailable,
+        }
+    }
+}
+
+impl std::ops::Add<Vector> for Cursor {
+    type Output = Self;
+
+    fn add(self, translation: Vector) -> Self {
+        self * Transformation::translate(translation.x, translation.y)
+    }
+}
+
+impl std::ops::Sub<Vector> for Cursor {
+    type Output = Self;
+
+    fn sub(self, translation: Vector) -> Self {
+        self + Vector::new(-translation.x, -translation.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transformations_keep_the_window_position() {
+        let cursor = Cursor::Available(Point::new(10.0, 20.0));
+
+        let scaled = cursor * Transformation::scale(0.5);
+        let translated = scaled + Vector::new(5.0, 5.0);
+
+        assert_eq!(translated.position(), Some(Point::new(10.0, 15.0)));
+        assert_eq!(translated.window_position(), Some(Point::new(10.0, 20.0)));
+
+        assert_eq!(
+            Cursor::Unavailable * Transformation::scale(2.0),
+            Cursor::Unavailable
+        );
+    }
+}
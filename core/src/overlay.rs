@@ -103,6 +103,31 @@ where
     ) -> Option<Element<'a, Message, Theme, Renderer>> {
         None
     }
+
+    /// Returns the top-most overlays of the [`Overlay`], if there are any.
+    ///
+    /// Overlays that contain other overlay [`Element`]s should return their
+    /// top-most overlays here; see [`Element::top_most`].
+    ///
+    /// By default, it returns `None`; and any top-most overlays inside are
+    /// displayed along with the rest of the [`Overlay`].
+    fn top_most_overlay(
+        &mut self,
+    ) -> Option<Element<'_, Message, Theme, Renderer>> {
+        None
+    }
+}
+
+/// What an overlay does when its anchor moves; usually, because one of its
+/// ancestors was scrolled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollPolicy {
+    /// The overlay follows the new position of its anchor, and it closes
+    /// once the anchor is scrolled out of view.
+    Reposition,
+
+    /// The overlay closes as soon as its anchor moves.
+    Close,
 }
 
 /// Returns a [`Group`] of overlay [`Element`] children.
@@ -114,6 +139,7 @@ pub fn from_children<'a, Message, Theme, Renderer>(
     tree: &'a mut Tree,
     layout: Layout<'_>,
     renderer: &Renderer,
+    viewport: &Rectangle,
     translation: Vector,
 ) -> Option<Element<'a, Message, Theme, Renderer>>
 where
@@ -124,9 +150,13 @@ where
         .zip(&mut tree.children)
         .zip(layout.children())
         .filter_map(|((child, state), layout)| {
-            child
-                .as_widget_mut()
-                .overlay(state, layout, renderer, translation)
+            child.as_widget_mut().overlay(
+                state,
+                layout,
+                renderer,
+                viewport,
+                translation,
+            )
         })
         .collect::<Vec<_>>();
 
@@ -11,6 +11,8 @@ use crate::{Clipboard, Layout, Point, Rectangle, Shell, Size};
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Theme, Renderer> {
     overlay: Box<dyn Overlay<Message, Theme, Renderer> + 'a>,
+    is_top_most: bool,
+    is_deferred: bool,
 }
 
 impl<'a, Message, Theme, Renderer> Element<'a, Message, Theme, Renderer>
@@ -21,7 +23,24 @@ where
     pub fn new(
         overlay: Box<dyn Overlay<Message, Theme, Renderer> + 'a>,
     ) -> Self {
-        Self { overlay }
+        Self {
+            overlay,
+            is_top_most: false,
+            is_deferred: false,
+        }
+    }
+
+    /// Marks the [`Element`] as top-most.
+    ///
+    /// Top-most overlays are drawn above every other overlay, even the ones
+    /// nested deeper or opened later; and they receive events before them.
+    /// This is useful for content that must never be covered, like toasts or
+    /// the image of a drag.
+    ///
+    /// The nested overlays of a top-most [`Element`] are not displayed.
+    pub fn top_most(mut self) -> Self {
+        self.is_top_most = true;
+        self
     }
 
     /// Applies a transformation to the produced message of the [`Element`].
@@ -37,6 +56,8 @@ where
     {
         Element {
             overlay: Box::new(Map::new(self.overlay, f)),
+            is_top_most: self.is_top_most,
+            is_deferred: self.is_deferred,
         }
     }
 
@@ -46,6 +67,10 @@ where
         renderer: &Renderer,
         bounds: Size,
     ) -> layout::Node {
+        if self.is_deferred {
+            return layout::Node::new(Size::ZERO);
+        }
+
         self.overlay.layout(renderer, bounds)
     }
 
@@ -59,6 +84,10 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if self.is_deferred {
+            return event::Status::Ignored;
+        }
+
         self.overlay
             .on_event(event, layout, cursor, renderer, clipboard, shell)
     }
@@ -71,6 +100,10 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.is_deferred {
+            return mouse::Interaction::None;
+        }
+
         self.overlay
             .mouse_interaction(layout, cursor, viewport, renderer)
     }
@@ -84,6 +117,10 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
     ) {
+        if self.is_deferred {
+            return;
+        }
+
         self.overlay.draw(renderer, theme, style, layout, cursor);
     }
 
@@ -94,6 +131,10 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation<()>,
     ) {
+        if self.is_deferred {
+            return;
+        }
+
         self.overlay.operate(layout, renderer, operation);
     }
 
@@ -104,7 +145,8 @@ where
         renderer: &Renderer,
         cursor_position: Point,
     ) -> bool {
-        self.overlay.is_over(layout, renderer, cursor_position)
+        !self.is_deferred
+            && self.overlay.is_over(layout, renderer, cursor_position)
     }
 
     /// Returns the nested overlay of the [`Element`], if there is any.
@@ -113,8 +155,98 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<Element<'b, Message, Theme, Renderer>> {
+        if self.is_deferred {
+            return None;
+        }
+
         self.overlay.overlay(layout, renderer)
     }
+
+    /// Returns the top-most overlays of the [`Element`], if there are any.
+    ///
+    /// Once returned, the top-most overlays are skipped by the rest of the
+    /// methods of the [`Element`]; since the caller is expected to display
+    /// them on their own, on top of everything else.
+    pub fn top_most_overlay<'b>(
+        &'b mut self,
+    ) -> Option<Element<'b, Message, Theme, Renderer>> {
+        if self.is_top_most {
+            self.is_deferred = true;
+
+            return Some(Element::new(Box::new(TopMost {
+                overlay: self.overlay.as_mut(),
+            })));
+        }
+
+        self.overlay.top_most_overlay()
+    }
+}
+
+struct TopMost<'a, 'b, Message, Theme, Renderer> {
+    overlay: &'b mut (dyn Overlay<Message, Theme, Renderer> + 'a),
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for TopMost<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.overlay.layout(renderer, bounds)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<()>,
+    ) {
+        self.overlay.operate(layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.overlay
+            .on_event(event, layout, cursor, renderer, clipboard, shell)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.overlay
+            .mouse_interaction(layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.overlay.draw(renderer, theme, style, layout, cursor);
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        self.overlay.is_over(layout, renderer, cursor_position)
+    }
 }
 
 struct Map<'a, A, B, Theme, Renderer> {
@@ -159,7 +291,8 @@ where
         shell: &mut Shell<'_, B>,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         let event_status = self.content.on_event(
             event,
@@ -215,4 +348,10 @@ where
             .overlay(layout, renderer)
             .map(|overlay| overlay.map(self.mapper))
     }
+
+    fn top_most_overlay(&mut self) -> Option<Element<'_, B, Theme, Renderer>> {
+        self.content
+            .top_most_overlay()
+            .map(|overlay| overlay.map(self.mapper))
+    }
 }
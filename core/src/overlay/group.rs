@@ -171,6 +171,18 @@ where
 
         (!children.is_empty()).then(|| Group::with_children(children).overlay())
     }
+
+    fn top_most_overlay(
+        &mut self,
+    ) -> Option<overlay::Element<'_, Message, Theme, Renderer>> {
+        let children = self
+            .children
+            .iter_mut()
+            .filter_map(overlay::Element::top_most_overlay)
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| Group::with_children(children).overlay())
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Group<'a, Message, Theme, Renderer>>
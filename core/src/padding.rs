@@ -1,4 +1,5 @@
 //! Space stuff around the perimeter.
+use crate::alignment::{self, Direction};
 use crate::{Pixels, Size};
 
 /// An amount of space to pad for each side of a box
@@ -128,6 +129,28 @@ impl Padding {
         }
     }
 
+    /// Returns the [`Padding`] at the start of the current [`Direction`];
+    /// the [`left`] one, unless the direction is right-to-left.
+    ///
+    /// [`left`]: Self::left
+    pub fn start(self) -> f32 {
+        match alignment::direction() {
+            Direction::LeftToRight => self.left,
+            Direction::RightToLeft => self.right,
+        }
+    }
+
+    /// Returns the [`Padding`] at the end of the current [`Direction`];
+    /// the [`right`] one, unless the direction is right-to-left.
+    ///
+    /// [`right`]: Self::right
+    pub fn end(self) -> f32 {
+        match alignment::direction() {
+            Direction::LeftToRight => self.right,
+            Direction::RightToLeft => self.left,
+        }
+    }
+
     /// Returns the total amount of vertical [`Padding`].
     pub fn vertical(self) -> f32 {
         self.top + self.bottom
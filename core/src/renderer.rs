@@ -2,10 +2,19 @@
 #[cfg(debug_assertions)]
 mod null;
 
+mod any;
+
+pub use any::AnyRenderer;
+
+use crate::image;
 use crate::{
     Background, Border, Color, Rectangle, Shadow, Size, Transformation, Vector,
 };
 
+use std::any::Any;
+use std::fmt;
+use std::rc::Rc;
+
 /// A component that can be used by widgets to draw themselves on a screen.
 pub trait Renderer {
     /// Starts recording a new layer.
@@ -56,6 +65,55 @@ pub trait Renderer {
         );
     }
 
+    /// Starts forcing the given [`image::FilterMethod`] on every image drawn
+    /// until [`end_filter_method`] is called.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`end_filter_method`]: Self::end_filter_method
+    fn start_filter_method(&mut self, _filter_method: image::FilterMethod) {}
+
+    /// Ends forcing the last [`image::FilterMethod`] started.
+    ///
+    /// By default, it does nothing.
+    fn end_filter_method(&mut self) {}
+
+    /// Forces the given [`image::FilterMethod`] on the images drawn in the
+    /// given closure.
+    fn with_filter_method(
+        &mut self,
+        filter_method: image::FilterMethod,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.start_filter_method(filter_method);
+        f(self);
+        self.end_filter_method();
+    }
+
+    /// Starts recording the primitives drawn until [`end_snapshot`] is
+    /// called into a [`Snapshot`], instead of drawing them.
+    ///
+    /// By default, it does nothing; and the primitives are drawn as usual.
+    ///
+    /// [`end_snapshot`]: Self::end_snapshot
+    fn start_snapshot(&mut self) {}
+
+    /// Ends recording the last [`Snapshot`] started.
+    ///
+    /// Returns `None` if the primitives could not be recorded; in which case
+    /// they have been drawn as usual.
+    ///
+    /// By default, it returns `None`.
+    fn end_snapshot(&mut self) -> Option<Snapshot> {
+        None
+    }
+
+    /// Draws the primitives recorded in the given [`Snapshot`], with the
+    /// alpha channel of their colors scaled by the given opacity.
+    ///
+    /// By default, it does nothing.
+    fn draw_snapshot(&mut self, _snapshot: &Snapshot, _opacity: f32) {}
+
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
@@ -100,3 +158,31 @@ impl Default for Style {
         }
     }
 }
+
+/// Some primitives recorded by a [`Renderer`], ready to be drawn again.
+///
+/// A [`Snapshot`] keeps the resources of its primitives alive; so it can be
+/// drawn even after the widget that recorded it is gone.
+///
+/// See [`Renderer::start_snapshot`].
+#[derive(Clone)]
+pub struct Snapshot(Rc<dyn Any>);
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`] holding the given primitives.
+    pub fn new<T: 'static>(primitives: T) -> Self {
+        Self(Rc::new(primitives))
+    }
+
+    /// Returns the primitives of the [`Snapshot`], if they are of the given
+    /// type.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Snapshot").finish_non_exhaustive()
+    }
+}
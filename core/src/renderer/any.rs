@@ -0,0 +1,105 @@
+use crate::renderer::{self, Quad};
+use crate::text::{self, Paragraph as _, Text};
+use crate::{
+    Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation,
+};
+
+/// An object-safe subset of the renderer traits.
+///
+/// Any renderer that implements [`Renderer`] and [`text::Renderer`] is also
+/// an [`AnyRenderer`]; which can be used as a trait object by widgets that
+/// need to be compiled without knowing the concrete renderer of an
+/// application, like an [`AnyWidget`] loaded from a dynamic library.
+///
+/// Only quads and plain text are supported. Images, vectors, geometry and
+/// custom shader primitives cannot cross the boundary.
+///
+/// Every call is dynamically dispatched, and [`measure_text`] shapes the
+/// text from scratch every time; so widgets drawn through an [`AnyRenderer`]
+/// will be slower than their non-dynamic counterparts.
+///
+/// [`Renderer`]: crate::Renderer
+/// [`AnyWidget`]: crate::widget::AnyWidget
+/// [`measure_text`]: Self::measure_text
+pub trait AnyRenderer {
+    /// Starts recording a new layer.
+    fn start_layer(&mut self, bounds: Rectangle);
+
+    /// Ends recording a new layer.
+    fn end_layer(&mut self);
+
+    /// Starts recording with a new [`Transformation`].
+    fn start_transformation(&mut self, transformation: Transformation);
+
+    /// Ends recording with the last [`Transformation`].
+    fn end_transformation(&mut self);
+
+    /// Fills a [`Quad`] with the provided [`Background`].
+    fn fill_quad(&mut self, quad: Quad, background: Background);
+
+    /// Draws the given [`Text`] at the given position and with the given
+    /// [`Color`].
+    fn fill_text(
+        &mut self,
+        text: Text<String, Font>,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    );
+
+    /// Returns the minimum [`Size`] needed to fit the given [`Text`].
+    fn measure_text(&self, text: Text<&str, Font>) -> Size;
+
+    /// Returns the default [`Font`] of the renderer.
+    fn default_font(&self) -> Font;
+
+    /// Returns the default size of [`Text`].
+    fn default_size(&self) -> Pixels;
+}
+
+impl<T> AnyRenderer for T
+where
+    T: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    fn start_layer(&mut self, bounds: Rectangle) {
+        renderer::Renderer::start_layer(self, bounds);
+    }
+
+    fn end_layer(&mut self) {
+        renderer::Renderer::end_layer(self);
+    }
+
+    fn start_transformation(&mut self, transformation: Transformation) {
+        renderer::Renderer::start_transformation(self, transformation);
+    }
+
+    fn end_transformation(&mut self) {
+        renderer::Renderer::end_transformation(self);
+    }
+
+    fn fill_quad(&mut self, quad: Quad, background: Background) {
+        renderer::Renderer::fill_quad(self, quad, background);
+    }
+
+    fn fill_text(
+        &mut self,
+        text: Text<String, Font>,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        text::Renderer::fill_text(self, text, position, color, clip_bounds);
+    }
+
+    fn measure_text(&self, text: Text<&str, Font>) -> Size {
+        T::Paragraph::with_text(text).min_bounds()
+    }
+
+    fn default_font(&self) -> Font {
+        text::Renderer::default_font(self)
+    }
+
+    fn default_size(&self) -> Pixels {
+        text::Renderer::default_size(self)
+    }
+}
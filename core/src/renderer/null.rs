@@ -64,7 +64,7 @@ impl text::Renderer for () {
 
     fn fill_text(
         &mut self,
-        _paragraph: Text,
+        _text: impl Into<text::Fill<'_, Self::Paragraph>>,
         _position: Point,
         _color: Color,
         _clip_bounds: Rectangle,
@@ -152,6 +152,7 @@ impl text::Editor for () {
         &mut self,
         _new_bounds: Size,
         _new_font: Self::Font,
+        _new_font_features: &'static [crate::font::Feature],
         _new_size: Pixels,
         _new_line_height: text::LineHeight,
         _new_highlighter: &mut impl text::Highlighter,
@@ -0,0 +1,119 @@
+//! Share the preferences of the user with every widget.
+use crate::time::Duration;
+
+use std::cell::Cell;
+use std::fmt;
+
+thread_local! {
+    static INTERACTION: Cell<Interaction> = const {
+        Cell::new(Interaction::DEFAULT)
+    };
+
+    static MOTION: Cell<Motion> = const { Cell::new(Motion::Full) };
+}
+
+/// The preferences of the user that determine how pointer input is
+/// interpreted; like the speed of a double click.
+///
+/// Shells query them from the platform on startup, unless an application
+/// overrides them. Widgets read the ones of the current thread with
+/// [`Interaction::current`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interaction {
+    /// The maximum time between two clicks for them to form a double click.
+    ///
+    /// By default, it is 300 milliseconds.
+    pub double_click: Duration,
+
+    /// The distance, in logical pixels, the cursor needs to travel while
+    /// pressed for a drag to start.
+    ///
+    /// By default, it is `5.0`.
+    pub drag_threshold: f32,
+}
+
+impl Interaction {
+    /// The default [`Interaction`] settings.
+    pub const DEFAULT: Self = Self {
+        double_click: Duration::from_millis(300),
+        drag_threshold: 5.0,
+    };
+
+    /// Returns the [`Interaction`] settings of the current thread.
+    pub fn current() -> Self {
+        INTERACTION.with(Cell::get)
+    }
+
+    /// Makes these the [`Interaction`] settings of the current thread; which
+    /// is where the widgets of an application are processed.
+    pub fn apply(self) {
+        INTERACTION.with(|interaction| interaction.set(self));
+    }
+}
+
+impl Default for Interaction {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How much widgets animate; following the "reduce motion" accessibility
+/// preference of the user.
+///
+/// Shells query the preference from the platform on startup, unless an
+/// application overrides it. Widgets read the [`Motion`] of the current
+/// thread with [`Motion::current`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Motion {
+    /// Every animation is shown.
+    #[default]
+    Full,
+
+    /// Animations that move content continuously, like spinning progress
+    /// indicators, are replaced by a subtle pulsing opacity; and content
+    /// does not slide into place.
+    ///
+    /// Brief transitions between values are kept.
+    Reduced,
+
+    /// Nothing is animated.
+    ///
+    /// Progress indicators are static, and values change right away.
+    None,
+}
+
+impl Motion {
+    /// A list with every [`Motion`] policy.
+    pub const ALL: &'static [Self] = &[Self::Full, Self::Reduced, Self::None];
+
+    /// Returns the [`Motion`] matching the given "reduce motion" preference
+    /// of a platform.
+    pub fn from_preference(prefers_reduced_motion: bool) -> Self {
+        if prefers_reduced_motion {
+            Self::Reduced
+        } else {
+            Self::Full
+        }
+    }
+
+    /// Returns the [`Motion`] of the current thread.
+    pub fn current() -> Self {
+        MOTION.with(Cell::get)
+    }
+
+    /// Makes this the [`Motion`] of the current thread; which is where the
+    /// widgets of an application are processed.
+    pub fn apply(self) {
+        MOTION.with(|motion| motion.set(self));
+    }
+}
+
+impl fmt::Display for Motion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Full => "Full",
+            Self::Reduced => "Reduced",
+            Self::None => "None",
+        })
+    }
+}
@@ -1,3 +1,5 @@
+use crate::drag;
+use crate::keyboard;
 use crate::window;
 
 /// A connection to the state of a shell.
@@ -10,6 +12,9 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    input_mode: Option<keyboard::InputMode>,
+    drag: Option<drag::Payload>,
+    is_drag_changed: bool,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -20,6 +25,9 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            input_mode: None,
+            drag: None,
+            is_drag_changed: false,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -53,6 +61,53 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the given [`keyboard::InputMode`] for the text typed by the
+    /// user; normally from the focused widget.
+    pub fn request_input_mode(&mut self, mode: keyboard::InputMode) {
+        self.input_mode = Some(mode);
+    }
+
+    /// Returns the requested [`keyboard::InputMode`], if any.
+    pub fn input_mode(&self) -> Option<keyboard::InputMode> {
+        self.input_mode
+    }
+
+    /// Sets the ongoing drag of the [`Shell`].
+    ///
+    /// A shell runtime uses this to keep the drag going across events.
+    /// Widgets creating a nested [`Shell`] should share the drag of their
+    /// own, so their contents can take part in it.
+    pub fn with_drag(mut self, drag: Option<drag::Payload>) -> Self {
+        self.drag = drag;
+        self
+    }
+
+    /// Returns the [`drag::Payload`] being dragged, if any.
+    pub fn drag(&self) -> Option<&drag::Payload> {
+        self.drag.as_ref()
+    }
+
+    /// Starts dragging the given [`drag::Payload`], replacing any ongoing
+    /// drag.
+    ///
+    /// The drag ends automatically when the left mouse button is released
+    /// or `Escape` is pressed.
+    pub fn start_drag(&mut self, payload: drag::Payload) {
+        self.drag = Some(payload);
+        self.is_drag_changed = true;
+    }
+
+    /// Ends the ongoing drag, if any.
+    pub fn end_drag(&mut self) {
+        self.drag = None;
+        self.is_drag_changed = true;
+    }
+
+    /// Returns whether a drag has been started or ended in the [`Shell`].
+    pub fn is_drag_changed(&self) -> bool {
+        self.is_drag_changed
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -99,6 +154,15 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        if let Some(mode) = other.input_mode {
+            self.request_input_mode(mode);
+        }
+
+        if other.is_drag_changed {
+            self.drag = other.drag;
+            self.is_drag_changed = true;
+        }
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
@@ -37,10 +37,38 @@ pub struct Text<Content = String, Font = crate::Font> {
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
 
+    /// The maximum factor the spaces of the [`Text`] can be stretched by
+    /// when justified.
+    ///
+    /// A paragraph with lines that would need to be stretched further is
+    /// aligned to the left instead.
+    pub max_space_factor: f32,
+
+    /// The OpenType features of the [`Text`].
+    ///
+    /// If not empty, they replace the default features of its font.
+    pub font_features: &'static [crate::font::Feature],
+
     /// The [`Shaping`] strategy of the [`Text`].
     pub shaping: Shaping,
 }
 
+impl<Content> Text<Content, crate::Font> {
+    /// Returns the font of the [`Text`] with its
+    /// [`font_features`](Self::font_features) applied.
+    pub fn font_with_features(&self) -> crate::Font {
+        if self.font_features.is_empty() {
+            self.font
+        } else {
+            self.font.features(self.font_features)
+        }
+    }
+}
+
+/// The default maximum factor the spaces of a justified [`Text`] can be
+/// stretched by.
+pub const DEFAULT_MAX_SPACE_FACTOR: f32 = 3.0;
+
 /// The shaping strategy of some text.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Shaping {
@@ -213,15 +241,49 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws the given [`Text`] at the given position and with the given
     /// [`Color`].
+    ///
+    /// Plain [`Text`] is shaped and cached by the renderer using its
+    /// contents; while a [`Fill::Cached`] paragraph is drawn directly.
     fn fill_text(
         &mut self,
-        text: Text<String, Self::Font>,
+        text: impl Into<Fill<'_, Self::Paragraph>>,
         position: Point,
         color: Color,
         clip_bounds: Rectangle,
     );
 }
 
+/// Some text to be filled by a [`Renderer`].
+///
+/// Text drawn every frame, like a static label, can be shaped once and kept
+/// in the state of a widget; so filling it skips the text cache of the
+/// renderer entirely.
+#[derive(Debug)]
+pub enum Fill<'a, P: Paragraph> {
+    /// Some [`Text`] to be shaped by the renderer, cached by its contents.
+    Text(Text<String, P::Font>),
+    /// A [`Paragraph`] already shaped; usually, kept in the state of a widget.
+    Cached(&'a P),
+}
+
+impl<'a, P: Paragraph> From<Text<String, P::Font>> for Fill<'a, P> {
+    fn from(text: Text<String, P::Font>) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl<'a, P: Paragraph> From<&'a P> for Fill<'a, P> {
+    fn from(paragraph: &'a P) -> Self {
+        Self::Cached(paragraph)
+    }
+}
+
+impl<'a, P: Paragraph> From<&'a paragraph::Plain<P>> for Fill<'a, P> {
+    fn from(paragraph: &'a paragraph::Plain<P>) -> Self {
+        Self::Cached(paragraph.raw())
+    }
+}
+
 /// A span of text.
 #[derive(Debug, Clone)]
 pub struct Span<'a, Link = (), Font = crate::Font> {
@@ -1,4 +1,5 @@
 //! Edit text.
+use crate::font;
 use crate::text::highlighter::{self, Highlighter};
 use crate::text::LineHeight;
 use crate::{Pixels, Point, Rectangle, Size};
@@ -41,10 +42,14 @@ pub trait Editor: Sized + Default {
     fn min_bounds(&self) -> Size;
 
     /// Updates the [`Editor`] with some new attributes.
+    ///
+    /// If not empty, the new font features replace the default features of
+    /// the new font.
     fn update(
         &mut self,
         new_bounds: Size,
         new_font: Self::Font,
+        new_font_features: &'static [font::Feature],
         new_size: Pixels,
         new_line_height: LineHeight,
         new_highlighter: &mut impl Highlighter,
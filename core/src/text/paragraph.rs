@@ -33,6 +33,14 @@ pub trait Paragraph: Sized + Default {
     /// [`Paragraph`].
     fn min_bounds(&self) -> Size;
 
+    /// Returns the distance from the top of the [`Paragraph`] to the
+    /// baseline of its first line, if it has any.
+    ///
+    /// By default, a [`Paragraph`] has no baseline.
+    fn baseline(&self) -> Option<f32> {
+        None
+    }
+
     /// Tests whether the provided point is within the boundaries of the
     /// [`Paragraph`], returning information about the nearest character.
     fn hit_test(&self, point: Point) -> Option<Hit>;
@@ -90,6 +98,8 @@ impl<P: Paragraph> Plain<P> {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            max_space_factor: text.max_space_factor,
+            font_features: text.font_features,
             shaping: text.shaping,
         }) {
             Difference::None => {}
@@ -124,6 +134,12 @@ impl<P: Paragraph> Plain<P> {
         self.raw.min_width()
     }
 
+    /// Returns the distance from the top of the [`Paragraph`] to the
+    /// baseline of its first line, if it has any.
+    pub fn baseline(&self) -> Option<f32> {
+        self.raw.baseline()
+    }
+
     /// Returns the cached [`Paragraph`].
     pub fn raw(&self) -> &P {
         &self.raw
@@ -1,4 +1,4 @@
-use crate::{Point, Rectangle, Size, Vector};
+use crate::{Point, Radians, Rectangle, Size, Vector};
 
 use glam::{Mat4, Vec3, Vec4};
 use std::ops::Mul;
@@ -31,9 +31,23 @@ impl Transformation {
         Transformation(Mat4::from_scale(Vec3::new(scaling, scaling, 1.0)))
     }
 
+    /// Creates a rotation transformation around the origin.
+    ///
+    /// Positive angles rotate clockwise on the screen, since its y axis
+    /// points down.
+    pub fn rotate(angle: impl Into<Radians>) -> Transformation {
+        Transformation(Mat4::from_rotation_z(angle.into().0))
+    }
+
+    /// Returns the inverse of the [`Transformation`]; which maps transformed
+    /// points back to their original coordinates.
+    pub fn inverse(&self) -> Transformation {
+        Transformation(self.0.inverse())
+    }
+
     /// Returns the scale factor of the [`Transformation`].
     pub fn scale_factor(&self) -> f32 {
-        self.0.x_axis.x
+        self.0.x_axis.truncate().length()
     }
 
     /// Returns the translation of the [`Transformation`].
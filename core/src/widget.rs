@@ -3,8 +3,11 @@ pub mod operation;
 pub mod text;
 pub mod tree;
 
+pub(crate) mod any;
+
 mod id;
 
+pub use any::AnyWidget;
 pub use id::Id;
 pub use operation::Operation;
 pub use text::Text;
@@ -141,11 +144,16 @@ where
     }
 
     /// Returns the overlay of the [`Widget`], if there is any.
+    ///
+    /// The `viewport` is the visible area of the [`Widget`], in the same
+    /// coordinates as its [`Layout`]; overlays can use it to know whether
+    /// their anchor is still visible.
     fn overlay<'a>(
         &'a mut self,
         _state: &'a mut Tree,
         _layout: Layout<'_>,
         _renderer: &Renderer,
+        _viewport: &Rectangle,
         _translation: Vector,
     ) -> Option<overlay::Element<'a, Message, Theme, Renderer>> {
         None
@@ -0,0 +1,173 @@
+use crate::event::{self, Event};
+use crate::layout::{self, Layout};
+use crate::mouse;
+use crate::renderer::{self, AnyRenderer};
+use crate::widget::tree::{self, Tree};
+use crate::{Clipboard, Length, Rectangle, Shell, Size, Widget};
+
+/// A [`Widget`] that can be compiled without knowing the concrete renderer
+/// of an application.
+///
+/// An [`AnyWidget`] draws itself using an [`AnyRenderer`] trait object. This
+/// allows widgets to be compiled separately; for instance, as a plugin
+/// library loaded at runtime. Use [`Element::boxed_dyn`] to turn one into an
+/// [`Element`].
+///
+/// Only leaf widgets are supported: an [`AnyWidget`] cannot have children,
+/// overlays, or take part in operations. Drawing is limited to the subset
+/// of primitives exposed by [`AnyRenderer`].
+///
+/// Rust has no stable ABI. A widget loaded from a dynamic library must be
+/// built with the same compiler and the same version of `iced_core` as the
+/// application loading it.
+///
+/// [`Element`]: crate::Element
+/// [`Element::boxed_dyn`]: crate::Element::boxed_dyn
+pub trait AnyWidget<Message, Theme> {
+    /// Returns the [`Size`] of the [`AnyWidget`] in lengths.
+    fn size(&self) -> Size<Length>;
+
+    /// Returns the [`layout::Node`] of the [`AnyWidget`].
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &dyn AnyRenderer,
+        limits: &layout::Limits,
+    ) -> layout::Node;
+
+    /// Draws the [`AnyWidget`] using the given [`AnyRenderer`].
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut dyn AnyRenderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    );
+
+    /// Returns the [`Tag`] of the [`AnyWidget`].
+    ///
+    /// [`Tag`]: tree::Tag
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    /// Returns the [`State`] of the [`AnyWidget`].
+    ///
+    /// [`State`]: tree::State
+    fn state(&self) -> tree::State {
+        tree::State::None
+    }
+
+    /// Processes a runtime [`Event`].
+    ///
+    /// By default, it does nothing.
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &dyn AnyRenderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    /// Returns the current [`mouse::Interaction`] of the [`AnyWidget`].
+    ///
+    /// By default, it returns [`mouse::Interaction::None`].
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        mouse::Interaction::None
+    }
+}
+
+/// A [`Widget`] backed by an [`AnyWidget`].
+pub(crate) struct Dyn<'a, Message, Theme> {
+    widget: Box<dyn AnyWidget<Message, Theme> + 'a>,
+}
+
+impl<'a, Message, Theme> Dyn<'a, Message, Theme> {
+    pub(crate) fn new(widget: Box<dyn AnyWidget<Message, Theme> + 'a>) -> Self {
+        Self { widget }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Dyn<'a, Message, Theme>
+where
+    Renderer: crate::Renderer + AnyRenderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.widget.size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.widget.layout(tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.widget
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn tag(&self) -> tree::Tag {
+        self.widget.tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.widget.state()
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.widget.on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.widget
+            .mouse_interaction(tree, layout, cursor, viewport)
+    }
+}
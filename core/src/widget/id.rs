@@ -21,12 +21,93 @@ impl Id {
 
         Self(Internal::Unique(id))
     }
+
+    /// Returns the [`Id`] of a widget with this [`Id`] inside the scope with
+    /// the given [`Id`].
+    ///
+    /// Widgets that establish a scope, like a keyed item or a component,
+    /// report the [`Id`] of every widget inside them scoped within their own
+    /// during operations. Scopes are nested from the innermost to the
+    /// outermost:
+    ///
+    /// ```
+    /// # use iced_core::widget::Id;
+    /// let email = Id::new("email")
+    ///     .within(Id::new("login_form"))
+    ///     .within(Id::new("sidebar"));
+    ///
+    /// assert_ne!(email, Id::new("email"));
+    /// ```
+    pub fn within(self, scope: impl Into<Id>) -> Self {
+        Self(Internal::Scoped(Box::new(scope.into()), Box::new(self)))
+    }
+
+    /// Returns the name of the [`Id`], or `None` if it is
+    /// [`unique`](Self::unique).
+    ///
+    /// Unlike a unique [`Id`], a name stays the same across runs of an
+    /// application. The name of a scoped [`Id`] joins the names of its
+    /// scopes with `/`, from the outermost to the innermost.
+    ///
+    /// ```
+    /// # use iced_core::widget::Id;
+    /// let email = Id::new("email").within(Id::new("login_form"));
+    ///
+    /// assert_eq!(email.name().as_deref(), Some("login_form/email"));
+    /// assert_eq!(Id::unique().name(), None);
+    /// ```
+    pub fn name(&self) -> Option<String> {
+        match &self.0 {
+            Internal::Unique(_) => None,
+            Internal::Custom(name) => Some(name.to_string()),
+            Internal::Scoped(scope, id) => {
+                Some(format!("{}/{}", scope.name()?, id.name()?))
+            }
+        }
+    }
+}
+
+/// Creates an [`Id`] tied to the location of the macro call.
+///
+/// Every call site produces a different [`Id`], but the same call site
+/// always produces the same one. Therefore, it can be used directly in a
+/// `view` function without the [`Id`] changing between frames.
+///
+/// The macro produces any type that implements `From<Id>`; like the
+/// specific identifiers of widgets.
+///
+/// ```
+/// # use iced_core::widget::Id;
+/// # use iced_core::id;
+/// fn search_bar() -> Id {
+///     id!()
+/// }
+///
+/// let other: Id = id!();
+///
+/// assert_eq!(search_bar(), search_bar());
+/// assert_ne!(search_bar(), other);
+/// ```
+#[macro_export]
+macro_rules! id {
+    () => {
+        ::std::convert::From::from($crate::widget::Id::new(concat!(
+            module_path!(),
+            "@",
+            file!(),
+            ":",
+            line!(),
+            ":",
+            column!()
+        )))
+    };
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Internal {
     Unique(usize),
     Custom(borrow::Cow<'static, str>),
+    Scoped(Box<Id>, Box<Id>),
 }
 
 #[cfg(test)]
@@ -40,4 +121,26 @@ mod tests {
 
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn macro_is_tied_to_call_site() {
+        let ids: Vec<Id> = (0..2).map(|_| crate::id!()).collect();
+        let other: Id = crate::id!();
+
+        assert_eq!(ids[0], ids[1]);
+        assert_ne!(ids[0], other);
+    }
+
+    #[test]
+    fn scopes_distinguish_the_same_id() {
+        let a = Id::new("input").within(Id::new("a"));
+        let b = Id::new("input").within(Id::new("b"));
+
+        assert_ne!(a, b);
+        assert_eq!(a, Id::new("input").within(Id::new("a")));
+        assert_ne!(
+            Id::new("input").within(Id::new("a")).within(Id::new("b")),
+            Id::new("input").within(Id::new("b")).within(Id::new("a")),
+        );
+    }
 }
@@ -10,6 +10,7 @@ pub use text_input::TextInput;
 use crate::widget::Id;
 use crate::{Rectangle, Vector};
 
+use rustc_hash::FxHashSet;
 use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
@@ -29,7 +30,16 @@ pub trait Operation<T>: Send {
     );
 
     /// Operates on a widget that can be focused.
-    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+    ///
+    /// The `bounds` are the layout bounds of the widget, without any
+    /// scrolling translation applied.
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
 
     /// Operates on a widget that can be scrolled.
     fn scrollable(
@@ -136,8 +146,9 @@ where
                     &mut self,
                     state: &mut dyn Focusable,
                     id: Option<&Id>,
+                    bounds: Rectangle,
                 ) {
-                    self.operation.focusable(state, id);
+                    self.operation.focusable(state, id, bounds);
                 }
 
                 fn text_input(
@@ -161,8 +172,13 @@ where
             .container(id, bounds, operate_on_children);
         }
 
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-            self.operation.focusable(state, id);
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
         }
 
         fn scrollable(
@@ -244,3 +260,317 @@ pub fn scope<T: 'static>(
         operation: Box::new(operation),
     }
 }
+
+/// Applies an [`Operation`] to a widget that establishes an [`Id`] scope.
+///
+/// The widget is operated on as a container with the given [`Id`]. Then, the
+/// [`Id`] of every widget traversed by `operate_on_children` is seen by the
+/// [`Operation`] within the scope; see [`Id::within`].
+pub fn namespace<T>(
+    operation: &mut dyn Operation<T>,
+    scope: &Id,
+    bounds: Rectangle,
+    operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+) {
+    struct Namespace<'a, T> {
+        scope: &'a Id,
+        operation: &'a mut dyn Operation<T>,
+    }
+
+    impl<'a, T> Namespace<'a, T> {
+        fn scoped(&self, id: Option<&Id>) -> Option<Id> {
+            id.map(|id| id.clone().within(self.scope.clone()))
+        }
+    }
+
+    impl<'a, T> Operation<T> for Namespace<'a, T> {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            let id = self.scoped(id);
+            let scope = self.scope;
+
+            self.operation
+                .container(id.as_ref(), bounds, &mut |operation| {
+                    operate_on_children(&mut Namespace { scope, operation });
+                });
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            let id = self.scoped(id);
+            self.operation.focusable(state, id.as_ref(), bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            let id = self.scoped(id);
+            self.operation
+                .scrollable(state, id.as_ref(), bounds, translation);
+        }
+
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            let id = self.scoped(id);
+            self.operation.text_input(state, id.as_ref());
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            let id = self.scoped(id);
+            self.operation.custom(state, id.as_ref());
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            self.operation.finish()
+        }
+    }
+
+    operation.container(Some(scope), bounds, &mut |operation| {
+        operate_on_children(&mut Namespace { scope, operation });
+    });
+}
+
+/// The [`Id`]s shared by multiple widgets during an [`Operation`].
+#[derive(Debug, Default)]
+pub struct Collisions {
+    seen: FxHashSet<(Kind, Id)>,
+    ids: Vec<Id>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Container,
+    Focusable,
+    Scrollable,
+    TextInput,
+    Custom,
+}
+
+impl Collisions {
+    /// Returns the [`Id`]s that collided.
+    pub fn ids(&self) -> &[Id] {
+        &self.ids
+    }
+
+    /// Returns true if no [`Id`] collided.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    fn record(&mut self, kind: Kind, id: Option<&Id>) {
+        let Some(id) = id else {
+            return;
+        };
+
+        if !self.seen.insert((kind, id.clone())) && !self.ids.contains(id) {
+            log::warn!(
+                "Multiple widgets share the same id: {id:?}. \
+                Operations may target the wrong widget."
+            );
+
+            self.ids.push(id.clone());
+        }
+    }
+}
+
+/// Wraps an [`Operation`] and records any [`Id`] found in more than one
+/// widget of the same kind in the given [`Collisions`], logging a warning.
+pub fn detect_collisions<'a, T>(
+    operation: &'a mut dyn Operation<T>,
+    collisions: &'a mut Collisions,
+) -> impl Operation<T> + 'a {
+    struct DetectCollisions<'a, T> {
+        operation: &'a mut dyn Operation<T>,
+        collisions: &'a mut Collisions,
+    }
+
+    impl<'a, T> Operation<T> for DetectCollisions<'a, T> {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            self.collisions.record(Kind::Container, id);
+
+            let Self {
+                operation,
+                collisions,
+            } = self;
+
+            operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut DetectCollisions {
+                    operation,
+                    collisions: &mut **collisions,
+                });
+            });
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.collisions.record(Kind::Focusable, id);
+            self.operation.focusable(state, id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.collisions.record(Kind::Scrollable, id);
+            self.operation.scrollable(state, id, bounds, translation);
+        }
+
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            self.collisions.record(Kind::TextInput, id);
+            self.operation.text_input(state, id);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            self.collisions.record(Kind::Custom, id);
+            self.operation.custom(state, id);
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            self.operation.finish()
+        }
+    }
+
+    DetectCollisions {
+        operation,
+        collisions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Traverse;
+
+    impl Operation<()> for Traverse {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    struct State;
+
+    impl Scrollable for State {
+        fn snap_to(&mut self, _offset: scrollable::RelativeOffset) {}
+
+        fn scroll_to(&mut self, _offset: scrollable::AbsoluteOffset) {}
+
+        fn scroll_by(&mut self, _offset: scrollable::AbsoluteOffset) {}
+    }
+
+    #[test]
+    fn detects_collisions_in_nested_containers() {
+        let a = Id::new("a");
+        let b = Id::new("b");
+
+        let mut traverse = Traverse;
+        let mut collisions = Collisions::default();
+
+        {
+            let mut operation =
+                detect_collisions(&mut traverse, &mut collisions);
+
+            operation.container(Some(&a), Rectangle::default(), &mut |op| {
+                op.container(None, Rectangle::default(), &mut |op| {
+                    op.container(Some(&b), Rectangle::default(), &mut |_| {});
+                    op.container(Some(&a), Rectangle::default(), &mut |_| {});
+                });
+            });
+        }
+
+        assert_eq!(collisions.ids(), &[a]);
+    }
+
+    #[test]
+    fn namespaces_scope_the_ids_of_descendants() {
+        struct Record(Vec<Option<Id>>);
+
+        impl Operation<()> for Record {
+            fn container(
+                &mut self,
+                id: Option<&Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+            ) {
+                self.0.push(id.cloned());
+
+                operate_on_children(self);
+            }
+        }
+
+        let outer = Id::new("outer");
+        let inner = Id::new("inner");
+        let leaf = Id::new("leaf");
+
+        let mut record = Record(Vec::new());
+
+        namespace(&mut record, &outer, Rectangle::default(), &mut |op| {
+            namespace(op, &inner, Rectangle::default(), &mut |op| {
+                op.container(Some(&leaf), Rectangle::default(), &mut |_| {});
+                op.container(None, Rectangle::default(), &mut |_| {});
+            });
+        });
+
+        assert_eq!(
+            record.0,
+            vec![
+                Some(outer.clone()),
+                Some(inner.clone().within(outer.clone())),
+                Some(leaf.within(inner).within(outer)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_widgets_reported_as_different_kinds() {
+        let id = Id::new("scrollable");
+
+        let mut traverse = Traverse;
+        let mut collisions = Collisions::default();
+
+        {
+            let mut operation =
+                detect_collisions(&mut traverse, &mut collisions);
+
+            // A scrollable is reported both as a scrollable and a container
+            operation.scrollable(
+                &mut State,
+                Some(&id),
+                Rectangle::default(),
+                Vector::ZERO,
+            );
+            operation.container(Some(&id), Rectangle::default(), &mut |_| {});
+        }
+
+        assert!(collisions.is_empty());
+    }
+}
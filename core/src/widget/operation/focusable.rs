@@ -1,7 +1,8 @@
 //! Operate on widgets that can be focused.
+use crate::widget::operation::scrollable::{self, AbsoluteOffset};
 use crate::widget::operation::{Operation, Outcome};
 use crate::widget::Id;
-use crate::Rectangle;
+use crate::{Rectangle, Vector};
 
 /// The internal state of a widget that can be focused.
 pub trait Focusable {
@@ -26,13 +27,20 @@ pub struct Count {
 }
 
 /// Produces an [`Operation`] that focuses the widget with the given [`Id`].
+///
+/// The widget will be revealed afterwards; see [`reveal`].
 pub fn focus<T>(target: Id) -> impl Operation<T> {
     struct Focus {
         target: Id,
     }
 
     impl<T> Operation<T> for Focus {
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match id {
                 Some(id) if id == &self.target => {
                     state.focus();
@@ -51,6 +59,10 @@ pub fn focus<T>(target: Id) -> impl Operation<T> {
         ) {
             operate_on_children(self);
         }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Chain(Box::new(Reveal::default()))
+        }
     }
 
     Focus { target }
@@ -71,7 +83,12 @@ where
     where
         O: Operation<T> + 'static,
     {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if state.is_focused() {
                 self.count.focused = Some(self.count.total);
             }
@@ -102,6 +119,8 @@ where
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the previous focusable widget.
 /// - if not found, focuses the last focusable widget.
+///
+/// The focused widget will be revealed afterwards; see [`reveal`].
 pub fn focus_previous<T>() -> impl Operation<T> {
     struct FocusPrevious {
         count: Count,
@@ -109,7 +128,12 @@ pub fn focus_previous<T>() -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for FocusPrevious {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if self.count.total == 0 {
                 return;
             }
@@ -134,6 +158,10 @@ pub fn focus_previous<T>() -> impl Operation<T> {
         ) {
             operate_on_children(self);
         }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Chain(Box::new(Reveal::default()))
+        }
     }
 
     count(|count| FocusPrevious { count, current: 0 })
@@ -142,6 +170,8 @@ pub fn focus_previous<T>() -> impl Operation<T> {
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the next focusable widget.
 /// - if not found, focuses the first focusable widget.
+///
+/// The focused widget will be revealed afterwards; see [`reveal`].
 pub fn focus_next<T>() -> impl Operation<T> {
     struct FocusNext {
         count: Count,
@@ -149,7 +179,12 @@ pub fn focus_next<T>() -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for FocusNext {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match self.count.focused {
                 None if self.current == 0 => state.focus(),
                 Some(focused) if focused == self.current => state.unfocus(),
@@ -168,11 +203,68 @@ pub fn focus_next<T>() -> impl Operation<T> {
         ) {
             operate_on_children(self);
         }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Chain(Box::new(Reveal::default()))
+        }
     }
 
     count(|count| FocusNext { count, current: 0 })
 }
 
+/// Produces an [`Operation`] that focuses the first focusable widget inside
+/// the container with the given [`Id`], and unfocuses any other widget.
+///
+/// The focused widget will be revealed afterwards; see [`reveal`].
+pub fn focus_first<T>(scope: Id) -> impl Operation<T> {
+    struct FocusFirst {
+        scope: Id,
+        is_inside: bool,
+        is_done: bool,
+    }
+
+    impl<T> Operation<T> for FocusFirst {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            if self.is_inside && !self.is_done {
+                state.focus();
+                self.is_done = true;
+            } else {
+                state.unfocus();
+            }
+        }
+
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            if !self.is_inside && id == Some(&self.scope) {
+                self.is_inside = true;
+                operate_on_children(self);
+                self.is_inside = false;
+            } else {
+                operate_on_children(self);
+            }
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Chain(Box::new(Reveal::default()))
+        }
+    }
+
+    FocusFirst {
+        scope,
+        is_inside: false,
+        is_done: false,
+    }
+}
+
 /// Produces an [`Operation`] that searches for the current focused widget
 /// and stores its ID. This ignores widgets that do not have an ID.
 pub fn find_focused() -> impl Operation<Id> {
@@ -181,7 +273,12 @@ pub fn find_focused() -> impl Operation<Id> {
     }
 
     impl Operation<Id> for FindFocused {
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if state.is_focused() && id.is_some() {
                 self.focused = id.cloned();
             }
@@ -207,3 +304,321 @@ pub fn find_focused() -> impl Operation<Id> {
 
     FindFocused { focused: None }
 }
+
+/// Produces an [`Operation`] that scrolls the minimal distance to reveal the
+/// current focused widget in every scrollable that contains it.
+pub fn reveal<T>() -> impl Operation<T> {
+    Reveal::default()
+}
+
+#[derive(Debug, Default)]
+struct Reveal {
+    current: usize,
+    ancestors: Vec<(usize, Rectangle, Vector)>,
+    pending: Option<(usize, Rectangle, Vector)>,
+    deltas: Option<Vec<(usize, Vector)>>,
+}
+
+impl<T> Operation<T> for Reveal {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        // A scrollable reports itself right before its contents
+        let scrollable = self.pending.take();
+
+        if let Some(scrollable) = scrollable {
+            self.ancestors.push(scrollable);
+        }
+
+        operate_on_children(self);
+
+        if scrollable.is_some() {
+            let _ = self.ancestors.pop();
+        }
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        if !state.is_focused() || self.deltas.is_some() {
+            return;
+        }
+
+        let mut target = bounds;
+        let mut deltas = Vec::new();
+
+        // We reveal from the innermost scrollable to the outermost one,
+        // keeping track of where the target will be visible in each
+        for &(index, bounds, translation) in self.ancestors.iter().rev() {
+            let viewport = bounds + translation;
+
+            let delta = Vector::new(
+                reveal_axis(viewport.x, viewport.width, target.x, target.width),
+                reveal_axis(
+                    viewport.y,
+                    viewport.height,
+                    target.y,
+                    target.height,
+                ),
+            );
+
+            if delta != Vector::ZERO {
+                deltas.push((index, delta));
+            }
+
+            target = target - (translation + delta);
+        }
+
+        self.deltas = Some(deltas);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn scrollable::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.pending = Some((self.current, bounds, translation));
+        self.current += 1;
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match &self.deltas {
+            Some(deltas) if !deltas.is_empty() => {
+                Outcome::Chain(Box::new(ScrollBy {
+                    current: 0,
+                    deltas: deltas.clone(),
+                }))
+            }
+            _ => Outcome::None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ScrollBy {
+    current: usize,
+    deltas: Vec<(usize, Vector)>,
+}
+
+impl<T> Operation<T> for ScrollBy {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn scrollable::Scrollable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if let Some((_, delta)) =
+            self.deltas.iter().find(|(index, _)| *index == self.current)
+        {
+            state.scroll_by(AbsoluteOffset {
+                x: delta.x,
+                y: delta.y,
+            });
+        }
+
+        self.current += 1;
+    }
+}
+
+/// Returns the minimal distance a viewport needs to move along an axis to
+/// reveal the given target; favoring its start if it does not fit.
+fn reveal_axis(
+    start: f32,
+    length: f32,
+    target: f32,
+    target_length: f32,
+) -> f32 {
+    let end = start + length;
+    let target_end = target + target_length;
+
+    if target < start {
+        target - start
+    } else if target_end > end {
+        (target_end - end).min(target - start)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Widget {
+        is_focused: bool,
+    }
+
+    impl Focusable for Widget {
+        fn is_focused(&self) -> bool {
+            self.is_focused
+        }
+
+        fn focus(&mut self) {
+            self.is_focused = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.is_focused = false;
+        }
+    }
+
+    #[derive(Default)]
+    struct Scrolled {
+        offset: Vector,
+    }
+
+    impl scrollable::Scrollable for Scrolled {
+        fn snap_to(&mut self, _offset: scrollable::RelativeOffset) {}
+
+        fn scroll_to(&mut self, _offset: AbsoluteOffset) {}
+
+        fn scroll_by(&mut self, offset: AbsoluteOffset) {
+            self.offset = self.offset + Vector::new(offset.x, offset.y);
+        }
+    }
+
+    /// Traverses an outer scrollable containing an inner scrollable, which
+    /// contains a focused widget.
+    fn traverse(
+        operation: &mut dyn Operation<()>,
+        outer: &mut Scrolled,
+        inner: &mut Scrolled,
+        inner_bounds: Rectangle,
+        widget_bounds: Rectangle,
+    ) {
+        let outer_bounds = Rectangle::new(
+            crate::Point::ORIGIN,
+            crate::Size::new(100.0, 100.0),
+        );
+
+        operation.scrollable(outer, None, outer_bounds, Vector::ZERO);
+        operation.container(None, outer_bounds, &mut |operation| {
+            operation.scrollable(inner, None, inner_bounds, Vector::ZERO);
+            operation.container(None, inner_bounds, &mut |operation| {
+                operation.focusable(
+                    &mut Widget { is_focused: true },
+                    None,
+                    widget_bounds,
+                );
+            });
+        });
+    }
+
+    fn perform(
+        inner_bounds: Rectangle,
+        widget_bounds: Rectangle,
+    ) -> (Scrolled, Scrolled) {
+        let mut outer = Scrolled::default();
+        let mut inner = Scrolled::default();
+
+        let mut operation: Box<dyn Operation<()>> = Box::new(reveal());
+
+        loop {
+            traverse(
+                operation.as_mut(),
+                &mut outer,
+                &mut inner,
+                inner_bounds,
+                widget_bounds,
+            );
+
+            match operation.finish() {
+                Outcome::Chain(next) => {
+                    operation = next;
+                }
+                _ => break,
+            }
+        }
+
+        (outer, inner)
+    }
+
+    fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn focus_first_only_focuses_inside_the_scope() {
+        let a = Id::new("a");
+        let b = Id::new("b");
+
+        let mut widgets: Vec<Widget> =
+            (0..4).map(|i| Widget { is_focused: i == 0 }).collect();
+
+        let mut operation = focus_first::<()>(b.clone());
+        let (first, second) = widgets.split_at_mut(2);
+
+        for (scope, widgets) in [(&a, first), (&b, second)] {
+            operation.container(
+                Some(scope),
+                Rectangle::default(),
+                &mut |operation| {
+                    for widget in widgets.iter_mut() {
+                        operation.focusable(widget, None, Rectangle::default());
+                    }
+                },
+            );
+        }
+
+        let focused: Vec<bool> =
+            widgets.iter().map(|widget| widget.is_focused).collect();
+
+        assert_eq!(focused, [false, false, true, false]);
+    }
+
+    #[test]
+    fn visible_widget_is_not_scrolled() {
+        let (outer, inner) = perform(
+            rectangle(0.0, 0.0, 100.0, 50.0),
+            rectangle(0.0, 10.0, 100.0, 20.0),
+        );
+
+        assert_eq!(outer.offset, Vector::ZERO);
+        assert_eq!(inner.offset, Vector::ZERO);
+    }
+
+    #[test]
+    fn widget_below_the_fold_is_revealed_minimally() {
+        let (outer, inner) = perform(
+            rectangle(0.0, 0.0, 100.0, 100.0),
+            rectangle(0.0, 150.0, 100.0, 20.0),
+        );
+
+        assert_eq!(outer.offset, Vector::ZERO);
+        assert_eq!(inner.offset, Vector::new(0.0, 70.0));
+    }
+
+    #[test]
+    fn nested_scrollables_reveal_through_all_ancestors() {
+        let (outer, inner) = perform(
+            rectangle(0.0, 200.0, 100.0, 100.0),
+            rectangle(0.0, 350.0, 100.0, 20.0),
+        );
+
+        assert_eq!(inner.offset, Vector::new(0.0, 70.0));
+        assert_eq!(outer.offset, Vector::new(0.0, 200.0));
+    }
+}
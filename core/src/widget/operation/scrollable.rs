@@ -1,6 +1,8 @@
 //! Operate on widgets that can be scrolled.
+use crate::time::Duration;
+use crate::widget::operation::Outcome;
 use crate::widget::{Id, Operation};
-use crate::{Rectangle, Vector};
+use crate::{Point, Rectangle, Vector};
 
 /// The internal state of a widget that can be scrolled.
 pub trait Scrollable {
@@ -9,6 +11,27 @@ pub trait Scrollable {
 
     /// Scroll the widget to the given [`AbsoluteOffset`] along the horizontal & vertical axis.
     fn scroll_to(&mut self, offset: AbsoluteOffset);
+
+    /// Scrolls the widget by the given [`AbsoluteOffset`]; that is, moves its
+    /// visible contents by the given amount of pixels along the horizontal &
+    /// vertical axis.
+    ///
+    /// Positive values reveal the contents further to the right and bottom.
+    fn scroll_by(&mut self, offset: AbsoluteOffset);
+
+    /// Scrolls the widget smoothly to the given [`AbsoluteOffset`] along the
+    /// horizontal & vertical axis; taking the given [`Duration`] and following
+    /// the given [`Easing`] curve.
+    ///
+    /// By default, the widget scrolls instantly.
+    fn scroll_to_animated(
+        &mut self,
+        offset: AbsoluteOffset,
+        _duration: Duration,
+        _easing: Easing,
+    ) {
+        self.scroll_to(offset);
+    }
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -79,6 +102,181 @@ pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
     ScrollTo { target, offset }
 }
 
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`]
+/// smoothly to the provided [`AbsoluteOffset`].
+pub fn scroll_to_animated<T>(
+    target: Id,
+    offset: AbsoluteOffset,
+    duration: Duration,
+    easing: Easing,
+) -> impl Operation<T> {
+    struct ScrollToAnimated {
+        target: Id,
+        offset: AbsoluteOffset,
+        duration: Duration,
+        easing: Easing,
+    }
+
+    impl<T> Operation<T> for ScrollToAnimated {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            _translation: Vector,
+        ) {
+            if Some(&self.target) == id {
+                state.scroll_to_animated(
+                    self.offset,
+                    self.duration,
+                    self.easing,
+                );
+            }
+        }
+    }
+
+    ScrollToAnimated {
+        target,
+        offset,
+        duration,
+        easing,
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`]
+/// smoothly until the top-left corner of the descendant container with the
+/// `child` [`Id`] is at the top-left corner of its viewport.
+pub fn scroll_to_child_animated<T>(
+    target: Id,
+    child: Id,
+    duration: Duration,
+    easing: Easing,
+) -> impl Operation<T>
+where
+    T: 'static,
+{
+    struct ScrollToChild {
+        target: Id,
+        child: Id,
+        duration: Duration,
+        easing: Easing,
+        origin: Option<Point>,
+        position: Option<Point>,
+    }
+
+    impl<T> Operation<T> for ScrollToChild
+    where
+        T: 'static,
+    {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            if self.position.is_some() {
+                return;
+            }
+
+            if self.origin.is_some() && Some(&self.child) == id {
+                self.position = Some(bounds.position());
+                return;
+            }
+
+            operate_on_children(self);
+
+            // Only the descendants of the scrollable are looked at
+            if Some(&self.target) == id && self.position.is_none() {
+                self.origin = None;
+            }
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _translation: Vector,
+        ) {
+            if self.origin.is_none() && Some(&self.target) == id {
+                self.origin = Some(bounds.position());
+            }
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            let (Some(origin), Some(position)) = (self.origin, self.position)
+            else {
+                return Outcome::None;
+            };
+
+            let offset = position - origin;
+
+            Outcome::Chain(Box::new(scroll_to_animated(
+                self.target.clone(),
+                AbsoluteOffset {
+                    x: offset.x,
+                    y: offset.y,
+                },
+                self.duration,
+                self.easing,
+            )))
+        }
+    }
+
+    ScrollToChild {
+        target,
+        child,
+        duration,
+        easing,
+        origin: None,
+        position: None,
+    }
+}
+
+/// The curve followed by an animated scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// The scroll moves at a constant speed.
+    Linear,
+    /// The scroll starts slowly and speeds up.
+    EaseIn,
+    /// The scroll starts quickly and slows down.
+    #[default]
+    EaseOut,
+    /// The scroll starts slowly, speeds up, and slows down again.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the progress of the scroll at the given moment; both going from
+    /// `0.0` to `1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
 /// The amount of absolute offset in each direction of a [`Scrollable`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct AbsoluteOffset {
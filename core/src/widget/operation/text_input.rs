@@ -1,8 +1,10 @@
 //! Operate on widgets that have text input.
-use crate::widget::operation::Operation;
+use crate::widget::operation::{Operation, Outcome};
 use crate::widget::Id;
 use crate::Rectangle;
 
+use std::ops::Range;
+
 /// The internal state of a widget that has text input.
 pub trait TextInput {
     /// Moves the cursor of the text input to the front of the input text.
@@ -13,6 +15,32 @@ pub trait TextInput {
     fn move_cursor_to(&mut self, position: usize);
     /// Selects all the content of the text input.
     fn select_all(&mut self);
+    /// Selects the given range of `char`s of the text input.
+    fn select_range(&mut self, range: Range<usize>);
+    /// Returns the current [`Selection`] of the text input.
+    fn selection(&self) -> Selection;
+    /// Undoes the last edit of the text input.
+    fn undo(&mut self);
+    /// Redoes the last undone edit of the text input.
+    fn redo(&mut self);
+}
+
+/// The selected text of a text input.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Selection {
+    /// The range of the selection, in `char` indices.
+    ///
+    /// It is empty, at the position of the cursor, when nothing is selected.
+    pub range: Range<usize>,
+    /// The selected text.
+    pub text: String,
+}
+
+impl Selection {
+    /// Returns true if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
 }
 
 /// Produces an [`Operation`] that moves the cursor of the widget with the given [`Id`] to the
@@ -134,3 +162,135 @@ pub fn select_all<T>(target: Id) -> impl Operation<T> {
 
     MoveCursor { target }
 }
+
+/// Produces an [`Operation`] that selects the given range of `char`s of the
+/// widget with the given [`Id`].
+pub fn select_range<T>(target: Id, range: Range<usize>) -> impl Operation<T> {
+    struct SelectRange {
+        target: Id,
+        range: Range<usize>,
+    }
+
+    impl<T> Operation<T> for SelectRange {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.select_range(self.range.clone());
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    SelectRange { target, range }
+}
+
+/// Produces an [`Operation`] that undoes the last edit of the widget with the
+/// given [`Id`].
+pub fn undo<T>(target: Id) -> impl Operation<T> {
+    struct Undo {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Undo {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.undo();
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    Undo { target }
+}
+
+/// Produces an [`Operation`] that redoes the last undone edit of the widget
+/// with the given [`Id`].
+pub fn redo<T>(target: Id) -> impl Operation<T> {
+    struct Redo {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Redo {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.redo();
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    Redo { target }
+}
+
+/// Produces an [`Operation`] that returns the current [`Selection`] of the
+/// widget with the given [`Id`].
+pub fn selection(target: Id) -> impl Operation<Selection> {
+    struct FindSelection {
+        target: Id,
+        selection: Option<Selection>,
+    }
+
+    impl Operation<Selection> for FindSelection {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    self.selection = Some(state.selection());
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Selection>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn finish(&self) -> Outcome<Selection> {
+            match &self.selection {
+                Some(selection) => Outcome::Some(selection.clone()),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    FindSelection {
+        target,
+        selection: None,
+    }
+}
@@ -1,5 +1,6 @@
 //! Write some text for your users to read.
 use crate::alignment;
+use crate::font;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
@@ -27,7 +28,9 @@ where
     height: Length,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    max_space_factor: f32,
     font: Option<Renderer::Font>,
+    font_features: &'static [font::Feature],
     shaping: Shaping,
     class: Theme::Class<'a>,
 }
@@ -44,10 +47,12 @@ where
             size: None,
             line_height: LineHeight::default(),
             font: None,
+            font_features: &[],
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
             shaping: Shaping::Basic,
             class: Theme::default(),
         }
@@ -73,6 +78,19 @@ where
         self
     }
 
+    /// Sets the OpenType [`font::Feature`]s of the [`Text`].
+    ///
+    /// They replace the default features of its [`Font`].
+    ///
+    /// [`Font`]: crate::text::Renderer::Font
+    pub fn font_features(
+        mut self,
+        font_features: &'static [font::Feature],
+    ) -> Self {
+        self.font_features = font_features;
+        self
+    }
+
     /// Sets the width of the [`Text`] boundaries.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -109,6 +127,16 @@ where
         self
     }
 
+    /// Sets the maximum factor the spaces of the [`Text`] can be stretched
+    /// by when justified.
+    ///
+    /// A paragraph with lines that would need to be stretched further is
+    /// aligned to the left instead.
+    pub fn max_space_factor(mut self, max_space_factor: f32) -> Self {
+        self.max_space_factor = max_space_factor;
+        self
+    }
+
     /// Sets the [`Shaping`] strategy of the [`Text`].
     pub fn shaping(mut self, shaping: Shaping) -> Self {
         self.shaping = shaping;
@@ -195,8 +223,10 @@ where
             self.line_height,
             self.size,
             self.font,
+            self.font_features,
             self.horizontal_alignment,
             self.vertical_alignment,
+            self.max_space_factor,
             self.shaping,
         )
     }
@@ -229,14 +259,18 @@ pub fn layout<Renderer>(
     line_height: LineHeight,
     size: Option<Pixels>,
     font: Option<Renderer::Font>,
+    font_features: &'static [font::Feature],
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    max_space_factor: f32,
     shaping: Shaping,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
 {
-    layout::sized(limits, width, height, |limits| {
+    let horizontal_alignment = horizontal_alignment.resolve();
+
+    let node = layout::sized(limits, width, height, |limits| {
         let bounds = limits.max();
 
         let size = size.unwrap_or_else(|| renderer.default_size());
@@ -252,11 +286,36 @@ where
             font,
             horizontal_alignment,
             vertical_alignment,
+            max_space_factor,
+            font_features,
             shaping,
         });
 
         paragraph.min_bounds()
-    })
+    });
+
+    let baseline = baseline(state.0.raw(), node.size().height);
+
+    node.with_baseline(baseline)
+}
+
+/// Computes the baseline of a [`Paragraph`] laid out in a space of the given
+/// height; taking its vertical alignment into account.
+///
+/// [`Paragraph`]: text::Paragraph
+pub fn baseline<P>(paragraph: &P, height: f32) -> Option<f32>
+where
+    P: text::Paragraph,
+{
+    let offset = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => 0.0,
+        alignment::Vertical::Center => {
+            (height - paragraph.min_bounds().height) / 2.0
+        }
+        alignment::Vertical::Bottom => height - paragraph.min_bounds().height,
+    };
+
+    paragraph.baseline().map(|baseline| offset + baseline)
 }
 
 /// Draws text using the same logic as the [`Text`] widget.
@@ -269,6 +328,8 @@ where
 ///   used.
 /// * The alignment attributes do not affect the position of the bounds of the
 ///   [`Layout`].
+/// * The paragraph is filled as [`text::Fill::Cached`]; so the renderer only
+///   keeps a weak handle to it, skipping its own text cache.
 pub fn draw<Renderer>(
     renderer: &mut Renderer,
     style: &renderer::Style,
@@ -281,10 +342,14 @@ pub fn draw<Renderer>(
 {
     let bounds = layout.bounds();
 
-    let x = match paragraph.horizontal_alignment() {
-        alignment::Horizontal::Left => bounds.x,
+    let x = match paragraph.horizontal_alignment().resolve() {
+        alignment::Horizontal::Left
+        | alignment::Horizontal::Start
+        | alignment::Horizontal::Justify => bounds.x,
         alignment::Horizontal::Center => bounds.center_x(),
-        alignment::Horizontal::Right => bounds.x + bounds.width,
+        alignment::Horizontal::Right | alignment::Horizontal::End => {
+            bounds.x + bounds.width
+        }
     };
 
     let y = match paragraph.vertical_alignment() {
@@ -293,7 +358,7 @@ pub fn draw<Renderer>(
         alignment::Vertical::Bottom => bounds.y + bounds.height,
     };
 
-    renderer.fill_paragraph(
+    renderer.fill_text(
         paragraph,
         Point::new(x, y),
         appearance.color.unwrap_or(style.text_color),
@@ -189,6 +189,63 @@ pub fn diff_children_custom_with_search<T>(
     }
 }
 
+/// Reconciles the `current_children` with the provided list of widgets by
+/// matching their keys, instead of their positions.
+///
+/// The state of a child moves along with its key; so reordering, inserting,
+/// or removing children does not disturb the state of their siblings.
+/// Children with a new key get new state. If a key is repeated, its children
+/// are matched in order.
+pub fn diff_children_keyed<K, T>(
+    current_children: &mut Vec<Tree>,
+    current_keys: &[K],
+    new_children: &[T],
+    new_keys: &[K],
+    diff: impl Fn(&mut Tree, &T),
+    new_state: impl Fn(&T) -> Tree,
+) where
+    K: PartialEq,
+{
+    if current_keys == new_keys && current_children.len() == new_children.len()
+    {
+        for (child_state, new) in current_children.iter_mut().zip(new_children)
+        {
+            diff(child_state, new);
+        }
+
+        return;
+    }
+
+    let mut previous: Vec<_> = current_keys
+        .iter()
+        .zip(current_children.drain(..))
+        .map(Some)
+        .collect();
+
+    // Children usually keep their relative order; so the search for a key
+    // starts right after the last match
+    let mut next = 0;
+
+    current_children.extend(new_keys.iter().zip(new_children).map(
+        |(key, new)| {
+            let index = (next..previous.len()).chain(0..next).find(
+                |&i| matches!(&previous[i], Some((other, _)) if *other == key),
+            );
+
+            match index.and_then(|index| {
+                next = index + 1;
+                previous[index].take()
+            }) {
+                Some((_, mut child_state)) => {
+                    diff(&mut child_state, new);
+                    child_state
+                }
+                None => new_state(new),
+            }
+        },
+    ));
+}
+
 /// The identifier of some widget state.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct Tag(any::TypeId);
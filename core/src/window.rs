@@ -2,6 +2,7 @@
 pub mod icon;
 pub mod settings;
 
+mod backdrop;
 mod event;
 mod id;
 mod level;
@@ -9,7 +10,9 @@ mod mode;
 mod position;
 mod redraw_request;
 mod user_attention;
+mod video_mode;
 
+pub use backdrop::Backdrop;
 pub use event::Event;
 pub use icon::Icon;
 pub use id::Id;
@@ -19,3 +22,4 @@ pub use position::Position;
 pub use redraw_request::RedrawRequest;
 pub use settings::Settings;
 pub use user_attention::UserAttention;
+pub use video_mode::VideoMode;
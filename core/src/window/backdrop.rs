@@ -0,0 +1,36 @@
+/// The material drawn behind the contents of a window.
+///
+/// Any backdrop other than [`Backdrop::Opaque`] makes the window transparent;
+/// therefore, the background of the application should be at least partially
+/// transparent for the backdrop to show through.
+///
+/// Backdrops a platform does not support fall back to [`Backdrop::Opaque`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backdrop {
+    /// The window is opaque.
+    #[default]
+    Opaque,
+
+    /// Whatever is behind the window shows through, untouched.
+    Transparent,
+
+    /// Whatever is behind the window shows through, blurred.
+    ///
+    /// ## Platform-specific
+    /// - **macOS:** The window background is blurred.
+    /// - **Windows:** Same as [`Backdrop::Acrylic`].
+    /// - **Linux:** Only on Wayland compositors supporting the KDE blur
+    ///   protocol; the window stays transparent otherwise.
+    Blur,
+
+    /// The translucent, blurred material of transient windows in Windows 11.
+    ///
+    /// Only supported on Windows.
+    Acrylic,
+
+    /// The opaque material tinted with the desktop wallpaper of main windows
+    /// in Windows 11.
+    ///
+    /// Only supported on Windows.
+    Mica,
+}
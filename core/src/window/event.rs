@@ -1,4 +1,5 @@
 use crate::time::Instant;
+use crate::window::Mode;
 use crate::{Point, Size};
 
 use std::path::PathBuf;
@@ -28,13 +29,32 @@ pub enum Event {
     /// A window was resized.
     Resized(Size),
 
+    /// The [`Mode`] of a window changed.
+    ///
+    /// This contains the [`Mode`] actually applied, which may differ from
+    /// the one requested; for instance, when exclusive fullscreen is not
+    /// available.
+    ModeChanged(Mode),
+
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
     RedrawRequested(Instant),
 
     /// The user has requested for the window to close.
-    CloseRequested,
+    ///
+    /// The window is only closed automatically if its
+    /// [`Settings::exit_on_close_request`] is `true`; otherwise, the
+    /// application may close it later or ignore the request entirely.
+    ///
+    /// [`Settings::exit_on_close_request`]: crate::window::Settings::exit_on_close_request
+    CloseRequested {
+        /// The number of the request.
+        ///
+        /// It increases monotonically with every close request made to any
+        /// window; so repeated requests can be told apart.
+        request: u64,
+    },
 
     /// A window was focused.
     Focused,
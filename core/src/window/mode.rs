@@ -1,3 +1,5 @@
+use crate::window::VideoMode;
+
 /// The mode of a window-based application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
@@ -5,8 +7,18 @@ pub enum Mode {
     Windowed,
 
     /// The application takes the whole screen of its current monitor.
+    ///
+    /// The window is borderless and the monitor keeps its video mode.
     Fullscreen,
 
+    /// The application takes its current monitor exclusively, switching it
+    /// to the given [`VideoMode`].
+    ///
+    /// The window falls back to [`Mode::Fullscreen`] if the monitor does not
+    /// support the [`VideoMode`] or the platform does not support exclusive
+    /// fullscreen; like Wayland or the Web.
+    Exclusive(VideoMode),
+
     /// The application is hidden
     Hidden,
 }
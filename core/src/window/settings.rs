@@ -24,8 +24,8 @@ mod platform;
 #[path = "settings/other.rs"]
 mod platform;
 
-use crate::window::{Icon, Level, Position};
-use crate::Size;
+use crate::window::{Backdrop, Icon, Id, Level, Position};
+use crate::{Color, Size};
 
 pub use platform::PlatformSpecific;
 /// The window settings of an application.
@@ -55,12 +55,46 @@ pub struct Settings {
     /// Whether the window should be transparent.
     pub transparent: bool,
 
+    /// The [`Backdrop`] of the window.
+    ///
+    /// Any backdrop other than [`Backdrop::Opaque`] makes the window
+    /// [`transparent`](Self::transparent).
+    pub backdrop: Backdrop,
+
     /// The window [`Level`].
     pub level: Level,
 
+    /// The color the window is cleared with before its first frame is
+    /// rendered.
+    ///
+    /// By default, the background color of the theme of the application is
+    /// used.
+    pub initial_background: Option<Color>,
+
     /// The icon of the window.
     pub icon: Option<Icon>,
 
+    /// The parent window of the window, if any.
+    ///
+    /// A window with a parent is owned by it. It will be centered over its
+    /// parent when opened with a [`Position::Default`] or [`Position::Centered`].
+    ///
+    /// ## Platform-specific
+    /// - **Windows:** The window is owned by its parent; it is minimized
+    ///   together with it and always stays above it.
+    /// - **macOS:** The window is a child window of its parent.
+    /// - **Other platforms:** Only the initial centering is applied.
+    pub parent: Option<Id>,
+
+    /// Whether the window should be modal to its [`parent`](Self::parent).
+    ///
+    /// While a modal window is open, its parent window will ignore any
+    /// user input and it will be dimmed. A parent with several modal
+    /// windows stays blocked until all of them are closed.
+    ///
+    /// This has no effect if the window has no parent.
+    pub modal: bool,
+
     /// Platform specific settings.
     pub platform_specific: PlatformSpecific,
 
@@ -73,6 +107,34 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// Whether pressing Alt+Enter toggles the window between
+    /// [`Mode::Windowed`] and [`Mode::Fullscreen`].
+    ///
+    /// By default this is disabled.
+    ///
+    /// [`Mode::Windowed`]: crate::window::Mode::Windowed
+    /// [`Mode::Fullscreen`]: crate::window::Mode::Fullscreen
+    pub fullscreen_shortcut: bool,
+}
+
+impl Settings {
+    /// Sets the [`parent`](Self::parent) of the [`Settings`].
+    pub fn with_parent(self, parent: Id) -> Self {
+        Self {
+            parent: Some(parent),
+            ..self
+        }
+    }
+
+    /// Sets the [`initial_background`](Self::initial_background) of the
+    /// [`Settings`].
+    pub fn with_initial_background(self, color: impl Into<Color>) -> Self {
+        Self {
+            initial_background: Some(color.into()),
+            ..self
+        }
+    }
 }
 
 impl Default for Settings {
@@ -86,9 +148,14 @@ impl Default for Settings {
             resizable: true,
             decorations: true,
             transparent: false,
+            backdrop: Backdrop::default(),
             level: Level::default(),
+            initial_background: None,
             icon: None,
+            parent: None,
+            modal: false,
             exit_on_close_request: true,
+            fullscreen_shortcut: false,
             platform_specific: PlatformSpecific::default(),
         }
     }
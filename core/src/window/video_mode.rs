@@ -0,0 +1,19 @@
+use crate::Size;
+
+/// A video mode of a monitor.
+///
+/// A window can take a monitor exclusively with one of its video modes,
+/// changing its resolution and refresh rate. See [`Mode::Exclusive`].
+///
+/// [`Mode::Exclusive`]: crate::window::Mode::Exclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VideoMode {
+    /// The resolution of the [`VideoMode`], in physical pixels.
+    pub size: Size<u32>,
+
+    /// The bit depth of the [`VideoMode`], in bits per pixel.
+    pub bit_depth: u16,
+
+    /// The refresh rate of the [`VideoMode`], in millihertz.
+    pub refresh_rate_millihertz: u32,
+}
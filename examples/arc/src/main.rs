@@ -56,6 +56,7 @@ impl<Message> canvas::Program<Message> for Arc {
         _state: &Self::State,
         renderer: &Renderer,
         theme: &Theme,
+        _style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
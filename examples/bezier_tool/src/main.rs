@@ -146,6 +146,7 @@ mod bezier {
             state: &Self::State,
             renderer: &Renderer,
             theme: &Theme,
+            _style: &canvas::Style,
             bounds: Rectangle,
             cursor: mouse::Cursor,
         ) -> Vec<Geometry> {
@@ -0,0 +1,110 @@
+use iced::widget::canvas::Path;
+use iced::widget::{
+    button, center, checkbox, clip_path, column, container, slider, text,
+};
+use iced::{Center, Element, Point, Theme, Vector};
+
+use std::f32::consts::PI;
+
+const SIZE: f32 = 320.0;
+
+pub fn main() -> iced::Result {
+    iced::application("Clip Path - Iced", Hexagon::update, Hexagon::view).run()
+}
+
+#[derive(Default)]
+struct Hexagon {
+    clicks: usize,
+    value: f32,
+    is_round: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Clicked,
+    ValueChanged(f32),
+    RoundToggled(bool),
+}
+
+impl Hexagon {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Clicked => {
+                self.clicks += 1;
+            }
+            Message::ValueChanged(value) => {
+                self.value = value;
+            }
+            Message::RoundToggled(is_round) => {
+                self.is_round = is_round;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let content = container(
+            column![
+                text("Inside a hexagon").size(24),
+                button(text!("Clicked {} time(s)", self.clicks))
+                    .on_press(Message::Clicked),
+                slider(0.0..=100.0, self.value, Message::ValueChanged),
+                text!("{:.0}", self.value),
+            ]
+            .spacing(20)
+            .padding(40)
+            .align_x(Center),
+        )
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+
+            container::Style::default()
+                .background(palette.primary.weak.color)
+                .color(palette.primary.weak.text)
+        })
+        .center(SIZE);
+
+        // Nested clip paths intersect; so the circle cuts the hexagon
+        let content: Element<_> = if self.is_round {
+            clip_path(content, circle()).into()
+        } else {
+            content.into()
+        };
+
+        center(
+            column![
+                clip_path(content, hexagon()),
+                checkbox("Clip to a circle too", self.is_round)
+                    .on_toggle(Message::RoundToggled),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
+
+/// A regular hexagon inscribed in the content.
+fn hexagon() -> Path {
+    let center = Point::new(SIZE / 2.0, SIZE / 2.0);
+    let radius = SIZE / 2.0;
+
+    Path::new(|builder| {
+        for i in 0..6 {
+            let angle = PI / 3.0 * i as f32 + PI / 6.0;
+            let vertex =
+                center + Vector::new(angle.cos(), angle.sin()) * radius;
+
+            if i == 0 {
+                builder.move_to(vertex);
+            } else {
+                builder.line_to(vertex);
+            }
+        }
+
+        builder.close();
+    })
+}
+
+fn circle() -> Path {
+    Path::circle(Point::new(SIZE / 2.0, SIZE / 2.0), SIZE * 0.45)
+}
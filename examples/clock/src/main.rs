@@ -20,7 +20,8 @@ pub fn main() -> iced::Result {
 
 struct Clock {
     now: chrono::DateTime<chrono::Local>,
-    clock: Cache,
+    face: Cache,
+    hands: Cache,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -35,8 +36,13 @@ impl Clock {
                 let now = local_time;
 
                 if now != self.now {
+                    // The face only changes with the theme
+                    if theme_index(now) != theme_index(self.now) {
+                        self.face.clear();
+                    }
+
                     self.now = now;
-                    self.clock.clear();
+                    self.hands.clear();
                 }
             }
         }
@@ -54,8 +60,7 @@ impl Clock {
     }
 
     fn theme(&self) -> Theme {
-        Theme::ALL[(self.now.timestamp() as usize / 10) % Theme::ALL.len()]
-            .clone()
+        Theme::ALL[theme_index(self.now)].clone()
     }
 }
 
@@ -63,7 +68,8 @@ impl Default for Clock {
     fn default() -> Self {
         Self {
             now: chrono::offset::Local::now(),
-            clock: Cache::default(),
+            face: Cache::default(),
+            hands: Cache::default(),
         }
     }
 }
@@ -76,19 +82,47 @@ impl<Message> canvas::Program<Message> for Clock {
         _state: &Self::State,
         renderer: &Renderer,
         theme: &Theme,
+        style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
         use chrono::Timelike;
 
-        let clock = self.clock.draw(renderer, bounds.size(), |frame| {
-            let palette = theme.extended_palette();
-
+        let face = self.face.draw(renderer, bounds.size(), |frame| {
             let center = frame.center();
             let radius = frame.width().min(frame.height()) / 2.0;
 
             let background = Path::circle(center, radius);
-            frame.fill(&background, palette.secondary.strong.color);
+            frame.fill(&background, style.background.strong.color);
+
+            let tick = Path::line(
+                Point::new(0.0, -0.9 * radius),
+                Point::new(0.0, -0.95 * radius),
+            );
+
+            frame.translate(Vector::new(center.x, center.y));
+
+            for hour in 0..12 {
+                frame.with_save(|frame| {
+                    frame.rotate(hand_rotation(hour, 12));
+                    frame.stroke(
+                        &tick,
+                        Stroke {
+                            width: radius / 50.0,
+                            style: stroke::Style::Solid(
+                                style.background.strong.text,
+                            ),
+                            line_cap: LineCap::Round,
+                            ..Stroke::default()
+                        },
+                    );
+                });
+            }
+        });
+
+        let hands = self.hands.draw(renderer, bounds.size(), |frame| {
+            let center = frame.center();
+            let radius = frame.width().min(frame.height()) / 2.0;
 
             let short_hand =
                 Path::line(Point::ORIGIN, Point::new(0.0, -0.5 * radius));
@@ -101,7 +135,7 @@ impl<Message> canvas::Program<Message> for Clock {
             let thin_stroke = || -> Stroke {
                 Stroke {
                     width,
-                    style: stroke::Style::Solid(palette.secondary.strong.text),
+                    style: stroke::Style::Solid(style.background.strong.text),
                     line_cap: LineCap::Round,
                     ..Stroke::default()
                 }
@@ -110,7 +144,7 @@ impl<Message> canvas::Program<Message> for Clock {
             let wide_stroke = || -> Stroke {
                 Stroke {
                     width: width * 3.0,
-                    style: stroke::Style::Solid(palette.secondary.strong.text),
+                    style: stroke::Style::Solid(style.background.strong.text),
                     line_cap: LineCap::Round,
                     ..Stroke::default()
                 }
@@ -144,7 +178,7 @@ impl<Message> canvas::Program<Message> for Clock {
                         (0.78 * radius) * rotate_factor,
                         -width * 2.0,
                     ),
-                    color: palette.secondary.strong.text,
+                    color: style.background.strong.text,
                     horizontal_alignment: if rotate_factor > 0.0 {
                         alignment::Horizontal::Right
                     } else {
@@ -157,10 +191,15 @@ impl<Message> canvas::Program<Message> for Clock {
             });
         });
 
-        vec![clock]
+        // The hands are drawn on top of the face
+        vec![face, hands]
     }
 }
 
+fn theme_index(now: chrono::DateTime<chrono::Local>) -> usize {
+    (now.timestamp() as usize / 10) % Theme::ALL.len()
+}
+
 fn hand_rotation(n: u32, total: u32) -> Degrees {
     let turns = n as f32 / total as f32;
 
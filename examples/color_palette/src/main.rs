@@ -251,6 +251,7 @@ impl<Message> canvas::Program<Message> for Theme {
         _state: &Self::State,
         renderer: &Renderer,
         theme: &iced::Theme,
+        _style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
@@ -2,10 +2,16 @@ use iced::widget::{button, column, text, Column};
 use iced::Center;
 
 pub fn main() -> iced::Result {
-    iced::run("A cool counter", Counter::update, Counter::view)
+    let counter =
+        iced::application("A cool counter", Counter::update, Counter::view);
+
+    #[cfg(feature = "time-travel")]
+    let counter = counter.record();
+
+    counter.run()
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 struct Counter {
     value: i64,
 }
@@ -0,0 +1,174 @@
+use iced::advanced::text::highlighter::{self, Highlighter};
+use iced::font::{self, Font};
+use iced::widget::{column, text, text_editor};
+use iced::{Color, Element, Fill, Theme};
+
+use std::ops::Range;
+
+pub fn main() -> iced::Result {
+    iced::application(
+        "Custom Highlighter - Iced",
+        Example::update,
+        Example::view,
+    )
+    .default_font(Font::MONOSPACE)
+    .run()
+}
+
+struct Example {
+    content: text_editor::Content,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ActionPerformed(text_editor::Action),
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        let snippet = "\
+// Adds two numbers
+fn add(a: i32, b: i32) -> i32 {
+    let sum = a + b;
+    return sum;
+}
+";
+
+        Self {
+            content: text_editor::Content::with_text(&snippet.repeat(2000)),
+        }
+    }
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ActionPerformed(action) => {
+                self.content.perform(action);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let status = text!("{} lines", self.content.line_count()).size(14);
+
+        column![
+            text_editor(&self.content)
+                .height(Fill)
+                .on_action(Message::ActionPerformed)
+                .highlight::<Keywords>(KEYWORDS, to_format),
+            status,
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+const KEYWORDS: &[&str] = &["fn", "let", "return", "if", "else", "for"];
+
+/// A highlighter that highlights some keywords, numbers, and comments.
+///
+/// Every line is highlighted on its own; so the [`Keywords`] highlighter
+/// only needs to remember the line it is at.
+struct Keywords {
+    keywords: &'static [&'static str],
+    current_line: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Keyword,
+    Number,
+    Comment,
+}
+
+impl Highlighter for Keywords {
+    type Settings = &'static [&'static str];
+    type Highlight = Kind;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Kind)>;
+
+    fn new(keywords: &Self::Settings) -> Self {
+        Self {
+            keywords: *keywords,
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_keywords: &Self::Settings) {
+        self.keywords = *new_keywords;
+        self.current_line = 0;
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        self.current_line += 1;
+
+        let (code, comment) = match line.find("//") {
+            Some(start) => (&line[..start], Some(start..line.len())),
+            None => (line, None),
+        };
+
+        let mut highlights = Vec::new();
+        let mut start = None;
+
+        for (i, c) in code.char_indices().chain([(code.len(), ' ')]) {
+            match start {
+                None if c.is_alphanumeric() || c == '_' => {
+                    start = Some(i);
+                }
+                Some(word_start) if !(c.is_alphanumeric() || c == '_') => {
+                    let word = &code[word_start..i];
+
+                    if self.keywords.contains(&word) {
+                        highlights.push((word_start..i, Kind::Keyword));
+                    } else if word.chars().all(|c| c.is_ascii_digit()) {
+                        highlights.push((word_start..i, Kind::Number));
+                    }
+
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        highlights.extend(comment.map(|range| (range, Kind::Comment)));
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+fn to_format(kind: &Kind, theme: &Theme) -> highlighter::Format<Font> {
+    let palette = theme.extended_palette();
+
+    match kind {
+        Kind::Keyword => highlighter::Format {
+            color: Some(palette.primary.strong.color),
+            font: Some(Font {
+                weight: font::Weight::Bold,
+                ..Font::MONOSPACE
+            }),
+        },
+        Kind::Number => highlighter::Format {
+            color: Some(palette.success.base.color),
+            font: None,
+        },
+        Kind::Comment => highlighter::Format {
+            color: Some(Color {
+                a: 0.6,
+                ..palette.background.base.text
+            }),
+            font: Some(Font {
+                style: font::Style::Italic,
+                ..Font::MONOSPACE
+            }),
+        },
+    }
+}
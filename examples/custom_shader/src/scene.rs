@@ -148,6 +148,9 @@ impl shader::Primitive for Primitive {
 
         let pipeline = storage.get_mut::<Pipeline>().unwrap();
 
+        // Pick up any changes to the WGSL source (debug builds only)
+        pipeline.reload(device);
+
         // Upload data to GPU
         pipeline.update(
             device,
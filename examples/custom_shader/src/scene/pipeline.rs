@@ -12,12 +12,16 @@ use vertex::Vertex;
 use crate::wgpu;
 use crate::wgpu::util::DeviceExt;
 
+use iced::widget::shader;
 use iced::{Rectangle, Size};
 
 const SKY_TEXTURE_SIZE: u32 = 128;
 
 pub struct Pipeline {
     pipeline: wgpu::RenderPipeline,
+    layout: wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    shader: shader::Module,
     vertices: wgpu::Buffer,
     cubes: Buffer,
     uniforms: wgpu::Buffer,
@@ -227,58 +231,22 @@ impl Pipeline {
                 push_constant_ranges: &[],
             });
 
-        let shader =
-            device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                label: Some("cubes shader"),
-                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
-                    include_str!("../shaders/cubes.wgsl"),
-                )),
-            });
+        // In debug builds, the shader is reloaded every time its file changes
+        let shader = shader::Module::new(
+            "cubes shader",
+            include_str!("../shaders/cubes.wgsl"),
+        )
+        .watch(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/shaders/cubes.wgsl"
+        ));
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("cubes pipeline"),
-                layout: Some(&layout),
-                vertex: wgpu::VertexState {
-                    module: &shader,
-                    entry_point: "vs_main",
-                    buffers: &[Vertex::desc(), cube::Raw::desc()],
-                },
-                primitive: wgpu::PrimitiveState::default(),
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Less,
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
-                multisample: wgpu::MultisampleState {
-                    count: 1,
-                    mask: !0,
-                    alpha_to_coverage_enabled: false,
-                },
-                fragment: Some(wgpu::FragmentState {
-                    module: &shader,
-                    entry_point: "fs_main",
-                    targets: &[Some(wgpu::ColorTargetState {
-                        format,
-                        blend: Some(wgpu::BlendState {
-                            color: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::SrcAlpha,
-                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                                operation: wgpu::BlendOperation::Add,
-                            },
-                            alpha: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::One,
-                                dst_factor: wgpu::BlendFactor::One,
-                                operation: wgpu::BlendOperation::Max,
-                            },
-                        }),
-                        write_mask: wgpu::ColorWrites::ALL,
-                    })],
-                }),
-                multiview: None,
-            });
+        let pipeline = Self::create_pipeline(
+            device,
+            &layout,
+            format,
+            &shader.create(device),
+        );
 
         let depth_pipeline = DepthPipeline::new(
             device,
@@ -288,6 +256,9 @@ impl Pipeline {
 
         Self {
             pipeline,
+            layout,
+            format,
+            shader,
             cubes: cubes_buffer,
             uniforms,
             uniform_bind_group,
@@ -326,6 +297,73 @@ impl Pipeline {
         }
     }
 
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+        shader: &wgpu::ShaderModule,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("cubes pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::desc(), cube::Raw::desc()],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::SrcAlpha,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One,
+                            dst_factor: wgpu::BlendFactor::One,
+                            operation: wgpu::BlendOperation::Max,
+                        },
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+        })
+    }
+
+    pub fn reload(&mut self, device: &wgpu::Device) {
+        let Self {
+            pipeline,
+            layout,
+            format,
+            shader,
+            ..
+        } = self;
+
+        if let Some(reloaded) = shader.reload(device, |module| {
+            Self::create_pipeline(device, layout, *format, module)
+        }) {
+            *pipeline = reloaded;
+        }
+    }
+
     pub fn update(
         &mut self,
         device: &wgpu::Device,
@@ -0,0 +1,132 @@
+use iced::time::Duration;
+use iced::widget::swipeable::Side;
+use iced::widget::{
+    center, column, container, easing, keyed_column, scrollable, swipeable,
+    text,
+};
+use iced::{Center, Element, Fill, Theme};
+
+pub fn main() -> iced::Result {
+    iced::application("Deletable List - Iced", List::update, List::view)
+        .theme(|_| Theme::Dark)
+        .run()
+}
+
+struct List {
+    messages: Vec<Message>,
+    archived: usize,
+    deleted: usize,
+}
+
+#[derive(Debug)]
+struct Message {
+    id: usize,
+    subject: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    Swiped(usize, Side),
+}
+
+impl List {
+    fn update(&mut self, action: Action) {
+        match action {
+            Action::Swiped(id, side) => {
+                self.messages.retain(|message| message.id != id);
+
+                match side {
+                    Side::Leading => self.archived += 1,
+                    Side::Trailing => self.deleted += 1,
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Action> {
+        let summary =
+            text!("{} archived, {} deleted", self.archived, self.deleted)
+                .style(text::secondary);
+
+        if self.messages.is_empty() {
+            return center(
+                column![text("Inbox zero!").size(30), summary]
+                    .spacing(10)
+                    .align_x(Center),
+            )
+            .into();
+        }
+
+        let messages = keyed_column(self.messages.iter().map(|message| {
+            let entry = swipeable(
+                container(text(&message.subject))
+                    .padding(20)
+                    .width(Fill)
+                    .style(item),
+            )
+            .leading(action("Archive", Side::Leading))
+            .trailing(action("Delete", Side::Trailing))
+            .on_action(move |side| Action::Swiped(message.id, side));
+
+            (message.id, entry.into())
+        }))
+        .spacing(1)
+        .animate_layout(Duration::from_millis(250), easing::STANDARD);
+
+        column![
+            text("Swipe left to delete, right to archive").size(20),
+            summary,
+            scrollable(messages).height(Fill),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
+
+impl Default for List {
+    fn default() -> Self {
+        Self {
+            messages: (0..30)
+                .map(|id| Message {
+                    id,
+                    subject: format!("Message #{}", id + 1),
+                })
+                .collect(),
+            archived: 0,
+            deleted: 0,
+        }
+    }
+}
+
+fn action<'a>(label: &'a str, side: Side) -> Element<'a, Action> {
+    container(text(label))
+        .padding([0, 20])
+        .height(Fill)
+        .align_y(Center)
+        .style(move |theme: &Theme| {
+            let palette = theme.extended_palette();
+
+            let pair = match side {
+                Side::Leading => palette.success.base,
+                Side::Trailing => palette.danger.base,
+            };
+
+            container::Style {
+                background: Some(pair.color.into()),
+                text_color: Some(pair.text),
+                ..container::Style::default()
+            }
+        })
+        .into()
+}
+
+fn item(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+
+    container::Style {
+        background: Some(palette.background.weak.color.into()),
+        text_color: Some(palette.background.weak.text),
+        ..container::Style::default()
+    }
+}
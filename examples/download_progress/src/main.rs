@@ -1,7 +1,8 @@
 mod download;
 
 use iced::widget::{button, center, column, progress_bar, text, Column};
-use iced::{Center, Element, Right, Subscription};
+use iced::window;
+use iced::{Center, Element, Right, Subscription, Task};
 
 pub fn main() -> iced::Result {
     iced::application(
@@ -17,6 +18,14 @@ pub fn main() -> iced::Result {
 struct Example {
     downloads: Vec<Download>,
     last_id: usize,
+    taskbar: Taskbar,
+}
+
+/// The progress and badge last shown on the taskbar or dock icon.
+#[derive(Debug, Default, PartialEq)]
+struct Taskbar {
+    percentage: Option<u32>,
+    active: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -31,10 +40,11 @@ impl Example {
         Self {
             downloads: vec![Download::new(0)],
             last_id: 0,
+            taskbar: Taskbar::default(),
         }
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::Add => {
                 self.last_id += 1;
@@ -54,6 +64,46 @@ impl Example {
                 }
             }
         }
+
+        self.update_taskbar()
+    }
+
+    /// Shows the overall progress of the active downloads on the taskbar or
+    /// dock icon, together with their count as a badge.
+    fn update_taskbar(&mut self) -> Task<Message> {
+        let active: Vec<f32> = self
+            .downloads
+            .iter()
+            .filter_map(|download| match download.state {
+                State::Downloading { progress } => Some(progress),
+                _ => None,
+            })
+            .collect();
+
+        let progress = (!active.is_empty())
+            .then(|| active.iter().sum::<f32>() / active.len() as f32 / 100.0);
+
+        let taskbar = Taskbar {
+            // Avoid flooding the platform with updates by only
+            // notifying it when the whole percentage changes
+            percentage: progress.map(|progress| (progress * 100.0) as u32),
+            active: active.len(),
+        };
+
+        if taskbar == self.taskbar {
+            return Task::none();
+        }
+
+        self.taskbar = taskbar;
+
+        let badge = (!active.is_empty()).then(|| active.len().to_string());
+
+        Task::batch([
+            window::get_latest()
+                .and_then(move |id| window::set_progress(id, progress)),
+            window::set_badge(badge),
+        ])
+        .then(|_| Task::none())
     }
 
     fn subscription(&self) -> Subscription<Message> {
@@ -151,7 +201,8 @@ impl Download {
             State::Errored { .. } => 0.0,
         };
 
-        let progress_bar = progress_bar(0.0..=100.0, current_progress);
+        let progress_bar = progress_bar(0.0..=100.0, current_progress)
+            .label(|progress| format!("{progress:.0}%"));
 
         let control: Element<_> = match &self.state {
             State::Idle => button("Start the download!")
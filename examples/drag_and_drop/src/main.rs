@@ -0,0 +1,130 @@
+use iced::widget::{
+    center, column, container, drag_source, drop_target, row, text,
+};
+use iced::{Border, Element, Fill, Theme};
+
+pub fn main() -> iced::Result {
+    iced::run("Drag and Drop - Iced", Contacts::update, Contacts::view)
+}
+
+struct Contacts {
+    contacts: Vec<&'static str>,
+    groups: Vec<Group>,
+    hovered: Option<usize>,
+}
+
+struct Group {
+    name: &'static str,
+    members: Vec<usize>,
+}
+
+/// The payload dragged around: the index of a contact.
+#[derive(Debug, Clone, Copy)]
+struct Contact(usize);
+
+#[derive(Debug, Clone)]
+enum Message {
+    Dropped { group: usize, contact: Contact },
+    Entered(usize),
+    Exited(usize),
+}
+
+impl Contacts {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Dropped {
+                group,
+                contact: Contact(contact),
+            } => {
+                let members = &mut self.groups[group].members;
+
+                if !members.contains(&contact) {
+                    members.push(contact);
+                }
+            }
+            Message::Entered(group) => {
+                self.hovered = Some(group);
+            }
+            Message::Exited(group) => {
+                if self.hovered == Some(group) {
+                    self.hovered = None;
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let contacts =
+            column(self.contacts.iter().enumerate().map(|(i, name)| {
+                drag_source(
+                    container(text(*name))
+                        .padding(10)
+                        .width(200)
+                        .style(container::rounded_box),
+                    Contact(i),
+                )
+                .into()
+            }))
+            .spacing(10);
+
+        let groups = row(self.groups.iter().enumerate().map(|(i, group)| {
+            let is_hovered = self.hovered == Some(i);
+
+            let members = column(
+                group
+                    .members
+                    .iter()
+                    .map(|&member| text(self.contacts[member]).into()),
+            )
+            .spacing(5);
+
+            drop_target(
+                container(
+                    column![text(group.name).size(20), members].spacing(10),
+                )
+                .padding(10)
+                .width(200)
+                .height(Fill)
+                .style(move |theme: &Theme| {
+                    let style = container::bordered_box(theme);
+
+                    if is_hovered {
+                        style.border(Border {
+                            color: theme.palette().primary,
+                            width: 2.0,
+                            ..style.border
+                        })
+                    } else {
+                        style
+                    }
+                }),
+            )
+            .on_drop(move |contact| Message::Dropped { group: i, contact })
+            .on_enter(Message::Entered(i))
+            .on_exit(Message::Exited(i))
+            .into()
+        }))
+        .spacing(10);
+
+        center(row![contacts, groups].spacing(40).height(400)).into()
+    }
+}
+
+impl Default for Contacts {
+    fn default() -> Self {
+        Self {
+            contacts: vec!["Alice", "Bob", "Carol", "Dave"],
+            groups: vec![
+                Group {
+                    name: "Family",
+                    members: Vec::new(),
+                },
+                Group {
+                    name: "Work",
+                    members: Vec::new(),
+                },
+            ],
+            hovered: None,
+        }
+    }
+}
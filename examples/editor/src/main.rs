@@ -1,10 +1,11 @@
 use iced::highlighter::{self, Highlighter};
 use iced::keyboard;
 use iced::widget::{
-    button, column, container, horizontal_space, pick_list, row, text,
-    text_editor, tooltip,
+    button, center, column, container, horizontal_space, mouse_area, opaque,
+    pick_list, row, stack, text, text_editor, tooltip,
 };
-use iced::{Center, Element, Fill, Font, Subscription, Task, Theme};
+use iced::window;
+use iced::{Center, Color, Element, Fill, Font, Subscription, Task, Theme};
 
 use std::ffi;
 use std::io;
@@ -17,6 +18,7 @@ pub fn main() -> iced::Result {
         .theme(Editor::theme)
         .font(include_bytes!("../fonts/icons.ttf").as_slice())
         .default_font(Font::MONOSPACE)
+        .exit_on_close_request(false)
         .run_with(Editor::new)
 }
 
@@ -26,6 +28,15 @@ struct Editor {
     theme: highlighter::Theme,
     is_loading: bool,
     is_dirty: bool,
+    closing: Option<Closing>,
+}
+
+/// A close request waiting for the user to decide what to do with the
+/// unsaved changes.
+#[derive(Debug, Clone, Copy)]
+struct Closing {
+    window: window::Id,
+    is_saving: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +48,10 @@ enum Message {
     FileOpened(Result<(PathBuf, Arc<String>), Error>),
     SaveFile,
     FileSaved(Result<PathBuf, Error>),
+    CloseRequested(window::Id),
+    SaveAndClose,
+    DiscardAndClose,
+    CancelClose,
 }
 
 impl Editor {
@@ -48,6 +63,7 @@ impl Editor {
                 theme: highlighter::Theme::SolarizedDark,
                 is_loading: true,
                 is_dirty: false,
+                closing: None,
             },
             Task::perform(
                 load_file(format!(
@@ -121,18 +137,80 @@ impl Editor {
                     self.is_dirty = false;
                 }
 
+                match self.closing.take() {
+                    Some(closing) if closing.is_saving && !self.is_dirty => {
+                        window::close(closing.window)
+                    }
+                    // The file could not be saved; so we ask again
+                    Some(closing) => {
+                        self.closing = Some(Closing {
+                            is_saving: false,
+                            ..closing
+                        });
+
+                        Task::none()
+                    }
+                    None => Task::none(),
+                }
+            }
+            Message::CloseRequested(window) => {
+                if !self.is_dirty {
+                    return window::close(window);
+                }
+
+                // The user may request to close the window again while the
+                // dialog is open; it must not be opened twice
+                if self.closing.is_none() {
+                    self.closing = Some(Closing {
+                        window,
+                        is_saving: false,
+                    });
+                }
+
+                Task::none()
+            }
+            Message::SaveAndClose => {
+                let Some(closing) = &mut self.closing else {
+                    return Task::none();
+                };
+
+                if self.is_loading || closing.is_saving {
+                    Task::none()
+                } else {
+                    self.is_loading = true;
+                    closing.is_saving = true;
+
+                    Task::perform(
+                        save_file(self.file.clone(), self.content.text()),
+                        Message::FileSaved,
+                    )
+                }
+            }
+            Message::DiscardAndClose => match self.closing.take() {
+                Some(closing) => window::close(closing.window),
+                None => Task::none(),
+            },
+            Message::CancelClose => {
+                if !self.closing.is_some_and(|closing| closing.is_saving) {
+                    self.closing = None;
+                }
+
                 Task::none()
             }
         }
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        keyboard::on_key_press(|key, modifiers| match key.as_ref() {
-            keyboard::Key::Character("s") if modifiers.command() => {
-                Some(Message::SaveFile)
-            }
-            _ => None,
-        })
+        Subscription::batch([
+            keyboard::on_key_press(|key, modifiers| match key.as_ref() {
+                keyboard::Key::Character("s") if modifiers.command() => {
+                    Some(Message::SaveFile)
+                }
+                _ => None,
+            }),
+            window::close_requests()
+                .map(|(window, _request)| Message::CloseRequested(window)),
+        ])
     }
 
     fn view(&self) -> Element<Message> {
@@ -181,7 +259,7 @@ impl Editor {
         ]
         .spacing(10);
 
-        column![
+        let editor = column![
             controls,
             text_editor(&self.content)
                 .height(Fill)
@@ -202,8 +280,13 @@ impl Editor {
             status,
         ]
         .spacing(10)
-        .padding(10)
-        .into()
+        .padding(10);
+
+        if let Some(closing) = self.closing {
+            modal(editor, unsaved_changes(closing), Message::CancelClose)
+        } else {
+            editor.into()
+        }
     }
 
     fn theme(&self) -> Theme {
@@ -287,6 +370,56 @@ fn action<'a, Message: Clone + 'a>(
     }
 }
 
+fn unsaved_changes<'a>(closing: Closing) -> Element<'a, Message> {
+    let choice = |label, message: Message| {
+        button(text(label).width(Fill).align_x(Center))
+            .width(100)
+            .on_press_maybe((!closing.is_saving).then_some(message))
+    };
+
+    container(
+        column![
+            text("Save changes before closing?").size(20),
+            text("Your changes will be lost if you don't save them."),
+            row![
+                choice("Save", Message::SaveAndClose),
+                choice("Discard", Message::DiscardAndClose)
+                    .style(button::danger),
+                choice("Cancel", Message::CancelClose).style(button::secondary),
+            ]
+            .spacing(10),
+        ]
+        .spacing(20),
+    )
+    .padding(20)
+    .style(container::rounded_box)
+    .into()
+}
+
+fn modal<'a>(
+    base: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+    on_blur: Message,
+) -> Element<'a, Message> {
+    stack![
+        base.into(),
+        mouse_area(center(opaque(content)).style(|_theme| {
+            container::Style {
+                background: Some(
+                    Color {
+                        a: 0.8,
+                        ..Color::BLACK
+                    }
+                    .into(),
+                ),
+                ..container::Style::default()
+            }
+        }))
+        .on_press(on_blur)
+    ]
+    .into()
+}
+
 fn new_icon<'a, Message>() -> Element<'a, Message> {
     icon('\u{0e800}')
 }
@@ -36,7 +36,9 @@ impl Events {
                 Task::none()
             }
             Message::EventOccurred(event) => {
-                if let Event::Window(window::Event::CloseRequested) = event {
+                if let Event::Window(window::Event::CloseRequested { .. }) =
+                    event
+                {
                     window::get_latest().and_then(window::close)
                 } else {
                     Task::none()
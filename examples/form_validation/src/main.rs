@@ -0,0 +1,224 @@
+use iced::keyboard;
+use iced::widget::{
+    self, button, center, column, text, text_editor, text_input,
+};
+use iced::{Element, Fill, Subscription, Task};
+
+pub fn main() -> iced::Result {
+    iced::application("Form Validation - Iced", Form::update, Form::view)
+        .subscription(Form::subscription)
+        .run()
+}
+
+#[derive(Default)]
+struct Form {
+    name: String,
+    email: String,
+    password: String,
+    bio: text_editor::Content,
+    touched: Vec<Field>,
+    focused: Option<Field>,
+    is_submitted: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Name,
+    Email,
+    Password,
+    Bio,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edited(Field, String),
+    BioEdited(text_editor::Action),
+    Focused(Field),
+    Blurred(Field),
+    Tab { backwards: bool },
+    Submit,
+}
+
+impl Form {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Edited(field, value) => {
+                let target = match field {
+                    Field::Name => &mut self.name,
+                    Field::Email => &mut self.email,
+                    Field::Password => &mut self.password,
+                    Field::Bio => return Task::none(),
+                };
+
+                *target = value;
+
+                self.is_submitted = false;
+
+                Task::none()
+            }
+            Message::BioEdited(action) => {
+                self.bio.perform(action);
+                self.is_submitted = false;
+
+                Task::none()
+            }
+            Message::Focused(field) => {
+                self.focused = Some(field);
+
+                Task::none()
+            }
+            Message::Blurred(field) => {
+                if self.focused == Some(field) {
+                    self.focused = None;
+                }
+
+                self.touch(field);
+
+                Task::none()
+            }
+            Message::Tab { backwards } => {
+                if backwards {
+                    widget::focus_previous()
+                } else {
+                    widget::focus_next()
+                }
+            }
+            Message::Submit => {
+                for field in Field::ALL {
+                    self.touch(field);
+                }
+
+                self.is_submitted =
+                    Field::ALL.iter().all(|field| self.error(*field).is_none());
+
+                Task::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let input = |field: Field, placeholder, value| {
+            text_input(placeholder, value)
+                .on_input(move |value| Message::Edited(field, value))
+                .on_focus(Message::Focused(field))
+                .on_blur(Message::Blurred(field))
+                .on_submit(Message::Submit)
+                .secure(field == Field::Password)
+                .padding(10)
+        };
+
+        let bio = text_editor(&self.bio)
+            .on_action(Message::BioEdited)
+            .on_focus(Message::Focused(Field::Bio))
+            .on_blur(Message::Blurred(Field::Bio))
+            .height(120)
+            .padding(10);
+
+        let status = if self.is_submitted {
+            text("Welcome aboard!").style(text::success)
+        } else if let Some(field) = self.focused {
+            text!("Editing {}", field.label().to_lowercase())
+                .style(text::secondary)
+        } else {
+            text("Tab through the fields to validate them")
+                .style(text::secondary)
+        };
+
+        let form = column![
+            text("Sign up").size(30),
+            self.field(Field::Name, input(Field::Name, "Ferris", &self.name)),
+            self.field(
+                Field::Email,
+                input(Field::Email, "ferris@iced.rs", &self.email)
+            ),
+            self.field(
+                Field::Password,
+                input(Field::Password, "At least 8 characters", &self.password)
+            ),
+            self.field(Field::Bio, bio),
+            button("Submit").on_press(Message::Submit).padding([10, 20]),
+            status,
+        ]
+        .spacing(20)
+        .max_width(400);
+
+        center(form).padding(20).into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        keyboard::on_key_press(|key, modifiers| match key {
+            keyboard::Key::Named(keyboard::key::Named::Tab) => {
+                Some(Message::Tab {
+                    backwards: modifiers.shift(),
+                })
+            }
+            _ => None,
+        })
+    }
+
+    fn field<'a>(
+        &self,
+        field: Field,
+        input: impl Into<Element<'a, Message>>,
+    ) -> Element<'a, Message> {
+        let error = self
+            .touched
+            .contains(&field)
+            .then(|| self.error(field))
+            .flatten();
+
+        column![text(field.label()).size(14), input.into()]
+            .push_maybe(
+                error.map(|error| text(error).size(14).style(text::danger)),
+            )
+            .spacing(5)
+            .width(Fill)
+            .into()
+    }
+
+    fn touch(&mut self, field: Field) {
+        if !self.touched.contains(&field) {
+            self.touched.push(field);
+        }
+    }
+
+    fn error(&self, field: Field) -> Option<&'static str> {
+        match field {
+            Field::Name => self
+                .name
+                .trim()
+                .is_empty()
+                .then_some("Please, tell us your name."),
+            Field::Email => {
+                let is_valid =
+                    self.email.split_once('@').is_some_and(|(user, domain)| {
+                        !user.is_empty()
+                            && domain.split_once('.').is_some_and(
+                                |(name, tld)| {
+                                    !name.is_empty() && !tld.is_empty()
+                                },
+                            )
+                    });
+
+                (!is_valid).then_some("This does not look like an email.")
+            }
+            Field::Password => (self.password.chars().count() < 8)
+                .then_some("The password must have at least 8 characters."),
+            Field::Bio => (self.bio.text().chars().count() > 200)
+                .then_some("Please, keep it under 200 characters."),
+        }
+    }
+}
+
+impl Field {
+    const ALL: [Self; 4] = [Self::Name, Self::Email, Self::Password, Self::Bio];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Email => "Email",
+            Self::Password => "Password",
+            Self::Bio => "About you",
+        }
+    }
+}
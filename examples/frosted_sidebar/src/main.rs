@@ -0,0 +1,101 @@
+use iced::application;
+use iced::widget::{button, column, container, row, text};
+use iced::window;
+use iced::{Color, Element, Fill, Theme};
+
+pub fn main() -> iced::Result {
+    iced::application("Frosted Sidebar - Iced", Notes::update, Notes::view)
+        .style(Notes::style)
+        .backdrop(window::Backdrop::Blur)
+        .run()
+}
+
+#[derive(Default)]
+struct Notes {
+    selected: Section,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Section {
+    #[default]
+    Inbox,
+    Drafts,
+    Archive,
+    Trash,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Selected(Section),
+}
+
+impl Notes {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(section) => {
+                self.selected = section;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let sidebar = container(
+            column(Section::ALL.into_iter().map(|section| {
+                button(text(section.label()))
+                    .on_press(Message::Selected(section))
+                    .style(if section == self.selected {
+                        button::primary
+                    } else {
+                        button::text
+                    })
+                    .width(Fill)
+                    .into()
+            }))
+            .spacing(5),
+        )
+        .padding(10)
+        .width(200)
+        .height(Fill)
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+
+            container::Style::default()
+                .background(palette.background.base.color.scale_alpha(0.4))
+        });
+
+        let content = container(
+            column![
+                text(self.selected.label()).size(30),
+                text("The sidebar lets the desktop show through, blurred.")
+            ]
+            .spacing(10),
+        )
+        .padding(20)
+        .width(Fill)
+        .height(Fill)
+        .style(container::rounded_box);
+
+        row![sidebar, content].into()
+    }
+
+    fn style(&self, theme: &Theme) -> application::Appearance {
+        application::Appearance {
+            background_color: Color::TRANSPARENT,
+            text_color: theme.palette().text,
+        }
+    }
+}
+
+impl Section {
+    const ALL: [Self; 4] =
+        [Self::Inbox, Self::Drafts, Self::Archive, Self::Trash];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Inbox => "Inbox",
+            Self::Drafts => "Drafts",
+            Self::Archive => "Archive",
+            Self::Trash => "Trash",
+        }
+    }
+}
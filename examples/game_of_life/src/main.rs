@@ -516,6 +516,7 @@ mod grid {
             _interaction: &Interaction,
             renderer: &Renderer,
             _theme: &Theme,
+            style: &canvas::Style,
             bounds: Rectangle,
             cursor: mouse::Cursor,
         ) -> Vec<Geometry> {
@@ -523,7 +524,7 @@ mod grid {
 
             let life = self.life_cache.draw(renderer, bounds.size(), |frame| {
                 let background = Path::rectangle(Point::ORIGIN, frame.size());
-                frame.fill(&background, Color::from_rgb8(0x40, 0x44, 0x4B));
+                frame.fill(&background, style.background.weak.color);
 
                 frame.with_save(|frame| {
                     frame.translate(center);
@@ -537,7 +538,7 @@ mod grid {
                         frame.fill_rectangle(
                             Point::new(cell.j as f32, cell.i as f32),
                             Size::UNIT,
-                            Color::WHITE,
+                            style.text,
                         );
                     }
                 });
@@ -562,14 +563,14 @@ mod grid {
                             Size::UNIT,
                             Color {
                                 a: 0.5,
-                                ..Color::BLACK
+                                ..style.primary.base.color
                             },
                         );
                     });
                 }
 
                 let text = Text {
-                    color: Color::WHITE,
+                    color: style.text,
                     size: 14.0.into(),
                     position: Point::new(frame.width(), frame.height()),
                     horizontal_alignment: alignment::Horizontal::Right,
@@ -614,7 +615,7 @@ mod grid {
                         let (total_rows, total_columns) =
                             (rows.clone().count(), columns.clone().count());
                         let width = 2.0 / Cell::SIZE as f32;
-                        let color = Color::from_rgb8(70, 74, 83);
+                        let color = style.background.strong.color;
 
                         frame
                             .translate(Vector::new(-width / 2.0, -width / 2.0));
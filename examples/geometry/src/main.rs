@@ -134,6 +134,7 @@ mod rainbow {
                 },
                 transformation: Transformation::IDENTITY,
                 clip_bounds: Rectangle::INFINITE,
+                blend: mesh::Blend::Alpha,
             };
 
             renderer.with_translation(
@@ -0,0 +1,124 @@
+use iced::gradient;
+use iced::mouse;
+use iced::widget::canvas::{self, Canvas, Geometry, Path, Stroke};
+use iced::widget::{column, container, row, slider, text};
+use iced::{
+    Center, Color, Element, Fill, Point, Radians, Rectangle, Renderer, Size,
+    Theme,
+};
+
+pub fn main() -> iced::Result {
+    iced::application("Glow - Iced", Glow::update, Glow::view)
+        .theme(|_| Theme::Dark)
+        .antialiasing(true)
+        .hdr(true)
+        .run()
+}
+
+/// The color of the sign, before any intensity is applied.
+const NEON: Color = Color::from_rgb(1.0, 0.2, 0.6);
+
+struct Glow {
+    intensity: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    IntensityChanged(f32),
+}
+
+impl Glow {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::IntensityChanged(intensity) => {
+                self.intensity = intensity;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let intensity = self.intensity;
+
+        let sign = Canvas::new(self).width(Fill).height(Fill);
+
+        let bar = container("").width(Fill).height(40).style(move |_theme| {
+            gradient::Linear::new(Radians::PI / 2.0)
+                .add_stop(0.0, Color::BLACK)
+                .add_stop(0.5, NEON.with_intensity(intensity))
+                .add_stop(1.0, Color::BLACK)
+                .into()
+        });
+
+        let controls = row![
+            text("Intensity"),
+            slider(1.0..=8.0, self.intensity, Message::IntensityChanged)
+                .step(0.1)
+                .width(200),
+            text!("{:.1}x", self.intensity),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        column![sign, bar, container(controls).center_x(Fill)]
+            .padding(20)
+            .spacing(20)
+            .into()
+    }
+}
+
+impl Default for Glow {
+    fn default() -> Self {
+        Self { intensity: 4.0 }
+    }
+}
+
+impl<Message> canvas::Program<Message> for Glow {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        _style: &canvas::Style,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        let center = frame.center();
+        let radius = frame.width().min(frame.height()) * 0.3;
+        let ring = Path::circle(center, radius);
+
+        // Halo
+        for i in (1..=6).rev() {
+            let spread = i as f32;
+
+            frame.stroke(
+                &ring,
+                Stroke::default()
+                    .with_width(4.0 + spread * 6.0)
+                    .with_color(NEON.scale_alpha(0.04 * (7.0 - spread))),
+            );
+        }
+
+        // Tube
+        frame.stroke(
+            &ring,
+            Stroke::default()
+                .with_width(4.0)
+                .with_color(NEON.with_intensity(self.intensity)),
+        );
+
+        // Reflection on the floor
+        let floor = center.y + radius * 1.3;
+
+        frame.fill_rectangle(
+            Point::new(0.0, floor),
+            Size::new(frame.width(), 2.0),
+            NEON.with_intensity(self.intensity / 4.0).scale_alpha(0.5),
+        );
+
+        vec![frame.into_geometry()]
+    }
+}
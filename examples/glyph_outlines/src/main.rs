@@ -0,0 +1,130 @@
+use iced::alignment;
+use iced::mouse;
+use iced::time::Instant;
+use iced::widget::canvas::{self, stroke, Canvas, Geometry, Path, Stroke};
+use iced::widget::text;
+use iced::window;
+use iced::{
+    Element, Fill, Point, Rectangle, Renderer, Subscription, Theme, Vector,
+};
+
+pub fn main() -> iced::Result {
+    iced::application(
+        "Glyph Outlines - Iced",
+        GlyphOutlines::update,
+        GlyphOutlines::view,
+    )
+    .subscription(GlyphOutlines::subscription)
+    .theme(|_| Theme::Dark)
+    .antialiasing(true)
+    .run()
+}
+
+/// The duration of a full drawing cycle, in seconds.
+const CYCLE: f32 = 4.0;
+
+/// The length of the dash used to draw the outlines.
+///
+/// It must be larger than the perimeter of any glyph in the headline.
+const DASH: f32 = 1_000.0;
+
+struct GlyphOutlines {
+    start: Instant,
+    now: Instant,
+    outlines: Vec<Path>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Tick(Instant),
+}
+
+impl GlyphOutlines {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tick(now) => {
+                self.now = now;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        Canvas::new(self).width(Fill).height(Fill).into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Tick)
+    }
+}
+
+impl Default for GlyphOutlines {
+    fn default() -> Self {
+        let outlines = canvas::Text {
+            content: String::from("Iced ffi"),
+            size: 120.0.into(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            ..canvas::Text::default()
+        }
+        .to_paths();
+
+        let now = Instant::now();
+
+        Self {
+            start: now,
+            now,
+            outlines,
+        }
+    }
+}
+
+impl<Message> canvas::Program<Message> for GlyphOutlines {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        _style: &canvas::Style,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let palette = theme.palette();
+
+        let progress = ((self.now - self.start).as_secs_f32() % CYCLE) / CYCLE;
+        let drawn = DASH * progress;
+
+        let center = frame.center();
+        frame.translate(Vector::new(center.x, center.y));
+
+        for outline in &self.outlines {
+            frame.stroke(
+                outline,
+                Stroke {
+                    style: stroke::Style::Solid(palette.primary),
+                    width: 2.0,
+                    line_dash: stroke::LineDash {
+                        segments: &[drawn, DASH],
+                        offset: 0.0,
+                    },
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        let label = canvas::Text {
+            content: format!("{} glyph outlines", self.outlines.len()),
+            position: Point::new(0.0, 100.0),
+            color: palette.text,
+            horizontal_alignment: alignment::Horizontal::Center,
+            ..canvas::Text::default()
+        };
+
+        frame.fill_text(label);
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,216 @@
+use iced::widget::{
+    button, center, checkbox, column, pick_list, row, text, text_input,
+};
+use iced::{Center, Element, Task};
+
+use std::fmt;
+
+pub fn main() -> iced::Result {
+    iced::application(Form::title, Form::update, Form::view).run()
+}
+
+#[derive(Default)]
+struct Form {
+    language: Language,
+    name: String,
+    subscribe: bool,
+    submitted: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    LanguageSelected(Language),
+    NameChanged(String),
+    SubscribeToggled(bool),
+    Submit,
+    Reset,
+}
+
+impl Form {
+    fn title(&self) -> String {
+        format!("{} - Iced", self.tr(Key::Title))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::LanguageSelected(language) => {
+                self.language = language;
+
+                text::invalidate_all()
+            }
+            Message::NameChanged(name) => {
+                self.name = name;
+                self.submitted = false;
+
+                Task::none()
+            }
+            Message::SubscribeToggled(subscribe) => {
+                self.subscribe = subscribe;
+
+                Task::none()
+            }
+            Message::Submit => {
+                self.submitted = true;
+
+                Task::none()
+            }
+            Message::Reset => {
+                *self = Self {
+                    language: self.language,
+                    ..Self::default()
+                };
+
+                Task::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let language = row![
+            text(self.tr(Key::Language)),
+            pick_list(
+                Language::ALL,
+                Some(self.language),
+                Message::LanguageSelected
+            ),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let name = text_input(self.tr(Key::Name), &self.name)
+            .on_input(Message::NameChanged)
+            .on_submit(Message::Submit)
+            .padding(10);
+
+        let subscribe = checkbox(self.tr(Key::Subscribe), self.subscribe)
+            .on_toggle(Message::SubscribeToggled);
+
+        let actions = row![
+            button(self.tr(Key::Submit)).on_press_maybe(
+                (!self.name.is_empty()).then_some(Message::Submit)
+            ),
+            button(self.tr(Key::Reset))
+                .on_press(Message::Reset)
+                .style(button::secondary),
+        ]
+        .spacing(10);
+
+        let status = if self.submitted {
+            text!("{}, {}!", self.tr(Key::Greeting), self.name)
+        } else {
+            text(self.tr(Key::Hint))
+        };
+
+        center(
+            column![
+                text(self.tr(Key::Title)).size(40),
+                language,
+                name,
+                subscribe,
+                actions,
+                status,
+            ]
+            .spacing(20)
+            .max_width(400),
+        )
+        .padding(20)
+        .into()
+    }
+
+    fn tr(&self, key: Key) -> &'static str {
+        self.language.tr(key)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+    French,
+}
+
+impl Language {
+    const ALL: [Language; 4] = [
+        Language::English,
+        Language::Spanish,
+        Language::German,
+        Language::French,
+    ];
+
+    /// Looks up the string for the given [`Key`] in this language.
+    fn tr(self, key: Key) -> &'static str {
+        match (self, key) {
+            (Language::English, Key::Title) => "Newsletter",
+            (Language::English, Key::Language) => "Language",
+            (Language::English, Key::Name) => "Your name",
+            (Language::English, Key::Subscribe) => "Send me the weekly digest",
+            (Language::English, Key::Submit) => "Sign up",
+            (Language::English, Key::Reset) => "Reset",
+            (Language::English, Key::Greeting) => "Welcome aboard",
+            (Language::English, Key::Hint) => "Type your name to sign up.",
+
+            (Language::Spanish, Key::Title) => "Boletín",
+            (Language::Spanish, Key::Language) => "Idioma",
+            (Language::Spanish, Key::Name) => "Tu nombre",
+            (Language::Spanish, Key::Subscribe) => "Envíame el resumen semanal",
+            (Language::Spanish, Key::Submit) => "Suscribirse",
+            (Language::Spanish, Key::Reset) => "Restablecer",
+            (Language::Spanish, Key::Greeting) => "Bienvenido",
+            (Language::Spanish, Key::Hint) => {
+                "Escribe tu nombre para suscribirte."
+            }
+
+            (Language::German, Key::Title) => "Rundbrief",
+            (Language::German, Key::Language) => "Sprache",
+            (Language::German, Key::Name) => "Dein Name",
+            (Language::German, Key::Subscribe) => {
+                "Schick mir die wöchentliche Zusammenfassung"
+            }
+            (Language::German, Key::Submit) => "Jetzt anmelden",
+            (Language::German, Key::Reset) => "Zurücksetzen",
+            (Language::German, Key::Greeting) => "Willkommen an Bord",
+            (Language::German, Key::Hint) => {
+                "Gib deinen Namen ein, um dich anzumelden."
+            }
+
+            (Language::French, Key::Title) => "Lettre d'information",
+            (Language::French, Key::Language) => "Langue",
+            (Language::French, Key::Name) => "Votre nom",
+            (Language::French, Key::Subscribe) => {
+                "Envoyez-moi le résumé hebdomadaire"
+            }
+            (Language::French, Key::Submit) => "S'inscrire",
+            (Language::French, Key::Reset) => "Réinitialiser",
+            (Language::French, Key::Greeting) => "Bienvenue à bord",
+            (Language::French, Key::Hint) => {
+                "Saisissez votre nom pour vous inscrire."
+            }
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::German => "Deutsch",
+            Language::French => "Français",
+        })
+    }
+}
+
+/// The strings of the application.
+#[derive(Debug, Clone, Copy)]
+enum Key {
+    Title,
+    Language,
+    Name,
+    Subscribe,
+    Submit,
+    Reset,
+    Greeting,
+    Hint,
+}
@@ -285,6 +285,7 @@ fn square<'a>(size: impl Into<Length> + Copy) -> Element<'a, Message> {
             _state: &Self::State,
             renderer: &Renderer,
             theme: &Theme,
+            _style: &canvas::Style,
             bounds: Rectangle,
             _cursor: mouse::Cursor,
         ) -> Vec<canvas::Geometry> {
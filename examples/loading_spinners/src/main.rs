@@ -1,44 +1,121 @@
-use iced::widget::{center, column, row, slider, text};
-use iced::{Center, Element};
+use iced::settings::Motion;
+use iced::system::power;
+use iced::widget::{
+    center, checkbox, circular_progress, column, easing, linear_progress,
+    pick_list, row, slider, text,
+};
+use iced::{Center, Element, Subscription, Task};
 
 use std::time::Duration;
 
-mod circular;
-mod easing;
-mod linear;
-
-use circular::Circular;
-use linear::Linear;
-
 pub fn main() -> iced::Result {
     iced::application(
         "Loading Spinners - Iced",
         LoadingSpinners::update,
         LoadingSpinners::view,
     )
+    .subscription(LoadingSpinners::subscription)
     .antialiasing(true)
-    .run()
+    .run_with(LoadingSpinners::new)
 }
 
+/// The interval between redraws when saving energy; half the usual rate.
+const SAVING_REDRAW_INTERVAL: Duration = Duration::from_millis(33);
+
 struct LoadingSpinners {
     cycle_duration: f32,
+    custom: easing::Easing,
+    progress: f32,
+    is_determinate: bool,
+    power: Option<power::State>,
+    motion: Motion,
 }
 
 #[derive(Debug, Clone, Copy)]
 enum Message {
     CycleDurationChanged(f32),
+    ProgressChanged(f32),
+    DeterminateToggled(bool),
+    PowerQueried(Option<power::State>),
+    PowerChanged(power::Event),
+    MotionSelected(Motion),
+    ReducedMotionChanged(bool),
 }
 
 impl LoadingSpinners {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                cycle_duration: 2.0,
+                custom: easing::Easing::cubic_bezier(0.68, 0.0, 0.32, 1.0),
+                progress: 0.5,
+                is_determinate: true,
+                power: None,
+                motion: Motion::current(),
+            },
+            iced::system::power_state().map(Message::PowerQueried),
+        )
+    }
+
     fn update(&mut self, message: Message) {
         match message {
             Message::CycleDurationChanged(duration) => {
                 self.cycle_duration = duration;
             }
+            Message::ProgressChanged(progress) => {
+                self.progress = progress;
+            }
+            Message::DeterminateToggled(is_determinate) => {
+                self.is_determinate = is_determinate;
+            }
+            Message::PowerQueried(power) => {
+                self.power = power;
+            }
+            Message::PowerChanged(event) => {
+                let Some(power) = &mut self.power else {
+                    return;
+                };
+
+                match event {
+                    power::Event::SourceChanged(source) => {
+                        power.source = source;
+                    }
+                    power::Event::BatteryChanged(battery) => {
+                        power.battery = Some(battery);
+                    }
+                    power::Event::LowPowerChanged(is_low_power) => {
+                        power.is_low_power = is_low_power;
+                    }
+                }
+            }
+            Message::MotionSelected(motion) => {
+                self.motion = motion;
+                self.motion.apply();
+            }
+            Message::ReducedMotionChanged(prefers_reduced_motion) => {
+                self.motion = Motion::from_preference(prefers_reduced_motion);
+                self.motion.apply();
+            }
         }
     }
 
+    fn subscription(&self) -> Subscription<Message> {
+        Subscription::batch([
+            iced::system::power_events().map(Message::PowerChanged),
+            iced::system::reduced_motion_changes()
+                .map(Message::ReducedMotionChanged),
+        ])
+    }
+
     fn view(&self) -> Element<Message> {
+        let is_saving_energy = self
+            .power
+            .as_ref()
+            .is_some_and(power::State::should_save_energy);
+
+        let redraw_interval =
+            is_saving_energy.then_some(SAVING_REDRAW_INTERVAL);
+
         let column = [
             &easing::EMPHASIZED,
             &easing::EMPHASIZED_DECELERATE,
@@ -46,6 +123,7 @@ impl LoadingSpinners {
             &easing::STANDARD,
             &easing::STANDARD_DECELERATE,
             &easing::STANDARD_ACCELERATE,
+            &self.custom,
         ]
         .iter()
         .zip([
@@ -55,17 +133,24 @@ impl LoadingSpinners {
             "Standard:",
             "Standard Decelerate:",
             "Standard Accelerate:",
+            "Custom Cubic Bézier:",
         ])
         .fold(column![], |column, (easing, label)| {
             column.push(
                 row![
                     text(label).width(250),
-                    Linear::new().easing(easing).cycle_duration(
-                        Duration::from_secs_f32(self.cycle_duration)
-                    ),
-                    Circular::new().easing(easing).cycle_duration(
-                        Duration::from_secs_f32(self.cycle_duration)
-                    )
+                    linear_progress()
+                        .easing(easing)
+                        .cycle_duration(Duration::from_secs_f32(
+                            self.cycle_duration
+                        ))
+                        .redraw_interval(redraw_interval),
+                    circular_progress()
+                        .easing(easing)
+                        .cycle_duration(Duration::from_secs_f32(
+                            self.cycle_duration
+                        ))
+                        .redraw_interval(redraw_interval)
                 ]
                 .align_y(Center)
                 .spacing(20.0),
@@ -73,28 +158,73 @@ impl LoadingSpinners {
         })
         .spacing(20);
 
-        center(
-            column.push(
-                row![
-                    text("Cycle duration:"),
-                    slider(1.0..=1000.0, self.cycle_duration * 100.0, |x| {
-                        Message::CycleDurationChanged(x / 100.0)
-                    })
-                    .width(200.0),
-                    text!("{:.2}s", self.cycle_duration),
-                ]
-                .align_y(Center)
-                .spacing(20.0),
+        let progress = self.is_determinate.then_some(self.progress);
+
+        let determinate = row![
+            checkbox("Determinate", self.is_determinate)
+                .on_toggle(Message::DeterminateToggled)
+                .width(250),
+            linear_progress()
+                .progress(progress)
+                .progress_duration(Duration::from_millis(300))
+                .redraw_interval(redraw_interval),
+            circular_progress()
+                .progress(progress)
+                .progress_duration(Duration::from_millis(300))
+                .redraw_interval(redraw_interval),
+            slider(0.0..=1.0, self.progress, Message::ProgressChanged)
+                .step(0.05)
+                .width(200.0),
+        ]
+        .align_y(Center)
+        .spacing(20.0);
+
+        let power = match self.power {
+            Some(power) if is_saving_energy => text!(
+                "Saving energy{}: animating at half rate",
+                power
+                    .battery
+                    .map(|battery| format!(" ({battery}%)"))
+                    .unwrap_or_default()
             ),
+            Some(_) => text("Plugged in: animating at full rate"),
+            None => text("Power state unavailable"),
+        };
+
+        center(
+            column
+                .push(determinate)
+                .push(
+                    row![
+                        text("Cycle duration:"),
+                        slider(
+                            1.0..=1000.0,
+                            self.cycle_duration * 100.0,
+                            |x| Message::CycleDurationChanged(x / 100.0)
+                        )
+                        .ticks([1.0, 250.0, 500.0, 750.0, 1000.0])
+                        .tick_labels(|x| format!("{}s", x / 100.0))
+                        .snap_to_ticks(true)
+                        .width(300.0),
+                        text!("{:.2}s", self.cycle_duration),
+                    ]
+                    .align_y(Center)
+                    .spacing(20.0),
+                )
+                .push(
+                    row![
+                        text("Motion:"),
+                        pick_list(
+                            Motion::ALL,
+                            Some(self.motion),
+                            Message::MotionSelected
+                        ),
+                    ]
+                    .align_y(Center)
+                    .spacing(20.0),
+                )
+                .push(power),
         )
         .into()
     }
 }
-
-impl Default for LoadingSpinners {
-    fn default() -> Self {
-        Self {
-            cycle_duration: 2.0,
-        }
-    }
-}
@@ -0,0 +1,97 @@
+use iced::widget::{button, center, checkbox, column, text, text_input};
+use iced::{Element, Font, Pixels};
+
+pub fn main() -> iced::Result {
+    iced::application("Login - Iced", Login::update, Login::view).run()
+}
+
+#[derive(Default)]
+struct Login {
+    username: String,
+    password: String,
+    is_toggle: bool,
+    allow_copy: bool,
+    is_logged_in: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    UsernameChanged(String),
+    PasswordChanged(String),
+    ToggleChanged(bool),
+    AllowCopyChanged(bool),
+    LogIn,
+}
+
+impl Login {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::UsernameChanged(username) => {
+                self.username = username;
+                self.is_logged_in = false;
+            }
+            Message::PasswordChanged(password) => {
+                self.password = password;
+                self.is_logged_in = false;
+            }
+            Message::ToggleChanged(is_toggle) => {
+                self.is_toggle = is_toggle;
+            }
+            Message::AllowCopyChanged(allow_copy) => {
+                self.allow_copy = allow_copy;
+            }
+            Message::LogIn => {
+                self.is_logged_in =
+                    !self.username.is_empty() && !self.password.is_empty();
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let username = text_input("Username", &self.username)
+            .on_input(Message::UsernameChanged)
+            .on_submit(Message::LogIn)
+            .padding(10);
+
+        let password = text_input("Password", &self.password)
+            .secure(true)
+            .reveal_icon(text_input::Icon {
+                font: Font::default(),
+                code_point: '👁',
+                size: Some(Pixels(18.0)),
+                spacing: 10.0,
+                side: text_input::Side::Right,
+            })
+            .reveal(if self.is_toggle {
+                text_input::Reveal::Toggle
+            } else {
+                text_input::Reveal::Hold
+            })
+            .allow_copy(self.allow_copy)
+            .on_input(Message::PasswordChanged)
+            .on_submit(Message::LogIn)
+            .padding(10);
+
+        let status = if self.is_logged_in {
+            text!("Welcome back, {}!", self.username).style(text::success)
+        } else {
+            text("Enter your credentials to log in").style(text::secondary)
+        };
+
+        let content = column![
+            text("Log in").size(30),
+            username,
+            password,
+            checkbox("Toggle the password with the icon", self.is_toggle)
+                .on_toggle(Message::ToggleChanged),
+            checkbox("Allow copying the password", self.allow_copy)
+                .on_toggle(Message::AllowCopyChanged),
+            button("Log in").on_press(Message::LogIn).padding([10, 20]),
+            status,
+        ]
+        .spacing(20)
+        .max_width(400);
+
+        center(content).padding(20).into()
+    }
+}
@@ -1,22 +1,38 @@
-use iced::widget::{self, markdown, row, scrollable, text_editor};
+use iced::widget::{self, image, markdown, row, scrollable, text_editor};
 use iced::{Element, Fill, Font, Task, Theme};
 
+use once_cell::sync::Lazy;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 pub fn main() -> iced::Result {
     iced::application("Markdown - Iced", Markdown::update, Markdown::view)
         .theme(Markdown::theme)
         .run_with(Markdown::new)
 }
 
+static PREVIEW: Lazy<scrollable::Id> = Lazy::new(scrollable::Id::unique);
+
 struct Markdown {
     content: text_editor::Content,
-    items: Vec<markdown::Item>,
+    markdown: markdown::Content,
+    images: HashMap<String, Image>,
     theme: Theme,
 }
 
+enum Image {
+    Loading,
+    Loaded(image::Handle),
+    Failed,
+}
+
 #[derive(Debug, Clone)]
 enum Message {
     Edit(text_editor::Action),
     LinkClicked(markdown::Url),
+    ImageClicked(String),
+    ImageFetched(String, Option<image::Handle>),
 }
 
 impl Markdown {
@@ -25,18 +41,22 @@ impl Markdown {
 
         let theme = Theme::TokyoNight;
 
-        (
-            Self {
-                content: text_editor::Content::with_text(INITIAL_CONTENT),
-                items: markdown::parse(INITIAL_CONTENT, theme.palette())
-                    .collect(),
-                theme,
-            },
-            widget::focus_next(),
-        )
+        let mut markdown = Self {
+            content: text_editor::Content::with_text(INITIAL_CONTENT),
+            markdown: markdown::Content::parse(
+                INITIAL_CONTENT,
+                theme.palette(),
+            ),
+            images: HashMap::new(),
+            theme,
+        };
+
+        let load = markdown.load_images();
+
+        (markdown, Task::batch([widget::focus_next(), load]))
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::Edit(action) => {
                 let is_edit = action.is_edit();
@@ -44,15 +64,37 @@ impl Markdown {
                 self.content.perform(action);
 
                 if is_edit {
-                    self.items = markdown::parse(
-                        &self.content.text(),
-                        self.theme.palette(),
-                    )
-                    .collect();
+                    self.markdown.update(&self.content.text());
+
+                    return self.load_images();
                 }
+
+                Task::none()
             }
             Message::LinkClicked(link) => {
+                if let Some(anchor) = markdown::anchor(&link) {
+                    return markdown::scroll_to_anchor(PREVIEW.clone(), anchor);
+                }
+
                 let _ = open::that_in_background(link.to_string());
+
+                Task::none()
+            }
+            Message::ImageClicked(url) => {
+                if is_remote(&url) {
+                    let _ = open::that_in_background(url);
+                } else {
+                    let _ = open::that_in_background(local_path(&url));
+                }
+
+                Task::none()
+            }
+            Message::ImageFetched(url, handle) => {
+                let _ = self
+                    .images
+                    .insert(url, handle.map_or(Image::Failed, Image::Loaded));
+
+                Task::none()
             }
         }
     }
@@ -64,19 +106,88 @@ impl Markdown {
             .padding(10)
             .font(Font::MONOSPACE);
 
-        let preview = markdown(
-            &self.items,
+        let preview = markdown::view_content_with_images(
+            &self.markdown,
             markdown::Settings::default(),
             Message::LinkClicked,
+            |url| match self.images.get(url)? {
+                Image::Loaded(handle) => Some(handle.clone()),
+                Image::Loading | Image::Failed => None,
+            },
+            Message::ImageClicked,
         );
 
-        row![editor, scrollable(preview).spacing(10).height(Fill)]
-            .spacing(10)
-            .padding(10)
-            .into()
+        row![
+            editor,
+            scrollable(preview)
+                .id(PREVIEW.clone())
+                .spacing(10)
+                .height(Fill)
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
     }
 
     fn theme(&self) -> Theme {
         self.theme.clone()
     }
+
+    fn load_images(&mut self) -> Task<Message> {
+        let mut urls = Vec::new();
+        image_urls(self.markdown.items(), &mut urls);
+
+        let mut tasks = Vec::new();
+
+        for url in urls {
+            if self.images.contains_key(&url) {
+                continue;
+            }
+
+            let _ = self.images.insert(url.clone(), Image::Loading);
+
+            if is_remote(&url) {
+                tasks.push(Task::perform(fetch(url.clone()), move |handle| {
+                    Message::ImageFetched(url.clone(), handle)
+                }));
+            } else {
+                // Decode local images in the background, so the preview
+                // does not stutter the first time they are shown
+                tasks.push(image::load(local_path(&url)).map(move |result| {
+                    Message::ImageFetched(url.clone(), result.ok())
+                }));
+            }
+        }
+
+        Task::batch(tasks)
+    }
+}
+
+fn image_urls(items: &[markdown::Item], urls: &mut Vec<String>) {
+    for item in items {
+        match item {
+            markdown::Item::Image { url, .. } => urls.push(url.clone()),
+            markdown::Item::List { items, .. } => {
+                for items in items {
+                    image_urls(items, urls);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_remote(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+fn local_path(url: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(url)
+}
+
+async fn fetch(url: String) -> Option<image::Handle> {
+    let response = reqwest::get(&url).await.ok()?.error_for_status().ok()?;
+    let bytes = response.bytes().await.ok()?;
+
+    Some(image::Handle::from_bytes(bytes))
 }
@@ -0,0 +1,187 @@
+use iced::event;
+use iced::keyboard;
+use iced::time;
+use iced::widget::{
+    button, center, column, container, progress_bar, row, slider, text,
+};
+use iced::{Center, Element, Fill, Subscription};
+
+use std::time::Duration;
+
+pub fn main() -> iced::Result {
+    iced::application("Media Player - Iced", Player::update, Player::view)
+        .subscription(Player::subscription)
+        .run()
+}
+
+/// The length of the mock media, in seconds.
+const DURATION: f32 = 240.0;
+
+/// The interval between ticks of the simulation.
+const TICK: Duration = Duration::from_millis(100);
+
+/// The seconds of media downloaded on every tick.
+const DOWNLOAD_RATE: f32 = 0.8;
+
+#[derive(Default)]
+struct Player {
+    position: f32,
+    is_playing: bool,
+    buffered: Vec<(f32, f32)>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+    Seek(f32),
+    Tick,
+    Unhandled(event::Unhandled),
+}
+
+impl Player {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle => {
+                self.is_playing = !self.is_playing;
+            }
+            Message::Seek(position) => {
+                self.position = position;
+            }
+            Message::Tick => {
+                self.download();
+
+                if self.is_playing && !self.is_stalled() {
+                    self.position =
+                        (self.position + TICK.as_secs_f32()).min(DURATION);
+
+                    if self.position >= DURATION {
+                        self.is_playing = false;
+                    }
+                }
+            }
+            Message::Unhandled(event::Unhandled {
+                event:
+                    iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::Space),
+                        ..
+                    }),
+                is_text_focused: false,
+                ..
+            }) => {
+                self.is_playing = !self.is_playing;
+            }
+            Message::Unhandled(_) => {}
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let screen = container(
+            text(if self.is_playing && self.is_stalled() {
+                "Buffering..."
+            } else {
+                "Big Buck Bunny"
+            })
+            .size(30),
+        )
+        .center(Fill)
+        .style(container::dark);
+
+        let seek_bar = self.buffered.iter().fold(
+            slider(0.0..=DURATION, self.position, Message::Seek).step(0.1),
+            |slider, (start, end)| slider.buffered(*start..=*end),
+        );
+
+        let downloaded = self.buffered.iter().fold(
+            progress_bar(0.0..=DURATION, 0.0).height(6),
+            |bar, (start, end)| bar.buffered(*start..=*end),
+        );
+
+        let controls = row![
+            button(if self.is_playing { "Pause" } else { "Play" })
+                .on_press(Message::Toggle)
+                .width(80),
+            text!("{} / {}", timestamp(self.position), timestamp(DURATION)),
+            seek_bar,
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let buffered: f32 =
+            self.buffered.iter().map(|(start, end)| end - start).sum();
+
+        let status = text!(
+            "Buffered {} of media in {} range(s)",
+            timestamp(buffered),
+            self.buffered.len()
+        )
+        .size(14);
+
+        center(
+            column![screen, controls, downloaded, status]
+                .spacing(10)
+                .max_width(800),
+        )
+        .padding(20)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        Subscription::batch([
+            time::every(TICK).map(|_| Message::Tick),
+            event::listen_unhandled().map(Message::Unhandled),
+        ])
+    }
+
+    /// Simulates downloading the media from the playback position onwards;
+    /// starting a new range if the position is not buffered.
+    fn download(&mut self) {
+        let cursor = self
+            .range_at(self.position)
+            .map_or(self.position, |(_, end)| end);
+
+        if cursor >= DURATION {
+            return;
+        }
+
+        self.buffered
+            .push((cursor, (cursor + DOWNLOAD_RATE).min(DURATION)));
+
+        self.buffered.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        self.buffered = self.buffered.iter().fold(
+            Vec::new(),
+            |mut ranges: Vec<(f32, f32)>, &(start, end)| {
+                match ranges.last_mut() {
+                    Some((_, last)) if start <= *last => {
+                        *last = last.max(end);
+                    }
+                    _ => ranges.push((start, end)),
+                }
+
+                ranges
+            },
+        );
+    }
+
+    fn range_at(&self, position: f32) -> Option<(f32, f32)> {
+        self.buffered
+            .iter()
+            .copied()
+            .find(|(start, end)| (*start..=*end).contains(&position))
+    }
+
+    fn is_stalled(&self) -> bool {
+        match self.range_at(self.position) {
+            Some((_, end)) => {
+                end - self.position < TICK.as_secs_f32() && end < DURATION
+            }
+            None => true,
+        }
+    }
+}
+
+fn timestamp(seconds: f32) -> String {
+    let seconds = seconds as u32;
+
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
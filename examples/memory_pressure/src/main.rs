@@ -0,0 +1,96 @@
+use iced::time::Instant;
+use iced::widget::{center, column, image, row, text};
+use iced::window;
+use iced::{Center, Element, Fill, Subscription};
+
+use std::collections::VecDeque;
+
+pub fn main() -> iced::Result {
+    iced::application(
+        "Memory Pressure - Iced",
+        Pressure::update,
+        Pressure::view,
+    )
+    .subscription(Pressure::subscription)
+    .memory_budget(BUDGET)
+    .run()
+}
+
+/// The GPU memory budget of the example; enough for a couple of atlas
+/// layers.
+const BUDGET: u64 = 48 * 1024 * 1024;
+const SIZE: u32 = 1024;
+const VISIBLE: usize = 4;
+
+#[derive(Default)]
+struct Pressure {
+    images: VecDeque<image::Handle>,
+    generated: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Frame(Instant),
+}
+
+impl Pressure {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Frame(_now) => {
+                self.images.push_back(generate(self.generated));
+                self.generated += 1;
+
+                if self.images.len() > VISIBLE {
+                    let _ = self.images.pop_front();
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let images = row(self
+            .images
+            .iter()
+            .map(|handle| image(handle.clone()).width(Fill).into()))
+        .spacing(10);
+
+        center(
+            column![
+                images,
+                text!(
+                    "{} images of {SIZE}x{SIZE} generated \
+                    (press F12 to see the GPU memory usage)",
+                    self.generated
+                )
+                .size(14),
+            ]
+            .spacing(10)
+            .align_x(Center),
+        )
+        .padding(20)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+}
+
+/// Generates a gradient image with a different tint for every `seed`.
+fn generate(seed: u64) -> image::Handle {
+    let tint = (seed.wrapping_mul(47) % 256) as u8;
+    let mut pixels = Vec::with_capacity((SIZE * SIZE * 4) as usize);
+
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            pixels.extend_from_slice(&[
+                (x * 255 / SIZE) as u8,
+                (y * 255 / SIZE) as u8,
+                tint,
+                255,
+            ]);
+        }
+    }
+
+    image::Handle::from_rgba(SIZE, SIZE, pixels)
+}
@@ -5,7 +5,7 @@ use iced::widget::{
 use iced::window;
 use iced::{Center, Element, Fill, Subscription, Task, Theme, Vector};
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 fn main() -> iced::Result {
     iced::daemon(Example::title, Example::update, Example::view)
@@ -17,6 +17,7 @@ fn main() -> iced::Result {
 
 struct Example {
     windows: BTreeMap<window::Id, Window>,
+    about_dialogs: BTreeSet<window::Id>,
 }
 
 #[derive(Debug)]
@@ -32,6 +33,9 @@ struct Window {
 enum Message {
     OpenWindow,
     WindowOpened(window::Id),
+    OpenAbout(window::Id),
+    AboutOpened(window::Id),
+    CloseAbout(window::Id),
     WindowClosed(window::Id),
     ScaleInputChanged(window::Id, String),
     ScaleChanged(window::Id, String),
@@ -43,6 +47,7 @@ impl Example {
         (
             Self {
                 windows: BTreeMap::new(),
+                about_dialogs: BTreeSet::new(),
             },
             window::open(window::Settings::default())
                 .map(Message::WindowOpened),
@@ -50,6 +55,10 @@ impl Example {
     }
 
     fn title(&self, window: window::Id) -> String {
+        if self.about_dialogs.contains(&window) {
+            return String::from("About");
+        }
+
         self.windows
             .get(&window)
             .map(|window| window.title.clone())
@@ -89,8 +98,22 @@ impl Example {
 
                 focus_input
             }
+            Message::OpenAbout(parent) => window::open(window::Settings {
+                size: iced::Size::new(300.0, 200.0),
+                resizable: false,
+                modal: true,
+                ..window::Settings::default().with_parent(parent)
+            })
+            .map(Message::AboutOpened),
+            Message::AboutOpened(id) => {
+                self.about_dialogs.insert(id);
+
+                Task::none()
+            }
+            Message::CloseAbout(id) => window::close(id),
             Message::WindowClosed(id) => {
                 self.windows.remove(&id);
+                self.about_dialogs.remove(&id);
 
                 if self.windows.is_empty() {
                     iced::exit()
@@ -126,7 +149,18 @@ impl Example {
     }
 
     fn view(&self, window_id: window::Id) -> Element<Message> {
-        if let Some(window) = self.windows.get(&window_id) {
+        if self.about_dialogs.contains(&window_id) {
+            center(
+                column![
+                    text("A multi-window example built with iced."),
+                    button(text("Close"))
+                        .on_press(Message::CloseAbout(window_id)),
+                ]
+                .spacing(20)
+                .align_x(Center),
+            )
+            .into()
+        } else if let Some(window) = self.windows.get(&window_id) {
             center(window.view(window_id)).into()
         } else {
             horizontal_space().into()
@@ -185,8 +219,11 @@ impl Window {
         let new_window_button =
             button(text("New Window")).on_press(Message::OpenWindow);
 
+        let about_button =
+            button(text("About")).on_press(Message::OpenAbout(id));
+
         let content = scrollable(
-            column![scale_input, title_input, new_window_button]
+            column![scale_input, title_input, new_window_button, about_button]
                 .spacing(50)
                 .width(Fill)
                 .align_x(Center),
@@ -82,6 +82,7 @@ impl canvas::Program<Message> for Multitouch {
         _state: &Self::State,
         renderer: &Renderer,
         _theme: &Theme,
+        _style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
@@ -0,0 +1,93 @@
+use iced::time::Instant;
+use iced::widget::{center, column, image, text};
+use iced::window;
+use iced::{Center, Element, Fill, Rectangle, Size, Subscription};
+
+pub fn main() -> iced::Result {
+    iced::application("Noise Stream - Iced", Noise::update, Noise::view)
+        .subscription(Noise::subscription)
+        .run()
+}
+
+const SIZE: Size<u32> = Size::new(320, 240);
+const BAND: u32 = 24;
+
+struct Noise {
+    image: image::Handle,
+    band: Vec<u8>,
+    row: u32,
+    seed: u32,
+    frames: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Frame(Instant),
+}
+
+impl Noise {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Frame(_now) => {
+                for pixel in self.band.chunks_exact_mut(4) {
+                    let value = (self.next() >> 24) as u8;
+
+                    pixel.copy_from_slice(&[value, value, value, 255]);
+                }
+
+                let region = Rectangle {
+                    x: 0,
+                    y: self.row,
+                    width: SIZE.width,
+                    height: BAND,
+                };
+
+                image::update_region(&self.image, region, &self.band)
+                    .expect("Update noise band");
+
+                self.row = (self.row + BAND) % SIZE.height;
+                self.frames += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        center(
+            column![
+                image(self.image.clone())
+                    .width(Fill)
+                    .height(Fill)
+                    .filter_method(image::FilterMethod::Nearest),
+                text!("{} frames streamed", self.frames).size(14),
+            ]
+            .spacing(10)
+            .align_x(Center),
+        )
+        .padding(20)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    /// Generates the next pseudorandom number with a xorshift.
+    fn next(&mut self) -> u32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+        self.seed
+    }
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Self {
+            image: image::Handle::from_rgba_dynamic(SIZE),
+            band: vec![0; (SIZE.width * BAND * 4) as usize],
+            row: 0,
+            seed: 0x9E37_79B9,
+            frames: 0,
+        }
+    }
+}
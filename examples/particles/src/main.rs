@@ -0,0 +1,229 @@
+use iced::mouse;
+use iced::time::{Duration, Instant};
+use iced::widget::canvas::{self, Blend, Canvas, Fill, Geometry, Path};
+use iced::widget::{column, container, pick_list, row, text};
+use iced::window;
+use iced::{
+    Center, Color, Element, Point, Rectangle, Renderer, Size, Subscription,
+    Theme, Vector,
+};
+
+use rand::Rng;
+use std::fmt;
+
+pub fn main() -> iced::Result {
+    iced::application("Particles - Iced", Particles::update, Particles::view)
+        .subscription(Particles::subscription)
+        .theme(|_| Theme::Dark)
+        .antialiasing(true)
+        .run()
+}
+
+/// The amount of particles spawned every second.
+const RATE: f32 = 400.0;
+
+/// The time a particle stays alive.
+const LIFETIME: Duration = Duration::from_secs(2);
+
+/// The downwards acceleration of the particles, in pixels per second squared.
+const GRAVITY: f32 = 300.0;
+
+struct Particles {
+    particles: Vec<Particle>,
+    mode: Mode,
+    last_tick: Option<Instant>,
+    pending: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Tick(Instant),
+    ModeSelected(Mode),
+}
+
+impl Particles {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tick(now) => {
+                let delta = self
+                    .last_tick
+                    .map(|last_tick| now - last_tick)
+                    .unwrap_or_default()
+                    .as_secs_f32();
+
+                self.last_tick = Some(now);
+
+                for particle in &mut self.particles {
+                    particle.velocity.y += GRAVITY * delta;
+                    particle.position =
+                        particle.position + particle.velocity * delta;
+                }
+
+                self.particles
+                    .retain(|particle| now - particle.born < LIFETIME);
+
+                self.pending += RATE * delta;
+
+                let mut rng = rand::thread_rng();
+
+                while self.pending >= 1.0 {
+                    self.particles.push(Particle::spawn(&mut rng, now));
+                    self.pending -= 1.0;
+                }
+            }
+            Message::ModeSelected(mode) => {
+                self.mode = mode;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let controls = row![
+            text("Blend mode"),
+            pick_list(Mode::ALL, Some(self.mode), Message::ModeSelected),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        column![
+            Canvas::new(self).width(iced::Fill).height(iced::Fill),
+            container(controls).center_x(iced::Fill),
+        ]
+        .padding(20)
+        .spacing(20)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Tick)
+    }
+}
+
+impl Default for Particles {
+    fn default() -> Self {
+        Self {
+            particles: Vec::new(),
+            mode: Mode::Additive,
+            last_tick: None,
+            pending: 0.0,
+        }
+    }
+}
+
+impl<Message> canvas::Program<Message> for Particles {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        _style: &canvas::Style,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        // A light band, so multiply and screen can be told apart
+        frame.fill_rectangle(
+            Point::new(0.0, frame.height() * 0.6),
+            Size::new(frame.width(), frame.height() * 0.1),
+            Color::from_rgb(0.6, 0.6, 0.7),
+        );
+
+        frame.translate(Vector::new(frame.width() / 2.0, frame.height() * 0.8));
+
+        let now = self.last_tick.unwrap_or_else(Instant::now);
+        let blend = self.mode.blend();
+
+        for particle in &self.particles {
+            let age = (now - particle.born).as_secs_f32();
+            let life = 1.0 - (age / LIFETIME.as_secs_f32()).min(1.0);
+
+            // Glow
+            frame.fill(
+                &Path::circle(particle.position, 12.0 * life + 4.0),
+                Fill {
+                    blend,
+                    ..Fill::from(Color {
+                        a: 0.15 * life,
+                        ..particle.color
+                    })
+                },
+            );
+
+            // Core
+            frame.fill(
+                &Path::circle(particle.position, 2.0 * life + 1.0),
+                Fill {
+                    blend,
+                    ..Fill::from(Color {
+                        a: life,
+                        ..particle.color
+                    })
+                },
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Particle {
+    position: Point,
+    velocity: Vector,
+    color: Color,
+    born: Instant,
+}
+
+impl Particle {
+    fn spawn(rng: &mut impl Rng, now: Instant) -> Self {
+        let angle = rng.gen_range(-0.3..0.3f32) - std::f32::consts::FRAC_PI_2;
+        let speed = rng.gen_range(250.0..450.0f32);
+
+        Self {
+            position: Point::ORIGIN,
+            velocity: Vector::new(angle.cos(), angle.sin()) * speed,
+            color: Color::from_rgb(
+                1.0,
+                rng.gen_range(0.3..0.7),
+                rng.gen_range(0.0..0.3),
+            ),
+            born: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Alpha,
+    Additive,
+    Multiply,
+    Screen,
+}
+
+impl Mode {
+    const ALL: [Self; 4] =
+        [Self::Alpha, Self::Additive, Self::Multiply, Self::Screen];
+
+    fn blend(self) -> Blend {
+        match self {
+            Self::Alpha => Blend::Alpha,
+            Self::Additive => Blend::Additive,
+            Self::Multiply => Blend::Multiply,
+            Self::Screen => Blend::Screen,
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Alpha => "Alpha",
+            Self::Additive => "Additive",
+            Self::Multiply => "Multiply",
+            Self::Screen => "Screen",
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use iced::widget::{column, pick_list, scrollable, vertical_space};
+use iced::overlay::ScrollPolicy;
+use iced::widget::{
+    column, container, pick_list, scrollable, tooltip, vertical_space,
+};
 use iced::{Center, Element, Fill};
 
 pub fn main() -> iced::Result {
@@ -32,10 +35,28 @@ impl Example {
         )
         .placeholder("Choose a language...");
 
+        let following = pick_list(
+            &Language::ALL[..],
+            self.selected_language,
+            Message::LanguageSelected,
+        )
+        .placeholder("This one follows while scrolling...")
+        .scroll_policy(ScrollPolicy::Reposition);
+
+        let hint = tooltip(
+            "Which is your favorite language?",
+            container("Scroll to close me!")
+                .padding(10)
+                .style(container::rounded_box),
+            tooltip::Position::Bottom,
+        )
+        .scroll_policy(ScrollPolicy::Close);
+
         let content = column![
             vertical_space().height(600),
-            "Which is your favorite language?",
+            hint,
             pick_list,
+            following,
             vertical_space().height(600),
         ]
         .width(Fill)
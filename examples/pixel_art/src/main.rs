@@ -0,0 +1,110 @@
+use iced::widget::{center, column, image, row, slider, text};
+use iced::{Center, Element};
+
+pub fn main() -> iced::Result {
+    iced::run("Pixel Art - Iced", PixelArt::update, PixelArt::view)
+}
+
+struct PixelArt {
+    sprite: image::Handle,
+    scale: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    ScaleChanged(u32),
+}
+
+impl PixelArt {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ScaleChanged(scale) => {
+                self.scale = scale;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let size = (SIZE * self.scale) as f32;
+
+        let sprite = |label, filter_method| {
+            column![
+                image(self.sprite.clone())
+                    .width(size)
+                    .height(size)
+                    .filter_method(filter_method),
+                text(label),
+            ]
+            .spacing(10)
+            .align_x(Center)
+        };
+
+        center(
+            column![
+                row![
+                    sprite("Linear", image::FilterMethod::Linear),
+                    sprite("Nearest", image::FilterMethod::Nearest),
+                ]
+                .spacing(40),
+                slider(1..=24, self.scale, Message::ScaleChanged).width(300),
+                text!("Scale: {}x", self.scale),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
+
+impl Default for PixelArt {
+    fn default() -> Self {
+        Self {
+            sprite: sprite(),
+            scale: 12,
+        }
+    }
+}
+
+/// The width and height of the sprite, in pixels.
+const SIZE: u32 = 16;
+
+/// The pixels of the sprite; where each character is a color of the
+/// [`palette`].
+const SPRITE: [&str; SIZE as usize] = [
+    "................",
+    "...####..####...",
+    "..#rrrr##rrrr#..",
+    ".#rrwwrrrrrrrr#.",
+    ".#rwwrrrrrrrrr#.",
+    ".#rrrrrrrrrrrr#.",
+    ".#rrrrrrrrrrdr#.",
+    "..#rrrrrrrrdr#..",
+    "...#rrrrrrddr#..",
+    "....#rrrrrdd#...",
+    ".....#rrrdd#....",
+    "......#rdd#.....",
+    ".......##.......",
+    "................",
+    "................",
+    "................",
+];
+
+fn palette(pixel: u8) -> [u8; 4] {
+    match pixel {
+        b'#' => [0x1a, 0x1c, 0x2c, 0xff],
+        b'r' => [0xe4, 0x3b, 0x44, 0xff],
+        b'd' => [0xa2, 0x26, 0x33, 0xff],
+        b'w' => [0xff, 0xff, 0xff, 0xff],
+        _ => [0x00, 0x00, 0x00, 0x00],
+    }
+}
+
+fn sprite() -> image::Handle {
+    let pixels: Vec<u8> = SPRITE
+        .iter()
+        .flat_map(|row| row.bytes())
+        .flat_map(palette)
+        .collect();
+
+    image::Handle::from_rgba(SIZE, SIZE, pixels)
+}
@@ -0,0 +1,82 @@
+use iced::advanced::widget::AnyWidget;
+use iced::widget::{center, column, text};
+use iced::{Center, Element, Theme};
+
+use libloading::Library;
+
+pub fn main() -> iced::Result {
+    iced::application("Plugin - Iced", Plugin::update, Plugin::view)
+        .theme(|_| Theme::Dark)
+        .run()
+}
+
+struct Plugin {
+    library: Result<Library, String>,
+    clicks: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    PluginClicked,
+}
+
+type Create = fn() -> Box<dyn AnyWidget<(), Theme>>;
+
+impl Plugin {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PluginClicked => {
+                self.clicks += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let plugin: Element<Message> = match &self.library {
+            Ok(library) => {
+                // SAFETY: The plugin is built from this repository with the
+                // same compiler and the same version of `iced_core`.
+                let create = unsafe { library.get::<Create>(b"create") };
+
+                match create {
+                    Ok(create) => Element::boxed_dyn(create())
+                        .map(|()| Message::PluginClicked),
+                    Err(error) => text!("Invalid plugin: {error}").into(),
+                }
+            }
+            Err(error) => text(error).into(),
+        };
+
+        center(
+            column![plugin, text!("Clicked {} times", self.clicks)]
+                .spacing(20)
+                .align_x(Center),
+        )
+        .into()
+    }
+}
+
+impl Default for Plugin {
+    fn default() -> Self {
+        Self {
+            library: load(),
+            clicks: 0,
+        }
+    }
+}
+
+fn load() -> Result<Library, String> {
+    let path = std::env::current_exe()
+        .map_err(|error| error.to_string())?
+        .with_file_name(libloading::library_filename("plugin_widget"));
+
+    // SAFETY: Loading the plugin runs no initialization code.
+    unsafe { Library::new(&path) }.map_err(|error| {
+        format!(
+            "Could not load the plugin from {}. \
+            Build it first with `cargo build --package plugin_widget`.\n\n\
+            {error}",
+            path.display()
+        )
+    })
+}
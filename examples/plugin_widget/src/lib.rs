@@ -0,0 +1,192 @@
+//! A widget compiled as a dynamic library, without knowing the renderer of
+//! the application that loads it.
+use iced_core::alignment;
+use iced_core::border;
+use iced_core::event::{self, Event};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::renderer::{self, AnyRenderer, Quad};
+use iced_core::text::{LineHeight, Shaping, Text};
+use iced_core::widget::tree::{self, Tree};
+use iced_core::widget::AnyWidget;
+use iced_core::{
+    Clipboard, Length, Rectangle, Shadow, Shell, Size, Theme, Vector,
+};
+
+/// Creates the widget exposed by the plugin.
+///
+/// The widget produces a `()` message every time it is clicked.
+#[no_mangle]
+pub fn create() -> Box<dyn AnyWidget<(), Theme>> {
+    Box::new(Badge {
+        label: String::from("I was loaded from a plugin!"),
+    })
+}
+
+const PADDING: f32 = 12.0;
+
+struct Badge {
+    label: String,
+}
+
+#[derive(Default)]
+struct State {
+    is_pressed: bool,
+}
+
+impl Badge {
+    fn text(&self, renderer: &dyn AnyRenderer, bounds: Size) -> Text<String> {
+        Text {
+            content: self.label.clone(),
+            bounds,
+            size: renderer.default_size(),
+            line_height: LineHeight::default(),
+            font: renderer.default_font(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            max_space_factor: iced_core::text::DEFAULT_MAX_SPACE_FACTOR,
+            font_features: &[],
+            shaping: Shaping::Basic,
+        }
+    }
+}
+
+impl AnyWidget<(), Theme> for Badge {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &dyn AnyRenderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text = self.text(renderer, limits.max());
+
+        let label = renderer.measure_text(Text {
+            content: text.content.as_str(),
+            bounds: text.bounds,
+            size: text.size,
+            line_height: text.line_height,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            max_space_factor: text.max_space_factor,
+            font_features: text.font_features,
+            shaping: text.shaping,
+        });
+
+        let size = limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(
+                label.width + PADDING * 2.0,
+                label.height + PADDING * 2.0,
+            ),
+        );
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut dyn AnyRenderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let palette = theme.extended_palette();
+
+        let pair = if state.is_pressed {
+            palette.primary.strong
+        } else if cursor.is_over(bounds) {
+            palette.primary.base
+        } else {
+            palette.primary.weak
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: border::rounded(bounds.height / 2.0),
+                shadow: Shadow {
+                    color: palette.background.strong.color,
+                    offset: Vector::new(0.0, 2.0),
+                    blur_radius: 4.0,
+                },
+            },
+            pair.color.into(),
+        );
+
+        renderer.fill_text(
+            self.text(renderer, bounds.size()),
+            bounds.center(),
+            pair.text,
+            bounds,
+        );
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &dyn AnyRenderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, ()>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(layout.bounds()) =>
+            {
+                state.is_pressed = true;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if cursor.is_over(layout.bounds()) {
+                    shell.publish(());
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
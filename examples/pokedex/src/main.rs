@@ -1,9 +1,11 @@
 use iced::futures;
+use iced::time::Duration;
 use iced::widget::{self, center, column, image, row, text};
 use iced::{Center, Element, Fill, Right, Task};
 
 pub fn main() -> iced::Result {
     iced::application(Pokedex::title, Pokedex::update, Pokedex::view)
+        .splash(Pokedex::splash, Duration::from_secs(10))
         .run_with(Pokedex::new)
 }
 
@@ -62,6 +64,18 @@ impl Pokedex {
         }
     }
 
+    fn splash(&self) -> Element<Message> {
+        center(
+            column![
+                text("Pokédex").size(60),
+                text("Catching the first Pokémon...").size(20),
+            ]
+            .spacing(10)
+            .align_x(Center),
+        )
+        .into()
+    }
+
     fn view(&self) -> Element<Message> {
         let content: Element<_> = match self {
             Pokedex::Loading => {
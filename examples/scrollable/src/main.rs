@@ -1,3 +1,4 @@
+use iced::time::Duration;
 use iced::widget::{
     button, column, container, horizontal_space, progress_bar, radio, row,
     scrollable, slider, text, vertical_space,
@@ -93,14 +94,12 @@ impl ScrollableDemo {
 
                 Task::none()
             }
-            Message::ScrollToBeginning => {
-                self.current_scroll_offset = scrollable::RelativeOffset::START;
-
-                scrollable::snap_to(
-                    SCROLLABLE_ID.clone(),
-                    self.current_scroll_offset,
-                )
-            }
+            Message::ScrollToBeginning => scrollable::scroll_to_animated(
+                SCROLLABLE_ID.clone(),
+                scrollable::AbsoluteOffset::default(),
+                Duration::from_millis(500),
+                scrollable::Easing::EaseInOut,
+            ),
             Message::ScrollToEnd => {
                 self.current_scroll_offset = scrollable::RelativeOffset::END;
 
@@ -344,6 +343,8 @@ fn progress_bar_custom_style(theme: &Theme) -> progress_bar::Style {
     progress_bar::Style {
         background: theme.extended_palette().background.strong.color.into(),
         bar: Color::from_rgb8(250, 85, 134).into(),
+        buffered: Color::from_rgba8(250, 85, 134, 0.35).into(),
         border: Border::default(),
+        ..progress_bar::primary(theme)
     }
 }
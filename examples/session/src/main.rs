@@ -0,0 +1,230 @@
+use iced::session::{self, Session};
+use iced::widget::pane_grid::{self, PaneGrid};
+use iced::widget::{button, column, container, row, scrollable, tab_bar, text};
+use iced::window;
+use iced::{Element, Fill, Subscription, Task};
+
+use std::path::PathBuf;
+
+const TABS: [&str; 2] = ["Lines", "Numbers"];
+
+pub fn main() -> iced::Result {
+    let session = Session::load(path()).unwrap_or_default();
+    let geometry = session.window("main");
+
+    let window = geometry.map_or_else(window::Settings::default, |geometry| {
+        geometry.configure(window::Settings::default())
+    });
+
+    iced::application("Session - Iced", Example::update, Example::view)
+        .subscription(Example::subscription)
+        .window(window)
+        .exit_on_close_request(false)
+        .run_with(move || Example::new(session))
+}
+
+fn path() -> PathBuf {
+    std::env::temp_dir().join("iced_session_example.json")
+}
+
+struct Example {
+    session: Session,
+    restore: session::Restore,
+    panes: pane_grid::State<Pane>,
+    panes_created: usize,
+    tab: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pane {
+    id: usize,
+}
+
+impl Pane {
+    fn name(&self) -> String {
+        format!("pane-{}", self.id)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        let id = name.strip_prefix("pane-")?.parse().ok()?;
+
+        Some(Self { id })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    TabSelected(usize),
+    Split(pane_grid::Axis, pane_grid::Pane),
+    Close(pane_grid::Pane),
+    Resized(pane_grid::ResizeEvent),
+    Dragged(pane_grid::DragEvent),
+    CloseRequested(window::Id),
+    Captured(Session),
+}
+
+impl Example {
+    fn new(session: Session) -> (Self, Task<Message>) {
+        let panes = session
+            .pane_grid("panes", Pane::from_name)
+            .unwrap_or_else(|| pane_grid::State::new(Pane { id: 0 }).0);
+
+        let panes_created = panes
+            .iter()
+            .map(|(_, pane)| pane.id + 1)
+            .max()
+            .unwrap_or_default();
+
+        let tab = session
+            .tab("content")
+            .and_then(|tab| TABS.iter().position(|name| *name == tab))
+            .unwrap_or_default();
+
+        let geometry = session.window("main");
+        let restore = session.restore();
+
+        let task = Task::batch([
+            restore.apply(),
+            match geometry {
+                Some(geometry) => window::get_oldest()
+                    .and_then(move |window| geometry.restore(window)),
+                None => Task::none(),
+            },
+        ]);
+
+        (
+            Self {
+                session,
+                restore,
+                panes,
+                panes_created,
+                tab,
+            },
+            task,
+        )
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        let task = match message {
+            Message::TabSelected(tab) => {
+                self.tab = tab;
+
+                Task::none()
+            }
+            Message::Split(axis, pane) => {
+                let _ = self.panes.split(
+                    axis,
+                    pane,
+                    Pane {
+                        id: self.panes_created,
+                    },
+                );
+
+                self.panes_created += 1;
+
+                Task::none()
+            }
+            Message::Close(pane) => {
+                let _ = self.panes.close(pane);
+
+                Task::none()
+            }
+            Message::Resized(pane_grid::ResizeEvent { split, ratio }) => {
+                self.panes.resize(split, ratio);
+
+                Task::none()
+            }
+            Message::Dragged(pane_grid::DragEvent::Dropped {
+                pane,
+                target,
+            }) => {
+                self.panes.drop(pane, target);
+
+                Task::none()
+            }
+            Message::Dragged(_) => Task::none(),
+            Message::CloseRequested(window) => {
+                self.session.set_pane_grid("panes", &self.panes, Pane::name);
+                self.session.set_tab("content", TABS[self.tab]);
+
+                return self
+                    .session
+                    .clone()
+                    .capture([("main", window)])
+                    .map(Message::Captured);
+            }
+            Message::Captured(session) => {
+                if let Err(error) = session.save(path()) {
+                    eprintln!("The session could not be saved: {error}");
+                }
+
+                return iced::exit();
+            }
+        };
+
+        // The scrollables of a tab only exist once it is selected
+        Task::batch([task, self.restore.apply()])
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::close_requests()
+            .map(|(window, _request)| Message::CloseRequested(window))
+    }
+
+    fn view(&self) -> Element<Message> {
+        let tab = TABS[self.tab];
+        let total_panes = self.panes.len();
+
+        let tabs = tab_bar(TABS, self.tab, Message::TabSelected);
+
+        let panes = PaneGrid::new(&self.panes, |id, pane, _is_maximized| {
+            let controls = row![
+                button(text("Split →").size(14))
+                    .padding(3)
+                    .on_press(Message::Split(pane_grid::Axis::Vertical, id)),
+                button(text("Split ↓").size(14))
+                    .padding(3)
+                    .on_press(Message::Split(pane_grid::Axis::Horizontal, id)),
+                button(text("Close").size(14))
+                    .padding(3)
+                    .style(button::danger)
+                    .on_press_maybe(
+                        (total_panes > 1).then_some(Message::Close(id))
+                    ),
+            ]
+            .spacing(5);
+
+            let title_bar = pane_grid::TitleBar::new(text!("Pane {}", pane.id))
+                .controls(controls)
+                .padding(10)
+                .style(container::rounded_box);
+
+            pane_grid::Content::new(
+                scrollable(view_content(tab, pane))
+                    .id(scrollable::Id::new(format!("{tab}/{}", pane.name())))
+                    .width(Fill)
+                    .height(Fill),
+            )
+            .title_bar(title_bar)
+            .style(container::bordered_box)
+        })
+        .spacing(10)
+        .on_drag(Message::Dragged)
+        .on_resize(10, Message::Resized);
+
+        column![tabs, panes].spacing(10).padding(10).into()
+    }
+}
+
+fn view_content<'a>(tab: &str, pane: &Pane) -> Element<'a, Message> {
+    let lines = (1..=200).map(|line| {
+        let content = match tab {
+            "Numbers" => text!("{}", line * (pane.id + 1)),
+            _ => text!("Line {line} of pane {}", pane.id),
+        };
+
+        content.into()
+    });
+
+    column(lines).spacing(5).padding(10).width(Fill).into()
+}
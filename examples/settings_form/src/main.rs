@@ -0,0 +1,184 @@
+use iced::widget::grid::{Cell, Track};
+use iced::widget::{
+    button, center, container, grid, pick_list, slider, text, text_input,
+    toggler,
+};
+use iced::{Center, Element, Fill, Right, Theme};
+
+pub fn main() -> iced::Result {
+    iced::application("Settings Form - Iced", Settings::update, Settings::view)
+        .theme(Settings::theme)
+        .run()
+}
+
+#[derive(Debug)]
+struct Settings {
+    name: String,
+    email: String,
+    theme: Theme,
+    font_size: u8,
+    scale: f32,
+    newsletter: bool,
+    saved: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    NameChanged(String),
+    EmailChanged(String),
+    ThemeSelected(Theme),
+    FontSizeChanged(u8),
+    ScaleChanged(f32),
+    NewsletterToggled(bool),
+    Save,
+}
+
+impl Settings {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::NameChanged(name) => {
+                self.name = name;
+            }
+            Message::EmailChanged(email) => {
+                self.email = email;
+            }
+            Message::ThemeSelected(theme) => {
+                self.theme = theme;
+            }
+            Message::FontSizeChanged(font_size) => {
+                self.font_size = font_size;
+            }
+            Message::ScaleChanged(scale) => {
+                self.scale = scale;
+            }
+            Message::NewsletterToggled(newsletter) => {
+                self.newsletter = newsletter;
+            }
+            Message::Save => {
+                self.saved = true;
+                return;
+            }
+        }
+
+        self.saved = false;
+    }
+
+    fn view(&self) -> Element<Message> {
+        let label = |label| text(label).size(16);
+
+        let form = grid()
+            .columns([Track::Shrink, Track::from(Fill), Track::Fixed(50.0)])
+            .column_spacing(20)
+            .row_spacing(15)
+            .align_y(Center)
+            .push_cell(Cell::new(0, 0, text("Settings").size(30)).span(1, 3))
+            .push(1, 0, label("Name"))
+            .push_cell(
+                Cell::new(
+                    1,
+                    1,
+                    text_input("Ferris", &self.name)
+                        .on_input(Message::NameChanged),
+                )
+                .span(1, 2),
+            )
+            .push(2, 0, label("Email"))
+            .push_cell(
+                Cell::new(
+                    2,
+                    1,
+                    text_input("ferris@example.com", &self.email)
+                        .on_input(Message::EmailChanged),
+                )
+                .span(1, 2),
+            )
+            .push(3, 0, label("Theme"))
+            .push_cell(
+                Cell::new(
+                    3,
+                    1,
+                    pick_list(
+                        Theme::ALL,
+                        Some(&self.theme),
+                        Message::ThemeSelected,
+                    )
+                    .width(Fill),
+                )
+                .span(1, 2),
+            )
+            .push(4, 0, label("Font size"))
+            .push(
+                4,
+                1,
+                slider(10..=32, self.font_size, Message::FontSizeChanged),
+            )
+            .push_cell(
+                Cell::new(4, 2, text!("{}px", self.font_size)).align_x(Right),
+            )
+            .push(5, 0, label("Interface scale"))
+            .push(
+                5,
+                1,
+                slider(0.5..=2.0, self.scale, Message::ScaleChanged).step(0.1),
+            )
+            .push_cell(
+                Cell::new(5, 2, text!("{:.1}x", self.scale)).align_x(Right),
+            )
+            .push(6, 0, label("Notifications"))
+            .push_cell(
+                Cell::new(
+                    6,
+                    1,
+                    toggler(
+                        String::from("Email me about new releases"),
+                        self.newsletter,
+                        Message::NewsletterToggled,
+                    ),
+                )
+                .span(1, 2),
+            )
+            .push_cell(
+                Cell::new(
+                    7,
+                    1,
+                    text(
+                        "We only send a few emails a year, and you can \
+                        unsubscribe at any time with the link at the \
+                        bottom of any of them.",
+                    )
+                    .size(14),
+                )
+                .span(1, 2),
+            )
+            .push_cell(
+                Cell::new(
+                    8,
+                    0,
+                    button(if self.saved { "Saved!" } else { "Save" })
+                        .on_press_maybe((!self.saved).then_some(Message::Save)),
+                )
+                .span(1, 3)
+                .align_x(Right),
+            );
+
+        center(container(form).max_width(600).padding(20)).into()
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme.clone()
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            email: String::new(),
+            theme: Theme::Light,
+            font_size: 16,
+            scale: 1.0,
+            newsletter: false,
+            saved: false,
+        }
+    }
+}
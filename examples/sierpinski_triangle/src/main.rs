@@ -107,6 +107,7 @@ impl canvas::Program<Message> for SierpinskiGraph {
         _state: &Self::State,
         renderer: &Renderer,
         _theme: &Theme,
+        _style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
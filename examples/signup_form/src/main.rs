@@ -0,0 +1,178 @@
+use iced::widget::{button, center, column, form, row, text};
+use iced::Element;
+
+pub fn main() -> iced::Result {
+    iced::application("Signup Form - Iced", Signup::update, Signup::view).run()
+}
+
+struct Signup {
+    form: form::State,
+    is_submitted: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Form(form::Event),
+    Submit,
+    Reset,
+}
+
+const COUNTRIES: [&str; 5] = ["Canada", "Japan", "Kenya", "Peru", "Spain"];
+
+impl Signup {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Form(event) => {
+                self.form.update(event);
+                self.is_submitted = false;
+            }
+            Message::Submit => {
+                self.is_submitted = self.form.submit();
+            }
+            Message::Reset => {
+                self.form.reset();
+                self.is_submitted = false;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let state = &self.form;
+
+        let status = if self.is_submitted {
+            text!("Welcome aboard, {}!", state.value("username"))
+                .style(text::success)
+        } else {
+            text("Fill in the form to sign up").style(text::secondary)
+        };
+
+        let content = column![
+            text("Sign up").size(30),
+            form::field(
+                state,
+                "username",
+                "Username",
+                form::text_input(state, "username", "ferris", Message::Form)
+                    .padding(10),
+            ),
+            form::field(
+                state,
+                "email",
+                "Email",
+                form::text_input(
+                    state,
+                    "email",
+                    "ferris@iced.rs",
+                    Message::Form
+                )
+                .padding(10),
+            ),
+            form::field(
+                state,
+                "age",
+                "Age",
+                form::number_input(state, "age", "18", Message::Form)
+                    .padding(10),
+            ),
+            form::field(
+                state,
+                "country",
+                "Country",
+                form::pick_list(state, "country", COUNTRIES, Message::Form)
+                    .placeholder("Choose a country...")
+                    .padding(10),
+            ),
+            form::field(
+                state,
+                "password",
+                "Password",
+                form::text_input(
+                    state,
+                    "password",
+                    "At least 8 characters",
+                    Message::Form
+                )
+                .secure(true)
+                .on_submit(Message::Submit)
+                .padding(10),
+            ),
+            row![
+                button("Submit")
+                    .on_press_maybe(state.is_valid().then_some(Message::Submit))
+                    .padding([10, 20]),
+                button("Reset")
+                    .on_press(Message::Reset)
+                    .style(button::secondary)
+                    .padding([10, 20]),
+            ]
+            .spacing(10),
+            status,
+        ]
+        .spacing(20)
+        .max_width(400);
+
+        center(content).padding(20).into()
+    }
+}
+
+impl Default for Signup {
+    fn default() -> Self {
+        let form = form::State::new()
+            .field(
+                "username",
+                form::Field::new()
+                    .validator(form::required("Please, choose a username."))
+                    .validator(form::regex(
+                        r"^[a-z0-9_]{3,16}$",
+                        "Use 3 to 16 lowercase letters, digits or underscores.",
+                    ))
+                    .validate_on(form::Trigger::Change),
+            )
+            .field(
+                "email",
+                form::Field::new()
+                    .validator(form::required("Please, tell us your email."))
+                    .validator(form::regex(
+                        r"^[^@\s]+@[^@\s]+\.[^@\s]+$",
+                        "This does not look like an email.",
+                    )),
+            )
+            .field(
+                "age",
+                form::Field::new()
+                    .validator(form::required("Please, tell us your age."))
+                    .validator(form::range(
+                        13.0..=120.0,
+                        "You must be at least 13 years old.",
+                    ))
+                    .validate_on(form::Trigger::Change),
+            )
+            .field(
+                "country",
+                form::Field::new()
+                    .validator(form::required("Please, choose a country."))
+                    .validate_on(form::Trigger::Change),
+            )
+            .field(
+                "password",
+                form::Field::new().validator(form::custom(|password| {
+                    if password.chars().count() < 8 {
+                        Err(String::from(
+                            "The password must have at least 8 characters.",
+                        ))
+                    } else if !password.chars().any(|c| c.is_ascii_digit()) {
+                        Err(String::from(
+                            "The password must contain at least one digit.",
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                })),
+            );
+
+        Self {
+            form,
+            is_submitted: false,
+        }
+    }
+}
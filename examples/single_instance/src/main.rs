@@ -0,0 +1,123 @@
+use iced::single_instance;
+use iced::widget::tab_bar::Tab;
+use iced::widget::{center, column, scrollable, tab_bar, text};
+use iced::window;
+use iced::{Element, Fill, Font, Subscription, Task};
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn main() -> iced::Result {
+    iced::application("Single Instance - Iced", Viewer::update, Viewer::view)
+        .subscription(Viewer::subscription)
+        .single_instance("com.iced.single_instance")
+        .run_with(Viewer::new)
+}
+
+struct Viewer {
+    files: Vec<File>,
+    active: usize,
+}
+
+struct File {
+    path: PathBuf,
+    contents: Result<String, String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    SingleInstance(single_instance::Event),
+    Selected(usize),
+    Closed(usize),
+}
+
+impl Viewer {
+    fn new() -> (Self, Task<Message>) {
+        let mut viewer = Self {
+            files: Vec::new(),
+            active: 0,
+        };
+
+        viewer.open(env::args().skip(1));
+
+        (viewer, Task::none())
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::SingleInstance(single_instance::Event::InstanceArgs(
+                arguments,
+            )) => {
+                self.open(arguments);
+
+                window::get_latest().and_then(window::gain_focus)
+            }
+            Message::Selected(index) => {
+                self.active = index;
+
+                Task::none()
+            }
+            Message::Closed(index) => {
+                let _ = self.files.remove(index);
+
+                if index < self.active || self.active == self.files.len() {
+                    self.active = self.active.saturating_sub(1);
+                }
+
+                Task::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let Some(file) = self.files.get(self.active) else {
+            return center(text(
+                "Run the example again with some paths to open them here",
+            ))
+            .into();
+        };
+
+        let tabs = self.files.iter().map(|file| {
+            Tab::new(
+                file.path
+                    .file_name()
+                    .unwrap_or(file.path.as_os_str())
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+            .closable(true)
+        });
+
+        let contents = match &file.contents {
+            Ok(contents) => text(contents).font(Font::MONOSPACE),
+            Err(error) => {
+                text!("Could not open {}: {error}", file.path.display())
+            }
+        };
+
+        column![
+            tab_bar(tabs, self.active, Message::Selected)
+                .on_close(Message::Closed),
+            scrollable(contents).width(Fill).height(Fill),
+        ]
+        .padding(10)
+        .spacing(10)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        single_instance::listen().map(Message::SingleInstance)
+    }
+
+    fn open(&mut self, paths: impl IntoIterator<Item = String>) {
+        for path in paths {
+            let path = PathBuf::from(path);
+            let contents =
+                fs::read_to_string(&path).map_err(|error| error.to_string());
+
+            self.files.push(File { path, contents });
+            self.active = self.files.len() - 1;
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use iced::widget::{column, container, iced, slider, text, vertical_slider};
+use iced::widget::{column, container, iced, slider, text};
 use iced::{Center, Element, Fill};
 
 pub fn main() -> iced::Result {
@@ -36,7 +36,8 @@ impl Slider {
         .width(250);
 
         let v_slider = container(
-            vertical_slider(1..=100, self.value, Message::SliderChanged)
+            slider(1..=100, self.value, Message::SliderChanged)
+                .orientation(slider::Orientation::Vertical)
                 .default(50)
                 .shift_step(5),
         )
@@ -125,6 +125,7 @@ impl<Message> canvas::Program<Message> for State {
         _state: &Self::State,
         renderer: &Renderer,
         _theme: &Theme,
+        style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
@@ -139,7 +140,7 @@ impl<Message> canvas::Program<Message> for State {
                 });
 
                 frame.translate(frame.center() - Point::ORIGIN);
-                frame.fill(&stars, Color::WHITE);
+                frame.fill(&stars, style.text);
             });
 
         let system = self.system_cache.draw(renderer, bounds.size(), |frame| {
@@ -152,12 +153,13 @@ impl<Message> canvas::Program<Message> for State {
             frame.stroke(
                 &orbit,
                 Stroke {
-                    style: stroke::Style::Solid(Color::from_rgba8(
-                        0, 153, 255, 0.1,
-                    )),
+                    style: stroke::Style::Solid(Color {
+                        a: 0.1,
+                        ..style.primary.base.color
+                    }),
                     width: 1.0,
                     line_dash: canvas::LineDash {
-                        offset: 0,
+                        offset: 0.0,
                         segments: &[3.0, 6.0],
                     },
                     ..Stroke::default()
@@ -189,7 +191,7 @@ impl<Message> canvas::Program<Message> for State {
                     frame.translate(Vector::new(0.0, Self::MOON_DISTANCE));
 
                     let moon = Path::circle(Point::ORIGIN, Self::MOON_RADIUS);
-                    frame.fill(&moon, Color::WHITE);
+                    frame.fill(&moon, style.background.strong.color);
                 });
             });
         });
@@ -1,5 +1,9 @@
-use iced::widget::{center, checkbox, column, container, svg};
-use iced::{color, Element, Fill};
+use iced::widget::{button, center, checkbox, column, container, row, svg};
+use iced::{color, Center, Element, Fill};
+
+const ICON: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24">
+    <path fill="currentColor" d="M12 2 15 9h7l-5.5 4.5L18.5 21 12 16.5 5.5 21l2-7.5L2 9h7z"/>
+</svg>"#;
 
 pub fn main() -> iced::Result {
     iced::run("SVG - Iced", Tiger::update, Tiger::view)
@@ -46,8 +50,32 @@ impl Tiger {
             checkbox("Apply a color filter", self.apply_color_filter)
                 .on_toggle(Message::ToggleColorFilter);
 
+        // The icon is tinted with the text color of the button; which
+        // changes when it is hovered
+        let toggle = button(
+            svg(svg::Handle::from_memory(ICON))
+                .width(24)
+                .height(24)
+                .symbolic(true),
+        )
+        .on_press(Message::ToggleColorFilter(!self.apply_color_filter))
+        .style(|theme, status| {
+            let palette = theme.extended_palette();
+
+            button::Style {
+                text_color: match status {
+                    button::Status::Hovered => palette.primary.strong.color,
+                    _ => palette.background.base.text,
+                },
+                ..button::text(theme, status)
+            }
+        });
+
+        let controls =
+            row![apply_color_filter, toggle].spacing(10).align_y(Center);
+
         center(
-            column![svg, container(apply_color_filter).center_x(Fill)]
+            column![svg, container(controls).center_x(Fill)]
                 .spacing(20)
                 .height(Fill),
         )
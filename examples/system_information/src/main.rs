@@ -119,6 +119,11 @@ impl Example {
                 let graphics_backend =
                     text!("Graphics backend: {}", information.graphics_backend);
 
+                let graphics_formats = text!(
+                    "Graphics formats: {}",
+                    information.graphics_formats.as_deref().unwrap_or("None")
+                );
+
                 column![
                     system_name.size(30),
                     system_kernel.size(30),
@@ -130,6 +135,7 @@ impl Example {
                     memory_used.size(30),
                     graphics_adapter.size(30),
                     graphics_backend.size(30),
+                    graphics_formats.size(30),
                     button("Refresh").on_press(Message::Refresh)
                 ]
                 .spacing(10)
@@ -0,0 +1,224 @@
+use iced::widget::table::Direction;
+use iced::widget::{button, checkbox, column, row, table, text};
+use iced::{Center, Element, Fill, Task};
+
+use once_cell::sync::Lazy;
+
+static TABLE: Lazy<table::Id> = Lazy::new(table::Id::unique);
+
+pub fn main() -> iced::Result {
+    iced::application("Table - Iced", People::update, People::view).run()
+}
+
+struct People {
+    people: Vec<Person>,
+    order: Vec<usize>,
+    sort: (usize, Direction),
+    selected: Option<usize>,
+    widths: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Clone)]
+struct Person {
+    id: usize,
+    name: String,
+    city: &'static str,
+    score: u32,
+    is_active: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Sorted(usize, Direction),
+    Selected(usize),
+    Toggled(usize, bool),
+    SaveWidths,
+    WidthsSaved(Option<Vec<f32>>),
+    RestoreWidths,
+}
+
+impl People {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Sorted(column, direction) => {
+                self.sort = (column, direction);
+                self.sort_rows();
+
+                Task::none()
+            }
+            Message::Selected(row) => {
+                self.selected = Some(self.order[row]);
+
+                Task::none()
+            }
+            Message::Toggled(person, is_active) => {
+                self.people[person].is_active = is_active;
+
+                Task::none()
+            }
+            Message::SaveWidths => {
+                table::column_widths(TABLE.clone()).map(Message::WidthsSaved)
+            }
+            Message::WidthsSaved(widths) => {
+                self.widths = widths;
+
+                Task::none()
+            }
+            Message::RestoreWidths => match &self.widths {
+                Some(widths) => {
+                    table::set_column_widths(TABLE.clone(), widths.clone())
+                }
+                None => Task::none(),
+            },
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let columns = [
+            table::Column::new("#").width(70),
+            table::Column::new("Name").width(200).min_width(80),
+            table::Column::new("City"),
+            table::Column::new("Score").width(100),
+            table::Column::new("Active").width(80).sortable(false),
+        ];
+
+        let (column, direction) = self.sort;
+        let selected = self
+            .selected
+            .and_then(|person| self.order.iter().position(|&i| i == person));
+
+        let people = table(columns, self.order.len(), move |row| {
+            let person = &self.people[self.order[row]];
+
+            vec![
+                text(person.id).into(),
+                text(&person.name).into(),
+                text(person.city).into(),
+                text(person.score).into(),
+                checkbox("", person.is_active)
+                    .on_toggle(move |is_active| {
+                        Message::Toggled(person.id, is_active)
+                    })
+                    .into(),
+            ]
+        })
+        .id(TABLE.clone())
+        .sort(column, direction)
+        .selected(selected)
+        .on_sort(Message::Sorted)
+        .on_select(Message::Selected);
+
+        let selection = match self.selected {
+            Some(person) => {
+                let person = &self.people[person];
+
+                text!("Selected {} from {}", person.name, person.city)
+            }
+            None => text("Click a row to select it"),
+        };
+
+        let widths = match &self.widths {
+            Some(widths) => text!(
+                "Saved widths: {}",
+                widths
+                    .iter()
+                    .map(|width| format!("{width:.0}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => text("No saved widths"),
+        };
+
+        let controls = row![
+            selection.width(Fill),
+            widths,
+            button("Save widths").on_press(Message::SaveWidths),
+            button("Restore widths").on_press_maybe(
+                self.widths.is_some().then_some(Message::RestoreWidths)
+            ),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        column![people, controls].spacing(10).padding(10).into()
+    }
+
+    fn sort_rows(&mut self) {
+        let (column, direction) = self.sort;
+        let people = &self.people;
+
+        self.order.sort_by(|&a, &b| {
+            let (a, b) = (&people[a], &people[b]);
+
+            let ordering = match column {
+                1 => a.name.cmp(&b.name),
+                2 => a.city.cmp(b.city),
+                3 => a.score.cmp(&b.score),
+                _ => a.id.cmp(&b.id),
+            };
+
+            match direction {
+                Direction::Ascending => ordering,
+                Direction::Descending => ordering.reverse(),
+            }
+        });
+    }
+}
+
+impl Default for People {
+    fn default() -> Self {
+        let people: Vec<_> = (0..5_000).map(Person::generate).collect();
+
+        Self {
+            order: (0..people.len()).collect(),
+            people,
+            sort: (0, Direction::Ascending),
+            selected: None,
+            widths: None,
+        }
+    }
+}
+
+impl Person {
+    fn generate(id: usize) -> Self {
+        const SYLLABLES: [&str; 12] = [
+            "fe", "rris", "ka", "lo", "mi", "ra", "ne", "to", "su", "vi", "da",
+            "an",
+        ];
+
+        const CITIES: [&str; 8] = [
+            "Barcelona",
+            "Berlin",
+            "Lagos",
+            "Lima",
+            "Montreal",
+            "Osaka",
+            "Seoul",
+            "Sydney",
+        ];
+
+        // A tiny linear congruential generator is enough for fake data
+        let mut seed = (id as u64).wrapping_add(1);
+        let mut next = |range: usize| {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+
+            (seed >> 33) as usize % range
+        };
+
+        let mut name: String = (0..2 + next(2))
+            .map(|_| SYLLABLES[next(SYLLABLES.len())])
+            .collect();
+
+        name[..1].make_ascii_uppercase();
+
+        Self {
+            id,
+            name,
+            city: CITIES[next(CITIES.len())],
+            score: next(1_000) as u32,
+            is_active: next(2) == 0,
+        }
+    }
+}
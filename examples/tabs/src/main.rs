@@ -0,0 +1,99 @@
+use iced::widget::tab_bar::Tab;
+use iced::widget::{button, center, column, row, tab_bar, text};
+use iced::{Center, Element, Fill};
+
+pub fn main() -> iced::Result {
+    iced::run("Tabs - Iced", Tabs::update, Tabs::view)
+}
+
+struct Tabs {
+    documents: Vec<Document>,
+    active: usize,
+    next_id: usize,
+}
+
+struct Document {
+    id: usize,
+    is_pinned: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Selected(usize),
+    Closed(usize),
+    Opened,
+}
+
+impl Tabs {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(index) => {
+                self.active = index;
+            }
+            Message::Closed(index) => {
+                let _ = self.documents.remove(index);
+
+                if index < self.active || self.active == self.documents.len() {
+                    self.active = self.active.saturating_sub(1);
+                }
+            }
+            Message::Opened => {
+                self.documents.push(Document {
+                    id: self.next_id,
+                    is_pinned: false,
+                });
+
+                self.next_id += 1;
+                self.active = self.documents.len() - 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let tabs = self.documents.iter().map(|document| {
+            Tab::new(document.title()).closable(!document.is_pinned)
+        });
+
+        let tab_bar = tab_bar(tabs, self.active, Message::Selected)
+            .on_close(Message::Closed)
+            .cycle_with_keyboard(true);
+
+        let content = match self.documents.get(self.active) {
+            Some(document) => text!("This is {}", document.title()),
+            None => text("All tabs are closed"),
+        };
+
+        column![
+            row![tab_bar, button("+").on_press(Message::Opened)]
+                .spacing(10)
+                .align_y(Center),
+            center(content.size(30)).width(Fill).height(Fill),
+        ]
+        .padding(10)
+        .spacing(10)
+        .into()
+    }
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self {
+            documents: vec![Document {
+                id: 0,
+                is_pinned: true,
+            }],
+            active: 0,
+            next_id: 1,
+        }
+    }
+}
+
+impl Document {
+    fn title(&self) -> String {
+        if self.is_pinned {
+            String::from("Home")
+        } else {
+            format!("Document {}", self.id)
+        }
+    }
+}
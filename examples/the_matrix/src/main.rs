@@ -52,6 +52,7 @@ impl<Message> canvas::Program<Message> for TheMatrix {
         state: &Self::State,
         renderer: &Renderer,
         _theme: &Theme,
+        _style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<canvas::Geometry> {
@@ -425,6 +425,7 @@ mod toast {
             state: &'b mut Tree,
             layout: Layout<'_>,
             renderer: &Renderer,
+            viewport: &Rectangle,
             translation: Vector,
         ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
             let instants = state.state.downcast_mut::<Vec<Option<Instant>>>();
@@ -435,6 +436,7 @@ mod toast {
                 &mut content_state[0],
                 layout,
                 renderer,
+                viewport,
                 translation,
             );
 
@@ -447,6 +449,7 @@ mod toast {
                     on_close: &self.on_close,
                     timeout_secs: self.timeout_secs,
                 }))
+                .top_most()
             });
             let overlays =
                 content.into_iter().chain(toasts).collect::<Vec<_>>();
@@ -539,7 +542,8 @@ mod toast {
                 .zip(self.instants.iter_mut())
                 .map(|(((child, state), layout), instant)| {
                     let mut local_messages = vec![];
-                    let mut local_shell = Shell::new(&mut local_messages);
+                    let mut local_shell = Shell::new(&mut local_messages)
+                        .with_drag(shell.drag().cloned());
 
                     let status = child.as_widget_mut().on_event(
                         state,
@@ -10,37 +10,45 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use std::time::Duration;
+
 static INPUT_ID: Lazy<text_input::Id> = Lazy::new(text_input::Id::unique);
 
 pub fn main() -> iced::Result {
     #[cfg(not(target_arch = "wasm32"))]
     tracing_subscriber::fmt::init();
 
-    iced::application(Todos::title, Todos::update, Todos::view)
+    let todos = iced::application(Todos::title, Todos::update, Todos::view)
         .subscription(Todos::subscription)
         .font(include_bytes!("../fonts/icons.ttf").as_slice())
-        .window_size((500.0, 800.0))
-        .run_with(Todos::new)
+        .window_size((500.0, 800.0));
+
+    let new = Todos::new;
+
+    #[cfg(feature = "time-travel")]
+    let (todos, new) = (todos.record(), iced::debug::recorder::init(new));
+
+    todos.run_with(new)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Todos {
     Loading,
     Loaded(State),
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 struct State {
     input_value: String,
     filter: Filter,
     tasks: Vec<Task>,
     dirty: bool,
-    saving: bool,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     Loaded(Result<SavedState, LoadError>),
+    Save,
     Saved(Result<(), SaveError>),
     InputChanged(String),
     CreateTask,
@@ -88,7 +96,7 @@ impl Todos {
                 text_input::focus(INPUT_ID.clone())
             }
             Todos::Loaded(state) => {
-                let mut saved = false;
+                let mut changed = true;
 
                 let command = match message {
                     Message::InputChanged(value) => {
@@ -136,9 +144,22 @@ impl Todos {
                             Command::none()
                         }
                     }
+                    Message::Save => {
+                        state.dirty = false;
+                        changed = false;
+
+                        Command::perform(
+                            SavedState {
+                                input_value: state.input_value.clone(),
+                                filter: state.filter,
+                                tasks: state.tasks.clone(),
+                            }
+                            .save(),
+                            Message::Saved,
+                        )
+                    }
                     Message::Saved(_result) => {
-                        state.saving = false;
-                        saved = true;
+                        changed = false;
 
                         Command::none()
                     }
@@ -156,28 +177,21 @@ impl Todos {
                     Message::Loaded(_) => Command::none(),
                 };
 
-                if !saved {
-                    state.dirty = true;
+                if !changed {
+                    return command;
                 }
 
-                let save = if state.dirty && !state.saving {
-                    state.dirty = false;
-                    state.saving = true;
-
-                    Command::perform(
-                        SavedState {
-                            input_value: state.input_value.clone(),
-                            filter: state.filter,
-                            tasks: state.tasks.clone(),
-                        }
-                        .save(),
-                        Message::Saved,
-                    )
-                } else {
-                    Command::none()
-                };
-
-                Command::batch(vec![command, save])
+                state.dirty = true;
+
+                // Save once the changes settle for a second
+                Command::batch(vec![
+                    command,
+                    Command::debounce(
+                        "save",
+                        Duration::from_secs(1),
+                        Message::Save,
+                    ),
+                ])
             }
         }
     }
@@ -545,9 +559,6 @@ impl SavedState {
                 .map_err(|_| SaveError::Write)?;
         }
 
-        // This is a simple way to save at most once every couple seconds
-        async_std::task::sleep(std::time::Duration::from_secs(2)).await;
-
         Ok(())
     }
 }
@@ -581,8 +592,6 @@ impl SavedState {
             .set_item("state", &json)
             .map_err(|_| SaveError::Write)?;
 
-        let _ = wasm_timer::Delay::new(std::time::Duration::from_secs(2)).await;
-
         Ok(())
     }
 }
@@ -1,9 +1,10 @@
+use iced::navigation;
 use iced::widget::{
     button, checkbox, column, container, horizontal_space, image, radio, row,
     scrollable, slider, text, text_input, toggler, vertical_space,
 };
 use iced::widget::{Button, Column, Container, Slider};
-use iced::{Center, Color, Element, Fill, Font, Pixels};
+use iced::{Center, Color, Element, Fill, Font, Pixels, Subscription};
 
 pub fn main() -> iced::Result {
     #[cfg(target_arch = "wasm32")]
@@ -16,12 +17,13 @@ pub fn main() -> iced::Result {
     tracing_subscriber::fmt::init();
 
     iced::application(Tour::title, Tour::update, Tour::view)
+        .subscription(Tour::subscription)
         .centered()
         .run()
 }
 
 pub struct Tour {
-    screen: Screen,
+    screens: navigation::Stack<Screen>,
     slider: u8,
     layout: Layout,
     spacing: u16,
@@ -58,7 +60,7 @@ pub enum Message {
 
 impl Tour {
     fn title(&self) -> String {
-        let screen = match self.screen {
+        let screen = match self.screens.current() {
             Screen::Welcome => "Welcome",
             Screen::Radio => "Radio button",
             Screen::Toggler => "Toggler",
@@ -78,13 +80,11 @@ impl Tour {
     fn update(&mut self, event: Message) {
         match event {
             Message::BackPressed => {
-                if let Some(screen) = self.screen.previous() {
-                    self.screen = screen;
-                }
+                let _ = self.screens.pop();
             }
             Message::NextPressed => {
-                if let Some(screen) = self.screen.next() {
-                    self.screen = screen;
+                if let Some(screen) = self.screens.current().next() {
+                    self.screens.push(screen);
                 }
             }
             Message::SliderChanged(value) => {
@@ -136,7 +136,7 @@ impl Tour {
     fn view(&self) -> Element<Message> {
         let controls =
             row![]
-                .push_maybe(self.screen.previous().is_some().then(|| {
+                .push_maybe(self.screens.can_pop().then(|| {
                     padded_button("Back")
                         .on_press(Message::BackPressed)
                         .style(button::secondary)
@@ -146,7 +146,7 @@ impl Tour {
                     padded_button("Next").on_press(Message::NextPressed)
                 }));
 
-        let screen = match self.screen {
+        let screen = match self.screens.current() {
             Screen::Welcome => self.welcome(),
             Screen::Radio => self.radio(),
             Screen::Toggler => self.toggler(),
@@ -178,8 +178,12 @@ impl Tour {
         container(scrollable).center_y(Fill).into()
     }
 
+    fn subscription(&self) -> Subscription<Message> {
+        navigation::back_requests().map(|()| Message::BackPressed)
+    }
+
     fn can_continue(&self) -> bool {
-        match self.screen {
+        match self.screens.current() {
             Screen::Welcome => true,
             Screen::Radio => self.language == Some(Language::Rust),
             Screen::Toggler => self.toggler,
@@ -523,20 +527,6 @@ impl Screen {
             )
             .copied()
     }
-
-    pub fn previous(self) -> Option<Screen> {
-        let position = Self::ALL
-            .iter()
-            .copied()
-            .position(|screen| screen == self)
-            .expect("Screen must exist");
-
-        if position > 0 {
-            Some(Self::ALL[position - 1])
-        } else {
-            None
-        }
-    }
 }
 
 fn ferris<'a>(
@@ -616,7 +606,7 @@ pub enum Layout {
 impl Default for Tour {
     fn default() -> Self {
         Self {
-            screen: Screen::Welcome,
+            screens: navigation::Stack::new(Screen::Welcome),
             slider: 50,
             layout: Layout::Row,
             spacing: 20,
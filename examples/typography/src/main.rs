@@ -0,0 +1,88 @@
+use iced::font;
+use iced::widget::{
+    button, column, container, horizontal_rule, row, scrollable, text,
+    text_input,
+};
+use iced::{Alignment, Element, Fill, Font, Task};
+
+pub fn main() -> iced::Result {
+    iced::application("Typography - Iced", Typography::update, Typography::view)
+        .run_with(|| {
+            (
+                Typography::default(),
+                // The samples use large sizes; which take the longest to
+                // rasterize
+                font::preload_glyphs(Font::DEFAULT, [20u16, 40, 64], "")
+                    .then(|()| Task::none()),
+            )
+        })
+}
+
+#[derive(Default)]
+struct Typography {
+    amount: String,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    AmountChanged(String),
+}
+
+impl Typography {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::AmountChanged(amount) => {
+                self.amount = amount;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let comparison = |title, alignment| {
+            column![
+                text(title).size(14),
+                self.samples(alignment),
+                horizontal_rule(1),
+            ]
+            .spacing(10)
+        };
+
+        let content = column![
+            comparison("Center", Alignment::Center),
+            comparison("Baseline", Alignment::Baseline),
+        ]
+        .spacing(20)
+        .padding(20)
+        .max_width(600);
+
+        scrollable(container(content).center_x(Fill)).into()
+    }
+
+    fn samples(&self, alignment: Alignment) -> Element<Message> {
+        let temperature =
+            row![text("23").size(64), text("°C").size(20), text("Sunny")]
+                .spacing(5)
+                .align_y(alignment);
+
+        let price = row![
+            text("Total").size(16),
+            container(text("$1,299").size(40)).padding(10),
+            text(".99").size(20),
+        ]
+        .spacing(5)
+        .align_y(alignment);
+
+        let form = row![
+            text("Amount").size(24),
+            text_input("0.00", &self.amount)
+                .on_input(Message::AmountChanged)
+                .size(14)
+                .width(120),
+            button(text("Pay").size(18)),
+        ]
+        .spacing(10)
+        .align_y(alignment);
+
+        column![temperature, price, form].spacing(20).into()
+    }
+}
@@ -0,0 +1,246 @@
+use iced::task;
+use iced::updater::{self, Download, PublicKey, Release};
+use iced::widget::{
+    button, center, column, container, progress_bar, row, text, toggler,
+};
+use iced::{Center, Element, Fill, Task, Theme};
+
+// Replace these with the manifest and the public key of your releases
+const MANIFEST: &str = "https://example.com/updater/manifest.json";
+const PUBLIC_KEY: PublicKey = PublicKey::from_bytes([0; 32]);
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub fn main() -> iced::Result {
+    iced::application("Updater - Iced", Settings::update, Settings::view)
+        .theme(Settings::theme)
+        .run()
+}
+
+#[derive(Default)]
+struct Settings {
+    dark_mode: bool,
+    update: Update,
+}
+
+#[derive(Debug, Default)]
+enum Update {
+    #[default]
+    Idle,
+    Checking,
+    UpToDate,
+    Available(Release),
+    Downloading {
+        release: Release,
+        progress: Option<f32>,
+        _handle: task::Handle,
+    },
+    Ready(updater::Update),
+    Installed(Release),
+    Failed(updater::Error),
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    DarkModeToggled(bool),
+    Check,
+    Checked(Result<Option<Release>, updater::Error>),
+    Download,
+    Downloaded(Result<Download, updater::Error>),
+    Cancel,
+    Install,
+    Installed(Result<(), updater::Error>),
+}
+
+impl Settings {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::DarkModeToggled(dark_mode) => {
+                self.dark_mode = dark_mode;
+
+                Task::none()
+            }
+            Message::Check => {
+                self.update = Update::Checking;
+
+                updater::check(MANIFEST, VERSION, PUBLIC_KEY)
+                    .map(Message::Checked)
+            }
+            Message::Checked(result) => {
+                self.update = match result {
+                    Ok(Some(release)) => Update::Available(release),
+                    Ok(None) => Update::UpToDate,
+                    Err(error) => Update::Failed(error),
+                };
+
+                Task::none()
+            }
+            Message::Download => {
+                let Update::Available(release) = &self.update else {
+                    return Task::none();
+                };
+
+                let (task, handle) = updater::download(release)
+                    .map(Message::Downloaded)
+                    .abortable();
+
+                self.update = Update::Downloading {
+                    release: release.clone(),
+                    progress: Some(0.0),
+                    _handle: handle.abort_on_drop(),
+                };
+
+                task
+            }
+            Message::Downloaded(result) => {
+                let Update::Downloading { progress, .. } = &mut self.update
+                else {
+                    return Task::none();
+                };
+
+                match result {
+                    Ok(Download::Finished(update)) => {
+                        self.update = Update::Ready(update);
+                    }
+                    Ok(download) => {
+                        *progress = download.progress();
+                    }
+                    Err(error) => {
+                        self.update = Update::Failed(error);
+                    }
+                }
+
+                Task::none()
+            }
+            Message::Cancel => {
+                if let Update::Downloading { release, .. } = &self.update {
+                    self.update = Update::Available(release.clone());
+                }
+
+                Task::none()
+            }
+            Message::Install => {
+                let Update::Ready(update) = &self.update else {
+                    return Task::none();
+                };
+
+                updater::apply(update.clone()).map(Message::Installed)
+            }
+            Message::Installed(result) => {
+                let Update::Ready(update) = &self.update else {
+                    return Task::none();
+                };
+
+                self.update = match result {
+                    Ok(()) => Update::Installed(update.release().clone()),
+                    Err(error) => Update::Failed(error),
+                };
+
+                Task::none()
+            }
+        }
+    }
+
+    fn theme(&self) -> Theme {
+        if self.dark_mode {
+            Theme::Dark
+        } else {
+            Theme::Light
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let appearance = toggler(
+            String::from("Dark mode"),
+            self.dark_mode,
+            Message::DarkModeToggled,
+        );
+
+        let updates = column![
+            row![
+                text!("Version {VERSION}").width(Fill),
+                button("Check for updates").on_press_maybe(
+                    (!matches!(
+                        self.update,
+                        Update::Checking | Update::Downloading { .. }
+                    ))
+                    .then_some(Message::Check)
+                ),
+            ]
+            .align_y(Center),
+            self.update.view(),
+        ]
+        .spacing(10);
+
+        let settings = column![
+            text("Settings").size(30),
+            section("Appearance", appearance),
+            section("Updates", updates),
+        ]
+        .spacing(20)
+        .max_width(500);
+
+        center(settings).padding(20).into()
+    }
+}
+
+impl Update {
+    fn view(&self) -> Element<Message> {
+        match self {
+            Update::Idle => text("Updates are only checked on demand.").into(),
+            Update::Checking => text("Checking for updates...").into(),
+            Update::UpToDate => text("You are up to date!").into(),
+            Update::Available(release) => column![
+                text!("Version {} is available", release.version),
+                text(&release.notes).size(14),
+                button("Download").on_press(Message::Download),
+            ]
+            .spacing(10)
+            .into(),
+            Update::Downloading {
+                release, progress, ..
+            } => column![
+                text!("Downloading version {}...", release.version),
+                progress_bar(0.0..=1.0, progress.unwrap_or_default())
+                    .height(10),
+                button("Cancel")
+                    .style(button::secondary)
+                    .on_press(Message::Cancel),
+            ]
+            .spacing(10)
+            .into(),
+            Update::Ready(update) => column![
+                text!(
+                    "Version {} has been downloaded",
+                    update.release().version
+                ),
+                button("Install").on_press(Message::Install),
+            ]
+            .spacing(10)
+            .into(),
+            Update::Installed(release) => text!(
+                "Version {} will be used the next time you start the app.",
+                release.version
+            )
+            .into(),
+            Update::Failed(error) => text!("The update failed: {error}")
+                .style(text::danger)
+                .into(),
+        }
+    }
+}
+
+fn section<'a>(
+    title: &'a str,
+    content: impl Into<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    column![
+        text(title).size(20),
+        container(content)
+            .padding(15)
+            .width(Fill)
+            .style(container::rounded_box),
+    ]
+    .spacing(10)
+    .into()
+}
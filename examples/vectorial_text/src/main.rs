@@ -122,6 +122,7 @@ impl<Message> canvas::Program<Message> for State {
         _state: &Self::State,
         renderer: &Renderer,
         theme: &Theme,
+        _style: &canvas::Style,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<canvas::Geometry> {
@@ -0,0 +1,79 @@
+use iced::widget::{
+    button, column, container, row, scrollable, text, text_input, virtual_list,
+};
+use iced::{Center, Element, Fill};
+
+use std::collections::HashMap;
+
+const ROWS: usize = 100_000;
+
+pub fn main() -> iced::Result {
+    iced::application("Virtual List - Iced", Notes::update, Notes::view).run()
+}
+
+struct Notes {
+    count: usize,
+    notes: HashMap<usize, String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edited(usize, String),
+    Removed,
+    Reset,
+}
+
+impl Notes {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Edited(index, note) => {
+                let _ = self.notes.insert(index, note);
+            }
+            Message::Removed => {
+                self.count = self.count.saturating_sub(ROWS / 10);
+                self.notes.retain(|index, _| *index < self.count);
+            }
+            Message::Reset => {
+                self.count = ROWS;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let controls = row![
+            text!("{} rows", self.count).width(Fill),
+            button("Remove 10 000")
+                .on_press_maybe((self.count > 0).then_some(Message::Removed)),
+            button("Reset").on_press(Message::Reset),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let list = virtual_list(self.count, 40, |index| {
+            let note = self.notes.get(&index).map_or("", String::as_str);
+
+            row![
+                text!("#{index}").width(80),
+                text_input("Write a note...", note)
+                    .on_input(move |note| Message::Edited(index, note)),
+            ]
+            .spacing(10)
+            .padding([4, 0])
+            .align_y(Center)
+            .into()
+        });
+
+        container(column![controls, scrollable(list).height(Fill)].spacing(10))
+            .padding(20)
+            .into()
+    }
+}
+
+impl Default for Notes {
+    fn default() -> Self {
+        Self {
+            count: ROWS,
+            notes: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use iced::widget::{
+    button, center, checkbox, column, container, image, qr_code, row, text,
+    zoom_area,
+};
+use iced::{Center, Element, Fill};
+
+pub fn main() -> iced::Result {
+    iced::application("Zoom Area - Iced", Inspector::update, Inspector::view)
+        .run()
+}
+
+struct Inspector {
+    qr_code: qr_code::Data,
+    sprite: image::Handle,
+    is_crisp: bool,
+    clicks: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    CrispToggled(bool),
+    Clicked,
+}
+
+impl Inspector {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::CrispToggled(is_crisp) => {
+                self.is_crisp = is_crisp;
+            }
+            Message::Clicked => {
+                self.clicks += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let filter_method = if self.is_crisp {
+            image::FilterMethod::Nearest
+        } else {
+            image::FilterMethod::Linear
+        };
+
+        let qr_code = frame(
+            zoom_area(qr_code(&self.qr_code).cell_size(6))
+                .width(Fill)
+                .height(Fill),
+        );
+
+        let sprite = frame(
+            zoom_area(
+                column![
+                    image(self.sprite.clone()).width(192),
+                    button(text!("Clicked {} time(s)", self.clicks))
+                        .on_press(Message::Clicked),
+                ]
+                .spacing(10)
+                .align_x(Center),
+            )
+            .filter_method(filter_method)
+            .width(Fill)
+            .height(Fill),
+        );
+
+        let controls = row![
+            text("Ctrl + scroll to zoom, drag to pan, double click to reset")
+                .width(Fill),
+            checkbox("Nearest-neighbor filtering", self.is_crisp)
+                .on_toggle(Message::CrispToggled),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        column![row![qr_code, sprite].spacing(20).height(Fill), controls]
+            .spacing(20)
+            .padding(20)
+            .into()
+    }
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self {
+            qr_code: qr_code::Data::new("https://iced.rs")
+                .expect("Generate QR code"),
+            sprite: sprite(),
+            is_crisp: true,
+            clicks: 0,
+        }
+    }
+}
+
+fn frame<'a>(content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+    container(center(content))
+        .style(container::bordered_box)
+        .width(Fill)
+        .height(Fill)
+        .into()
+}
+
+/// Generates a tiny 12x12 smiley, so its pixels are worth zooming into.
+fn sprite() -> image::Handle {
+    const SPRITE: [&str; 12] = [
+        "....####....",
+        "..########..",
+        ".##########.",
+        ".###.##.###.",
+        "############",
+        "############",
+        "#.########.#",
+        "##.######.##",
+        ".###....###.",
+        ".##########.",
+        "..########..",
+        "....####....",
+    ];
+
+    let pixels = SPRITE
+        .iter()
+        .flat_map(|row| row.chars())
+        .flat_map(|pixel| match pixel {
+            '#' => [0xF5, 0xC2, 0x11, 0xFF],
+            _ => [0x00, 0x00, 0x00, 0x00],
+        })
+        .collect::<Vec<u8>>();
+
+    image::Handle::from_rgba(12, 12, pixels)
+}
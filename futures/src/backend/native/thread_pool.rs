@@ -16,4 +16,88 @@ impl crate::Executor for Executor {
 
 pub mod time {
     //! Listen and react to time.
+    use futures::channel::oneshot;
+    use futures::future::{self, Either, FutureExt};
+
+    use std::future::Future;
+    use std::sync::{Condvar, Mutex, MutexGuard, OnceLock, PoisonError};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    /// The pending timers, with their deadlines.
+    static TIMERS: Mutex<Vec<(Instant, oneshot::Sender<()>)>> =
+        Mutex::new(Vec::new());
+
+    /// Wakes the timer thread up when a new timer is added.
+    static WAKE: Condvar = Condvar::new();
+
+    /// Whether the timer thread is running.
+    static IS_RUNNING: OnceLock<bool> = OnceLock::new();
+
+    /// Returns a [`Future`] that completes once the given `duration` has
+    /// elapsed.
+    ///
+    /// The thread pool has no timers of its own, so every timer is driven
+    /// by a single background thread. If the thread cannot be started, the
+    /// [`Future`] never completes.
+    pub fn sleep(duration: Duration) -> impl Future<Output = ()> {
+        let (sender, receiver) = oneshot::channel();
+
+        let is_running = *IS_RUNNING.get_or_init(|| {
+            thread::Builder::new()
+                .name("iced timer".to_owned())
+                .spawn(run)
+                .map_err(|error| {
+                    log::error!("Failed to start the timer thread: {error}");
+                })
+                .is_ok()
+        });
+
+        if is_running {
+            timers().push((Instant::now() + duration, sender));
+            WAKE.notify_one();
+        }
+
+        receiver.then(|result| match result {
+            Ok(()) => Either::Left(future::ready(())),
+            Err(_) => Either::Right(future::pending()),
+        })
+    }
+
+    fn run() {
+        let mut timers = timers();
+
+        loop {
+            let now = Instant::now();
+
+            let (elapsed, pending): (Vec<_>, Vec<_>) =
+                std::mem::take(&mut *timers)
+                    .into_iter()
+                    .partition(|(deadline, _)| *deadline <= now);
+
+            *timers = pending;
+            timers.retain(|(_, sender)| !sender.is_canceled());
+
+            for (_, sender) in elapsed {
+                let _ = sender.send(());
+            }
+
+            let next = timers.iter().map(|(deadline, _)| *deadline).min();
+
+            timers = match next {
+                Some(deadline) => {
+                    WAKE.wait_timeout(timers, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => {
+                    WAKE.wait(timers).unwrap_or_else(PoisonError::into_inner)
+                }
+            };
+        }
+    }
+
+    fn timers() -> MutexGuard<'static, Vec<(Instant, oneshot::Sender<()>)>> {
+        TIMERS.lock().unwrap_or_else(PoisonError::into_inner)
+    }
 }
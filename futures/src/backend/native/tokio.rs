@@ -34,6 +34,16 @@ pub mod time {
         subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a [`Future`] that completes once the given `duration` has
+    /// elapsed.
+    ///
+    /// [`Future`]: std::future::Future
+    pub fn sleep(
+        duration: std::time::Duration,
+    ) -> impl std::future::Future<Output = ()> {
+        tokio::time::sleep(duration)
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
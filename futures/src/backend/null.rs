@@ -19,4 +19,14 @@ impl crate::Executor for Executor {
 
 pub mod time {
     //! Listen and react to time.
+
+    /// Returns a [`Future`] that never completes, since nothing is ever run
+    /// by this backend.
+    ///
+    /// [`Future`]: std::future::Future
+    pub fn sleep(
+        _duration: std::time::Duration,
+    ) -> impl std::future::Future<Output = ()> {
+        futures::future::pending()
+    }
 }
@@ -42,10 +42,63 @@ where
             window,
             event,
             status,
+            ..
         } => f(event, status, window),
     })
 }
 
+/// An [`Event`] that was not captured by any widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unhandled {
+    /// The [`Event`] itself.
+    pub event: Event,
+    /// The window where the [`Event`] happened.
+    pub window: window::Id,
+    /// Whether a widget accepting text input had focus when the [`Event`]
+    /// happened.
+    ///
+    /// Shortcuts of last resort should normally be ignored in this case,
+    /// since the user is most likely typing.
+    pub is_text_focused: bool,
+}
+
+/// Returns a [`Subscription`] to the runtime events that no widget handled.
+///
+/// Unlike [`listen`], every [`Unhandled`] event also tells whether a widget
+/// accepting text input had focus; which is useful to implement shortcuts
+/// of last resort—like pressing `Space` anywhere to play or pause—that must
+/// not trigger while the user is typing.
+pub fn listen_unhandled() -> Subscription<Unhandled> {
+    #[derive(Hash)]
+    struct ListenUnhandled;
+
+    subscription::filter_map(ListenUnhandled, unhandled)
+}
+
+fn unhandled(event: subscription::Event) -> Option<Unhandled> {
+    match event {
+        subscription::Event::Interaction {
+            event: Event::Window(window::Event::RedrawRequested(_)),
+            ..
+        }
+        | subscription::Event::Interaction {
+            status: event::Status::Captured,
+            ..
+        }
+        | subscription::Event::PlatformSpecific(_) => None,
+        subscription::Event::Interaction {
+            window,
+            event,
+            is_text_focused,
+            ..
+        } => Some(Unhandled {
+            event,
+            window,
+            is_text_focused,
+        }),
+    }
+}
+
 /// Creates a [`Subscription`] that produces a message for every runtime event,
 /// including the redraw request events.
 ///
@@ -65,6 +118,7 @@ where
             window,
             event,
             status,
+            ..
         } => f(event, status, window),
         subscription::Event::PlatformSpecific(_) => None,
     })
@@ -89,3 +143,56 @@ pub fn listen_url() -> Subscription<String> {
         _ => None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::{self, key};
+
+    fn space() -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Space),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: Some(" ".into()),
+        })
+    }
+
+    fn interaction(
+        status: event::Status,
+        is_text_focused: bool,
+    ) -> subscription::Event {
+        subscription::Event::Interaction {
+            window: window::Id::unique(),
+            event: space(),
+            status,
+            is_text_focused,
+        }
+    }
+
+    #[test]
+    fn captured_events_are_not_unhandled() {
+        assert_eq!(
+            unhandled(interaction(event::Status::Captured, false)),
+            None
+        );
+        assert_eq!(unhandled(interaction(event::Status::Captured, true)), None);
+    }
+
+    #[test]
+    fn ignored_events_are_unhandled() {
+        let unhandled = unhandled(interaction(event::Status::Ignored, false))
+            .expect("Space should be unhandled");
+
+        assert_eq!(unhandled.event, space());
+        assert!(!unhandled.is_text_focused);
+    }
+
+    #[test]
+    fn unhandled_events_report_text_focus() {
+        let unhandled = unhandled(interaction(event::Status::Ignored, true))
+            .expect("Space should be unhandled");
+
+        assert!(unhandled.is_text_focused);
+    }
+}
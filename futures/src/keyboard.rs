@@ -54,3 +54,44 @@ where
         _ => None,
     })
 }
+
+/// Returns a [`Subscription`] that produces the current [`Modifiers`]
+/// whenever they change.
+pub fn modifiers() -> Subscription<Modifiers> {
+    #[derive(Hash)]
+    struct OnModifiersChanged;
+
+    subscription::filter_map(OnModifiersChanged, move |event| match event {
+        subscription::Event::Interaction {
+            event: core::Event::Keyboard(Event::ModifiersChanged(modifiers)),
+            ..
+        } => Some(modifiers),
+        _ => None,
+    })
+}
+
+/// Listens to changes of the latched modifiers and calls the given
+/// function to map them into actual messages.
+///
+/// Modifiers are only latched when sticky modifiers are enabled; this can
+/// be used to display an indicator of the latched ones.
+pub fn on_modifiers_latched<Message>(
+    f: fn(Modifiers) -> Message,
+) -> Subscription<Message>
+where
+    Message: MaybeSend + 'static,
+{
+    #[derive(Hash)]
+    struct OnModifiersLatched;
+
+    subscription::filter_map(
+        (OnModifiersLatched, f),
+        move |event| match event {
+            subscription::Event::Interaction {
+                event: core::Event::Keyboard(Event::ModifiersLatched(modifiers)),
+                ..
+            } => Some(f(modifiers)),
+            _ => None,
+        },
+    )
+}
@@ -25,6 +25,10 @@ pub enum Event {
 
         /// The [`event::Status`] of the interaction.
         status: event::Status,
+
+        /// Whether a widget accepting text input had focus when the
+        /// interaction took place.
+        is_text_focused: bool,
     },
 
     /// A platform specific event.
@@ -19,10 +19,43 @@ impl Packed {
 pub const GAMMA_CORRECTION: bool = internal::GAMMA_CORRECTION;
 
 /// Packs a [`Color`].
+///
+/// The packed components are not premultiplied; blending them with
+/// `SrcAlpha` over a premultiplied target keeps it premultiplied, which is
+/// what translucent windows are composited with.
 pub fn pack(color: impl Into<Color>) -> Packed {
     Packed(internal::pack(color.into()))
 }
 
+/// Unpacks a [`Packed`] color back into a [`Color`].
+pub fn unpack(packed: Packed) -> Color {
+    internal::unpack(packed.0)
+}
+
+/// Maps a [`Color`] into the standard dynamic range, so it can be
+/// displayed on a surface that does not support HDR.
+///
+/// Colors with components above `1.0` are scaled down in linear space
+/// until their brightest component is `1.0`; which keeps their hue intact
+/// instead of washing them out. Any other color is only clamped.
+pub fn tone_map(color: Color) -> Color {
+    let [r, g, b, a] = color.into_linear();
+    let peak = r.max(g).max(b);
+
+    let color = if peak > 1.0 {
+        Color::from_linear_rgba(r / peak, g / peak, b / peak, a)
+    } else {
+        color
+    };
+
+    Color {
+        r: color.r.clamp(0.0, 1.0),
+        g: color.g.clamp(0.0, 1.0),
+        b: color.b.clamp(0.0, 1.0),
+        a: color.a.clamp(0.0, 1.0),
+    }
+}
+
 #[cfg(not(feature = "web-colors"))]
 mod internal {
     use crate::core::Color;
@@ -32,6 +65,10 @@ mod internal {
     pub fn pack(color: Color) -> [f32; 4] {
         color.into_linear()
     }
+
+    pub fn unpack([r, g, b, a]: [f32; 4]) -> Color {
+        Color::from_linear_rgba(r, g, b, a)
+    }
 }
 
 #[cfg(feature = "web-colors")]
@@ -43,4 +80,8 @@ mod internal {
     pub fn pack(color: Color) -> [f32; 4] {
         [color.r, color.g, color.b, color.a]
     }
+
+    pub fn unpack([r, g, b, a]: [f32; 4]) -> Color {
+        Color::from_rgba(r, g, b, a)
+    }
 }
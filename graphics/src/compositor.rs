@@ -19,11 +19,64 @@ pub trait Compositor: Sized {
     type Surface;
 
     /// Creates a new [`Compositor`].
+    ///
+    /// It tries every backend in [`Settings::backends`] in order—or in the
+    /// `ICED_BACKEND` environment variable, if set—until one of them can be
+    /// created; returning an [`Error::List`] with every failure otherwise.
     fn new<W: Window + Clone>(
         settings: Settings,
         compatible_window: W,
     ) -> impl Future<Output = Result<Self, Error>> {
-        Self::with_backend(settings, compatible_window, None)
+        async move {
+            let candidates: Vec<String> = match std::env::var("ICED_BACKEND") {
+                Ok(backends) => backends
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|candidate| !candidate.is_empty())
+                    .map(str::to_owned)
+                    .collect(),
+                Err(_) => settings
+                    .backends
+                    .iter()
+                    .map(|backend| backend.name().to_owned())
+                    .collect(),
+            };
+
+            if candidates.is_empty() {
+                return Self::with_backend(settings, compatible_window, None)
+                    .await;
+            }
+
+            let mut errors = Vec::new();
+
+            for candidate in &candidates {
+                match Self::with_backend(
+                    settings,
+                    compatible_window.clone(),
+                    Some(candidate.as_str()),
+                )
+                .await
+                {
+                    Ok(compositor) => {
+                        let information = compositor.fetch_information();
+
+                        log::info!(
+                            "Selected renderer: {candidate} ({} on {})",
+                            information.backend,
+                            information.adapter
+                        );
+
+                        return Ok(compositor);
+                    }
+                    Err(Error::List(list)) => errors.extend(list),
+                    Err(error) => errors.push(error),
+                }
+
+                log::warn!("The {candidate} backend could not be created");
+            }
+
+            Err(Error::List(errors))
+        }
     }
 
     /// Creates a new [`Compositor`] with a backend preference.
@@ -70,6 +123,35 @@ pub trait Compositor: Sized {
             .load_font(font);
     }
 
+    /// Invalidates the text shaped by the [`Compositor`]; so it is shaped
+    /// again with the current fonts.
+    fn invalidate_text(&mut self) {
+        crate::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .invalidate();
+    }
+
+    /// Warms up the [`Compositor`], so the first frame it presents is not
+    /// slowed down by lazy initialization; like the compilation of shaders.
+    ///
+    /// By default, it does nothing.
+    fn warm_up(&mut self) {}
+
+    /// Rasterizes the glyphs of the `charset` in the given [`Font`] and
+    /// sizes ahead of time.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Font`]: crate::core::Font
+    fn preload_glyphs(
+        &mut self,
+        _font: crate::core::Font,
+        _sizes: &[crate::core::Pixels],
+        _charset: &str,
+    ) {
+    }
+
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
     /// [`Renderer`]: Self::Renderer
@@ -145,6 +227,9 @@ pub struct Information {
     pub adapter: String,
     /// Contains the graphics backend.
     pub backend: String,
+    /// Contains the texture formats negotiated with the graphics adapter,
+    /// if any.
+    pub formats: Option<String>,
 }
 
 #[cfg(debug_assertions)]
@@ -180,10 +265,13 @@ impl Compositor for () {
 
     fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
 
+    fn invalidate_text(&mut self) {}
+
     fn fetch_information(&self) -> Information {
         Information {
             adapter: String::from("Null Renderer"),
             backend: String::from("Null"),
+            formats: None,
         }
     }
 
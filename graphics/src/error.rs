@@ -1,4 +1,5 @@
 //! See what can go wrong when creating graphical backends.
+use std::fmt;
 
 /// An error that occurred while creating an application's graphical context.
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
@@ -12,7 +13,7 @@ pub enum Error {
     NoAvailablePixelFormat,
 
     /// A suitable graphics adapter or device could not be found.
-    #[error("a suitable graphics adapter or device could not be found")]
+    #[error("{backend}: {reason}")]
     GraphicsAdapterNotFound {
         /// The name of the backend where the error happened
         backend: &'static str,
@@ -24,11 +25,64 @@ pub enum Error {
     #[error("an error occurred in the context's internal backend")]
     BackendError(String),
 
-    /// Multiple errors occurred
-    #[error("multiple errors occurred: {0:?}")]
+    /// Multiple errors occurred; one for every backend that was tried.
+    #[error("every backend failed ({})", list(.0))]
     List(Vec<Self>),
 }
 
+impl Error {
+    /// Returns the errors of every backend that was tried and could not be
+    /// created.
+    ///
+    /// Backends skipped because they did not match the preference are left
+    /// out, unless no other backend was tried.
+    pub fn failures(&self) -> Vec<&Error> {
+        failures(std::slice::from_ref(self))
+    }
+}
+
+fn failures(errors: &[Error]) -> Vec<&Error> {
+    fn flatten<'a>(errors: &'a [Error], flattened: &mut Vec<&'a Error>) {
+        for error in errors {
+            match error {
+                Error::List(list) => flatten(list, flattened),
+                error => flattened.push(error),
+            }
+        }
+    }
+
+    let mut all = Vec::new();
+    flatten(errors, &mut all);
+
+    let failures: Vec<_> = all
+        .iter()
+        .copied()
+        .filter(|error| {
+            !matches!(
+                error,
+                Error::GraphicsAdapterNotFound {
+                    reason: Reason::DidNotMatch { .. },
+                    ..
+                }
+            )
+        })
+        .collect();
+
+    if failures.is_empty() {
+        all
+    } else {
+        failures
+    }
+}
+
+fn list(errors: &[Error]) -> String {
+    failures(errors)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 /// The reason why a graphics adapter could not be found
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Reason {
@@ -39,4 +93,33 @@ pub enum Reason {
     },
     /// The request to create the backend failed
     RequestFailed(String),
+    /// No adapter was compatible with the requested options
+    AdapterNotFound {
+        /// The options used to request the adapter
+        options: String,
+        /// The adapters that were available
+        available: Vec<String>,
+    },
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DidNotMatch { preferred_backend } => {
+                write!(f, "the preferred backend was {preferred_backend}")
+            }
+            Self::RequestFailed(reason) => {
+                write!(f, "the request failed: {reason}")
+            }
+            Self::AdapterNotFound { options, available } => {
+                write!(f, "no adapter was compatible with {options}")?;
+
+                if available.is_empty() {
+                    write!(f, " and no adapters were available")
+                } else {
+                    write!(f, " among: {}", available.join(", "))
+                }
+            }
+        }
+    }
 }
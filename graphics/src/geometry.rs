@@ -17,6 +17,7 @@ pub use style::Style;
 pub use text::Text;
 
 pub use crate::gradient::{self, Gradient};
+pub use crate::mesh::Blend;
 
 use crate::cache::Cached;
 use crate::core::{self, Size};
@@ -34,6 +35,50 @@ pub trait Renderer: core::Renderer {
 
     /// Draws the given [`Self::Geometry`].
     fn draw_geometry(&mut self, geometry: Self::Geometry);
+
+    /// Draws the given [`Self::Geometry`] over everything drawn before in
+    /// the current layer.
+    ///
+    /// Renderers draw each kind of primitive of a layer at once; so the
+    /// meshes of a [`Self::Geometry`] drawn with [`draw_geometry`] may end
+    /// up under text drawn before them. This keeps the drawing order
+    /// instead; normally, by continuing the current layer in a new one
+    /// when needed.
+    ///
+    /// By default, it is the same as [`draw_geometry`].
+    ///
+    /// [`draw_geometry`]: Self::draw_geometry
+    fn draw_geometry_over(&mut self, geometry: Self::Geometry) {
+        self.draw_geometry(geometry);
+    }
+
+    /// Starts clipping the primitives recorded until [`end_clip`] is called
+    /// to the given [`Path`], filled with the non-zero rule.
+    ///
+    /// The primitives are recorded in a new layer; like [`start_layer`]
+    /// does, with the bounds of the [`Path`].
+    ///
+    /// By default, the primitives are only clipped to the bounds of the
+    /// [`Path`].
+    ///
+    /// [`end_clip`]: Self::end_clip
+    /// [`start_layer`]: core::Renderer::start_layer
+    fn start_clip(&mut self, path: &Path) {
+        self.start_layer(path.bounds());
+    }
+
+    /// Ends clipping to the last [`Path`] started.
+    fn end_clip(&mut self) {
+        self.end_layer();
+    }
+
+    /// Clips the primitives recorded in the given closure to the given
+    /// [`Path`].
+    fn with_clip(&mut self, path: &Path, f: impl FnOnce(&mut Self)) {
+        self.start_clip(path);
+        f(self);
+        self.end_clip();
+    }
 }
 
 #[cfg(debug_assertions)]
@@ -8,6 +8,10 @@ pub use cache::Group;
 ///
 /// A [`Cache`] will not redraw its geometry unless the dimensions of its layer
 /// change or it is explicitly cleared.
+///
+/// A single program can draw with many caches; like a static background and
+/// a dynamic overlay. Clearing one of them only redraws its own geometry,
+/// and renderers only upload the geometry that changed.
 pub struct Cache<Renderer>
 where
     Renderer: geometry::Renderer,
@@ -19,6 +23,7 @@ where
 struct Data<T> {
     bounds: Size,
     geometry: T,
+    generation: u64,
 }
 
 impl<Renderer> Cache<Renderer>
@@ -49,6 +54,27 @@ where
         self.raw.clear();
     }
 
+    /// Returns true if the [`Cache`] has no geometry; either because it was
+    /// never drawn or because it was cleared.
+    pub fn is_empty(&self) -> bool {
+        matches!(*self.raw.state().borrow(), cache::State::Empty { .. })
+    }
+
+    /// Returns the generation of the geometry of the [`Cache`]; that is, the
+    /// amount of times its geometry has been drawn.
+    ///
+    /// It starts at `0` and it only increases when the closure given to
+    /// [`Cache::draw`] is called; so it can be used to tell if the geometry
+    /// was rebuilt.
+    pub fn generation(&self) -> u64 {
+        match &*self.raw.state().borrow() {
+            cache::State::Empty { previous } => {
+                previous.as_ref().map_or(0, |data| data.generation)
+            }
+            cache::State::Filled { current } => current.generation,
+        }
+    }
+
     /// Draws geometry using the provided closure and stores it in the
     /// [`Cache`].
     ///
@@ -70,26 +96,33 @@ where
         let state = self.raw.state();
 
         let previous = match state.borrow().deref() {
-            cache::State::Empty { previous } => {
-                previous.as_ref().map(|data| data.geometry.clone())
-            }
+            cache::State::Empty { previous } => previous.clone(),
             cache::State::Filled { current } => {
                 if current.bounds == bounds {
                     return Cached::load(&current.geometry);
                 }
 
-                Some(current.geometry.clone())
+                Some(current.clone())
             }
         };
 
+        let generation =
+            previous.as_ref().map_or(0, |data| data.generation) + 1;
+
         let mut frame = Frame::new(renderer, bounds);
         draw_fn(&mut frame);
 
-        let geometry = frame.into_geometry().cache(self.raw.group(), previous);
+        let geometry = frame
+            .into_geometry()
+            .cache(self.raw.group(), previous.map(|data| data.geometry));
         let result = Cached::load(&geometry);
 
         *state.borrow_mut() = cache::State::Filled {
-            current: Data { bounds, geometry },
+            current: Data {
+                bounds,
+                geometry,
+                generation,
+            },
         };
 
         result
@@ -114,3 +147,33 @@ where
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generation_counts_redraws() {
+        let cache = Cache::<()>::new();
+        let size = Size::new(100.0, 100.0);
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.generation(), 0);
+
+        cache.draw(&(), size, |_| {});
+        cache.draw(&(), size, |_| {});
+
+        assert!(!cache.is_empty());
+        assert_eq!(cache.generation(), 1);
+
+        cache.clear();
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.generation(), 1);
+
+        cache.draw(&(), size, |_| {});
+        cache.draw(&(), Size::new(50.0, 50.0), |_| {});
+
+        assert_eq!(cache.generation(), 3);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! [`Geometry`]: super::Renderer::Geometry
 pub use crate::geometry::Style;
+pub use crate::mesh::Blend;
 
 use crate::core::Color;
 use crate::gradient::{self, Gradient};
@@ -23,6 +24,11 @@ pub struct Fill {
     ///
     /// [1]: https://www.w3.org/TR/SVG/painting.html#FillRuleProperty
     pub rule: Rule,
+
+    /// The blend mode of the fill.
+    ///
+    /// By default, it is set to [`Blend::Alpha`].
+    pub blend: Blend,
 }
 
 impl Default for Fill {
@@ -30,6 +36,7 @@ impl Default for Fill {
         Self {
             style: Style::Solid(Color::BLACK),
             rule: Rule::NonZero,
+            blend: Blend::Alpha,
         }
     }
 }
@@ -1,5 +1,5 @@
 //! Draw and generate geometry.
-use crate::core::{Point, Radians, Rectangle, Size, Vector};
+use crate::core::{image, svg, Point, Radians, Rectangle, Size, Vector};
 use crate::geometry::{self, Fill, Path, Stroke, Text};
 
 /// The region of a surface that can be used to draw geometry.
@@ -75,6 +75,34 @@ where
         self.raw.fill_text(text);
     }
 
+    /// Draws the raster image of the given [`image::Handle`] on the
+    /// [`Frame`], covering the given bounds.
+    ///
+    /// The image follows the current transform of the [`Frame`] and it is
+    /// clipped like the rest of its geometry. Like text, images are drawn
+    /// on top of the other geometry of the [`Frame`].
+    pub fn draw_image(
+        &mut self,
+        bounds: Rectangle,
+        handle: impl Into<image::Handle>,
+    ) {
+        self.raw.draw_image(bounds, handle.into());
+    }
+
+    /// Draws the vector image of the given [`svg::Handle`] on the [`Frame`],
+    /// covering the given bounds.
+    ///
+    /// See [`draw_image`] for how it is transformed and clipped.
+    ///
+    /// [`draw_image`]: Self::draw_image
+    pub fn draw_svg(
+        &mut self,
+        bounds: Rectangle,
+        handle: impl Into<svg::Handle>,
+    ) {
+        self.raw.draw_svg(bounds, handle.into());
+    }
+
     /// Stores the current transform of the [`Frame`] and executes the given
     /// drawing operations, restoring the transform afterwards.
     ///
@@ -192,6 +220,8 @@ pub trait Backend: Sized {
 
     fn fill(&mut self, path: &Path, fill: impl Into<Fill>);
     fn fill_text(&mut self, text: impl Into<Text>);
+    fn draw_image(&mut self, bounds: Rectangle, handle: image::Handle);
+    fn draw_svg(&mut self, bounds: Rectangle, handle: svg::Handle);
     fn fill_rectangle(
         &mut self,
         top_left: Point,
@@ -237,6 +267,8 @@ impl Backend for () {
 
     fn fill(&mut self, _path: &Path, _fill: impl Into<Fill>) {}
     fn fill_text(&mut self, _text: impl Into<Text>) {}
+    fn draw_image(&mut self, _bounds: Rectangle, _handle: image::Handle) {}
+    fn draw_svg(&mut self, _bounds: Rectangle, _handle: svg::Handle) {}
     fn fill_rectangle(
         &mut self,
         _top_left: Point,
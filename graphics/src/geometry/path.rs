@@ -2,6 +2,8 @@
 pub mod arc;
 
 mod builder;
+mod dash;
+mod simplify;
 
 #[doc(no_inline)]
 pub use arc::Arc;
@@ -9,7 +11,20 @@ pub use builder::Builder;
 
 pub use lyon_path;
 
-use iced_core::{Point, Size};
+use crate::geometry::stroke::LineDash;
+
+use iced_core::{Point, Rectangle, Size};
+
+use lyon_path::iterator::PathIterator;
+use lyon_path::PathEvent;
+
+/// The tolerance used to flatten the curves of a [`Path`] when testing
+/// whether it contains a point.
+const CONTAINS_TOLERANCE: f32 = 0.1;
+
+/// The tolerance used to flatten the curves of a [`Path`] before splitting
+/// it into dashes.
+const DASH_TOLERANCE: f32 = 0.1;
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -59,6 +74,90 @@ impl Path {
         &self.raw
     }
 
+    /// Returns a simplified version of the current [`Path`], where any detail
+    /// smaller than the given `tolerance` is dropped.
+    ///
+    /// Curves are flattened into line segments first, which are then reduced
+    /// using the Ramer–Douglas–Peucker algorithm. The endpoints of every
+    /// sub-path are preserved and closed sub-paths stay closed.
+    pub fn simplify(&self, tolerance: f32) -> Path {
+        Path {
+            raw: simplify::simplify(&self.raw, tolerance),
+        }
+    }
+
+    /// Returns the dashes of the current [`Path`], following the given
+    /// [`LineDash`] pattern.
+    ///
+    /// Curves are flattened into line segments first; so dashes follow them
+    /// exactly. The pattern restarts at every sub-path, and a dash that wraps
+    /// around the start of a closed sub-path stays joined.
+    pub fn dash(&self, line_dash: LineDash<'_>) -> Path {
+        Path {
+            raw: dash::dash(
+                &self.raw,
+                line_dash.segments,
+                line_dash.offset,
+                DASH_TOLERANCE,
+            ),
+        }
+    }
+
+    /// Returns the bounds of the [`Path`].
+    ///
+    /// The bounds include the control points of its curves; so they may be
+    /// slightly larger than the area the [`Path`] covers.
+    pub fn bounds(&self) -> Rectangle {
+        let mut points = self.raw.points().iter();
+
+        let Some(first) = points.next() else {
+            return Rectangle::new(Point::ORIGIN, Size::ZERO);
+        };
+
+        let (min, max) = points.fold((*first, *first), |(min, max), point| {
+            (min.min(*point), max.max(*point))
+        });
+
+        Rectangle {
+            x: min.x,
+            y: min.y,
+            width: max.x - min.x,
+            height: max.y - min.y,
+        }
+    }
+
+    /// Returns true if the given [`Point`] is inside the [`Path`], when
+    /// filled with the non-zero rule.
+    ///
+    /// Every sub-path is considered closed.
+    pub fn contains(&self, point: Point) -> bool {
+        let mut winding = 0;
+
+        let mut cross = |from: lyon_path::math::Point,
+                         to: lyon_path::math::Point| {
+            let side = (to.x - from.x) * (point.y - from.y)
+                - (point.x - from.x) * (to.y - from.y);
+
+            if from.y <= point.y {
+                if to.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if to.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        };
+
+        for event in self.raw.iter().flattened(CONTAINS_TOLERANCE) {
+            match event {
+                PathEvent::Line { from, to } => cross(from, to),
+                PathEvent::End { last, first, .. } => cross(last, first),
+                _ => {}
+            }
+        }
+
+        winding != 0
+    }
+
     /// Returns the current [`Path`] with the given transform applied to it.
     #[inline]
     pub fn transform(&self, transform: &lyon_path::math::Transform) -> Path {
@@ -67,3 +166,56 @@ impl Path {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_cover_every_point() {
+        let path = Path::new(|builder| {
+            builder.move_to(Point::new(10.0, 20.0));
+            builder.line_to(Point::new(-5.0, 40.0));
+            builder.line_to(Point::new(30.0, 25.0));
+        });
+
+        assert_eq!(
+            path.bounds(),
+            Rectangle {
+                x: -5.0,
+                y: 20.0,
+                width: 35.0,
+                height: 20.0,
+            }
+        );
+    }
+
+    #[test]
+    fn contains_follows_the_non_zero_rule() {
+        let circle = Path::circle(Point::new(50.0, 50.0), 20.0);
+
+        assert!(circle.contains(Point::new(50.0, 50.0)));
+        assert!(circle.contains(Point::new(65.0, 50.0)));
+        assert!(!circle.contains(Point::new(66.0, 66.0)));
+        assert!(!circle.contains(Point::new(0.0, 0.0)));
+
+        // An open triangle is closed implicitly
+        let triangle = Path::new(|builder| {
+            builder.move_to(Point::new(0.0, 0.0));
+            builder.line_to(Point::new(100.0, 0.0));
+            builder.line_to(Point::new(0.0, 100.0));
+        });
+
+        assert!(triangle.contains(Point::new(10.0, 10.0)));
+        assert!(!triangle.contains(Point::new(90.0, 90.0)));
+
+        // Overlapping sub-paths with the same orientation stay filled
+        let squares = Path::new(|builder| {
+            builder.rectangle(Point::ORIGIN, Size::new(20.0, 20.0));
+            builder.rectangle(Point::new(10.0, 10.0), Size::new(20.0, 20.0));
+        });
+
+        assert!(squares.contains(Point::new(15.0, 15.0)));
+        assert!(!squares.contains(Point::new(25.0, 5.0)));
+    }
+}
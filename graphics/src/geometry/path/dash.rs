@@ -0,0 +1,325 @@
+use lyon_path::iterator::PathIterator;
+use lyon_path::math::Point;
+use lyon_path::PathEvent;
+
+/// Splits the given path into dashes, following a pattern of alternating
+/// dash and gap lengths that starts at the given offset.
+///
+/// The pattern restarts at every sub-path. A dash that wraps around the start
+/// of a closed sub-path is joined with the first one; so they are stroked as
+/// a single dash.
+///
+/// The path is returned as is if the pattern would not produce any gaps.
+pub fn dash(
+    path: &lyon_path::Path,
+    segments: &[f32],
+    offset: f32,
+    tolerance: f32,
+) -> lyon_path::Path {
+    let is_valid = segments
+        .iter()
+        .all(|segment| segment.is_finite() && *segment >= 0.0);
+
+    let length: f32 = segments.iter().sum();
+
+    if !is_valid || length <= 0.0 || !offset.is_finite() {
+        return path.clone();
+    }
+
+    // An odd number of segments is repeated, so dashes and gaps alternate
+    let pattern = if segments.len() % 2 == 1 {
+        [segments, segments].concat()
+    } else {
+        segments.to_vec()
+    };
+
+    let start = offset.rem_euclid(pattern.iter().sum());
+
+    let mut builder = lyon_path::Path::builder();
+    let mut points = Vec::new();
+
+    for event in path.iter().flattened(tolerance) {
+        match event {
+            PathEvent::Begin { at } => {
+                points.clear();
+                points.push(at);
+            }
+            PathEvent::Line { to, .. } => {
+                points.push(to);
+            }
+            PathEvent::End { first, close, .. } => {
+                if close && points.last() != Some(&first) {
+                    points.push(first);
+                }
+
+                dash_polyline(&mut builder, &points, close, &pattern, start);
+            }
+            // Flattening only produces line segments
+            PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => {}
+        }
+    }
+
+    builder.build()
+}
+
+/// Adds the dashes of a polyline to the given builder.
+fn dash_polyline(
+    builder: &mut lyon_path::path::Builder,
+    points: &[Point],
+    close: bool,
+    pattern: &[f32],
+    start: f32,
+) {
+    let Some(first) = points.first() else {
+        return;
+    };
+
+    let mut index = 0;
+    let mut remaining = pattern[0];
+    let mut skipped = start;
+
+    // The start is always shorter than the pattern; but rounding may
+    // disagree, so the search is bounded
+    for _ in 0..pattern.len() {
+        if skipped <= 0.0 || skipped < remaining {
+            break;
+        }
+
+        skipped -= remaining;
+        index = (index + 1) % pattern.len();
+        remaining = pattern[index];
+    }
+
+    remaining = (remaining - skipped).max(0.0);
+
+    let starts_on = index % 2 == 0;
+    let mut is_on = starts_on;
+    let mut is_split = false;
+
+    let mut dash = if is_on { vec![*first] } else { Vec::new() };
+    let mut dashes = Vec::new();
+
+    for segment in points.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let length = (to - from).length();
+        let mut travelled = 0.0;
+
+        while length - travelled > remaining {
+            travelled += remaining;
+
+            dash.push(from.lerp(to, travelled / length));
+
+            if is_on {
+                dashes.push(std::mem::take(&mut dash));
+            }
+
+            is_on = !is_on;
+            is_split = true;
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+
+        remaining -= length - travelled;
+
+        if is_on {
+            dash.push(to);
+        }
+    }
+
+    if is_on {
+        dashes.push(dash);
+    }
+
+    if close && starts_on && is_on {
+        if !is_split {
+            // A single dash covers the whole sub-path; so it stays closed
+            add_polyline(builder, &points[..points.len() - 1], true);
+            return;
+        }
+
+        if let Some(last) = dashes.pop() {
+            let first = &mut dashes[0];
+
+            let _ = first.splice(..1, last);
+        }
+    }
+
+    for dash in &dashes {
+        add_polyline(builder, dash, false);
+    }
+}
+
+fn add_polyline(
+    builder: &mut lyon_path::path::Builder,
+    points: &[Point],
+    close: bool,
+) {
+    let mut points = points.iter();
+
+    let Some(first) = points.next() else {
+        return;
+    };
+
+    let _ = builder.begin(*first);
+
+    for point in points {
+        let _ = builder.line_to(*point);
+    }
+
+    builder.end(close);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polyline(points: &[(f32, f32)], close: bool) -> lyon_path::Path {
+        let mut builder = lyon_path::Path::builder();
+        let mut points = points.iter().map(|&(x, y)| Point::new(x, y));
+
+        let _ = builder.begin(points.next().unwrap());
+
+        for point in points {
+            let _ = builder.line_to(point);
+        }
+
+        builder.end(close);
+        builder.build()
+    }
+
+    fn dashes(path: &lyon_path::Path) -> Vec<Vec<(f32, f32)>> {
+        let round = |point: Point| {
+            (
+                (point.x * 1000.0).round() / 1000.0,
+                (point.y * 1000.0).round() / 1000.0,
+            )
+        };
+
+        let mut dashes = Vec::new();
+
+        for event in path.iter() {
+            match event {
+                PathEvent::Begin { at } => dashes.push(vec![round(at)]),
+                PathEvent::Line { to, .. } => {
+                    dashes.last_mut().unwrap().push(round(to));
+                }
+                _ => {}
+            }
+        }
+
+        dashes
+    }
+
+    const LINE: [(f32, f32); 2] = [(0.0, 0.0), (40.0, 0.0)];
+
+    const SQUARE: [(f32, f32); 4] =
+        [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+
+    #[test]
+    fn dashes_alternate_with_gaps() {
+        let line = polyline(&LINE, false);
+
+        assert_eq!(
+            dashes(&dash(&line, &[10.0, 5.0], 0.0, 0.1)),
+            vec![
+                vec![(0.0, 0.0), (10.0, 0.0)],
+                vec![(15.0, 0.0), (25.0, 0.0)],
+                vec![(30.0, 0.0), (40.0, 0.0)],
+            ]
+        );
+
+        // An odd pattern is repeated
+        assert_eq!(
+            dashes(&dash(&line, &[10.0], 0.0, 0.1)),
+            vec![
+                vec![(0.0, 0.0), (10.0, 0.0)],
+                vec![(20.0, 0.0), (30.0, 0.0)]
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_shifts_the_pattern() {
+        let line = polyline(&LINE, false);
+
+        assert_eq!(
+            dashes(&dash(&line, &[10.0, 10.0], 5.0, 0.1)),
+            vec![
+                vec![(0.0, 0.0), (5.0, 0.0)],
+                vec![(15.0, 0.0), (25.0, 0.0)],
+                vec![(35.0, 0.0), (40.0, 0.0)]
+            ]
+        );
+
+        assert_eq!(
+            dashes(&dash(&line, &[10.0, 10.0], -5.0, 0.1)),
+            vec![
+                vec![(5.0, 0.0), (15.0, 0.0)],
+                vec![(25.0, 0.0), (35.0, 0.0)]
+            ]
+        );
+    }
+
+    #[test]
+    fn dashes_follow_corners() {
+        let corner = polyline(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)], false);
+
+        assert_eq!(
+            dashes(&dash(&corner, &[15.0, 5.0], 0.0, 0.1)),
+            vec![vec![(0.0, 0.0), (10.0, 0.0), (10.0, 5.0)]]
+        );
+    }
+
+    #[test]
+    fn zero_length_dashes_are_dots() {
+        let line = polyline(&LINE, false);
+
+        assert_eq!(
+            dashes(&dash(&line, &[0.0, 15.0], 0.0, 0.1)),
+            vec![
+                vec![(0.0, 0.0), (0.0, 0.0)],
+                vec![(15.0, 0.0), (15.0, 0.0)],
+                vec![(30.0, 0.0), (30.0, 0.0)],
+            ]
+        );
+
+        // A pattern without any length is a solid line
+        assert_eq!(
+            dashes(&dash(&line, &[0.0, 0.0], 0.0, 0.1)),
+            vec![vec![(0.0, 0.0), (40.0, 0.0)]]
+        );
+    }
+
+    #[test]
+    fn long_patterns_keep_the_path() {
+        let line = polyline(&LINE, false);
+
+        assert_eq!(
+            dashes(&dash(&line, &[100.0, 10.0], 0.0, 0.1)),
+            vec![vec![(0.0, 0.0), (40.0, 0.0)]]
+        );
+
+        let square = dash(&polyline(&SQUARE, true), &[100.0, 10.0], 0.0, 0.1);
+
+        assert_eq!(dashes(&square), vec![SQUARE.to_vec()]);
+        assert!(square
+            .iter()
+            .any(|event| matches!(event, PathEvent::End { close: true, .. })));
+    }
+
+    #[test]
+    fn dashes_wrap_around_closed_paths() {
+        let square = polyline(&SQUARE, true);
+
+        assert_eq!(
+            dashes(&dash(&square, &[15.0, 10.0], 0.0, 0.1)),
+            vec![vec![
+                (5.0, 10.0),
+                (0.0, 10.0),
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 5.0)
+            ]]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use lyon_path::iterator::PathIterator;
+use lyon_path::math::Point;
+use lyon_path::PathEvent;
+
+/// Simplifies the given path using the Ramer–Douglas–Peucker algorithm on
+/// its flattened geometry.
+///
+/// The endpoints of every sub-path are always preserved, and closed
+/// sub-paths keep at least three points so they still enclose an area.
+pub fn simplify(path: &lyon_path::Path, tolerance: f32) -> lyon_path::Path {
+    let mut builder = lyon_path::Path::builder();
+    let mut points = Vec::new();
+
+    for event in path.iter().flattened(tolerance) {
+        match event {
+            PathEvent::Begin { at } => {
+                points.clear();
+                points.push(at);
+            }
+            PathEvent::Line { to, .. } => {
+                points.push(to);
+            }
+            PathEvent::End { close, .. } => {
+                let keep = if close {
+                    closed(&points, tolerance)
+                } else {
+                    ramer_douglas_peucker(&points, tolerance)
+                };
+
+                let mut kept = points
+                    .iter()
+                    .zip(keep)
+                    .filter_map(|(point, keep)| keep.then_some(*point));
+
+                if let Some(first) = kept.next() {
+                    let _ = builder.begin(first);
+
+                    for point in kept {
+                        let _ = builder.line_to(point);
+                    }
+
+                    builder.end(close);
+                }
+            }
+            // Flattening only produces line segments
+            PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => {}
+        }
+    }
+
+    builder.build()
+}
+
+/// Simplifies a closed polyline, whose last point connects to its first.
+fn closed(points: &[Point], tolerance: f32) -> Vec<bool> {
+    let mut ring = points.to_vec();
+    ring.extend(points.first());
+
+    let mut keep = ramer_douglas_peucker(&ring, tolerance);
+    let _ = keep.pop();
+
+    if points.len() >= 3 && keep.iter().filter(|keep| **keep).count() < 3 {
+        let farthest = |from: Point, to: Point| {
+            (1..points.len())
+                .map(|i| (i, distance(points[i], from, to)))
+                .fold((0, 0.0), |a, b| if b.1 > a.1 { b } else { a })
+                .0
+        };
+
+        let a = farthest(points[0], points[0]);
+        let b = farthest(points[0], points[a]);
+
+        keep[a] = true;
+        keep[b] = true;
+    }
+
+    keep
+}
+
+/// Returns which points of the given polyline must be kept to approximate
+/// it within the given tolerance.
+fn ramer_douglas_peucker(points: &[Point], tolerance: f32) -> Vec<bool> {
+    let mut keep = vec![false; points.len()];
+
+    let Some(last) = points.len().checked_sub(1) else {
+        return keep;
+    };
+
+    keep[0] = true;
+    keep[last] = true;
+
+    // A stack avoids overflowing on paths with lots of points
+    let mut ranges = vec![(0, last)];
+
+    while let Some((start, end)) = ranges.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+
+        let (index, max_distance) = (start + 1..end)
+            .map(|i| (i, distance(points[i], points[start], points[end])))
+            .fold((start, 0.0), |a, b| if b.1 > a.1 { b } else { a });
+
+        if max_distance > tolerance {
+            keep[index] = true;
+
+            ranges.push((start, index));
+            ranges.push((index, end));
+        }
+    }
+
+    keep
+}
+
+/// Returns the distance between a point and the segment between two others.
+fn distance(point: Point, from: Point, to: Point) -> f32 {
+    let segment = to - from;
+    let length_squared = segment.square_length();
+
+    if length_squared <= f32::EPSILON {
+        return (point - from).length();
+    }
+
+    let t = ((point - from).dot(segment) / length_squared).clamp(0.0, 1.0);
+
+    (point - (from + segment * t)).length()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polyline(points: &[(f32, f32)], close: bool) -> lyon_path::Path {
+        let mut builder = lyon_path::Path::builder();
+        let mut points = points.iter().map(|&(x, y)| Point::new(x, y));
+
+        let _ = builder.begin(points.next().unwrap());
+
+        for point in points {
+            let _ = builder.line_to(point);
+        }
+
+        builder.end(close);
+        builder.build()
+    }
+
+    fn points(path: &lyon_path::Path) -> Vec<(f32, f32)> {
+        path.iter()
+            .filter_map(|event| match event {
+                PathEvent::Begin { at } => Some((at.x, at.y)),
+                PathEvent::Line { to, .. } => Some((to.x, to.y)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn simplification_preserves_endpoints() {
+        let wiggly: Vec<_> = (0..=100)
+            .map(|i| (i as f32, if i % 2 == 0 { 0.0 } else { 0.1 }))
+            .collect();
+
+        let simplified = simplify(&polyline(&wiggly, false), 0.5);
+
+        assert_eq!(points(&simplified), vec![(0.0, 0.0), (100.0, 0.0)]);
+    }
+
+    #[test]
+    fn simplification_keeps_significant_corners() {
+        let corner = [(0.0, 0.0), (5.0, 0.05), (10.0, 0.0), (10.0, 10.0)];
+
+        let simplified = simplify(&polyline(&corner, false), 0.5);
+
+        assert_eq!(
+            points(&simplified),
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn closed_paths_keep_their_topology() {
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+
+        let simplified = simplify(&polyline(&square, true), 10.0);
+
+        assert!(points(&simplified).len() >= 3);
+        assert!(simplified
+            .iter()
+            .any(|event| matches!(event, PathEvent::End { close: true, .. })));
+    }
+}
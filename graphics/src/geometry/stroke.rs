@@ -2,6 +2,7 @@
 //!
 //! [`Path`]: super::Path
 pub use crate::geometry::Style;
+pub use crate::mesh::Blend;
 
 use iced_core::Color;
 
@@ -21,6 +22,22 @@ pub struct Stroke<'a> {
     pub line_join: LineJoin,
     /// The dash pattern used when stroking the line.
     pub line_dash: LineDash<'a>,
+    /// The blend mode of the stroke.
+    ///
+    /// By default, it is set to [`Blend::Alpha`].
+    pub blend: Blend,
+    /// The tolerance, in pixels on screen, used to simplify the path before
+    /// stroking it.
+    ///
+    /// When set, the path is simplified with [`Path::simplify`] using a
+    /// tolerance that takes the current transform of the frame into account; so detail that would be
+    /// smaller than a pixel is dropped before tessellation. This can greatly
+    /// speed up drawing large paths while zoomed out.
+    ///
+    /// By default, it is set to `None`.
+    ///
+    /// [`Path::simplify`]: super::Path::simplify
+    pub level_of_detail: Option<f32>,
 }
 
 impl<'a> Stroke<'a> {
@@ -46,6 +63,25 @@ impl<'a> Stroke<'a> {
     pub fn with_line_join(self, line_join: LineJoin) -> Self {
         Stroke { line_join, ..self }
     }
+
+    /// Sets the [`LineDash`] of the [`Stroke`].
+    pub fn with_line_dash(self, line_dash: LineDash<'a>) -> Self {
+        Stroke { line_dash, ..self }
+    }
+
+    /// Sets the [`Blend`] mode of the [`Stroke`].
+    pub fn with_blend(self, blend: Blend) -> Self {
+        Stroke { blend, ..self }
+    }
+
+    /// Enables level of detail for the [`Stroke`] with the given tolerance,
+    /// in pixels on screen.
+    pub fn with_level_of_detail(self, tolerance: f32) -> Self {
+        Stroke {
+            level_of_detail: Some(tolerance),
+            ..self
+        }
+    }
 }
 
 impl<'a> Default for Stroke<'a> {
@@ -56,6 +92,8 @@ impl<'a> Default for Stroke<'a> {
             line_cap: LineCap::default(),
             line_join: LineJoin::default(),
             line_dash: LineDash::default(),
+            blend: Blend::Alpha,
+            level_of_detail: None,
         }
     }
 }
@@ -88,11 +126,23 @@ pub enum LineJoin {
 }
 
 /// The dash pattern used when stroking the line.
+///
+/// The dashes follow the curves of the stroked path and every one of them
+/// is capped with the [`LineCap`] of the [`Stroke`]; so zero-length dashes
+/// with [`LineCap::Round`] draw dotted lines.
+///
+/// A pattern without any length, or with negative lengths, draws a solid
+/// line.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct LineDash<'a> {
     /// The alternating lengths of lines and gaps which describe the pattern.
+    ///
+    /// An odd number of lengths is repeated to produce an even one.
     pub segments: &'a [f32],
 
-    /// The offset of [`LineDash::segments`] to start the pattern.
-    pub offset: usize,
+    /// The distance into [`LineDash::segments`] where the pattern starts.
+    ///
+    /// Changing it over time makes the dashes move along the path; like
+    /// "marching ants".
+    pub offset: f32,
 }
@@ -1,6 +1,6 @@
 use crate::core::alignment;
 use crate::core::text::{LineHeight, Shaping};
-use crate::core::{Color, Font, Pixels, Point, Size, Vector};
+use crate::core::{Color, Font, Pixels, Point, Radians, Size, Vector};
 use crate::geometry::Path;
 use crate::text;
 
@@ -27,6 +27,9 @@ pub struct Text {
     /// The font of the text
     pub font: Font,
     /// The horizontal alignment of the text
+    ///
+    /// Canvas text is never wrapped; every line is the last one of its
+    /// paragraph and, therefore, justified text is aligned to the left.
     pub horizontal_alignment: alignment::Horizontal,
     /// The vertical alignment of the text
     pub vertical_alignment: alignment::Vertical,
@@ -38,13 +41,95 @@ impl Text {
     /// Computes the [`Path`]s of the [`Text`] and draws them using
     /// the given closure.
     pub fn draw_with(&self, mut f: impl FnMut(Path, Color)) {
+        self.visit_glyphs(true, |glyph| match glyph {
+            Glyph::Outline(path) => f(path, self.color),
+            Glyph::Pixel(pixel, color) => f(pixel, color),
+        });
+    }
+
+    /// Computes the outlines of the shaped glyphs of the [`Text`], producing
+    /// a [`Path`] per glyph.
+    ///
+    /// The resulting [`Path`]s are positioned exactly where [`draw_with`]
+    /// would draw them, honoring the alignment properties of the [`Text`].
+    ///
+    /// Since the outlines are extracted after shaping, a ligature produces a
+    /// single [`Path`] and glyphs coming from fallback fonts are included.
+    /// Glyphs without vector outlines (e.g. bitmap emoji) are skipped.
+    ///
+    /// This is useful to stroke, clip, or morph text in a canvas; like when
+    /// animating a logo being drawn.
+    ///
+    /// [`draw_with`]: Self::draw_with
+    pub fn to_paths(&self) -> Vec<Path> {
+        let mut paths = Vec::new();
+
+        self.visit_glyphs(false, |glyph| {
+            if let Glyph::Outline(path) = glyph {
+                paths.push(path);
+            }
+        });
+
+        paths
+    }
+
+    /// Computes the outlines of the shaped glyphs of the [`Text`] bent along
+    /// the circle with the given center and radius.
+    ///
+    /// The [`Text`] is laid out like in [`to_paths`]; then, the horizontal
+    /// line going through its [`position`] is wrapped clockwise around the
+    /// circle, with the [`position`] itself at the `start` angle. Every glyph
+    /// is rotated to follow the arc, facing away from the center.
+    ///
+    /// The alignment properties keep their meaning along the arc; for
+    /// instance, a centered [`Text`] is centered around the `start` angle and
+    /// a [`Text`] aligned to the bottom rests on the outside of the circle.
+    ///
+    /// [`to_paths`]: Self::to_paths
+    /// [`position`]: Self::position
+    pub fn to_paths_along_arc(
+        &self,
+        center: Point,
+        radius: f32,
+        start: impl Into<Radians>,
+    ) -> Vec<Path> {
+        use lyon_path::math::{vector, Angle, Transform};
+
+        let start = start.into();
+
+        if radius <= 0.0 {
+            return Vec::new();
+        }
+
+        self.to_paths()
+            .into_iter()
+            .map(|glyph| {
+                let x = glyph.bounds().center_x();
+                let angle = start.0 + (x - self.position.x) / radius;
+                let (sin, cos) = angle.sin_cos();
+
+                glyph.transform(
+                    &Transform::translation(-x, -self.position.y)
+                        .then_rotate(Angle::radians(
+                            angle + std::f32::consts::FRAC_PI_2,
+                        ))
+                        .then_translate(vector(
+                            center.x + radius * cos,
+                            center.y + radius * sin,
+                        )),
+                )
+            })
+            .collect()
+    }
+
+    fn visit_glyphs(&self, rasterize: bool, mut f: impl FnMut(Glyph)) {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
         let mut buffer = cosmic_text::BufferLine::new(
             &self.content,
             cosmic_text::LineEnding::default(),
-            cosmic_text::AttrsList::new(text::to_attributes(self.font)),
+            cosmic_text::AttrsList::new(&text::to_attributes(self.font)),
             text::to_shaping(self.shaping),
         );
 
@@ -57,16 +142,22 @@ impl Text {
             4,
         );
 
-        let translation_x = match self.horizontal_alignment {
-            alignment::Horizontal::Left => self.position.x,
-            alignment::Horizontal::Center | alignment::Horizontal::Right => {
+        let horizontal_alignment = self.horizontal_alignment.resolve();
+
+        let translation_x = match horizontal_alignment {
+            alignment::Horizontal::Left
+            | alignment::Horizontal::Start
+            | alignment::Horizontal::Justify => self.position.x,
+            alignment::Horizontal::Center
+            | alignment::Horizontal::Right
+            | alignment::Horizontal::End => {
                 let mut line_width = 0.0f32;
 
                 for line in layout.iter() {
                     line_width = line_width.max(line.w);
                 }
 
-                if self.horizontal_alignment == alignment::Horizontal::Center {
+                if horizontal_alignment == alignment::Horizontal::Center {
                     self.position.x - line_width / 2.0
                 } else {
                     self.position.x - line_width
@@ -138,8 +229,8 @@ impl Text {
                         }
                     });
 
-                    f(glyph, self.color);
-                } else {
+                    f(Glyph::Outline(glyph));
+                } else if rasterize {
                     // TODO: Raster image support for `Canvas`
                     let [r, g, b, a] = self.color.into_rgba8();
 
@@ -148,7 +239,7 @@ impl Text {
                         physical_glyph.cache_key,
                         cosmic_text::Color::rgba(r, g, b, a),
                         |x, y, color| {
-                            f(
+                            f(Glyph::Pixel(
                                 Path::rectangle(
                                     Point::new(x as f32, y as f32) + offset,
                                     Size::new(1.0, 1.0),
@@ -159,7 +250,7 @@ impl Text {
                                     color.b(),
                                     color.a() as f32 / 255.0,
                                 ),
-                            );
+                            ));
                         },
                     );
                 }
@@ -168,6 +259,11 @@ impl Text {
     }
 }
 
+enum Glyph {
+    Outline(Path),
+    Pixel(Path, Color),
+}
+
 impl Default for Text {
     fn default() -> Text {
         Text {
@@ -198,3 +294,58 @@ impl From<&str> for Text {
         String::from(content).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::borrow::Cow;
+
+    const FIRA_SANS: &[u8] = include_bytes!("../../fonts/FiraSans-Regular.ttf");
+
+    const CENTER: Point = Point::new(100.0, 100.0);
+    const RADIUS: f32 = 50.0;
+
+    fn distances(vertical_alignment: alignment::Vertical) -> Vec<f32> {
+        text::font_system()
+            .write()
+            .expect("Write font system")
+            .load_font(Cow::Borrowed(FIRA_SANS));
+
+        Text {
+            content: String::from("iced"),
+            font: Font::with_name("Fira Sans"),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment,
+            ..Text::default()
+        }
+        .to_paths_along_arc(
+            CENTER,
+            RADIUS,
+            Radians(-std::f32::consts::FRAC_PI_2),
+        )
+        .iter()
+        .map(|glyph| glyph.bounds().center().distance(CENTER))
+        .collect()
+    }
+
+    #[test]
+    fn text_aligned_to_the_bottom_rests_outside_the_arc() {
+        let distances = distances(alignment::Vertical::Bottom);
+
+        assert_eq!(distances.len(), 4);
+        assert!(distances
+            .iter()
+            .all(|distance| *distance > RADIUS && *distance < RADIUS + 16.0));
+    }
+
+    #[test]
+    fn text_aligned_to_the_top_hangs_inside_the_arc() {
+        let distances = distances(alignment::Vertical::Top);
+
+        assert_eq!(distances.len(), 4);
+        assert!(distances
+            .iter()
+            .all(|distance| *distance < RADIUS && *distance > RADIUS - 16.0));
+    }
+}
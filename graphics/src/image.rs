@@ -57,6 +57,43 @@ impl Image {
     }
 }
 
+/// Replaces every `currentColor` keyword of the given SVG source with the
+/// given [`Color`]; returning `None` if the source does not use it.
+///
+/// Symbolic icons painted with `currentColor` can be tinted this way, while
+/// keeping any other color they use.
+pub fn substitute_current_color(source: &str, color: Color) -> Option<String> {
+    const KEYWORD: &str = "currentcolor";
+
+    // Keywords are case-insensitive and ASCII lowercasing keeps every
+    // byte offset intact
+    let lowercase = source.to_ascii_lowercase();
+    let [r, g, b, a] = color.into_rgba8();
+    let hex = if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+
+    let mut substituted = String::with_capacity(source.len());
+    let mut last = 0;
+
+    for (start, _) in lowercase.match_indices(KEYWORD) {
+        substituted.push_str(&source[last..start]);
+        substituted.push_str(&hex);
+
+        last = start + KEYWORD.len();
+    }
+
+    if last == 0 {
+        return None;
+    }
+
+    substituted.push_str(&source[last..]);
+
+    Some(substituted)
+}
+
 #[cfg(feature = "image")]
 /// Tries to load an image by its [`Handle`].
 ///
@@ -115,7 +152,7 @@ pub fn load(
     }
 
     let (width, height, pixels) = match handle {
-        image::Handle::Path(_, path) => {
+        image::Handle::Path(_, path, color_handling) => {
             let image = ::image::open(path)?;
 
             let operation = std::fs::File::open(path)
@@ -124,7 +161,24 @@ pub fn load(
                 .and_then(|mut reader| Operation::from_exif(&mut reader).ok())
                 .unwrap_or_else(Operation::empty);
 
-            let rgba = operation.perform(image).into_rgba8();
+            let mut rgba = operation.perform(image).into_rgba8();
+
+            #[cfg(feature = "icc")]
+            if *color_handling == image::ColorHandling::ConvertToSrgb {
+                if let Some(profile) = ::image::ImageFormat::from_path(path)
+                    .ok()
+                    .and_then(|format| {
+                        let file = std::fs::File::open(path).ok()?;
+
+                        icc::profile(std::io::BufReader::new(file), format)
+                    })
+                {
+                    icc::convert_to_srgb(&profile, &mut rgba);
+                }
+            }
+
+            #[cfg(not(feature = "icc"))]
+            let _ = color_handling;
 
             (
                 rgba.width(),
@@ -132,14 +186,28 @@ pub fn load(
                 image::Bytes::from(rgba.into_raw()),
             )
         }
-        image::Handle::Bytes(_, bytes) => {
+        image::Handle::Bytes(_, bytes, color_handling) => {
             let image = ::image::load_from_memory(bytes)?;
             let operation =
                 Operation::from_exif(&mut std::io::Cursor::new(bytes))
                     .ok()
                     .unwrap_or_else(Operation::empty);
 
-            let rgba = operation.perform(image).into_rgba8();
+            let mut rgba = operation.perform(image).into_rgba8();
+
+            #[cfg(feature = "icc")]
+            if *color_handling == image::ColorHandling::ConvertToSrgb {
+                if let Some(profile) =
+                    ::image::guess_format(bytes).ok().and_then(|format| {
+                        icc::profile(std::io::Cursor::new(bytes), format)
+                    })
+                {
+                    icc::convert_to_srgb(&profile, &mut rgba);
+                }
+            }
+
+            #[cfg(not(feature = "icc"))]
+            let _ = color_handling;
 
             (
                 rgba.width(),
@@ -153,6 +221,26 @@ pub fn load(
             pixels,
             ..
         } => (*width, *height, pixels.clone()),
+        image::Handle::Dynamic(_, dynamic) => {
+            let size = dynamic.size();
+
+            (size.width, size.height, dynamic.pixels())
+        }
+        image::Handle::Compressed(_, compressed) => {
+            // Compressed textures are only uploaded as they are by GPU
+            // renderers; decoding them on the CPU is not supported
+            return Err(::image::error::ImageError::Unsupported(
+                ::image::error::UnsupportedError::from_format_and_kind(
+                    ::image::error::ImageFormatHint::Name(format!(
+                        "{:?}",
+                        compressed.format()
+                    )),
+                    ::image::error::UnsupportedErrorKind::GenericFeature(
+                        "decoding compressed textures".to_owned(),
+                    ),
+                ),
+            ));
+        }
     };
 
     if let Some(image) = ::image::ImageBuffer::from_raw(width, height, pixels) {
@@ -165,3 +253,102 @@ pub fn load(
         ))
     }
 }
+
+#[cfg(feature = "icc")]
+mod icc {
+    use std::io::{BufRead, Seek};
+
+    /// Reads the embedded ICC profile of an encoded image, if any.
+    pub fn profile<R>(
+        reader: R,
+        format: ::image::ImageFormat,
+    ) -> Option<Vec<u8>>
+    where
+        R: BufRead + Seek,
+    {
+        use ::image::codecs::{jpeg, png};
+        use ::image::ImageDecoder;
+
+        match format {
+            ::image::ImageFormat::Png => {
+                png::PngDecoder::new(reader).ok()?.icc_profile()
+            }
+            ::image::ImageFormat::Jpeg => {
+                jpeg::JpegDecoder::new(reader).ok()?.icc_profile()
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts the given RGBA pixels from the color space of the given ICC
+    /// profile to sRGB, in place.
+    ///
+    /// The pixels are left untouched if the profile is invalid or
+    /// unsupported.
+    pub fn convert_to_srgb(profile: &[u8], pixels: &mut [u8]) {
+        let Some(input) = qcms::Profile::new_from_slice(profile, false) else {
+            log::warn!("Ignoring invalid ICC profile of image");
+            return;
+        };
+
+        let mut output = qcms::Profile::new_sRGB();
+        output.precache_output_transform();
+
+        let Some(transform) = qcms::Transform::new(
+            &input,
+            &output,
+            qcms::DataType::RGBA8,
+            qcms::Intent::default(),
+        ) else {
+            log::warn!("Ignoring unsupported ICC profile of image");
+            return;
+        };
+
+        transform.apply(pixels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_color_is_substituted() {
+        let source = concat!(
+            r##"<svg><path fill="currentColor" stroke="#123456"/>"##,
+            r#"<circle style="fill: CURRENTCOLOR"/></svg>"#
+        );
+
+        assert_eq!(
+            substitute_current_color(source, Color::from_rgb8(255, 0, 128))
+                .as_deref(),
+            Some(concat!(
+                r##"<svg><path fill="#ff0080" stroke="#123456"/>"##,
+                r##"<circle style="fill: #ff0080"/></svg>"##
+            ))
+        );
+    }
+
+    #[test]
+    fn translucent_colors_keep_their_alpha() {
+        assert_eq!(
+            substitute_current_color(
+                r#"<path fill="currentColor"/>"#,
+                Color::from_rgba8(0, 0, 255, 0.5)
+            )
+            .as_deref(),
+            Some(r##"<path fill="#0000ff80"/>"##)
+        );
+    }
+
+    #[test]
+    fn sources_without_current_color_are_ignored() {
+        assert_eq!(
+            substitute_current_color(
+                r#"<svg><path fill="black"/></svg>"#,
+                Color::WHITE
+            ),
+            None
+        );
+    }
+}
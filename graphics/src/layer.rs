@@ -27,6 +27,7 @@ pub trait Layer: Default {
 pub struct Stack<T: Layer> {
     layers: Vec<T>,
     transformations: Vec<Transformation>,
+    clips: Vec<Rectangle>,
     previous: Vec<usize>,
     current: usize,
     active_count: usize,
@@ -38,6 +39,7 @@ impl<T: Layer> Stack<T> {
         Self {
             layers: vec![T::default()],
             transformations: vec![Transformation::IDENTITY],
+            clips: vec![Rectangle::INFINITE],
             previous: vec![],
             current: 0,
             active_count: 1,
@@ -64,11 +66,33 @@ impl<T: Layer> Stack<T> {
     pub fn push_clip(&mut self, bounds: Rectangle) {
         self.previous.push(self.current);
 
+        let bounds = bounds * self.transformation();
+
+        self.clips.push(bounds);
+        self.activate(bounds);
+    }
+
+    /// Continues drawing the current clipping region in a new layer, drawn
+    /// over every layer in the [`Stack`] so far.
+    ///
+    /// Layers draw each kind of primitive at once; so this lets primitives
+    /// be drawn over others recorded earlier in the same layer that would
+    /// be drawn on top of them otherwise—like meshes over text.
+    ///
+    /// The current layer will be recorded for drawing.
+    pub fn split(&mut self) {
+        self.flush();
+
+        let bounds = self.clips.last().copied().unwrap();
+
+        self.activate(bounds);
+    }
+
+    /// Makes a new layer with the given bounds the current one.
+    fn activate(&mut self, bounds: Rectangle) {
         self.current = self.active_count;
         self.active_count += 1;
 
-        let bounds = bounds * self.transformation();
-
         if self.current == self.layers.len() {
             self.layers.push(T::with_bounds(bounds));
         } else {
@@ -82,6 +106,7 @@ impl<T: Layer> Stack<T> {
     pub fn pop_clip(&mut self) {
         self.flush();
 
+        let _ = self.clips.pop();
         self.current = self.previous.pop().unwrap();
     }
 
@@ -133,6 +158,7 @@ impl<T: Layer> Stack<T> {
 
         self.current = 0;
         self.active_count = 1;
+        self.clips.truncate(1);
         self.previous.clear();
     }
 }
@@ -20,7 +20,9 @@ pub mod error;
 pub mod gradient;
 pub mod image;
 pub mod layer;
+pub mod memory;
 pub mod mesh;
+pub mod snapshot;
 pub mod text;
 
 #[cfg(feature = "geometry")]
@@ -34,7 +36,7 @@ pub use gradient::Gradient;
 pub use image::Image;
 pub use layer::Layer;
 pub use mesh::Mesh;
-pub use settings::Settings;
+pub use settings::{Backend, Settings};
 pub use text::Text;
 pub use viewport::Viewport;
 
@@ -0,0 +1,78 @@
+//! Measure the GPU memory used by a renderer.
+use std::fmt;
+
+/// An approximation of the GPU memory used by a renderer, in bytes.
+///
+/// Only the allocations made by the renderer itself are counted; drivers
+/// may allocate more behind the scenes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// The memory used by the image atlas and the textures of dynamic
+    /// images.
+    pub images: u64,
+    /// The memory used by the glyph atlases.
+    ///
+    /// Glyph atlases do not report their actual size, so each one counts as
+    /// the size it starts with. This is a lower bound.
+    pub glyphs: u64,
+    /// The memory used by the vertex, index, instance, and uniform buffers
+    /// of the pipelines.
+    pub buffers: u64,
+    /// The memory used by cached geometry; like the meshes of a canvas
+    /// cache.
+    pub geometry: u64,
+}
+
+impl Usage {
+    /// Returns the total amount of bytes of the [`Usage`].
+    pub fn total(&self) -> u64 {
+        self.images + self.glyphs + self.buffers + self.geometry
+    }
+
+    /// Returns the memory freed going from the [`Usage`] to the one after.
+    pub fn freed(&self, after: &Usage) -> Usage {
+        Usage {
+            images: self.images.saturating_sub(after.images),
+            glyphs: self.glyphs.saturating_sub(after.glyphs),
+            buffers: self.buffers.saturating_sub(after.buffers),
+            geometry: self.geometry.saturating_sub(after.geometry),
+        }
+    }
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "images: {}, glyphs: {}, buffers: {}, geometry: {}",
+            Bytes(self.images),
+            Bytes(self.glyphs),
+            Bytes(self.buffers),
+            Bytes(self.geometry),
+        )
+    }
+}
+
+/// An amount of bytes, displayed in a human readable unit; like `12.5 MiB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut amount = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+
+        while amount >= 1024.0 && unit < UNITS.len() - 1 {
+            amount /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{amount:.1} {}", UNITS[unit])
+    }
+}
@@ -18,6 +18,9 @@ pub enum Mesh {
 
         /// The clip bounds of the [`Mesh`].
         clip_bounds: Rectangle,
+
+        /// The [`Blend`] mode of the [`Mesh`].
+        blend: Blend,
     },
     /// A mesh with a gradient.
     Gradient {
@@ -29,6 +32,9 @@ pub enum Mesh {
 
         /// The clip bounds of the [`Mesh`].
         clip_bounds: Rectangle,
+
+        /// The [`Blend`] mode of the [`Mesh`].
+        blend: Blend,
     },
 }
 
@@ -49,6 +55,13 @@ impl Mesh {
         }
     }
 
+    /// Returns the [`Blend`] mode of the [`Mesh`].
+    pub fn blend(&self) -> Blend {
+        match self {
+            Self::Solid { blend, .. } | Self::Gradient { blend, .. } => *blend,
+        }
+    }
+
     /// Returns the clip bounds of the [`Mesh`].
     pub fn clip_bounds(&self) -> Rectangle {
         match self {
@@ -66,6 +79,26 @@ impl Mesh {
     }
 }
 
+/// The way the colors of a [`Mesh`] are combined with the colors behind it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Blend {
+    /// The colors are composited with alpha blending; the usual "source over".
+    #[default]
+    Alpha,
+    /// The colors are added together; useful for glow effects.
+    Additive,
+    /// The colors are multiplied; the result is always darker.
+    Multiply,
+    /// The inverted colors are multiplied; the result is always lighter.
+    Screen,
+}
+
+impl Blend {
+    /// All the [`Blend`] modes.
+    pub const ALL: [Self; 4] =
+        [Self::Alpha, Self::Additive, Self::Multiply, Self::Screen];
+}
+
 /// A set of vertices and indices representing a list of triangles.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Indexed<T> {
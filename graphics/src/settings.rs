@@ -16,6 +16,30 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// Whether the renderer should try to use a surface with a high dynamic
+    /// range, so emissive colors can look brighter than white.
+    ///
+    /// By default, it is `false`.
+    pub hdr: bool,
+
+    /// The [`Backend`]s to try, in order, until one of them can be created.
+    ///
+    /// The `ICED_BACKEND` environment variable overrides them with a comma
+    /// separated list of backend names; like `tiny-skia` to force software
+    /// rendering.
+    ///
+    /// By default, it is [`Backend::ALL`].
+    pub backends: &'static [Backend],
+
+    /// The amount of GPU memory, in bytes, the renderer should try to stay
+    /// under.
+    ///
+    /// When its usage goes over the budget, the renderer evicts its caches;
+    /// trading some performance for memory.
+    ///
+    /// By default, it is `None`.
+    pub memory_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -24,6 +48,39 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            hdr: false,
+            backends: Backend::ALL,
+            memory_budget: None,
+        }
+    }
+}
+
+/// A graphical backend that a renderer can be created with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// `wgpu`, with the graphics APIs it prefers on the platform.
+    Wgpu,
+    /// `wgpu`, limited to OpenGL.
+    WgpuGl,
+    /// The `tiny-skia` software renderer.
+    TinySkia,
+}
+
+impl Backend {
+    /// All the [`Backend`]s; from the fastest to the most compatible.
+    pub const ALL: &'static [Self] =
+        &[Self::Wgpu, Self::WgpuGl, Self::TinySkia];
+
+    /// Returns the name of the [`Backend`]; as understood by
+    /// [`Compositor::with_backend`] and the `ICED_BACKEND` environment
+    /// variable.
+    ///
+    /// [`Compositor::with_backend`]: crate::Compositor::with_backend
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Wgpu => "wgpu",
+            Self::WgpuGl => "wgpu-gl",
+            Self::TinySkia => "tiny-skia",
         }
     }
 }
@@ -0,0 +1,302 @@
+//! Record primitives to draw them later.
+use crate::color;
+use crate::core::image;
+use crate::core::renderer::Quad;
+use crate::core::svg;
+use crate::core::{
+    Background, Border, Color, Point, Radians, Rectangle, Shadow,
+    Transformation,
+};
+use crate::mesh::Mesh;
+use crate::text::Paragraph;
+
+/// A drawing operation recorded by a [`Recorder`].
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Starts a layer with the given bounds.
+    StartLayer(Rectangle),
+    /// Ends the last layer started.
+    EndLayer,
+    /// Starts applying the given [`Transformation`].
+    StartTransformation(Transformation),
+    /// Ends applying the last [`Transformation`] started.
+    EndTransformation,
+    /// Starts forcing the given [`image::FilterMethod`].
+    StartFilterMethod(image::FilterMethod),
+    /// Ends forcing the last [`image::FilterMethod`] started.
+    EndFilterMethod,
+    /// Fills a [`Quad`].
+    Quad(Quad, Background),
+    /// Fills a [`Paragraph`].
+    #[allow(missing_docs)]
+    Paragraph {
+        paragraph: Paragraph,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    },
+    /// Fills some text.
+    #[allow(missing_docs)]
+    Text {
+        text: crate::core::Text,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    },
+    /// Draws a raster image.
+    #[allow(missing_docs)]
+    Image {
+        handle: image::Handle,
+        filter_method: image::FilterMethod,
+        bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+    },
+    /// Draws a vector image.
+    #[allow(missing_docs)]
+    Svg {
+        handle: svg::Handle,
+        color_filter: Option<Color>,
+        bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+    },
+    /// Draws a [`Mesh`].
+    Mesh(Mesh),
+}
+
+impl Command {
+    /// Scales the alpha channel of the colors of the [`Command`] by the
+    /// given factor.
+    ///
+    /// The colors of the spans of a [`Paragraph`] and the gradients of a
+    /// [`Mesh`] are kept as they are.
+    pub fn scale_alpha(self, factor: f32) -> Self {
+        match self {
+            Self::Quad(quad, background) => Self::Quad(
+                Quad {
+                    border: Border {
+                        color: quad.border.color.scale_alpha(factor),
+                        ..quad.border
+                    },
+                    shadow: Shadow {
+                        color: quad.shadow.color.scale_alpha(factor),
+                        ..quad.shadow
+                    },
+                    ..quad
+                },
+                background.scale_alpha(factor),
+            ),
+            Self::Paragraph {
+                paragraph,
+                position,
+                color,
+                clip_bounds,
+            } => Self::Paragraph {
+                paragraph,
+                position,
+                color: color.scale_alpha(factor),
+                clip_bounds,
+            },
+            Self::Text {
+                text,
+                position,
+                color,
+                clip_bounds,
+            } => Self::Text {
+                text,
+                position,
+                color: color.scale_alpha(factor),
+                clip_bounds,
+            },
+            Self::Image {
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity,
+            } => Self::Image {
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity: opacity * factor,
+            },
+            Self::Svg {
+                handle,
+                color_filter,
+                bounds,
+                rotation,
+                opacity,
+            } => Self::Svg {
+                handle,
+                color_filter,
+                bounds,
+                rotation,
+                opacity: opacity * factor,
+            },
+            Self::Mesh(Mesh::Solid {
+                mut buffers,
+                transformation,
+                clip_bounds,
+                blend,
+            }) => {
+                for vertex in &mut buffers.vertices {
+                    vertex.color = color::pack(
+                        color::unpack(vertex.color).scale_alpha(factor),
+                    );
+                }
+
+                Self::Mesh(Mesh::Solid {
+                    buffers,
+                    transformation,
+                    clip_bounds,
+                    blend,
+                })
+            }
+            command => command,
+        }
+    }
+}
+
+/// A stack of recordings of [`Command`]s.
+///
+/// A renderer can use a [`Recorder`] to implement snapshots; see
+/// [`Renderer::start_snapshot`].
+///
+/// [`Renderer::start_snapshot`]: crate::core::Renderer::start_snapshot
+#[derive(Debug, Default)]
+pub struct Recorder {
+    recordings: Vec<Option<Vec<Command>>>,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] that is not recording.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new recording, nested in any recording in progress.
+    pub fn start(&mut self) {
+        self.recordings.push(Some(Vec::new()));
+    }
+
+    /// Ends the last recording started; returning its [`Command`]s, unless
+    /// it was interrupted.
+    pub fn end(&mut self) -> Option<Vec<Command>> {
+        self.recordings.pop().flatten()
+    }
+
+    /// Returns `true` if the last recording started is in progress.
+    ///
+    /// Anything drawn while recording should be recorded as a [`Command`],
+    /// instead of drawn right away.
+    pub fn is_recording(&self) -> bool {
+        matches!(self.recordings.last(), Some(Some(_)))
+    }
+
+    /// Records the given [`Command`], if the last recording started is in
+    /// progress.
+    pub fn record(&mut self, command: Command) {
+        if let Some(Some(commands)) = self.recordings.last_mut() {
+            commands.push(command);
+        }
+    }
+
+    /// Interrupts every recording in progress; returning all the [`Command`]s
+    /// recorded so far, in order.
+    ///
+    /// This must be called before drawing anything that cannot be recorded
+    /// as a [`Command`]. The returned [`Command`]s should be drawn right
+    /// away, so the order of the primitives is preserved; and so should
+    /// anything drawn until the interrupted recordings end.
+    pub fn interrupt(&mut self) -> Vec<Command> {
+        self.recordings
+            .iter_mut()
+            .filter_map(Option::take)
+            .flatten()
+            .collect()
+    }
+
+    /// Clears all the recordings of the [`Recorder`].
+    pub fn clear(&mut self) {
+        self.recordings.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh;
+
+    fn layer() -> Command {
+        Command::StartLayer(Rectangle::INFINITE)
+    }
+
+    #[test]
+    fn commands_are_recorded_only_while_recording() {
+        let mut recorder = Recorder::new();
+
+        assert!(!recorder.is_recording());
+        recorder.record(layer());
+
+        recorder.start();
+        assert!(recorder.is_recording());
+        recorder.record(layer());
+        assert_eq!(recorder.end().map(|commands| commands.len()), Some(1));
+
+        assert!(!recorder.is_recording());
+    }
+
+    #[test]
+    fn interrupted_recordings_produce_nothing() {
+        let mut recorder = Recorder::new();
+
+        recorder.start();
+        recorder.record(layer());
+
+        recorder.start();
+        recorder.record(layer());
+        recorder.record(layer());
+
+        assert_eq!(recorder.interrupt().len(), 3);
+        assert!(!recorder.is_recording());
+
+        // New recordings can still be nested in the interrupted ones
+        recorder.start();
+        recorder.record(layer());
+        assert_eq!(recorder.end().map(|commands| commands.len()), Some(1));
+
+        assert!(recorder.end().is_none());
+        assert!(recorder.end().is_none());
+    }
+
+    #[test]
+    fn scaling_alpha_keeps_the_mesh_colors() {
+        let vertex = mesh::SolidVertex2D {
+            position: [0.0, 0.0],
+            color: color::pack(Color::from_rgba(1.0, 0.5, 0.0, 0.8)),
+        };
+
+        let command = Command::Mesh(Mesh::Solid {
+            buffers: mesh::Indexed {
+                vertices: vec![vertex],
+                indices: vec![0],
+            },
+            transformation: Transformation::IDENTITY,
+            clip_bounds: Rectangle::INFINITE,
+            blend: mesh::Blend::Alpha,
+        });
+
+        let Command::Mesh(Mesh::Solid { buffers, .. }) =
+            command.scale_alpha(0.5)
+        else {
+            panic!("The command must stay a solid mesh");
+        };
+
+        let color = color::unpack(buffers.vertices[0].color);
+
+        assert!((color.r - 1.0).abs() < 1e-4);
+        assert!((color.a - 0.4).abs() < 1e-4);
+    }
+}
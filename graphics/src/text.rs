@@ -57,6 +57,9 @@ pub enum Text {
         horizontal_alignment: alignment::Horizontal,
         /// The vertical alignment of the text.
         vertical_alignment: alignment::Vertical,
+        /// The maximum factor the spaces of justified text can be stretched
+        /// by.
+        max_space_factor: f32,
         /// The shaping strategy of the text.
         shaping: Shaping,
         /// The clip bounds of the text.
@@ -117,12 +120,14 @@ impl Text {
         let mut bounds = bounds?;
 
         if let Some(alignment) = horizontal_alignment {
-            match alignment {
-                alignment::Horizontal::Left => {}
+            match alignment.resolve() {
+                alignment::Horizontal::Left
+                | alignment::Horizontal::Start
+                | alignment::Horizontal::Justify => {}
                 alignment::Horizontal::Center => {
                     bounds.x -= bounds.width / 2.0;
                 }
-                alignment::Horizontal::Right => {
+                alignment::Horizontal::Right | alignment::Horizontal::End => {
                     bounds.x -= bounds.width;
                 }
             }
@@ -192,12 +197,22 @@ impl FontSystem {
             cosmic_text::fontdb::Source::Binary(Arc::new(bytes.into_owned())),
         );
 
+        self.invalidate();
+    }
+
+    /// Increases the [`Version`] of the [`FontSystem`]; so any text shaped
+    /// with a previous version is shaped again.
+    ///
+    /// This is useful when the fonts or the locale of an application change
+    /// at runtime.
+    pub fn invalidate(&mut self) {
         self.version = Version(self.version.0 + 1);
     }
 
     /// Returns the current [`Version`] of the [`FontSystem`].
     ///
-    /// Loading a font will increase the version of a [`FontSystem`].
+    /// Loading a font or invalidating the [`FontSystem`] will increase its
+    /// version.
     pub fn version(&self) -> Version {
         self.version
     }
@@ -236,12 +251,114 @@ pub fn measure(buffer: &cosmic_text::Buffer) -> Size {
         buffer
             .layout_runs()
             .fold((0.0, 0.0), |(width, height), run| {
-                (run.line_w.max(width), height + run.line_height)
+                // Justified lines are wider than their natural width
+                let (start, end) = run.glyphs.iter().fold(
+                    (f32::INFINITY, f32::NEG_INFINITY),
+                    |(start, end), glyph| {
+                        (start.min(glyph.x), end.max(glyph.x + glyph.w))
+                    },
+                );
+
+                let line_width = run.line_w.max(end - start);
+
+                (line_width.max(width), height + run.line_height)
             });
 
     Size::new(width, height)
 }
 
+/// Justifies the lines of the given [`cosmic_text::Buffer`] to the given
+/// width.
+///
+/// The spaces of every line, except the last one of each paragraph, are
+/// stretched so the line fills the width. A line that would need its spaces
+/// stretched beyond the given factor is aligned to the left instead; the
+/// rest of its paragraph is still justified.
+///
+/// Since the alignment of a [`cosmic_text::BufferLine`] applies to all of
+/// its visual lines, a paragraph is split right after each of the lines
+/// aligned to the left. The split lines are joined back when justifying the
+/// [`cosmic_text::Buffer`] again.
+pub fn justify(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    width: f32,
+    max_space_factor: f32,
+) {
+    // Only the last line of the text has no ending, unless it was split
+    let mut i = 0;
+
+    while i + 1 < buffer.lines.len() {
+        if buffer.lines[i].ending() == cosmic_text::LineEnding::None {
+            let next = buffer.lines.remove(i + 1);
+            let ending = next.ending();
+
+            let line = &mut buffer.lines[i];
+            line.append(next);
+            let _ = line.set_ending(ending);
+        } else {
+            i += 1;
+        }
+    }
+
+    for line in &mut buffer.lines {
+        let _ = line.set_align(None);
+    }
+
+    buffer.shape_until_scroll(font_system, false);
+
+    let mut splits = Vec::new();
+    let mut runs = buffer.layout_runs().peekable();
+
+    while let Some(run) = runs.next() {
+        let Some(next) = runs.peek().filter(|next| next.line_i == run.line_i)
+        else {
+            continue;
+        };
+
+        let (spaces, space_width) = run
+            .glyphs
+            .iter()
+            .filter(|glyph| run.text[glyph.start..glyph.end] == *" ")
+            .fold((0, 0.0), |(spaces, width), glyph| {
+                (spaces + 1, width + glyph.w)
+            });
+
+        if spaces == 0 || space_width <= 0.0 {
+            continue;
+        }
+
+        let space_width = space_width / spaces as f32;
+        let stretched = space_width + (width - run.line_w) / spaces as f32;
+
+        if stretched > space_width * max_space_factor {
+            if let Some(index) =
+                next.glyphs.iter().map(|glyph| glyph.start).min()
+            {
+                splits.push((run.line_i, index));
+            }
+        }
+    }
+
+    // Split from the end, so the pending indices stay valid
+    for (line_i, index) in splits.into_iter().rev() {
+        let line = &mut buffer.lines[line_i];
+        let ending = line.ending();
+
+        let mut rest = line.split_off(index);
+        let _ = rest.set_ending(ending);
+        let _ = line.set_ending(cosmic_text::LineEnding::None);
+
+        buffer.lines.insert(line_i + 1, rest);
+    }
+
+    for line in &mut buffer.lines {
+        let _ = line.set_align(Some(cosmic_text::Align::Justified));
+    }
+
+    buffer.shape_until_scroll(font_system, false);
+}
+
 /// Returns the attributes of the given [`Font`].
 pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
     cosmic_text::Attrs::new()
@@ -249,6 +366,19 @@ pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
         .weight(to_weight(font.weight))
         .stretch(to_stretch(font.stretch))
         .style(to_style(font.style))
+        .font_features(to_features(font.features))
+}
+
+fn to_features(features: &[font::Feature]) -> cosmic_text::FontFeatures {
+    cosmic_text::FontFeatures {
+        features: features
+            .iter()
+            .map(|feature| cosmic_text::Feature {
+                tag: cosmic_text::FeatureTag::new(&feature.tag),
+                value: feature.value,
+            })
+            .collect(),
+    }
 }
 
 fn to_family(family: font::Family) -> cosmic_text::Family<'static> {
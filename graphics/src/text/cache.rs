@@ -1,4 +1,5 @@
 //! Cache text.
+use crate::core::alignment;
 use crate::core::{Font, Size};
 use crate::text;
 
@@ -12,6 +13,7 @@ pub struct Cache {
     entries: FxHashMap<KeyHash, Entry>,
     aliases: FxHashMap<KeyHash, KeyHash>,
     recently_used: FxHashSet<KeyHash>,
+    version: text::Version,
 }
 
 impl Cache {
@@ -25,6 +27,23 @@ impl Cache {
         self.entries.get(key)
     }
 
+    /// Clears the [`Cache`] if its entries were shaped with a different
+    /// [`text::Version`] of the font system.
+    ///
+    /// Returns `true` if the [`Cache`] was cleared.
+    pub fn clear_if_outdated(&mut self, version: text::Version) -> bool {
+        if self.version == version {
+            return false;
+        }
+
+        self.entries.clear();
+        self.aliases.clear();
+        self.recently_used.clear();
+        self.version = version;
+
+        true
+    }
+
     /// Allocates a text [`Entry`] if it is not already present in the [`Cache`].
     pub fn allocate(
         &mut self,
@@ -54,16 +73,28 @@ impl Cache {
             buffer.set_text(
                 font_system,
                 key.content,
-                text::to_attributes(key.font),
+                &text::to_attributes(key.font),
                 text::to_shaping(key.shaping),
             );
 
+            let is_justified = key.is_justified();
+
+            if is_justified {
+                text::justify(
+                    &mut buffer,
+                    font_system,
+                    key.bounds.width,
+                    key.max_space_factor,
+                );
+            }
+
             let bounds = text::measure(&buffer);
             let _ = entry.insert(Entry {
                 buffer,
                 min_bounds: bounds,
             });
 
+            // Justified text depends on the exact width of its bounds
             for bounds in [
                 bounds,
                 Size {
@@ -71,7 +102,7 @@ impl Cache {
                     ..bounds
                 },
             ] {
-                if key.bounds != bounds {
+                if !is_justified && key.bounds != bounds {
                     let _ = self.aliases.insert(
                         Key { bounds, ..key }.hash(FxHasher::default()),
                         hash,
@@ -112,11 +143,19 @@ pub struct Key<'a> {
     pub font: Font,
     /// The bounds of the text.
     pub bounds: Size,
+    /// The horizontal alignment of the text.
+    pub horizontal_alignment: alignment::Horizontal,
+    /// The maximum factor the spaces of justified text can be stretched by.
+    pub max_space_factor: f32,
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
 }
 
 impl Key<'_> {
+    fn is_justified(&self) -> bool {
+        self.horizontal_alignment == alignment::Horizontal::Justify
+    }
+
     fn hash<H: Hasher>(self, mut hasher: H) -> KeyHash {
         self.content.hash(&mut hasher);
         self.size.to_bits().hash(&mut hasher);
@@ -126,6 +165,11 @@ impl Key<'_> {
         self.bounds.height.to_bits().hash(&mut hasher);
         self.shaping.hash(&mut hasher);
 
+        // Any other alignment is applied when drawing the text
+        if self.is_justified() {
+            self.max_space_factor.to_bits().hash(&mut hasher);
+        }
+
         hasher.finish()
     }
 }
@@ -1,10 +1,11 @@
 //! Draw and edit text.
+use crate::core::font::{self, Font};
 use crate::core::text::editor::{
     self, Action, Cursor, Direction, Edit, Motion,
 };
 use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::LineHeight;
-use crate::core::{Font, Pixels, Point, Rectangle, Size};
+use crate::core::{Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use cosmic_text::Edit as _;
@@ -71,7 +72,7 @@ impl editor::Editor for Editor {
         buffer.set_text(
             font_system.raw(),
             text,
-            cosmic_text::Attrs::new(),
+            &cosmic_text::Attrs::new(),
             cosmic_text::Shaping::Advanced,
         );
 
@@ -428,6 +429,7 @@ impl editor::Editor for Editor {
         &mut self,
         new_bounds: Size,
         new_font: Font,
+        new_font_features: &'static [font::Feature],
         new_size: Pixels,
         new_line_height: LineHeight,
         new_highlighter: &mut impl Highlighter,
@@ -455,6 +457,12 @@ impl editor::Editor for Editor {
             internal.topmost_line_changed = Some(0);
         }
 
+        let new_font = if new_font_features.is_empty() {
+            new_font
+        } else {
+            new_font.features(new_font_features)
+        };
+
         if new_font != internal.font {
             log::trace!("Updating font of `Editor`...");
 
@@ -463,7 +471,7 @@ impl editor::Editor for Editor {
                 .iter_mut()
             {
                 let _ = line.set_attrs_list(cosmic_text::AttrsList::new(
-                    text::to_attributes(new_font),
+                    &text::to_attributes(new_font),
                 ));
             }
 
@@ -558,12 +566,21 @@ impl editor::Editor for Editor {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
-        let attributes = text::to_attributes(font);
+        let features = internal.font.features;
+        let with_features = |font: Font| {
+            if font.features.is_empty() {
+                font.features(features)
+            } else {
+                font
+            }
+        };
+
+        let attributes = text::to_attributes(with_features(font));
 
         for line in &mut buffer_mut_from_editor(&mut internal.editor).lines
             [current_line..=last_visible_line]
         {
-            let mut list = cosmic_text::AttrsList::new(attributes);
+            let mut list = cosmic_text::AttrsList::new(&attributes);
 
             for (range, highlight) in highlighter.highlight_line(line.text()) {
                 let format = format_highlight(&highlight);
@@ -571,12 +588,12 @@ impl editor::Editor for Editor {
                 if format.color.is_some() || format.font.is_some() {
                     list.add_span(
                         range,
-                        cosmic_text::Attrs {
+                        &cosmic_text::Attrs {
                             color_opt: format.color.map(text::to_color),
                             ..if let Some(font) = format.font {
-                                text::to_attributes(font)
+                                text::to_attributes(with_features(font))
                             } else {
-                                attributes
+                                attributes.clone()
                             }
                         },
                     );
@@ -19,6 +19,7 @@ struct Internal {
     shaping: Shaping,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    max_space_factor: f32,
     bounds: Size,
     min_bounds: Size,
     version: text::Version,
@@ -65,6 +66,8 @@ impl core::text::Paragraph for Paragraph {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
+        let font = text.font_with_features();
+
         let mut buffer = cosmic_text::Buffer::new(
             font_system.raw(),
             cosmic_text::Metrics::new(
@@ -82,17 +85,27 @@ impl core::text::Paragraph for Paragraph {
         buffer.set_text(
             font_system.raw(),
             text.content,
-            text::to_attributes(text.font),
+            &text::to_attributes(font),
             text::to_shaping(text.shaping),
         );
 
+        if text.horizontal_alignment == alignment::Horizontal::Justify {
+            text::justify(
+                &mut buffer,
+                font_system.raw(),
+                text.bounds.width,
+                text.max_space_factor,
+            );
+        }
+
         let min_bounds = text::measure(&buffer);
 
         Self(Arc::new(Internal {
             buffer,
-            font: text.font,
+            font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            max_space_factor: text.max_space_factor,
             shaping: text.shaping,
             bounds: text.bounds,
             min_bounds,
@@ -106,6 +119,8 @@ impl core::text::Paragraph for Paragraph {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
+        let font = text.font_with_features();
+
         let mut buffer = cosmic_text::Buffer::new(
             font_system.raw(),
             cosmic_text::Metrics::new(
@@ -123,7 +138,7 @@ impl core::text::Paragraph for Paragraph {
         buffer.set_rich_text(
             font_system.raw(),
             text.content.iter().enumerate().map(|(i, span)| {
-                let attrs = text::to_attributes(span.font.unwrap_or(text.font));
+                let attrs = text::to_attributes(span.font.unwrap_or(font));
 
                 let attrs = match (span.size, span.line_height) {
                     (None, None) => attrs,
@@ -148,17 +163,27 @@ impl core::text::Paragraph for Paragraph {
 
                 (span.text.as_ref(), attrs.metadata(i))
             }),
-            text::to_attributes(text.font),
+            &text::to_attributes(font),
             text::to_shaping(text.shaping),
         );
 
+        if text.horizontal_alignment == alignment::Horizontal::Justify {
+            text::justify(
+                &mut buffer,
+                font_system.raw(),
+                text.bounds.width,
+                text.max_space_factor,
+            );
+        }
+
         let min_bounds = text::measure(&buffer);
 
         Self(Arc::new(Internal {
             buffer,
-            font: text.font,
+            font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            max_space_factor: text.max_space_factor,
             shaping: text.shaping,
             bounds: text.bounds,
             min_bounds,
@@ -178,6 +203,15 @@ impl core::text::Paragraph for Paragraph {
             Some(new_bounds.height),
         );
 
+        if paragraph.horizontal_alignment == alignment::Horizontal::Justify {
+            text::justify(
+                &mut paragraph.buffer,
+                font_system.raw(),
+                new_bounds.width,
+                paragraph.max_space_factor,
+            );
+        }
+
         paragraph.bounds = new_bounds;
         paragraph.min_bounds = text::measure(&paragraph.buffer);
     }
@@ -190,10 +224,11 @@ impl core::text::Paragraph for Paragraph {
         if paragraph.version != font_system.version
             || metrics.font_size != text.size.0
             || metrics.line_height != text.line_height.to_absolute(text.size).0
-            || paragraph.font != text.font
+            || paragraph.font != text.font_with_features()
             || paragraph.shaping != text.shaping
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
+            || paragraph.max_space_factor != text.max_space_factor
         {
             core::text::Difference::Shape
         } else if paragraph.bounds != text.bounds {
@@ -215,6 +250,12 @@ impl core::text::Paragraph for Paragraph {
         self.internal().min_bounds
     }
 
+    fn baseline(&self) -> Option<f32> {
+        let run = self.internal().buffer.layout_runs().next()?;
+
+        Some(run.line_y)
+    }
+
     fn hit_test(&self, point: Point) -> Option<Hit> {
         let cursor = self.internal().buffer.hit(point.x, point.y)?;
 
@@ -321,6 +362,7 @@ impl PartialEq for Internal {
             && self.shaping == other.shaping
             && self.horizontal_alignment == other.horizontal_alignment
             && self.vertical_alignment == other.vertical_alignment
+            && self.max_space_factor == other.max_space_factor
             && self.bounds == other.bounds
             && self.min_bounds == other.min_bounds
             && self.buffer.metrics() == other.buffer.metrics()
@@ -338,6 +380,7 @@ impl Default for Internal {
             shaping: Shaping::default(),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            max_space_factor: core::text::DEFAULT_MAX_SPACE_FACTOR,
             bounds: Size::ZERO,
             min_bounds: Size::ZERO,
             version: text::Version::default(),
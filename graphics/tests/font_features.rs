@@ -0,0 +1,80 @@
+use iced_graphics::core::alignment;
+use iced_graphics::core::font::{self, Font};
+use iced_graphics::core::text::{self, LineHeight, Paragraph as _, Shaping};
+use iced_graphics::core::{Pixels, Size};
+use iced_graphics::text::{font_system, Paragraph};
+
+use std::borrow::Cow;
+
+const FIRA_SANS: &[u8] = include_bytes!("../fonts/FiraSans-Regular.ttf");
+
+const DIGITS: &str = "1234567890";
+
+const TABULAR: &[font::Feature] = &[font::Feature::enable(b"tnum")];
+
+const PROPORTIONAL: &[font::Feature] = &[font::Feature::enable(b"pnum")];
+
+fn paragraph(font: Font, font_features: &'static [font::Feature]) -> Paragraph {
+    font_system()
+        .write()
+        .expect("Write font system")
+        .load_font(Cow::Borrowed(FIRA_SANS));
+
+    Paragraph::with_text(text::Text {
+        content: DIGITS,
+        bounds: Size::INFINITY,
+        size: Pixels(16.0),
+        line_height: LineHeight::default(),
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+        font_features,
+        shaping: Shaping::Advanced,
+    })
+}
+
+fn advances(paragraph: &Paragraph) -> Vec<f32> {
+    paragraph
+        .buffer()
+        .layout_runs()
+        .flat_map(|run| run.glyphs.iter().map(|glyph| glyph.w))
+        .collect()
+}
+
+#[test]
+fn tabular_figures_have_the_same_advance() {
+    let tabular = advances(&paragraph(Font::with_name("Fira Sans"), TABULAR));
+
+    assert_eq!(tabular.len(), DIGITS.len());
+    assert!(tabular.iter().all(|advance| *advance == tabular[0]));
+
+    let proportional =
+        advances(&paragraph(Font::with_name("Fira Sans"), PROPORTIONAL));
+
+    assert!(proportional
+        .iter()
+        .any(|advance| *advance != proportional[0]));
+}
+
+#[test]
+fn font_features_are_measured() {
+    let tabular = paragraph(Font::with_name("Fira Sans"), TABULAR);
+    let proportional = paragraph(Font::with_name("Fira Sans"), PROPORTIONAL);
+
+    assert_ne!(tabular.min_bounds().width, proportional.min_bounds().width);
+}
+
+#[test]
+fn text_features_replace_the_default_features_of_the_font() {
+    let font = Font::with_name("Fira Sans").features(TABULAR);
+
+    assert_eq!(
+        advances(&paragraph(font, &[])),
+        advances(&paragraph(font, TABULAR))
+    );
+    assert_eq!(
+        advances(&paragraph(font, PROPORTIONAL)),
+        advances(&paragraph(Font::with_name("Fira Sans"), PROPORTIONAL))
+    );
+}
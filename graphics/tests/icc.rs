@@ -0,0 +1,32 @@
+#![cfg(feature = "icc")]
+use iced_graphics::core::image::{ColorHandling, Handle};
+use iced_graphics::image;
+
+/// The same pixels, tagged with the sRGB and Display P3 primaries.
+const SRGB: &[u8] = include_bytes!("fixtures/srgb.png");
+const DISPLAY_P3: &[u8] = include_bytes!("fixtures/display_p3.png");
+
+fn pixels(bytes: &'static [u8], color_handling: ColorHandling) -> Vec<u8> {
+    let handle = Handle::from_bytes(bytes).color_handling(color_handling);
+
+    image::load(&handle)
+        .expect("Load test image")
+        .into_raw()
+        .to_vec()
+}
+
+#[test]
+fn embedded_profiles_are_converted_to_srgb() {
+    assert_ne!(
+        pixels(SRGB, ColorHandling::ConvertToSrgb),
+        pixels(DISPLAY_P3, ColorHandling::ConvertToSrgb)
+    );
+}
+
+#[test]
+fn embedded_profiles_can_be_ignored() {
+    assert_eq!(
+        pixels(SRGB, ColorHandling::Ignore),
+        pixels(DISPLAY_P3, ColorHandling::Ignore)
+    );
+}
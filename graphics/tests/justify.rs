@@ -0,0 +1,153 @@
+use iced_graphics::core::alignment;
+use iced_graphics::core::text::{self, LineHeight, Paragraph as _, Shaping};
+use iced_graphics::core::{Font, Pixels, Size};
+use iced_graphics::text::cache::{self, Cache};
+use iced_graphics::text::{cosmic_text, font_system, Paragraph};
+
+use std::borrow::Cow;
+
+const FIRA_SANS: &[u8] = include_bytes!("../fonts/FiraSans-Regular.ttf");
+
+const CONTENT: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing \
+    elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+
+/// A first line too short to be justified, followed by [`CONTENT`].
+const SHORT_FIRST_LINE: &str = "I am an Incomprehensibilities Lorem ipsum \
+    dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor \
+    incididunt ut labore et dolore magna aliqua.";
+
+const WIDTH: f32 = 200.0;
+
+fn load_font() {
+    font_system()
+        .write()
+        .expect("Write font system")
+        .load_font(Cow::Borrowed(FIRA_SANS));
+}
+
+fn paragraph(
+    content: &str,
+    horizontal_alignment: alignment::Horizontal,
+    max_space_factor: f32,
+) -> Paragraph {
+    load_font();
+
+    Paragraph::with_text(text::Text {
+        content,
+        bounds: Size::new(WIDTH, f32::INFINITY),
+        size: Pixels(16.0),
+        line_height: LineHeight::default(),
+        font: Font::with_name("Fira Sans"),
+        horizontal_alignment,
+        vertical_alignment: alignment::Vertical::Top,
+        max_space_factor,
+        font_features: &[],
+        shaping: Shaping::Basic,
+    })
+}
+
+/// Returns the width of every visual line, from its first to its last glyph.
+fn line_widths(buffer: &cosmic_text::Buffer) -> Vec<f32> {
+    buffer
+        .layout_runs()
+        .map(|run| {
+            let (start, end) = run.glyphs.iter().fold(
+                (f32::INFINITY, f32::NEG_INFINITY),
+                |(start, end), glyph| {
+                    (start.min(glyph.x), end.max(glyph.x + glyph.w))
+                },
+            );
+
+            end - start
+        })
+        .collect()
+}
+
+#[test]
+fn justified_lines_fill_the_available_width() {
+    let ragged = paragraph(CONTENT, alignment::Horizontal::Left, 3.0);
+    let justified = paragraph(CONTENT, alignment::Horizontal::Justify, 3.0);
+
+    assert!(ragged.min_bounds().width < WIDTH);
+    assert!((justified.min_bounds().width - WIDTH).abs() < 1.0);
+    assert_eq!(ragged.min_bounds().height, justified.min_bounds().height);
+}
+
+#[test]
+fn justification_is_limited_by_the_maximum_space_factor() {
+    let ragged = paragraph(CONTENT, alignment::Horizontal::Left, 3.0);
+    let limited = paragraph(CONTENT, alignment::Horizontal::Justify, 1.0);
+
+    assert_eq!(ragged.min_bounds(), limited.min_bounds());
+}
+
+#[test]
+fn lines_above_the_maximum_space_factor_are_aligned_to_the_left() {
+    let ragged = paragraph(SHORT_FIRST_LINE, alignment::Horizontal::Left, 3.0);
+    let justified =
+        paragraph(SHORT_FIRST_LINE, alignment::Horizontal::Justify, 3.0);
+
+    let ragged = line_widths(ragged.buffer());
+    let justified = line_widths(justified.buffer());
+
+    assert_eq!(ragged.len(), justified.len());
+    assert_eq!(ragged[0], justified[0]);
+    assert_eq!(ragged.last(), justified.last());
+
+    // The rest of the paragraph is still justified
+    assert!(justified[1..justified.len() - 1]
+        .iter()
+        .all(|width| (width - WIDTH).abs() < 1.0));
+}
+
+#[test]
+fn justification_is_undone_when_resized() {
+    let mut resized =
+        paragraph(SHORT_FIRST_LINE, alignment::Horizontal::Justify, 3.0);
+    let lines = resized.buffer().lines.len();
+
+    resized.resize(Size::new(WIDTH * 2.0, f32::INFINITY));
+    resized.resize(Size::new(WIDTH, f32::INFINITY));
+
+    let justified =
+        paragraph(SHORT_FIRST_LINE, alignment::Horizontal::Justify, 3.0);
+
+    assert_eq!(resized.buffer().lines.len(), lines);
+    assert_eq!(
+        line_widths(resized.buffer()),
+        line_widths(justified.buffer())
+    );
+}
+
+#[test]
+fn cached_text_is_justified() {
+    load_font();
+
+    let mut font_system = font_system().write().expect("Write font system");
+    let mut cache = Cache::new();
+
+    let mut min_bounds = |horizontal_alignment| {
+        let (_, entry) = cache.allocate(
+            font_system.raw(),
+            cache::Key {
+                content: CONTENT,
+                size: 16.0,
+                line_height: LineHeight::default().to_absolute(Pixels(16.0)).0,
+                font: Font::with_name("Fira Sans"),
+                bounds: Size::new(WIDTH, f32::INFINITY),
+                horizontal_alignment,
+                max_space_factor: 3.0,
+                shaping: Shaping::Basic,
+            },
+        );
+
+        entry.min_bounds
+    };
+
+    let ragged = min_bounds(alignment::Horizontal::Left);
+    let justified = min_bounds(alignment::Horizontal::Justify);
+
+    assert!(ragged.width < WIDTH);
+    assert!((justified.width - WIDTH).abs() < 1.0);
+    assert_eq!(ragged.height, justified.height);
+}
@@ -68,6 +68,26 @@ where
     fn end_transformation(&mut self) {
         delegate!(self, renderer, renderer.end_transformation());
     }
+
+    fn start_filter_method(&mut self, filter_method: image::FilterMethod) {
+        delegate!(self, renderer, renderer.start_filter_method(filter_method));
+    }
+
+    fn end_filter_method(&mut self) {
+        delegate!(self, renderer, renderer.end_filter_method());
+    }
+
+    fn start_snapshot(&mut self) {
+        delegate!(self, renderer, renderer.start_snapshot());
+    }
+
+    fn end_snapshot(&mut self) -> Option<renderer::Snapshot> {
+        delegate!(self, renderer, renderer.end_snapshot())
+    }
+
+    fn draw_snapshot(&mut self, snapshot: &renderer::Snapshot, opacity: f32) {
+        delegate!(self, renderer, renderer.draw_snapshot(snapshot, opacity));
+    }
 }
 
 impl<A, B> core::text::Renderer for Renderer<A, B>
@@ -125,11 +145,13 @@ where
 
     fn fill_text(
         &mut self,
-        text: core::Text<String, Self::Font>,
+        text: impl Into<core::text::Fill<'_, Self::Paragraph>>,
         position: Point,
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        let text = text.into();
+
         delegate!(
             self,
             renderer,
@@ -245,46 +267,21 @@ where
         compatible_window: W,
         backend: Option<&str>,
     ) -> Result<Self, graphics::Error> {
-        use std::env;
-
-        let backends = backend
-            .map(str::to_owned)
-            .or_else(|| env::var("ICED_BACKEND").ok());
-
-        let mut candidates: Vec<_> = backends
-            .map(|backends| {
-                backends
-                    .split(',')
-                    .filter(|candidate| !candidate.is_empty())
-                    .map(str::to_owned)
-                    .map(Some)
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        if candidates.is_empty() {
-            candidates.push(None);
-        }
-
         let mut errors = vec![];
 
-        for backend in candidates.iter().map(Option::as_deref) {
-            match A::with_backend(settings, compatible_window.clone(), backend)
-                .await
-            {
-                Ok(compositor) => return Ok(Self::Primary(compositor)),
-                Err(error) => {
-                    errors.push(error);
-                }
+        match A::with_backend(settings, compatible_window.clone(), backend)
+            .await
+        {
+            Ok(compositor) => return Ok(Self::Primary(compositor)),
+            Err(error) => {
+                errors.push(error);
             }
+        }
 
-            match B::with_backend(settings, compatible_window.clone(), backend)
-                .await
-            {
-                Ok(compositor) => return Ok(Self::Secondary(compositor)),
-                Err(error) => {
-                    errors.push(error);
-                }
+        match B::with_backend(settings, compatible_window, backend).await {
+            Ok(compositor) => return Ok(Self::Secondary(compositor)),
+            Err(error) => {
+                errors.push(error);
             }
         }
 
@@ -339,6 +336,27 @@ where
         delegate!(self, compositor, compositor.load_font(font));
     }
 
+    fn invalidate_text(&mut self) {
+        delegate!(self, compositor, compositor.invalidate_text());
+    }
+
+    fn warm_up(&mut self) {
+        delegate!(self, compositor, compositor.warm_up());
+    }
+
+    fn preload_glyphs(
+        &mut self,
+        font: core::Font,
+        sizes: &[core::Pixels],
+        charset: &str,
+    ) {
+        delegate!(
+            self,
+            compositor,
+            compositor.preload_glyphs(font, sizes, charset)
+        );
+    }
+
     fn fetch_information(&self) -> compositor::Information {
         delegate!(self, compositor, compositor.fetch_information())
     }
@@ -441,7 +459,7 @@ where
 #[cfg(feature = "geometry")]
 mod geometry {
     use super::Renderer;
-    use crate::core::{Point, Radians, Rectangle, Size, Vector};
+    use crate::core::{image, svg, Point, Radians, Rectangle, Size, Vector};
     use crate::graphics::cache::{self, Cached};
     use crate::graphics::geometry::{self, Fill, Path, Stroke, Text};
 
@@ -475,6 +493,26 @@ mod geometry {
                 _ => unreachable!(),
             }
         }
+
+        fn draw_geometry_over(&mut self, geometry: Self::Geometry) {
+            match (self, geometry) {
+                (Self::Primary(renderer), Geometry::Primary(geometry)) => {
+                    renderer.draw_geometry_over(geometry);
+                }
+                (Self::Secondary(renderer), Geometry::Secondary(geometry)) => {
+                    renderer.draw_geometry_over(geometry);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        fn start_clip(&mut self, path: &Path) {
+            delegate!(self, renderer, renderer.start_clip(path));
+        }
+
+        fn end_clip(&mut self) {
+            delegate!(self, renderer, renderer.end_clip());
+        }
     }
 
     #[derive(Debug, Clone)]
@@ -572,6 +610,14 @@ mod geometry {
             delegate!(self, frame, frame.fill_text(text));
         }
 
+        fn draw_image(&mut self, bounds: Rectangle, handle: image::Handle) {
+            delegate!(self, frame, frame.draw_image(bounds, handle));
+        }
+
+        fn draw_svg(&mut self, bounds: Rectangle, handle: svg::Handle) {
+            delegate!(self, frame, frame.draw_svg(bounds, handle));
+        }
+
         fn push_transform(&mut self) {
             delegate!(self, frame, frame.push_transform());
         }
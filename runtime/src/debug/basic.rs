@@ -3,36 +3,68 @@ use crate::core::time;
 
 use std::collections::VecDeque;
 
+/// The default smoothing factor of the exponential moving averages.
+const DEFAULT_SMOOTHING: f64 = 0.1;
+
+/// How often the smoothed numbers of the overlay are refreshed.
+const REFRESH_INTERVAL: time::Duration = time::Duration::from_millis(250);
+
+/// The amount of frames displayed in the sparkline.
+const SPARKLINE_LENGTH: usize = 60;
+
+/// The characters used to draw the sparkline, from shortest to tallest.
+const SPARKLINE_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
+    display: Display,
+    smoothing: f64,
 
     startup_start: time::Instant,
     startup_duration: time::Duration,
 
+    warm_up_start: time::Instant,
+    warm_up_duration: time::Duration,
+    first_frame_duration: Option<time::Duration>,
+
     update_start: time::Instant,
-    update_durations: TimeBuffer,
+    update_durations: Metric,
 
     view_start: time::Instant,
-    view_durations: TimeBuffer,
+    view_durations: Metric,
 
     layout_start: time::Instant,
-    layout_durations: TimeBuffer,
+    layout_durations: Metric,
 
     event_start: time::Instant,
-    event_durations: TimeBuffer,
+    event_durations: Metric,
 
     draw_start: time::Instant,
-    draw_durations: TimeBuffer,
+    draw_durations: Metric,
 
     render_start: time::Instant,
-    render_durations: TimeBuffer,
+    render_durations: Metric,
+
+    frame_durations: Metric,
+
+    last_refresh: Option<time::Instant>,
+    readings: Option<Readings>,
 
     message_count: usize,
     last_messages: VecDeque<String>,
 }
 
+/// How the measurements are displayed in the overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Display {
+    /// The latest measurements, updated every frame.
+    Raw,
+    /// The moving averages, updated a few times per second.
+    Smoothed,
+}
+
 impl Debug {
     /// Creates a new [`struct@Debug`].
     pub fn new() -> Self {
@@ -40,26 +72,37 @@ impl Debug {
 
         Self {
             is_enabled: false,
+            display: Display::Smoothed,
+            smoothing: DEFAULT_SMOOTHING,
             startup_start: now,
             startup_duration: time::Duration::from_secs(0),
 
+            warm_up_start: now,
+            warm_up_duration: time::Duration::from_secs(0),
+            first_frame_duration: None,
+
             update_start: now,
-            update_durations: TimeBuffer::new(200),
+            update_durations: Metric::new(200),
 
             view_start: now,
-            view_durations: TimeBuffer::new(200),
+            view_durations: Metric::new(200),
 
             layout_start: now,
-            layout_durations: TimeBuffer::new(200),
+            layout_durations: Metric::new(200),
 
             event_start: now,
-            event_durations: TimeBuffer::new(200),
+            event_durations: Metric::new(200),
 
             draw_start: now,
-            draw_durations: TimeBuffer::new(200),
+            draw_durations: Metric::new(200),
 
             render_start: now,
-            render_durations: TimeBuffer::new(50),
+            render_durations: Metric::new(50),
+
+            frame_durations: Metric::new(200),
+
+            last_refresh: None,
+            readings: None,
 
             message_count: 0,
             last_messages: VecDeque::new(),
@@ -68,6 +111,25 @@ impl Debug {
 
     pub fn toggle(&mut self) {
         self.is_enabled = !self.is_enabled;
+        self.readings = None;
+    }
+
+    /// Cycles between displaying the raw and the smoothed measurements.
+    pub fn toggle_smoothing(&mut self) {
+        self.display = match self.display {
+            Display::Raw => Display::Smoothed,
+            Display::Smoothed => Display::Raw,
+        };
+        self.readings = None;
+    }
+
+    /// Sets the smoothing factor of the moving averages, from `0.0` to
+    /// `1.0`.
+    ///
+    /// Lower values produce steadier numbers that take longer to react to
+    /// changes.
+    pub fn set_smoothing(&mut self, alpha: f64) {
+        self.smoothing = alpha.clamp(f64::EPSILON, 1.0);
     }
 
     pub fn startup_started(&mut self) {
@@ -78,12 +140,21 @@ impl Debug {
         self.startup_duration = self.startup_start.elapsed();
     }
 
+    pub fn warm_up_started(&mut self) {
+        self.warm_up_start = time::Instant::now();
+    }
+
+    pub fn warm_up_finished(&mut self) {
+        self.warm_up_duration = self.warm_up_start.elapsed();
+    }
+
     pub fn update_started(&mut self) {
         self.update_start = time::Instant::now();
     }
 
     pub fn update_finished(&mut self) {
-        self.update_durations.push(self.update_start.elapsed());
+        self.update_durations
+            .push(self.update_start.elapsed(), self.smoothing);
     }
 
     pub fn view_started(&mut self) {
@@ -91,7 +162,8 @@ impl Debug {
     }
 
     pub fn view_finished(&mut self) {
-        self.view_durations.push(self.view_start.elapsed());
+        self.view_durations
+            .push(self.view_start.elapsed(), self.smoothing);
     }
 
     pub fn layout_started(&mut self) {
@@ -99,7 +171,8 @@ impl Debug {
     }
 
     pub fn layout_finished(&mut self) {
-        self.layout_durations.push(self.layout_start.elapsed());
+        self.layout_durations
+            .push(self.layout_start.elapsed(), self.smoothing);
     }
 
     pub fn event_processing_started(&mut self) {
@@ -107,7 +180,8 @@ impl Debug {
     }
 
     pub fn event_processing_finished(&mut self) {
-        self.event_durations.push(self.event_start.elapsed());
+        self.event_durations
+            .push(self.event_start.elapsed(), self.smoothing);
     }
 
     pub fn draw_started(&mut self) {
@@ -115,7 +189,8 @@ impl Debug {
     }
 
     pub fn draw_finished(&mut self) {
-        self.draw_durations.push(self.draw_start.elapsed());
+        self.draw_durations
+            .push(self.draw_start.elapsed(), self.smoothing);
     }
 
     pub fn render_started(&mut self) {
@@ -123,7 +198,33 @@ impl Debug {
     }
 
     pub fn render_finished(&mut self) {
-        self.render_durations.push(self.render_start.elapsed());
+        let now = time::Instant::now();
+
+        self.render_durations
+            .push(now - self.render_start, self.smoothing);
+
+        // A frame spans from primitive generation to presentation
+        let frame = now.saturating_duration_since(self.draw_start);
+
+        self.frame_durations.push(frame, self.smoothing);
+
+        if self.first_frame_duration.is_none() {
+            self.first_frame_duration = Some(frame);
+        }
+
+        if !self.is_enabled {
+            return;
+        }
+
+        let is_stale = !matches!(
+            self.last_refresh,
+            Some(last_refresh) if now - last_refresh < REFRESH_INTERVAL
+        );
+
+        if self.display == Display::Raw || is_stale {
+            self.readings = Some(self.measure());
+            self.last_refresh = Some(now);
+        }
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
@@ -141,31 +242,58 @@ impl Debug {
             return Vec::new();
         }
 
+        let readings = match self.display {
+            Display::Raw => self.measure(),
+            Display::Smoothed => {
+                self.readings.unwrap_or_else(|| self.measure())
+            }
+        };
+
         let mut lines = Vec::new();
 
         fn key_value<T: std::fmt::Debug>(key: &str, value: T) -> String {
             format!("{key} {value:?}")
         }
 
+        fn key_duration(key: &str, duration: time::Duration) -> String {
+            format!("{key} {}", milliseconds(duration))
+        }
+
         lines.push(format!(
             "{} {} - {}",
             env!("CARGO_PKG_NAME"),
             env!("CARGO_PKG_VERSION"),
             env!("CARGO_PKG_REPOSITORY"),
         ));
+        lines.push(format!(
+            "Display: {} (Shift+F12 to toggle)",
+            match self.display {
+                Display::Raw => "raw",
+                Display::Smoothed => "smoothed",
+            }
+        ));
         lines.push(key_value("Startup:", self.startup_duration));
-        lines.push(key_value("Update:", self.update_durations.average()));
-        lines.push(key_value("View:", self.view_durations.average()));
-        lines.push(key_value("Layout:", self.layout_durations.average()));
-        lines.push(key_value(
-            "Event processing:",
-            self.event_durations.average(),
+        lines.push(format!(
+            "Warm-up: {} (first frame: {})",
+            milliseconds(self.warm_up_duration),
+            milliseconds(self.first_frame_duration.unwrap_or_default()),
+        ));
+        lines.push(key_duration("Update:", readings.update));
+        lines.push(key_duration("View:", readings.view));
+        lines.push(key_duration("Layout:", readings.layout));
+        lines.push(key_duration("Event processing:", readings.event));
+        lines.push(key_duration("Primitive generation:", readings.draw));
+        lines.push(key_duration("Render:", readings.render));
+        lines.push(format!(
+            "Frame: {} (1% low: {}, max: {})",
+            milliseconds(readings.frame),
+            milliseconds(readings.frame_low),
+            milliseconds(readings.frame_max),
         ));
-        lines.push(key_value(
-            "Primitive generation:",
-            self.draw_durations.average(),
+        lines.push(format!(
+            "    {}",
+            sparkline(self.frame_durations.samples.latest(SPARKLINE_LENGTH))
         ));
-        lines.push(key_value("Render:", self.render_durations.average()));
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
@@ -178,6 +306,25 @@ impl Debug {
 
         lines
     }
+
+    fn measure(&self) -> Readings {
+        let value = |metric: &Metric| match self.display {
+            Display::Raw => metric.latest(),
+            Display::Smoothed => metric.average(),
+        };
+
+        Readings {
+            update: value(&self.update_durations),
+            view: value(&self.view_durations),
+            layout: value(&self.layout_durations),
+            event: value(&self.event_durations),
+            draw: value(&self.draw_durations),
+            render: value(&self.render_durations),
+            frame: value(&self.frame_durations),
+            frame_low: self.frame_durations.one_percent_low(),
+            frame_max: self.frame_durations.max(),
+        }
+    }
 }
 
 impl Default for Debug {
@@ -186,6 +333,69 @@ impl Default for Debug {
     }
 }
 
+/// The measurements displayed in the overlay.
+#[derive(Debug, Clone, Copy)]
+struct Readings {
+    update: time::Duration,
+    view: time::Duration,
+    layout: time::Duration,
+    event: time::Duration,
+    draw: time::Duration,
+    render: time::Duration,
+    frame: time::Duration,
+    frame_low: time::Duration,
+    frame_max: time::Duration,
+}
+
+/// A rolling window of measurements and their exponential moving average.
+#[derive(Debug)]
+struct Metric {
+    samples: TimeBuffer,
+    average: Option<f64>,
+}
+
+impl Metric {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: TimeBuffer::new(capacity),
+            average: None,
+        }
+    }
+
+    fn push(&mut self, duration: time::Duration, alpha: f64) {
+        let value = duration.as_secs_f64();
+
+        self.samples.push(duration);
+        self.average = Some(match self.average {
+            Some(average) => average + alpha * (value - average),
+            None => value,
+        });
+    }
+
+    fn latest(&self) -> time::Duration {
+        self.samples.latest(1).next().unwrap_or_default()
+    }
+
+    fn average(&self) -> time::Duration {
+        time::Duration::from_secs_f64(self.average.unwrap_or_default())
+    }
+
+    fn max(&self) -> time::Duration {
+        self.samples.iter().max().unwrap_or_default()
+    }
+
+    /// Returns the average of the slowest 1% of the measurements.
+    fn one_percent_low(&self) -> time::Duration {
+        let mut samples: Vec<_> = self.samples.iter().collect();
+        samples.sort_unstable_by(|a, b| b.cmp(a));
+
+        let count = (samples.len() / 100).max(1).min(samples.len());
+        let sum: time::Duration = samples[..count].iter().sum();
+
+        sum / count.max(1) as u32
+    }
+}
+
 #[derive(Debug)]
 struct TimeBuffer {
     head: usize,
@@ -208,13 +418,47 @@ impl TimeBuffer {
         self.size = (self.size + 1).min(self.contents.len());
     }
 
-    fn average(&self) -> time::Duration {
-        let sum: time::Duration = if self.size == self.contents.len() {
-            self.contents[..].iter().sum()
-        } else {
-            self.contents[..self.size].iter().sum()
-        };
+    /// Returns the measurements in the buffer, from oldest to newest.
+    fn iter(&self) -> impl Iterator<Item = time::Duration> + '_ {
+        self.latest(self.size)
+    }
+
+    /// Returns the last `count` measurements, from oldest to newest.
+    fn latest(
+        &self,
+        count: usize,
+    ) -> impl Iterator<Item = time::Duration> + '_ {
+        let count = count.min(self.size);
+        let length = self.contents.len();
+        let start = self.head + length + 1 - count;
+
+        (0..count).map(move |i| self.contents[(start + i) % length])
+    }
+}
 
-        sum / self.size.max(1) as u32
+/// Formats a [`time::Duration`] as milliseconds with a fixed precision; so
+/// the numbers do not jump around in the overlay.
+fn milliseconds(duration: time::Duration) -> String {
+    format!("{:>7.2} ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Draws the given measurements as a line of block characters, scaled to
+/// the slowest one.
+fn sparkline(samples: impl Iterator<Item = time::Duration>) -> String {
+    let samples: Vec<_> = samples.collect();
+    let max = samples.iter().max().copied().unwrap_or_default();
+
+    if max.is_zero() {
+        return String::new();
     }
+
+    samples
+        .iter()
+        .map(|sample| {
+            let ratio = sample.as_secs_f64() / max.as_secs_f64();
+            let index = (ratio * (SPARKLINE_BLOCKS.len() - 1) as f64).round();
+
+            SPARKLINE_BLOCKS[index as usize]
+        })
+        .collect()
 }
@@ -7,10 +7,16 @@ impl Debug {
         Self
     }
 
+    pub fn set_smoothing(&mut self, _alpha: f64) {}
+
     pub fn startup_started(&mut self) {}
 
     pub fn startup_finished(&mut self) {}
 
+    pub fn warm_up_started(&mut self) {}
+
+    pub fn warm_up_finished(&mut self) {}
+
     pub fn update_started(&mut self) {}
 
     pub fn update_finished(&mut self) {}
@@ -1,4 +1,5 @@
 //! Load and use fonts.
+use crate::core::{Font, Pixels};
 use crate::task::{self, Task};
 use crate::Action;
 use std::borrow::Cow;
@@ -8,9 +9,43 @@ use std::borrow::Cow;
 pub enum Error {}
 
 /// Load a font from its bytes.
+///
+/// Once loaded, all the text of the application is shaped and laid out
+/// again; so it can use the new font right away.
 pub fn load(bytes: impl Into<Cow<'static, [u8]>>) -> Task<Result<(), Error>> {
     task::oneshot(|channel| Action::LoadFont {
         bytes: bytes.into(),
         channel,
     })
 }
+
+/// The characters rasterized by [`preload_glyphs`] when given an empty
+/// charset: the printable ASCII characters.
+pub const ASCII: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@\
+ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// Rasterizes the glyphs of the `charset` in the given [`Font`] and sizes
+/// ahead of time; so the first frames displaying them are not slowed down.
+///
+/// An empty `charset` preloads the [`ASCII`] characters.
+///
+/// Renderers that rasterize glyphs on the fly, or do not cache them, may
+/// ignore this.
+pub fn preload_glyphs(
+    font: Font,
+    sizes: impl IntoIterator<Item = impl Into<Pixels>>,
+    charset: impl Into<String>,
+) -> Task<()> {
+    let charset = charset.into();
+
+    task::oneshot(|channel| Action::PreloadGlyphs {
+        font,
+        sizes: sizes.into_iter().map(Into::into).collect(),
+        charset: if charset.is_empty() {
+            String::from(ASCII)
+        } else {
+            charset
+        },
+        channel,
+    })
+}
@@ -16,12 +16,15 @@ pub mod overlay;
 pub mod program;
 pub mod system;
 pub mod task;
+pub mod text;
 pub mod user_interface;
 pub mod window;
 
 #[cfg(feature = "multi-window")]
 pub mod multi_window;
 
+mod timer;
+
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
 #[cfg(feature = "debug")]
@@ -58,6 +61,21 @@ pub enum Action<T> {
         channel: oneshot::Sender<Result<(), font::Error>>,
     },
 
+    /// Shape and lay out all the text of the user interfaces again.
+    InvalidateText,
+
+    /// Rasterize some glyphs of a font ahead of time.
+    PreloadGlyphs {
+        /// The font of the glyphs.
+        font: core::Font,
+        /// The sizes to rasterize the glyphs at.
+        sizes: Vec<core::Pixels>,
+        /// The characters of the glyphs.
+        charset: String,
+        /// The channel to send back once the glyphs are rasterized.
+        channel: oneshot::Sender<()>,
+    },
+
     /// Run a widget operation.
     Widget(Box<dyn widget::Operation<()>>),
 
@@ -70,6 +88,10 @@ pub enum Action<T> {
     /// Run a system action.
     System(system::Action),
 
+    /// Replaces the splash screen of every window with the actual view of
+    /// the application.
+    DismissSplash,
+
     /// Exits the runtime.
     ///
     /// This will normally close any application windows and
@@ -89,10 +111,23 @@ impl<T> Action<T> {
             Action::LoadFont { bytes, channel } => {
                 Err(Action::LoadFont { bytes, channel })
             }
+            Action::InvalidateText => Err(Action::InvalidateText),
+            Action::PreloadGlyphs {
+                font,
+                sizes,
+                charset,
+                channel,
+            } => Err(Action::PreloadGlyphs {
+                font,
+                sizes,
+                charset,
+                channel,
+            }),
             Action::Widget(operation) => Err(Action::Widget(operation)),
             Action::Clipboard(action) => Err(Action::Clipboard(action)),
             Action::Window(action) => Err(Action::Window(action)),
             Action::System(action) => Err(Action::System(action)),
+            Action::DismissSplash => Err(Action::DismissSplash),
             Action::Exit => Err(Action::Exit),
         }
     }
@@ -108,6 +143,10 @@ where
             Action::LoadFont { .. } => {
                 write!(f, "Action::LoadFont")
             }
+            Action::InvalidateText => write!(f, "Action::InvalidateText"),
+            Action::PreloadGlyphs { font, sizes, .. } => {
+                write!(f, "Action::PreloadGlyphs({font:?}, {sizes:?})")
+            }
             Action::Widget { .. } => {
                 write!(f, "Action::Widget")
             }
@@ -116,6 +155,7 @@ where
             }
             Action::Window(_) => write!(f, "Action::Window"),
             Action::System(action) => write!(f, "Action::System({action:?})"),
+            Action::DismissSplash => write!(f, "Action::DismissSplash"),
             Action::Exit => write!(f, "Action::Exit"),
         }
     }
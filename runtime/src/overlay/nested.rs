@@ -7,6 +7,9 @@ use crate::core::widget;
 use crate::core::{Clipboard, Event, Layout, Point, Rectangle, Shell, Size};
 
 /// An overlay container that displays nested overlays
+///
+/// The top-most overlays of every level are displayed above all the others;
+/// see [`overlay::Element::top_most`].
 #[allow(missing_debug_implementations)]
 pub struct Nested<'a, Message, Theme, Renderer> {
     overlay: overlay::Element<'a, Message, Theme, Renderer>,
@@ -39,6 +42,11 @@ where
         where
             Renderer: renderer::Renderer,
         {
+            let top_most = element
+                .top_most_overlay()
+                .map(|mut top_most| top_most.layout(renderer, bounds))
+                .unwrap_or_else(|| layout::Node::new(Size::ZERO));
+
             let node = element.layout(renderer, bounds);
 
             if let Some(mut nested) =
@@ -46,10 +54,14 @@ where
             {
                 layout::Node::with_children(
                     node.size(),
-                    vec![node, recurse(&mut nested, renderer, bounds)],
+                    vec![
+                        node,
+                        top_most,
+                        recurse(&mut nested, renderer, bounds),
+                    ],
                 )
             } else {
-                layout::Node::with_children(node.size(), vec![node])
+                layout::Node::with_children(node.size(), vec![node, top_most])
             }
         }
 
@@ -72,19 +84,25 @@ where
             theme: &Theme,
             style: &renderer::Style,
             cursor: mouse::Cursor,
+            top_most_cursor: mouse::Cursor,
         ) where
             Renderer: renderer::Renderer,
         {
             let mut layouts = layout.children();
 
             if let Some(layout) = layouts.next() {
+                let top_most_layout = layouts.next();
                 let nested_layout = layouts.next();
 
+                let _ = element.top_most_overlay();
+
                 let is_over = cursor
                     .position()
                     .zip(nested_layout)
                     .and_then(|(cursor_position, nested_layout)| {
-                        element.overlay(layout, renderer).map(|nested| {
+                        element.overlay(layout, renderer).map(|mut nested| {
+                            let _ = nested.top_most_overlay();
+
                             nested.is_over(
                                 nested_layout.children().next().unwrap(),
                                 renderer,
@@ -118,12 +136,44 @@ where
                         theme,
                         style,
                         cursor,
+                        top_most_cursor,
                     );
                 }
+
+                // Drawn after the overlays nested deeper; so it stays on top
+                if let Some((top_most, top_most_layout)) =
+                    element.top_most_overlay().zip(top_most_layout)
+                {
+                    renderer.with_layer(top_most_layout.bounds(), |renderer| {
+                        top_most.draw(
+                            renderer,
+                            theme,
+                            style,
+                            top_most_layout,
+                            top_most_cursor,
+                        );
+                    });
+                }
             }
         }
 
-        recurse(&mut self.overlay, layout, renderer, theme, style, cursor);
+        let is_over_top_most = cursor.position().is_some_and(|position| {
+            is_over_top_most(&mut self.overlay, layout, renderer, position)
+        });
+
+        recurse(
+            &mut self.overlay,
+            layout,
+            renderer,
+            theme,
+            style,
+            if is_over_top_most {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            cursor,
+        );
     }
 
     /// Applies a [`widget::Operation`] to the [`Nested`] overlay.
@@ -144,6 +194,9 @@ where
             let mut layouts = layout.children();
 
             if let Some(layout) = layouts.next() {
+                let top_most_layout = layouts.next();
+                let _ = element.top_most_overlay();
+
                 element.operate(layout, renderer, operation);
 
                 if let Some((mut nested, nested_layout)) =
@@ -151,6 +204,12 @@ where
                 {
                     recurse(&mut nested, nested_layout, renderer, operation);
                 }
+
+                if let Some((mut top_most, top_most_layout)) =
+                    element.top_most_overlay().zip(top_most_layout)
+                {
+                    top_most.operate(top_most_layout, renderer, operation);
+                }
             }
         }
 
@@ -158,6 +217,9 @@ where
     }
 
     /// Processes a runtime [`Event`].
+    ///
+    /// The top-most overlays receive the [`Event`] first; if none of them
+    /// captures it, it is processed by the rest of the overlays.
     pub fn on_event(
         &mut self,
         event: Event,
@@ -167,6 +229,70 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        fn top_most<Message, Theme, Renderer>(
+            element: &mut overlay::Element<'_, Message, Theme, Renderer>,
+            layout: Layout<'_>,
+            event: Event,
+            cursor: mouse::Cursor,
+            renderer: &Renderer,
+            clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+        ) -> (event::Status, bool)
+        where
+            Renderer: renderer::Renderer,
+        {
+            let mut layouts = layout.children();
+
+            let Some(layout) = layouts.next() else {
+                return (event::Status::Ignored, false);
+            };
+
+            let top_most_layout = layouts.next();
+
+            let (status, is_over) = if let Some((mut top_most, layout)) =
+                element.top_most_overlay().zip(top_most_layout)
+            {
+                let is_over = cursor.position().is_some_and(|position| {
+                    top_most.is_over(layout, renderer, position)
+                });
+
+                let status = top_most.on_event(
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+
+                (status, is_over)
+            } else {
+                (event::Status::Ignored, false)
+            };
+
+            if matches!(status, event::Status::Captured) {
+                return (status, is_over);
+            }
+
+            if let Some((mut nested, nested_layout)) =
+                element.overlay(layout, renderer).zip(layouts.next())
+            {
+                let (status, nested_is_over) = top_most(
+                    &mut nested,
+                    nested_layout,
+                    event,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+
+                (status, is_over || nested_is_over)
+            } else {
+                (status, is_over)
+            }
+        }
+
         fn recurse<Message, Theme, Renderer>(
             element: &mut overlay::Element<'_, Message, Theme, Renderer>,
             layout: Layout<'_>,
@@ -182,6 +308,9 @@ where
             let mut layouts = layout.children();
 
             if let Some(layout) = layouts.next() {
+                let _ = layouts.next();
+                let _ = element.top_most_overlay();
+
                 let (nested_status, nested_is_over) =
                     if let Some((mut nested, nested_layout)) =
                         element.overlay(layout, renderer).zip(layouts.next())
@@ -235,11 +364,29 @@ where
             }
         }
 
+        let (status, is_over_top_most) = top_most(
+            &mut self.overlay,
+            layout,
+            event.clone(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if matches!(status, event::Status::Captured) {
+            return status;
+        }
+
         let (status, _) = recurse(
             &mut self.overlay,
             layout,
             event,
-            cursor,
+            if is_over_top_most {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
             renderer,
             clipboard,
             shell,
@@ -256,6 +403,43 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        fn top_most<Message, Theme, Renderer>(
+            element: &mut overlay::Element<'_, Message, Theme, Renderer>,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            viewport: &Rectangle,
+            renderer: &Renderer,
+        ) -> Option<mouse::Interaction>
+        where
+            Renderer: renderer::Renderer,
+        {
+            let mut layouts = layout.children();
+
+            let layout = layouts.next()?;
+            let cursor_position = cursor.position()?;
+
+            if let Some((top_most, top_most_layout)) =
+                element.top_most_overlay().zip(layouts.next())
+            {
+                if top_most.is_over(top_most_layout, renderer, cursor_position)
+                {
+                    return Some(top_most.mouse_interaction(
+                        top_most_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    ));
+                }
+            }
+
+            element
+                .overlay(layout, renderer)
+                .zip(layouts.next())
+                .and_then(|(mut nested, layout)| {
+                    top_most(&mut nested, layout, cursor, viewport, renderer)
+                })
+        }
+
         fn recurse<Message, Theme, Renderer>(
             element: &mut overlay::Element<'_, Message, Theme, Renderer>,
             layout: Layout<'_>,
@@ -271,6 +455,9 @@ where
             let layout = layouts.next()?;
             let cursor_position = cursor.position()?;
 
+            let _ = layouts.next();
+            let _ = element.top_most_overlay();
+
             if !element.is_over(layout, renderer, cursor_position) {
                 return None;
             }
@@ -296,7 +483,10 @@ where
             )
         }
 
-        recurse(&mut self.overlay, layout, cursor, viewport, renderer)
+        top_most(&mut self.overlay, layout, cursor, viewport, renderer)
+            .or_else(|| {
+                recurse(&mut self.overlay, layout, cursor, viewport, renderer)
+            })
             .unwrap_or_default()
     }
 
@@ -319,6 +509,9 @@ where
             let mut layouts = layout.children();
 
             if let Some(layout) = layouts.next() {
+                let _ = layouts.next();
+                let _ = element.top_most_overlay();
+
                 if element.is_over(layout, renderer, cursor_position) {
                     return true;
                 }
@@ -340,6 +533,39 @@ where
             }
         }
 
-        recurse(&mut self.overlay, layout, renderer, cursor_position)
+        is_over_top_most(&mut self.overlay, layout, renderer, cursor_position)
+            || recurse(&mut self.overlay, layout, renderer, cursor_position)
     }
 }
+
+/// Returns true if the cursor is over any of the top-most overlays of the
+/// given nested [`overlay::Element`].
+fn is_over_top_most<Message, Theme, Renderer>(
+    element: &mut overlay::Element<'_, Message, Theme, Renderer>,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    cursor_position: Point,
+) -> bool
+where
+    Renderer: renderer::Renderer,
+{
+    let mut layouts = layout.children();
+
+    let Some(layout) = layouts.next() else {
+        return false;
+    };
+
+    let is_over = element.top_most_overlay().zip(layouts.next()).is_some_and(
+        |(top_most, layout)| {
+            top_most.is_over(layout, renderer, cursor_position)
+        },
+    );
+
+    is_over
+        || element
+            .overlay(layout, renderer)
+            .zip(layouts.next())
+            .is_some_and(|(mut nested, layout)| {
+                is_over_top_most(&mut nested, layout, renderer, cursor_position)
+            })
+}
@@ -1,4 +1,6 @@
 //! Access the native system.
+pub mod power;
+
 use crate::futures::futures::channel::oneshot;
 
 /// An operation to be performed on the system.
@@ -36,4 +38,6 @@ pub struct Information {
     pub graphics_backend: String,
     /// Model information for the active graphics adapter
     pub graphics_adapter: String,
+    /// Texture formats negotiated with the active graphics adapter
+    pub graphics_formats: Option<String>,
 }
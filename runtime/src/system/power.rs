@@ -0,0 +1,71 @@
+//! Observe the power supply of the system.
+
+/// The amount of battery charge, in percent, that must change before an
+/// [`Event::BatteryChanged`] is produced.
+pub const BATTERY_STEP: u8 = 5;
+
+/// The power state of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    /// The current [`Source`] of power.
+    pub source: Source,
+    /// The charge of the battery, in percent, if the system has one.
+    pub battery: Option<u8>,
+    /// Whether the system is in a low-power or battery saving mode.
+    pub is_low_power: bool,
+}
+
+/// The source of power of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// The system is plugged in.
+    Ac,
+    /// The system is running on battery.
+    Battery,
+}
+
+/// A change in the power state of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The system switched to a different [`Source`] of power.
+    SourceChanged(Source),
+    /// The charge of the battery changed, in percent.
+    ///
+    /// Changes are coarse: an event is only produced once the charge crosses
+    /// a multiple of [`BATTERY_STEP`].
+    BatteryChanged(u8),
+    /// The system entered or left low-power mode.
+    LowPowerChanged(bool),
+}
+
+impl State {
+    /// Returns the list of [`Event`] describing the changes between this
+    /// [`State`] and the next one.
+    pub fn changes(&self, next: &State) -> Vec<Event> {
+        let mut events = Vec::new();
+
+        if self.source != next.source {
+            events.push(Event::SourceChanged(next.source));
+        }
+
+        if let Some(battery) = next.battery {
+            let step = |battery: u8| battery / BATTERY_STEP;
+
+            if self.battery.map(step) != Some(step(battery)) {
+                events.push(Event::BatteryChanged(battery));
+            }
+        }
+
+        if self.is_low_power != next.is_low_power {
+            events.push(Event::LowPowerChanged(next.is_low_power));
+        }
+
+        events
+    }
+
+    /// Returns true if the system is running on battery or in low-power mode;
+    /// that is, when applications should save energy.
+    pub fn should_save_energy(&self) -> bool {
+        self.source == Source::Battery || self.is_low_power
+    }
+}
@@ -6,9 +6,12 @@ use crate::futures::futures::future::{self, FutureExt};
 use crate::futures::futures::never::Never;
 use crate::futures::futures::stream::{self, Stream, StreamExt};
 use crate::futures::{boxed_stream, BoxStream, MaybeSend};
+use crate::timer;
 use crate::Action;
 
 use std::future::Future;
+use std::hash::Hash;
+use std::time::Duration;
 
 /// A set of concurrent actions to be performed by the iced runtime.
 ///
@@ -55,6 +58,62 @@ impl<T> Task<T> {
         Self::stream(stream.map(f))
     }
 
+    /// Creates a [`Task`] that produces the given value once the given
+    /// [`Duration`] has elapsed.
+    ///
+    /// This is useful for small delayed behaviors; like hiding a "Copied!"
+    /// label after a couple of seconds.
+    pub fn delay(duration: Duration, value: T) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        Self::future(timer::sleep(duration).map(move |_| value))
+    }
+
+    /// Creates a [`Task`] that produces the given value once the given
+    /// [`Duration`] has elapsed without another [`Task::debounce`] being
+    /// created with the same token.
+    ///
+    /// Creating a new debounced [`Task`] resets the timer of any pending one
+    /// with the same token, which will then finish without producing
+    /// anything. This way, re-triggering the same delayed value in quick
+    /// succession produces it only once—after the last quiet period.
+    ///
+    /// ```no_run
+    /// # use iced_runtime::Task;
+    /// # use std::time::Duration;
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Edited(String),
+    ///     Save,
+    /// }
+    ///
+    /// fn update(message: Message) -> Task<Message> {
+    ///     match message {
+    ///         Message::Edited(_content) => {
+    ///             Task::debounce("save", Duration::from_secs(1), Message::Save)
+    ///         }
+    ///         Message::Save => {
+    ///             // Save the content...
+    ///             Task::none()
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn debounce(token: impl Hash, duration: Duration, value: T) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        let mut value = Some(value);
+
+        Self::stream(
+            stream::once(timer::debounce(timer::Token::new(token), duration))
+                .filter_map(move |is_quiet| {
+                    future::ready(if is_quiet { value.take() } else { None })
+                }),
+        )
+    }
+
     /// Combines the given tasks and produces a single [`Task`] that will run all of them
     /// in parallel.
     pub fn batch(tasks: impl IntoIterator<Item = Self>) -> Self
@@ -353,3 +412,85 @@ pub fn effect<T>(action: impl Into<Action<Never>>) -> Task<T> {
 pub fn into_stream<T>(task: Task<T>) -> Option<BoxStream<Action<T>>> {
     task.0
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::futures::backend::default;
+    use crate::futures::futures::executor;
+    use crate::futures::Executor as _;
+
+    use std::time::Instant;
+
+    fn outputs<T>(task: Task<T>) -> Vec<T> {
+        let Some(stream) = into_stream(task) else {
+            return Vec::new();
+        };
+
+        // Timers rely on the default executor; tokio needs its context
+        let runtime = default::Executor::new().expect("Create executor");
+
+        runtime.enter(|| {
+            executor::block_on(
+                stream
+                    .filter_map(|action| {
+                        future::ready(action.output::<T>().ok())
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    #[test]
+    fn rapid_debounces_produce_a_single_value() {
+        let duration = Duration::from_millis(50);
+
+        let tasks: Vec<_> = (0..5)
+            .map(|i| {
+                std::thread::sleep(Duration::from_millis(10));
+
+                Task::debounce("rapid", duration, i)
+            })
+            .collect();
+
+        let last_trigger = Instant::now();
+
+        assert_eq!(outputs(Task::batch(tasks)), vec![4]);
+        assert!(last_trigger.elapsed() >= duration);
+    }
+
+    #[test]
+    fn quiet_debounces_produce_every_value() {
+        let duration = Duration::from_millis(10);
+
+        let first = outputs(Task::debounce("quiet", duration, 1));
+        let second = outputs(Task::debounce("quiet", duration, 2));
+
+        assert_eq!(first, vec![1]);
+        assert_eq!(second, vec![2]);
+    }
+
+    #[test]
+    fn debounces_with_different_tokens_are_independent() {
+        let duration = Duration::from_millis(10);
+
+        let tasks = Task::batch([
+            Task::debounce("left", duration, "left"),
+            Task::debounce("right", duration, "right"),
+        ]);
+
+        let mut outputs = outputs(tasks);
+        outputs.sort_unstable();
+
+        assert_eq!(outputs, vec!["left", "right"]);
+    }
+
+    #[test]
+    fn delays_wait_before_producing_their_value() {
+        let duration = Duration::from_millis(20);
+        let start = Instant::now();
+
+        assert_eq!(outputs(Task::delay(duration, 42)), vec![42]);
+        assert!(start.elapsed() >= duration);
+    }
+}
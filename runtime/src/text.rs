@@ -0,0 +1,15 @@
+//! Shape and lay out text again.
+use crate::task::{self, Task};
+use crate::Action;
+
+/// Invalidates all the text of the running application; so it is shaped
+/// again and every user interface is laid out from scratch.
+///
+/// This is useful when the strings of an application change all at once,
+/// like when switching its language at runtime. Loading a font with
+/// [`font::load`] does this already.
+///
+/// [`font::load`]: crate::font::load
+pub fn invalidate_all<T>() -> Task<T> {
+    task::effect(Action::InvalidateText)
+}
@@ -0,0 +1,78 @@
+//! Wait for some time to pass.
+use crate::futures::backend::default::time;
+use crate::futures::futures::future::{self, FutureExt};
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Returns a [`Future`] that completes once the given [`Duration`] has
+/// elapsed.
+///
+/// The timers of the default backend are used; so a [`Task`] waiting with
+/// it must be run by the default executor. The timer starts once the
+/// [`Future`] is first polled by it.
+///
+/// [`Task`]: crate::Task
+pub fn sleep(duration: Duration) -> impl Future<Output = ()> {
+    async move { time::sleep(duration).await }
+}
+
+/// The generation of every debounce token that is currently waiting.
+static DEBOUNCES: Mutex<Option<HashMap<Token, u64>>> = Mutex::new(None);
+
+/// The last generation handed out by [`debounce`].
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// A hashed token identifying a group of debounced timers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token(u64);
+
+impl Token {
+    /// Creates a [`Token`] by hashing the given value.
+    pub fn new(value: impl Hash) -> Self {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+
+        Self(hasher.finish())
+    }
+}
+
+/// Returns a [`Future`] that completes with `true` once the given
+/// [`Duration`] has elapsed without [`debounce`] being called again with the
+/// same [`Token`].
+///
+/// The [`Token`] is registered as soon as this function is called—not when
+/// the [`Future`] is first polled. Any pending [`Future`] with the same
+/// [`Token`] completes with `false` once its own timer elapses.
+pub fn debounce(
+    token: Token,
+    duration: Duration,
+) -> impl Future<Output = bool> {
+    let generation = GENERATION.fetch_add(1, atomic::Ordering::Relaxed) + 1;
+
+    let _ = registry(|debounces| debounces.insert(token, generation));
+
+    sleep(duration).then(move |_| {
+        future::ready(registry(|debounces| {
+            if debounces.get(&token) == Some(&generation) {
+                let _ = debounces.remove(&token);
+
+                true
+            } else {
+                false
+            }
+        }))
+    })
+}
+
+fn registry<T>(f: impl FnOnce(&mut HashMap<Token, u64>) -> T) -> T {
+    let mut debounces = DEBOUNCES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    f(debounces.get_or_insert_with(HashMap::new))
+}
@@ -1,8 +1,11 @@
 //! Implement your own event loop to drive a user interface.
+use crate::core::drag;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::touch;
 use crate::core::widget;
 use crate::core::window;
 use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
@@ -27,6 +30,8 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    input_mode: Option<keyboard::InputMode>,
+    drag: Option<drag::Payload>,
 }
 
 impl<'a, Message, Theme, Renderer> UserInterface<'a, Message, Theme, Renderer>
@@ -92,9 +97,11 @@ where
     ) -> Self {
         let root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache { mut state, drag } = cache;
         state.diff(root.as_widget());
 
+        layout::diagnostics::reset();
+
         let base = root.as_widget().layout(
             &mut state,
             renderer,
@@ -107,6 +114,8 @@ where
             state,
             overlay: None,
             bounds,
+            input_mode: None,
+            drag,
         }
     }
 
@@ -188,6 +197,8 @@ where
         let mut outdated = false;
         let mut redraw_request = None;
 
+        self.input_mode = None;
+
         let mut manual_overlay = ManuallyDrop::new(
             self.root
                 .as_widget_mut()
@@ -195,6 +206,7 @@ where
                     &mut self.state,
                     Layout::new(&self.base),
                     renderer,
+                    &Rectangle::with_size(self.bounds),
                     Vector::ZERO,
                 )
                 .map(overlay::Nested::new),
@@ -208,7 +220,8 @@ where
             let mut event_statuses = Vec::new();
 
             for event in events.iter().cloned() {
-                let mut shell = Shell::new(messages);
+                let mut shell =
+                    Shell::new(messages).with_drag(self.drag.clone());
 
                 let event_status = overlay.on_event(
                     event,
@@ -220,6 +233,7 @@ where
                 );
 
                 event_statuses.push(event_status);
+                self.drag = shell.drag().cloned();
 
                 match (redraw_request, shell.redraw_request()) {
                     (None, Some(at)) => {
@@ -231,6 +245,10 @@ where
                     _ => {}
                 }
 
+                if let Some(mode) = shell.input_mode() {
+                    self.input_mode = Some(mode);
+                }
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -247,6 +265,7 @@ where
                                 &mut self.state,
                                 Layout::new(&self.base),
                                 renderer,
+                                &Rectangle::with_size(self.bounds),
                                 Vector::ZERO,
                             )
                             .map(overlay::Nested::new),
@@ -302,11 +321,18 @@ where
             .cloned()
             .zip(overlay_statuses)
             .map(|(event, overlay_status)| {
+                let ends_drag = is_drag_end(&event);
+
                 if matches!(overlay_status, event::Status::Captured) {
+                    if ends_drag {
+                        self.drag = None;
+                    }
+
                     return overlay_status;
                 }
 
-                let mut shell = Shell::new(messages);
+                let mut shell =
+                    Shell::new(messages).with_drag(self.drag.clone());
 
                 let event_status = self.root.as_widget_mut().on_event(
                     &mut self.state,
@@ -319,6 +345,12 @@ where
                     &viewport,
                 );
 
+                self.drag = if ends_drag {
+                    None
+                } else {
+                    shell.drag().cloned()
+                };
+
                 if matches!(event_status, event::Status::Captured) {
                     self.overlay = None;
                 }
@@ -333,6 +365,10 @@ where
                     _ => {}
                 }
 
+                if let Some(mode) = shell.input_mode() {
+                    self.input_mode = Some(mode);
+                }
+
                 shell.revalidate_layout(|| {
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
@@ -453,6 +489,7 @@ where
                 &mut self.state,
                 Layout::new(&self.base),
                 renderer,
+                &viewport,
                 Vector::ZERO,
             )
             .map(overlay::Nested::new)
@@ -523,6 +560,7 @@ where
                         &mut self.state,
                         Layout::new(base),
                         renderer,
+                        &viewport,
                         Vector::ZERO,
                     )
                     .map(overlay::Nested::new)
@@ -563,10 +601,33 @@ where
     }
 
     /// Applies a [`widget::Operation`] to the [`UserInterface`].
+    ///
+    /// In debug builds, a warning is logged for every [`widget::Id`] shared
+    /// by multiple widgets.
     pub fn operate(
         &mut self,
         renderer: &Renderer,
         operation: &mut dyn widget::Operation<()>,
+    ) {
+        if cfg!(debug_assertions) {
+            let mut collisions = widget::operation::Collisions::default();
+
+            self.traverse(
+                renderer,
+                &mut widget::operation::detect_collisions(
+                    operation,
+                    &mut collisions,
+                ),
+            );
+        } else {
+            self.traverse(renderer, operation);
+        }
+    }
+
+    fn traverse(
+        &mut self,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<()>,
     ) {
         self.root.as_widget().operate(
             &mut self.state,
@@ -582,6 +643,7 @@ where
                 &mut self.state,
                 Layout::new(&self.base),
                 renderer,
+                &Rectangle::with_size(self.bounds),
                 Vector::ZERO,
             )
             .map(overlay::Nested::new)
@@ -598,16 +660,33 @@ where
         }
     }
 
+    /// Returns the [`keyboard::InputMode`] requested by the focused widget
+    /// during the latest [`update`](Self::update), if any.
+    pub fn input_mode(&self) -> Option<keyboard::InputMode> {
+        self.input_mode
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                drag: self.drag,
+            },
+            renderer,
+        )
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            drag: self.drag,
+        }
     }
 }
 
@@ -615,6 +694,7 @@ where
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    drag: Option<drag::Payload>,
 }
 
 impl Cache {
@@ -625,6 +705,7 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            drag: None,
         }
     }
 }
@@ -635,6 +716,20 @@ impl Default for Cache {
     }
 }
 
+/// Returns whether the given [`Event`] ends any ongoing drag; either by
+/// dropping it or cancelling it.
+fn is_drag_end(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            })
+    )
+}
+
 /// The resulting state after updating a [`UserInterface`].
 #[derive(Debug, Clone, Copy)]
 pub enum State {
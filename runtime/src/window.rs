@@ -5,7 +5,7 @@ pub use screenshot::Screenshot;
 
 use crate::core::time::Instant;
 use crate::core::window::{
-    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+    Event, Icon, Id, Level, Mode, Settings, UserAttention, VideoMode,
 };
 use crate::core::{Point, Size};
 use crate::futures::event;
@@ -74,6 +74,12 @@ pub enum Action {
     /// Get the current [`Mode`] of the window.
     GetMode(Id, oneshot::Sender<Mode>),
 
+    /// Toggle the window between [`Mode::Windowed`] and [`Mode::Fullscreen`].
+    ToggleFullscreen(Id),
+
+    /// Get the [`VideoMode`]s supported by the current monitor of the window.
+    GetVideoModes(Id, oneshot::Sender<Vec<VideoMode>>),
+
     /// Toggle the window to maximized or back
     ToggleMaximize(Id),
 
@@ -144,6 +150,34 @@ pub enum Action {
 
     /// Screenshot the viewport of the window.
     Screenshot(Id, oneshot::Sender<Screenshot>),
+
+    /// Show the given progress, between `0.0` and `1.0`, on the taskbar or
+    /// dock icon of the window; or hide it with `None`.
+    ///
+    /// Produces `true` if the platform supports it and the call had effect.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** Uses the progress of the taskbar button.
+    /// - **Linux:** Broadcasts a Unity launcher entry update, supported by
+    ///   Unity and docks like Plank, Latte or KDE Plasma. Only has effect
+    ///   while one of them is running.
+    /// - **macOS / iOS / Android / Web:** Unsupported.
+    SetProgress(Id, Option<f32>, oneshot::Sender<bool>),
+
+    /// Show the given badge on the dock icon of the application; or remove
+    /// it with `None`.
+    ///
+    /// Produces `true` if the platform supports it and the call had effect.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Sets the badge label of the dock tile.
+    /// - **Linux:** Broadcasts a Unity launcher entry update. Only numeric
+    ///   badges are supported, and only while a dock showing them is
+    ///   running.
+    /// - **Windows / iOS / Android / Web:** Unsupported.
+    SetBadge(Option<String>, oneshot::Sender<bool>),
 }
 
 /// Subscribes to the frames of the window of the running application.
@@ -205,11 +239,20 @@ pub fn resize_events() -> Subscription<(Id, Size)> {
     })
 }
 
-/// Subscribes to all [`Event::CloseRequested`] occurences in the running application.
-pub fn close_requests() -> Subscription<Id> {
+/// Subscribes to all [`Event::CloseRequested`] occurences in the running
+/// application; producing the [`Id`] of the window and the number of the
+/// request.
+///
+/// A close request can be accepted by closing the window with [`close`], at
+/// any time, or vetoed by ignoring it. Since the user may request the same
+/// window to close once again while the application is still deciding, the
+/// number of each request increases monotonically.
+pub fn close_requests() -> Subscription<(Id, u64)> {
     event::listen_with(|event, _status, id| {
-        if let crate::core::Event::Window(Event::CloseRequested) = event {
-            Some(id)
+        if let crate::core::Event::Window(Event::CloseRequested { request }) =
+            event
+        {
+            Some((id, request))
         } else {
             None
         }
@@ -295,6 +338,8 @@ pub fn move_to<T>(id: Id, position: Point) -> Task<T> {
 }
 
 /// Changes the [`Mode`] of the window.
+///
+/// An [`Event::ModeChanged`] reports the [`Mode`] actually applied.
 pub fn change_mode<T>(id: Id, mode: Mode) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeMode(id, mode)))
 }
@@ -306,6 +351,24 @@ pub fn get_mode(id: Id) -> Task<Mode> {
     })
 }
 
+/// Toggles the window between [`Mode::Windowed`] and [`Mode::Fullscreen`].
+///
+/// An exclusive fullscreen window goes back to [`Mode::Windowed`].
+pub fn toggle_fullscreen<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ToggleFullscreen(id)))
+}
+
+/// Gets the [`VideoMode`]s supported by the current monitor of the window.
+///
+/// Any of them can be used to take the monitor exclusively with
+/// [`Mode::Exclusive`]. The list is empty if the platform does not expose
+/// the video modes of its monitors.
+pub fn get_video_modes(id: Id) -> Task<Vec<VideoMode>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetVideoModes(id, channel))
+    })
+}
+
 /// Toggles the window to maximized or back.
 pub fn toggle_maximize<T>(id: Id) -> Task<T> {
     task::effect(crate::Action::Window(Action::ToggleMaximize(id)))
@@ -393,3 +456,29 @@ pub fn screenshot(id: Id) -> Task<Screenshot> {
         crate::Action::Window(Action::Screenshot(id, channel))
     })
 }
+
+/// Shows the given progress, between `0.0` and `1.0`, on the taskbar or dock
+/// icon of the window; or hides it with `None`.
+///
+/// The resulting [`Task`] produces `true` if the call had effect. See
+/// [`Action::SetProgress`] for the supported platforms.
+pub fn set_progress(id: Id, progress: Option<f32>) -> Task<bool> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::SetProgress(
+            id,
+            progress.map(|progress| progress.clamp(0.0, 1.0)),
+            channel,
+        ))
+    })
+}
+
+/// Shows the given badge on the dock icon of the application; or removes it
+/// with `None`.
+///
+/// The resulting [`Task`] produces `true` if the call had effect. See
+/// [`Action::SetBadge`] for the supported platforms.
+pub fn set_badge(badge: Option<String>) -> Task<bool> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::SetBadge(badge, channel))
+    })
+}
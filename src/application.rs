@@ -31,8 +31,10 @@
 //! }
 //! ```
 use crate::program::{self, Program};
+use crate::settings::{Backend, Interaction, Motion};
+use crate::time::Duration;
 use crate::window;
-use crate::{Element, Font, Result, Settings, Size, Subscription, Task};
+use crate::{Color, Element, Font, Result, Settings, Size, Subscription, Task};
 
 use std::borrow::Cow;
 
@@ -191,6 +193,93 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`Settings::hdr`] of the [`Application`].
+    pub fn hdr(self, hdr: bool) -> Self {
+        Self {
+            settings: Settings {
+                hdr,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::sticky_modifiers`] of the [`Application`].
+    pub fn sticky_modifiers(self, sticky_modifiers: bool) -> Self {
+        Self {
+            settings: Settings {
+                sticky_modifiers,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::interaction`] of the [`Application`]; overriding
+    /// the preferences of the user.
+    pub fn interaction(self, interaction: Interaction) -> Self {
+        Self {
+            settings: Settings {
+                interaction: Some(interaction),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::motion`] of the [`Application`]; overriding the
+    /// "reduce motion" preference of the user.
+    pub fn motion(self, motion: Motion) -> Self {
+        Self {
+            settings: Settings {
+                motion: Some(motion),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::memory_budget`] of the [`Application`], in bytes.
+    pub fn memory_budget(self, memory_budget: u64) -> Self {
+        Self {
+            settings: Settings {
+                memory_budget: Some(memory_budget),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::backends`] of the [`Application`].
+    pub fn backends(self, backends: &'static [Backend]) -> Self {
+        Self {
+            settings: Settings {
+                backends,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::single_instance`] identifier of the
+    /// [`Application`].
+    ///
+    /// Any later invocation of the [`Application`] will forward its command-line
+    /// arguments to the running instance and exit. The arguments can be
+    /// received with [`single_instance::listen`].
+    ///
+    /// [`single_instance::listen`]: crate::single_instance::listen
+    #[cfg(feature = "single-instance")]
+    pub fn single_instance(self, app_id: impl Into<String>) -> Self {
+        Self {
+            settings: Settings {
+                single_instance: Some(app_id.into()),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Application`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -237,6 +326,18 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::fullscreen_shortcut`] of the
+    /// [`Application`].
+    pub fn fullscreen_shortcut(self, fullscreen_shortcut: bool) -> Self {
+        Self {
+            window: window::Settings {
+                fullscreen_shortcut,
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings::size`] of the [`Application`].
     pub fn window_size(self, size: impl Into<Size>) -> Self {
         Self {
@@ -259,6 +360,17 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::backdrop`] of the [`Application`].
+    pub fn backdrop(self, backdrop: window::Backdrop) -> Self {
+        Self {
+            window: window::Settings {
+                backdrop,
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings::resizable`] of the [`Application`].
     pub fn resizable(self, resizable: bool) -> Self {
         Self {
@@ -303,6 +415,15 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::initial_background`] of the
+    /// [`Application`].
+    pub fn initial_background(self, color: impl Into<Color>) -> Self {
+        Self {
+            window: self.window.with_initial_background(color),
+            ..self
+        }
+    }
+
     /// Sets the [`Title`] of the [`Application`].
     pub(crate) fn title(
         self,
@@ -361,6 +482,62 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the splash screen of the [`Application`].
+    ///
+    /// The splash screen is shown instead of the view of the [`Application`]
+    /// until the [`Task`] given to [`run_with`](Self::run_with) finishes, or
+    /// until the `timeout` is reached.
+    pub fn splash(
+        self,
+        view: impl for<'a> self::View<
+            'a,
+            P::State,
+            P::Message,
+            P::Theme,
+            P::Renderer,
+        >,
+        timeout: Duration,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Application {
+            raw: program::with_splash(
+                self.raw,
+                move |state, _window| view.view(state).into(),
+                timeout,
+            ),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
+    /// Records the messages of the [`Application`], allowing it to
+    /// travel back in time.
+    ///
+    /// Check out the [`recorder`](crate::debug::recorder) module for
+    /// more details.
+    #[cfg(feature = "time-travel")]
+    pub fn record(
+        self,
+    ) -> Application<
+        impl Program<
+            State = crate::debug::recorder::State<P::State, P::Message>,
+            Message = crate::debug::recorder::Message<P::Message>,
+            Theme = P::Theme,
+        >,
+    >
+    where
+        P: Program<Theme = crate::Theme>,
+        P::State: Clone,
+        P::Message: Clone,
+    {
+        Application {
+            raw: crate::debug::recorder::record(self.raw),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
     /// Sets the scale factor of the [`Application`].
     pub fn scale_factor(
         self,
@@ -1,6 +1,7 @@
 //! Create and run daemons that run in the background.
 use crate::application;
 use crate::program::{self, Program};
+use crate::settings::{Backend, Interaction, Motion};
 use crate::window;
 use crate::{Element, Font, Result, Settings, Subscription, Task};
 
@@ -140,6 +141,93 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the [`Settings::hdr`] of the [`Daemon`].
+    pub fn hdr(self, hdr: bool) -> Self {
+        Self {
+            settings: Settings {
+                hdr,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::sticky_modifiers`] of the [`Daemon`].
+    pub fn sticky_modifiers(self, sticky_modifiers: bool) -> Self {
+        Self {
+            settings: Settings {
+                sticky_modifiers,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::interaction`] of the [`Daemon`]; overriding
+    /// the preferences of the user.
+    pub fn interaction(self, interaction: Interaction) -> Self {
+        Self {
+            settings: Settings {
+                interaction: Some(interaction),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::motion`] of the [`Daemon`]; overriding the
+    /// "reduce motion" preference of the user.
+    pub fn motion(self, motion: Motion) -> Self {
+        Self {
+            settings: Settings {
+                motion: Some(motion),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::memory_budget`] of the [`Daemon`], in bytes.
+    pub fn memory_budget(self, memory_budget: u64) -> Self {
+        Self {
+            settings: Settings {
+                memory_budget: Some(memory_budget),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::backends`] of the [`Daemon`].
+    pub fn backends(self, backends: &'static [Backend]) -> Self {
+        Self {
+            settings: Settings {
+                backends,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::single_instance`] identifier of the
+    /// [`Daemon`].
+    ///
+    /// Any later invocation of the [`Daemon`] will forward its command-line
+    /// arguments to the running instance and exit. The arguments can be
+    /// received with [`single_instance::listen`].
+    ///
+    /// [`single_instance::listen`]: crate::single_instance::listen
+    #[cfg(feature = "single-instance")]
+    pub fn single_instance(self, app_id: impl Into<String>) -> Self {
+        Self {
+            settings: Settings {
+                single_instance: Some(app_id.into()),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Daemon`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
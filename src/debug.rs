@@ -0,0 +1,2 @@
+//! Debug your applications.
+pub mod recorder;
@@ -0,0 +1,426 @@
+//! Record the messages of an application and travel back in time.
+//!
+//! A recorded application keeps every message it processes, together with
+//! a snapshot of its state every [`SNAPSHOT_INTERVAL`] messages. Pressing
+//! `F9` opens a timeline that can be used to rewind the application to
+//! any recorded point.
+//!
+//! Rewinding clones the nearest snapshot and replays the messages after
+//! it. The [`Task`] produced by a replayed message is discarded; so side
+//! effects are never performed twice.
+//!
+//! While scrubbing the timeline, the application is paused:
+//!
+//! - Its subscriptions are suppressed.
+//! - Any interaction with its past view is ignored.
+//! - Any message produced by a running [`Task`] is queued until the
+//!   application resumes.
+//!
+//! # Example
+//! ```no_run
+//! use iced::widget::{button, column, text, Column};
+//!
+//! pub fn main() -> iced::Result {
+//!     iced::application("A counter", update, view)
+//!         .record()
+//!         .run()
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! fn update(value: &mut u64, message: Message) {
+//!     match message {
+//!         Message::Increment => *value += 1,
+//!     }
+//! }
+//!
+//! fn view(value: &u64) -> Column<Message> {
+//!     column![
+//!         text(value),
+//!         button("+").on_press(Message::Increment),
+//!     ]
+//! }
+//! ```
+use crate::keyboard;
+use crate::program::{self, Program};
+use crate::time::Duration;
+use crate::widget::{button, column, container, row, slider, stack, text};
+use crate::window;
+use crate::{Center, Element, Fill, Subscription, Task, Theme};
+
+/// The amount of messages recorded between state snapshots.
+pub const SNAPSHOT_INTERVAL: usize = 32;
+
+/// The state of a recorded application.
+#[derive(Debug)]
+pub struct State<S, M> {
+    live: S,
+    timeline: Timeline<S, M>,
+    scrub: Option<Scrub<S>>,
+    queue: Vec<M>,
+    is_open: bool,
+}
+
+impl<S: Clone, M> State<S, M> {
+    /// Starts recording an application with the given initial state.
+    pub fn new(state: S) -> Self {
+        Self {
+            timeline: Timeline {
+                snapshots: vec![state.clone()],
+                messages: Vec::new(),
+            },
+            live: state,
+            scrub: None,
+            queue: Vec::new(),
+            is_open: false,
+        }
+    }
+
+    /// Returns the amount of messages recorded so far.
+    pub fn len(&self) -> usize {
+        self.timeline.messages.len()
+    }
+
+    /// Returns true if no messages have been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.timeline.messages.is_empty()
+    }
+
+    /// Returns true if the timeline is being scrubbed; that is, if the
+    /// application is paused at some point in the past.
+    pub fn is_scrubbing(&self) -> bool {
+        self.scrub.is_some()
+    }
+
+    fn present(&self) -> &S {
+        self.scrub.as_ref().map_or(&self.live, |scrub| &scrub.state)
+    }
+}
+
+impl<S: Clone + Default, M> Default for State<S, M> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+#[derive(Debug)]
+struct Timeline<S, M> {
+    /// The snapshot at index `i` is the state after processing
+    /// `i * SNAPSHOT_INTERVAL` messages.
+    snapshots: Vec<S>,
+    messages: Vec<M>,
+}
+
+#[derive(Debug)]
+struct Scrub<S> {
+    position: usize,
+    state: S,
+}
+
+/// A message of a recorded application.
+#[derive(Debug, Clone)]
+pub enum Message<M> {
+    /// A message produced by the view of the application.
+    View(M),
+    /// A message produced by a [`Task`] or a [`Subscription`] of the
+    /// application.
+    Live(M),
+    /// The timeline was toggled.
+    Toggle,
+    /// The timeline was scrubbed to the given position.
+    Scrub(u32),
+    /// The application was resumed from its latest state.
+    Resume,
+    /// The application was resumed from the current position of the
+    /// timeline, dropping any message recorded after it.
+    Branch,
+}
+
+/// Turns the initialization logic of an application into the one of its
+/// recorded counterpart.
+///
+/// This is meant to be used with `run_with`.
+pub fn init<S, M>(
+    initialize: impl FnOnce() -> (S, Task<M>),
+) -> impl FnOnce() -> (State<S, M>, Task<Message<M>>)
+where
+    S: Clone,
+    M: Send + 'static,
+{
+    move || {
+        let (state, task) = initialize();
+
+        (State::new(state), task.map(Message::Live))
+    }
+}
+
+pub(crate) fn record<P>(
+    program: P,
+) -> impl Program<
+    State = State<P::State, P::Message>,
+    Message = Message<P::Message>,
+    Theme = P::Theme,
+>
+where
+    P: Program<Theme = Theme>,
+    P::State: Clone,
+    P::Message: Clone,
+{
+    struct Recorder<P> {
+        program: P,
+    }
+
+    impl<P> Recorder<P>
+    where
+        P: Program,
+        P::State: Clone,
+        P::Message: Clone,
+    {
+        fn process(
+            &self,
+            state: &mut State<P::State, P::Message>,
+            message: P::Message,
+        ) -> Task<Message<P::Message>> {
+            let task = self.program.update(&mut state.live, message.clone());
+            let timeline = &mut state.timeline;
+
+            timeline.messages.push(message);
+
+            if timeline.messages.len() % SNAPSHOT_INTERVAL == 0 {
+                timeline.snapshots.push(state.live.clone());
+            }
+
+            task.map(Message::Live)
+        }
+
+        fn replay(
+            &self,
+            timeline: &Timeline<P::State, P::Message>,
+            position: usize,
+        ) -> P::State {
+            let snapshot = position / SNAPSHOT_INTERVAL;
+            let mut state = timeline.snapshots[snapshot].clone();
+
+            for message in
+                &timeline.messages[snapshot * SNAPSHOT_INTERVAL..position]
+            {
+                let _ = self.program.update(&mut state, message.clone());
+            }
+
+            state
+        }
+
+        fn resume(
+            &self,
+            state: &mut State<P::State, P::Message>,
+        ) -> Task<Message<P::Message>> {
+            state.scrub = None;
+
+            let queue = std::mem::take(&mut state.queue);
+
+            Task::batch(
+                queue
+                    .into_iter()
+                    .map(|message| self.process(state, message))
+                    .collect::<Vec<_>>(),
+            )
+        }
+    }
+
+    impl<P> Program for Recorder<P>
+    where
+        P: Program<Theme = Theme>,
+        P::State: Clone,
+        P::Message: Clone,
+    {
+        type State = State<P::State, P::Message>;
+        type Message = Message<P::Message>;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            match message {
+                Message::View(message) => {
+                    if state.is_scrubbing() {
+                        return Task::none();
+                    }
+
+                    self.process(state, message)
+                }
+                Message::Live(message) => {
+                    if state.is_scrubbing() {
+                        state.queue.push(message);
+
+                        return Task::none();
+                    }
+
+                    self.process(state, message)
+                }
+                Message::Toggle => {
+                    state.is_open = !state.is_open;
+
+                    if state.is_open {
+                        Task::none()
+                    } else {
+                        self.resume(state)
+                    }
+                }
+                Message::Scrub(position) => {
+                    let position = (position as usize).min(state.len());
+
+                    if position == state.len() {
+                        return self.resume(state);
+                    }
+
+                    state.scrub = Some(Scrub {
+                        position,
+                        state: self.replay(&state.timeline, position),
+                    });
+
+                    Task::none()
+                }
+                Message::Resume => self.resume(state),
+                Message::Branch => {
+                    let Some(scrub) = state.scrub.take() else {
+                        return Task::none();
+                    };
+
+                    let timeline = &mut state.timeline;
+
+                    timeline.messages.truncate(scrub.position);
+                    timeline
+                        .snapshots
+                        .truncate(scrub.position / SNAPSHOT_INTERVAL + 1);
+
+                    state.live = scrub.state;
+
+                    self.resume(state)
+                }
+            }
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            let content = self
+                .program
+                .view(state.present(), window)
+                .map(Message::View);
+
+            if !state.is_open {
+                return content;
+            }
+
+            let len = state.len();
+            let position =
+                state.scrub.as_ref().map_or(len, |scrub| scrub.position);
+
+            let controls = row![
+                text!("{position} / {len}").width(80),
+                slider(0..=len.max(1) as u32, position as u32, Message::Scrub),
+                button("Branch").on_press_maybe(
+                    state.is_scrubbing().then_some(Message::Branch)
+                ),
+                button("Resume").on_press_maybe(
+                    state.is_scrubbing().then_some(Message::Resume)
+                ),
+            ]
+            .spacing(10)
+            .align_y(Center);
+
+            let timeline = column![
+                text(if state.is_scrubbing() {
+                    "Paused"
+                } else {
+                    "Recording"
+                })
+                .size(12),
+                controls,
+            ]
+            .spacing(5);
+
+            stack![
+                content,
+                container(
+                    container(timeline)
+                        .padding(10)
+                        .width(Fill)
+                        .style(container::rounded_box)
+                )
+                .padding(10)
+                .align_bottom(Fill)
+            ]
+            .into()
+        }
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state.present(), window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            let toggle = keyboard::on_key_press(|key, _modifiers| {
+                matches!(key, keyboard::Key::Named(keyboard::key::Named::F9))
+                    .then_some(Message::Toggle)
+            });
+
+            if state.is_scrubbing() {
+                return toggle;
+            }
+
+            Subscription::batch([
+                toggle,
+                self.program.subscription(&state.live).map(Message::Live),
+            ])
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state.present(), window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> program::Appearance {
+            self.program.style(state.present(), theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state.present(), window)
+        }
+
+        fn splash<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>>
+        {
+            self.program
+                .splash(state.present(), window)
+                .map(|splash| splash.map(Message::View))
+        }
+
+        fn splash_timeout(&self) -> Duration {
+            self.program.splash_timeout()
+        }
+    }
+
+    Recorder { program }
+}
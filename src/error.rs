@@ -14,7 +14,7 @@ pub enum Error {
     WindowCreationFailed(Box<dyn std::error::Error + Send + Sync>),
 
     /// The application graphics context could not be created.
-    #[error("the application graphics context could not be created")]
+    #[error("the application graphics context could not be created: {0}")]
     GraphicsCreationFailed(graphics::Error),
 }
 
@@ -466,6 +466,7 @@ mod program;
 
 pub mod application;
 pub mod daemon;
+pub mod navigation;
 pub mod settings;
 pub mod time;
 pub mod window;
@@ -473,10 +474,20 @@ pub mod window;
 #[cfg(feature = "advanced")]
 pub mod advanced;
 
+#[cfg(feature = "time-travel")]
+pub mod debug;
+
+#[cfg(feature = "session")]
+pub mod session;
+
+#[cfg(feature = "updater")]
+pub mod updater;
+
 pub use crate::core::alignment;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
+pub use crate::core::id;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
@@ -531,15 +542,23 @@ pub mod event {
     //! Handle events of a user interface.
     pub use crate::core::event::{Event, Status};
     pub use iced_futures::event::{
-        listen, listen_raw, listen_url, listen_with,
+        listen, listen_raw, listen_unhandled, listen_url, listen_with,
+        Unhandled,
     };
 }
 
+pub mod input_method {
+    //! Listen and react to the events of input methods.
+    pub use crate::core::input_method::Event;
+}
+
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::key;
     pub use crate::core::keyboard::{Event, Key, Location, Modifiers};
-    pub use iced_futures::keyboard::{on_key_press, on_key_release};
+    pub use iced_futures::keyboard::{
+        modifiers, on_key_press, on_key_release, on_modifiers_latched,
+    };
 }
 
 pub mod mouse {
@@ -549,13 +568,21 @@ pub mod mouse {
     };
 }
 
-#[cfg(feature = "system")]
 pub mod system {
-    //! Retrieve system information.
+    //! Retrieve system information and preferences.
+    #[cfg(feature = "power")]
+    pub use crate::runtime::system::power;
+    #[cfg(feature = "system")]
     pub use crate::runtime::system::Information;
     pub use crate::shell::system::*;
 }
 
+#[cfg(feature = "single-instance")]
+pub mod single_instance {
+    //! Enforce a single instance of your application.
+    pub use crate::shell::single_instance::*;
+}
+
 pub mod overlay {
     //! Display interactive elements on top of other widgets.
 
@@ -571,6 +598,8 @@ pub mod overlay {
         Renderer = crate::Renderer,
     > = crate::core::overlay::Element<'a, Message, Theme, Renderer>;
 
+    pub use crate::core::overlay::ScrollPolicy;
+
     pub use iced_widget::overlay::*;
 }
 
@@ -0,0 +1,225 @@
+//! Navigate between the screens of an application.
+//!
+//! A [`Stack`] keeps track of the screens a user has visited, allowing an
+//! application to [`push`] new screens and [`pop`] back to the previous ones.
+//!
+//! It is a simple state type; so it composes with the usual update and view
+//! logic of an application.
+//!
+//! # Example
+//! ```no_run
+//! use iced::navigation;
+//! use iced::widget::{button, column, text};
+//! use iced::{Element, Subscription, Task};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Screen {
+//!     Inbox,
+//!     Conversation(String),
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Navigate(navigation::Action<Screen, Message>),
+//!     Back,
+//!     Replied(String),
+//! }
+//!
+//! struct Mail {
+//!     screens: navigation::Stack<Screen>,
+//!     last_reply: Option<String>,
+//! }
+//!
+//! impl Mail {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::Navigate(action) => self.screens.perform(action),
+//!             Message::Back => self.screens.perform(navigation::Action::Pop),
+//!             Message::Replied(reply) => {
+//!                 self.last_reply = Some(reply);
+//!
+//!                 Task::none()
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         match self.screens.current() {
+//!             Screen::Inbox => button("Open conversation")
+//!                 .on_press(Message::Navigate(navigation::Action::Push(
+//!                     Screen::Conversation(String::from("Hello!")),
+//!                 )))
+//!                 .into(),
+//!             Screen::Conversation(content) => column![
+//!                 text(content),
+//!                 button("Reply and go back").on_press(Message::Navigate(
+//!                     navigation::Action::PopWith(Box::new(Message::Replied(
+//!                         String::from("Hi!")
+//!                     )))
+//!                 )),
+//!             ]
+//!             .into(),
+//!         }
+//!     }
+//!
+//!     fn subscription(&self) -> Subscription<Message> {
+//!         navigation::back_requests().map(|()| Message::Back)
+//!     }
+//! }
+//! ```
+//!
+//! [`push`]: Stack::push
+//! [`pop`]: Stack::pop
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::window;
+use crate::{Subscription, Task};
+use iced_futures::MaybeSend;
+
+/// A stack of screens.
+///
+/// A [`Stack`] always contains at least one screen: its root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack<Screen> {
+    root: Screen,
+    screens: Vec<Screen>,
+}
+
+impl<Screen> Stack<Screen> {
+    /// Creates a new [`Stack`] with the given root screen.
+    pub fn new(root: Screen) -> Self {
+        Self {
+            root,
+            screens: Vec::new(),
+        }
+    }
+
+    /// Returns the current screen of the [`Stack`]; that is, the
+    /// screen on top.
+    pub fn current(&self) -> &Screen {
+        self.screens.last().unwrap_or(&self.root)
+    }
+
+    /// Returns a mutable reference to the current screen of the [`Stack`].
+    pub fn current_mut(&mut self) -> &mut Screen {
+        self.screens.last_mut().unwrap_or(&mut self.root)
+    }
+
+    /// Returns the root screen of the [`Stack`].
+    pub fn root(&self) -> &Screen {
+        &self.root
+    }
+
+    /// Returns the amount of screens in the [`Stack`], including its root.
+    pub fn depth(&self) -> usize {
+        self.screens.len() + 1
+    }
+
+    /// Returns true if the current screen can be popped; that is, if it
+    /// is not the root screen.
+    pub fn can_pop(&self) -> bool {
+        !self.screens.is_empty()
+    }
+
+    /// Pushes a new screen on top of the [`Stack`], making it the current one.
+    pub fn push(&mut self, screen: Screen) {
+        self.screens.push(screen);
+    }
+
+    /// Pops the current screen of the [`Stack`], returning it.
+    ///
+    /// The root screen can never be popped; in which case `None` is returned.
+    pub fn pop(&mut self) -> Option<Screen> {
+        self.screens.pop()
+    }
+
+    /// Pops every screen of the [`Stack`] until only the root screen remains.
+    pub fn pop_to_root(&mut self) {
+        self.screens.clear();
+    }
+
+    /// Replaces the current screen of the [`Stack`] with a new one, returning
+    /// the old screen.
+    pub fn replace(&mut self, screen: Screen) -> Screen {
+        std::mem::replace(self.current_mut(), screen)
+    }
+
+    /// Returns an iterator over the screens of the [`Stack`], from the root
+    /// to the current screen.
+    pub fn iter(&self) -> impl Iterator<Item = &Screen> {
+        std::iter::once(&self.root).chain(self.screens.iter())
+    }
+
+    /// Performs a navigation [`Action`] on the [`Stack`].
+    ///
+    /// The resulting [`Task`] will produce the message carried by an
+    /// [`Action::PopWith`], if the pop was successful.
+    pub fn perform<Message>(
+        &mut self,
+        action: Action<Screen, Message>,
+    ) -> Task<Message>
+    where
+        Message: MaybeSend + 'static,
+    {
+        match action {
+            Action::Push(screen) => {
+                self.push(screen);
+            }
+            Action::Pop => {
+                let _ = self.pop();
+            }
+            Action::PopWith(message) => {
+                if self.pop().is_some() {
+                    return Task::done(*message);
+                }
+            }
+            Action::Replace(screen) => {
+                let _ = self.replace(screen);
+            }
+        }
+
+        Task::none()
+    }
+}
+
+/// A navigation action that can be performed on a [`Stack`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action<Screen, Message> {
+    /// Push a new screen.
+    Push(Screen),
+    /// Pop the current screen.
+    Pop,
+    /// Pop the current screen and produce the given message.
+    ///
+    /// This is useful to pass results back from a popped screen.
+    PopWith(Box<Message>),
+    /// Replace the current screen.
+    Replace(Screen),
+}
+
+/// Returns a [`Subscription`] that produces a message every time the user
+/// requests to go back.
+///
+/// By default, a back request is issued when the `Escape` key is pressed and
+/// no widget captured it, or when the back button of a mouse is pressed.
+pub fn back_requests() -> Subscription<()> {
+    fn is_back_request(
+        event: Event,
+        status: event::Status,
+        _window: window::Id,
+    ) -> Option<()> {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if status == event::Status::Ignored => Some(()),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Back)) => {
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    crate::event::listen_with(is_back_request)
+}
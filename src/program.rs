@@ -1,6 +1,7 @@
 use crate::core::text;
 use crate::graphics::compositor;
 use crate::shell;
+use crate::time::Duration;
 use crate::window;
 use crate::{Element, Executor, Result, Settings, Subscription, Task};
 
@@ -62,6 +63,18 @@ pub trait Program: Sized {
         1.0
     }
 
+    fn splash<'a>(
+        &self,
+        _state: &'a Self::State,
+        _window: window::Id,
+    ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>> {
+        None
+    }
+
+    fn splash_timeout(&self) -> Duration {
+        Duration::from_secs(5)
+    }
+
     /// Runs the [`Program`].
     ///
     /// The state of the [`Program`] must implement [`Default`].
@@ -160,6 +173,34 @@ pub trait Program: Sized {
             fn scale_factor(&self, window: window::Id) -> f64 {
                 self.program.scale_factor(&self.state, window)
             }
+
+            fn splash(
+                &self,
+                window: window::Id,
+            ) -> Option<
+                crate::Element<'_, Self::Message, Self::Theme, Self::Renderer>,
+            > {
+                self.program.splash(&self.state, window)
+            }
+
+            fn splash_timeout(&self) -> Duration {
+                self.program.splash_timeout()
+            }
+        }
+
+        #[cfg(feature = "single-instance")]
+        if let Some(app_id) = &settings.single_instance {
+            use crate::single_instance::{self, Instance};
+
+            match single_instance::acquire(app_id) {
+                Ok(Instance::Primary) => {}
+                Ok(Instance::Secondary) => return Ok(()),
+                Err(error) => {
+                    log::warn!(
+                        "Single instance could not be enforced: {error}"
+                    );
+                }
+            }
         }
 
         #[allow(clippy::needless_update)]
@@ -171,6 +212,9 @@ pub trait Program: Sized {
             } else {
                 None
             },
+            hdr: settings.hdr,
+            backends: settings.backends,
+            memory_budget: settings.memory_budget,
             ..crate::graphics::Settings::default()
         };
 
@@ -184,6 +228,14 @@ pub trait Program: Sized {
                 default_font: settings.default_font,
                 default_text_size: settings.default_text_size,
                 antialiasing: settings.antialiasing,
+                hdr: settings.hdr,
+                sticky_modifiers: settings.sticky_modifiers,
+                interaction: settings.interaction,
+                motion: settings.motion,
+                backends: settings.backends,
+                memory_budget: settings.memory_budget,
+                #[cfg(feature = "single-instance")]
+                single_instance: None,
             }
             .into(),
             renderer_settings,
@@ -259,6 +311,19 @@ pub fn with_title<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn splash<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>>
+        {
+            self.program.splash(state, window)
+        }
+
+        fn splash_timeout(&self) -> Duration {
+            self.program.splash_timeout()
+        }
     }
 
     WithTitle { program, title }
@@ -329,6 +394,19 @@ pub fn with_subscription<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn splash<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>>
+        {
+            self.program.splash(state, window)
+        }
+
+        fn splash_timeout(&self) -> Duration {
+            self.program.splash_timeout()
+        }
     }
 
     WithSubscription {
@@ -402,6 +480,19 @@ pub fn with_theme<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn splash<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>>
+        {
+            self.program.splash(state, window)
+        }
+
+        fn splash_timeout(&self) -> Duration {
+            self.program.splash_timeout()
+        }
     }
 
     WithTheme { program, theme: f }
@@ -472,6 +563,19 @@ pub fn with_style<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn splash<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>>
+        {
+            self.program.splash(state, window)
+        }
+
+        fn splash_timeout(&self) -> Duration {
+            self.program.splash_timeout()
+        }
     }
 
     WithStyle { program, style: f }
@@ -542,6 +646,19 @@ pub fn with_scale_factor<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             (self.scale_factor)(state, window)
         }
+
+        fn splash<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>>
+        {
+            self.program.splash(state, window)
+        }
+
+        fn splash_timeout(&self) -> Duration {
+            self.program.splash_timeout()
+        }
     }
 
     WithScaleFactor {
@@ -550,6 +667,102 @@ pub fn with_scale_factor<P: Program>(
     }
 }
 
+pub fn with_splash<P: Program>(
+    program: P,
+    splash: impl for<'a> Fn(
+        &'a P::State,
+        window::Id,
+    ) -> Element<'a, P::Message, P::Theme, P::Renderer>,
+    timeout: Duration,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithSplash<P, F> {
+        program: P,
+        splash: F,
+        timeout: Duration,
+    }
+
+    impl<P: Program, F> Program for WithSplash<P, F>
+    where
+        F: for<'a> Fn(
+            &'a P::State,
+            window::Id,
+        )
+            -> Element<'a, P::Message, P::Theme, P::Renderer>,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn splash<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Option<Element<'a, Self::Message, Self::Theme, Self::Renderer>>
+        {
+            Some((self.splash)(state, window))
+        }
+
+        fn splash_timeout(&self) -> Duration {
+            self.timeout
+        }
+    }
+
+    WithSplash {
+        program,
+        splash,
+        timeout,
+    }
+}
+
 /// The renderer of some [`Program`].
 pub trait Renderer: text::Renderer + compositor::Default {}
 
@@ -0,0 +1,693 @@
+//! Restore the state of an application across runs.
+//!
+//! A [`Session`] collects the state a user expects to find again when
+//! reopening an application: the offsets of its scrollables, the layouts of
+//! its pane grids, the geometry of its windows, and its selected tabs. It can
+//! be saved to and loaded from any path as JSON.
+//!
+//! Scroll offsets are captured with [`Session::capture`] and restored with
+//! [`Session::restore`]. Only scrollables with a custom [`Id`] are restorable,
+//! since unique ones change every run; see [`Id::name`]. The rest of the
+//! state is owned by the application, which stores it in the [`Session`]
+//! explicitly.
+//!
+//! # Example
+//! ```no_run
+//! use iced::session::{self, Session};
+//! use iced::widget::{pane_grid, scrollable, text, PaneGrid};
+//! use iced::window;
+//! use iced::{Element, Task};
+//!
+//! const PATH: &str = "session.json";
+//!
+//! struct Editor {
+//!     files: pane_grid::State<String>,
+//!     session: Session,
+//!     restore: session::Restore,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     CloseRequested(window::Id),
+//!     Captured(Session),
+//! }
+//!
+//! impl Editor {
+//!     fn new() -> (Self, Task<Message>) {
+//!         let session = Session::load(PATH).unwrap_or_default();
+//!         let files = session
+//!             .pane_grid("files", |file| Some(file.to_owned()))
+//!             .unwrap_or_else(|| {
+//!                 pane_grid::State::new(String::from("main.rs")).0
+//!             });
+//!
+//!         let restore = session.restore();
+//!         let task = restore.apply();
+//!
+//!         (
+//!             Self {
+//!                 files,
+//!                 session,
+//!                 restore,
+//!             },
+//!             task,
+//!         )
+//!     }
+//!
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::CloseRequested(window) => {
+//!                 self.session
+//!                     .set_pane_grid("files", &self.files, Clone::clone);
+//!
+//!                 self.session
+//!                     .clone()
+//!                     .capture([("main", window)])
+//!                     .map(Message::Captured)
+//!             }
+//!             Message::Captured(session) => {
+//!                 let _ = session.save(PATH);
+//!
+//!                 iced::exit()
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         PaneGrid::new(&self.files, |_pane, file, _is_maximized| {
+//!             scrollable(text(file.as_str()))
+//!                 .id(scrollable::Id::new(file.clone()))
+//!                 .into()
+//!         })
+//!         .into()
+//!     }
+//! }
+//! ```
+use crate::core::widget::operation::scrollable::AbsoluteOffset;
+use crate::core::widget::operation::{self, Operation, Scrollable};
+use crate::core::widget::Id;
+use crate::core::{Point, Rectangle, Size, Vector};
+use crate::runtime::task;
+use crate::widget::pane_grid;
+use crate::window;
+use crate::Task;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// The restorable state of an application.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    scrollables: BTreeMap<String, Offset>,
+    pane_grids: BTreeMap<String, PaneGrid>,
+    windows: BTreeMap<String, Window>,
+    tabs: BTreeMap<String, String>,
+}
+
+impl Session {
+    /// Creates a new empty [`Session`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the [`Session`] saved at the given path.
+    ///
+    /// The first run of an application has no [`Session`] to load yet; so
+    /// this is commonly followed by `unwrap_or_default`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path)?;
+
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Saves the [`Session`] at the given path, creating any missing
+    /// directories.
+    ///
+    /// The [`Session`] is written to a temporary file first; so a previous
+    /// [`Session`] is never left half overwritten.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        let temporary = path.with_extension("tmp");
+
+        fs::write(&temporary, json)?;
+        fs::rename(&temporary, path)?;
+
+        Ok(())
+    }
+
+    /// Captures the scroll offsets of every named scrollable in the
+    /// application and the [`Geometry`] of the given windows; producing the
+    /// resulting [`Session`].
+    ///
+    /// Each window is stored with the given name. The offsets of scrollables
+    /// that are not present are kept as they were.
+    pub fn capture(
+        self,
+        windows: impl IntoIterator<Item = (impl Into<String>, window::Id)>,
+    ) -> Task<Session> {
+        let mut session = Some(self);
+        let mut windows = Some(
+            windows
+                .into_iter()
+                .map(|(name, id)| (name.into(), id))
+                .collect::<Vec<(String, window::Id)>>(),
+        );
+
+        task::widget(Capture::default()).then(move |offsets| {
+            let mut session = session.take().unwrap_or_default();
+            session.scrollables.extend(offsets);
+
+            let geometries = Task::batch(
+                windows.take().unwrap_or_default().into_iter().map(
+                    |(name, id)| {
+                        Geometry::capture(id)
+                            .map(move |geometry| (name.clone(), geometry))
+                    },
+                ),
+            );
+
+            let mut session = Some(session);
+
+            geometries.collect().map(move |geometries| {
+                let mut session = session.take().unwrap_or_default();
+
+                for (name, geometry) in geometries {
+                    session.set_window(name, geometry);
+                }
+
+                session
+            })
+        })
+    }
+
+    /// Returns a [`Restore`] that reapplies the scroll offsets of the
+    /// [`Session`].
+    pub fn restore(&self) -> Restore {
+        Restore {
+            pending: Arc::new(Mutex::new(self.scrollables.clone())),
+        }
+    }
+
+    /// Returns the [`Geometry`] of the window with the given name.
+    pub fn window(&self, name: &str) -> Option<Geometry> {
+        self.windows.get(name).copied().map(Geometry::from)
+    }
+
+    /// Stores the [`Geometry`] of the window with the given name.
+    pub fn set_window(&mut self, name: impl Into<String>, geometry: Geometry) {
+        let _ = self.windows.insert(name.into(), Window::from(geometry));
+    }
+
+    /// Rebuilds the [`pane_grid::State`] stored with the given name.
+    ///
+    /// The `pane` closure produces the state of a pane from its name. Panes
+    /// it cannot produce are left out; their siblings taking their space.
+    /// `None` is returned if no pane is left.
+    pub fn pane_grid<T>(
+        &self,
+        name: &str,
+        mut pane: impl FnMut(&str) -> Option<T>,
+    ) -> Option<pane_grid::State<T>> {
+        let pane_grid = self.pane_grids.get(name)?;
+
+        let mut names = Vec::new();
+        let configuration =
+            pane_grid.layout.configuration(&mut pane, &mut names)?;
+
+        let mut state = pane_grid::State::with_configuration(configuration);
+
+        let maximized = pane_grid.maximized.as_ref().and_then(|maximized| {
+            let mut panes = Vec::new();
+            collect_panes(state.layout(), &mut panes);
+
+            names
+                .iter()
+                .zip(panes)
+                .find_map(|(name, pane)| (name == maximized).then_some(pane))
+        });
+
+        if let Some(pane) = maximized {
+            state.maximize(pane);
+        }
+
+        Some(state)
+    }
+
+    /// Stores the layout of the given [`pane_grid::State`] with the given
+    /// name.
+    ///
+    /// The `pane` closure produces the name of a pane from its state. It is
+    /// used to find the pane again when restoring; so it should be unique
+    /// and stable across runs.
+    pub fn set_pane_grid<T>(
+        &mut self,
+        name: impl Into<String>,
+        state: &pane_grid::State<T>,
+        pane: impl Fn(&T) -> String,
+    ) {
+        let name = name.into();
+
+        let Some(layout) = Layout::from_node(state.layout(), state, &pane)
+        else {
+            let _ = self.pane_grids.remove(&name);
+            return;
+        };
+
+        let maximized = state
+            .maximized()
+            .and_then(|maximized| state.get(maximized))
+            .map(&pane);
+
+        let _ = self.pane_grids.insert(name, PaneGrid { layout, maximized });
+    }
+
+    /// Returns the selected tab stored with the given name.
+    pub fn tab(&self, name: &str) -> Option<&str> {
+        self.tabs.get(name).map(String::as_str)
+    }
+
+    /// Stores the selected tab with the given name.
+    pub fn set_tab(&mut self, name: impl Into<String>, tab: impl Into<String>) {
+        let _ = self.tabs.insert(name.into(), tab.into());
+    }
+}
+
+/// The scroll offsets of a [`Session`] waiting to be restored.
+///
+/// Scrollables may not be present right away; like when they belong to
+/// content that is loaded later or to a tab that is not selected. A
+/// [`Restore`] keeps track of the offsets that could not be applied yet, so
+/// [`Restore::apply`] can be called again whenever new widgets may have
+/// appeared; like after every update.
+///
+/// Offsets of scrollables that never appear are simply never applied.
+#[derive(Debug, Clone, Default)]
+pub struct Restore {
+    pending: Arc<Mutex<BTreeMap<String, Offset>>>,
+}
+
+impl Restore {
+    /// Applies the pending scroll offsets to the scrollables that are
+    /// present.
+    ///
+    /// Once every offset has been applied, this produces [`Task::none`].
+    pub fn apply<T>(&self) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        if self.is_done() {
+            return Task::none();
+        }
+
+        task::widget(Apply {
+            pending: self.pending.clone(),
+        })
+    }
+
+    /// Returns true if every scroll offset has been applied.
+    pub fn is_done(&self) -> bool {
+        self.pending
+            .lock()
+            .map_or(true, |pending| pending.is_empty())
+    }
+}
+
+/// The geometry of a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometry {
+    /// The logical size of the window.
+    ///
+    /// The size of a maximized window is its maximized size.
+    pub size: Size,
+    /// The logical position of the window, if the platform reports it.
+    pub position: Option<Point>,
+    /// Whether the window is maximized.
+    pub maximized: bool,
+}
+
+impl Geometry {
+    /// Captures the [`Geometry`] of the window with the given [`window::Id`].
+    pub fn capture(window: window::Id) -> Task<Geometry> {
+        window::get_size(window).then(move |size| {
+            window::get_position(window).then(move |position| {
+                window::get_maximized(window).map(move |maximized| Geometry {
+                    size,
+                    position,
+                    maximized,
+                })
+            })
+        })
+    }
+
+    /// Restores the [`Geometry`] of the window with the given
+    /// [`window::Id`].
+    pub fn restore<T>(self, window: window::Id) -> Task<T> {
+        let mut task = window::resize(window, self.size);
+
+        if let Some(position) = self.position {
+            task = task.chain(window::move_to(window, position));
+        }
+
+        if self.maximized {
+            task = task.chain(window::maximize(window, true));
+        }
+
+        task
+    }
+
+    /// Applies the size and position of the [`Geometry`] to the given
+    /// [`window::Settings`]; so a new window opens right where it was.
+    pub fn configure(self, settings: window::Settings) -> window::Settings {
+        window::Settings {
+            size: self.size,
+            position: self
+                .position
+                .map(window::Position::Specific)
+                .unwrap_or(settings.position),
+            ..settings
+        }
+    }
+}
+
+/// An error that occurred while loading or saving a [`Session`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The [`Session`] could not be read or written.
+    #[error("the session could not be read or written: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The [`Session`] is not valid JSON or has an unexpected format.
+    #[error("the session has an invalid format: {0}")]
+    Format(Arc<serde_json::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Format(Arc::new(error))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Offset {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Window {
+    width: f32,
+    height: f32,
+    position: Option<(f32, f32)>,
+    maximized: bool,
+}
+
+impl From<Geometry> for Window {
+    fn from(geometry: Geometry) -> Self {
+        Self {
+            width: geometry.size.width,
+            height: geometry.size.height,
+            position: geometry
+                .position
+                .map(|position| (position.x, position.y)),
+            maximized: geometry.maximized,
+        }
+    }
+}
+
+impl From<Window> for Geometry {
+    fn from(window: Window) -> Self {
+        Self {
+            size: Size::new(window.width, window.height),
+            position: window.position.map(|(x, y)| Point::new(x, y)),
+            maximized: window.maximized,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PaneGrid {
+    layout: Layout,
+    maximized: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Layout {
+    Split {
+        axis: Axis,
+        ratio: f32,
+        a: Box<Layout>,
+        b: Box<Layout>,
+    },
+    Pane(String),
+}
+
+impl Layout {
+    fn from_node<T>(
+        node: &pane_grid::Node,
+        state: &pane_grid::State<T>,
+        pane: &impl Fn(&T) -> String,
+    ) -> Option<Self> {
+        match node {
+            pane_grid::Node::Split {
+                axis, ratio, a, b, ..
+            } => {
+                let a = Self::from_node(a, state, pane);
+                let b = Self::from_node(b, state, pane);
+
+                match (a, b) {
+                    (Some(a), Some(b)) => Some(Layout::Split {
+                        axis: Axis::from(*axis),
+                        ratio: *ratio,
+                        a: Box::new(a),
+                        b: Box::new(b),
+                    }),
+                    (a, b) => a.or(b),
+                }
+            }
+            pane_grid::Node::Pane(id) => {
+                state.get(*id).map(|state| Layout::Pane(pane(state)))
+            }
+        }
+    }
+
+    /// Produces the [`pane_grid::Configuration`] of the [`Layout`]; pushing
+    /// the names of the panes that were produced in order.
+    fn configuration<T>(
+        &self,
+        pane: &mut impl FnMut(&str) -> Option<T>,
+        names: &mut Vec<String>,
+    ) -> Option<pane_grid::Configuration<T>> {
+        match self {
+            Layout::Split { axis, ratio, a, b } => {
+                let a = a.configuration(pane, names);
+                let b = b.configuration(pane, names);
+
+                match (a, b) {
+                    (Some(a), Some(b)) => {
+                        Some(pane_grid::Configuration::Split {
+                            axis: pane_grid::Axis::from(*axis),
+                            ratio: *ratio,
+                            a: Box::new(a),
+                            b: Box::new(b),
+                        })
+                    }
+                    (a, b) => a.or(b),
+                }
+            }
+            Layout::Pane(name) => {
+                let state = pane(name)?;
+                names.push(name.clone());
+
+                Some(pane_grid::Configuration::Pane(state))
+            }
+        }
+    }
+}
+
+fn collect_panes(node: &pane_grid::Node, panes: &mut Vec<pane_grid::Pane>) {
+    match node {
+        pane_grid::Node::Split { a, b, .. } => {
+            collect_panes(a, panes);
+            collect_panes(b, panes);
+        }
+        pane_grid::Node::Pane(pane) => panes.push(*pane),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl From<pane_grid::Axis> for Axis {
+    fn from(axis: pane_grid::Axis) -> Self {
+        match axis {
+            pane_grid::Axis::Horizontal => Axis::Horizontal,
+            pane_grid::Axis::Vertical => Axis::Vertical,
+        }
+    }
+}
+
+impl From<Axis> for pane_grid::Axis {
+    fn from(axis: Axis) -> Self {
+        match axis {
+            Axis::Horizontal => pane_grid::Axis::Horizontal,
+            Axis::Vertical => pane_grid::Axis::Vertical,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Capture {
+    offsets: BTreeMap<String, Offset>,
+}
+
+impl Operation<BTreeMap<String, Offset>> for Capture {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(
+            &mut dyn Operation<BTreeMap<String, Offset>>,
+        ),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if let Some(name) = id.and_then(Id::name) {
+            let _ = self.offsets.insert(
+                name,
+                Offset {
+                    x: translation.x,
+                    y: translation.y,
+                },
+            );
+        }
+    }
+
+    fn finish(&self) -> operation::Outcome<BTreeMap<String, Offset>> {
+        operation::Outcome::Some(self.offsets.clone())
+    }
+}
+
+struct Apply {
+    pending: Arc<Mutex<BTreeMap<String, Offset>>>,
+}
+
+impl<T> Operation<T> for Apply {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        let Some(name) = id.and_then(Id::name) else {
+            return;
+        };
+
+        let Ok(mut pending) = self.pending.lock() else {
+            return;
+        };
+
+        if let Some(Offset { x, y }) = pending.remove(&name) {
+            state.scroll_to(AbsoluteOffset { x, y });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panes(layout: &pane_grid::Node) -> Vec<pane_grid::Pane> {
+        let mut panes = Vec::new();
+        collect_panes(layout, &mut panes);
+        panes
+    }
+
+    #[test]
+    fn pane_grids_are_restored() {
+        let (mut state, first) = pane_grid::State::new("a");
+        let (second, _) =
+            state.split(pane_grid::Axis::Vertical, first, "b").unwrap();
+        let _ = state.split(pane_grid::Axis::Horizontal, second, "c");
+        state.maximize(second);
+
+        let mut session = Session::new();
+        session.set_pane_grid("main", &state, |name| name.to_string());
+
+        let json = serde_json::to_string(&session).unwrap();
+        let session: Session = serde_json::from_str(&json).unwrap();
+
+        let restored = session.pane_grid("main", |name| Some(name.to_owned()));
+        let restored = restored.unwrap();
+
+        let names = |state: &pane_grid::State<String>| -> Vec<String> {
+            panes(state.layout())
+                .into_iter()
+                .filter_map(|pane| state.get(pane).map(ToString::to_string))
+                .collect()
+        };
+
+        assert_eq!(names(&restored), ["a", "b", "c"]);
+        assert_eq!(
+            restored.maximized().and_then(|pane| restored.get(pane)),
+            Some(&String::from("b"))
+        );
+    }
+
+    #[test]
+    fn missing_panes_are_left_out() {
+        let (mut state, first) = pane_grid::State::new("a");
+        let _ = state.split(pane_grid::Axis::Vertical, first, "b");
+
+        let mut session = Session::new();
+        session.set_pane_grid("main", &state, |name| name.to_string());
+
+        let restored = session
+            .pane_grid("main", |name| (name == "b").then_some(()))
+            .unwrap();
+
+        assert_eq!(restored.len(), 1);
+        assert!(matches!(restored.layout(), pane_grid::Node::Pane(_)));
+
+        assert!(session.pane_grid("main", |_| None::<()>).is_none());
+        assert!(session.pane_grid("other", |_| Some(())).is_none());
+    }
+}
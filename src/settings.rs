@@ -1,4 +1,7 @@
 //! Configure your application.
+pub use crate::core::settings::{Interaction, Motion};
+pub use crate::graphics::Backend;
+
 use crate::{Font, Pixels};
 
 use std::borrow::Cow;
@@ -35,6 +38,72 @@ pub struct Settings {
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
+
+    /// If set to true, the renderer will try to use a surface with a high
+    /// dynamic range.
+    ///
+    /// Enabling it allows colors with components above `1.0` to look
+    /// brighter than white on displays that support it. Everywhere else,
+    /// those colors will be tone-mapped.
+    ///
+    /// By default, it is disabled.
+    pub hdr: bool,
+
+    /// If set to true, modifiers that are tapped on their own stay latched
+    /// until the next key press or mouse click.
+    ///
+    /// This is an accessibility aid for users who cannot hold a modifier
+    /// while pressing another key. Applications may display the latched
+    /// modifiers with [`keyboard::on_modifiers_latched`].
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`keyboard::on_modifiers_latched`]: crate::keyboard::on_modifiers_latched
+    pub sticky_modifiers: bool,
+
+    /// The [`Interaction`] settings; like the speed of a double click.
+    ///
+    /// By default, it is `None`; which queries them from the platform on
+    /// startup, respecting the preferences of the user.
+    pub interaction: Option<Interaction>,
+
+    /// The [`Motion`] policy; that is, how much widgets animate.
+    ///
+    /// By default, it is `None`; which queries the "reduce motion"
+    /// preference of the user from the platform on startup.
+    pub motion: Option<Motion>,
+
+    /// The graphical [`Backend`]s to try, in order, until one of them can be
+    /// created.
+    ///
+    /// The `ICED_BACKEND` environment variable overrides them with a comma
+    /// separated list of backend names; like `ICED_BACKEND=tiny-skia` to force
+    /// software rendering.
+    ///
+    /// By default, it is [`Backend::ALL`].
+    pub backends: &'static [Backend],
+
+    /// The amount of GPU memory, in bytes, the renderer should try to stay
+    /// under.
+    ///
+    /// When its usage goes over the budget, the renderer evicts its caches
+    /// of images, glyphs, and geometry; trading some performance for memory.
+    /// The usage is shown in the debug overlay.
+    ///
+    /// By default, it is `None`.
+    pub memory_budget: Option<u64>,
+
+    /// The identifier used to enforce a single instance of the application.
+    ///
+    /// If provided, launching the application while another instance with
+    /// the same identifier is running forwards the command-line arguments to
+    /// it and exits immediately. See the [`single_instance`] module.
+    ///
+    /// By default, it is `None`.
+    ///
+    /// [`single_instance`]: crate::single_instance
+    #[cfg(feature = "single-instance")]
+    pub single_instance: Option<String>,
 }
 
 impl Default for Settings {
@@ -45,6 +114,14 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
+            hdr: false,
+            sticky_modifiers: false,
+            interaction: None,
+            motion: None,
+            backends: Backend::ALL,
+            memory_budget: None,
+            #[cfg(feature = "single-instance")]
+            single_instance: None,
         }
     }
 }
@@ -54,6 +131,9 @@ impl From<Settings> for iced_winit::Settings {
         iced_winit::Settings {
             id: settings.id,
             fonts: settings.fonts,
+            sticky_modifiers: settings.sticky_modifiers,
+            interaction: settings.interaction,
+            motion: settings.motion,
         }
     }
 }
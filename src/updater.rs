@@ -0,0 +1,663 @@
+//! Check for, download, and apply updates of an application.
+//!
+//! An application distributed outside of a store can publish a JSON manifest
+//! describing its latest release:
+//!
+//! ```json
+//! {
+//!     "version": "1.2.0",
+//!     "notes": "Fixes a crash on startup.",
+//!     "assets": {
+//!         "x86_64-linux": {
+//!             "url": "https://example.com/app-1.2.0-x86_64-linux",
+//!             "sha256": "5d1e…",
+//!             "signature": "9f2c…"
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Every asset is the executable of the application for the [`platform`]
+//! it is keyed by, and `sha256` is the hex-encoded SHA-256 digest of its
+//! bytes.
+//!
+//! The `signature` of an asset is the hex-encoded Ed25519 signature of the
+//! version, a `0` byte, the platform, a `0` byte, the raw bytes of the
+//! digest, and the notes; in that order. A manifest is rejected as soon as
+//! it is fetched if its signature does not match the [`PublicKey`] compiled
+//! into the application; so an old release—or one meant for a different
+//! platform—cannot be passed off as a new one.
+//!
+//! Downloads are verified against their signed digest before they are
+//! written to disk, and once again before they are applied.
+//!
+//! Nothing happens unless the application runs the tasks of this module:
+//! [`check`], [`download`], and [`apply`]. They run in the executor; so
+//! the network and the verification of signatures never block the UI. An
+//! applied update takes effect the next time the application starts.
+//!
+//! # Example
+//! ```no_run
+//! use iced::updater::{self, Download, PublicKey, Release};
+//! use iced::Task;
+//!
+//! const MANIFEST: &str = "https://example.com/manifest.json";
+//! const PUBLIC_KEY: PublicKey = PublicKey::from_bytes([0; 32]);
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Checked(Result<Option<Release>, updater::Error>),
+//!     Downloaded(Result<Download, updater::Error>),
+//!     Applied(Result<(), updater::Error>),
+//! }
+//!
+//! fn check() -> Task<Message> {
+//!     updater::check(MANIFEST, env!("CARGO_PKG_VERSION"), PUBLIC_KEY)
+//!         .map(Message::Checked)
+//! }
+//!
+//! fn update(message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Checked(Ok(Some(release))) => {
+//!             updater::download(&release).map(Message::Downloaded)
+//!         }
+//!         Message::Downloaded(Ok(Download::Finished(update))) => {
+//!             updater::apply(update).map(Message::Applied)
+//!         }
+//!         _ => Task::none(),
+//!     }
+//! }
+//! ```
+use crate::futures::channel::mpsc;
+use crate::futures::SinkExt;
+use crate::stream;
+use crate::Task;
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The Ed25519 public key that verifies the signatures of the releases in
+/// a manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey([u8; 32]);
+
+impl PublicKey {
+    /// Creates a [`PublicKey`] from its raw bytes.
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+/// A release of an application newer than the running one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    /// The version of the [`Release`].
+    pub version: String,
+
+    /// The release notes of the [`Release`].
+    pub notes: String,
+
+    platform: String,
+    url: String,
+    sha256: [u8; 32],
+    signature: [u8; 64],
+    public_key: PublicKey,
+}
+
+impl Release {
+    /// Returns the URL of the asset of the [`Release`] for the current
+    /// [`platform`].
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// The progress of a [`download`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Download {
+    /// Some bytes of the asset have been received.
+    Progress {
+        /// The amount of bytes received so far.
+        downloaded: u64,
+        /// The total amount of bytes of the asset, if known.
+        total: Option<u64>,
+    },
+
+    /// The asset was verified and staged as an [`Update`].
+    ///
+    /// It can be applied with [`apply`].
+    Finished(Update),
+}
+
+/// A verified asset of a [`Release`], staged next to the executable of the
+/// application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Update {
+    release: Release,
+    path: PathBuf,
+}
+
+impl Update {
+    /// Returns the [`Release`] of the [`Update`].
+    pub fn release(&self) -> &Release {
+        &self.release
+    }
+
+    /// Returns the path where the [`Update`] is staged.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Download {
+    /// Returns the progress of the [`Download`] in `[0.0, 1.0]`, if the
+    /// size of the asset is known.
+    pub fn progress(&self) -> Option<f32> {
+        match self {
+            Download::Progress {
+                downloaded,
+                total: Some(total),
+            } if *total > 0 => {
+                Some((*downloaded as f32 / *total as f32).min(1.0))
+            }
+            Download::Progress { .. } => None,
+            Download::Finished(_) => Some(1.0),
+        }
+    }
+}
+
+/// Returns the name of the current platform in the assets of a manifest;
+/// like `x86_64-linux`, `aarch64-macos`, or `x86_64-windows`.
+pub fn platform() -> String {
+    format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS)
+}
+
+/// Fetches the manifest at the given URL and returns its [`Release`], if it
+/// is newer than the current version and it has an asset for the current
+/// [`platform`].
+///
+/// The signature of the [`Release`] is verified against the given
+/// [`PublicKey`].
+///
+/// The current version is usually `env!("CARGO_PKG_VERSION")`.
+pub fn check(
+    manifest: impl Into<String>,
+    current_version: impl Into<String>,
+    public_key: PublicKey,
+) -> Task<Result<Option<Release>, Error>> {
+    let manifest = manifest.into();
+    let current_version = current_version.into();
+
+    Task::future(async move {
+        let current_version = parse_version(&current_version)?;
+
+        let bytes = reqwest::get(&manifest)
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+
+        let manifest = serde_json::from_slice(&bytes)
+            .map_err(|error| Error::Manifest(error.to_string()))?;
+
+        release(manifest, &current_version, &platform(), public_key)
+    })
+}
+
+/// Downloads the asset of the given [`Release`], reporting its progress.
+///
+/// The asset is verified against the signed digest of the [`Release`] once
+/// it is complete; and only then it is staged next to the executable of the
+/// application. Any update staged before is replaced.
+pub fn download(release: &Release) -> Task<Result<Download, Error>> {
+    let release = release.clone();
+
+    Task::stream(stream::try_channel(1, move |output| fetch(release, output)))
+}
+
+async fn fetch(
+    release: Release,
+    mut output: mpsc::Sender<Download>,
+) -> Result<(), Error> {
+    let mut response = reqwest::get(&release.url).await?.error_for_status()?;
+
+    let total = response.content_length();
+    let mut bytes = Vec::new();
+
+    while let Some(chunk) = response.chunk().await? {
+        bytes.extend_from_slice(&chunk);
+
+        let _ = output
+            .send(Download::Progress {
+                downloaded: bytes.len() as u64,
+                total,
+            })
+            .await;
+    }
+
+    verify_asset(&release, &bytes)?;
+
+    let path = stage(&bytes)?;
+
+    let _ = output
+        .send(Download::Finished(Update { release, path }))
+        .await;
+
+    Ok(())
+}
+
+/// Replaces the executable of the running application with the given
+/// [`Update`].
+///
+/// The [`Update`] is verified against its [`Release`] once again before it
+/// is applied. The running application keeps running as is; the update
+/// takes effect the next time it starts. On Windows, the previous
+/// executable is kept next to the new one with an `old` extension until
+/// the next update.
+///
+/// Updates are not supported on Wasm.
+pub fn apply(update: Update) -> Task<Result<(), Error>> {
+    Task::future(async move { replace(&update) })
+}
+
+/// An error that occurred while updating an application.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// A request failed.
+    #[error("the request failed: {0}")]
+    Request(Arc<reqwest::Error>),
+
+    /// The manifest is not valid JSON or has an unexpected format.
+    #[error("the manifest is invalid: {0}")]
+    Manifest(String),
+
+    /// The manifest is not signed by the [`PublicKey`], or an asset does
+    /// not match its signed digest.
+    #[error("the signature of the release is invalid")]
+    InvalidSignature,
+
+    /// The update could not be written.
+    #[error("the update could not be written: {0}")]
+    Io(Arc<io::Error>),
+
+    /// Updates are not supported on this platform.
+    #[error("updates are not supported on this platform")]
+    Unsupported,
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Request(Arc::new(error))
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    version: String,
+    #[serde(default)]
+    notes: String,
+    assets: HashMap<String, Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Asset {
+    url: String,
+    sha256: String,
+    signature: String,
+}
+
+fn release(
+    mut manifest: Manifest,
+    current_version: &semver::Version,
+    platform: &str,
+    public_key: PublicKey,
+) -> Result<Option<Release>, Error> {
+    if parse_version(&manifest.version)? <= *current_version {
+        return Ok(None);
+    }
+
+    let Some(asset) = manifest.assets.remove(platform) else {
+        return Ok(None);
+    };
+
+    let sha256 = decode_hex(&asset.sha256).ok_or_else(|| {
+        Error::Manifest(format!("invalid digest for {platform}"))
+    })?;
+
+    let signature = decode_hex(&asset.signature).ok_or_else(|| {
+        Error::Manifest(format!("invalid signature for {platform}"))
+    })?;
+
+    let release = Release {
+        version: manifest.version,
+        notes: manifest.notes,
+        platform: platform.to_owned(),
+        url: asset.url,
+        sha256,
+        signature,
+        public_key,
+    };
+
+    verify(&release)?;
+
+    Ok(Some(release))
+}
+
+fn parse_version(version: &str) -> Result<semver::Version, Error> {
+    semver::Version::parse(version.trim_start_matches('v')).map_err(|error| {
+        Error::Manifest(format!("invalid version {version:?}: {error}"))
+    })
+}
+
+fn decode_hex<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    if hex.len() != N * 2 {
+        return None;
+    }
+
+    let mut bytes = [0; N];
+
+    for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+/// Returns the signed message of a [`Release`].
+fn message(
+    version: &str,
+    platform: &str,
+    sha256: &[u8; 32],
+    notes: &str,
+) -> Vec<u8> {
+    [
+        version.as_bytes(),
+        &[0],
+        platform.as_bytes(),
+        &[0],
+        sha256,
+        notes.as_bytes(),
+    ]
+    .concat()
+}
+
+fn verify(release: &Release) -> Result<(), Error> {
+    let key = ed25519_dalek::VerifyingKey::from_bytes(&release.public_key.0)
+        .map_err(|_| Error::InvalidSignature)?;
+
+    let signature = ed25519_dalek::Signature::from_bytes(&release.signature);
+
+    let message = message(
+        &release.version,
+        &release.platform,
+        &release.sha256,
+        &release.notes,
+    );
+
+    key.verify_strict(&message, &signature)
+        .map_err(|_| Error::InvalidSignature)
+}
+
+fn verify_asset(release: &Release, bytes: &[u8]) -> Result<(), Error> {
+    verify(release)?;
+
+    if Sha256::digest(bytes).as_slice() != release.sha256 {
+        return Err(Error::InvalidSignature);
+    }
+
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn stage(bytes: &[u8]) -> Result<PathBuf, Error> {
+    use std::io::Write;
+
+    // The update is staged next to the executable; so it is only writable
+    // by whoever can replace the executable itself, and the final rename
+    // stays in the same file system and never leaves it half written
+    let path = std::env::current_exe()?.with_extension("update");
+
+    // Removing a symbolic link does not follow it, and a new file is never
+    // opened through one
+    let _ = fs::remove_file(&path);
+
+    let mut options = fs::OpenOptions::new();
+    let _ = options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    let _ = std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o755);
+
+    let mut file = options.open(&path)?;
+
+    file.write_all(bytes)?;
+    file.sync_all()?;
+
+    Ok(path)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn stage(_bytes: &[u8]) -> Result<PathBuf, Error> {
+    Err(Error::Unsupported)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn replace(update: &Update) -> Result<(), Error> {
+    let current = std::env::current_exe()?;
+
+    verify_asset(&update.release, &fs::read(&update.path)?)?;
+
+    // A running executable cannot be replaced on Windows; but it can be
+    // renamed out of the way
+    let old = cfg!(windows).then(|| current.with_extension("old"));
+
+    install(&update.path, &current, old.as_deref())?;
+
+    Ok(())
+}
+
+/// Moves the update at the given path over the current executable; after
+/// moving the current one to the old path, if any.
+///
+/// If the update cannot be moved, the old executable is put back.
+#[cfg(not(target_arch = "wasm32"))]
+fn install(
+    update: &Path,
+    current: &Path,
+    old: Option<&Path>,
+) -> io::Result<()> {
+    let Some(old) = old else {
+        return fs::rename(update, current);
+    };
+
+    let _ = fs::remove_file(old);
+
+    fs::rename(current, old)?;
+
+    if let Err(error) = fs::rename(update, current) {
+        let _ = fs::rename(old, current);
+
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn replace(_update: &Update) -> Result<(), Error> {
+    Err(Error::Unsupported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const SIGNING_KEY: [u8; 32] = [7; 32];
+    const ASSET: &[u8] = b"executable";
+
+    fn manifest(version: &str, signature: &str) -> Manifest {
+        serde_json::from_value(serde_json::json!({
+            "version": version,
+            "notes": "Notes",
+            "assets": {
+                "x86_64-linux": {
+                    "url": "https://example.com/app",
+                    "sha256": hex(&Sha256::digest(ASSET)),
+                    "signature": signature,
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn sign(version: &str, platform: &str) -> String {
+        let message =
+            message(version, platform, &Sha256::digest(ASSET).into(), "Notes");
+
+        hex(&SigningKey::from_bytes(&SIGNING_KEY)
+            .sign(&message)
+            .to_bytes())
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn public_key() -> PublicKey {
+        PublicKey::from_bytes(
+            SigningKey::from_bytes(&SIGNING_KEY)
+                .verifying_key()
+                .to_bytes(),
+        )
+    }
+
+    #[test]
+    fn only_newer_releases_are_returned() {
+        let current = semver::Version::new(1, 2, 0);
+
+        let check = |version, platform| {
+            release(
+                manifest(version, &sign(version, platform)),
+                &current,
+                platform,
+                public_key(),
+            )
+        };
+
+        assert_eq!(check("1.1.9", "x86_64-linux").unwrap(), None);
+        assert_eq!(check("1.2.0", "x86_64-linux").unwrap(), None);
+        assert_eq!(check("1.3.0", "aarch64-macos").unwrap(), None);
+
+        let release = check("v1.3.0", "x86_64-linux").unwrap().unwrap();
+
+        assert_eq!(release.version, "v1.3.0");
+        assert_eq!(release.url(), "https://example.com/app");
+
+        assert!(matches!(
+            check("latest", "x86_64-linux"),
+            Err(Error::Manifest(_))
+        ));
+    }
+
+    #[test]
+    fn manifests_are_verified() {
+        let current = semver::Version::new(1, 0, 0);
+
+        let check = |manifest, public_key| {
+            release(manifest, &current, "x86_64-linux", public_key)
+        };
+
+        assert!(check(
+            manifest("2.0.0", &sign("2.0.0", "x86_64-linux")),
+            public_key()
+        )
+        .is_ok());
+
+        // An old release cannot be passed off as a new one
+        assert!(matches!(
+            check(
+                manifest("2.0.0", &sign("0.9.0", "x86_64-linux")),
+                public_key()
+            ),
+            Err(Error::InvalidSignature)
+        ));
+
+        // Nor one meant for a different platform
+        assert!(matches!(
+            check(
+                manifest("2.0.0", &sign("2.0.0", "x86_64-windows")),
+                public_key()
+            ),
+            Err(Error::InvalidSignature)
+        ));
+
+        let forged = PublicKey::from_bytes(
+            SigningKey::from_bytes(&[8; 32]).verifying_key().to_bytes(),
+        );
+
+        assert!(matches!(
+            check(manifest("2.0.0", &sign("2.0.0", "x86_64-linux")), forged),
+            Err(Error::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn assets_are_verified() {
+        let release = release(
+            manifest("2.0.0", &sign("2.0.0", "x86_64-linux")),
+            &semver::Version::new(1, 0, 0),
+            "x86_64-linux",
+            public_key(),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(verify_asset(&release, ASSET).is_ok());
+
+        assert!(matches!(
+            verify_asset(&release, b"tampered"),
+            Err(Error::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn failed_installs_keep_the_old_executable() {
+        let directory = std::env::temp_dir()
+            .join(format!("iced-updater-{}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+
+        let current = directory.join("app");
+        let old = directory.join("app.old");
+        let update = directory.join("app.update");
+
+        fs::write(&current, "current").unwrap();
+
+        assert!(install(&update, &current, Some(&old)).is_err());
+        assert_eq!(fs::read_to_string(&current).unwrap(), "current");
+        assert!(!old.exists());
+
+        fs::write(&update, "update").unwrap();
+
+        assert!(install(&update, &current, Some(&old)).is_ok());
+        assert_eq!(fs::read_to_string(&current).unwrap(), "update");
+        assert_eq!(fs::read_to_string(&old).unwrap(), "current");
+        assert!(!update.exists());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
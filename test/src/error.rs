@@ -0,0 +1,72 @@
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// An error produced while checking a [`Snapshot`].
+///
+/// [`Snapshot`]: crate::Snapshot
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// A snapshot file could not be read or written.
+    #[error("snapshot file {path} could not be accessed: {error}")]
+    Io {
+        /// The path of the snapshot file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        error: Arc<io::Error>,
+    },
+    /// A snapshot file could not be decoded or encoded.
+    #[error("snapshot file {path} is not a valid PNG image: {error}")]
+    Image {
+        /// The path of the snapshot file.
+        path: PathBuf,
+        /// The underlying image error.
+        error: Arc<image::ImageError>,
+    },
+    /// There is no stored snapshot to compare the rendering against.
+    #[error(
+        "snapshot {name} is missing (rendering saved to {new}); run with \
+        UPDATE_SNAPSHOTS=1 to record it",
+        new = new.display()
+    )]
+    Missing {
+        /// The name of the snapshot.
+        name: String,
+        /// The path where the rendering was saved.
+        new: PathBuf,
+    },
+    /// The rendering does not have the size of the stored snapshot.
+    #[error(
+        "snapshot {name} has size {expected:?}, but the rendering has \
+        size {actual:?} (saved to {new})",
+        new = new.display()
+    )]
+    SizeMismatch {
+        /// The name of the snapshot.
+        name: String,
+        /// The size of the stored snapshot.
+        expected: (u32, u32),
+        /// The size of the rendering.
+        actual: (u32, u32),
+        /// The path where the rendering was saved.
+        new: PathBuf,
+    },
+    /// The rendering does not look like the stored snapshot.
+    #[error(
+        "snapshot {name} does not match: {pixels} pixels differ \
+        (saved to {new}, see {diff}); run with UPDATE_SNAPSHOTS=1 \
+        to update it",
+        new = new.display(),
+        diff = diff.display()
+    )]
+    Mismatch {
+        /// The name of the snapshot.
+        name: String,
+        /// The amount of pixels that differ.
+        pixels: usize,
+        /// The path where the rendering was saved.
+        new: PathBuf,
+        /// The path where the difference image was saved.
+        diff: PathBuf,
+    },
+}
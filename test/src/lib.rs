@@ -0,0 +1,199 @@
+//! Test your iced widgets with golden images.
+//!
+//! `iced_test` renders an [`Element`] offscreen using [`iced_tiny_skia`] and
+//! compares the result against a reference PNG stored in the `snapshots`
+//! directory of the crate running the test.
+//!
+//! # Example
+//! ```no_run
+//! use iced_test::core::{Size, Theme};
+//! use iced_widget::button;
+//!
+//! iced_test::assert_snapshot!(
+//!     button("Hello").on_press(()),
+//!     Size::new(200.0, 60.0),
+//!     Theme::Light,
+//!     "button_hello"
+//! );
+//! ```
+//!
+//! # Updating snapshots
+//! If a snapshot is missing or does not match, the test fails and the new
+//! rendering is saved as `<name>.new.png`; the differing pixels of a
+//! mismatch are highlighted in `<name>.diff.png`.
+//!
+//! Set the `UPDATE_SNAPSHOTS=1` environment variable to record missing
+//! snapshots and overwrite the stored ones with the new renderings. Then,
+//! commit the reference PNGs in the `snapshots` directory.
+//!
+//! # Fonts
+//! Text is rendered using the [`FONT`] bundled with this crate; so snapshots
+//! do not depend on the fonts installed in the system.
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+mod error;
+mod snapshot;
+
+pub use error::Error;
+pub use snapshot::Snapshot;
+
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+pub use iced_tiny_skia as renderer;
+
+use crate::core::clipboard;
+use crate::core::mouse;
+use crate::core::renderer::Style;
+use crate::core::{Element, Event, Font, Pixels, Rectangle, Size, Theme};
+use crate::renderer::graphics::{self, Viewport};
+use crate::runtime::user_interface::{self, UserInterface};
+
+use std::borrow::Cow;
+use std::sync::Once;
+
+/// The renderer used to render snapshots.
+pub type Renderer = iced_tiny_skia::Renderer;
+
+/// The font used to render text in snapshots.
+pub const FONT: Font = Font::with_name("Fira Sans");
+
+/// The text size used to render text in snapshots.
+pub const TEXT_SIZE: Pixels = Pixels(16.0);
+
+const FONT_BYTES: &[u8] = include_bytes!("../fonts/FiraSans-Regular.ttf");
+
+/// Asserts that an [`Element`] looks like the snapshot with the given name.
+///
+/// The element is rendered with the given [`Size`] and [`Theme`]. Optionally,
+/// a [`mouse::Cursor`] and a list of [`Event`] can be provided to render
+/// widgets in different states; like hovered or pressed.
+///
+/// Snapshots are stored in the `snapshots` directory of the crate calling
+/// the macro.
+///
+/// [`mouse::Cursor`]: crate::core::mouse::Cursor
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($element:expr, $size:expr, $theme:expr, $name:expr $(,)?) => {
+        $crate::assert_snapshot!(
+            $element,
+            $size,
+            $theme,
+            $name,
+            $crate::core::mouse::Cursor::Unavailable
+        )
+    };
+    (
+        $element:expr,
+        $size:expr,
+        $theme:expr,
+        $name:expr,
+        $cursor:expr
+        $(, $event:expr)* $(,)?
+    ) => {
+        if let Err(error) = $crate::render(
+            $element,
+            $size,
+            &$theme,
+            $cursor,
+            &[$($event),*],
+        )
+        .check(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("snapshots"),
+            $name,
+        ) {
+            panic!("{error}");
+        }
+    };
+}
+
+/// Renders an [`Element`] into a [`Snapshot`].
+///
+/// The given events are processed by the [`Element`] before drawing it;
+/// any messages produced are discarded.
+pub fn render<'a, Message>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    size: impl Into<Size>,
+    theme: &Theme,
+    cursor: mouse::Cursor,
+    events: &[Event],
+) -> Snapshot {
+    static LOAD_FONT: Once = Once::new();
+
+    LOAD_FONT.call_once(|| {
+        graphics::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .load_font(Cow::Borrowed(FONT_BYTES));
+    });
+
+    let size = size.into();
+    let mut renderer = Renderer::new(FONT, TEXT_SIZE);
+
+    let mut user_interface = UserInterface::build(
+        element,
+        size,
+        user_interface::Cache::default(),
+        &mut renderer,
+    );
+
+    if !events.is_empty() {
+        let mut messages = Vec::new();
+
+        let _ = user_interface.update(
+            events,
+            cursor,
+            &mut renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+    }
+
+    let palette = theme.palette();
+
+    let _ = user_interface.draw(
+        &mut renderer,
+        theme,
+        &Style {
+            text_color: palette.text,
+        },
+        cursor,
+    );
+
+    let physical_size = Size::new(size.width as u32, size.height as u32);
+    let viewport = Viewport::with_physical_size(physical_size, 1.0);
+
+    let mut pixels = tiny_skia::Pixmap::new(
+        physical_size.width.max(1),
+        physical_size.height.max(1),
+    )
+    .expect("Create snapshot pixmap");
+
+    let mut clip_mask = tiny_skia::Mask::new(pixels.width(), pixels.height())
+        .expect("Create snapshot clip mask");
+
+    renderer.draw::<&str>(
+        &mut pixels.as_mut(),
+        &mut clip_mask,
+        &viewport,
+        &[Rectangle::with_size(size)],
+        palette.background,
+        &[],
+    );
+
+    // The renderer draws BGRA pixels, the same way a window surface expects
+    let rgba = pixels
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let pixel = pixel.demultiply();
+
+            [pixel.blue(), pixel.green(), pixel.red(), pixel.alpha()]
+        })
+        .collect();
+
+    Snapshot::from_rgba(pixels.width(), pixels.height(), rgba)
+}
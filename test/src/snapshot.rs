@@ -0,0 +1,203 @@
+use crate::Error;
+
+use std::path::Path;
+use std::sync::Arc;
+
+/// The environment variable that, when set to `1`, overwrites the stored
+/// snapshots with the new renderings.
+const UPDATE_VARIABLE: &str = "UPDATE_SNAPSHOTS";
+
+/// The maximum perceived color difference between two pixels for them to be
+/// considered equal, from `0.0` to `1.0`.
+const THRESHOLD: f32 = 0.1;
+
+/// The maximum possible value of [`delta`].
+const MAX_DELTA: f32 = 35215.0;
+
+/// The rendering of an [`Element`].
+///
+/// [`Element`]: crate::core::Element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    image: image::RgbaImage,
+}
+
+impl Snapshot {
+    /// Creates a [`Snapshot`] from its raw RGBA pixels.
+    ///
+    /// # Panics
+    /// Panics if the amount of pixels does not match the given size.
+    pub fn from_rgba(width: u32, height: u32, rgba: Vec<u8>) -> Self {
+        Self {
+            image: image::RgbaImage::from_raw(width, height, rgba)
+                .expect("Snapshot pixels must match its size"),
+        }
+    }
+
+    /// Returns the width of the [`Snapshot`], in pixels.
+    pub fn width(&self) -> u32 {
+        self.image.width()
+    }
+
+    /// Returns the height of the [`Snapshot`], in pixels.
+    pub fn height(&self) -> u32 {
+        self.image.height()
+    }
+
+    /// Returns the raw RGBA pixels of the [`Snapshot`].
+    pub fn as_rgba(&self) -> &[u8] {
+        self.image.as_raw()
+    }
+
+    /// Returns the amount of pixels that look different between two
+    /// snapshots of the same size.
+    pub fn diff(&self, other: &Self) -> usize {
+        self.image
+            .pixels()
+            .zip(other.image.pixels())
+            .filter(|(a, b)| is_different(a.0, b.0))
+            .count()
+    }
+
+    /// Checks the [`Snapshot`] against the one stored with the given name in
+    /// the given directory.
+    ///
+    /// If the `UPDATE_SNAPSHOTS` environment variable is set to `1`, the
+    /// [`Snapshot`] will be stored instead. Otherwise, a missing snapshot is
+    /// an error.
+    pub fn check(
+        &self,
+        directory: impl AsRef<Path>,
+        name: &str,
+    ) -> Result<(), Error> {
+        let directory = directory.as_ref();
+        let path = directory.join(format!("{name}.png"));
+
+        let update =
+            std::env::var(UPDATE_VARIABLE).is_ok_and(|value| value == "1");
+
+        if update {
+            return self.save(&path);
+        }
+
+        let new = directory.join(format!("{name}.new.png"));
+
+        if !path.exists() {
+            self.save(&new)?;
+
+            return Err(Error::Missing {
+                name: name.to_owned(),
+                new,
+            });
+        }
+
+        let expected = image::open(&path)
+            .map_err(|error| Error::Image {
+                path: path.clone(),
+                error: Arc::new(error),
+            })?
+            .into_rgba8();
+
+        if expected.dimensions() != self.image.dimensions() {
+            self.save(&new)?;
+
+            return Err(Error::SizeMismatch {
+                name: name.to_owned(),
+                expected: expected.dimensions(),
+                actual: self.image.dimensions(),
+                new,
+            });
+        }
+
+        let expected = Self { image: expected };
+        let pixels = self.diff(&expected);
+
+        if pixels == 0 {
+            return Ok(());
+        }
+
+        let diff = directory.join(format!("{name}.diff.png"));
+
+        self.save(&new)?;
+        self.difference(&expected).save(&diff)?;
+
+        Err(Error::Mismatch {
+            name: name.to_owned(),
+            pixels,
+            new,
+            diff,
+        })
+    }
+
+    /// Produces an image highlighting the pixels that differ in red over a
+    /// faded version of the [`Snapshot`].
+    fn difference(&self, other: &Self) -> Self {
+        let mut image = self.image.clone();
+
+        for (pixel, other) in image.pixels_mut().zip(other.image.pixels()) {
+            if is_different(pixel.0, other.0) {
+                pixel.0 = [255, 0, 0, 255];
+            } else {
+                let [r, g, b, _] = pixel.0;
+                let gray = 255 - (255 - luma(r, g, b) as u8) / 4;
+
+                pixel.0 = [gray, gray, gray, 255];
+            }
+        }
+
+        Self { image }
+    }
+
+    fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory).map_err(|error| Error::Io {
+                path: directory.to_path_buf(),
+                error: Arc::new(error),
+            })?;
+        }
+
+        self.image.save(path).map_err(|error| Error::Image {
+            path: path.to_path_buf(),
+            error: Arc::new(error),
+        })
+    }
+}
+
+fn is_different(a: [u8; 4], b: [u8; 4]) -> bool {
+    a != b && delta(a, b) > MAX_DELTA * THRESHOLD * THRESHOLD
+}
+
+/// Computes the perceived difference between two colors in the YIQ color
+/// space; as described in "Measuring perceived color difference using YIQ
+/// NTSC transmission color space in mobile applications" by Kotsarenko and
+/// Ramos.
+fn delta(a: [u8; 4], b: [u8; 4]) -> f32 {
+    let [r1, g1, b1] = blend(a);
+    let [r2, g2, b2] = blend(b);
+
+    let y = luma(r1, g1, b1) - luma(r2, g2, b2);
+    let i = in_phase(r1, g1, b1) - in_phase(r2, g2, b2);
+    let q = quadrature(r1, g1, b1) - quadrature(r2, g2, b2);
+
+    0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q
+}
+
+/// Blends a color with a white background.
+fn blend([r, g, b, a]: [u8; 4]) -> [f32; 3] {
+    let alpha = f32::from(a) / 255.0;
+    let blend = |c: u8| 255.0 + (f32::from(c) - 255.0) * alpha;
+
+    [blend(r), blend(g), blend(b)]
+}
+
+fn luma<T: Into<f32>>(r: T, g: T, b: T) -> f32 {
+    r.into() * 0.2989 + g.into() * 0.5866 + b.into() * 0.1145
+}
+
+fn in_phase(r: f32, g: f32, b: f32) -> f32 {
+    r * 0.596 - g * 0.2742 - b * 0.3218
+}
+
+fn quadrature(r: f32, g: f32, b: f32) -> f32 {
+    r * 0.2115 - g * 0.5226 + b * 0.3111
+}
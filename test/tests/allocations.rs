@@ -0,0 +1,193 @@
+//! Count the allocations made by drawing a view full of static labels.
+//!
+//! Labels are drawn either by filling plain text every frame, like widgets
+//! used to; or by filling the paragraph kept in the state of the `text`,
+//! `button` and `checkbox` widgets.
+// A counting global allocator needs to implement an unsafe trait
+#![allow(unsafe_code)]
+use iced_test::core::layout::{self, Layout};
+use iced_test::core::mouse;
+use iced_test::core::renderer::{self, Renderer as _};
+use iced_test::core::text::{self, Renderer as _};
+use iced_test::core::widget::{self, Widget};
+use iced_test::core::{
+    alignment, Color, Element, Length, Rectangle, Size, Theme,
+};
+use iced_test::renderer::graphics;
+use iced_test::runtime::user_interface::{self, UserInterface};
+use iced_test::{Renderer, FONT, TEXT_SIZE};
+use iced_widget::{button, checkbox, column, text};
+
+use std::alloc::{GlobalAlloc, Layout as Allocation, System};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+const LABELS: usize = 500;
+
+const FRAMES: usize = 10;
+
+const SIZE: Size = Size::new(400.0, 20_000.0);
+
+#[global_allocator]
+static ALLOCATOR: Counter = Counter;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The [`System`] allocator, counting the allocations of every thread.
+struct Counter;
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Allocation) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Allocation) {
+        System.dealloc(pointer, layout);
+    }
+
+    unsafe fn realloc(
+        &self,
+        pointer: *mut u8,
+        layout: Allocation,
+        size: usize,
+    ) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        System.realloc(pointer, layout, size)
+    }
+}
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+/// A label filling plain text every frame; the way static labels were
+/// drawn before widgets filled their cached paragraph directly.
+struct Plain<'a>(&'a str);
+
+impl<Message> Widget<Message, Theme, Renderer> for Plain<'_> {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fixed(TEXT_SIZE.0 * 1.5))
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut widget::Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, Length::Fill, Length::Fixed(TEXT_SIZE.0 * 1.5))
+    }
+
+    fn draw(
+        &self,
+        _tree: &widget::Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.fill_text(
+            text::Text {
+                content: self.0.to_string(),
+                bounds: bounds.size(),
+                size: TEXT_SIZE,
+                line_height: text::LineHeight::default(),
+                font: FONT,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                font_features: &[],
+                shaping: text::Shaping::Basic,
+            },
+            bounds.position(),
+            style.text_color,
+            *viewport,
+        );
+    }
+}
+
+/// Returns the average allocations made drawing a frame of the given
+/// [`Element`].
+fn allocations_per_frame<'a>(
+    element: impl Into<Element<'a, (), Theme, Renderer>>,
+) -> usize {
+    graphics::text::font_system()
+        .write()
+        .expect("Write to font system")
+        .load_font(Cow::Borrowed(include_bytes!(
+            "../fonts/FiraSans-Regular.ttf"
+        )));
+
+    let mut renderer = Renderer::new(FONT, TEXT_SIZE);
+
+    let mut user_interface = UserInterface::build(
+        element,
+        SIZE,
+        user_interface::Cache::default(),
+        &mut renderer,
+    );
+
+    let mut draw = |renderer: &mut Renderer| {
+        let _ = user_interface.draw(
+            renderer,
+            &Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            mouse::Cursor::Unavailable,
+        );
+
+        renderer.clear();
+    };
+
+    // Let the renderer grow its buffers first
+    draw(&mut renderer);
+
+    let start = allocations();
+
+    for _ in 0..FRAMES {
+        draw(&mut renderer);
+    }
+
+    (allocations() - start) / FRAMES
+}
+
+#[test]
+fn static_labels_do_not_allocate_every_frame() {
+    let labels: Vec<String> =
+        (0..LABELS).map(|i| format!("Label {i}")).collect();
+
+    let before =
+        allocations_per_frame(column(labels.iter().map(|label| {
+            Element::<'_, (), Theme, Renderer>::new(Plain(label))
+        })));
+
+    let after = allocations_per_frame(column(labels.iter().enumerate().map(
+        |(i, label)| {
+            let label: Element<'_, (), Theme, Renderer> = match i % 3 {
+                0 => text(label).into(),
+                1 => button(text(label)).into(),
+                _ => checkbox(label, false).into(),
+            };
+
+            label
+        },
+    )));
+
+    println!(
+        "Allocations per frame of {LABELS} labels: \
+        {before} before, {after} after"
+    );
+
+    assert!(before >= LABELS, "{before} allocations before");
+    assert!(after < LABELS, "{after} allocations after");
+}
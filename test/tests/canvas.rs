@@ -0,0 +1,104 @@
+use iced_test::core::mouse;
+use iced_test::core::{Color, Element, Length, Point, Rectangle, Size, Theme};
+use iced_test::{Renderer, Snapshot};
+use iced_widget::canvas::{Frame, Geometry};
+use iced_widget::{canvas, container, horizontal_space, stack};
+
+const SIZE: Size = Size::new(100.0, 100.0);
+
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+
+/// A program drawing a red background with some text and, in a second
+/// group, a blue background covering both.
+struct Groups;
+
+impl canvas::Program<(), Theme, Renderer> for Groups {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        _style: &canvas::Style,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        let mut background = Frame::new(renderer, bounds.size());
+
+        background.fill_rectangle(
+            Point::ORIGIN,
+            bounds.size(),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+        background.fill_text("Text");
+
+        let mut foreground = Frame::new(renderer, bounds.size());
+
+        foreground.fill_rectangle(
+            Point::ORIGIN,
+            bounds.size(),
+            Color::from_rgb(0.0, 0.0, 1.0),
+        );
+
+        vec![background.into_geometry(), foreground.into_geometry()]
+    }
+}
+
+/// Renders the [`Groups`] with a green square drawn after them at the
+/// top-left corner.
+fn render() -> Snapshot {
+    let square =
+        container(horizontal_space())
+            .width(50)
+            .height(50)
+            .style(|_theme| {
+                container::Style::default()
+                    .background(Color::from_rgb(0.0, 1.0, 0.0))
+            });
+
+    let element: Element<'_, (), Theme, Renderer> = stack![
+        canvas(Groups).width(Length::Fill).height(Length::Fill),
+        square,
+    ]
+    .into();
+
+    iced_test::render(
+        element,
+        SIZE,
+        &Theme::Light,
+        mouse::Cursor::Unavailable,
+        &[],
+    )
+}
+
+fn pixel(snapshot: &Snapshot, x: usize, y: usize) -> [u8; 4] {
+    let index = (y * snapshot.width() as usize + x) * 4;
+
+    snapshot.as_rgba()[index..index + 4].try_into().unwrap()
+}
+
+#[test]
+fn widgets_drawn_after_a_canvas_stay_on_top() {
+    let snapshot = render();
+
+    assert_eq!(pixel(&snapshot, 25, 25), GREEN);
+}
+
+#[test]
+fn canvas_groups_cover_the_text_of_earlier_ones() {
+    let snapshot = render();
+
+    for y in 0..snapshot.height() as usize {
+        for x in 50..snapshot.width() as usize {
+            assert_eq!(pixel(&snapshot, x, y), BLUE);
+        }
+    }
+
+    for y in 50..snapshot.height() as usize {
+        for x in 0..50 {
+            assert_eq!(pixel(&snapshot, x, y), BLUE);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use iced_test::core::event;
+use iced_test::core::layout;
+use iced_test::core::mouse;
+use iced_test::core::overlay;
+use iced_test::core::renderer::{self, Renderer as _};
+use iced_test::core::widget::{Tree, Widget};
+use iced_test::core::{
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell,
+    Size, Theme, Vector,
+};
+use iced_test::{Renderer, Snapshot};
+use iced_widget::pick_list;
+
+const SIZE: Size = Size::new(200.0, 200.0);
+
+const TOAST: Rectangle = Rectangle {
+    x: 0.0,
+    y: 50.0,
+    width: 200.0,
+    height: 50.0,
+};
+
+/// A red toast displayed over the given content.
+struct Toasted<'a> {
+    content: Element<'a, (), Theme, Renderer>,
+    is_top_most: bool,
+}
+
+impl Widget<(), Theme, Renderer> for Toasted<'_> {
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, ()>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn overlay<'a>(
+        &'a mut self,
+        tree: &'a mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'a, (), Theme, Renderer>> {
+        let toast = overlay::Element::new(Box::new(Toast));
+
+        let toast = if self.is_top_most {
+            toast.top_most()
+        } else {
+            toast
+        };
+
+        // The toast was opened before the content overlays
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        Some(
+            overlay::Group::with_children(
+                std::iter::once(toast).chain(content).collect(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+struct Toast;
+
+impl overlay::Overlay<(), Theme, Renderer> for Toast {
+    fn layout(&mut self, _renderer: &Renderer, _bounds: Size) -> layout::Node {
+        layout::Node::new(TOAST.size()).move_to(TOAST.position())
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+    }
+}
+
+/// Renders a toast over an open [`pick_list`] menu.
+fn render(is_top_most: bool) -> Snapshot {
+    let options = ["A", "B", "C", "D", "E"];
+
+    let toasted = Toasted {
+        content: pick_list(options, None::<&str>, |_| ())
+            .width(Length::Fill)
+            .into(),
+        is_top_most,
+    };
+
+    let cursor = mouse::Cursor::Available(Point::new(10.0, 10.0));
+
+    iced_test::render(
+        Element::new(toasted),
+        SIZE,
+        &Theme::Light,
+        cursor,
+        &[Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))],
+    )
+}
+
+fn pixel(snapshot: &Snapshot, point: Point) -> [u8; 4] {
+    let index =
+        (point.y as usize * snapshot.width() as usize + point.x as usize) * 4;
+
+    snapshot.as_rgba()[index..index + 4].try_into().unwrap()
+}
+
+#[test]
+fn top_most_toasts_stay_above_menus() {
+    let center = TOAST.center();
+
+    assert_eq!(pixel(&render(true), center), [255, 0, 0, 255]);
+
+    // Otherwise, the menu opened afterwards covers the toast
+    assert_ne!(pixel(&render(false), center), [255, 0, 0, 255]);
+}
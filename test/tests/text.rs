@@ -0,0 +1,109 @@
+use iced_test::core::alignment::{self, Direction};
+use iced_test::core::{mouse, Alignment, Element, Length, Size, Theme};
+use iced_test::{Renderer, Snapshot};
+use iced_widget::{keyed_column, text};
+
+const SIZE: Size = Size::new(200.0, 40.0);
+
+/// Renders some text with the given alignment and [`Direction`].
+fn render(
+    alignment: impl Into<alignment::Horizontal>,
+    direction: Direction,
+) -> Snapshot {
+    render_with(
+        text("Text").width(Length::Fill).align_x(alignment),
+        direction,
+    )
+}
+
+/// Renders the given [`Element`] with the given [`Direction`].
+fn render_with<'a>(
+    element: impl Into<Element<'a, (), Theme, Renderer>>,
+    direction: Direction,
+) -> Snapshot {
+    alignment::set_direction(direction);
+
+    let snapshot = iced_test::render(
+        element,
+        SIZE,
+        &Theme::Light,
+        mouse::Cursor::Unavailable,
+        &[],
+    );
+
+    alignment::set_direction(Direction::LeftToRight);
+
+    snapshot
+}
+
+/// Returns whether the left and the right halves of the [`Snapshot`] have
+/// any text drawn on them, respectively.
+fn halves(snapshot: &Snapshot) -> (bool, bool) {
+    let width = snapshot.width() as usize;
+    let background = &snapshot.as_rgba()[0..4];
+
+    let mut halves = (false, false);
+
+    for (i, pixel) in snapshot.as_rgba().chunks_exact(4).enumerate() {
+        if pixel != background {
+            if i % width < width / 2 {
+                halves.0 = true;
+            } else {
+                halves.1 = true;
+            }
+        }
+    }
+
+    halves
+}
+
+#[test]
+fn logical_text_alignment_is_mirrored_right_to_left() {
+    assert_eq!(
+        halves(&render(Alignment::Start, Direction::LeftToRight)),
+        (true, false)
+    );
+
+    assert_eq!(
+        halves(&render(Alignment::Start, Direction::RightToLeft)),
+        (false, true)
+    );
+
+    assert_eq!(
+        halves(&render(Alignment::End, Direction::RightToLeft)),
+        (true, false)
+    );
+
+    assert_eq!(
+        halves(&render(alignment::Horizontal::Left, Direction::RightToLeft)),
+        (true, false)
+    );
+}
+
+#[test]
+fn logical_keyed_column_alignment_is_mirrored_right_to_left() {
+    let render = |alignment: Alignment, direction| {
+        let column: Element<'_, (), Theme, Renderer> =
+            keyed_column([(0, text("Text").into())])
+                .width(Length::Fill)
+                .align_items(alignment)
+                .into();
+
+        render_with(column, direction)
+    };
+
+    assert_eq!(
+        halves(&render(Alignment::Start, Direction::LeftToRight)),
+        (true, false)
+    );
+
+    assert_eq!(
+        halves(&render(Alignment::Start, Direction::RightToLeft)),
+        (false, true)
+    );
+
+    assert_eq!(
+        halves(&render(Alignment::End, Direction::RightToLeft)),
+        (true, false)
+    );
+}
@@ -0,0 +1,150 @@
+//! The reference snapshots of these tests have not been recorded yet; so
+//! they are ignored until they are. Record them by running:
+//!
+//! ```text
+//! UPDATE_SNAPSHOTS=1 cargo test --package iced_test -- --ignored
+//! ```
+//!
+//! Then, commit the `snapshots` directory and remove the `ignore`
+//! attributes.
+use iced_test::assert_snapshot;
+use iced_test::core::mouse;
+use iced_test::core::{Event, Point, Size, Theme};
+use iced_widget::{button, checkbox, slider};
+
+const SIZE: Size = Size::new(200.0, 60.0);
+
+fn hovering(x: f32, y: f32) -> mouse::Cursor {
+    mouse::Cursor::Available(Point::new(x, y))
+}
+
+fn left_click() -> Event {
+    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn button_active() {
+    assert_snapshot!(
+        button("Button").on_press(()),
+        SIZE,
+        Theme::Light,
+        "button_active"
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn button_hovered() {
+    assert_snapshot!(
+        button("Button").on_press(()),
+        SIZE,
+        Theme::Light,
+        "button_hovered",
+        hovering(10.0, 10.0)
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn button_pressed() {
+    assert_snapshot!(
+        button("Button").on_press(()),
+        SIZE,
+        Theme::Light,
+        "button_pressed",
+        hovering(10.0, 10.0),
+        left_click()
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn button_disabled() {
+    assert_snapshot!(
+        button::<(), _, _>("Button"),
+        SIZE,
+        Theme::Light,
+        "button_disabled",
+        hovering(10.0, 10.0)
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn checkbox_unchecked() {
+    assert_snapshot!(
+        checkbox("Checkbox", false).on_toggle(|_| ()),
+        SIZE,
+        Theme::Light,
+        "checkbox_unchecked"
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn checkbox_checked() {
+    assert_snapshot!(
+        checkbox("Checkbox", true).on_toggle(|_| ()),
+        SIZE,
+        Theme::Light,
+        "checkbox_checked"
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn checkbox_hovered() {
+    assert_snapshot!(
+        checkbox("Checkbox", false).on_toggle(|_| ()),
+        SIZE,
+        Theme::Light,
+        "checkbox_hovered",
+        hovering(8.0, 8.0)
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn checkbox_disabled() {
+    assert_snapshot!(
+        checkbox::<(), _, _>("Checkbox", true),
+        SIZE,
+        Theme::Light,
+        "checkbox_disabled"
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn slider_active() {
+    assert_snapshot!(
+        slider(0.0..=100.0, 50.0, |_| ()),
+        SIZE,
+        Theme::Light,
+        "slider_active"
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn slider_hovered() {
+    assert_snapshot!(
+        slider(0.0..=100.0, 50.0, |_| ()),
+        SIZE,
+        Theme::Light,
+        "slider_hovered",
+        hovering(100.0, 8.0)
+    );
+}
+
+#[test]
+#[ignore = "the reference snapshot has not been recorded yet"]
+fn slider_dark() {
+    assert_snapshot!(
+        slider(0.0..=100.0, 25.0, |_| ()),
+        SIZE,
+        Theme::Dark,
+        "slider_dark"
+    );
+}
@@ -2,7 +2,8 @@ use crate::core::renderer::Quad;
 use crate::core::{
     Background, Color, Gradient, Rectangle, Size, Transformation, Vector,
 };
-use crate::graphics::{Image, Text};
+use crate::graphics::mesh;
+use crate::graphics::{self, Image, Text};
 use crate::text;
 use crate::Primitive;
 
@@ -10,6 +11,10 @@ use crate::Primitive;
 pub struct Engine {
     text_pipeline: text::Pipeline,
 
+    /// Whether the current layer is clipped by a path; in which case the
+    /// clip mask is needed even by primitives within its bounds.
+    pub(crate) clip_path: bool,
+
     #[cfg(feature = "image")]
     pub(crate) raster_pipeline: crate::raster::Pipeline,
     #[cfg(feature = "svg")]
@@ -20,6 +25,7 @@ impl Engine {
     pub fn new() -> Self {
         Self {
             text_pipeline: text::Pipeline::new(),
+            clip_path: false,
             #[cfg(feature = "image")]
             raster_pipeline: crate::raster::Pipeline::new(),
             #[cfg(feature = "svg")]
@@ -51,8 +57,9 @@ impl Engine {
             return;
         }
 
-        let clip_mask = (!physical_bounds.is_within(&clip_bounds))
-            .then_some(clip_mask as &_);
+        let clip_mask = (self.clip_path
+            || !physical_bounds.is_within(&clip_bounds))
+        .then_some(clip_mask as &_);
 
         let transform = into_transform(transformation);
 
@@ -171,13 +178,7 @@ impl Engine {
                             .map(|stop| {
                                 tiny_skia::GradientStop::new(
                                     stop.offset,
-                                    tiny_skia::Color::from_rgba(
-                                        stop.color.b,
-                                        stop.color.g,
-                                        stop.color.r,
-                                        stop.color.a,
-                                    )
-                                    .expect("Create color"),
+                                    into_color(stop.color),
                                 )
                             })
                             .collect();
@@ -352,8 +353,9 @@ impl Engine {
                     return;
                 }
 
-                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
-                    .then_some(clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || !physical_bounds.is_within(&clip_bounds))
+                .then_some(clip_mask as &_);
 
                 self.text_pipeline.draw_paragraph(
                     paragraph,
@@ -380,8 +382,9 @@ impl Engine {
                     return;
                 }
 
-                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
-                    .then_some(clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || !physical_bounds.is_within(&clip_bounds))
+                .then_some(clip_mask as &_);
 
                 self.text_pipeline.draw_editor(
                     editor,
@@ -401,6 +404,7 @@ impl Engine {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                max_space_factor,
                 shaping,
                 clip_bounds: text_bounds, // TODO
             } => {
@@ -410,8 +414,9 @@ impl Engine {
                     return;
                 }
 
-                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
-                    .then_some(clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || !physical_bounds.is_within(&clip_bounds))
+                .then_some(clip_mask as &_);
 
                 self.text_pipeline.draw_cached(
                     content,
@@ -422,6 +427,7 @@ impl Engine {
                     *font,
                     *horizontal_alignment,
                     *vertical_alignment,
+                    *max_space_factor,
                     *shaping,
                     pixels,
                     clip_mask,
@@ -451,8 +457,9 @@ impl Engine {
                     return;
                 }
 
-                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
-                    .then_some(clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || !physical_bounds.is_within(&clip_bounds))
+                .then_some(clip_mask as &_);
 
                 self.text_pipeline.draw_raw(
                     &buffer,
@@ -493,8 +500,9 @@ impl Engine {
                     return;
                 };
 
-                let clip_mask =
-                    (physical_bounds != clip_bounds).then_some(clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || physical_bounds != clip_bounds)
+                    .then_some(clip_mask as &_);
 
                 pixels.fill_path(
                     path,
@@ -526,8 +534,9 @@ impl Engine {
                     return;
                 };
 
-                let clip_mask =
-                    (physical_bounds != clip_bounds).then_some(clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || physical_bounds != clip_bounds)
+                    .then_some(clip_mask as &_);
 
                 pixels.stroke_path(
                     path,
@@ -563,8 +572,9 @@ impl Engine {
                     return;
                 }
 
-                let clip_mask = (!physical_bounds.is_within(&_clip_bounds))
-                    .then_some(_clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || !physical_bounds.is_within(&_clip_bounds))
+                .then_some(_clip_mask as &_);
 
                 let center = physical_bounds.center();
                 let radians = f32::from(*rotation);
@@ -599,8 +609,9 @@ impl Engine {
                     return;
                 }
 
-                let clip_mask = (!physical_bounds.is_within(&_clip_bounds))
-                    .then_some(_clip_mask as &_);
+                let clip_mask = (self.clip_path
+                    || !physical_bounds.is_within(&_clip_bounds))
+                .then_some(_clip_mask as &_);
 
                 let center = physical_bounds.center();
                 let radians = f32::from(*rotation);
@@ -648,11 +659,23 @@ impl Engine {
 }
 
 pub fn into_color(color: Color) -> tiny_skia::Color {
+    // tiny-skia only renders to SDR targets
+    let color = graphics::color::tone_map(color);
+
     tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
         .expect("Convert color from iced to tiny_skia")
 }
 
-fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
+pub fn into_blend_mode(blend: mesh::Blend) -> tiny_skia::BlendMode {
+    match blend {
+        mesh::Blend::Alpha => tiny_skia::BlendMode::SourceOver,
+        mesh::Blend::Additive => tiny_skia::BlendMode::Plus,
+        mesh::Blend::Multiply => tiny_skia::BlendMode::Multiply,
+        mesh::Blend::Screen => tiny_skia::BlendMode::Screen,
+    }
+}
+
+pub fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     let translation = transformation.translation();
 
     tiny_skia::Transform {
@@ -858,3 +881,20 @@ pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
         tiny_skia::Transform::default(),
     );
 }
+
+/// Intersects the clip mask with the given clip paths, which are in logical
+/// coordinates.
+pub fn intersect_clip_mask(
+    clip_mask: &mut tiny_skia::Mask,
+    paths: &[tiny_skia::Path],
+    scale_factor: f32,
+) {
+    for path in paths {
+        clip_mask.intersect_path(
+            path,
+            tiny_skia::FillRule::Winding,
+            true,
+            tiny_skia::Transform::from_scale(scale_factor, scale_factor),
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use crate::core::text::LineHeight;
-use crate::core::{Pixels, Point, Radians, Rectangle, Size, Vector};
+use crate::core::{
+    image, svg, Pixels, Point, Radians, Rectangle, Size, Transformation, Vector,
+};
+use crate::engine;
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
 use crate::graphics::geometry::{self, Path, Style};
-use crate::graphics::{Gradient, Text};
+use crate::graphics::{Gradient, Image, Text};
 use crate::Primitive;
 
 use std::rc::Rc;
@@ -14,6 +17,7 @@ pub enum Geometry {
     Live {
         text: Vec<Text>,
         primitives: Vec<Primitive>,
+        images: Vec<(Image, Rectangle)>,
         clip_bounds: Rectangle,
     },
     Cache(Cache),
@@ -23,6 +27,8 @@ pub enum Geometry {
 pub struct Cache {
     pub text: Rc<[Text]>,
     pub primitives: Rc<[Primitive]>,
+    /// The images of the geometry, with their clip bounds.
+    pub images: Rc<[(Image, Rectangle)]>,
     pub clip_bounds: Rectangle,
 }
 
@@ -38,10 +44,12 @@ impl Cached for Geometry {
             Self::Live {
                 primitives,
                 text,
+                images,
                 clip_bounds,
             } => Cache {
                 primitives: Rc::from(primitives),
                 text: Rc::from(text),
+                images: Rc::from(images),
                 clip_bounds,
             },
             Self::Cache(cache) => cache,
@@ -56,6 +64,7 @@ pub struct Frame {
     stack: Vec<tiny_skia::Transform>,
     primitives: Vec<Primitive>,
     text: Vec<Text>,
+    images: Vec<(Image, Rectangle)>,
 }
 
 impl Frame {
@@ -69,6 +78,7 @@ impl Frame {
             stack: Vec::new(),
             primitives: Vec::new(),
             text: Vec::new(),
+            images: Vec::new(),
             transform: tiny_skia::Transform::from_translate(
                 clip_bounds.x,
                 clip_bounds.y,
@@ -107,6 +117,7 @@ impl geometry::frame::Backend for Frame {
 
         let mut paint = into_paint(fill.style);
         paint.shader.transform(self.transform);
+        paint.blend_mode = engine::into_blend_mode(fill.blend);
 
         self.primitives.push(Primitive::Fill {
             path,
@@ -131,6 +142,7 @@ impl geometry::frame::Backend for Frame {
 
         let mut paint = tiny_skia::Paint {
             anti_alias: false,
+            blend_mode: engine::into_blend_mode(fill.blend),
             ..into_paint(fill.style)
         };
         paint.shader.transform(self.transform);
@@ -143,17 +155,34 @@ impl geometry::frame::Backend for Frame {
     }
 
     fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        let stroke = stroke.into();
+
+        let simplified;
+        let path = match stroke.level_of_detail {
+            Some(tolerance) => {
+                let transform = self.transform;
+                let scale_factor = (transform.sx * transform.sy
+                    - transform.kx * transform.ky)
+                    .abs()
+                    .sqrt()
+                    .max(f32::EPSILON);
+
+                simplified = path.simplify(tolerance / scale_factor);
+                &simplified
+            }
+            None => path,
+        };
+
         let Some(path) =
             convert_path(path).and_then(|path| path.transform(self.transform))
         else {
             return;
         };
-
-        let stroke = stroke.into();
         let skia_stroke = into_stroke(&stroke);
 
         let mut paint = into_paint(stroke.style);
         paint.shader.transform(self.transform);
+        paint.blend_mode = engine::into_blend_mode(stroke.blend);
 
         self.primitives.push(Primitive::Stroke {
             path,
@@ -218,6 +247,7 @@ impl geometry::frame::Backend for Frame {
                 font: text.font,
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
+                max_space_factor: crate::core::text::DEFAULT_MAX_SPACE_FACTOR,
                 shaping: text.shaping,
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             });
@@ -226,6 +256,38 @@ impl geometry::frame::Backend for Frame {
         }
     }
 
+    fn draw_image(&mut self, bounds: Rectangle, handle: image::Handle) {
+        let (bounds, rotation) = transform_image(self.transform, bounds);
+
+        let image = Image::Raster {
+            handle,
+            filter_method: image::FilterMethod::default(),
+            bounds,
+            rotation,
+            opacity: 1.0,
+        };
+
+        if image.bounds().intersects(&self.clip_bounds) {
+            self.images.push((image, self.clip_bounds));
+        }
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, handle: svg::Handle) {
+        let (bounds, rotation) = transform_image(self.transform, bounds);
+
+        let svg = Image::Vector {
+            handle,
+            color: None,
+            bounds,
+            rotation,
+            opacity: 1.0,
+        };
+
+        if svg.bounds().intersects(&self.clip_bounds) {
+            self.images.push((svg, self.clip_bounds));
+        }
+    }
+
     fn push_transform(&mut self) {
         self.stack.push(self.transform);
     }
@@ -241,6 +303,7 @@ impl geometry::frame::Backend for Frame {
     fn paste(&mut self, frame: Self, _at: Point) {
         self.primitives.extend(frame.primitives);
         self.text.extend(frame.text);
+        self.images.extend(frame.images);
     }
 
     fn translate(&mut self, translation: Vector) {
@@ -270,11 +333,53 @@ impl geometry::frame::Backend for Frame {
         Geometry::Live {
             primitives: self.primitives,
             text: self.text,
+            images: self.images,
             clip_bounds: self.clip_bounds,
         }
     }
 }
 
+/// Transforms the bounds of an image; returning the bounds and the rotation
+/// around their center of the transformed image.
+///
+/// Only translation, scale, and rotation can be represented; skews and
+/// reflections are dropped.
+fn transform_image(
+    transform: tiny_skia::Transform,
+    bounds: Rectangle,
+) -> (Rectangle, Radians) {
+    let mut center = [tiny_skia::Point {
+        x: bounds.center_x(),
+        y: bounds.center_y(),
+    }];
+
+    transform.map_points(&mut center);
+
+    let scale_x = transform.sx.hypot(transform.ky);
+    let scale_y = transform.kx.hypot(transform.sy);
+
+    let size = Size::new(bounds.width * scale_x, bounds.height * scale_y);
+
+    (
+        Rectangle::new(
+            Point::new(
+                center[0].x - size.width / 2.0,
+                center[0].y - size.height / 2.0,
+            ),
+            size,
+        ),
+        Radians(transform.ky.atan2(transform.sx)),
+    )
+}
+
+/// Converts a clip [`Path`] with the given [`Transformation`] applied.
+pub fn clip_path(
+    path: &Path,
+    transformation: Transformation,
+) -> Option<tiny_skia::Path> {
+    convert_path(path)?.transform(engine::into_transform(transformation))
+}
+
 fn convert_path(path: &Path) -> Option<tiny_skia::Path> {
     use iced_graphics::geometry::path::lyon_path;
 
@@ -342,10 +447,9 @@ fn convert_path(path: &Path) -> Option<tiny_skia::Path> {
 pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
     tiny_skia::Paint {
         shader: match style {
-            Style::Solid(color) => tiny_skia::Shader::SolidColor(
-                tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
-                    .expect("Create color"),
-            ),
+            Style::Solid(color) => {
+                tiny_skia::Shader::SolidColor(engine::into_color(color))
+            }
             Style::Gradient(gradient) => match gradient {
                 Gradient::Linear(linear) => {
                     let stops: Vec<tiny_skia::GradientStop> = linear
@@ -355,13 +459,7 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
                         .map(|stop| {
                             tiny_skia::GradientStop::new(
                                 stop.offset,
-                                tiny_skia::Color::from_rgba(
-                                    stop.color.b,
-                                    stop.color.g,
-                                    stop.color.r,
-                                    stop.color.a,
-                                )
-                                .expect("Create color"),
+                                engine::into_color(stop.color),
                             )
                         })
                         .collect();
@@ -418,9 +516,16 @@ pub fn into_stroke(stroke: &Stroke<'_>) -> tiny_skia::Stroke {
         dash: if stroke.line_dash.segments.is_empty() {
             None
         } else {
+            let segments = stroke.line_dash.segments;
+
+            // An odd pattern is repeated, so dashes and gaps alternate
             tiny_skia::StrokeDash::new(
-                stroke.line_dash.segments.into(),
-                stroke.line_dash.offset as f32,
+                if segments.len() % 2 == 1 {
+                    [segments, segments].concat()
+                } else {
+                    segments.to_vec()
+                },
+                stroke.line_dash.offset,
             )
         },
         ..Default::default()
@@ -2,19 +2,24 @@ use crate::core::{
     image, renderer::Quad, svg, Background, Color, Point, Radians, Rectangle,
     Transformation,
 };
+use crate::engine;
+use crate::graphics::color;
 use crate::graphics::damage;
 use crate::graphics::layer;
 use crate::graphics::text::{Editor, Paragraph, Text};
-use crate::graphics::{self, Image};
+use crate::graphics::{self, Image, Mesh};
 use crate::Primitive;
 
 use std::rc::Rc;
+use std::sync::Once;
 
 pub type Stack = layer::Stack<Layer>;
 
 #[derive(Debug, Clone)]
 pub struct Layer {
     pub bounds: Rectangle,
+    /// The clip paths enclosing the layer, in logical coordinates.
+    pub clip: Rc<[tiny_skia::Path]>,
     pub quads: Vec<(Quad, Background)>,
     pub primitives: Vec<Item<Primitive>>,
     pub text: Vec<Item<Text>>,
@@ -22,6 +27,18 @@ pub struct Layer {
 }
 
 impl Layer {
+    /// Returns whether anything in the [`Layer`] is drawn over the
+    /// primitives added to it later.
+    pub fn covers_primitives(&self) -> bool {
+        !self.text.is_empty() || self.covers_text()
+    }
+
+    /// Returns whether anything in the [`Layer`] is drawn over the text
+    /// added to it later.
+    pub fn covers_text(&self) -> bool {
+        !self.images.is_empty()
+    }
+
     pub fn draw_quad(
         &mut self,
         mut quad: Quad,
@@ -78,6 +95,8 @@ impl Layer {
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
+        let font = text.font_with_features();
+
         let text = Text::Cached {
             content: text.content,
             bounds: Rectangle::new(position, text.bounds) * transformation,
@@ -85,9 +104,10 @@ impl Layer {
             size: text.size * transformation.scale_factor(),
             line_height: text.line_height.to_absolute(text.size)
                 * transformation.scale_factor(),
-            font: text.font,
+            font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            max_space_factor: text.max_space_factor,
             shaping: text.shaping,
             clip_bounds: clip_bounds * transformation,
         };
@@ -155,6 +175,20 @@ impl Layer {
         self.images.push(svg);
     }
 
+    pub fn draw_mesh(&mut self, mesh: Mesh, transformation: Transformation) {
+        let primitives = into_primitives(&mesh);
+
+        if primitives.is_empty() {
+            return;
+        }
+
+        self.draw_primitive_group(
+            primitives,
+            mesh.clip_bounds() * transformation,
+            transformation * mesh.transformation(),
+        );
+    }
+
     pub fn draw_primitive_group(
         &mut self,
         primitives: Vec<Primitive>,
@@ -186,6 +220,10 @@ impl Layer {
             return vec![previous.bounds, current.bounds];
         }
 
+        if previous.clip != current.clip {
+            return vec![current.bounds];
+        }
+
         let mut damage = damage::list(
             &previous.quads,
             &current.quads,
@@ -275,6 +313,7 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            clip: Rc::from([]),
             quads: Vec::new(),
             primitives: Vec::new(),
             text: Vec::new(),
@@ -299,6 +338,7 @@ impl graphics::Layer for Layer {
 
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
+        self.clip = Rc::from([]);
 
         self.quads.clear();
         self.primitives.clear();
@@ -339,3 +379,78 @@ impl<T> Item<T> {
         }
     }
 }
+
+/// Converts the triangles of a [`Mesh`] into filled paths; merging the
+/// consecutive triangles that share the same color.
+///
+/// The color of a triangle is the average of the colors of its vertices.
+fn into_primitives(mesh: &Mesh) -> Vec<Primitive> {
+    let Mesh::Solid { buffers, blend, .. } = mesh else {
+        static WARNING: Once = Once::new();
+
+        WARNING.call_once(|| {
+            log::warn!(
+                "iced_tiny_skia does not support drawing gradient meshes"
+            );
+        });
+
+        return Vec::new();
+    };
+
+    let fill = |builder: tiny_skia::PathBuilder, color| {
+        Some(Primitive::Fill {
+            path: builder.finish()?,
+            paint: tiny_skia::Paint {
+                shader: tiny_skia::Shader::SolidColor(engine::into_color(
+                    color,
+                )),
+                anti_alias: true,
+                blend_mode: engine::into_blend_mode(*blend),
+                ..tiny_skia::Paint::default()
+            },
+            rule: tiny_skia::FillRule::Winding,
+        })
+    };
+
+    let mut primitives = Vec::new();
+    let mut builder = tiny_skia::PathBuilder::new();
+    let mut current = None;
+
+    for triangle in buffers.indices.chunks_exact(3) {
+        let [Some(a), Some(b), Some(c)] =
+            [0, 1, 2].map(|i| buffers.vertices.get(triangle[i] as usize))
+        else {
+            continue;
+        };
+
+        let [red, green, blue, alpha] = [a, b, c]
+            .map(|vertex| color::unpack(vertex.color))
+            .into_iter()
+            .fold([0.0; 4], |[r, g, b, alpha], color| {
+                [r + color.r, g + color.g, b + color.b, alpha + color.a]
+            })
+            .map(|component| component / 3.0);
+
+        let color = Color::from_rgba(red, green, blue, alpha);
+
+        if let Some(previous) = current.filter(|previous| *previous != color) {
+            let builder =
+                std::mem::replace(&mut builder, tiny_skia::PathBuilder::new());
+
+            primitives.extend(fill(builder, previous));
+        }
+
+        current = Some(color);
+
+        builder.move_to(a.position[0], a.position[1]);
+        builder.line_to(b.position[0], b.position[1]);
+        builder.line_to(c.position[0], c.position[1]);
+        builder.close();
+    }
+
+    if let Some(color) = current {
+        primitives.extend(fill(builder, color));
+    }
+
+    primitives
+}
@@ -33,9 +33,12 @@ use crate::core::{
 };
 use crate::engine::Engine;
 use crate::graphics::compositor;
+use crate::graphics::snapshot;
 use crate::graphics::text::{Editor, Paragraph};
 use crate::graphics::Viewport;
 
+use std::rc::Rc;
+
 /// A [`tiny-skia`] graphics renderer for [`iced`].
 ///
 /// [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
@@ -45,6 +48,9 @@ pub struct Renderer {
     default_font: Font,
     default_text_size: Pixels,
     layers: layer::Stack,
+    clips: Vec<Rc<[tiny_skia::Path]>>,
+    filter_methods: Vec<core::image::FilterMethod>,
+    snapshots: snapshot::Recorder,
     engine: Engine, // TODO: Shared engine
 }
 
@@ -54,6 +60,9 @@ impl Renderer {
             default_font,
             default_text_size,
             layers: layer::Stack::new(),
+            clips: Vec::new(),
+            filter_methods: Vec::new(),
+            snapshots: snapshot::Recorder::new(),
             engine: Engine::new(),
         }
     }
@@ -142,6 +151,13 @@ impl Renderer {
                 };
 
                 engine::adjust_clip_mask(clip_mask, clip_bounds);
+                engine::intersect_clip_mask(
+                    clip_mask,
+                    &layer.clip,
+                    scale_factor,
+                );
+
+                self.engine.clip_path = !layer.clip.is_empty();
 
                 for (quad, background) in &layer.quads {
                     self.engine.draw_quad(
@@ -163,6 +179,11 @@ impl Renderer {
                     };
 
                     engine::adjust_clip_mask(clip_mask, new_clip_bounds);
+                    engine::intersect_clip_mask(
+                        clip_mask,
+                        &layer.clip,
+                        scale_factor,
+                    );
 
                     for primitive in group.as_slice() {
                         self.engine.draw_primitive(
@@ -176,6 +197,11 @@ impl Renderer {
                     }
 
                     engine::adjust_clip_mask(clip_mask, clip_bounds);
+                    engine::intersect_clip_mask(
+                        clip_mask,
+                        &layer.clip,
+                        scale_factor,
+                    );
                 }
 
                 for group in &layer.text {
@@ -222,38 +248,221 @@ impl Renderer {
             }
         }
 
+        self.engine.clip_path = false;
         self.engine.trim();
     }
 }
 
+impl Renderer {
+    /// Draws the given snapshot commands.
+    fn replay(
+        &mut self,
+        commands: impl IntoIterator<Item = snapshot::Command>,
+    ) {
+        use crate::core::text::Renderer as _;
+        use crate::core::Renderer as _;
+        use crate::graphics::mesh::Renderer as _;
+
+        for command in commands {
+            match command {
+                snapshot::Command::StartLayer(bounds) => {
+                    self.start_layer(bounds);
+                }
+                snapshot::Command::EndLayer => self.end_layer(),
+                snapshot::Command::StartTransformation(transformation) => {
+                    self.start_transformation(transformation);
+                }
+                snapshot::Command::EndTransformation => {
+                    self.end_transformation();
+                }
+                snapshot::Command::StartFilterMethod(filter_method) => {
+                    self.start_filter_method(filter_method);
+                }
+                snapshot::Command::EndFilterMethod => self.end_filter_method(),
+                snapshot::Command::Quad(quad, background) => {
+                    self.fill_quad(quad, background);
+                }
+                snapshot::Command::Paragraph {
+                    paragraph,
+                    position,
+                    color,
+                    clip_bounds,
+                } => {
+                    self.fill_paragraph(
+                        &paragraph,
+                        position,
+                        color,
+                        clip_bounds,
+                    );
+                }
+                snapshot::Command::Text {
+                    text,
+                    position,
+                    color,
+                    clip_bounds,
+                } => {
+                    self.fill_text(text, position, color, clip_bounds);
+                }
+                #[cfg(feature = "image")]
+                snapshot::Command::Image {
+                    handle,
+                    filter_method,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    core::image::Renderer::draw_image(
+                        self,
+                        handle,
+                        filter_method,
+                        bounds,
+                        rotation,
+                        opacity,
+                    );
+                }
+                #[cfg(feature = "svg")]
+                snapshot::Command::Svg {
+                    handle,
+                    color_filter,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    core::svg::Renderer::draw_svg(
+                        self,
+                        handle,
+                        color_filter,
+                        bounds,
+                        rotation,
+                        opacity,
+                    );
+                }
+                #[cfg(not(feature = "image"))]
+                snapshot::Command::Image { .. } => {}
+                #[cfg(not(feature = "svg"))]
+                snapshot::Command::Svg { .. } => {}
+                snapshot::Command::Mesh(mesh) => self.draw_mesh(mesh),
+            }
+        }
+    }
+
+    /// Interrupts the snapshots in progress; drawing what they recorded so
+    /// far right away.
+    fn interrupt_snapshots(&mut self) {
+        let commands = self.snapshots.interrupt();
+
+        self.replay(commands);
+    }
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::StartLayer(bounds));
+            return;
+        }
+
         self.layers.push_clip(bounds);
+
+        // Nested layers are clipped by the paths enclosing them
+        if let Some(clip) = self.clips.last() {
+            self.layers.current_mut().0.clip = clip.clone();
+        }
     }
 
     fn end_layer(&mut self) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::EndLayer);
+            return;
+        }
+
         self.layers.pop_clip();
     }
 
     fn start_transformation(&mut self, transformation: Transformation) {
+        if self.snapshots.is_recording() {
+            self.snapshots
+                .record(snapshot::Command::StartTransformation(transformation));
+            return;
+        }
+
         self.layers.push_transformation(transformation);
     }
 
     fn end_transformation(&mut self) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::EndTransformation);
+            return;
+        }
+
         self.layers.pop_transformation();
     }
 
+    fn start_filter_method(
+        &mut self,
+        filter_method: core::image::FilterMethod,
+    ) {
+        if self.snapshots.is_recording() {
+            self.snapshots
+                .record(snapshot::Command::StartFilterMethod(filter_method));
+            return;
+        }
+
+        self.filter_methods.push(filter_method);
+    }
+
+    fn end_filter_method(&mut self) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::EndFilterMethod);
+            return;
+        }
+
+        let _ = self.filter_methods.pop();
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
         background: impl Into<Background>,
     ) {
+        let background = background.into();
+
+        if self.snapshots.is_recording() {
+            self.snapshots
+                .record(snapshot::Command::Quad(quad, background));
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+        layer.draw_quad(quad, background, transformation);
+    }
+
+    fn start_snapshot(&mut self) {
+        self.snapshots.start();
+    }
+
+    fn end_snapshot(&mut self) -> Option<renderer::Snapshot> {
+        self.snapshots.end().map(renderer::Snapshot::new)
+    }
+
+    fn draw_snapshot(&mut self, snapshot: &renderer::Snapshot, opacity: f32) {
+        let Some(commands) = snapshot.downcast_ref::<Vec<snapshot::Command>>()
+        else {
+            return;
+        };
+
+        self.replay(
+            commands
+                .iter()
+                .cloned()
+                .map(|command| command.scale_alpha(opacity)),
+        );
     }
 
     fn clear(&mut self) {
         self.layers.clear();
+        self.snapshots.clear();
+        self.clips.clear();
     }
 }
 
@@ -281,6 +490,16 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Paragraph {
+                paragraph: text.clone(),
+                position,
+                color,
+                clip_bounds,
+            });
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
 
         layer.draw_paragraph(
@@ -299,17 +518,39 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        self.interrupt_snapshots();
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_editor(editor, position, color, clip_bounds, transformation);
     }
 
     fn fill_text(
         &mut self,
-        text: core::Text,
+        text: impl Into<core::text::Fill<'_, Self::Paragraph>>,
         position: Point,
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        use crate::core::text::Fill;
+
+        let text = match text.into() {
+            Fill::Text(text) => text,
+            Fill::Cached(paragraph) => {
+                self.fill_paragraph(paragraph, position, color, clip_bounds);
+                return;
+            }
+        };
+
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Text {
+                text,
+                position,
+                color,
+                clip_bounds,
+            });
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_text(text, position, color, clip_bounds, transformation);
     }
@@ -324,13 +565,45 @@ impl graphics::geometry::Renderer for Renderer {
         geometry::Frame::new(size)
     }
 
+    fn start_clip(&mut self, path: &graphics::geometry::Path) {
+        self.interrupt_snapshots();
+
+        use crate::core::Renderer as _;
+
+        let paths = self.clips.last().map(|clip| &clip[..]).unwrap_or(&[]);
+        let converted = geometry::clip_path(path, self.layers.transformation());
+
+        // Degenerate paths only clip to their bounds
+        let clip = match converted {
+            Some(clip_path) => {
+                paths.iter().cloned().chain(Some(clip_path)).collect()
+            }
+            None => Rc::from(paths),
+        };
+
+        self.clips.push(clip);
+        self.start_layer(path.bounds());
+    }
+
+    fn end_clip(&mut self) {
+        self.interrupt_snapshots();
+
+        use crate::core::Renderer as _;
+
+        self.end_layer();
+        let _ = self.clips.pop();
+    }
+
     fn draw_geometry(&mut self, geometry: Self::Geometry) {
+        self.interrupt_snapshots();
+
         let (layer, transformation) = self.layers.current_mut();
 
         match geometry {
             Geometry::Live {
                 primitives,
                 text,
+                images,
                 clip_bounds,
             } => {
                 layer.draw_primitive_group(
@@ -340,6 +613,8 @@ impl graphics::geometry::Renderer for Renderer {
                 );
 
                 layer.draw_text_group(text, clip_bounds, transformation);
+
+                self.draw_geometry_images(&images);
             }
             Geometry::Cache(cache) => {
                 layer.draw_primitive_cache(
@@ -353,14 +628,116 @@ impl graphics::geometry::Renderer for Renderer {
                     cache.clip_bounds,
                     transformation,
                 );
+
+                self.draw_geometry_images(&cache.images);
+            }
+        }
+    }
+
+    fn draw_geometry_over(&mut self, geometry: Self::Geometry) {
+        self.interrupt_snapshots();
+
+        let (has_primitives, has_text) = match &geometry {
+            Geometry::Live {
+                primitives, text, ..
+            } => (!primitives.is_empty(), !text.is_empty()),
+            Geometry::Cache(cache) => {
+                (!cache.primitives.is_empty(), !cache.text.is_empty())
+            }
+        };
+
+        let (layer, _) = self.layers.current_mut();
+
+        if has_primitives && layer.covers_primitives()
+            || has_text && layer.covers_text()
+        {
+            // The new layer must be clipped by the same paths
+            let clip = layer.clip.clone();
+
+            self.layers.split();
+            self.layers.current_mut().0.clip = clip;
+        }
+
+        self.draw_geometry(geometry);
+    }
+}
+
+#[cfg(feature = "geometry")]
+impl Renderer {
+    /// Draws the images of some geometry; each in a layer of its own if it
+    /// overflows its clip bounds.
+    fn draw_geometry_images(
+        &mut self,
+        images: &[(graphics::Image, Rectangle)],
+    ) {
+        use crate::core::Renderer as _;
+
+        for (image, clip_bounds) in images {
+            let is_clipped = !image.bounds().is_within(clip_bounds);
+
+            if is_clipped {
+                self.start_layer(*clip_bounds);
+            }
+
+            let (layer, transformation) = self.layers.current_mut();
+
+            match image.clone() {
+                graphics::Image::Raster {
+                    handle,
+                    filter_method,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    let filter_method = self
+                        .filter_methods
+                        .last()
+                        .copied()
+                        .unwrap_or(filter_method);
+
+                    layer.draw_image(
+                        handle,
+                        filter_method,
+                        bounds,
+                        transformation,
+                        rotation,
+                        opacity,
+                    );
+                }
+                graphics::Image::Vector {
+                    handle,
+                    color,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    layer.draw_svg(
+                        handle,
+                        color,
+                        bounds,
+                        transformation,
+                        rotation,
+                        opacity,
+                    );
+                }
+            }
+
+            if is_clipped {
+                self.end_layer();
             }
         }
     }
 }
 
 impl graphics::mesh::Renderer for Renderer {
-    fn draw_mesh(&mut self, _mesh: graphics::Mesh) {
-        log::warn!("iced_tiny_skia does not support drawing meshes");
+    fn draw_mesh(&mut self, mesh: graphics::Mesh) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Mesh(mesh));
+            return;
+        }
+
+        let (layer, transformation) = self.layers.current_mut();
+        layer.draw_mesh(mesh, transformation);
     }
 }
 
@@ -380,6 +757,20 @@ impl core::image::Renderer for Renderer {
         rotation: core::Radians,
         opacity: f32,
     ) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Image {
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity,
+            });
+            return;
+        }
+
+        let filter_method =
+            self.filter_methods.last().copied().unwrap_or(filter_method);
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_image(
             handle,
@@ -409,6 +800,17 @@ impl core::svg::Renderer for Renderer {
         rotation: core::Radians,
         opacity: f32,
     ) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Svg {
+                handle,
+                color_filter: color,
+                bounds,
+                rotation,
+                opacity,
+            });
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_svg(
             handle,
@@ -19,6 +19,10 @@ impl Pipeline {
     }
 
     pub fn dimensions(&self, handle: &raster::Handle) -> Size<u32> {
+        if let raster::Handle::Compressed(_, compressed) = handle {
+            return compressed.size();
+        }
+
         if let Some(image) = self.cache.borrow_mut().allocate(handle) {
             Size::new(image.width(), image.height())
         } else {
@@ -84,8 +88,34 @@ impl Cache {
     ) -> Option<tiny_skia::PixmapRef<'_>> {
         let id = handle.id();
 
+        // Dynamic images are loaded again whenever their pixels change
+        let version = match handle {
+            raster::Handle::Dynamic(_, dynamic) => dynamic.version(),
+            _ => 0,
+        };
+
+        if let Some(Some(entry)) = self.entries.get(&id) {
+            if entry.version != version {
+                let _ = self.entries.remove(&id);
+            }
+        }
+
         if let hash_map::Entry::Vacant(entry) = self.entries.entry(id) {
-            let image = graphics::image::load(handle).ok()?;
+            let image = match graphics::image::load(handle) {
+                Ok(image) => image,
+                Err(error) => {
+                    // Compressed images can never be loaded; so they are
+                    // rejected only once
+                    if let raster::Handle::Compressed(..) = handle {
+                        log::error!("Cannot draw compressed image: {error}");
+
+                        let _ = entry.insert(None);
+                        let _ = self.hits.insert(id);
+                    }
+
+                    return None;
+                }
+            };
 
             let mut buffer =
                 vec![0u32; image.width() as usize * image.height() as usize];
@@ -102,6 +132,7 @@ impl Cache {
                 width: image.width(),
                 height: image.height(),
                 pixels: buffer,
+                version,
             }));
         }
 
@@ -127,4 +158,5 @@ struct Entry {
     width: u32,
     height: u32,
     pixels: Vec<u32>,
+    version: u64,
 }
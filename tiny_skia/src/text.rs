@@ -110,6 +110,7 @@ impl Pipeline {
         font: Font,
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
+        max_space_factor: f32,
         shaping: Shaping,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
@@ -118,6 +119,10 @@ impl Pipeline {
         let line_height = f32::from(line_height);
 
         let mut font_system = font_system().write().expect("Write font system");
+        let cache = self.cache.get_mut();
+
+        let _ = cache.clear_if_outdated(font_system.version());
+
         let font_system = font_system.raw();
 
         let key = cache::Key {
@@ -126,10 +131,12 @@ impl Pipeline {
             font,
             size: size.into(),
             line_height,
+            horizontal_alignment,
+            max_space_factor,
             shaping,
         };
 
-        let (_, entry) = self.cache.get_mut().allocate(font_system, key);
+        let (_, entry) = cache.allocate(font_system, key);
 
         let width = entry.min_bounds.width;
         let height = entry.min_bounds.height;
@@ -205,10 +212,14 @@ fn draw(
 ) {
     let bounds = bounds * transformation;
 
-    let x = match horizontal_alignment {
-        alignment::Horizontal::Left => bounds.x,
+    let x = match horizontal_alignment.resolve() {
+        alignment::Horizontal::Left
+        | alignment::Horizontal::Start
+        | alignment::Horizontal::Justify => bounds.x,
         alignment::Horizontal::Center => bounds.x - bounds.width / 2.0,
-        alignment::Horizontal::Right => bounds.x - bounds.width,
+        alignment::Horizontal::Right | alignment::Horizontal::End => {
+            bounds.x - bounds.width
+        }
     };
 
     let y = match vertical_alignment {
@@ -1,10 +1,12 @@
 use crate::core::svg::{Data, Handle};
 use crate::core::{Color, Rectangle, Size};
+use crate::graphics;
 
 use resvg::usvg;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tiny_skia::Transform;
 
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::hash_map;
 use std::fs;
@@ -66,6 +68,8 @@ impl Pipeline {
 struct Cache {
     trees: FxHashMap<u64, Option<resvg::usvg::Tree>>,
     tree_hits: FxHashSet<u64>,
+    tinted: FxHashMap<(u64, [u8; 4]), Option<resvg::usvg::Tree>>,
+    tinted_hits: FxHashSet<(u64, [u8; 4])>,
     rasters: FxHashMap<RasterKey, tiny_skia::Pixmap>,
     raster_hits: FxHashSet<RasterKey>,
 }
@@ -108,6 +112,40 @@ impl Cache {
         self.trees.get(&id).unwrap().as_ref()
     }
 
+    /// Loads the tree of the svg with its `currentColor` keywords substituted
+    /// by the given color; `None` if it does not use any.
+    fn load_tinted(
+        &mut self,
+        handle: &Handle,
+        color: [u8; 4],
+    ) -> Option<&usvg::Tree> {
+        let key = (handle.id(), color);
+
+        if let hash_map::Entry::Vacant(entry) = self.tinted.entry(key) {
+            let [r, g, b, a] = color;
+
+            let svg = source(handle.data())
+                .and_then(|source| {
+                    graphics::image::substitute_current_color(
+                        &source,
+                        Color::from_rgba8(r, g, b, f32::from(a) / 255.0),
+                    )
+                })
+                .and_then(|tinted| {
+                    usvg::Tree::from_str(
+                        &tinted,
+                        &usvg::Options::default(), // TODO: Set usvg::Options::fontdb
+                    )
+                    .ok()
+                });
+
+            let _ = entry.insert(svg);
+        }
+
+        let _ = self.tinted_hits.insert(key);
+        self.tinted.get(&key).unwrap().as_ref()
+    }
+
     fn viewport_dimensions(&mut self, handle: &Handle) -> Option<Size<u32>> {
         let tree = self.load(handle)?;
         let size = tree.size();
@@ -133,7 +171,17 @@ impl Cache {
 
         #[allow(clippy::map_entry)]
         if !self.rasters.contains_key(&key) {
-            let tree = self.load(handle)?;
+            // Icons that use `currentColor` are tinted before rasterization;
+            // any other icon has the color filter applied to all of its pixels
+            let is_tinted = key
+                .color
+                .is_some_and(|color| self.load_tinted(handle, color).is_some());
+
+            let tree = if is_tinted {
+                self.tinted.get(&(key.id, key.color?))?.as_ref()?
+            } else {
+                self.load(handle)?
+            };
 
             let mut image = tiny_skia::Pixmap::new(size.width, size.height)?;
 
@@ -159,7 +207,7 @@ impl Cache {
 
             resvg::render(tree, transform, &mut image.as_mut());
 
-            if let Some([r, g, b, _]) = key.color {
+            if let Some([r, g, b, _]) = key.color.filter(|_| !is_tinted) {
                 // Apply color filter
                 for pixel in
                     bytemuck::cast_slice_mut::<u8, u32>(image.data_mut())
@@ -188,23 +236,40 @@ impl Cache {
             let _ = self.rasters.insert(key, image);
         }
 
+        if let Some(color) = key.color {
+            let _ = self.tinted_hits.insert((key.id, color));
+        }
+
         let _ = self.raster_hits.insert(key);
         self.rasters.get(&key).map(tiny_skia::Pixmap::as_ref)
     }
 
     fn trim(&mut self) {
         self.trees.retain(|key, _| self.tree_hits.contains(key));
+        self.tinted.retain(|key, _| self.tinted_hits.contains(key));
         self.rasters.retain(|key, _| self.raster_hits.contains(key));
 
         self.tree_hits.clear();
+        self.tinted_hits.clear();
         self.raster_hits.clear();
     }
 }
 
+/// Returns the source of an svg, unless it is compressed.
+fn source(data: &Data) -> Option<Cow<'_, str>> {
+    match data {
+        Data::Path(path) => fs::read_to_string(path).ok().map(Cow::Owned),
+        Data::Bytes(bytes) => {
+            std::str::from_utf8(bytes).ok().map(Cow::Borrowed)
+        }
+    }
+}
+
 impl std::fmt::Debug for Cache {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Cache")
             .field("tree_hits", &self.tree_hits)
+            .field("tinted_hits", &self.tinted_hits)
             .field("rasters", &self.rasters)
             .field("raster_hits", &self.raster_hits)
             .finish_non_exhaustive()
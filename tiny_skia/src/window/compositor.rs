@@ -104,6 +104,7 @@ impl crate::graphics::Compositor for Compositor {
         Information {
             adapter: String::from("CPU"),
             backend: String::from("tiny-skia"),
+            formats: None,
         }
     }
 
@@ -126,6 +126,11 @@ impl<T: bytemuck::Pod> Buffer<T> {
         self.raw.slice(self.offset_at(index)..)
     }
 
+    /// Returns the size of the buffer in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
     /// Clears any temporary data (i.e. offsets) from the buffer.
     pub fn clear(&mut self) {
         self.offsets.clear();
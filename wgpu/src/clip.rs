@@ -0,0 +1,377 @@
+//! Clip layers to arbitrary paths using a stencil buffer.
+//!
+//! Every clip path increments the stencil buffer inside of its area before
+//! its layers are drawn, and decrements it afterwards. The layers inside
+//! of `n` clip paths are only drawn where the stencil buffer equals `n`;
+//! so nested clip paths intersect.
+use crate::core::{Size, Transformation};
+use crate::Buffer;
+
+use std::num::NonZeroU64;
+use std::ops::Range;
+
+/// The format of the stencil buffer.
+pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Stencil8;
+
+/// The maximum amount of clip paths that can be nested.
+#[cfg_attr(not(feature = "geometry"), allow(dead_code))]
+///
+/// Every level takes a value of the 8-bit stencil buffer. Deeper clip
+/// paths only clip their contents to their bounds.
+pub const MAX_DEPTH: usize = u8::MAX as usize;
+
+const INITIAL_VERTEX_COUNT: usize = 1_000;
+const INITIAL_INDEX_COUNT: usize = 1_000;
+
+/// The triangles covering the area of a clip path.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(not(feature = "geometry"), allow(dead_code))]
+pub struct Mask {
+    pub vertices: Vec<[f32; 2]>,
+    pub indices: Vec<u32>,
+}
+
+/// A [`Mask`] clipping a range of layers.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "geometry"), allow(dead_code))]
+pub struct Clip {
+    pub layers: Range<usize>,
+    pub mask: Mask,
+}
+
+/// Returns the state of the pipelines that draw inside of clip paths; which
+/// only draw where the stencil buffer matches the reference of the pass.
+pub fn stencil() -> wgpu::DepthStencilState {
+    state(wgpu::StencilOperation::Keep)
+}
+
+/// Returns the attachment of the given stencil buffer for a render pass
+/// that keeps its contents.
+pub fn attachment(
+    view: &wgpu::TextureView,
+) -> wgpu::RenderPassDepthStencilAttachment<'_> {
+    wgpu::RenderPassDepthStencilAttachment {
+        view,
+        depth_ops: None,
+        stencil_ops: Some(wgpu::Operations {
+            load: wgpu::LoadOp::Load,
+            store: wgpu::StoreOp::Store,
+        }),
+    }
+}
+
+fn state(pass_op: wgpu::StencilOperation) -> wgpu::DepthStencilState {
+    let face = wgpu::StencilFaceState {
+        compare: wgpu::CompareFunction::Equal,
+        fail_op: wgpu::StencilOperation::Keep,
+        depth_fail_op: wgpu::StencilOperation::Keep,
+        pass_op,
+    };
+
+    wgpu::DepthStencilState {
+        format: FORMAT,
+        depth_write_enabled: false,
+        depth_compare: wgpu::CompareFunction::Always,
+        stencil: wgpu::StencilState {
+            front: face,
+            back: face,
+            read_mask: !0,
+            write_mask: !0,
+        },
+        bias: wgpu::DepthBiasState::default(),
+    }
+}
+
+/// The stencil buffer of a frame.
+#[derive(Debug, Default)]
+pub struct Target {
+    view: Option<wgpu::TextureView>,
+    size: Size<u32>,
+}
+
+impl Target {
+    /// Makes sure the stencil buffer has the given size.
+    pub fn prepare(&mut self, device: &wgpu::Device, size: Size<u32>) {
+        if self.view.is_some() && self.size == size {
+            return;
+        }
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::clip stencil texture"),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+
+        self.view =
+            Some(texture.create_view(&wgpu::TextureViewDescriptor::default()));
+        self.size = size;
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        self.view.as_ref().expect("Prepared stencil buffer")
+    }
+
+    /// Returns the amount of bytes allocated by the stencil buffer.
+    pub fn memory_usage(&self) -> u64 {
+        if self.view.is_some() {
+            u64::from(self.size.width) * u64::from(self.size.height)
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Pipeline {
+    push: wgpu::RenderPipeline,
+    pop: wgpu::RenderPipeline,
+    uniforms: wgpu::Buffer,
+    constants: wgpu::BindGroup,
+    vertices: Buffer<[f32; 2]>,
+    indices: Buffer<u32>,
+    masks: Vec<(Range<u32>, i32)>,
+    target_size: Size<u32>,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::clip uniforms buffer"),
+            size: std::mem::size_of::<[f32; 16]>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::clip uniforms layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::clip uniforms bind group"),
+            layout: &constants_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniforms.as_entire_binding(),
+            }],
+        });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::clip pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constants_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu clip shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/clip.wgsl"),
+                )),
+            });
+
+        let create = |label, pass_op| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: std::mem::size_of::<[f32; 2]>() as u64,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array!(
+                            // Position
+                            0 => Float32x2,
+                        ),
+                    }],
+                },
+                // Masks only write to the stencil buffer
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::empty(),
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: Some(state(pass_op)),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+
+        Self {
+            push: create(
+                "iced_wgpu::clip push pipeline",
+                wgpu::StencilOperation::IncrementClamp,
+            ),
+            pop: create(
+                "iced_wgpu::clip pop pipeline",
+                wgpu::StencilOperation::DecrementClamp,
+            ),
+            uniforms,
+            constants,
+            vertices: Buffer::new(
+                device,
+                "iced_wgpu::clip vertex buffer",
+                INITIAL_VERTEX_COUNT,
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            ),
+            indices: Buffer::new(
+                device,
+                "iced_wgpu::clip index buffer",
+                INITIAL_INDEX_COUNT,
+                wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
+            ),
+            masks: Vec::new(),
+            target_size: Size::new(0, 0),
+        }
+    }
+
+    /// Uploads the masks of the given clips; all at once.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        clips: &[Clip],
+        transformation: Transformation,
+        target_size: Size<u32>,
+    ) {
+        self.masks.clear();
+        self.target_size = target_size;
+
+        if clips.is_empty() {
+            return;
+        }
+
+        let transform: [f32; 16] = transformation.into();
+
+        belt.write_buffer(
+            encoder,
+            &self.uniforms,
+            0,
+            NonZeroU64::new(std::mem::size_of::<[f32; 16]>() as u64)
+                .expect("Non-empty uniforms"),
+            device,
+        )
+        .copy_from_slice(bytemuck::bytes_of(&transform));
+
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for clip in clips {
+            let start = indices.len() as u32;
+
+            self.masks.push((
+                start..start + clip.mask.indices.len() as u32,
+                vertices.len() as i32,
+            ));
+
+            vertices.extend_from_slice(&clip.mask.vertices);
+            indices.extend_from_slice(&clip.mask.indices);
+        }
+
+        if indices.is_empty() {
+            return;
+        }
+
+        let _ = self.vertices.resize(device, vertices.len());
+        let _ = self.indices.resize(device, indices.len());
+
+        let _ = self.vertices.write(device, encoder, belt, 0, &vertices);
+        let _ = self.indices.write(device, encoder, belt, 0, &indices);
+    }
+
+    /// Adds the mask of the clip with the given index to the stencil buffer;
+    /// entering the given depth.
+    pub fn push<'a>(
+        &'a self,
+        clip: usize,
+        depth: u32,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) {
+        self.draw(&self.push, clip, depth - 1, render_pass);
+    }
+
+    /// Removes the mask of the clip with the given index from the stencil
+    /// buffer; leaving the given depth.
+    pub fn pop<'a>(
+        &'a self,
+        clip: usize,
+        depth: u32,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) {
+        self.draw(&self.pop, clip, depth, render_pass);
+    }
+
+    fn draw<'a>(
+        &'a self,
+        pipeline: &'a wgpu::RenderPipeline,
+        clip: usize,
+        reference: u32,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) {
+        let Some((indices, base_vertex)) = self.masks.get(clip) else {
+            return;
+        };
+
+        if indices.is_empty() {
+            return;
+        }
+
+        // Masks are not clipped by the bounds of any layer
+        render_pass.set_scissor_rect(
+            0,
+            0,
+            self.target_size.width,
+            self.target_size.height,
+        );
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, &self.constants, &[]);
+        render_pass.set_stencil_reference(reference);
+        render_pass.set_vertex_buffer(0, self.vertices.slice(..));
+        render_pass.set_index_buffer(
+            self.indices.slice(..),
+            wgpu::IndexFormat::Uint32,
+        );
+        render_pass.draw_indexed(indices.clone(), *base_vertex, 0..1);
+    }
+
+    /// Returns the amount of bytes allocated by the [`Pipeline`].
+    pub fn memory_usage(&self) -> u64 {
+        self.vertices.size() + self.indices.size()
+    }
+
+    /// Drops the masks of the current frame.
+    pub fn end_frame(&mut self) {
+        self.masks.clear();
+    }
+}
@@ -2,6 +2,38 @@ use std::borrow::Cow;
 
 use wgpu::util::DeviceExt;
 
+/// Returns true if the given [`wgpu::TextureFormat`] can store colors
+/// beyond the standard dynamic range.
+pub fn is_hdr(format: wgpu::TextureFormat) -> bool {
+    matches!(
+        format,
+        wgpu::TextureFormat::Rgba16Float
+            | wgpu::TextureFormat::Rgba32Float
+            | wgpu::TextureFormat::Rg11b10Float
+    )
+}
+
+/// Returns the shader part that defines the `tone_map` function used by
+/// the fragment shaders rendering to the given [`wgpu::TextureFormat`].
+///
+/// HDR formats keep colors as they are, while any other format brings
+/// emissive colors back into range before they get clamped. Formats lacking
+/// sRGB support also get their colors encoded into sRGB.
+pub fn tone_mapping(
+    format: wgpu::TextureFormat,
+) -> (&'static str, &'static str) {
+    if is_hdr(format) {
+        ("color/hdr.wgsl", include_str!("shader/color/hdr.wgsl"))
+    } else if crate::format::encodes_srgb(format) {
+        (
+            "color/sdr_srgb.wgsl",
+            include_str!("shader/color/sdr_srgb.wgsl"),
+        )
+    } else {
+        ("color/sdr.wgsl", include_str!("shader/color/sdr.wgsl"))
+    }
+}
+
 pub fn convert(
     device: &wgpu::Device,
     encoder: &mut wgpu::CommandEncoder,
@@ -1,9 +1,13 @@
 use crate::buffer;
+use crate::clip;
+use crate::core::{Font, Pixels};
 use crate::graphics::Antialiasing;
 use crate::primitive;
 use crate::quad;
+use crate::shader;
 use crate::text;
 use crate::triangle;
+use crate::warm_up;
 
 #[allow(missing_debug_implementations)]
 pub struct Engine {
@@ -13,6 +17,7 @@ pub struct Engine {
     pub(crate) quad_pipeline: quad::Pipeline,
     pub(crate) text_pipeline: text::Pipeline,
     pub(crate) triangle_pipeline: triangle::Pipeline,
+    pub(crate) clip_pipeline: clip::Pipeline,
     #[cfg(any(feature = "image", feature = "svg"))]
     pub(crate) image_pipeline: crate::image::Pipeline,
     pub(crate) primitive_storage: primitive::Storage,
@@ -30,12 +35,14 @@ impl Engine {
         let quad_pipeline = quad::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, antialiasing);
+        let clip_pipeline = clip::Pipeline::new(device, format);
 
         #[cfg(any(feature = "image", feature = "svg"))]
         let image_pipeline = {
             let backend = _adapter.get_info().backend;
+            let atlas_format = crate::format::atlas(_adapter);
 
-            crate::image::Pipeline::new(device, format, backend)
+            crate::image::Pipeline::new(device, format, atlas_format, backend)
         };
 
         Self {
@@ -50,6 +57,7 @@ impl Engine {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+            clip_pipeline,
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
@@ -66,6 +74,39 @@ impl Engine {
         self.image_pipeline.create_cache(device)
     }
 
+    /// Renders an offscreen frame exercising every built-in pipeline of the
+    /// [`Engine`]; including their multisampling and blend mode variants.
+    ///
+    /// Pipelines and their shaders may be compiled lazily by the driver, and
+    /// text needs its font system to be loaded. Warming up before the first
+    /// frame is presented takes these costs away from it.
+    pub fn warm_up(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        warm_up::primitives(self, device, queue);
+    }
+
+    /// Rasterizes the glyphs of the `charset` in the given [`Font`] and
+    /// sizes ahead of time, storing them in the glyph atlas of the
+    /// [`Engine`].
+    pub fn preload_glyphs(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        font: Font,
+        sizes: &[Pixels],
+        charset: &str,
+    ) {
+        warm_up::glyphs(self, device, queue, font, sizes, charset);
+    }
+
+    pub(crate) fn reload_shaders(&mut self, device: &wgpu::Device) {
+        if !shader::HOT_RELOAD {
+            return;
+        }
+
+        self.quad_pipeline.reload(device);
+        self.triangle_pipeline.reload(device);
+    }
+
     pub fn submit(
         &mut self,
         queue: &wgpu::Queue,
@@ -78,6 +119,7 @@ impl Engine {
         self.quad_pipeline.end_frame();
         self.text_pipeline.end_frame();
         self.triangle_pipeline.end_frame();
+        self.clip_pipeline.end_frame();
 
         #[cfg(any(feature = "image", feature = "svg"))]
         self.image_pipeline.end_frame();
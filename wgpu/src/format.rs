@@ -0,0 +1,70 @@
+//! Negotiate texture formats with the capabilities of an adapter.
+use crate::graphics::color;
+
+/// Returns true if the fragment shaders rendering to the given
+/// [`wgpu::TextureFormat`] must encode their output into sRGB on their own.
+///
+/// This happens when gamma correction is enabled, but the render target has
+/// no sRGB variant available; like the `Bgra8Unorm` surfaces of some Android
+/// devices and WebGL.
+pub fn encodes_srgb(format: wgpu::TextureFormat) -> bool {
+    color::GAMMA_CORRECTION
+        && !format.is_srgb()
+        && !crate::color::is_hdr(format)
+}
+
+/// Returns true if the given [`wgpu::TextureFormat`] can be used as a render
+/// target by the built-in pipelines; which need to blend into it.
+pub fn is_renderable(
+    adapter: &wgpu::Adapter,
+    format: wgpu::TextureFormat,
+) -> bool {
+    let features = adapter.get_texture_format_features(format);
+
+    features
+        .allowed_usages
+        .contains(wgpu::TextureUsages::RENDER_ATTACHMENT)
+        && features
+            .flags
+            .contains(wgpu::TextureFormatFeatureFlags::BLENDABLE)
+}
+
+/// Chooses the [`wgpu::TextureFormat`] of the image atlas.
+///
+/// If the adapter cannot sample an sRGB atlas with filtering, a linear one
+/// is used instead and the image shader decodes its texels.
+#[cfg(any(feature = "image", feature = "svg"))]
+pub fn atlas(adapter: &wgpu::Adapter) -> wgpu::TextureFormat {
+    if !color::GAMMA_CORRECTION {
+        return wgpu::TextureFormat::Rgba8Unorm;
+    }
+
+    let features = adapter
+        .get_texture_format_features(wgpu::TextureFormat::Rgba8UnormSrgb);
+
+    let is_supported = features.allowed_usages.contains(
+        wgpu::TextureUsages::COPY_DST
+            | wgpu::TextureUsages::COPY_SRC
+            | wgpu::TextureUsages::TEXTURE_BINDING,
+    ) && features
+        .flags
+        .contains(wgpu::TextureFormatFeatureFlags::FILTERABLE);
+
+    if is_supported {
+        wgpu::TextureFormat::Rgba8UnormSrgb
+    } else {
+        log::warn!(
+            "Rgba8UnormSrgb is not supported for images; \
+            falling back to Rgba8Unorm with decoding in shaders"
+        );
+
+        wgpu::TextureFormat::Rgba8Unorm
+    }
+}
+
+/// Returns true if the image shader must decode the texels of an atlas with
+/// the given [`wgpu::TextureFormat`] from sRGB on its own.
+#[cfg(any(feature = "image", feature = "svg"))]
+pub fn decodes_srgb(atlas: wgpu::TextureFormat) -> bool {
+    color::GAMMA_CORRECTION && !atlas.is_srgb()
+}
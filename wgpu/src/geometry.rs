@@ -1,7 +1,7 @@
 //! Build and draw geometry.
 use crate::core::text::LineHeight;
 use crate::core::{
-    Pixels, Point, Radians, Rectangle, Size, Transformation, Vector,
+    image, svg, Pixels, Point, Radians, Rectangle, Size, Transformation, Vector,
 };
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::color;
@@ -10,8 +10,8 @@ use crate::graphics::geometry::{
     self, LineCap, LineDash, LineJoin, Path, Stroke, Style,
 };
 use crate::graphics::gradient::{self, Gradient};
-use crate::graphics::mesh::{self, Mesh};
-use crate::graphics::{self, Text};
+use crate::graphics::mesh::{self, Blend, Mesh};
+use crate::graphics::{self, Image, Text};
 use crate::text;
 use crate::triangle;
 
@@ -19,10 +19,15 @@ use lyon::geom::euclid;
 use lyon::tessellation;
 
 use std::borrow::Cow;
+use std::rc::Rc;
 
 #[derive(Debug)]
 pub enum Geometry {
-    Live { meshes: Vec<Mesh>, text: Vec<Text> },
+    Live {
+        meshes: Vec<Mesh>,
+        text: Vec<Text>,
+        images: Vec<(Image, Rectangle)>,
+    },
     Cached(Cache),
 }
 
@@ -30,6 +35,8 @@ pub enum Geometry {
 pub struct Cache {
     pub meshes: Option<triangle::Cache>,
     pub text: Option<text::Cache>,
+    /// The images of the geometry, with their clip bounds.
+    pub images: Rc<[(Image, Rectangle)]>,
 }
 
 impl Cached for Geometry {
@@ -45,7 +52,11 @@ impl Cached for Geometry {
         previous: Option<Self::Cache>,
     ) -> Self::Cache {
         match self {
-            Self::Live { meshes, text } => {
+            Self::Live {
+                meshes,
+                text,
+                images,
+            } => {
                 if let Some(mut previous) = previous {
                     if let Some(cache) = &mut previous.meshes {
                         cache.update(meshes);
@@ -59,11 +70,14 @@ impl Cached for Geometry {
                         previous.text = text::Cache::new(group, text);
                     }
 
+                    previous.images = Rc::from(images);
+
                     previous
                 } else {
                     Cache {
                         meshes: triangle::Cache::new(meshes),
                         text: text::Cache::new(group, text),
+                        images: Rc::from(images),
                     }
                 }
             }
@@ -79,6 +93,7 @@ pub struct Frame {
     buffers: BufferStack,
     meshes: Vec<Mesh>,
     text: Vec<Text>,
+    images: Vec<(Image, Rectangle)>,
     transforms: Transforms,
     fill_tessellator: tessellation::FillTessellator,
     stroke_tessellator: tessellation::StrokeTessellator,
@@ -97,6 +112,7 @@ impl Frame {
             buffers: BufferStack::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            images: Vec::new(),
             transforms: Transforms {
                 previous: Vec::new(),
                 current: Transform(lyon::math::Transform::translation(
@@ -133,11 +149,11 @@ impl geometry::frame::Backend for Frame {
     }
 
     fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
-        let Fill { style, rule } = fill.into();
+        let Fill { style, rule, blend } = fill.into();
 
         let mut buffer = self
             .buffers
-            .get_fill(&self.transforms.current.transform_style(style));
+            .get_fill(&self.transforms.current.transform_style(style), blend);
 
         let options = tessellation::FillOptions::default()
             .with_fill_rule(into_fill_rule(rule));
@@ -166,11 +182,11 @@ impl geometry::frame::Backend for Frame {
         size: Size,
         fill: impl Into<Fill>,
     ) {
-        let Fill { style, rule } = fill.into();
+        let Fill { style, rule, blend } = fill.into();
 
         let mut buffer = self
             .buffers
-            .get_fill(&self.transforms.current.transform_style(style));
+            .get_fill(&self.transforms.current.transform_style(style), blend);
 
         let top_left = self
             .transforms
@@ -198,9 +214,10 @@ impl geometry::frame::Backend for Frame {
     fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
         let stroke = stroke.into();
 
-        let mut buffer = self
-            .buffers
-            .get_stroke(&self.transforms.current.transform_style(stroke.style));
+        let mut buffer = self.buffers.get_stroke(
+            &self.transforms.current.transform_style(stroke.style),
+            stroke.blend,
+        );
 
         let mut options = tessellation::StrokeOptions::default();
         options.line_width = stroke.width;
@@ -208,10 +225,18 @@ impl geometry::frame::Backend for Frame {
         options.end_cap = into_line_cap(stroke.line_cap);
         options.line_join = into_line_join(stroke.line_join);
 
+        let path =
+            match stroke.level_of_detail {
+                Some(tolerance) => Cow::Owned(path.simplify(
+                    tolerance / self.transforms.current.scale_factor(),
+                )),
+                None => Cow::Borrowed(path),
+            };
+
         let path = if stroke.line_dash.segments.is_empty() {
-            Cow::Borrowed(path)
+            path
         } else {
-            Cow::Owned(dashed(path, stroke.line_dash))
+            Cow::Owned(path.dash(stroke.line_dash))
         };
 
         if self.transforms.current.is_identity() {
@@ -279,6 +304,7 @@ impl geometry::frame::Backend for Frame {
                 font: text.font,
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
+                max_space_factor: crate::core::text::DEFAULT_MAX_SPACE_FACTOR,
                 shaping: text.shaping,
                 clip_bounds: self.clip_bounds,
             });
@@ -287,6 +313,40 @@ impl geometry::frame::Backend for Frame {
         }
     }
 
+    fn draw_image(&mut self, bounds: Rectangle, handle: image::Handle) {
+        let (bounds, rotation) =
+            self.transforms.current.transform_image(bounds);
+
+        let image = Image::Raster {
+            handle,
+            filter_method: image::FilterMethod::default(),
+            bounds,
+            rotation,
+            opacity: 1.0,
+        };
+
+        if image.bounds().intersects(&self.clip_bounds) {
+            self.images.push((image, self.clip_bounds));
+        }
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, handle: svg::Handle) {
+        let (bounds, rotation) =
+            self.transforms.current.transform_image(bounds);
+
+        let svg = Image::Vector {
+            handle,
+            color: None,
+            bounds,
+            rotation,
+            opacity: 1.0,
+        };
+
+        if svg.bounds().intersects(&self.clip_bounds) {
+            self.images.push((svg, self.clip_bounds));
+        }
+    }
+
     #[inline]
     fn translate(&mut self, translation: Vector) {
         self.transforms.current.0 =
@@ -340,6 +400,7 @@ impl geometry::frame::Backend for Frame {
             .extend(frame.buffers.into_meshes(frame.clip_bounds));
 
         self.text.extend(frame.text);
+        self.images.extend(frame.images);
     }
 
     fn into_geometry(mut self) -> Self::Geometry {
@@ -349,13 +410,17 @@ impl geometry::frame::Backend for Frame {
         Geometry::Live {
             meshes: self.meshes,
             text: self.text,
+            images: self.images,
         }
     }
 }
 
 enum Buffer {
-    Solid(tessellation::VertexBuffers<mesh::SolidVertex2D, u32>),
-    Gradient(tessellation::VertexBuffers<mesh::GradientVertex2D, u32>),
+    Solid(Blend, tessellation::VertexBuffers<mesh::SolidVertex2D, u32>),
+    Gradient(
+        Blend,
+        tessellation::VertexBuffers<mesh::GradientVertex2D, u32>,
+    ),
 }
 
 struct BufferStack {
@@ -367,20 +432,22 @@ impl BufferStack {
         Self { stack: Vec::new() }
     }
 
-    fn get_mut(&mut self, style: &Style) -> &mut Buffer {
+    fn get_mut(&mut self, style: &Style, blend: Blend) -> &mut Buffer {
         match style {
             Style::Solid(_) => match self.stack.last() {
-                Some(Buffer::Solid(_)) => {}
+                Some(Buffer::Solid(last, _)) if *last == blend => {}
                 _ => {
                     self.stack.push(Buffer::Solid(
+                        blend,
                         tessellation::VertexBuffers::new(),
                     ));
                 }
             },
             Style::Gradient(_) => match self.stack.last() {
-                Some(Buffer::Gradient(_)) => {}
+                Some(Buffer::Gradient(last, _)) if *last == blend => {}
                 _ => {
                     self.stack.push(Buffer::Gradient(
+                        blend,
                         tessellation::VertexBuffers::new(),
                     ));
                 }
@@ -393,15 +460,16 @@ impl BufferStack {
     fn get_fill<'a>(
         &'a mut self,
         style: &Style,
+        blend: Blend,
     ) -> Box<dyn tessellation::FillGeometryBuilder + 'a> {
-        match (style, self.get_mut(style)) {
-            (Style::Solid(color), Buffer::Solid(buffer)) => {
+        match (style, self.get_mut(style, blend)) {
+            (Style::Solid(color), Buffer::Solid(_, buffer)) => {
                 Box::new(tessellation::BuffersBuilder::new(
                     buffer,
                     TriangleVertex2DBuilder(color::pack(*color)),
                 ))
             }
-            (Style::Gradient(gradient), Buffer::Gradient(buffer)) => {
+            (Style::Gradient(gradient), Buffer::Gradient(_, buffer)) => {
                 Box::new(tessellation::BuffersBuilder::new(
                     buffer,
                     GradientVertex2DBuilder {
@@ -416,15 +484,16 @@ impl BufferStack {
     fn get_stroke<'a>(
         &'a mut self,
         style: &Style,
+        blend: Blend,
     ) -> Box<dyn tessellation::StrokeGeometryBuilder + 'a> {
-        match (style, self.get_mut(style)) {
-            (Style::Solid(color), Buffer::Solid(buffer)) => {
+        match (style, self.get_mut(style, blend)) {
+            (Style::Solid(color), Buffer::Solid(_, buffer)) => {
                 Box::new(tessellation::BuffersBuilder::new(
                     buffer,
                     TriangleVertex2DBuilder(color::pack(*color)),
                 ))
             }
-            (Style::Gradient(gradient), Buffer::Gradient(buffer)) => {
+            (Style::Gradient(gradient), Buffer::Gradient(_, buffer)) => {
                 Box::new(tessellation::BuffersBuilder::new(
                     buffer,
                     GradientVertex2DBuilder {
@@ -440,7 +509,7 @@ impl BufferStack {
         self.stack
             .into_iter()
             .filter_map(move |buffer| match buffer {
-                Buffer::Solid(buffer) if !buffer.indices.is_empty() => {
+                Buffer::Solid(blend, buffer) if !buffer.indices.is_empty() => {
                     Some(Mesh::Solid {
                         buffers: mesh::Indexed {
                             vertices: buffer.vertices,
@@ -448,9 +517,12 @@ impl BufferStack {
                         },
                         clip_bounds,
                         transformation: Transformation::IDENTITY,
+                        blend,
                     })
                 }
-                Buffer::Gradient(buffer) if !buffer.indices.is_empty() => {
+                Buffer::Gradient(blend, buffer)
+                    if !buffer.indices.is_empty() =>
+                {
                     Some(Mesh::Gradient {
                         buffers: mesh::Indexed {
                             vertices: buffer.vertices,
@@ -458,6 +530,7 @@ impl BufferStack {
                         },
                         clip_bounds,
                         transformation: Transformation::IDENTITY,
+                        blend,
                     })
                 }
                 _ => None,
@@ -488,6 +561,14 @@ impl Transform {
         (self.0.m11, self.0.m22)
     }
 
+    /// Returns the average factor by which the [`Transform`] scales areas.
+    fn scale_factor(&self) -> f32 {
+        (self.0.m11 * self.0.m22 - self.0.m12 * self.0.m21)
+            .abs()
+            .sqrt()
+            .max(f32::EPSILON)
+    }
+
     fn transform_point(&self, point: Point) -> Point {
         let transformed = self
             .0
@@ -499,6 +580,31 @@ impl Transform {
         }
     }
 
+    /// Transforms the bounds of an image; returning the bounds and the
+    /// rotation around their center of the transformed image.
+    ///
+    /// Only translation, scale, and rotation can be represented; skews and
+    /// reflections are dropped.
+    fn transform_image(&self, bounds: Rectangle) -> (Rectangle, Radians) {
+        let center = self.transform_point(bounds.center());
+
+        let scale_x = self.0.m11.hypot(self.0.m12);
+        let scale_y = self.0.m21.hypot(self.0.m22);
+
+        let size = Size::new(bounds.width * scale_x, bounds.height * scale_y);
+
+        (
+            Rectangle::new(
+                Point::new(
+                    center.x - size.width / 2.0,
+                    center.y - size.height / 2.0,
+                ),
+                size,
+            ),
+            Radians(self.0.m12.atan2(self.0.m11)),
+        )
+    }
+
     fn transform_style(&self, style: Style) -> Style {
         match style {
             Style::Solid(color) => Style::Solid(color),
@@ -612,46 +718,37 @@ fn into_fill_rule(rule: fill::Rule) -> lyon::tessellation::FillRule {
     }
 }
 
-pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
-    use lyon::algorithms::walk::{
-        walk_along_path, RepeatedPattern, WalkerEvent,
-    };
-    use lyon::path::iterator::PathIterator;
-
-    Path::new(|builder| {
-        let segments_odd = (line_dash.segments.len() % 2 == 1)
-            .then(|| [line_dash.segments, line_dash.segments].concat());
-
-        let mut draw_line = false;
-
-        walk_along_path(
-            path.raw().iter().flattened(
-                lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE,
-            ),
-            0.0,
-            lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE,
-            &mut RepeatedPattern {
-                callback: |event: WalkerEvent<'_>| {
-                    let point = Point {
-                        x: event.position.x,
-                        y: event.position.y,
-                    };
+/// Tessellates the area of a clip path, with the given [`Transformation`]
+/// applied.
+pub(crate) fn clip_mask(
+    path: &Path,
+    transformation: Transformation,
+) -> crate::clip::Mask {
+    let mut buffers = tessellation::VertexBuffers::<[f32; 2], u32>::new();
+
+    let result = tessellation::FillTessellator::new().tessellate_path(
+        path.raw(),
+        &tessellation::FillOptions::default()
+            .with_fill_rule(lyon::tessellation::FillRule::NonZero),
+        &mut tessellation::BuffersBuilder::new(
+            &mut buffers,
+            |vertex: tessellation::FillVertex<'_>| {
+                let position = vertex.position();
+                let point = Point::new(position.x, position.y) * transformation;
+
+                [point.x, point.y]
+            },
+        ),
+    );
 
-                    if draw_line {
-                        builder.line_to(point);
-                    } else {
-                        builder.move_to(point);
-                    }
+    if let Err(error) = result {
+        log::warn!("Cannot tessellate clip path: {error:?}");
 
-                    draw_line = !draw_line;
+        return crate::clip::Mask::default();
+    }
 
-                    true
-                },
-                index: line_dash.offset,
-                intervals: segments_odd
-                    .as_deref()
-                    .unwrap_or(line_dash.segments),
-            },
-        );
-    })
+    crate::clip::Mask {
+        vertices: buffers.vertices,
+        indices: buffers.indices,
+    }
 }
@@ -12,9 +12,14 @@ use allocator::Allocator;
 
 pub const SIZE: u32 = 2048;
 
+/// The maximum size of a single staging buffer used to upload images.
+///
+/// Bigger images still get a staging buffer of their own.
+const MAX_BATCH_SIZE: usize = 16 * 1024 * 1024;
+
 use crate::core::Size;
-use crate::graphics::color;
 
+use std::mem;
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -23,13 +28,55 @@ pub struct Atlas {
     texture_view: wgpu::TextureView,
     texture_bind_group: wgpu::BindGroup,
     texture_layout: Arc<wgpu::BindGroupLayout>,
+    format: wgpu::TextureFormat,
     layers: Vec<Layer>,
+    min_layers: usize,
+    pending: Vec<Upload>,
+}
+
+/// An image waiting to be copied into the atlas.
+#[derive(Debug)]
+struct Upload {
+    data: Vec<u8>,
+    bytes_per_row: u32,
+    rows_per_image: u32,
+    pieces: Vec<Piece>,
+}
+
+/// A piece of an [`Upload`] and where to copy it in the atlas.
+#[derive(Debug, Clone, Copy)]
+struct Piece {
+    offset: u64,
+    origin: wgpu::Origin3d,
+    extent: wgpu::Extent3d,
+}
+
+impl Piece {
+    fn new(offset: u64, allocation: &Allocation) -> Self {
+        let (x, y) = allocation.position();
+        let Size { width, height } = allocation.size();
+
+        Self {
+            offset,
+            origin: wgpu::Origin3d {
+                x,
+                y,
+                z: allocation.layer() as u32,
+            },
+            extent: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        }
+    }
 }
 
 impl Atlas {
     pub fn new(
         device: &wgpu::Device,
         backend: wgpu::Backend,
+        format: wgpu::TextureFormat,
         texture_layout: Arc<wgpu::BindGroupLayout>,
     ) -> Self {
         let layers = match backend {
@@ -52,11 +99,7 @@ impl Atlas {
             mip_level_count: 1,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
-            format: if color::GAMMA_CORRECTION {
-                wgpu::TextureFormat::Rgba8UnormSrgb
-            } else {
-                wgpu::TextureFormat::Rgba8Unorm
-            },
+            format,
             usage: wgpu::TextureUsages::COPY_DST
                 | wgpu::TextureUsages::COPY_SRC
                 | wgpu::TextureUsages::TEXTURE_BINDING,
@@ -83,7 +126,10 @@ impl Atlas {
             texture_view,
             texture_bind_group,
             texture_layout,
+            format,
+            min_layers: layers.len(),
             layers,
+            pending: Vec::new(),
         }
     }
 
@@ -95,24 +141,57 @@ impl Atlas {
         self.layers.len()
     }
 
-    pub fn upload(
+    /// Returns the amount of bytes allocated by the texture of the [`Atlas`].
+    pub fn memory_usage(&self) -> u64 {
+        u64::from(self.texture.depth_or_array_layers())
+            * u64::from(SIZE)
+            * u64::from(SIZE)
+            * 4
+    }
+
+    /// Drops the empty layers at the end of the [`Atlas`]; reallocating its
+    /// texture with less layers.
+    pub fn shrink(
         &mut self,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
+    ) {
+        if !self.pending.is_empty() {
+            return;
+        }
+
+        let used = self
+            .layers
+            .iter()
+            .rposition(|layer| !layer.is_empty())
+            .map_or(0, |last| last + 1);
+
+        let layers = used.max(self.min_layers);
+
+        if layers >= self.layers.len() {
+            return;
+        }
+
+        log::debug!(
+            "Shrinking atlas from {} to {layers} layers",
+            self.layers.len()
+        );
+
+        self.layers.truncate(layers);
+        self.reallocate(device, encoder);
+    }
+
+    /// Allocates an entry for an image and queues its data to be uploaded
+    /// on the next [`flush`].
+    ///
+    /// [`flush`]: Self::flush
+    pub fn upload(
+        &mut self,
         width: u32,
         height: u32,
         data: &[u8],
     ) -> Option<Entry> {
-        let entry = {
-            let current_size = self.layers.len();
-            let entry = self.allocate(width, height)?;
-
-            // We grow the internal texture after allocating if necessary
-            let new_layers = self.layers.len() - current_size;
-            self.grow(new_layers, device, encoder);
-
-            entry
-        };
+        let entry = self.allocate(width, height)?;
 
         log::debug!("Allocated atlas entry: {entry:?}");
 
@@ -125,6 +204,10 @@ impl Atlas {
         let padded_width = (4 * width + padding) as usize;
         let padded_data_size = padded_width * height as usize;
 
+        if padded_data_size == 0 {
+            return Some(entry);
+        }
+
         let mut padded_data = vec![0; padded_data_size];
 
         for row in 0..height as usize {
@@ -135,38 +218,69 @@ impl Atlas {
             );
         }
 
-        match &entry {
-            Entry::Contiguous(allocation) => {
-                self.upload_allocation(
-                    &padded_data,
-                    width,
-                    height,
-                    padding,
-                    0,
-                    allocation,
-                    device,
-                    encoder,
-                );
-            }
-            Entry::Fragmented { fragments, .. } => {
-                for fragment in fragments {
+        let pieces = match &entry {
+            Entry::Contiguous(allocation) => vec![Piece::new(0, allocation)],
+            Entry::Fragmented { fragments, .. } => fragments
+                .iter()
+                .map(|fragment| {
                     let (x, y) = fragment.position;
-                    let offset = (y * padded_width as u32 + 4 * x) as usize;
-
-                    self.upload_allocation(
-                        &padded_data,
-                        width,
-                        height,
-                        padding,
-                        offset,
-                        &fragment.allocation,
-                        device,
-                        encoder,
-                    );
-                }
+                    let offset = y as u64 * padded_width as u64 + 4 * x as u64;
+
+                    Piece::new(offset, &fragment.allocation)
+                })
+                .collect(),
+        };
+
+        self.pending.push(Upload {
+            data: padded_data,
+            bytes_per_row: 4 * width + padding,
+            rows_per_image: height,
+            pieces,
+        });
+
+        Some(entry)
+    }
+
+    /// Uploads all the images queued since the last flush.
+    ///
+    /// The texture is grown at most once; and the images are copied in as
+    /// few staging buffers as possible.
+    pub fn flush(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let texture_layers = self.texture.depth_or_array_layers() as usize;
+
+        if self.layers.len() > texture_layers {
+            self.reallocate(device, encoder);
+        }
+
+        let images = self.pending.len();
+        let mut batches = 0;
+        let mut uploads = mem::take(&mut self.pending).into_iter().peekable();
+
+        while uploads.peek().is_some() {
+            let mut batch = Vec::new();
+            let mut size = 0;
+
+            while let Some(upload) = uploads.next_if(|upload| {
+                batch.is_empty() || size + upload.data.len() <= MAX_BATCH_SIZE
+            }) {
+                size += upload.data.len();
+                batch.push(upload);
             }
+
+            self.upload_batch(&batch, size, device, encoder);
+            batches += 1;
         }
 
+        log::debug!("Uploaded {images} images in {batches} batches");
+
         if log::log_enabled!(log::Level::Debug) {
             log::debug!(
                 "Atlas layers: {} (busy: {}, allocations: {})",
@@ -175,8 +289,6 @@ impl Atlas {
                 self.layers.iter().map(Layer::allocations).sum::<usize>(),
             );
         }
-
-        Some(entry)
     }
 
     pub fn remove(&mut self, entry: &Entry) {
@@ -313,69 +425,68 @@ impl Atlas {
         }
     }
 
-    fn upload_allocation(
-        &mut self,
-        data: &[u8],
-        image_width: u32,
-        image_height: u32,
-        padding: u32,
-        offset: usize,
-        allocation: &Allocation,
+    fn upload_batch(
+        &self,
+        batch: &[Upload],
+        size: usize,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
     ) {
-        use wgpu::util::DeviceExt;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::image atlas upload buffer"),
+            size: size as u64,
+            usage: wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: true,
+        });
 
-        let (x, y) = allocation.position();
-        let Size { width, height } = allocation.size();
-        let layer = allocation.layer();
+        {
+            let mut staging = buffer.slice(..).get_mapped_range_mut();
+            let mut offset = 0;
 
-        let extent = wgpu::Extent3d {
-            width,
-            height,
-            depth_or_array_layers: 1,
-        };
+            for upload in batch {
+                staging[offset..offset + upload.data.len()]
+                    .copy_from_slice(&upload.data);
 
-        let buffer =
-            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("image upload buffer"),
-                contents: data,
-                usage: wgpu::BufferUsages::COPY_SRC,
-            });
+                offset += upload.data.len();
+            }
+        }
 
-        encoder.copy_buffer_to_texture(
-            wgpu::ImageCopyBuffer {
-                buffer: &buffer,
-                layout: wgpu::ImageDataLayout {
-                    offset: offset as u64,
-                    bytes_per_row: Some(4 * image_width + padding),
-                    rows_per_image: Some(image_height),
-                },
-            },
-            wgpu::ImageCopyTexture {
-                texture: &self.texture,
-                mip_level: 0,
-                origin: wgpu::Origin3d {
-                    x,
-                    y,
-                    z: layer as u32,
-                },
-                aspect: wgpu::TextureAspect::default(),
-            },
-            extent,
-        );
+        buffer.unmap();
+
+        let mut offset = 0;
+
+        for upload in batch {
+            for piece in &upload.pieces {
+                encoder.copy_buffer_to_texture(
+                    wgpu::ImageCopyBuffer {
+                        buffer: &buffer,
+                        layout: wgpu::ImageDataLayout {
+                            offset: offset + piece.offset,
+                            bytes_per_row: Some(upload.bytes_per_row),
+                            rows_per_image: Some(upload.rows_per_image),
+                        },
+                    },
+                    wgpu::ImageCopyTexture {
+                        texture: &self.texture,
+                        mip_level: 0,
+                        origin: piece.origin,
+                        aspect: wgpu::TextureAspect::default(),
+                    },
+                    piece.extent,
+                );
+            }
+
+            offset += upload.data.len() as u64;
+        }
     }
 
-    fn grow(
+    /// Replaces the texture of the [`Atlas`] with one that fits all of its
+    /// layers; copying the busy layers that fit in both.
+    fn reallocate(
         &mut self,
-        amount: usize,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
     ) {
-        if amount == 0 {
-            return;
-        }
-
         let new_texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("iced_wgpu::image texture atlas"),
             size: wgpu::Extent3d {
@@ -386,22 +497,19 @@ impl Atlas {
             mip_level_count: 1,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
-            format: if color::GAMMA_CORRECTION {
-                wgpu::TextureFormat::Rgba8UnormSrgb
-            } else {
-                wgpu::TextureFormat::Rgba8Unorm
-            },
+            format: self.format,
             usage: wgpu::TextureUsages::COPY_DST
                 | wgpu::TextureUsages::COPY_SRC
                 | wgpu::TextureUsages::TEXTURE_BINDING,
             view_formats: &[],
         });
 
-        let amount_to_copy = self.layers.len() - amount;
+        let amount_to_copy = self
+            .layers
+            .len()
+            .min(self.texture.depth_or_array_layers() as usize);
 
-        for (i, layer) in
-            self.layers.iter_mut().take(amount_to_copy).enumerate()
-        {
+        for (i, layer) in self.layers.iter().take(amount_to_copy).enumerate() {
             if layer.is_empty() {
                 continue;
             }
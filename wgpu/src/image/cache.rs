@@ -8,6 +8,10 @@ pub struct Cache {
     atlas: Atlas,
     #[cfg(feature = "image")]
     raster: crate::image::raster::Cache,
+    #[cfg(feature = "image")]
+    dynamic: crate::image::dynamic::Cache,
+    #[cfg(feature = "image")]
+    compressed: crate::image::compressed::Cache,
     #[cfg(feature = "svg")]
     vector: crate::image::vector::Cache,
 }
@@ -16,10 +20,23 @@ impl Cache {
     pub fn new(
         device: &wgpu::Device,
         backend: wgpu::Backend,
+        format: wgpu::TextureFormat,
         layout: Arc<wgpu::BindGroupLayout>,
     ) -> Self {
         Self {
-            atlas: Atlas::new(device, backend, layout),
+            #[cfg(feature = "image")]
+            dynamic: crate::image::dynamic::Cache::new(
+                backend,
+                format,
+                layout.clone(),
+            ),
+            #[cfg(feature = "image")]
+            compressed: crate::image::compressed::Cache::new(
+                backend,
+                format,
+                layout.clone(),
+            ),
+            atlas: Atlas::new(device, backend, format, layout),
             #[cfg(feature = "image")]
             raster: crate::image::raster::Cache::default(),
             #[cfg(feature = "svg")]
@@ -35,8 +52,31 @@ impl Cache {
         self.atlas.layer_count()
     }
 
+    /// Returns the amount of bytes allocated by the atlas and the textures
+    /// of the dynamic and compressed images.
+    pub fn memory_usage(&self) -> u64 {
+        #[cfg(feature = "image")]
+        let dynamic =
+            self.dynamic.memory_usage() + self.compressed.memory_usage();
+
+        #[cfg(not(feature = "image"))]
+        let dynamic = 0;
+
+        self.atlas.memory_usage() + dynamic
+    }
+
     #[cfg(feature = "image")]
     pub fn measure_image(&mut self, handle: &core::image::Handle) -> Size<u32> {
+        match handle {
+            core::image::Handle::Dynamic(_, dynamic) => {
+                return dynamic.size();
+            }
+            core::image::Handle::Compressed(_, compressed) => {
+                return compressed.size();
+            }
+            _ => {}
+        }
+
         self.raster.load(handle).dimensions()
     }
 
@@ -48,39 +88,104 @@ impl Cache {
     #[cfg(feature = "image")]
     pub fn upload_raster(
         &mut self,
-        device: &wgpu::Device,
-        encoder: &mut wgpu::CommandEncoder,
         handle: &core::image::Handle,
     ) -> Option<&atlas::Entry> {
-        self.raster.upload(device, encoder, handle, &mut self.atlas)
+        self.raster.upload(handle, &mut self.atlas)
     }
 
-    #[cfg(feature = "svg")]
-    pub fn upload_vector(
+    #[cfg(feature = "image")]
+    pub fn upload_dynamic(
         &mut self,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        id: core::image::Id,
+        dynamic: &core::image::Dynamic,
+    ) -> Option<&crate::image::dynamic::Entry> {
+        self.dynamic.upload(device, encoder, belt, id, dynamic)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn upload_compressed(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        id: core::image::Id,
+        compressed: &core::image::Compressed,
+    ) -> Option<&crate::image::compressed::Entry> {
+        self.compressed.upload(device, encoder, id, compressed)
+    }
+
+    /// Returns the bind group of the dedicated texture of a dynamic or
+    /// compressed image.
+    #[cfg(feature = "image")]
+    pub fn texture_bind_group(
+        &self,
+        id: core::image::Id,
+    ) -> Option<&wgpu::BindGroup> {
+        self.dynamic
+            .get(id)
+            .map(crate::image::dynamic::Entry::bind_group)
+            .or_else(|| {
+                self.compressed
+                    .get(id)
+                    .map(crate::image::compressed::Entry::bind_group)
+            })
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn upload_vector(
+        &mut self,
         handle: &core::svg::Handle,
         color: Option<core::Color>,
         size: [f32; 2],
         scale: f32,
     ) -> Option<&atlas::Entry> {
-        self.vector.upload(
-            device,
-            encoder,
-            handle,
-            color,
-            size,
-            scale,
-            &mut self.atlas,
-        )
+        self.vector
+            .upload(handle, color, size, scale, &mut self.atlas)
+    }
+
+    pub fn flush(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        self.atlas.flush(device, encoder);
     }
 
     pub fn trim(&mut self) {
         #[cfg(feature = "image")]
         self.raster.trim(&mut self.atlas);
 
+        #[cfg(feature = "image")]
+        self.dynamic.trim();
+
+        #[cfg(feature = "image")]
+        self.compressed.trim();
+
         #[cfg(feature = "svg")]
         self.vector.trim(&mut self.atlas);
     }
+
+    /// Frees all the images that were not used since the last trim and
+    /// shrinks the atlas to fit the remaining ones.
+    pub fn evict(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        #[cfg(feature = "image")]
+        self.raster.evict(&mut self.atlas);
+
+        #[cfg(feature = "image")]
+        self.dynamic.trim();
+
+        #[cfg(feature = "image")]
+        self.compressed.trim();
+
+        #[cfg(feature = "svg")]
+        self.vector.evict(&mut self.atlas);
+
+        self.atlas.shrink(device, encoder);
+    }
 }
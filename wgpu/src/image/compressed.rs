@@ -0,0 +1,474 @@
+//! Keep compressed images in dedicated textures, outside of the atlas.
+use crate::core::image;
+use crate::core::Size;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::sync::Arc;
+
+use wgpu::util::DeviceExt;
+
+/// A dedicated texture holding the mip levels of a compressed image.
+#[derive(Debug)]
+pub struct Entry {
+    size: Size<u32>,
+    bind_group: wgpu::BindGroup,
+    texture: wgpu::Texture,
+}
+
+impl Entry {
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// An error produced when uploading a compressed image.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The device does not support the format of the image.
+    #[error(
+        "{format:?} textures are not supported by the device \
+        (requires {feature:?})"
+    )]
+    Unsupported {
+        /// The format of the image.
+        format: image::Format,
+        /// The feature the device is missing.
+        feature: wgpu::Features,
+    },
+    /// The image has no mip levels.
+    #[error("the compressed image has no mip levels")]
+    Empty,
+    /// The size of the image is zero or not a multiple of the block size.
+    #[error(
+        "the size {size:?} of a {format:?} texture must be a non-zero \
+        multiple of its block size"
+    )]
+    InvalidSize {
+        /// The format of the image.
+        format: image::Format,
+        /// The size of the image.
+        size: Size<u32>,
+    },
+    /// The image is larger than the textures the device supports.
+    #[error(
+        "the size {size:?} exceeds the maximum texture dimension of the \
+        device ({max})"
+    )]
+    TooLarge {
+        /// The size of the image.
+        size: Size<u32>,
+        /// The maximum dimension of a texture of the device.
+        max: u32,
+    },
+    /// The image has more mip levels than its size allows.
+    #[error("the image has {levels} mip levels, but at most {max} fit")]
+    TooManyLevels {
+        /// The amount of mip levels of the image.
+        levels: usize,
+        /// The amount of mip levels of a full chain of the image.
+        max: u32,
+    },
+}
+
+/// Caches the textures of compressed images.
+#[derive(Debug)]
+pub struct Cache {
+    entries: FxHashMap<image::Id, Result<Entry, Error>>,
+    hits: FxHashSet<image::Id>,
+    srgb: bool,
+    layers: u32,
+    texture_layout: Arc<wgpu::BindGroupLayout>,
+}
+
+impl Cache {
+    pub fn new(
+        backend: wgpu::Backend,
+        atlas_format: wgpu::TextureFormat,
+        texture_layout: Arc<wgpu::BindGroupLayout>,
+    ) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            hits: FxHashSet::default(),
+            // The texels must be sampled like the ones of the atlas, so the
+            // image shader treats both the same way
+            srgb: atlas_format.is_srgb(),
+            // Like the atlas, GL needs 2 layers to figure out that the
+            // texture is an array
+            layers: match backend {
+                wgpu::Backend::Gl => 2,
+                _ => 1,
+            },
+            texture_layout,
+        }
+    }
+
+    /// Uploads the mip levels of the compressed image, unless they have
+    /// been uploaded already.
+    ///
+    /// An image that cannot be uploaded is rejected and the reason is
+    /// logged only once.
+    pub fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        id: image::Id,
+        compressed: &image::Compressed,
+    ) -> Option<&Entry> {
+        let _ = self.hits.insert(id);
+
+        let Self {
+            entries,
+            srgb,
+            layers,
+            texture_layout,
+            ..
+        } = self;
+
+        entries
+            .entry(id)
+            .or_insert_with(|| {
+                let entry = allocate(
+                    device,
+                    encoder,
+                    *srgb,
+                    *layers,
+                    texture_layout,
+                    compressed,
+                );
+
+                if let Err(error) = &entry {
+                    log::error!("Cannot upload compressed image: {error}");
+                }
+
+                entry
+            })
+            .as_ref()
+            .ok()
+    }
+
+    pub fn get(&self, id: image::Id) -> Option<&Entry> {
+        self.entries.get(&id).and_then(|entry| entry.as_ref().ok())
+    }
+
+    /// Frees the textures of the compressed images that were not used since
+    /// the last trim.
+    pub fn trim(&mut self) {
+        let hits = &self.hits;
+
+        self.entries.retain(|id, _| hits.contains(id));
+        self.hits.clear();
+    }
+
+    /// Returns the amount of bytes allocated by the textures of the
+    /// compressed images.
+    pub fn memory_usage(&self) -> u64 {
+        self.entries
+            .values()
+            .filter_map(|entry| entry.as_ref().ok())
+            .map(|entry| {
+                let texture = &entry.texture;
+                let format = texture.format();
+                let (block_width, block_height) = format.block_dimensions();
+                let block_length =
+                    u64::from(format.block_size(None).unwrap_or(16));
+
+                (0..texture.mip_level_count())
+                    .map(|level| {
+                        let width = (texture.width() >> level).max(1);
+                        let height = (texture.height() >> level).max(1);
+
+                        u64::from(width.div_ceil(block_width))
+                            * u64::from(height.div_ceil(block_height))
+                            * block_length
+                    })
+                    .sum::<u64>()
+                    * u64::from(texture.depth_or_array_layers())
+            })
+            .sum()
+    }
+}
+
+fn allocate(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    srgb: bool,
+    layers: u32,
+    texture_layout: &wgpu::BindGroupLayout,
+    compressed: &image::Compressed,
+) -> Result<Entry, Error> {
+    let texture_format = validate(
+        compressed,
+        srgb,
+        device.features(),
+        device.limits().max_texture_dimension_2d,
+    )?;
+
+    let format = compressed.format();
+    let size = compressed.size();
+    let levels = compressed.levels();
+    let block = format.block_size();
+
+    log::debug!("Allocating compressed image texture: {format:?} {size:?}");
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::image compressed texture"),
+        size: wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: layers,
+        },
+        mip_level_count: levels.len() as u32,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: texture_format,
+        usage: wgpu::TextureUsages::COPY_DST
+            | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+
+    for (level, data) in (0_u32..).zip(levels) {
+        let blocks = Size::new(
+            (size.width >> level).max(1).div_ceil(block.width),
+            (size.height >> level).max(1).div_ceil(block.height),
+        );
+
+        let row = blocks.width as usize * format.block_length();
+        let bytes_per_row = padded_bytes_per_row(row);
+
+        let mut padded = vec![0; bytes_per_row * blocks.height as usize];
+
+        for (target, source) in
+            padded.chunks_exact_mut(bytes_per_row).zip(data.chunks(row))
+        {
+            target[..source.len()].copy_from_slice(source);
+        }
+
+        let upload =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu::image compressed upload buffer"),
+                contents: &padded,
+                usage: wgpu::BufferUsages::COPY_SRC,
+            });
+
+        encoder.copy_buffer_to_texture(
+            wgpu::ImageCopyBuffer {
+                buffer: &upload,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row as u32),
+                    rows_per_image: Some(blocks.height),
+                },
+            },
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: level,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::default(),
+            },
+            // Copies of compressed textures must cover whole blocks; even
+            // for the mip levels that are smaller than one
+            wgpu::Extent3d {
+                width: blocks.width * block.width,
+                height: blocks.height * block.height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor {
+        dimension: Some(wgpu::TextureViewDimension::D2Array),
+        ..Default::default()
+    });
+
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("iced_wgpu::image compressed texture bind group"),
+        layout: texture_layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::TextureView(&view),
+        }],
+    });
+
+    Ok(Entry {
+        size,
+        bind_group,
+        texture,
+    })
+}
+
+/// Checks that a texture can be created for the compressed image with the
+/// given device features and maximum dimension; returning its
+/// [`wgpu::TextureFormat`].
+fn validate(
+    compressed: &image::Compressed,
+    srgb: bool,
+    features: wgpu::Features,
+    max_dimension: u32,
+) -> Result<wgpu::TextureFormat, Error> {
+    let format = compressed.format();
+    let size = compressed.size();
+    let levels = compressed.levels();
+
+    let texture_format = texture_format(format, srgb);
+    let feature = texture_format.required_features();
+
+    if !features.contains(feature) {
+        return Err(Error::Unsupported { format, feature });
+    }
+
+    if levels.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    let block = format.block_size();
+
+    if size.width == 0
+        || size.height == 0
+        || size.width % block.width != 0
+        || size.height % block.height != 0
+    {
+        return Err(Error::InvalidSize { format, size });
+    }
+
+    if size.width > max_dimension || size.height > max_dimension {
+        return Err(Error::TooLarge {
+            size,
+            max: max_dimension,
+        });
+    }
+
+    let max_levels = format.level_count(size);
+
+    if levels.len() > max_levels as usize {
+        return Err(Error::TooManyLevels {
+            levels: levels.len(),
+            max: max_levels,
+        });
+    }
+
+    Ok(texture_format)
+}
+
+/// Returns the [`wgpu::TextureFormat`] of the given [`image::Format`]; using
+/// its sRGB variant if needed.
+fn texture_format(format: image::Format, srgb: bool) -> wgpu::TextureFormat {
+    use image::Format;
+    use wgpu::{AstcBlock, AstcChannel, TextureFormat};
+
+    let astc = |block| TextureFormat::Astc {
+        block,
+        channel: if srgb {
+            AstcChannel::UnormSrgb
+        } else {
+            AstcChannel::Unorm
+        },
+    };
+
+    match (format, srgb) {
+        (Format::Bc1, false) => TextureFormat::Bc1RgbaUnorm,
+        (Format::Bc1, true) => TextureFormat::Bc1RgbaUnormSrgb,
+        (Format::Bc2, false) => TextureFormat::Bc2RgbaUnorm,
+        (Format::Bc2, true) => TextureFormat::Bc2RgbaUnormSrgb,
+        (Format::Bc3, false) => TextureFormat::Bc3RgbaUnorm,
+        (Format::Bc3, true) => TextureFormat::Bc3RgbaUnormSrgb,
+        (Format::Bc7, false) => TextureFormat::Bc7RgbaUnorm,
+        (Format::Bc7, true) => TextureFormat::Bc7RgbaUnormSrgb,
+        (Format::Etc2Rgb8, false) => TextureFormat::Etc2Rgb8Unorm,
+        (Format::Etc2Rgb8, true) => TextureFormat::Etc2Rgb8UnormSrgb,
+        (Format::Etc2Rgb8A1, false) => TextureFormat::Etc2Rgb8A1Unorm,
+        (Format::Etc2Rgb8A1, true) => TextureFormat::Etc2Rgb8A1UnormSrgb,
+        (Format::Etc2Rgba8, false) => TextureFormat::Etc2Rgba8Unorm,
+        (Format::Etc2Rgba8, true) => TextureFormat::Etc2Rgba8UnormSrgb,
+        (Format::Astc4x4, _) => astc(AstcBlock::B4x4),
+        (Format::Astc5x5, _) => astc(AstcBlock::B5x5),
+        (Format::Astc6x6, _) => astc(AstcBlock::B6x6),
+        (Format::Astc8x8, _) => astc(AstcBlock::B8x8),
+        (Format::Astc10x10, _) => astc(AstcBlock::B10x10),
+        (Format::Astc12x12, _) => astc(AstcBlock::B12x12),
+    }
+}
+
+/// Returns the given bytes per row, padded to the alignment required by
+/// wgpu.
+fn padded_bytes_per_row(bytes_per_row: usize) -> usize {
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as usize;
+
+    bytes_per_row.div_ceil(align) * align
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::image::{Format, Handle};
+
+    const MAX_DIMENSION: u32 = 8192;
+
+    fn validate(
+        size: Size<u32>,
+        length: usize,
+        features: wgpu::Features,
+    ) -> Result<wgpu::TextureFormat, Error> {
+        let Handle::Compressed(_, compressed) =
+            Handle::from_compressed(vec![0; length], Format::Bc7, size)
+        else {
+            unreachable!()
+        };
+
+        super::validate(&compressed, true, features, MAX_DIMENSION)
+    }
+
+    #[test]
+    fn supported_images_are_valid() {
+        assert_eq!(
+            validate(
+                Size::new(8, 4),
+                32,
+                wgpu::Features::TEXTURE_COMPRESSION_BC
+            ),
+            Ok(wgpu::TextureFormat::Bc7RgbaUnormSrgb)
+        );
+    }
+
+    #[test]
+    fn missing_features_are_rejected() {
+        assert!(matches!(
+            validate(Size::new(4, 4), 16, wgpu::Features::empty()),
+            Err(Error::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn zero_sizes_are_rejected() {
+        for size in [Size::new(0, 4), Size::new(4, 0), Size::new(0, 0)] {
+            assert_eq!(
+                validate(size, 16, wgpu::Features::TEXTURE_COMPRESSION_BC),
+                Err(Error::InvalidSize {
+                    format: Format::Bc7,
+                    size
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn sizes_beyond_the_device_limit_are_rejected() {
+        let size = Size::new(MAX_DIMENSION * 2, 4);
+
+        assert_eq!(
+            validate(
+                size,
+                Format::Bc7.level_length(size, 0),
+                wgpu::Features::TEXTURE_COMPRESSION_BC
+            ),
+            Err(Error::TooLarge {
+                size,
+                max: MAX_DIMENSION
+            })
+        );
+    }
+}
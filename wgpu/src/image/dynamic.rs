@@ -0,0 +1,230 @@
+//! Keep dynamic images in dedicated textures, outside of the atlas.
+use crate::core::image;
+use crate::core::Size;
+
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+
+/// A dedicated texture holding the pixels of a dynamic image.
+#[derive(Debug)]
+pub struct Entry {
+    size: Size<u32>,
+    bind_group: wgpu::BindGroup,
+    texture: wgpu::Texture,
+    /// The buffer the dirty regions are copied from into the texture.
+    upload: wgpu::Buffer,
+    pixels: image::WeakDynamic,
+}
+
+impl Entry {
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// Caches the textures of dynamic images.
+#[derive(Debug)]
+pub struct Cache {
+    entries: FxHashMap<image::Id, Entry>,
+    format: wgpu::TextureFormat,
+    layers: u32,
+    texture_layout: Arc<wgpu::BindGroupLayout>,
+}
+
+impl Cache {
+    pub fn new(
+        backend: wgpu::Backend,
+        format: wgpu::TextureFormat,
+        texture_layout: Arc<wgpu::BindGroupLayout>,
+    ) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            format,
+            // Like the atlas, GL needs 2 layers to figure out that the
+            // texture is an array
+            layers: match backend {
+                wgpu::Backend::Gl => 2,
+                _ => 1,
+            },
+            texture_layout,
+        }
+    }
+
+    /// Uploads the region of the dynamic image updated since the last call,
+    /// allocating its texture first if needed.
+    pub fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        id: image::Id,
+        dynamic: &image::Dynamic,
+    ) -> Option<&Entry> {
+        let size = dynamic.size();
+
+        if size.width == 0 || size.height == 0 {
+            return None;
+        }
+
+        let Self {
+            entries,
+            format,
+            layers,
+            texture_layout,
+        } = self;
+
+        let entry = entries.entry(id).or_insert_with(|| {
+            allocate(device, *format, *layers, texture_layout, dynamic)
+        });
+
+        let _ = dynamic.take_dirty(|region, pixels| {
+            let bytes_per_row = padded_bytes_per_row(region.width);
+            let stride = size.width as usize * 4;
+            let row = region.width as usize * 4;
+
+            let Some(length) =
+                wgpu::BufferSize::new(u64::from(bytes_per_row * region.height))
+            else {
+                return;
+            };
+
+            let mut staging =
+                belt.write_buffer(encoder, &entry.upload, 0, length, device);
+
+            for (i, target) in
+                staging.chunks_exact_mut(bytes_per_row as usize).enumerate()
+            {
+                let offset =
+                    (region.y as usize + i) * stride + region.x as usize * 4;
+
+                target[..row].copy_from_slice(&pixels[offset..offset + row]);
+            }
+
+            drop(staging);
+
+            encoder.copy_buffer_to_texture(
+                wgpu::ImageCopyBuffer {
+                    buffer: &entry.upload,
+                    layout: wgpu::ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: Some(bytes_per_row),
+                        rows_per_image: Some(region.height),
+                    },
+                },
+                wgpu::ImageCopyTexture {
+                    texture: &entry.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: region.x,
+                        y: region.y,
+                        z: 0,
+                    },
+                    aspect: wgpu::TextureAspect::default(),
+                },
+                wgpu::Extent3d {
+                    width: region.width,
+                    height: region.height,
+                    depth_or_array_layers: 1,
+                },
+            );
+        });
+
+        Some(entry)
+    }
+
+    pub fn get(&self, id: image::Id) -> Option<&Entry> {
+        self.entries.get(&id)
+    }
+
+    /// Frees the textures of the dynamic images that have been dropped.
+    pub fn trim(&mut self) {
+        self.entries.retain(|_, entry| !entry.pixels.is_dropped());
+    }
+
+    /// Returns the amount of bytes allocated by the textures and upload
+    /// buffers of the dynamic images.
+    pub fn memory_usage(&self) -> u64 {
+        self.entries
+            .values()
+            .map(|entry| {
+                let texture = &entry.texture;
+
+                u64::from(texture.width())
+                    * u64::from(texture.height())
+                    * u64::from(texture.depth_or_array_layers())
+                    * 4
+                    + entry.upload.size()
+            })
+            .sum()
+    }
+}
+
+fn allocate(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    layers: u32,
+    texture_layout: &wgpu::BindGroupLayout,
+    dynamic: &image::Dynamic,
+) -> Entry {
+    let size = dynamic.size();
+
+    log::debug!("Allocating dynamic image texture: {size:?}");
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::image dynamic texture"),
+        size: wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: layers,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::COPY_DST
+            | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor {
+        dimension: Some(wgpu::TextureViewDimension::D2Array),
+        ..Default::default()
+    });
+
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("iced_wgpu::image dynamic texture bind group"),
+        layout: texture_layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::TextureView(&view),
+        }],
+    });
+
+    let upload = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("iced_wgpu::image dynamic upload buffer"),
+        size: u64::from(padded_bytes_per_row(size.width) * size.height),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+
+    Entry {
+        size,
+        bind_group,
+        texture,
+        upload,
+        pixels: dynamic.downgrade(),
+    }
+}
+
+/// Returns the bytes per row of an upload of the given width, padded to the
+/// alignment required by wgpu.
+fn padded_bytes_per_row(width: u32) -> u32 {
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let padding = (align - (4 * width) % align) % align;
+
+    4 * width + padding
+}
@@ -3,12 +3,19 @@ pub(crate) use cache::Cache;
 
 mod atlas;
 
+#[cfg(feature = "image")]
+mod compressed;
+
+#[cfg(feature = "image")]
+mod dynamic;
+
 #[cfg(feature = "image")]
 mod raster;
 
 #[cfg(feature = "svg")]
 mod vector;
 
+use crate::clip;
 use crate::core::{Rectangle, Size, Transformation};
 use crate::Buffer;
 
@@ -25,6 +32,7 @@ pub type Batch = Vec<Image>;
 pub struct Pipeline {
     pipeline: wgpu::RenderPipeline,
     backend: wgpu::Backend,
+    atlas_format: wgpu::TextureFormat,
     nearest_sampler: wgpu::Sampler,
     linear_sampler: wgpu::Sampler,
     texture_layout: Arc<wgpu::BindGroupLayout>,
@@ -37,6 +45,7 @@ impl Pipeline {
     pub fn new(
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
+        atlas_format: wgpu::TextureFormat,
         backend: wgpu::Backend,
     ) -> Self {
         let nearest_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
@@ -113,10 +122,14 @@ impl Pipeline {
         let shader =
             device.create_shader_module(wgpu::ShaderModuleDescriptor {
                 label: Some("iced_wgpu image shader"),
-                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
-                    concat!(
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Owned(
+                    format!(
+                        "const DECODE_SRGB: bool = {};\n\
+                        const ENCODE_SRGB: bool = {};\n\
+                        {}\n{}",
+                        crate::format::decodes_srgb(atlas_format),
+                        crate::format::encodes_srgb(format),
                         include_str!("../shader/vertex.wgsl"),
-                        "\n",
                         include_str!("../shader/image.wgsl"),
                     ),
                 )),
@@ -177,7 +190,7 @@ impl Pipeline {
                     front_face: wgpu::FrontFace::Cw,
                     ..Default::default()
                 },
-                depth_stencil: None,
+                depth_stencil: Some(clip::stencil()),
                 multisample: wgpu::MultisampleState {
                     count: 1,
                     mask: !0,
@@ -189,6 +202,7 @@ impl Pipeline {
         Pipeline {
             pipeline,
             backend,
+            atlas_format,
             nearest_sampler,
             linear_sampler,
             texture_layout: Arc::new(texture_layout),
@@ -199,7 +213,16 @@ impl Pipeline {
     }
 
     pub fn create_cache(&self, device: &wgpu::Device) -> Cache {
-        Cache::new(device, self.backend, self.texture_layout.clone())
+        Cache::new(
+            device,
+            self.backend,
+            self.atlas_format,
+            self.texture_layout.clone(),
+        )
+    }
+
+    pub fn atlas_format(&self) -> wgpu::TextureFormat {
+        self.atlas_format
     }
 
     pub fn prepare(
@@ -216,6 +239,8 @@ impl Pipeline {
 
         let nearest_instances: &mut Vec<Instance> = &mut Vec::new();
         let linear_instances: &mut Vec<Instance> = &mut Vec::new();
+        let dynamic_instances: &mut Vec<Instance> = &mut Vec::new();
+        let dynamic_draws: &mut Vec<Dynamic> = &mut Vec::new();
 
         for image in images {
             match &image {
@@ -227,8 +252,50 @@ impl Pipeline {
                     rotation,
                     opacity,
                 } => {
-                    if let Some(atlas_entry) =
-                        cache.upload_raster(device, encoder, handle)
+                    if let crate::core::image::Handle::Dynamic(id, dynamic) =
+                        handle
+                    {
+                        if let Some(entry) = cache
+                            .upload_dynamic(device, encoder, belt, *id, dynamic)
+                        {
+                            add_dynamic_instance(
+                                [bounds.x, bounds.y],
+                                [bounds.width, bounds.height],
+                                f32::from(*rotation),
+                                *opacity,
+                                entry.size(),
+                                dynamic_instances,
+                            );
+
+                            dynamic_draws.push(Dynamic {
+                                id: *id,
+                                filter_method: *filter_method,
+                            });
+                        }
+                    } else if let crate::core::image::Handle::Compressed(
+                        id,
+                        compressed,
+                    ) = handle
+                    {
+                        if let Some(entry) = cache
+                            .upload_compressed(device, encoder, *id, compressed)
+                        {
+                            add_dynamic_instance(
+                                [bounds.x, bounds.y],
+                                [bounds.width, bounds.height],
+                                f32::from(*rotation),
+                                *opacity,
+                                entry.size(),
+                                dynamic_instances,
+                            );
+
+                            dynamic_draws.push(Dynamic {
+                                id: *id,
+                                filter_method: *filter_method,
+                            });
+                        }
+                    } else if let Some(atlas_entry) =
+                        cache.upload_raster(handle)
                     {
                         add_instances(
                             [bounds.x, bounds.y],
@@ -260,9 +327,9 @@ impl Pipeline {
                 } => {
                     let size = [bounds.width, bounds.height];
 
-                    if let Some(atlas_entry) = cache.upload_vector(
-                        device, encoder, handle, *color, size, scale,
-                    ) {
+                    if let Some(atlas_entry) =
+                        cache.upload_vector(handle, *color, size, scale)
+                    {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
@@ -278,7 +345,10 @@ impl Pipeline {
             }
         }
 
-        if nearest_instances.is_empty() && linear_instances.is_empty() {
+        if nearest_instances.is_empty()
+            && linear_instances.is_empty()
+            && dynamic_instances.is_empty()
+        {
             return;
         }
 
@@ -299,6 +369,8 @@ impl Pipeline {
             belt,
             nearest_instances,
             linear_instances,
+            dynamic_instances,
+            mem::take(dynamic_draws),
             transformation,
         );
 
@@ -325,12 +397,26 @@ impl Pipeline {
             render_pass.set_bind_group(1, cache.bind_group(), &[]);
 
             layer.render(render_pass);
+
+            #[cfg(feature = "image")]
+            layer.render_dynamic(cache, render_pass);
         }
     }
 
     pub fn end_frame(&mut self) {
         self.prepare_layer = 0;
     }
+
+    /// Returns the amount of bytes allocated by the layers of the
+    /// [`Pipeline`].
+    pub fn memory_usage(&self) -> u64 {
+        self.layers.iter().map(Layer::memory_usage).sum()
+    }
+
+    /// Drops the layers that were not prepared in the current frame.
+    pub fn shrink(&mut self) {
+        self.layers.truncate(self.prepare_layer);
+    }
 }
 
 #[derive(Debug)]
@@ -338,6 +424,17 @@ struct Layer {
     uniforms: wgpu::Buffer,
     nearest: Data,
     linear: Data,
+    dynamic_instances: Buffer<Instance>,
+    dynamic_draws: Vec<Dynamic>,
+}
+
+/// An image drawn from its own texture, instead of the atlas.
+#[derive(Debug, Clone, Copy)]
+struct Dynamic {
+    #[cfg_attr(not(feature = "image"), allow(dead_code))]
+    id: crate::core::image::Id,
+    #[cfg_attr(not(feature = "image"), allow(dead_code))]
+    filter_method: crate::core::image::FilterMethod,
 }
 
 impl Layer {
@@ -360,10 +457,19 @@ impl Layer {
         let linear =
             Data::new(device, constant_layout, linear_sampler, &uniforms);
 
+        let dynamic_instances = Buffer::new(
+            device,
+            "iced_wgpu::image dynamic instance buffer",
+            Instance::INITIAL,
+            wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        );
+
         Self {
             uniforms,
             nearest,
             linear,
+            dynamic_instances,
+            dynamic_draws: Vec::new(),
         }
     }
 
@@ -374,6 +480,8 @@ impl Layer {
         belt: &mut wgpu::util::StagingBelt,
         nearest_instances: &[Instance],
         linear_instances: &[Instance],
+        dynamic_instances: &[Instance],
+        dynamic_draws: Vec<Dynamic>,
         transformation: Transformation,
     ) {
         let uniforms = Uniforms {
@@ -395,12 +503,71 @@ impl Layer {
             .upload(device, encoder, belt, nearest_instances);
 
         self.linear.upload(device, encoder, belt, linear_instances);
+
+        self.dynamic_draws = dynamic_draws;
+
+        if !dynamic_instances.is_empty() {
+            let _ = self
+                .dynamic_instances
+                .resize(device, dynamic_instances.len());
+
+            let _ = self.dynamic_instances.write(
+                device,
+                encoder,
+                belt,
+                0,
+                dynamic_instances,
+            );
+        }
+    }
+
+    fn memory_usage(&self) -> u64 {
+        self.uniforms.size()
+            + self.nearest.instances.size()
+            + self.linear.instances.size()
+            + self.dynamic_instances.size()
     }
 
     fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
         self.nearest.render(render_pass);
         self.linear.render(render_pass);
     }
+
+    /// Renders the dynamic images of the [`Layer`], each one with the bind
+    /// group of its own texture.
+    #[cfg(feature = "image")]
+    fn render_dynamic<'a>(
+        &'a self,
+        cache: &'a Cache,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) {
+        if self.dynamic_draws.is_empty() {
+            return;
+        }
+
+        render_pass.set_vertex_buffer(0, self.dynamic_instances.slice(..));
+
+        for (i, draw) in self.dynamic_draws.iter().enumerate() {
+            let Some(bind_group) = cache.texture_bind_group(draw.id) else {
+                continue;
+            };
+
+            let constants = match draw.filter_method {
+                crate::core::image::FilterMethod::Nearest => {
+                    &self.nearest.constants
+                }
+                crate::core::image::FilterMethod::Linear => {
+                    &self.linear.constants
+                }
+            };
+
+            let i = i as u32;
+
+            render_pass.set_bind_group(0, constants, &[]);
+            render_pass.set_bind_group(1, bind_group, &[]);
+            render_pass.draw(0..6, i..i + 1);
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -562,6 +729,30 @@ fn add_instances(
     }
 }
 
+#[cfg(feature = "image")]
+fn add_dynamic_instance(
+    position: [f32; 2],
+    size: [f32; 2],
+    rotation: f32,
+    opacity: f32,
+    texture_size: Size<u32>,
+    instances: &mut Vec<Instance>,
+) {
+    let width = texture_size.width as f32;
+    let height = texture_size.height as f32;
+
+    instances.push(Instance {
+        _position: position,
+        _center: [position[0] + size[0] / 2.0, position[1] + size[1] / 2.0],
+        _size: size,
+        _rotation: rotation,
+        _opacity: opacity,
+        _position_in_atlas: [0.5 / width, 0.5 / height],
+        _size_in_atlas: [(width - 1.0) / width, (height - 1.0) / height],
+        _layer: 0,
+    });
+}
+
 #[inline]
 fn add_instance(
     position: [f32; 2],
@@ -65,8 +65,6 @@ impl Cache {
     /// Load image and upload raster data
     pub fn upload(
         &mut self,
-        device: &wgpu::Device,
-        encoder: &mut wgpu::CommandEncoder,
         handle: &image::Handle,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
@@ -75,7 +73,7 @@ impl Cache {
         if let Memory::Host(image) = memory {
             let (width, height) = image.dimensions();
 
-            let entry = atlas.upload(device, encoder, width, height, image)?;
+            let entry = atlas.upload(width, height, image)?;
 
             *memory = Memory::Device(entry);
         }
@@ -87,6 +85,13 @@ impl Cache {
         }
     }
 
+    /// Trims the cache misses from the cache, even if no new entries
+    /// have landed since the last trim.
+    pub fn evict(&mut self, atlas: &mut Atlas) {
+        self.should_trim = true;
+        self.trim(atlas);
+    }
+
     /// Trim cache misses from cache
     pub fn trim(&mut self, atlas: &mut Atlas) {
         // Only trim if new entries have landed in the `Cache`
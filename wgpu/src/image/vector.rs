@@ -1,10 +1,12 @@
 use crate::core::svg;
 use crate::core::{Color, Size};
+use crate::graphics;
 use crate::image::atlas::{self, Atlas};
 
 use resvg::tiny_skia;
 use resvg::usvg;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::borrow::Cow;
 use std::fs;
 
 /// Entry in cache corresponding to an svg handle
@@ -33,8 +35,10 @@ impl Svg {
 #[derive(Debug, Default)]
 pub struct Cache {
     svgs: FxHashMap<u64, Svg>,
+    tinted: FxHashMap<(u64, [u8; 4]), Svg>,
     rasterized: FxHashMap<(u64, u32, u32, ColorFilter), atlas::Entry>,
     svg_hits: FxHashSet<u64>,
+    tinted_hits: FxHashSet<(u64, [u8; 4])>,
     rasterized_hits: FxHashSet<(u64, u32, u32, ColorFilter)>,
     should_trim: bool,
 }
@@ -77,11 +81,44 @@ impl Cache {
         self.svgs.get(&handle.id()).unwrap()
     }
 
+    /// Load svg with its `currentColor` keywords substituted by the given
+    /// color; `None` if it does not use any
+    fn load_tinted(
+        &mut self,
+        handle: &svg::Handle,
+        color: [u8; 4],
+    ) -> Option<&usvg::Tree> {
+        let key = (handle.id(), color);
+        let _ = self.tinted_hits.insert(key);
+
+        match self.tinted.entry(key).or_insert_with(|| {
+            let [r, g, b, a] = color;
+
+            source(handle.data())
+                .and_then(|source| {
+                    graphics::image::substitute_current_color(
+                        &source,
+                        Color::from_rgba8(r, g, b, f32::from(a) / 255.0),
+                    )
+                })
+                .and_then(|tinted| {
+                    usvg::Tree::from_str(
+                        &tinted,
+                        &usvg::Options::default(), // TODO: Set usvg::Options::fontdb
+                    )
+                    .ok()
+                })
+                .map(Svg::Loaded)
+                .unwrap_or(Svg::NotFound)
+        }) {
+            Svg::Loaded(tree) => Some(tree),
+            Svg::NotFound => None,
+        }
+    }
+
     /// Load svg and upload raster data
     pub fn upload(
         &mut self,
-        device: &wgpu::Device,
-        encoder: &mut wgpu::CommandEncoder,
         handle: &svg::Handle,
         color: Option<Color>,
         [width, height]: [f32; 2],
@@ -106,11 +143,32 @@ impl Cache {
             let _ = self.svg_hits.insert(id);
             let _ = self.rasterized_hits.insert(key);
 
+            if let Some(color) = color {
+                let _ = self.tinted_hits.insert((id, color));
+            }
+
             return self.rasterized.get(&key);
         }
 
-        match self.load(handle) {
-            Svg::Loaded(tree) => {
+        // Icons that use `currentColor` are tinted before rasterization;
+        // any other icon has the color filter applied to all of its pixels
+        let is_tinted = color
+            .is_some_and(|color| self.load_tinted(handle, color).is_some());
+
+        let tree = if is_tinted {
+            match self.tinted.get(&(id, color?)) {
+                Some(Svg::Loaded(tree)) => Some(tree),
+                _ => None,
+            }
+        } else {
+            match self.load(handle) {
+                Svg::Loaded(tree) => Some(tree),
+                Svg::NotFound => None,
+            }
+        };
+
+        match tree {
+            Some(tree) => {
                 if width == 0 || height == 0 {
                     return None;
                 }
@@ -145,7 +203,7 @@ impl Cache {
 
                 let mut rgba = img.take();
 
-                if let Some(color) = color {
+                if let Some(color) = color.filter(|_| !is_tinted) {
                     rgba.chunks_exact_mut(4).for_each(|rgba| {
                         if rgba[3] > 0 {
                             rgba[0] = color[0];
@@ -155,8 +213,7 @@ impl Cache {
                     });
                 }
 
-                let allocation =
-                    atlas.upload(device, encoder, width, height, &rgba)?;
+                let allocation = atlas.upload(width, height, &rgba)?;
 
                 log::debug!("allocating {id} {width}x{height}");
 
@@ -166,10 +223,17 @@ impl Cache {
 
                 self.rasterized.get(&key)
             }
-            Svg::NotFound => None,
+            None => None,
         }
     }
 
+    /// Trims the cache misses from the cache, even if no new entries
+    /// have landed since the last trim.
+    pub fn evict(&mut self, atlas: &mut Atlas) {
+        self.should_trim = true;
+        self.trim(atlas);
+    }
+
     /// Load svg and upload raster data
     pub fn trim(&mut self, atlas: &mut Atlas) {
         if !self.should_trim {
@@ -177,9 +241,11 @@ impl Cache {
         }
 
         let svg_hits = &self.svg_hits;
+        let tinted_hits = &self.tinted_hits;
         let rasterized_hits = &self.rasterized_hits;
 
         self.svgs.retain(|k, _| svg_hits.contains(k));
+        self.tinted.retain(|k, _| tinted_hits.contains(k));
         self.rasterized.retain(|k, entry| {
             let retain = rasterized_hits.contains(k);
 
@@ -190,6 +256,7 @@ impl Cache {
             retain
         });
         self.svg_hits.clear();
+        self.tinted_hits.clear();
         self.rasterized_hits.clear();
         self.should_trim = false;
     }
@@ -203,3 +270,13 @@ impl std::fmt::Debug for Svg {
         }
     }
 }
+
+/// Returns the source of an svg, unless it is compressed
+fn source(data: &svg::Data) -> Option<Cow<'_, str>> {
+    match data {
+        svg::Data::Path(path) => fs::read_to_string(path).ok().map(Cow::Owned),
+        svg::Data::Bytes(bytes) => {
+            std::str::from_utf8(bytes).ok().map(Cow::Borrowed)
+        }
+    }
+}
@@ -27,6 +27,21 @@ pub struct Layer {
 }
 
 impl Layer {
+    /// Returns whether anything in the [`Layer`] is drawn over the meshes
+    /// added to it later.
+    pub fn covers_meshes(&self) -> bool {
+        !self.primitives.is_empty()
+            || !self.text.is_empty()
+            || !self.pending_text.is_empty()
+            || self.covers_text()
+    }
+
+    /// Returns whether anything in the [`Layer`] is drawn over the text
+    /// added to it later.
+    pub fn covers_text(&self) -> bool {
+        !self.images.is_empty()
+    }
+
     pub fn draw_quad(
         &mut self,
         quad: renderer::Quad,
@@ -95,6 +110,8 @@ impl Layer {
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
+        let font = text.font_with_features();
+
         let text = Text::Cached {
             content: text.content,
             bounds: Rectangle::new(position, text.bounds) * transformation,
@@ -102,9 +119,10 @@ impl Layer {
             size: text.size * transformation.scale_factor(),
             line_height: text.line_height.to_absolute(text.size)
                 * transformation.scale_factor(),
-            font: text.font,
+            font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            max_space_factor: text.max_space_factor,
             shaping: text.shaping,
             clip_bounds: clip_bounds * transformation,
         };
@@ -25,17 +25,22 @@
 pub mod layer;
 pub mod primitive;
 pub mod settings;
+pub mod shader;
 pub mod window;
 
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
 mod buffer;
+mod clip;
 mod color;
 mod engine;
+mod format;
+mod profile;
 mod quad;
 mod text;
 mod triangle;
+mod warm_up;
 
 #[cfg(any(feature = "image", feature = "svg"))]
 #[path = "image/mod.rs"]
@@ -64,6 +69,8 @@ use crate::core::{
     Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation,
     Vector,
 };
+use crate::graphics::memory;
+use crate::graphics::snapshot;
 use crate::graphics::text::{Editor, Paragraph};
 use crate::graphics::Viewport;
 
@@ -76,10 +83,16 @@ pub struct Renderer {
     default_font: Font,
     default_text_size: Pixels,
     layers: layer::Stack,
+    clips: Vec<clip::Clip>,
+    open_clips: Vec<Option<usize>>,
+    stencil: clip::Target,
 
     triangle_storage: triangle::Storage,
     text_storage: text::Storage,
     text_viewport: text::Viewport,
+    filter_methods: Vec<core::image::FilterMethod>,
+    snapshots: snapshot::Recorder,
+    memory_budget: Option<u64>,
 
     // TODO: Centralize all the image feature handling
     #[cfg(any(feature = "svg", feature = "image"))]
@@ -97,10 +110,16 @@ impl Renderer {
             default_font,
             default_text_size,
             layers: layer::Stack::new(),
+            clips: Vec::new(),
+            open_clips: Vec::new(),
+            stencil: clip::Target::default(),
 
             triangle_storage: triangle::Storage::new(),
             text_storage: text::Storage::new(),
             text_viewport: engine.text_pipeline.create_viewport(device),
+            filter_methods: Vec::new(),
+            snapshots: snapshot::Recorder::new(),
+            memory_budget: None,
 
             #[cfg(any(feature = "svg", feature = "image"))]
             image_cache: std::cell::RefCell::new(
@@ -109,6 +128,43 @@ impl Renderer {
         }
     }
 
+    /// Sets the amount of GPU memory, in bytes, the [`Renderer`] should try
+    /// to stay under.
+    ///
+    /// When the budget is exceeded after presenting a frame, the caches
+    /// holding resources that were not used in the frame are evicted.
+    pub fn set_memory_budget(&mut self, memory_budget: Option<u64>) {
+        self.memory_budget = memory_budget;
+    }
+
+    /// Returns an approximation of the GPU memory used by the [`Renderer`]
+    /// and the [`Engine`] it presents with.
+    pub fn memory_usage(&self, engine: &Engine) -> memory::Usage {
+        #[cfg(any(feature = "svg", feature = "image"))]
+        let images = self.image_cache.borrow().memory_usage();
+
+        #[cfg(not(any(feature = "svg", feature = "image")))]
+        let images = 0;
+
+        #[cfg(any(feature = "svg", feature = "image"))]
+        let image_buffers = engine.image_pipeline.memory_usage();
+
+        #[cfg(not(any(feature = "svg", feature = "image")))]
+        let image_buffers = 0;
+
+        memory::Usage {
+            images,
+            glyphs: engine.text_pipeline.memory_usage()
+                + self.text_storage.memory_usage(),
+            buffers: engine.quad_pipeline.memory_usage()
+                + engine.triangle_pipeline.memory_usage()
+                + engine.clip_pipeline.memory_usage()
+                + self.stencil.memory_usage()
+                + image_buffers,
+            geometry: self.triangle_storage.memory_usage(),
+        }
+    }
+
     pub fn present<T: AsRef<str>>(
         &mut self,
         engine: &mut Engine,
@@ -121,10 +177,51 @@ impl Renderer {
         viewport: &Viewport,
         overlay: &[T],
     ) {
-        self.draw_overlay(overlay, viewport);
+        if overlay.is_empty() {
+            self.draw_overlay(overlay, viewport);
+        } else {
+            let usage = self.memory_usage(engine);
+
+            let budget = match self.memory_budget {
+                Some(budget) => memory::Bytes(budget).to_string(),
+                None => String::from("none"),
+            };
+
+            let lines: Vec<String> = overlay
+                .iter()
+                .map(|line| line.as_ref().to_owned())
+                .chain([
+                    format!(
+                        "GPU memory: {} (budget: {budget})",
+                        memory::Bytes(usage.total())
+                    ),
+                    format!("  {usage}"),
+                ])
+                .collect();
+
+            self.draw_overlay(&lines, viewport);
+        }
+
         self.prepare(engine, device, queue, format, encoder, viewport);
         self.render(engine, encoder, frame, clear_color, viewport);
 
+        if let Some(budget) = self.memory_budget {
+            let usage = self.memory_usage(engine);
+
+            if usage.total() > budget {
+                self.relieve(engine, device, queue, encoder);
+
+                let freed = usage.freed(&self.memory_usage(engine));
+
+                log::info!(
+                    "GPU memory over budget ({} > {}); freed {} ({freed})",
+                    memory::Bytes(usage.total()),
+                    memory::Bytes(budget),
+                    memory::Bytes(freed.total()),
+                );
+            }
+        }
+
         self.triangle_storage.trim();
         self.text_storage.trim();
 
@@ -132,6 +229,31 @@ impl Renderer {
         self.image_cache.borrow_mut().trim();
     }
 
+    /// Frees the GPU resources that were not used in the current frame.
+    fn relieve(
+        &mut self,
+        engine: &mut Engine,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        #[cfg(any(feature = "svg", feature = "image"))]
+        {
+            self.image_cache.borrow_mut().evict(device, encoder);
+            engine.image_pipeline.shrink();
+        }
+
+        #[cfg(not(any(feature = "svg", feature = "image")))]
+        let _ = encoder;
+
+        engine.text_pipeline.evict(device, queue);
+        self.text_storage.clear();
+
+        self.triangle_storage.clear();
+        engine.triangle_pipeline.shrink();
+        engine.quad_pipeline.shrink();
+    }
+
     fn prepare(
         &mut self,
         engine: &mut Engine,
@@ -141,9 +263,22 @@ impl Renderer {
         encoder: &mut wgpu::CommandEncoder,
         viewport: &Viewport,
     ) {
+        let _scope = profile::stage("prepare");
         let scale_factor = viewport.scale_factor() as f32;
 
+        engine.reload_shaders(device);
+
         self.text_viewport.update(queue, viewport.physical_size());
+        self.stencil.prepare(device, viewport.physical_size());
+
+        engine.clip_pipeline.prepare(
+            device,
+            encoder,
+            &mut engine.staging_belt,
+            &self.clips,
+            viewport.projection() * Transformation::scale(scale_factor),
+            viewport.physical_size(),
+        );
 
         for layer in self.layers.iter_mut() {
             if !layer.quads.is_empty() {
@@ -171,6 +306,8 @@ impl Renderer {
 
             if !layer.primitives.is_empty() {
                 for instance in &layer.primitives {
+                    let _scope = profile::primitive("prepare", instance);
+
                     instance.primitive.prepare(
                         device,
                         queue,
@@ -208,6 +345,10 @@ impl Renderer {
                 );
             }
         }
+
+        // All the images of the frame are uploaded at once
+        #[cfg(any(feature = "svg", feature = "image"))]
+        self.image_cache.borrow_mut().flush(device, encoder);
     }
 
     fn render(
@@ -220,6 +361,8 @@ impl Renderer {
     ) {
         use std::mem::ManuallyDrop;
 
+        let _scope = profile::stage("render");
+
         let mut render_pass = ManuallyDrop::new(encoder.begin_render_pass(
             &wgpu::RenderPassDescriptor {
                 label: Some("iced_wgpu render pass"),
@@ -230,13 +373,22 @@ impl Renderer {
                         load: match clear_color {
                             Some(background_color) => wgpu::LoadOp::Clear({
                                 let [r, g, b, a] =
-                                    graphics::color::pack(background_color)
-                                        .components();
-
+                                    if format::encodes_srgb(engine.format) {
+                                        let Color { r, g, b, a } =
+                                            background_color;
+
+                                        [r, g, b, a]
+                                    } else {
+                                        graphics::color::pack(background_color)
+                                            .components()
+                                    };
+
+                                // Premultiplied, so translucent backgrounds
+                                // blend like the rest of the frame
                                 wgpu::Color {
-                                    r: f64::from(r),
-                                    g: f64::from(g),
-                                    b: f64::from(b),
+                                    r: f64::from(r * a),
+                                    g: f64::from(g * a),
+                                    b: f64::from(b * a),
                                     a: f64::from(a),
                                 }
                             }),
@@ -245,7 +397,16 @@ impl Renderer {
                         store: wgpu::StoreOp::Store,
                     },
                 })],
-                depth_stencil_attachment: None,
+                depth_stencil_attachment: Some(
+                    wgpu::RenderPassDepthStencilAttachment {
+                        view: self.stencil.view(),
+                        depth_ops: None,
+                        stencil_ops: Some(wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(0),
+                            store: wgpu::StoreOp::Store,
+                        }),
+                    },
+                ),
                 timestamp_writes: None,
                 occlusion_query_set: None,
             },
@@ -267,7 +428,40 @@ impl Renderer {
 
         let scale = Transformation::scale(scale_factor);
 
-        for layer in self.layers.iter() {
+        let mut clips = self.clips.iter().enumerate().peekable();
+        let mut open_clips: Vec<usize> = Vec::new();
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            // Clips are left innermost first and entered outermost first
+            while let Some(&clip) = open_clips.last() {
+                if self.clips[clip].layers.end > i {
+                    break;
+                }
+
+                engine.clip_pipeline.pop(
+                    clip,
+                    open_clips.len() as u32,
+                    &mut render_pass,
+                );
+
+                let _ = open_clips.pop();
+            }
+
+            while let Some((clip, _)) =
+                clips.next_if(|(_, clip)| clip.layers.start == i)
+            {
+                open_clips.push(clip);
+
+                engine.clip_pipeline.push(
+                    clip,
+                    open_clips.len() as u32,
+                    &mut render_pass,
+                );
+            }
+
+            let clip_depth = open_clips.len() as u32;
+            render_pass.set_stencil_reference(clip_depth);
+
             let Some(physical_bounds) =
                 physical_bounds.intersection(&(layer.bounds * scale))
             else {
@@ -279,6 +473,8 @@ impl Renderer {
             };
 
             if !layer.quads.is_empty() {
+                profile::push(&mut *render_pass, "iced_wgpu quads");
+
                 engine.quad_pipeline.render(
                     quad_layer,
                     scissor_rect,
@@ -286,12 +482,16 @@ impl Renderer {
                     &mut render_pass,
                 );
 
+                profile::pop(&mut *render_pass);
+
                 quad_layer += 1;
             }
 
             if !layer.triangles.is_empty() {
                 let _ = ManuallyDrop::into_inner(render_pass);
 
+                profile::push(encoder, "iced_wgpu triangles");
+
                 mesh_layer += engine.triangle_pipeline.render(
                     encoder,
                     frame,
@@ -300,8 +500,12 @@ impl Renderer {
                     &layer.triangles,
                     physical_bounds,
                     scale,
+                    self.stencil.view(),
+                    clip_depth,
                 );
 
+                profile::pop(encoder);
+
                 render_pass = ManuallyDrop::new(encoder.begin_render_pass(
                     &wgpu::RenderPassDescriptor {
                         label: Some("iced_wgpu render pass"),
@@ -315,13 +519,19 @@ impl Renderer {
                                 },
                             },
                         )],
-                        depth_stencil_attachment: None,
+                        depth_stencil_attachment: Some(clip::attachment(
+                            self.stencil.view(),
+                        )),
                         timestamp_writes: None,
                         occlusion_query_set: None,
                     },
                 ));
+
+                render_pass.set_stencil_reference(clip_depth);
             }
 
+            // Custom primitives are only clipped by the bounds of the
+            // layer; they render with their own passes
             if !layer.primitives.is_empty() {
                 let _ = ManuallyDrop::into_inner(render_pass);
 
@@ -330,12 +540,17 @@ impl Renderer {
                         .intersection(&physical_bounds)
                         .and_then(Rectangle::snap)
                     {
+                        let _scope = profile::primitive("render", instance);
+                        profile::push_primitive(encoder, instance);
+
                         instance.primitive.render(
                             encoder,
                             &engine.primitive_storage,
                             frame,
                             &clip_bounds,
                         );
+
+                        profile::pop(encoder);
                     }
                 }
 
@@ -352,14 +567,20 @@ impl Renderer {
                                 },
                             },
                         )],
-                        depth_stencil_attachment: None,
+                        depth_stencil_attachment: Some(clip::attachment(
+                            self.stencil.view(),
+                        )),
                         timestamp_writes: None,
                         occlusion_query_set: None,
                     },
                 ));
+
+                render_pass.set_stencil_reference(clip_depth);
             }
 
             if !layer.text.is_empty() {
+                profile::push(&mut *render_pass, "iced_wgpu text");
+
                 text_layer += engine.text_pipeline.render(
                     &self.text_viewport,
                     &self.text_storage,
@@ -368,10 +589,14 @@ impl Renderer {
                     scissor_rect,
                     &mut render_pass,
                 );
+
+                profile::pop(&mut *render_pass);
             }
 
             #[cfg(any(feature = "svg", feature = "image"))]
             if !layer.images.is_empty() {
+                profile::push(&mut *render_pass, "iced_wgpu images");
+
                 engine.image_pipeline.render(
                     &image_cache,
                     image_layer,
@@ -379,6 +604,8 @@ impl Renderer {
                     &mut render_pass,
                 );
 
+                profile::pop(&mut *render_pass);
+
                 image_layer += 1;
             }
         }
@@ -386,6 +613,69 @@ impl Renderer {
         let _ = ManuallyDrop::into_inner(render_pass);
     }
 
+    /// Draws the images of some geometry; each in a layer of its own if it
+    /// overflows its clip bounds.
+    #[cfg(feature = "geometry")]
+    fn draw_geometry_images(
+        &mut self,
+        images: &[(graphics::Image, Rectangle)],
+    ) {
+        for (image, clip_bounds) in images {
+            let is_clipped = !image.bounds().is_within(clip_bounds);
+
+            if is_clipped {
+                self.layers.push_clip(*clip_bounds);
+            }
+
+            let (layer, transformation) = self.layers.current_mut();
+
+            match image.clone() {
+                graphics::Image::Raster {
+                    handle,
+                    filter_method,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    let filter_method = self
+                        .filter_methods
+                        .last()
+                        .copied()
+                        .unwrap_or(filter_method);
+
+                    layer.draw_image(
+                        handle,
+                        filter_method,
+                        bounds,
+                        transformation,
+                        rotation,
+                        opacity,
+                    );
+                }
+                graphics::Image::Vector {
+                    handle,
+                    color,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    layer.draw_svg(
+                        handle,
+                        color,
+                        bounds,
+                        transformation,
+                        rotation,
+                        opacity,
+                    );
+                }
+            }
+
+            if is_clipped {
+                self.layers.pop_clip();
+            }
+        }
+    }
+
     fn draw_overlay(
         &mut self,
         overlay: &[impl AsRef<str>],
@@ -407,6 +697,8 @@ impl Renderer {
                         font: Font::MONOSPACE,
                         horizontal_alignment: alignment::Horizontal::Left,
                         vertical_alignment: alignment::Vertical::Top,
+                        max_space_factor: core::text::DEFAULT_MAX_SPACE_FACTOR,
+                        font_features: &[],
                         shaping: core::text::Shaping::Basic,
                     };
 
@@ -430,34 +722,216 @@ impl Renderer {
     }
 }
 
+impl Renderer {
+    /// Draws the given snapshot commands.
+    fn replay(
+        &mut self,
+        commands: impl IntoIterator<Item = snapshot::Command>,
+    ) {
+        use crate::core::text::Renderer as _;
+        use crate::core::Renderer as _;
+        use crate::graphics::mesh::Renderer as _;
+
+        for command in commands {
+            match command {
+                snapshot::Command::StartLayer(bounds) => {
+                    self.start_layer(bounds);
+                }
+                snapshot::Command::EndLayer => self.end_layer(),
+                snapshot::Command::StartTransformation(transformation) => {
+                    self.start_transformation(transformation);
+                }
+                snapshot::Command::EndTransformation => {
+                    self.end_transformation();
+                }
+                snapshot::Command::StartFilterMethod(filter_method) => {
+                    self.start_filter_method(filter_method);
+                }
+                snapshot::Command::EndFilterMethod => self.end_filter_method(),
+                snapshot::Command::Quad(quad, background) => {
+                    self.fill_quad(quad, background);
+                }
+                snapshot::Command::Paragraph {
+                    paragraph,
+                    position,
+                    color,
+                    clip_bounds,
+                } => {
+                    self.fill_paragraph(
+                        &paragraph,
+                        position,
+                        color,
+                        clip_bounds,
+                    );
+                }
+                snapshot::Command::Text {
+                    text,
+                    position,
+                    color,
+                    clip_bounds,
+                } => {
+                    self.fill_text(text, position, color, clip_bounds);
+                }
+                #[cfg(feature = "image")]
+                snapshot::Command::Image {
+                    handle,
+                    filter_method,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    core::image::Renderer::draw_image(
+                        self,
+                        handle,
+                        filter_method,
+                        bounds,
+                        rotation,
+                        opacity,
+                    );
+                }
+                #[cfg(feature = "svg")]
+                snapshot::Command::Svg {
+                    handle,
+                    color_filter,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
+                    core::svg::Renderer::draw_svg(
+                        self,
+                        handle,
+                        color_filter,
+                        bounds,
+                        rotation,
+                        opacity,
+                    );
+                }
+                #[cfg(not(feature = "image"))]
+                snapshot::Command::Image { .. } => {}
+                #[cfg(not(feature = "svg"))]
+                snapshot::Command::Svg { .. } => {}
+                snapshot::Command::Mesh(mesh) => self.draw_mesh(mesh),
+            }
+        }
+    }
+
+    /// Interrupts the snapshots in progress; drawing what they recorded so
+    /// far right away.
+    fn interrupt_snapshots(&mut self) {
+        let commands = self.snapshots.interrupt();
+
+        self.replay(commands);
+    }
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::StartLayer(bounds));
+            return;
+        }
+
         self.layers.push_clip(bounds);
     }
 
     fn end_layer(&mut self) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::EndLayer);
+            return;
+        }
+
         self.layers.pop_clip();
     }
 
     fn start_transformation(&mut self, transformation: Transformation) {
+        if self.snapshots.is_recording() {
+            self.snapshots
+                .record(snapshot::Command::StartTransformation(transformation));
+            return;
+        }
+
         self.layers.push_transformation(transformation);
     }
 
     fn end_transformation(&mut self) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::EndTransformation);
+            return;
+        }
+
         self.layers.pop_transformation();
     }
 
+    fn start_filter_method(
+        &mut self,
+        filter_method: core::image::FilterMethod,
+    ) {
+        if self.snapshots.is_recording() {
+            self.snapshots
+                .record(snapshot::Command::StartFilterMethod(filter_method));
+            return;
+        }
+
+        self.filter_methods.push(filter_method);
+    }
+
+    fn end_filter_method(&mut self) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::EndFilterMethod);
+            return;
+        }
+
+        let _ = self.filter_methods.pop();
+    }
+
     fn fill_quad(
         &mut self,
         quad: core::renderer::Quad,
         background: impl Into<Background>,
     ) {
+        let background = background.into();
+
+        if self.snapshots.is_recording() {
+            self.snapshots
+                .record(snapshot::Command::Quad(quad, background));
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+        layer.draw_quad(quad, background, transformation);
+    }
+
+    fn start_snapshot(&mut self) {
+        self.snapshots.start();
+    }
+
+    fn end_snapshot(&mut self) -> Option<core::renderer::Snapshot> {
+        self.snapshots.end().map(core::renderer::Snapshot::new)
+    }
+
+    fn draw_snapshot(
+        &mut self,
+        snapshot: &core::renderer::Snapshot,
+        opacity: f32,
+    ) {
+        let Some(commands) = snapshot.downcast_ref::<Vec<snapshot::Command>>()
+        else {
+            return;
+        };
+
+        self.replay(
+            commands
+                .iter()
+                .cloned()
+                .map(|command| command.scale_alpha(opacity)),
+        );
     }
 
     fn clear(&mut self) {
         self.layers.clear();
+        self.snapshots.clear();
+        self.clips.clear();
+        self.open_clips.clear();
     }
 }
 
@@ -485,6 +959,16 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Paragraph {
+                paragraph: text.clone(),
+                position,
+                color,
+                clip_bounds,
+            });
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
 
         layer.draw_paragraph(
@@ -503,17 +987,39 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        self.interrupt_snapshots();
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_editor(editor, position, color, clip_bounds, transformation);
     }
 
     fn fill_text(
         &mut self,
-        text: core::Text,
+        text: impl Into<core::text::Fill<'_, Self::Paragraph>>,
         position: Point,
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        use crate::core::text::Fill;
+
+        let text = match text.into() {
+            Fill::Text(text) => text,
+            Fill::Cached(paragraph) => {
+                self.fill_paragraph(paragraph, position, color, clip_bounds);
+                return;
+            }
+        };
+
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Text {
+                text,
+                position,
+                color,
+                clip_bounds,
+            });
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_text(text, position, color, clip_bounds, transformation);
     }
@@ -535,6 +1041,20 @@ impl core::image::Renderer for Renderer {
         rotation: core::Radians,
         opacity: f32,
     ) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Image {
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity,
+            });
+            return;
+        }
+
+        let filter_method =
+            self.filter_methods.last().copied().unwrap_or(filter_method);
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_image(
             handle,
@@ -561,6 +1081,17 @@ impl core::svg::Renderer for Renderer {
         rotation: core::Radians,
         opacity: f32,
     ) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Svg {
+                handle,
+                color_filter,
+                bounds,
+                rotation,
+                opacity,
+            });
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_svg(
             handle,
@@ -575,6 +1106,11 @@ impl core::svg::Renderer for Renderer {
 
 impl graphics::mesh::Renderer for Renderer {
     fn draw_mesh(&mut self, mesh: graphics::Mesh) {
+        if self.snapshots.is_recording() {
+            self.snapshots.record(snapshot::Command::Mesh(mesh));
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_mesh(mesh, transformation);
     }
@@ -589,13 +1125,54 @@ impl graphics::geometry::Renderer for Renderer {
         geometry::Frame::new(size)
     }
 
+    fn start_clip(&mut self, path: &graphics::geometry::Path) {
+        self.interrupt_snapshots();
+
+        if self.open_clips.len() >= clip::MAX_DEPTH {
+            self.layers.push_clip(path.bounds());
+            self.open_clips.push(None);
+
+            return;
+        }
+
+        let mask = geometry::clip_mask(path, self.layers.transformation());
+
+        self.layers.push_clip(path.bounds());
+
+        let start = self.layers.as_slice().len() - 1;
+
+        self.open_clips.push(Some(self.clips.len()));
+        self.clips.push(clip::Clip {
+            layers: start..start,
+            mask,
+        });
+    }
+
+    fn end_clip(&mut self) {
+        self.interrupt_snapshots();
+
+        if let Some(Some(clip)) = self.open_clips.pop() {
+            self.clips[clip].layers.end = self.layers.as_slice().len();
+        }
+
+        self.layers.pop_clip();
+    }
+
     fn draw_geometry(&mut self, geometry: Self::Geometry) {
+        self.interrupt_snapshots();
+
         let (layer, transformation) = self.layers.current_mut();
 
         match geometry {
-            Geometry::Live { meshes, text } => {
+            Geometry::Live {
+                meshes,
+                text,
+                images,
+            } => {
                 layer.draw_mesh_group(meshes, transformation);
                 layer.draw_text_group(text, transformation);
+
+                self.draw_geometry_images(&images);
             }
             Geometry::Cached(cache) => {
                 if let Some(meshes) = cache.meshes {
@@ -605,13 +1182,40 @@ impl graphics::geometry::Renderer for Renderer {
                 if let Some(text) = cache.text {
                     layer.draw_text_cache(text, transformation);
                 }
+
+                self.draw_geometry_images(&cache.images);
             }
         }
     }
+
+    fn draw_geometry_over(&mut self, geometry: Self::Geometry) {
+        self.interrupt_snapshots();
+
+        let (has_meshes, has_text) = match &geometry {
+            Geometry::Live { meshes, text, .. } => {
+                (!meshes.is_empty(), !text.is_empty())
+            }
+            Geometry::Cached(cache) => {
+                (cache.meshes.is_some(), cache.text.is_some())
+            }
+        };
+
+        let (layer, _) = self.layers.current_mut();
+
+        if has_meshes && layer.covers_meshes()
+            || has_text && layer.covers_text()
+        {
+            self.layers.split();
+        }
+
+        self.draw_geometry(geometry);
+    }
 }
 
 impl primitive::Renderer for Renderer {
     fn draw_primitive(&mut self, bounds: Rectangle, primitive: impl Primitive) {
+        self.interrupt_snapshots();
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_primitive(bounds, Box::new(primitive), transformation);
     }
@@ -30,6 +30,14 @@ pub trait Primitive: Debug + Send + Sync + 'static {
         target: &wgpu::TextureView,
         clip_bounds: &Rectangle<u32>,
     );
+
+    /// Returns the name of the [`Primitive`], used to label it when
+    /// profiling.
+    ///
+    /// By default, it is the name of its type.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,124 @@
+//! Profile the pipelines of a frame.
+//!
+//! With the `tracing` feature, custom primitives are prepared and rendered
+//! inside spans labeled with their type and bounds, and the draw calls of
+//! every pipeline are recorded in debug groups; so graphics debuggers, like
+//! RenderDoc or Xcode, can tell them apart.
+//!
+//! Without the feature, all of this compiles to nothing.
+use crate::primitive;
+
+/// A profiling scope, which ends when dropped.
+#[must_use]
+pub struct Scope {
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Enters the scope of a stage of the renderer; like `prepare`.
+#[inline(always)]
+pub fn stage(name: &'static str) -> Scope {
+    #[cfg(not(feature = "tracing"))]
+    let _ = name;
+
+    Scope {
+        #[cfg(feature = "tracing")]
+        _span: tracing::info_span!("iced_wgpu", stage = name).entered(),
+    }
+}
+
+/// Enters the scope of a stage of the given custom primitive.
+#[inline(always)]
+pub fn primitive(stage: &'static str, instance: &primitive::Instance) -> Scope {
+    #[cfg(not(feature = "tracing"))]
+    let _ = (stage, instance);
+
+    Scope {
+        #[cfg(feature = "tracing")]
+        _span: tracing::info_span!(
+            "primitive",
+            stage,
+            primitive = instance.primitive.name(),
+            bounds = ?instance.bounds,
+        )
+        .entered(),
+    }
+}
+
+/// Something that can record debug groups.
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub trait Recorder {
+    /// Starts a debug group with the given label.
+    fn push_group(&mut self, label: &str);
+
+    /// Ends the last debug group started.
+    fn pop_group(&mut self);
+}
+
+impl Recorder for wgpu::RenderPass<'_> {
+    fn push_group(&mut self, label: &str) {
+        self.push_debug_group(label);
+    }
+
+    fn pop_group(&mut self) {
+        self.pop_debug_group();
+    }
+}
+
+impl Recorder for wgpu::CommandEncoder {
+    fn push_group(&mut self, label: &str) {
+        self.push_debug_group(label);
+    }
+
+    fn pop_group(&mut self) {
+        self.pop_debug_group();
+    }
+}
+
+/// Starts a debug group with the given label in the [`Recorder`].
+#[inline(always)]
+pub fn push(recorder: &mut impl Recorder, label: &'static str) {
+    #[cfg(feature = "tracing")]
+    recorder.push_group(label);
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = (recorder, label);
+}
+
+/// Starts a debug group for the given custom primitive in the [`Recorder`].
+#[inline(always)]
+pub fn push_primitive(
+    recorder: &mut impl Recorder,
+    instance: &primitive::Instance,
+) {
+    #[cfg(feature = "tracing")]
+    recorder.push_group(&format!(
+        "iced_wgpu primitive: {} at {:?}",
+        instance.primitive.name(),
+        instance.bounds
+    ));
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = (recorder, instance);
+}
+
+/// Ends the last debug group started in the [`Recorder`].
+#[inline(always)]
+pub fn pop(recorder: &mut impl Recorder) {
+    #[cfg(feature = "tracing")]
+    recorder.pop_group();
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = recorder;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(not(feature = "tracing"))]
+    fn scopes_compile_away_without_tracing() {
+        assert_eq!(std::mem::size_of::<Scope>(), 0);
+    }
+}
@@ -80,6 +80,11 @@ impl Pipeline {
         }
     }
 
+    pub fn reload(&mut self, device: &wgpu::Device) {
+        self.solid.reload(device);
+        self.gradient.reload(device);
+    }
+
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -147,6 +152,17 @@ impl Pipeline {
     pub fn end_frame(&mut self) {
         self.prepare_layer = 0;
     }
+
+    /// Returns the amount of bytes allocated by the layers of the
+    /// [`Pipeline`].
+    pub fn memory_usage(&self) -> u64 {
+        self.layers.iter().map(Layer::memory_usage).sum()
+    }
+
+    /// Drops the layers that were not prepared in the current frame.
+    pub fn shrink(&mut self) {
+        self.layers.truncate(self.prepare_layer);
+    }
 }
 
 #[derive(Debug)]
@@ -227,6 +243,13 @@ impl Layer {
         )
         .copy_from_slice(bytes);
     }
+
+    /// Returns the amount of bytes allocated by the [`Layer`].
+    pub fn memory_usage(&self) -> u64 {
+        self.constants_buffer.size()
+            + self.solid.memory_usage()
+            + self.gradient.memory_usage()
+    }
 }
 
 /// A group of [`Quad`]s rendered together.
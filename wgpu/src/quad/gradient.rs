@@ -1,3 +1,4 @@
+use crate::clip;
 use crate::graphics::gradient;
 use crate::quad::{self, Quad};
 use crate::Buffer;
@@ -55,12 +56,23 @@ impl Layer {
 
         self.instance_count = instances.len();
     }
+
+    /// Returns the amount of bytes allocated by the [`Layer`].
+    pub fn memory_usage(&self) -> u64 {
+        self.instances.size()
+    }
 }
 
 #[derive(Debug)]
 pub struct Pipeline {
     #[cfg(not(target_arch = "wasm32"))]
     pipeline: wgpu::RenderPipeline,
+    #[cfg(not(target_arch = "wasm32"))]
+    layout: wgpu::PipelineLayout,
+    #[cfg(not(target_arch = "wasm32"))]
+    format: wgpu::TextureFormat,
+    #[cfg(not(target_arch = "wasm32"))]
+    shader: crate::shader::Module,
 }
 
 impl Pipeline {
@@ -73,6 +85,7 @@ impl Pipeline {
         #[cfg(not(target_arch = "wasm32"))]
         {
             use crate::graphics::color;
+            use crate::shader;
 
             let layout = device.create_pipeline_layout(
                 &wgpu::PipelineLayoutDescriptor {
@@ -82,104 +95,124 @@ impl Pipeline {
                 },
             );
 
-            let shader =
-                device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                    label: Some("iced_wgpu.quad.gradient.shader"),
-                    source: wgpu::ShaderSource::Wgsl(
-                        std::borrow::Cow::Borrowed(
-                            if color::GAMMA_CORRECTION {
-                                concat!(
-                                    include_str!("../shader/quad.wgsl"),
-                                    "\n",
-                                    include_str!("../shader/vertex.wgsl"),
-                                    "\n",
-                                    include_str!(
-                                        "../shader/quad/gradient.wgsl"
-                                    ),
-                                    "\n",
-                                    include_str!("../shader/color/oklab.wgsl")
-                                )
-                            } else {
-                                concat!(
-                                    include_str!("../shader/quad.wgsl"),
-                                    "\n",
-                                    include_str!("../shader/vertex.wgsl"),
-                                    "\n",
-                                    include_str!(
-                                        "../shader/quad/gradient.wgsl"
-                                    ),
-                                    "\n",
-                                    include_str!(
-                                        "../shader/color/linear_rgb.wgsl"
-                                    )
-                                )
-                            },
-                        ),
+            let shader = shader::Module::engine(
+                "iced_wgpu.quad.gradient.shader",
+                &[
+                    ("quad.wgsl", include_str!("../shader/quad.wgsl")),
+                    ("vertex.wgsl", include_str!("../shader/vertex.wgsl")),
+                    (
+                        "quad/gradient.wgsl",
+                        include_str!("../shader/quad/gradient.wgsl"),
                     ),
-                });
-
-            let pipeline = device.create_render_pipeline(
-                &wgpu::RenderPipelineDescriptor {
-                    label: Some("iced_wgpu.quad.gradient.pipeline"),
-                    layout: Some(&layout),
-                    vertex: wgpu::VertexState {
-                        module: &shader,
-                        entry_point: "gradient_vs_main",
-                        buffers: &[wgpu::VertexBufferLayout {
-                            array_stride: std::mem::size_of::<Gradient>()
-                                as u64,
-                            step_mode: wgpu::VertexStepMode::Instance,
-                            attributes: &wgpu::vertex_attr_array!(
-                                // Colors 1-2
-                                0 => Uint32x4,
-                                // Colors 3-4
-                                1 => Uint32x4,
-                                // Colors 5-6
-                                2 => Uint32x4,
-                                // Colors 7-8
-                                3 => Uint32x4,
-                                // Offsets 1-8
-                                4 => Uint32x4,
-                                // Direction
-                                5 => Float32x4,
-                                // Position & Scale
-                                6 => Float32x4,
-                                // Border color
-                                7 => Float32x4,
-                                // Border radius
-                                8 => Float32x4,
-                                // Border width
-                                9 => Float32
-                            ),
-                        }],
-                    },
-                    fragment: Some(wgpu::FragmentState {
-                        module: &shader,
-                        entry_point: "gradient_fs_main",
-                        targets: &quad::color_target_state(format),
-                    }),
-                    primitive: wgpu::PrimitiveState {
-                        topology: wgpu::PrimitiveTopology::TriangleList,
-                        front_face: wgpu::FrontFace::Cw,
-                        ..Default::default()
-                    },
-                    depth_stencil: None,
-                    multisample: wgpu::MultisampleState {
-                        count: 1,
-                        mask: !0,
-                        alpha_to_coverage_enabled: false,
+                    if color::GAMMA_CORRECTION {
+                        (
+                            "color/oklab.wgsl",
+                            include_str!("../shader/color/oklab.wgsl"),
+                        )
+                    } else {
+                        (
+                            "color/linear_rgb.wgsl",
+                            include_str!("../shader/color/linear_rgb.wgsl"),
+                        )
                     },
-                    multiview: None,
-                },
+                    crate::color::tone_mapping(format),
+                ],
             );
 
-            Self { pipeline }
+            let pipeline =
+                Self::create(device, &layout, format, &shader.create(device));
+
+            Self {
+                pipeline,
+                layout,
+                format,
+                shader,
+            }
         }
 
         #[cfg(target_arch = "wasm32")]
         Self {}
     }
 
+    #[allow(unused_variables)]
+    pub fn reload(&mut self, device: &wgpu::Device) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Self {
+                pipeline,
+                layout,
+                format,
+                shader,
+            } = self;
+
+            if let Some(reloaded) = shader.reload(device, |module| {
+                Self::create(device, layout, *format, module)
+            }) {
+                *pipeline = reloaded;
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn create(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+        shader: &wgpu::ShaderModule,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_wgpu.quad.gradient.pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "gradient_vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<Gradient>() as u64,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array!(
+                        // Colors 1-2
+                        0 => Uint32x4,
+                        // Colors 3-4
+                        1 => Uint32x4,
+                        // Colors 5-6
+                        2 => Uint32x4,
+                        // Colors 7-8
+                        3 => Uint32x4,
+                        // Offsets 1-8
+                        4 => Uint32x4,
+                        // Direction
+                        5 => Float32x4,
+                        // Position & Scale
+                        6 => Float32x4,
+                        // Border color
+                        7 => Float32x4,
+                        // Border radius
+                        8 => Float32x4,
+                        // Border width
+                        9 => Float32
+                    ),
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "gradient_fs_main",
+                targets: &quad::color_target_state(format),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                front_face: wgpu::FrontFace::Cw,
+                ..Default::default()
+            },
+            depth_stencil: Some(clip::stencil()),
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+        })
+    }
+
     #[allow(unused_variables)]
     pub fn render<'a>(
         &'a self,
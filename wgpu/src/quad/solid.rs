@@ -1,5 +1,7 @@
+use crate::clip;
 use crate::graphics::color;
 use crate::quad::{self, Quad};
+use crate::shader;
 use crate::Buffer;
 
 use bytemuck::{Pod, Zeroable};
@@ -49,11 +51,19 @@ impl Layer {
 
         self.instance_count = instances.len();
     }
+
+    /// Returns the amount of bytes allocated by the [`Layer`].
+    pub fn memory_usage(&self) -> u64 {
+        self.instances.size()
+    }
 }
 
 #[derive(Debug)]
 pub struct Pipeline {
     pipeline: wgpu::RenderPipeline,
+    layout: wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    shader: shader::Module,
 }
 
 impl Pipeline {
@@ -69,72 +79,97 @@ impl Pipeline {
                 bind_group_layouts: &[constants_layout],
             });
 
-        let shader =
-            device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                label: Some("iced_wgpu.quad.solid.shader"),
-                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
-                    concat!(
-                        include_str!("../shader/quad.wgsl"),
-                        "\n",
-                        include_str!("../shader/vertex.wgsl"),
-                        "\n",
-                        include_str!("../shader/quad/solid.wgsl"),
-                    ),
-                )),
-            });
+        let shader = shader::Module::engine(
+            "iced_wgpu.quad.solid.shader",
+            &[
+                ("quad.wgsl", include_str!("../shader/quad.wgsl")),
+                ("vertex.wgsl", include_str!("../shader/vertex.wgsl")),
+                ("quad/solid.wgsl", include_str!("../shader/quad/solid.wgsl")),
+                crate::color::tone_mapping(format),
+            ],
+        );
 
         let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("iced_wgpu.quad.solid.pipeline"),
-                layout: Some(&layout),
-                vertex: wgpu::VertexState {
-                    module: &shader,
-                    entry_point: "solid_vs_main",
-                    buffers: &[wgpu::VertexBufferLayout {
-                        array_stride: std::mem::size_of::<Solid>() as u64,
-                        step_mode: wgpu::VertexStepMode::Instance,
-                        attributes: &wgpu::vertex_attr_array!(
-                            // Color
-                            0 => Float32x4,
-                            // Position
-                            1 => Float32x2,
-                            // Size
-                            2 => Float32x2,
-                            // Border color
-                            3 => Float32x4,
-                            // Border radius
-                            4 => Float32x4,
-                            // Border width
-                            5 => Float32,
-                            // Shadow color
-                            6 => Float32x4,
-                            // Shadow offset
-                            7 => Float32x2,
-                            // Shadow blur radius
-                            8 => Float32,
-                        ),
-                    }],
-                },
-                fragment: Some(wgpu::FragmentState {
-                    module: &shader,
-                    entry_point: "solid_fs_main",
-                    targets: &quad::color_target_state(format),
-                }),
-                primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleList,
-                    front_face: wgpu::FrontFace::Cw,
-                    ..Default::default()
-                },
-                depth_stencil: None,
-                multisample: wgpu::MultisampleState {
-                    count: 1,
-                    mask: !0,
-                    alpha_to_coverage_enabled: false,
-                },
-                multiview: None,
-            });
+            Self::create(device, &layout, format, &shader.create(device));
+
+        Self {
+            pipeline,
+            layout,
+            format,
+            shader,
+        }
+    }
+
+    pub fn reload(&mut self, device: &wgpu::Device) {
+        let Self {
+            pipeline,
+            layout,
+            format,
+            shader,
+        } = self;
 
-        Self { pipeline }
+        if let Some(reloaded) = shader.reload(device, |module| {
+            Self::create(device, layout, *format, module)
+        }) {
+            *pipeline = reloaded;
+        }
+    }
+
+    fn create(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+        shader: &wgpu::ShaderModule,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_wgpu.quad.solid.pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "solid_vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<Solid>() as u64,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array!(
+                        // Color
+                        0 => Float32x4,
+                        // Position
+                        1 => Float32x2,
+                        // Size
+                        2 => Float32x2,
+                        // Border color
+                        3 => Float32x4,
+                        // Border radius
+                        4 => Float32x4,
+                        // Border width
+                        5 => Float32,
+                        // Shadow color
+                        6 => Float32x4,
+                        // Shadow offset
+                        7 => Float32x2,
+                        // Shadow blur radius
+                        8 => Float32,
+                    ),
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "solid_fs_main",
+                targets: &quad::color_target_state(format),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                front_face: wgpu::FrontFace::Cw,
+                ..Default::default()
+            },
+            depth_stencil: Some(clip::stencil()),
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+        })
     }
 
     pub fn render<'a>(
@@ -27,6 +27,25 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// Whether the [`Renderer`] should try to use a floating point surface
+    /// format, which allows colors to go beyond the standard dynamic range.
+    ///
+    /// If no such format is available, the [`Renderer`] will fall back to
+    /// a regular format and tone-map any emissive colors.
+    ///
+    /// By default, it is `false`.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    pub hdr: bool,
+
+    /// The amount of GPU memory, in bytes, the [`Renderer`] should try to
+    /// stay under.
+    ///
+    /// By default, it is `None`.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    pub memory_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -37,6 +56,8 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            hdr: false,
+            memory_budget: None,
         }
     }
 }
@@ -47,6 +68,8 @@ impl From<graphics::Settings> for Settings {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing,
+            hdr: settings.hdr,
+            memory_budget: settings.memory_budget,
             ..Settings::default()
         }
     }
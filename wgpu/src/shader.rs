@@ -0,0 +1,206 @@
+//! Load WGSL shaders that can be reloaded while the application runs.
+//!
+//! Hot-reloading is only available in debug builds on native platforms.
+//! In any other build, a [`Module`] simply uses the source it was created
+//! with and [`Module::reload`] never produces anything.
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Whether hot-reloading is enabled in the current build.
+pub const HOT_RELOAD: bool =
+    cfg!(all(debug_assertions, not(target_arch = "wasm32")));
+
+/// The environment variable pointing to the shader directory of this
+/// crate (i.e. `wgpu/src/shader`) that enables hot-reloading of the
+/// built-in shaders.
+pub const ENGINE_SHADERS_VARIABLE: &str = "ICED_WGPU_SHADERS";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A WGSL shader module that can be watched for changes.
+#[derive(Debug)]
+pub struct Module {
+    label: &'static str,
+    parts: Vec<Part>,
+    last_poll: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct Part {
+    source: Cow<'static, str>,
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+}
+
+impl Module {
+    /// Creates a new [`Module`] with the given label and WGSL source.
+    pub fn new(label: &'static str, source: &'static str) -> Self {
+        Self {
+            label,
+            parts: vec![Part {
+                source: Cow::Borrowed(source),
+                path: None,
+                modified: None,
+            }],
+            last_poll: None,
+        }
+    }
+
+    /// Watches the WGSL file at the given path for changes.
+    ///
+    /// The file should contain the same source the [`Module`] was created
+    /// with; normally, the file passed to `include_str!`.
+    pub fn watch(mut self, path: impl Into<PathBuf>) -> Self {
+        if HOT_RELOAD {
+            if let Some(part) = self.parts.last_mut() {
+                let path = path.into();
+
+                part.modified = modified(&path);
+                part.path = Some(path);
+            }
+        }
+
+        self
+    }
+
+    /// Creates a [`Module`] for one of the built-in shaders, made of the
+    /// given parts; which are pairs of a path relative to the shader
+    /// directory and its source.
+    ///
+    /// The parts are watched if [`ENGINE_SHADERS_VARIABLE`] is set.
+    pub(crate) fn engine(
+        label: &'static str,
+        parts: &[(&'static str, &'static str)],
+    ) -> Self {
+        let directory = if HOT_RELOAD {
+            std::env::var_os(ENGINE_SHADERS_VARIABLE).map(PathBuf::from)
+        } else {
+            None
+        };
+
+        Self {
+            label,
+            parts: parts
+                .iter()
+                .map(|&(path, source)| {
+                    let path = directory.as_ref().map(|dir| dir.join(path));
+
+                    Part {
+                        source: Cow::Borrowed(source),
+                        modified: path.as_deref().and_then(modified),
+                        path,
+                    }
+                })
+                .collect(),
+            last_poll: None,
+        }
+    }
+
+    /// Returns the current WGSL source of the [`Module`].
+    pub fn source(&self) -> Cow<'_, str> {
+        match self.parts.as_slice() {
+            [part] => Cow::Borrowed(part.source.as_ref()),
+            parts => Cow::Owned(
+                parts
+                    .iter()
+                    .map(|part| part.source.as_ref())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
+    }
+
+    /// Creates the [`wgpu::ShaderModule`] of the [`Module`].
+    pub fn create(&self, device: &wgpu::Device) -> wgpu::ShaderModule {
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(self.label),
+            source: wgpu::ShaderSource::Wgsl(self.source()),
+        })
+    }
+
+    /// Checks whether any watched file has changed and, if so, recompiles
+    /// the [`Module`] and calls `build` with the result.
+    ///
+    /// Compilation and `build` run inside a validation error scope. If any
+    /// error happens, it will be logged and `None` will be returned; so
+    /// the caller can keep using its last good pipeline.
+    pub fn reload<T>(
+        &mut self,
+        device: &wgpu::Device,
+        build: impl FnOnce(&wgpu::ShaderModule) -> T,
+    ) -> Option<T> {
+        if !HOT_RELOAD || !self.poll() {
+            return None;
+        }
+
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+
+        let module = self.create(device);
+        let result = build(&module);
+
+        match futures::executor::block_on(device.pop_error_scope()) {
+            Some(error) => {
+                log::error!("Failed to reload shader {}: {error}", self.label);
+
+                None
+            }
+            None => {
+                log::info!("Shader {} reloaded", self.label);
+
+                Some(result)
+            }
+        }
+    }
+
+    fn poll(&mut self) -> bool {
+        if self.parts.iter().all(|part| part.path.is_none()) {
+            return false;
+        }
+
+        let now = Instant::now();
+
+        if self
+            .last_poll
+            .is_some_and(|last_poll| now - last_poll < POLL_INTERVAL)
+        {
+            return false;
+        }
+
+        self.last_poll = Some(now);
+
+        let mut changed = false;
+
+        for part in &mut self.parts {
+            let Some(path) = &part.path else {
+                continue;
+            };
+
+            let last_modified = modified(path);
+
+            if last_modified.is_none() || last_modified == part.modified {
+                continue;
+            }
+
+            match std::fs::read_to_string(path) {
+                Ok(source) => {
+                    part.source = Cow::Owned(source);
+                    part.modified = last_modified;
+
+                    changed = true;
+                }
+                Err(error) => {
+                    log::warn!("Failed to read {}: {error}", path.display());
+                }
+            }
+        }
+
+        changed
+    }
+}
+
+fn modified(path: &std::path::Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
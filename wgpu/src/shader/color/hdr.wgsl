@@ -0,0 +1,3 @@
+fn tone_map(color: vec4<f32>) -> vec4<f32> {
+    return color;
+}
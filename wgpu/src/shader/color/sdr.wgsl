@@ -0,0 +1,11 @@
+// Scales emissive colors down until their brightest channel fits, which
+// keeps their hue instead of clamping every channel on its own.
+fn tone_map(color: vec4<f32>) -> vec4<f32> {
+    let peak = max(color.r, max(color.g, color.b));
+
+    if peak > 1.0 {
+        return vec4<f32>(color.rgb / peak, color.a);
+    }
+
+    return color;
+}
@@ -0,0 +1,16 @@
+// Scales emissive colors down like `sdr.wgsl` does and then encodes them
+// into sRGB, since the render target is unable to do it on its own.
+fn tone_map(color: vec4<f32>) -> vec4<f32> {
+    let peak = max(color.r, max(color.g, color.b));
+
+    var rgb = max(color.rgb, vec3<f32>(0.0));
+
+    if peak > 1.0 {
+        rgb = rgb / peak;
+    }
+
+    let lower = rgb * 12.92;
+    let higher = 1.055 * pow(rgb, vec3<f32>(1.0 / 2.4)) - vec3<f32>(0.055);
+
+    return vec4<f32>(select(higher, lower, rgb <= vec3<f32>(0.0031308)), color.a);
+}
@@ -59,5 +59,31 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Sample the texture at the given UV coordinate and layer.
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer)) * vec4<f32>(1.0, 1.0, 1.0, input.opacity);
+    var color = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    // `DECODE_SRGB` and `ENCODE_SRGB` are defined by the pipeline, depending
+    // on the texture formats supported by the adapter.
+    if DECODE_SRGB {
+        color = vec4<f32>(srgb_to_linear(color.rgb), color.a);
+    }
+
+    if ENCODE_SRGB {
+        color = vec4<f32>(linear_to_srgb(color.rgb), color.a);
+    }
+
+    return color * vec4<f32>(1.0, 1.0, 1.0, input.opacity);
+}
+
+fn srgb_to_linear(srgb: vec3<f32>) -> vec3<f32> {
+    let lower = srgb / 12.92;
+    let higher = pow((srgb + vec3<f32>(0.055)) / 1.055, vec3<f32>(2.4));
+
+    return select(higher, lower, srgb <= vec3<f32>(0.04045));
+}
+
+fn linear_to_srgb(linear: vec3<f32>) -> vec3<f32> {
+    let lower = linear * 12.92;
+    let higher = 1.055 * pow(linear, vec3<f32>(1.0 / 2.4)) - vec3<f32>(0.055);
+
+    return select(higher, lower, linear <= vec3<f32>(0.0031308));
 }
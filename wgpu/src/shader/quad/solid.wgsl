@@ -64,7 +64,7 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
 fn solid_fs_main(
     input: SolidVertexOutput
 ) -> @location(0) vec4<f32> {
-    var mixed_color: vec4<f32> = input.color;
+    var mixed_color: vec4<f32> = tone_map(input.color);
 
     var border_radius = select_border_radius(
         input.border_radius,
@@ -88,7 +88,7 @@ fn solid_fs_main(
             internal_distance
         );
 
-        mixed_color = mix(input.color, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
+        mixed_color = mix(mixed_color, tone_map(input.border_color), vec4<f32>(border_mix, border_mix, border_mix, border_mix));
     }
 
     var dist: f32 = distance_alg(
@@ -115,7 +115,7 @@ fn solid_fs_main(
         let shadow_distance = max(rounded_box_sdf(input.position.xy - input.pos - input.shadow_offset - (input.scale / 2.0), input.scale / 2.0, shadow_radius), 0.);
         
         let shadow_alpha = 1.0 - smoothstep(-input.shadow_blur_radius, input.shadow_blur_radius, shadow_distance);
-        let shadow_color = input.shadow_color;
+        let shadow_color = tone_map(input.shadow_color);
         let base_color = mix(
             vec4<f32>(shadow_color.x, shadow_color.y, shadow_color.z, 0.0),
             quad_color,
@@ -119,7 +119,9 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    return gradient(input.raw_position, input.direction, colors, offsets, last_index);
+    let color = tone_map(gradient(input.raw_position, input.direction, colors, offsets, last_index));
+
+    return vec4<f32>(color.rgb * color.a, color.a);
 }
 
 fn unpack_u32(color: vec2<u32>) -> vec4<f32> {
@@ -20,5 +20,7 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
 
 @fragment
 fn solid_fs_main(input: SolidVertexOutput) -> @location(0) vec4<f32> {
-    return input.color;
+    let color = tone_map(input.color);
+
+    return vec4<f32>(color.rgb * color.a, color.a);
 }
@@ -1,9 +1,12 @@
+use crate::clip;
 use crate::core::alignment;
 use crate::core::{Rectangle, Size, Transformation};
 use crate::graphics::cache;
 use crate::graphics::color;
 use crate::graphics::text::cache::{self as text_cache, Cache as BufferCache};
-use crate::graphics::text::{font_system, to_color, Editor, Paragraph};
+use crate::graphics::text::{
+    font_system, to_color, Editor, Paragraph, Version,
+};
 
 use rustc_hash::FxHashMap;
 use std::collections::hash_map;
@@ -13,11 +16,24 @@ use std::sync::Arc;
 
 pub use crate::graphics::Text;
 
-const COLOR_MODE: glyphon::ColorMode = if color::GAMMA_CORRECTION {
-    glyphon::ColorMode::Accurate
-} else {
-    glyphon::ColorMode::Web
-};
+/// Returns the [`glyphon::ColorMode`] for rendering to the given format.
+///
+/// Targets lacking sRGB support are rendered with web colors, since glyphon
+/// cannot encode them on its own.
+fn color_mode(format: wgpu::TextureFormat) -> glyphon::ColorMode {
+    if color::GAMMA_CORRECTION && !crate::format::encodes_srgb(format) {
+        glyphon::ColorMode::Accurate
+    } else {
+        glyphon::ColorMode::Web
+    }
+}
+
+/// The amount of bytes a [`glyphon::TextAtlas`] allocates when created; a
+/// 256x256 mask atlas and a 256x256 color atlas.
+///
+/// Glyph atlases do not report the size they grow to, so this is used as an
+/// estimate of their memory usage.
+const ATLAS_SIZE: u64 = 256 * 256 * (1 + 4);
 
 pub type Batch = Vec<Item>;
 
@@ -76,6 +92,7 @@ struct Upload {
     transformation: Transformation,
     version: usize,
     group_version: usize,
+    font_version: Version,
     text: rc::Weak<[Text]>,
     _atlas: rc::Weak<()>,
 }
@@ -132,7 +149,11 @@ impl Storage {
 
             Group {
                 atlas: glyphon::TextAtlas::with_color_mode(
-                    device, queue, state, format, COLOR_MODE,
+                    device,
+                    queue,
+                    state,
+                    format,
+                    color_mode(format),
                 ),
                 version: 0,
                 should_trim: false,
@@ -140,12 +161,16 @@ impl Storage {
             }
         });
 
+        let font_version =
+            font_system().read().expect("Read font system").version();
+
         match self.uploads.entry(cache.id) {
             hash_map::Entry::Occupied(entry) => {
                 let upload = entry.into_mut();
 
                 if upload.version != cache.version
                     || upload.group_version != group.version
+                    || upload.font_version != font_version
                     || upload.transformation != new_transformation
                 {
                     if !cache.text.is_empty() {
@@ -170,6 +195,7 @@ impl Storage {
                     upload.text = Rc::downgrade(&cache.text);
                     upload.version = cache.version;
                     upload.group_version = group.version;
+                    upload.font_version = font_version;
                     upload.transformation = new_transformation;
 
                     upload.buffer_cache.trim();
@@ -180,7 +206,7 @@ impl Storage {
                     &mut group.atlas,
                     device,
                     wgpu::MultisampleState::default(),
-                    None,
+                    Some(clip::stencil()),
                 );
 
                 let mut buffer_cache = BufferCache::new();
@@ -206,6 +232,7 @@ impl Storage {
                     transformation: new_transformation,
                     version: 0,
                     group_version: group.version,
+                    font_version,
                     text: Rc::downgrade(&cache.text),
                     _atlas: Rc::downgrade(&group.handle),
                 });
@@ -256,6 +283,19 @@ impl Storage {
             true
         });
     }
+
+    /// Returns an estimate of the amount of bytes allocated by the glyph
+    /// atlases of the [`Storage`].
+    pub fn memory_usage(&self) -> u64 {
+        self.groups.len() as u64 * ATLAS_SIZE
+    }
+
+    /// Drops all the glyph atlases and uploads of the [`Storage`]; they will
+    /// be prepared again when drawn.
+    pub fn clear(&mut self) {
+        self.uploads.clear();
+        self.groups.clear();
+    }
 }
 
 pub struct Viewport(glyphon::Viewport);
@@ -290,7 +330,11 @@ impl Pipeline {
     ) -> Self {
         let state = glyphon::Cache::new(device);
         let atlas = glyphon::TextAtlas::with_color_mode(
-            device, queue, &state, format, COLOR_MODE,
+            device,
+            queue,
+            &state,
+            format,
+            color_mode(format),
         );
 
         Pipeline {
@@ -325,7 +369,7 @@ impl Pipeline {
                             &mut self.atlas,
                             device,
                             wgpu::MultisampleState::default(),
-                            None,
+                            Some(clip::stencil()),
                         ));
                     }
 
@@ -427,6 +471,27 @@ impl Pipeline {
 
         self.prepare_layer = 0;
     }
+
+    /// Returns an estimate of the amount of bytes allocated by the glyph
+    /// atlas of the [`Pipeline`].
+    pub fn memory_usage(&self) -> u64 {
+        ATLAS_SIZE
+    }
+
+    /// Replaces the glyph atlas of the [`Pipeline`] with an empty one and
+    /// drops its renderers and buffers.
+    pub fn evict(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.atlas = glyphon::TextAtlas::with_color_mode(
+            device,
+            queue,
+            &self.state,
+            self.format,
+            color_mode(self.format),
+        );
+
+        self.renderers.clear();
+        self.cache = BufferCache::new();
+    }
 }
 
 fn prepare(
@@ -442,6 +507,8 @@ fn prepare(
     layer_transformation: Transformation,
 ) -> Result<(), glyphon::PrepareError> {
     let mut font_system = font_system().write().expect("Write font system");
+    let _ = buffer_cache.clear_if_outdated(font_system.version());
+
     let font_system = font_system.raw();
 
     enum Allocation {
@@ -466,6 +533,8 @@ fn prepare(
                 size,
                 line_height,
                 font,
+                horizontal_alignment,
+                max_space_factor,
                 shaping,
                 ..
             } => {
@@ -480,6 +549,8 @@ fn prepare(
                             width: bounds.width,
                             height: bounds.height,
                         },
+                        horizontal_alignment: *horizontal_alignment,
+                        max_space_factor: *max_space_factor,
                         shaping: *shaping,
                     },
                 );
@@ -603,10 +674,14 @@ fn prepare(
 
             let bounds = bounds * transformation * layer_transformation;
 
-            let left = match horizontal_alignment {
-                alignment::Horizontal::Left => bounds.x,
+            let left = match horizontal_alignment.resolve() {
+                alignment::Horizontal::Left
+                | alignment::Horizontal::Start
+                | alignment::Horizontal::Justify => bounds.x,
                 alignment::Horizontal::Center => bounds.x - bounds.width / 2.0,
-                alignment::Horizontal::Right => bounds.x - bounds.width,
+                alignment::Horizontal::Right | alignment::Horizontal::End => {
+                    bounds.x - bounds.width
+                }
             };
 
             let top = match vertical_alignment {
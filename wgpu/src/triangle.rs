@@ -2,12 +2,13 @@
 mod msaa;
 
 use crate::core::{Rectangle, Size, Transformation};
-use crate::graphics::mesh::{self, Mesh};
+use crate::graphics::mesh::{self, Blend, Mesh};
 use crate::graphics::Antialiasing;
-use crate::Buffer;
+use crate::{clip, Buffer};
 
 use rustc_hash::FxHashMap;
 use std::collections::hash_map;
+use std::ops::Range;
 use std::rc::{self, Rc};
 use std::sync::atomic::{self, AtomicU64};
 
@@ -151,6 +152,21 @@ impl Storage {
         self.uploads
             .retain(|_id, upload| upload.batch.strong_count() > 0);
     }
+
+    /// Returns the amount of bytes allocated by the uploads of the
+    /// [`Storage`].
+    pub fn memory_usage(&self) -> u64 {
+        self.uploads
+            .values()
+            .map(|upload| upload.layer.memory_usage())
+            .sum()
+    }
+
+    /// Drops all the uploads of the [`Storage`]; they will be uploaded
+    /// again when drawn.
+    pub fn clear(&mut self) {
+        self.uploads.clear();
+    }
 }
 
 #[derive(Debug)]
@@ -177,6 +193,11 @@ impl Pipeline {
         }
     }
 
+    pub fn reload(&mut self, device: &wgpu::Device) {
+        self.solid.reload(device);
+        self.gradient.reload(device);
+    }
+
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -248,6 +269,8 @@ impl Pipeline {
         batch: &Batch,
         bounds: Rectangle,
         screen_transformation: Transformation,
+        stencil: &wgpu::TextureView,
+        clip_depth: u32,
     ) -> usize {
         let mut layer_count = 0;
 
@@ -287,6 +310,8 @@ impl Pipeline {
             &self.gradient,
             bounds,
             items,
+            stencil,
+            clip_depth,
         );
 
         layer_count
@@ -295,60 +320,130 @@ impl Pipeline {
     pub fn end_frame(&mut self) {
         self.prepare_layer = 0;
     }
+
+    /// Returns the amount of bytes allocated by the layers of the
+    /// [`Pipeline`].
+    pub fn memory_usage(&self) -> u64 {
+        self.layers.iter().map(Layer::memory_usage).sum()
+    }
+
+    /// Drops the layers that were not prepared in the current frame.
+    pub fn shrink(&mut self) {
+        self.layers.truncate(self.prepare_layer);
+    }
 }
 
 fn render<'a>(
     encoder: &mut wgpu::CommandEncoder,
     target: &wgpu::TextureView,
-    mut blit: Option<&mut msaa::Blit>,
+    blit: Option<&mut msaa::Blit>,
     solid: &solid::Pipeline,
     gradient: &gradient::Pipeline,
     bounds: Rectangle,
     group: impl Iterator<Item = (&'a Layer, &'a [Mesh], Transformation)>,
+    stencil: &wgpu::TextureView,
+    clip_depth: u32,
 ) {
-    {
-        let (attachment, resolve_target, load) = if let Some(blit) = &mut blit {
-            let (attachment, resolve_target) = blit.targets();
-
-            (
-                attachment,
-                Some(resolve_target),
-                wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
-            )
-        } else {
-            (target, None, wgpu::LoadOp::Load)
-        };
-
+    let Some(blit) = blit else {
         let mut render_pass =
             encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("iced_wgpu.triangle.render_pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: attachment,
-                    resolve_target,
+                    view: target,
+                    resolve_target: None,
                     ops: wgpu::Operations {
-                        load,
+                        load: wgpu::LoadOp::Load,
                         store: wgpu::StoreOp::Store,
                     },
                 })],
-                depth_stencil_attachment: None,
+                depth_stencil_attachment: Some(clip::attachment(stencil)),
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
 
+        render_pass.set_stencil_reference(clip_depth);
+
         for (layer, meshes, transformation) in group {
             layer.render(
                 solid,
                 gradient,
                 meshes,
+                0..meshes.len(),
                 bounds,
                 transformation,
                 &mut render_pass,
             );
         }
+
+        return;
+    };
+
+    // Multisampled meshes are resolved in an intermediate target before
+    // being blitted; so every run of meshes sharing a blend mode is resolved
+    // and blitted on its own, blending with everything drawn before it.
+    let group: Vec<_> = group.collect();
+    let mut runs: Vec<(Blend, Vec<(usize, Range<usize>)>)> = Vec::new();
+
+    for (i, (_, meshes, _)) in group.iter().enumerate() {
+        for (j, mesh) in meshes.iter().enumerate() {
+            let blend = mesh.blend();
+
+            match runs.last_mut() {
+                Some((last, segments)) if *last == blend => {
+                    match segments.last_mut() {
+                        Some((item, range)) if *item == i => {
+                            range.end = j + 1;
+                        }
+                        _ => segments.push((i, j..j + 1)),
+                    }
+                }
+                _ => runs.push((blend, vec![(i, j..j + 1)])),
+            }
+        }
     }
 
-    if let Some(blit) = blit {
-        blit.draw(encoder, target);
+    for (blend, segments) in runs {
+        {
+            let (attachment, resolve_target) = blit.targets();
+
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu.triangle.render_pass"),
+                    color_attachments: &[Some(
+                        wgpu::RenderPassColorAttachment {
+                            view: attachment,
+                            resolve_target: Some(resolve_target),
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(msaa::clear_color(
+                                    blend,
+                                )),
+                                store: wgpu::StoreOp::Store,
+                            },
+                        },
+                    )],
+                    // The multisampled target is blitted with the stencil
+                    // test instead
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+
+            for (i, range) in segments {
+                let (layer, meshes, transformation) = group[i];
+
+                layer.render(
+                    solid,
+                    gradient,
+                    meshes,
+                    range,
+                    bounds,
+                    transformation,
+                    &mut render_pass,
+                );
+            }
+        }
+
+        blit.draw(encoder, target, stencil, clip_depth, blend);
     }
 }
 
@@ -487,20 +582,45 @@ impl Layer {
         }
     }
 
+    fn memory_usage(&self) -> u64 {
+        self.index_buffer.size()
+            + self.solid.vertices.size()
+            + self.solid.uniforms.size()
+            + self.gradient.vertices.size()
+            + self.gradient.uniforms.size()
+    }
+
     fn render<'a>(
         &'a self,
         solid: &'a solid::Pipeline,
         gradient: &'a gradient::Pipeline,
         meshes: &[Mesh],
+        range: Range<usize>,
         bounds: Rectangle,
         transformation: Transformation,
         render_pass: &mut wgpu::RenderPass<'a>,
     ) {
         let mut num_solids = 0;
         let mut num_gradients = 0;
-        let mut last_is_solid = None;
+        let mut last_pipeline = None;
 
         for (index, mesh) in meshes.iter().enumerate() {
+            // Meshes that are not drawn still take their slot in the buffers
+            let offset = match mesh {
+                Mesh::Solid { .. } => {
+                    num_solids += 1;
+                    num_solids - 1
+                }
+                Mesh::Gradient { .. } => {
+                    num_gradients += 1;
+                    num_gradients - 1
+                }
+            };
+
+            if !range.contains(&index) {
+                continue;
+            }
+
             let Some(clip_bounds) = bounds
                 .intersection(&(mesh.clip_bounds() * transformation))
                 .and_then(Rectangle::snap)
@@ -516,47 +636,41 @@ impl Layer {
             );
 
             match mesh {
-                Mesh::Solid { .. } => {
-                    if !last_is_solid.unwrap_or(false) {
-                        render_pass.set_pipeline(&solid.pipeline);
+                Mesh::Solid { blend, .. } => {
+                    if last_pipeline != Some((true, *blend)) {
+                        render_pass.set_pipeline(solid.pipeline(*blend));
 
-                        last_is_solid = Some(true);
+                        last_pipeline = Some((true, *blend));
                     }
 
                     render_pass.set_bind_group(
                         0,
                         &self.solid.constants,
-                        &[(num_solids * std::mem::size_of::<Uniforms>())
-                            as u32],
+                        &[(offset * std::mem::size_of::<Uniforms>()) as u32],
                     );
 
                     render_pass.set_vertex_buffer(
                         0,
-                        self.solid.vertices.slice_from_index(num_solids),
+                        self.solid.vertices.slice_from_index(offset),
                     );
-
-                    num_solids += 1;
                 }
-                Mesh::Gradient { .. } => {
-                    if last_is_solid.unwrap_or(true) {
-                        render_pass.set_pipeline(&gradient.pipeline);
+                Mesh::Gradient { blend, .. } => {
+                    if last_pipeline != Some((false, *blend)) {
+                        render_pass.set_pipeline(gradient.pipeline(*blend));
 
-                        last_is_solid = Some(false);
+                        last_pipeline = Some((false, *blend));
                     }
 
                     render_pass.set_bind_group(
                         0,
                         &self.gradient.constants,
-                        &[(num_gradients * std::mem::size_of::<Uniforms>())
-                            as u32],
+                        &[(offset * std::mem::size_of::<Uniforms>()) as u32],
                     );
 
                     render_pass.set_vertex_buffer(
                         0,
-                        self.gradient.vertices.slice_from_index(num_gradients),
+                        self.gradient.vertices.slice_from_index(offset),
                     );
-
-                    num_gradients += 1;
                 }
             };
 
@@ -570,12 +684,43 @@ impl Layer {
     }
 }
 
+/// The shaders output premultiplied colors, so every [`Blend`] mode takes
+/// the alpha of the mesh into account.
 fn fragment_target(
     texture_format: wgpu::TextureFormat,
+    blend: Blend,
 ) -> wgpu::ColorTargetState {
+    let color = |src_factor, dst_factor| wgpu::BlendComponent {
+        src_factor,
+        dst_factor,
+        operation: wgpu::BlendOperation::Add,
+    };
+
+    let blend = match blend {
+        Blend::Alpha => wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+        Blend::Additive => wgpu::BlendState {
+            color: color(wgpu::BlendFactor::One, wgpu::BlendFactor::One),
+            alpha: wgpu::BlendComponent::OVER,
+        },
+        Blend::Multiply => wgpu::BlendState {
+            color: color(
+                wgpu::BlendFactor::Dst,
+                wgpu::BlendFactor::OneMinusSrcAlpha,
+            ),
+            alpha: wgpu::BlendComponent::OVER,
+        },
+        Blend::Screen => wgpu::BlendState {
+            color: color(
+                wgpu::BlendFactor::OneMinusDst,
+                wgpu::BlendFactor::One,
+            ),
+            alpha: wgpu::BlendComponent::OVER,
+        },
+    };
+
     wgpu::ColorTargetState {
         format: texture_format,
-        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+        blend: Some(blend),
         write_mask: wgpu::ColorWrites::ALL,
     }
 }
@@ -588,6 +733,14 @@ fn primitive_state() -> wgpu::PrimitiveState {
     }
 }
 
+/// Multisampled meshes are clipped when they are blitted; since the stencil
+/// buffer is not multisampled.
+fn depth_stencil_state(
+    antialiasing: Option<Antialiasing>,
+) -> Option<wgpu::DepthStencilState> {
+    antialiasing.is_none().then(clip::stencil)
+}
+
 fn multisample_state(
     antialiasing: Option<Antialiasing>,
 ) -> wgpu::MultisampleState {
@@ -636,15 +789,20 @@ impl Uniforms {
 }
 
 mod solid {
-    use crate::graphics::mesh;
+    use crate::graphics::mesh::{self, Blend};
     use crate::graphics::Antialiasing;
+    use crate::shader;
     use crate::triangle;
     use crate::Buffer;
 
     #[derive(Debug)]
     pub struct Pipeline {
-        pub pipeline: wgpu::RenderPipeline,
+        pipelines: [wgpu::RenderPipeline; 4],
         pub constants_layout: wgpu::BindGroupLayout,
+        layout: wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+        antialiasing: Option<Antialiasing>,
+        shader: shader::Module,
     }
 
     #[derive(Debug)]
@@ -726,72 +884,126 @@ mod solid {
                 },
             );
 
-            let shader =
-                device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                    label: Some("iced_wgpu.triangle.solid.shader"),
-                    source: wgpu::ShaderSource::Wgsl(
-                        std::borrow::Cow::Borrowed(concat!(
-                            include_str!("shader/triangle.wgsl"),
-                            "\n",
-                            include_str!("shader/triangle/solid.wgsl"),
-                        )),
+            let shader = shader::Module::engine(
+                "iced_wgpu.triangle.solid.shader",
+                &[
+                    ("triangle.wgsl", include_str!("shader/triangle.wgsl")),
+                    (
+                        "triangle/solid.wgsl",
+                        include_str!("shader/triangle/solid.wgsl"),
                     ),
-                });
+                    crate::color::tone_mapping(format),
+                ],
+            );
 
-            let pipeline =
-                device.create_render_pipeline(
-                    &wgpu::RenderPipelineDescriptor {
-                        label: Some("iced_wgpu::triangle::solid pipeline"),
-                        layout: Some(&layout),
-                        vertex: wgpu::VertexState {
-                            module: &shader,
-                            entry_point: "solid_vs_main",
-                            buffers: &[wgpu::VertexBufferLayout {
-                                array_stride: std::mem::size_of::<
-                                    mesh::SolidVertex2D,
-                                >(
-                                )
-                                    as u64,
-                                step_mode: wgpu::VertexStepMode::Vertex,
-                                attributes: &wgpu::vertex_attr_array!(
-                                    // Position
-                                    0 => Float32x2,
-                                    // Color
-                                    1 => Float32x4,
-                                ),
-                            }],
-                        },
-                        fragment: Some(wgpu::FragmentState {
-                            module: &shader,
-                            entry_point: "solid_fs_main",
-                            targets: &[Some(triangle::fragment_target(format))],
-                        }),
-                        primitive: triangle::primitive_state(),
-                        depth_stencil: None,
-                        multisample: triangle::multisample_state(antialiasing),
-                        multiview: None,
-                    },
-                );
+            let module = shader.create(device);
+            let pipelines = Blend::ALL.map(|blend| {
+                Self::create(
+                    device,
+                    &layout,
+                    format,
+                    antialiasing,
+                    &module,
+                    blend,
+                )
+            });
 
             Self {
-                pipeline,
+                pipelines,
                 constants_layout,
+                layout,
+                format,
+                antialiasing,
+                shader,
             }
         }
+
+        pub fn reload(&mut self, device: &wgpu::Device) {
+            let Self {
+                pipelines,
+                layout,
+                format,
+                antialiasing,
+                shader,
+                ..
+            } = self;
+
+            if let Some(reloaded) = shader.reload(device, |module| {
+                Blend::ALL.map(|blend| {
+                    Self::create(
+                        device,
+                        layout,
+                        *format,
+                        *antialiasing,
+                        module,
+                        blend,
+                    )
+                })
+            }) {
+                *pipelines = reloaded;
+            }
+        }
+
+        pub fn pipeline(&self, blend: Blend) -> &wgpu::RenderPipeline {
+            &self.pipelines[blend as usize]
+        }
+
+        fn create(
+            device: &wgpu::Device,
+            layout: &wgpu::PipelineLayout,
+            format: wgpu::TextureFormat,
+            antialiasing: Option<Antialiasing>,
+            shader: &wgpu::ShaderModule,
+            blend: Blend,
+        ) -> wgpu::RenderPipeline {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::triangle::solid pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: shader,
+                    entry_point: "solid_vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: std::mem::size_of::<mesh::SolidVertex2D>()
+                            as u64,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array!(
+                            // Position
+                            0 => Float32x2,
+                            // Color
+                            1 => Float32x4,
+                        ),
+                    }],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: shader,
+                    entry_point: "solid_fs_main",
+                    targets: &[Some(triangle::fragment_target(format, blend))],
+                }),
+                primitive: triangle::primitive_state(),
+                depth_stencil: triangle::depth_stencil_state(antialiasing),
+                multisample: triangle::multisample_state(antialiasing),
+                multiview: None,
+            })
+        }
     }
 }
 
 mod gradient {
     use crate::graphics::color;
-    use crate::graphics::mesh;
+    use crate::graphics::mesh::{self, Blend};
     use crate::graphics::Antialiasing;
+    use crate::shader;
     use crate::triangle;
     use crate::Buffer;
 
     #[derive(Debug)]
     pub struct Pipeline {
-        pub pipeline: wgpu::RenderPipeline,
+        pipelines: [wgpu::RenderPipeline; 4],
         pub constants_layout: wgpu::BindGroupLayout,
+        layout: wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+        antialiasing: Option<Antialiasing>,
+        shader: shader::Module,
     }
 
     #[derive(Debug)]
@@ -875,85 +1087,128 @@ mod gradient {
                 },
             );
 
-            let shader =
-                device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                    label: Some("iced_wgpu.triangle.gradient.shader"),
-                    source: wgpu::ShaderSource::Wgsl(
-                        std::borrow::Cow::Borrowed(
-                            if color::GAMMA_CORRECTION {
-                                concat!(
-                                    include_str!("shader/triangle.wgsl"),
-                                    "\n",
-                                    include_str!(
-                                        "shader/triangle/gradient.wgsl"
-                                    ),
-                                    "\n",
-                                    include_str!("shader/color/oklab.wgsl")
-                                )
-                            } else {
-                                concat!(
-                                    include_str!("shader/triangle.wgsl"),
-                                    "\n",
-                                    include_str!(
-                                        "shader/triangle/gradient.wgsl"
-                                    ),
-                                    "\n",
-                                    include_str!(
-                                        "shader/color/linear_rgb.wgsl"
-                                    )
-                                )
-                            },
-                        ),
+            let shader = shader::Module::engine(
+                "iced_wgpu.triangle.gradient.shader",
+                &[
+                    ("triangle.wgsl", include_str!("shader/triangle.wgsl")),
+                    (
+                        "triangle/gradient.wgsl",
+                        include_str!("shader/triangle/gradient.wgsl"),
                     ),
-                });
-
-            let pipeline = device.create_render_pipeline(
-                &wgpu::RenderPipelineDescriptor {
-                    label: Some("iced_wgpu.triangle.gradient.pipeline"),
-                    layout: Some(&layout),
-                    vertex: wgpu::VertexState {
-                        module: &shader,
-                        entry_point: "gradient_vs_main",
-                        buffers: &[wgpu::VertexBufferLayout {
-                            array_stride: std::mem::size_of::<
-                                mesh::GradientVertex2D,
-                            >()
-                                as u64,
-                            step_mode: wgpu::VertexStepMode::Vertex,
-                            attributes: &wgpu::vertex_attr_array!(
-                                // Position
-                                0 => Float32x2,
-                                // Colors 1-2
-                                1 => Uint32x4,
-                                // Colors 3-4
-                                2 => Uint32x4,
-                                // Colors 5-6
-                                3 => Uint32x4,
-                                // Colors 7-8
-                                4 => Uint32x4,
-                                // Offsets
-                                5 => Uint32x4,
-                                // Direction
-                                6 => Float32x4
-                            ),
-                        }],
+                    if color::GAMMA_CORRECTION {
+                        (
+                            "color/oklab.wgsl",
+                            include_str!("shader/color/oklab.wgsl"),
+                        )
+                    } else {
+                        (
+                            "color/linear_rgb.wgsl",
+                            include_str!("shader/color/linear_rgb.wgsl"),
+                        )
                     },
-                    fragment: Some(wgpu::FragmentState {
-                        module: &shader,
-                        entry_point: "gradient_fs_main",
-                        targets: &[Some(triangle::fragment_target(format))],
-                    }),
-                    primitive: triangle::primitive_state(),
-                    depth_stencil: None,
-                    multisample: triangle::multisample_state(antialiasing),
-                    multiview: None,
-                },
+                    crate::color::tone_mapping(format),
+                ],
             );
 
+            let module = shader.create(device);
+            let pipelines = Blend::ALL.map(|blend| {
+                Self::create(
+                    device,
+                    &layout,
+                    format,
+                    antialiasing,
+                    &module,
+                    blend,
+                )
+            });
+
             Self {
-                pipeline,
+                pipelines,
                 constants_layout,
+                layout,
+                format,
+                antialiasing,
+                shader,
+            }
+        }
+
+        pub fn reload(&mut self, device: &wgpu::Device) {
+            let Self {
+                pipelines,
+                layout,
+                format,
+                antialiasing,
+                shader,
+                ..
+            } = self;
+
+            if let Some(reloaded) = shader.reload(device, |module| {
+                Blend::ALL.map(|blend| {
+                    Self::create(
+                        device,
+                        layout,
+                        *format,
+                        *antialiasing,
+                        module,
+                        blend,
+                    )
+                })
+            }) {
+                *pipelines = reloaded;
             }
         }
+
+        pub fn pipeline(&self, blend: Blend) -> &wgpu::RenderPipeline {
+            &self.pipelines[blend as usize]
+        }
+
+        fn create(
+            device: &wgpu::Device,
+            layout: &wgpu::PipelineLayout,
+            format: wgpu::TextureFormat,
+            antialiasing: Option<Antialiasing>,
+            shader: &wgpu::ShaderModule,
+            blend: Blend,
+        ) -> wgpu::RenderPipeline {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu.triangle.gradient.pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: shader,
+                    entry_point: "gradient_vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: std::mem::size_of::<
+                            mesh::GradientVertex2D,
+                        >() as u64,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array!(
+                            // Position
+                            0 => Float32x2,
+                            // Colors 1-2
+                            1 => Uint32x4,
+                            // Colors 3-4
+                            2 => Uint32x4,
+                            // Colors 5-6
+                            3 => Uint32x4,
+                            // Colors 7-8
+                            4 => Uint32x4,
+                            // Offsets
+                            5 => Uint32x4,
+                            // Direction
+                            6 => Float32x4
+                        ),
+                    }],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: shader,
+                    entry_point: "gradient_fs_main",
+                    targets: &[Some(triangle::fragment_target(format, blend))],
+                }),
+                primitive: triangle::primitive_state(),
+                depth_stencil: triangle::depth_stencil_state(antialiasing),
+                multisample: triangle::multisample_state(antialiasing),
+                multiview: None,
+            })
+        }
     }
 }
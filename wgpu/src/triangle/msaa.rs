@@ -1,12 +1,14 @@
+use crate::clip;
 use crate::core::{Size, Transformation};
 use crate::graphics;
+use crate::graphics::mesh::Blend;
 
 use std::num::NonZeroU64;
 
 #[derive(Debug)]
 pub struct Blit {
     format: wgpu::TextureFormat,
-    pipeline: wgpu::RenderPipeline,
+    pipelines: [wgpu::RenderPipeline; 4],
     constants: wgpu::BindGroup,
     ratio: wgpu::Buffer,
     texture_layout: wgpu::BindGroupLayout,
@@ -104,7 +106,7 @@ impl Blit {
                 )),
             });
 
-        let pipeline =
+        let pipelines = Blend::ALL.map(|blend| {
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                 label: Some("iced_wgpu::triangle::msaa pipeline"),
                 layout: Some(&layout),
@@ -118,9 +120,7 @@ impl Blit {
                     entry_point: "fs_main",
                     targets: &[Some(wgpu::ColorTargetState {
                         format,
-                        blend: Some(
-                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
-                        ),
+                        blend: Some(composite(blend)),
                         write_mask: wgpu::ColorWrites::ALL,
                     })],
                 }),
@@ -129,18 +129,19 @@ impl Blit {
                     front_face: wgpu::FrontFace::Cw,
                     ..Default::default()
                 },
-                depth_stencil: None,
+                depth_stencil: Some(clip::stencil()),
                 multisample: wgpu::MultisampleState {
                     count: 1,
                     mask: !0,
                     alpha_to_coverage_enabled: false,
                 },
                 multiview: None,
-            });
+            })
+        });
 
         Blit {
             format,
-            pipeline,
+            pipelines,
             constants: constant_bind_group,
             ratio,
             texture_layout,
@@ -206,6 +207,9 @@ impl Blit {
         &self,
         encoder: &mut wgpu::CommandEncoder,
         target: &wgpu::TextureView,
+        stencil: &wgpu::TextureView,
+        clip_depth: u32,
+        blend: Blend,
     ) {
         let mut render_pass =
             encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -218,12 +222,13 @@ impl Blit {
                         store: wgpu::StoreOp::Store,
                     },
                 })],
-                depth_stencil_attachment: None,
+                depth_stencil_attachment: Some(clip::attachment(stencil)),
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
 
-        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_stencil_reference(clip_depth);
+        render_pass.set_pipeline(&self.pipelines[blend as usize]);
         render_pass.set_bind_group(0, &self.constants, &[]);
         render_pass.set_bind_group(
             1,
@@ -234,6 +239,55 @@ impl Blit {
     }
 }
 
+/// Returns the color the multisampled target must be cleared with before
+/// drawing meshes with the given [`Blend`] mode.
+///
+/// Multiplied meshes are drawn on top of white, which leaves the frame
+/// untouched when multiplied.
+pub fn clear_color(blend: Blend) -> wgpu::Color {
+    match blend {
+        Blend::Multiply => wgpu::Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+            a: 0.0,
+        },
+        Blend::Alpha | Blend::Additive | Blend::Screen => {
+            wgpu::Color::TRANSPARENT
+        }
+    }
+}
+
+/// Returns the [`wgpu::BlendState`] that composites the resolved meshes of
+/// the given [`Blend`] mode onto the frame.
+fn composite(blend: Blend) -> wgpu::BlendState {
+    let color = |src_factor, dst_factor| wgpu::BlendComponent {
+        src_factor,
+        dst_factor,
+        operation: wgpu::BlendOperation::Add,
+    };
+
+    match blend {
+        Blend::Alpha => wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+        Blend::Additive => wgpu::BlendState {
+            color: color(wgpu::BlendFactor::One, wgpu::BlendFactor::One),
+            alpha: wgpu::BlendComponent::OVER,
+        },
+        // The multiplied meshes already include the white they were drawn on
+        Blend::Multiply => wgpu::BlendState {
+            color: color(wgpu::BlendFactor::Dst, wgpu::BlendFactor::Zero),
+            alpha: wgpu::BlendComponent::OVER,
+        },
+        Blend::Screen => wgpu::BlendState {
+            color: color(
+                wgpu::BlendFactor::OneMinusDst,
+                wgpu::BlendFactor::One,
+            ),
+            alpha: wgpu::BlendComponent::OVER,
+        },
+    }
+}
+
 #[derive(Debug)]
 struct Targets {
     attachment: wgpu::TextureView,
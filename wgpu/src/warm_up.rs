@@ -0,0 +1,253 @@
+//! Warm up the pipelines and caches of an [`Engine`] before the first frame.
+use crate::core::alignment;
+use crate::core::gradient;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::text::{self, Renderer as _};
+use crate::core::{
+    Background, Border, Color, Font, Gradient, Pixels, Point, Rectangle,
+    Shadow, Size, Transformation, Vector,
+};
+use crate::graphics::color;
+use crate::graphics::mesh::{self, Mesh, Renderer as _};
+use crate::graphics::Viewport;
+use crate::{Engine, Renderer};
+
+/// The size of the target of the warm-up frame.
+const SIZE: Size<u32> = Size::new(64, 64);
+
+/// The width of the target of the frames that preload glyphs.
+const GLYPHS_WIDTH: u32 = 2048;
+
+/// Draws a frame exercising every built-in pipeline of the [`Engine`]; with
+/// every blend mode of meshes and, when enabled, multisampling.
+pub fn primitives(
+    engine: &mut Engine,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+) {
+    render(engine, device, queue, SIZE, |renderer| {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(16.0, 16.0));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: Color::WHITE,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                shadow: Shadow {
+                    color: Color::BLACK,
+                    offset: Vector::new(1.0, 1.0),
+                    blur_radius: 2.0,
+                },
+            },
+            Color::BLACK,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bounds + Vector::new(16.0, 0.0),
+                ..renderer::Quad::default()
+            },
+            Background::Gradient(Gradient::Linear(
+                gradient::Linear::new(0.0)
+                    .add_stop(0.0, Color::BLACK)
+                    .add_stop(1.0, Color::WHITE),
+            )),
+        );
+
+        for shaping in [text::Shaping::Basic, text::Shaping::Advanced] {
+            renderer.fill_text(
+                core_text("iced", Font::DEFAULT, Pixels(12.0), shaping),
+                Point::new(0.0, 16.0),
+                Color::WHITE,
+                Rectangle::with_size(Size::new(
+                    SIZE.width as f32,
+                    SIZE.height as f32,
+                )),
+            );
+        }
+
+        let triangle = [[0.0, 32.0], [16.0, 32.0], [0.0, 48.0]];
+        let clip_bounds = Rectangle::with_size(Size::new(
+            SIZE.width as f32,
+            SIZE.height as f32,
+        ));
+
+        for blend in mesh::Blend::ALL {
+            renderer.draw_mesh(Mesh::Solid {
+                buffers: mesh::Indexed {
+                    vertices: triangle
+                        .map(|position| mesh::SolidVertex2D {
+                            position,
+                            color: color::pack(Color::WHITE),
+                        })
+                        .to_vec(),
+                    indices: vec![0, 1, 2],
+                },
+                transformation: Transformation::IDENTITY,
+                clip_bounds,
+                blend,
+            });
+
+            renderer.draw_mesh(Mesh::Gradient {
+                buffers: mesh::Indexed {
+                    vertices: triangle
+                        .map(|position| mesh::GradientVertex2D {
+                            position,
+                            gradient: crate::graphics::gradient::pack(
+                                &Gradient::Linear(
+                                    gradient::Linear::new(0.0)
+                                        .add_stop(0.0, Color::BLACK)
+                                        .add_stop(1.0, Color::WHITE),
+                                ),
+                                bounds,
+                            ),
+                        })
+                        .to_vec(),
+                    indices: vec![0, 1, 2],
+                },
+                transformation: Transformation::IDENTITY,
+                clip_bounds,
+                blend,
+            });
+        }
+
+        #[cfg(feature = "image")]
+        {
+            use crate::core::image::{self, Renderer as _};
+
+            renderer.draw_image(
+                image::Handle::from_rgba(1, 1, vec![255; 4]),
+                image::FilterMethod::Linear,
+                bounds + Vector::new(32.0, 32.0),
+                crate::core::Radians(0.0),
+                1.0,
+            );
+        }
+    });
+}
+
+/// Draws a frame for every given size with the glyphs of the `charset` in
+/// the given [`Font`]; so they are rasterized and stored in the glyph atlas
+/// ahead of time.
+pub fn glyphs(
+    engine: &mut Engine,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    font: Font,
+    sizes: &[Pixels],
+    charset: &str,
+) {
+    let width = GLYPHS_WIDTH.min(device.limits().max_texture_dimension_2d);
+
+    for size in sizes {
+        let line_height = text::LineHeight::default().to_absolute(*size).0;
+        let glyphs_per_line = (width as f32 / size.0).floor().max(1.0);
+        let lines =
+            (charset.chars().count() as f32 / glyphs_per_line).ceil() + 1.0;
+
+        let height =
+            ((lines * line_height).ceil() as u32).clamp(1, width.max(1));
+
+        render(
+            engine,
+            device,
+            queue,
+            Size::new(width, height),
+            |renderer| {
+                renderer.fill_text(
+                    text::Text {
+                        bounds: Size::new(width as f32, f32::INFINITY),
+                        ..core_text(
+                            charset,
+                            font,
+                            *size,
+                            text::Shaping::Advanced,
+                        )
+                    },
+                    Point::ORIGIN,
+                    Color::WHITE,
+                    Rectangle::with_size(Size::new(
+                        width as f32,
+                        height as f32,
+                    )),
+                );
+            },
+        );
+    }
+}
+
+/// Renders whatever the given closure draws into an offscreen target of the
+/// given size, and submits it right away.
+fn render(
+    engine: &mut Engine,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    size: Size<u32>,
+    draw: impl FnOnce(&mut Renderer),
+) {
+    let mut renderer =
+        Renderer::new(device, engine, Font::DEFAULT, Pixels(16.0));
+
+    draw(&mut renderer);
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::warm_up target texture"),
+        size: wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: engine.format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("iced_wgpu::warm_up encoder"),
+        });
+
+    let format = engine.format;
+
+    renderer.present::<&str>(
+        engine,
+        device,
+        queue,
+        &mut encoder,
+        Some(Color::TRANSPARENT),
+        format,
+        &view,
+        &Viewport::with_physical_size(size, 1.0),
+        &[],
+    );
+
+    let _ = engine.submit(queue, encoder);
+}
+
+fn core_text(
+    content: &str,
+    font: Font,
+    size: Pixels,
+    shaping: text::Shaping,
+) -> text::Text {
+    text::Text {
+        content: content.to_owned(),
+        bounds: Size::INFINITY,
+        size,
+        line_height: text::LineHeight::default(),
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+        font_features: &[],
+        shaping,
+    }
+}
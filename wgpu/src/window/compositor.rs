@@ -1,5 +1,5 @@
 //! Connect a window with a renderer.
-use crate::core::{Color, Size};
+use crate::core::{Color, Font, Pixels, Size};
 use crate::graphics::color;
 use crate::graphics::compositor;
 use crate::graphics::error;
@@ -30,19 +30,36 @@ pub enum Error {
     #[error("the surface is not compatible")]
     IncompatibleSurface,
     /// No adapter was found for the options requested.
-    #[error("no adapter was found for the options requested: {0:?}")]
-    NoAdapterFound(String),
+    #[error("no adapter was found for the options requested: {options}")]
+    NoAdapterFound {
+        /// The options requested.
+        options: String,
+        /// The adapters that were available.
+        available: Vec<String>,
+    },
     /// No device request succeeded.
     #[error("no device request succeeded: {0:?}")]
     RequestDeviceFailed(Vec<(wgpu::Limits, wgpu::RequestDeviceError)>),
 }
 
+impl Error {
+    /// Turns the [`Error`] into a [`graphics::Error`] of the given backend.
+    fn into_graphics(self, backend: &'static str) -> graphics::Error {
+        graphics::Error::GraphicsAdapterNotFound {
+            backend,
+            reason: match self {
+                Error::NoAdapterFound { options, available } => {
+                    error::Reason::AdapterNotFound { options, available }
+                }
+                error => error::Reason::RequestFailed(error.to_string()),
+            },
+        }
+    }
+}
+
 impl From<Error> for graphics::Error {
     fn from(error: Error) -> Self {
-        Self::GraphicsAdapterNotFound {
-            backend: "wgpu",
-            reason: error::Reason::RequestFailed(error.to_string()),
-        }
+        error.into_graphics("wgpu")
     }
 }
 
@@ -62,14 +79,16 @@ impl Compositor {
         log::info!("{settings:#?}");
 
         #[cfg(not(target_arch = "wasm32"))]
-        if log::max_level() >= log::LevelFilter::Info {
-            let available_adapters: Vec<_> = instance
-                .enumerate_adapters(settings.backends)
-                .iter()
-                .map(wgpu::Adapter::get_info)
-                .collect();
-            log::info!("Available adapters: {available_adapters:#?}");
-        }
+        let available_adapters: Vec<_> = instance
+            .enumerate_adapters(settings.backends)
+            .iter()
+            .map(wgpu::Adapter::get_info)
+            .collect();
+
+        #[cfg(target_arch = "wasm32")]
+        let available_adapters: Vec<wgpu::AdapterInfo> = Vec::new();
+
+        log::info!("Available adapters: {available_adapters:#?}");
 
         #[allow(unsafe_code)]
         let compatible_surface = compatible_window
@@ -89,7 +108,15 @@ impl Compositor {
         let adapter = instance
             .request_adapter(&adapter_options)
             .await
-            .ok_or(Error::NoAdapterFound(format!("{:?}", adapter_options)))?;
+            .ok_or_else(|| Error::NoAdapterFound {
+                options: format!("{adapter_options:?}"),
+                available: available_adapters
+                    .iter()
+                    .map(|adapter| {
+                        format!("{} ({:?})", adapter.name, adapter.backend)
+                    })
+                    .collect(),
+            })?;
 
         log::info!("Selected: {:#?}", adapter.get_info());
 
@@ -102,11 +129,46 @@ impl Compositor {
 
                 log::info!("Available formats: {formats:#?}");
 
-                let format = if color::GAMMA_CORRECTION {
-                    formats.find(wgpu::TextureFormat::is_srgb)
-                } else {
-                    formats.find(|format| !wgpu::TextureFormat::is_srgb(format))
-                };
+                let hdr = settings
+                    .hdr
+                    .then(|| {
+                        capabilities.formats.iter().copied().find(|&format| {
+                            crate::color::is_hdr(format)
+                                && crate::format::is_renderable(
+                                    &adapter, format,
+                                )
+                        })
+                    })
+                    .flatten();
+
+                if settings.hdr && hdr.is_none() {
+                    log::warn!("No HDR format found!");
+                }
+
+                let format = hdr.or_else(|| {
+                    if color::GAMMA_CORRECTION {
+                        formats.find(wgpu::TextureFormat::is_srgb).or_else(
+                            || {
+                                let format = capabilities
+                                    .formats
+                                    .iter()
+                                    .copied()
+                                    .find(|format| !format.is_srgb())?;
+
+                                log::warn!(
+                                    "No sRGB format found! The shaders will \
+                                    encode colors into sRGB for {format:?}"
+                                );
+
+                                Some(format)
+                            },
+                        )
+                    } else {
+                        formats.find(|format| {
+                            !wgpu::TextureFormat::is_srgb(format)
+                        })
+                    }
+                });
 
                 let format = format.or_else(|| {
                     log::warn!("No format found!");
@@ -118,14 +180,18 @@ impl Compositor {
 
                 log::info!("Available alpha modes: {alpha_modes:#?}");
 
+                // The pipelines blend with `OneMinusSrcAlpha` over a
+                // premultiplied clear color; so the frame ends up holding
+                // premultiplied colors, which is what the compositor must
+                // expect for translucent windows to blend properly.
                 let preferred_alpha = if alpha_modes
-                    .contains(&wgpu::CompositeAlphaMode::PostMultiplied)
-                {
-                    wgpu::CompositeAlphaMode::PostMultiplied
-                } else if alpha_modes
                     .contains(&wgpu::CompositeAlphaMode::PreMultiplied)
                 {
                     wgpu::CompositeAlphaMode::PreMultiplied
+                } else if alpha_modes
+                    .contains(&wgpu::CompositeAlphaMode::PostMultiplied)
+                {
+                    wgpu::CompositeAlphaMode::PostMultiplied
                 } else {
                     wgpu::CompositeAlphaMode::Auto
                 };
@@ -151,6 +217,13 @@ impl Compositor {
             ..limits
         });
 
+        // Compressed images can only be drawn if the device supports their
+        // format, so we enable every compression feature available
+        let required_features = adapter.features()
+            & (wgpu::Features::TEXTURE_COMPRESSION_BC
+                | wgpu::Features::TEXTURE_COMPRESSION_ETC2
+                | wgpu::Features::TEXTURE_COMPRESSION_ASTC);
+
         let mut errors = Vec::new();
 
         for required_limits in limits {
@@ -160,7 +233,7 @@ impl Compositor {
                         label: Some(
                             "iced_wgpu::window::compositor device descriptor",
                         ),
-                        required_features: wgpu::Features::empty(),
+                        required_features,
                         required_limits: required_limits.clone(),
                     },
                     None,
@@ -270,36 +343,46 @@ impl graphics::Compositor for Compositor {
         compatible_window: W,
         backend: Option<&str>,
     ) -> Result<Self, graphics::Error> {
-        match backend {
+        let (name, backends) = match backend {
             None | Some("wgpu") => {
-                let mut settings = Settings::from(settings);
+                ("wgpu", wgpu::util::backend_bits_from_env())
+            }
+            Some("wgpu-gl") => ("wgpu-gl", Some(wgpu::Backends::GL)),
+            Some(backend) => {
+                return Err(graphics::Error::GraphicsAdapterNotFound {
+                    backend: "wgpu",
+                    reason: error::Reason::DidNotMatch {
+                        preferred_backend: backend.to_owned(),
+                    },
+                });
+            }
+        };
 
-                if let Some(backends) = wgpu::util::backend_bits_from_env() {
-                    settings.backends = backends;
-                }
+        let mut settings = Settings::from(settings);
 
-                if let Some(present_mode) = settings::present_mode_from_env() {
-                    settings.present_mode = present_mode;
-                }
+        if let Some(backends) = backends {
+            settings.backends = backends;
+        }
 
-                Ok(new(settings, compatible_window).await?)
-            }
-            Some(backend) => Err(graphics::Error::GraphicsAdapterNotFound {
-                backend: "wgpu",
-                reason: error::Reason::DidNotMatch {
-                    preferred_backend: backend.to_owned(),
-                },
-            }),
+        if let Some(present_mode) = settings::present_mode_from_env() {
+            settings.present_mode = present_mode;
         }
+
+        new(settings, compatible_window)
+            .await
+            .map_err(|error| error.into_graphics(name))
     }
 
     fn create_renderer(&self) -> Self::Renderer {
-        Renderer::new(
+        let mut renderer = Renderer::new(
             &self.device,
             &self.engine,
             self.settings.default_font,
             self.settings.default_text_size,
-        )
+        );
+
+        renderer.set_memory_budget(self.settings.memory_budget);
+        renderer
     }
 
     fn create_surface<W: compositor::Window>(
@@ -341,12 +424,46 @@ impl graphics::Compositor for Compositor {
         );
     }
 
+    fn warm_up(&mut self) {
+        self.engine.warm_up(&self.device, &self.queue);
+    }
+
+    fn preload_glyphs(&mut self, font: Font, sizes: &[Pixels], charset: &str) {
+        self.engine.preload_glyphs(
+            &self.device,
+            &self.queue,
+            font,
+            sizes,
+            charset,
+        );
+    }
+
     fn fetch_information(&self) -> compositor::Information {
         let information = self.adapter.get_info();
 
+        let formats = if crate::format::encodes_srgb(self.format) {
+            format!("{:?} (sRGB encoded by shaders)", self.format)
+        } else {
+            format!("{:?}", self.format)
+        };
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        let formats = {
+            let atlas = self.engine.image_pipeline.atlas_format();
+
+            if crate::format::decodes_srgb(atlas) {
+                format!(
+                    "{formats}, images: {atlas:?} (sRGB decoded by shaders)"
+                )
+            } else {
+                format!("{formats}, images: {atlas:?}")
+            }
+        };
+
         compositor::Information {
             adapter: information.name,
-            backend: format!("{:?}", information.backend),
+            backend: format!("wgpu ({:?})", information.backend),
+            formats: Some(formats),
         }
     }
 
@@ -428,7 +545,10 @@ pub fn screenshot<T: AsRef<str>>(
         &compositor.device,
         &mut encoder,
         texture,
-        if color::GAMMA_CORRECTION {
+        // Colors of targets lacking sRGB support are already encoded
+        if color::GAMMA_CORRECTION
+            && !crate::format::encodes_srgb(compositor.format)
+        {
             wgpu::TextureFormat::Rgba8UnormSrgb
         } else {
             wgpu::TextureFormat::Rgba8Unorm
@@ -0,0 +1,237 @@
+//! Render meshes with every blend mode, with and without multisampling, and
+//! check that the results match.
+//!
+//! The meshes are rendered headlessly. The tests are skipped on machines
+//! without an adapter.
+use iced_wgpu::core::{
+    Color, Font, Pixels, Point, Rectangle, Size, Transformation,
+};
+use iced_wgpu::graphics::color;
+use iced_wgpu::graphics::mesh::{
+    self, Blend, Mesh, Renderer as _, SolidVertex2D,
+};
+use iced_wgpu::graphics::{Antialiasing, Viewport};
+use iced_wgpu::{wgpu, Engine, Renderer};
+
+const SIZE: u32 = 64;
+
+/// The maximum difference allowed between channels.
+const TOLERANCE: u8 = 2;
+
+const BACKGROUND: Color = Color::from_rgb(0.4, 0.5, 0.6);
+
+/// The mesh drawn with alpha blending first, covering the left half.
+const BASE: (Rectangle, Color) = (
+    Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 32.0,
+        height: 64.0,
+    },
+    Color::from_rgb(0.8, 0.4, 0.2),
+);
+
+/// The mesh drawn with the blend mode under test, covering the top half.
+const BLENDED: (Rectangle, Color) = (
+    Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 64.0,
+        height: 32.0,
+    },
+    Color::from_rgba(0.5, 0.5, 0.5, 0.8),
+);
+
+#[test]
+fn alpha_blending_with_msaa_matches_without() {
+    assert_msaa_matches(Blend::Alpha);
+}
+
+#[test]
+fn additive_blending_with_msaa_matches_without() {
+    assert_msaa_matches(Blend::Additive);
+}
+
+#[test]
+fn multiply_blending_with_msaa_matches_without() {
+    assert_msaa_matches(Blend::Multiply);
+}
+
+#[test]
+fn screen_blending_with_msaa_matches_without() {
+    assert_msaa_matches(Blend::Screen);
+}
+
+fn assert_msaa_matches(blend: Blend) {
+    let Some(expected) = render(blend, None) else {
+        eprintln!("No adapter available; skipping");
+        return;
+    };
+
+    let actual = render(blend, Some(Antialiasing::MSAAx4))
+        .expect("Render with multisampling");
+
+    // Over the base mesh, over the background, and the background alone
+    for point in [
+        Point::new(16.0, 16.0),
+        Point::new(48.0, 16.0),
+        Point::new(48.0, 48.0),
+    ] {
+        let expected = pixel(&expected, point);
+        let actual = pixel(&actual, point);
+
+        let is_within_tolerance = expected
+            .iter()
+            .zip(actual)
+            .all(|(expected, actual)| expected.abs_diff(actual) <= TOLERANCE);
+
+        assert!(
+            is_within_tolerance,
+            "{blend:?} pixel at {point:?} differs: \
+            expected {expected:?}, got {actual:?}"
+        );
+    }
+}
+
+/// Renders the meshes with the given [`Blend`] mode and antialiasing;
+/// returning the RGBA pixels of the frame.
+fn render(blend: Blend, antialiasing: Option<Antialiasing>) -> Option<Vec<u8>> {
+    let format = if color::GAMMA_CORRECTION {
+        wgpu::TextureFormat::Rgba8UnormSrgb
+    } else {
+        wgpu::TextureFormat::Rgba8Unorm
+    };
+
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+
+    let adapter = futures::executor::block_on(
+        instance.request_adapter(&wgpu::RequestAdapterOptions::default()),
+    )?;
+
+    let (device, queue) = futures::executor::block_on(
+        adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("iced_wgpu blend device"),
+                required_features: wgpu::Features::empty(),
+                required_limits: wgpu::Limits::downlevel_webgl2_defaults()
+                    .using_resolution(adapter.limits()),
+            },
+            None,
+        ),
+    )
+    .ok()?;
+
+    let mut engine =
+        Engine::new(&adapter, &device, &queue, format, antialiasing);
+    let mut renderer =
+        Renderer::new(&device, &engine, Font::default(), Pixels(16.0));
+
+    renderer.draw_mesh(rectangle(BASE.0, BASE.1, Blend::Alpha));
+    renderer.draw_mesh(rectangle(BLENDED.0, BLENDED.1, blend));
+
+    let extent = wgpu::Extent3d {
+        width: SIZE,
+        height: SIZE,
+        depth_or_array_layers: 1,
+    };
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu blend target"),
+        size: extent,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("iced_wgpu blend encoder"),
+        });
+
+    renderer.present::<&str>(
+        &mut engine,
+        &device,
+        &queue,
+        &mut encoder,
+        Some(BACKGROUND),
+        format,
+        &view,
+        &Viewport::with_physical_size(Size::new(SIZE, SIZE), 1.0),
+        &[],
+    );
+
+    // 64 pixels of 4 bytes already match the row alignment of wgpu
+    let bytes_per_row = SIZE * 4;
+
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("iced_wgpu blend buffer"),
+        size: u64::from(bytes_per_row * SIZE),
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        extent,
+    );
+
+    let index = engine.submit(&queue, encoder);
+
+    let slice = buffer.slice(..);
+    slice.map_async(wgpu::MapMode::Read, |_| {});
+
+    let _ = device.poll(wgpu::Maintain::WaitForSubmissionIndex(index));
+
+    let pixels = slice.get_mapped_range().to_vec();
+
+    Some(pixels)
+}
+
+fn rectangle(bounds: Rectangle, color: Color, blend: Blend) -> Mesh {
+    let color = color::pack(color);
+
+    let vertex = |x, y| SolidVertex2D {
+        position: [x, y],
+        color,
+    };
+
+    Mesh::Solid {
+        buffers: mesh::Indexed {
+            vertices: vec![
+                vertex(bounds.x, bounds.y),
+                vertex(bounds.x + bounds.width, bounds.y),
+                vertex(bounds.x + bounds.width, bounds.y + bounds.height),
+                vertex(bounds.x, bounds.y + bounds.height),
+            ],
+            indices: vec![0, 1, 2, 0, 2, 3],
+        },
+        transformation: Transformation::IDENTITY,
+        clip_bounds: Rectangle::INFINITE,
+        blend,
+    }
+}
+
+fn pixel(pixels: &[u8], point: Point) -> [u8; 4] {
+    let index = (point.y as usize * SIZE as usize + point.x as usize) * 4;
+
+    [
+        pixels[index],
+        pixels[index + 1],
+        pixels[index + 2],
+        pixels[index + 3],
+    ]
+}
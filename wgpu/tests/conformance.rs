@@ -0,0 +1,284 @@
+//! Render the same scene on different adapters and texture formats and check
+//! that the results match.
+//!
+//! The scene is rendered headlessly. Any adapter that is not available is
+//! skipped, so these tests pass on machines without a GPU.
+use iced_wgpu::core::renderer::{self, Renderer as _};
+use iced_wgpu::core::{
+    Color, Font, Pixels, Point, Rectangle, Size, Transformation,
+};
+use iced_wgpu::graphics::color;
+use iced_wgpu::graphics::mesh::{self, Mesh, Renderer as _, SolidVertex2D};
+use iced_wgpu::graphics::Viewport;
+use iced_wgpu::{wgpu, Engine, Renderer};
+
+const SIZE: u32 = 64;
+
+/// The maximum difference allowed between channels.
+const TOLERANCE: u8 = 2;
+
+const BACKGROUND: Color = Color::from_rgb(0.1, 0.1, 0.12);
+
+/// The filled rectangles of the scene, with their colors.
+const SCENE: [(Rectangle, Color); 3] = [
+    (
+        Rectangle {
+            x: 8.0,
+            y: 8.0,
+            width: 16.0,
+            height: 16.0,
+        },
+        Color::from_rgb(0.9, 0.2, 0.1),
+    ),
+    (
+        Rectangle {
+            x: 40.0,
+            y: 8.0,
+            width: 16.0,
+            height: 16.0,
+        },
+        // Mid-gray, which differs the most between linear and sRGB
+        Color::from_rgb(0.5, 0.5, 0.5),
+    ),
+    (
+        Rectangle {
+            x: 8.0,
+            y: 40.0,
+            width: 16.0,
+            height: 16.0,
+        },
+        Color::from_rgb(0.2, 0.7, 0.3),
+    ),
+];
+
+/// The rectangle drawn with a mesh, to cover the triangle pipeline.
+const MESH: (Rectangle, Color) = (
+    Rectangle {
+        x: 40.0,
+        y: 40.0,
+        width: 16.0,
+        height: 16.0,
+    },
+    Color::from_rgb(0.3, 0.4, 0.9),
+);
+
+#[test]
+fn gl_without_srgb_matches_primary_backends() {
+    let reference_format = if color::GAMMA_CORRECTION {
+        wgpu::TextureFormat::Rgba8UnormSrgb
+    } else {
+        wgpu::TextureFormat::Rgba8Unorm
+    };
+
+    let Some(reference) = render(wgpu::Backends::PRIMARY, reference_format)
+    else {
+        eprintln!("No primary adapter available; skipping");
+        return;
+    };
+
+    // GL, like WebGL, may only offer linear surfaces
+    let Some(fallback) =
+        render(wgpu::Backends::GL, wgpu::TextureFormat::Rgba8Unorm)
+    else {
+        eprintln!("No GL adapter available; skipping");
+        return;
+    };
+
+    assert_similar(&reference, &fallback);
+}
+
+#[test]
+fn srgb_and_linear_targets_match() {
+    if !color::GAMMA_CORRECTION {
+        // Without gamma correction, colors are never encoded by the shaders
+        return;
+    }
+
+    let Some(srgb) =
+        render(wgpu::Backends::all(), wgpu::TextureFormat::Rgba8UnormSrgb)
+    else {
+        eprintln!("No adapter available; skipping");
+        return;
+    };
+
+    let linear = render(wgpu::Backends::all(), wgpu::TextureFormat::Rgba8Unorm)
+        .expect("Render to a linear target");
+
+    assert_similar(&srgb, &linear);
+}
+
+/// Renders the scene with the first adapter of the given backends into a
+/// texture with the given format; returning its RGBA pixels.
+fn render(
+    backends: wgpu::Backends,
+    format: wgpu::TextureFormat,
+) -> Option<Vec<u8>> {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends,
+        ..Default::default()
+    });
+
+    let adapter = futures::executor::block_on(
+        instance.request_adapter(&wgpu::RequestAdapterOptions::default()),
+    )?;
+
+    let (device, queue) = futures::executor::block_on(
+        adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("iced_wgpu conformance device"),
+                required_features: wgpu::Features::empty(),
+                required_limits: wgpu::Limits::downlevel_webgl2_defaults()
+                    .using_resolution(adapter.limits()),
+            },
+            None,
+        ),
+    )
+    .ok()?;
+
+    let mut engine = Engine::new(&adapter, &device, &queue, format, None);
+    let mut renderer =
+        Renderer::new(&device, &engine, Font::default(), Pixels(16.0));
+
+    for (bounds, color) in SCENE {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+
+    renderer.draw_mesh(rectangle(MESH.0, MESH.1));
+
+    let extent = wgpu::Extent3d {
+        width: SIZE,
+        height: SIZE,
+        depth_or_array_layers: 1,
+    };
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu conformance target"),
+        size: extent,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("iced_wgpu conformance encoder"),
+        });
+
+    renderer.present::<&str>(
+        &mut engine,
+        &device,
+        &queue,
+        &mut encoder,
+        Some(BACKGROUND),
+        format,
+        &view,
+        &Viewport::with_physical_size(Size::new(SIZE, SIZE), 1.0),
+        &[],
+    );
+
+    // 64 pixels of 4 bytes already match the row alignment of wgpu
+    let bytes_per_row = SIZE * 4;
+
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("iced_wgpu conformance buffer"),
+        size: u64::from(bytes_per_row * SIZE),
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        extent,
+    );
+
+    let index = engine.submit(&queue, encoder);
+
+    let slice = buffer.slice(..);
+    slice.map_async(wgpu::MapMode::Read, |_| {});
+
+    let _ = device.poll(wgpu::Maintain::WaitForSubmissionIndex(index));
+
+    let pixels = slice.get_mapped_range().to_vec();
+
+    Some(pixels)
+}
+
+fn rectangle(bounds: Rectangle, color: Color) -> Mesh {
+    let color = color::pack(color);
+
+    let vertex = |x, y| SolidVertex2D {
+        position: [x, y],
+        color,
+    };
+
+    Mesh::Solid {
+        buffers: mesh::Indexed {
+            vertices: vec![
+                vertex(bounds.x, bounds.y),
+                vertex(bounds.x + bounds.width, bounds.y),
+                vertex(bounds.x + bounds.width, bounds.y + bounds.height),
+                vertex(bounds.x, bounds.y + bounds.height),
+            ],
+            indices: vec![0, 1, 2, 0, 2, 3],
+        },
+        transformation: Transformation::IDENTITY,
+        clip_bounds: Rectangle::INFINITE,
+        blend: mesh::Blend::Alpha,
+    }
+}
+
+/// Asserts that both renders of the scene match within the [`TOLERANCE`],
+/// sampling the center of every shape and the background.
+fn assert_similar(expected: &[u8], actual: &[u8]) {
+    let samples = SCENE
+        .iter()
+        .chain([&MESH])
+        .map(|(bounds, _)| bounds.center())
+        .chain([Point::new(32.0, 32.0)]);
+
+    for point in samples {
+        let expected = pixel(expected, point);
+        let actual = pixel(actual, point);
+
+        let is_within_tolerance = expected
+            .iter()
+            .zip(actual)
+            .all(|(expected, actual)| expected.abs_diff(actual) <= TOLERANCE);
+
+        assert!(
+            is_within_tolerance,
+            "pixel at {point:?} differs: expected {expected:?}, got {actual:?}"
+        );
+    }
+}
+
+fn pixel(pixels: &[u8], point: Point) -> [u8; 4] {
+    let index = (point.y as usize * SIZE as usize + point.x as usize) * 4;
+
+    [
+        pixels[index],
+        pixels[index + 1],
+        pixels[index + 2],
+        pixels[index + 3],
+    ]
+}
@@ -6,12 +6,14 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
-    Background, Clipboard, Color, Element, Layout, Length, Padding, Rectangle,
-    Shadow, Shell, Size, Theme, Vector, Widget,
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
 };
 
 /// A generic widget that produces a message when pressed.
@@ -54,8 +56,13 @@ where
 {
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<OnPress<'a, Message>>,
+    repeat: Option<Repeat>,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
+    max_width: f32,
+    max_height: f32,
     padding: Padding,
     clip: bool,
     class: Theme::Class<'a>,
@@ -90,8 +97,13 @@ where
         Button {
             content,
             on_press: None,
+            repeat: None,
             width: size.width.fluid(),
             height: size.height.fluid(),
+            min_width: 0.0,
+            min_height: 0.0,
+            max_width: f32::INFINITY,
+            max_height: f32::INFINITY,
             padding: DEFAULT_PADDING,
             clip: false,
             class: Theme::default(),
@@ -110,6 +122,30 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Button`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Button`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = min_height.into().0;
+        self
+    }
+
+    /// Sets the maximum width of the [`Button`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the maximum height of the [`Button`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max_height = max_height.into().0;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Button`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -149,6 +185,18 @@ where
         self
     }
 
+    /// Makes the [`Button`] repeat its message while it is held down; like
+    /// the buttons of a stepper.
+    ///
+    /// A repeating [`Button`] produces its message as soon as it is pressed,
+    /// instead of when released. If it is still held down after the given
+    /// `delay`, the message is produced again every `interval`; until the
+    /// [`Button`] is released, the cursor leaves it, or it gets disabled.
+    pub fn repeat(mut self, delay: Duration, interval: Duration) -> Self {
+        self.repeat = Some(Repeat { delay, interval });
+        self
+    }
+
     /// Sets whether the contents of the [`Button`] should be clipped on
     /// overflow.
     pub fn clip(mut self, clip: bool) -> Self {
@@ -175,9 +223,28 @@ where
     }
 }
 
+/// The timing of a repeating [`Button`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Repeat {
+    delay: Duration,
+    interval: Duration,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_pressed: bool,
+    repeating: Repeating,
+}
+
+/// The repetition of the message of a [`Button`] being held down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Repeating {
+    #[default]
+    Idle,
+    /// The [`Button`] was just pressed; its delay starts on the next redraw.
+    Starting,
+    /// The message will be repeated at the given [`Instant`].
+    At(Instant),
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -216,8 +283,13 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let limits = limits.constrain(
+            Size::new(self.min_width, self.min_height),
+            Size::new(self.max_width, self.max_height),
+        );
+
         layout::padded(
-            limits,
+            &limits,
             self.width,
             self.height,
             self.padding,
@@ -275,7 +347,7 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
+                if let Some(on_press) = &self.on_press {
                     let bounds = layout.bounds();
 
                     if cursor.is_over(bounds) {
@@ -283,23 +355,32 @@ where
 
                         state.is_pressed = true;
 
+                        if self.repeat.is_some() {
+                            shell.publish(on_press.get());
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+
+                            state.repeating = Repeating::Starting;
+                        }
+
                         return event::Status::Captured;
                     }
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if let Some(on_press) = self.on_press.as_ref().map(OnPress::get)
-                {
+                if let Some(on_press) = &self.on_press {
                     let state = tree.state.downcast_mut::<State>();
 
                     if state.is_pressed {
                         state.is_pressed = false;
+                        state.repeating = Repeating::Idle;
 
                         let bounds = layout.bounds();
 
-                        if cursor.is_over(bounds) {
-                            shell.publish(on_press);
+                        if self.repeat.is_none() && cursor.is_over(bounds) {
+                            shell.publish(on_press.get());
                         }
 
                         return event::Status::Captured;
@@ -310,6 +391,45 @@ where
                 let state = tree.state.downcast_mut::<State>();
 
                 state.is_pressed = false;
+                state.repeating = Repeating::Idle;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                let (Some(repeat), Some(on_press)) =
+                    (self.repeat, &self.on_press)
+                else {
+                    state.repeating = Repeating::Idle;
+
+                    return event::Status::Ignored;
+                };
+
+                if !cursor.is_over(layout.bounds()) {
+                    state.repeating = Repeating::Idle;
+
+                    return event::Status::Ignored;
+                }
+
+                let next = match state.repeating {
+                    Repeating::Idle => return event::Status::Ignored,
+                    Repeating::Starting => now + repeat.delay,
+                    Repeating::At(next) if now >= next => {
+                        shell.publish(on_press.get());
+
+                        let next = next + repeat.interval;
+
+                        // Skip the repetitions missed by a late redraw
+                        if next > now {
+                            next
+                        } else {
+                            now + repeat.interval
+                        }
+                    }
+                    Repeating::At(next) => next,
+                };
+
+                state.repeating = Repeating::At(next);
+                shell.request_redraw(window::RedrawRequest::At(next));
             }
             _ => {}
         }
@@ -404,12 +524,14 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         self.content.as_widget_mut().overlay(
             &mut tree.children[0],
             layout.children().next().unwrap(),
             renderer,
+            viewport,
             translation,
         )
     }
@@ -606,3 +728,128 @@ fn disabled(style: Style) -> Style {
         ..style
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Point;
+    use crate::Space;
+
+    fn size(button: Button<'static, (), Theme, ()>) -> Size {
+        let element = Element::from(button);
+        let mut tree = Tree::new(&element);
+
+        element
+            .as_widget()
+            .layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+            )
+            .size()
+    }
+
+    #[test]
+    fn fill_respects_the_maximum() {
+        assert_eq!(
+            size(
+                Button::new(Space::new(10, 10))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .max_width(200)
+                    .max_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn shrink_respects_the_minimum() {
+        assert_eq!(
+            size(
+                Button::new(Space::new(10, 10))
+                    .min_width(200)
+                    .min_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    /// Sends the events to a 100x100 repeating [`Button`]; with the cursor
+    /// at the given positions.
+    fn repeat(events: impl IntoIterator<Item = (Point, Event)>) -> Vec<u8> {
+        let mut button: Element<'static, u8, Theme, ()> =
+            Button::new(Space::new(100, 100))
+                .padding(0)
+                .on_press(1)
+                .repeat(Duration::from_millis(500), Duration::from_millis(100))
+                .into();
+
+        let mut tree = Tree::new(&button);
+
+        let node = button.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for (cursor, event) in events {
+            let _ = button.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(cursor),
+                &(),
+                &mut crate::core::clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        messages
+    }
+
+    fn redraw(now: Instant) -> Event {
+        Event::Window(window::Event::RedrawRequested(now))
+    }
+
+    #[test]
+    fn repeating_buttons_repeat_while_held() {
+        let inside = Point::new(50.0, 50.0);
+        let start = Instant::now();
+        let after = |millis| start + Duration::from_millis(millis);
+
+        let press =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let release =
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+
+        assert_eq!(
+            repeat([
+                (inside, press.clone()),
+                (inside, redraw(start)),
+                (inside, redraw(after(400))),
+                (inside, redraw(after(500))),
+                (inside, redraw(after(550))),
+                (inside, redraw(after(600))),
+                (inside, release.clone()),
+                (inside, redraw(after(700))),
+            ]),
+            vec![1, 1, 1]
+        );
+
+        assert_eq!(
+            repeat([
+                (inside, press),
+                (inside, redraw(start)),
+                (Point::new(150.0, 50.0), redraw(after(500))),
+                (inside, redraw(after(600))),
+                (inside, release),
+            ]),
+            vec![1]
+        );
+    }
+}
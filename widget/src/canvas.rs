@@ -8,17 +8,19 @@ pub use program::Program;
 
 pub use crate::graphics::cache::Group;
 pub use crate::graphics::geometry::{
-    fill, gradient, path, stroke, Fill, Gradient, LineCap, LineDash, LineJoin,
-    Path, Stroke, Style, Text,
+    fill, gradient, path, stroke, Blend, Fill, Gradient, LineCap, LineDash,
+    LineJoin, Path, Stroke, Text,
 };
 
 use crate::core;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::theme::palette;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Element, Length, Rectangle, Shell, Size, Vector, Widget,
+    Clipboard, Color, Element, Length, Rectangle, Shell, Size, Theme, Vector,
+    Widget,
 };
 use crate::graphics::geometry;
 
@@ -43,8 +45,8 @@ pub type Frame<Renderer = crate::Renderer> = geometry::Frame<Renderer>;
 /// If you want to get a quick overview, here's how we can draw a simple circle:
 ///
 /// ```no_run
-/// # use iced_widget::canvas::{self, Canvas, Fill, Frame, Geometry, Path, Program};
-/// # use iced_widget::core::{Color, Rectangle};
+/// # use iced_widget::canvas::{self, Canvas, Fill, Frame, Geometry, Path, Program, Style};
+/// # use iced_widget::core::Rectangle;
 /// # use iced_widget::core::mouse;
 /// # use iced_widget::{Renderer, Theme};
 /// #
@@ -58,15 +60,15 @@ pub type Frame<Renderer = crate::Renderer> = geometry::Frame<Renderer>;
 /// impl Program<()> for Circle {
 ///     type State = ();
 ///
-///     fn draw(&self, _state: &(), renderer: &Renderer, _theme: &Theme, bounds: Rectangle, _cursor: mouse::Cursor) -> Vec<Geometry> {
+///     fn draw(&self, _state: &(), renderer: &Renderer, _theme: &Theme, style: &Style, bounds: Rectangle, _cursor: mouse::Cursor) -> Vec<Geometry> {
 ///         // We prepare a new `Frame`
 ///         let mut frame = Frame::new(renderer, bounds.size());
 ///
 ///         // We create a `Path` representing a simple circle
 ///         let circle = Path::circle(frame.center(), self.radius);
 ///
-///         // And fill it with some color
-///         frame.fill(&circle, Color::BLACK);
+///         // And fill it with the text color of the theme
+///         frame.fill(&circle, style.text);
 ///
 ///         // Finally, we produce the geometry
 ///         vec![frame.into_geometry()]
@@ -125,6 +127,7 @@ where
 impl<P, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Canvas<P, Message, Theme, Renderer>
 where
+    Theme: Catalog,
     Renderer: geometry::Renderer,
     P: Program<Message, Theme, Renderer>,
 {
@@ -172,7 +175,7 @@ where
             core::Event::Keyboard(keyboard_event) => {
                 Some(Event::Keyboard(keyboard_event))
             }
-            core::Event::Window(_) => None,
+            core::Event::Window(_) | core::Event::InputMethod(_) => None,
         };
 
         if let Some(canvas_event) = canvas_event {
@@ -222,15 +225,22 @@ where
         }
 
         let state = tree.state.downcast_ref::<P::State>();
+        let style = theme.style();
+
+        let layers = self
+            .program
+            .draw(state, renderer, theme, &style, bounds, cursor);
 
         renderer.with_translation(
             Vector::new(bounds.x, bounds.y),
             |renderer| {
-                let layers =
-                    self.program.draw(state, renderer, theme, bounds, cursor);
-
-                for layer in layers {
-                    renderer.draw_geometry(layer);
+                for (i, layer) in layers.into_iter().enumerate() {
+                    // Later groups must cover the text of earlier ones
+                    if i == 0 {
+                        renderer.draw_geometry(layer);
+                    } else {
+                        renderer.draw_geometry_over(layer);
+                    }
                 }
             },
         );
@@ -241,7 +251,7 @@ impl<'a, P, Message, Theme, Renderer> From<Canvas<P, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
-    Theme: 'a,
+    Theme: 'a + Catalog,
     Renderer: 'a + geometry::Renderer,
     P: 'a + Program<Message, Theme, Renderer>,
 {
@@ -251,3 +261,47 @@ where
         Element::new(canvas)
     }
 }
+
+/// The colors of a theme available to the [`Program`] of a [`Canvas`].
+///
+/// Drawing with these colors, instead of hardcoded ones, lets the contents
+/// of a [`Canvas`] adapt to light and dark themes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background colors.
+    pub background: palette::Background,
+    /// The text color.
+    pub text: Color,
+    /// The primary colors.
+    pub primary: palette::Primary,
+    /// The success colors.
+    pub success: palette::Success,
+    /// The danger colors.
+    pub danger: palette::Danger,
+}
+
+/// The theme catalog of a [`Canvas`].
+pub trait Catalog {
+    /// The [`Style`] passed to the [`Program`] of a [`Canvas`].
+    fn style(&self) -> Style;
+}
+
+impl Catalog for Theme {
+    fn style(&self) -> Style {
+        default(self)
+    }
+}
+
+/// The default [`Style`] of a [`Canvas`], derived from the extended palette
+/// of the [`Theme`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background,
+        text: palette.background.base.text,
+        primary: palette.primary,
+        success: palette.success,
+        danger: palette.danger,
+    }
+}
@@ -1,6 +1,6 @@
 use crate::canvas::event::{self, Event};
 use crate::canvas::mouse;
-use crate::canvas::Geometry;
+use crate::canvas::{Geometry, Style};
 use crate::core::Rectangle;
 use crate::graphics::geometry;
 
@@ -40,9 +40,17 @@ where
 
     /// Draws the state of the [`Program`], producing a bunch of [`Geometry`].
     ///
+    /// The [`Style`] contains the colors of the current theme, which lets
+    /// the [`Program`] adapt to it without bounds on the `Theme` type.
+    ///
     /// [`Geometry`] can be easily generated with a [`Frame`] or stored in a
     /// [`Cache`].
     ///
+    /// Every [`Geometry`] is drawn in the order returned; so the last one
+    /// is on top of the rest. Drawing each with its own [`Cache`] lets parts
+    /// that change often, like a crosshair, be redrawn without the static
+    /// ones, like a grid.
+    ///
     /// [`Geometry`]: crate::canvas::Geometry
     /// [`Frame`]: crate::canvas::Frame
     /// [`Cache`]: crate::canvas::Cache
@@ -51,6 +59,7 @@ where
         state: &Self::State,
         renderer: &Renderer,
         theme: &Theme,
+        style: &Style,
         bounds: Rectangle,
         cursor: mouse::Cursor,
     ) -> Vec<Geometry<Renderer>>;
@@ -93,10 +102,11 @@ where
         state: &Self::State,
         renderer: &Renderer,
         theme: &Theme,
+        style: &Style,
         bounds: Rectangle,
         cursor: mouse::Cursor,
     ) -> Vec<Geometry<Renderer>> {
-        T::draw(self, state, renderer, theme, bounds, cursor)
+        T::draw(self, state, renderer, theme, style, bounds, cursor)
     }
 
     fn mouse_interaction(
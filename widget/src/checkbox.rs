@@ -198,11 +198,11 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -218,17 +218,36 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let Icon {
+            font,
+            code_point,
+            size,
+            line_height,
+            shaping,
+        } = &self.icon;
+
+        state.icon.update(text::Text {
+            content: code_point.encode_utf8(&mut [0; 4]),
+            font: *font,
+            size: size.unwrap_or(Pixels(self.size * 0.7)),
+            line_height: *line_height,
+            bounds: Size::new(self.size, self.size),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+            font_features: &[],
+            shaping: *shaping,
+        });
+
         layout::next_to_each_other(
             &limits.width(self.width),
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
-
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -237,8 +256,10 @@ where
                     self.text_line_height,
                     self.text_size,
                     self.font,
+                    &[],
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
+                    text::DEFAULT_MAX_SPACE_FACTOR,
                     self.text_shaping,
                 )
             },
@@ -299,6 +320,7 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let is_mouse_over = cursor.is_over(layout.bounds());
         let is_disabled = self.on_toggle.is_none();
         let is_checked = self.is_checked;
@@ -328,27 +350,9 @@ where
                 style.background,
             );
 
-            let Icon {
-                font,
-                code_point,
-                size,
-                line_height,
-                shaping,
-            } = &self.icon;
-            let size = size.unwrap_or(Pixels(bounds.height * 0.7));
-
             if self.is_checked {
                 renderer.fill_text(
-                    text::Text {
-                        content: code_point.to_string(),
-                        font: *font,
-                        size,
-                        line_height: *line_height,
-                        bounds: bounds.size(),
-                        horizontal_alignment: alignment::Horizontal::Center,
-                        vertical_alignment: alignment::Vertical::Center,
-                        shaping: *shaping,
-                    },
+                    &state.icon,
                     bounds.center(),
                     style.icon_color,
                     *viewport,
@@ -358,14 +362,12 @@ where
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -389,6 +391,15 @@ where
     }
 }
 
+/// The local state of a [`Checkbox`].
+///
+/// Both the label and the icon are shaped only when they change.
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    icon: text::paragraph::Plain<P>,
+}
+
 /// The icon in a [`Checkbox`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Icon<Font> {
@@ -0,0 +1,682 @@
+//! Show a circular progress indicator.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::settings::Motion;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window::{self, RedrawRequest};
+use crate::core::{
+    self, Background, Border, Clipboard, Color, Element, Font, Layout, Length,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Transformation, Vector,
+    Widget,
+};
+use crate::easing::{self, Easing, Transition};
+use crate::graphics::color;
+use crate::graphics::mesh::{self, Mesh, SolidVertex2D};
+use crate::progress_bar::Label;
+
+use std::f32::consts::PI;
+
+const MIN_ANGLE: f32 = PI / 8.0;
+const WRAP_ANGLE: f32 = 2.0 * PI - PI / 4.0;
+const BASE_ROTATION_SPEED: u32 = u32::MAX / 80;
+
+/// The amount of segments used to draw a full turn of the bar.
+const SEGMENTS_PER_TURN: f32 = 64.0;
+
+/// The interval between redraws while the indicator is out of sight.
+const HIDDEN_REDRAW_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A circular progress indicator.
+///
+/// By default, it is indeterminate: its bar spins around while expanding and
+/// contracting following an [`Easing`]. Set its [`progress`] to make it
+/// determinate instead.
+///
+/// The indeterminate bar follows the current [`Motion`] policy: it becomes
+/// a full ring that pulses when motion is reduced, and a still one when
+/// there is no motion at all.
+///
+/// # Example
+/// ```no_run
+/// # type CircularProgress<'a> = iced_widget::CircularProgress<'a>;
+/// #
+/// let loading = CircularProgress::new().size(48);
+/// let uploading = CircularProgress::new().progress(0.75);
+/// ```
+///
+/// [`progress`]: Self::progress
+#[allow(missing_debug_implementations)]
+pub struct CircularProgress<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    size: f32,
+    thickness: f32,
+    progress: Option<f32>,
+    progress_duration: Duration,
+    easing: &'a Easing,
+    cycle_duration: Duration,
+    rotation_duration: Duration,
+    redraw_interval: Option<Duration>,
+    label: Option<Box<dyn Fn(f32) -> String + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> CircularProgress<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`CircularProgress`].
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// The default thickness of the bar of a [`CircularProgress`].
+    pub const DEFAULT_THICKNESS: f32 = 4.0;
+
+    /// Creates a new indeterminate [`CircularProgress`].
+    pub fn new() -> Self {
+        CircularProgress {
+            size: Self::DEFAULT_SIZE,
+            thickness: Self::DEFAULT_THICKNESS,
+            progress: None,
+            progress_duration: Duration::ZERO,
+            easing: &easing::STANDARD,
+            cycle_duration: Duration::from_millis(600),
+            rotation_duration: Duration::from_secs(2),
+            redraw_interval: None,
+            label: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the size of the [`CircularProgress`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the thickness of the bar of the [`CircularProgress`].
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    /// Makes the [`CircularProgress`] determinate, showing the given progress
+    /// between `0.0` and `1.0`.
+    ///
+    /// A determinate [`CircularProgress`] does not spin. Setting the progress
+    /// back to `None` makes it indeterminate again.
+    pub fn progress(mut self, progress: impl Into<Option<f32>>) -> Self {
+        self.progress =
+            progress.into().map(|progress| progress.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the duration of the transition of the bar of a determinate
+    /// [`CircularProgress`] towards a new progress, following its
+    /// [`Easing`].
+    ///
+    /// By default, changes in progress are shown right away.
+    pub fn progress_duration(mut self, duration: Duration) -> Self {
+        self.progress_duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] of the animation of the [`CircularProgress`].
+    pub fn easing(mut self, easing: &'a Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the duration of a full cycle of the [`CircularProgress`]; that
+    /// is, the time the bar takes to expand and contract once.
+    pub fn cycle_duration(mut self, duration: Duration) -> Self {
+        self.cycle_duration = duration / 2;
+        self
+    }
+
+    /// Sets the base rotation duration of the [`CircularProgress`].
+    ///
+    /// This is the duration that a full rotation would take if the bar
+    /// neither expanded nor contracted.
+    pub fn rotation_duration(mut self, duration: Duration) -> Self {
+        self.rotation_duration = duration;
+        self
+    }
+
+    /// Sets the minimum interval between redraws of the [`CircularProgress`].
+    ///
+    /// By default, the [`CircularProgress`] redraws every frame while
+    /// visible.
+    pub fn redraw_interval(mut self, interval: Option<Duration>) -> Self {
+        self.redraw_interval = interval;
+        self
+    }
+
+    /// Sets the function that formats the progress of a determinate
+    /// [`CircularProgress`] into a label, drawn at its center.
+    ///
+    /// The label is not drawn while the [`CircularProgress`] is
+    /// indeterminate.
+    pub fn label(mut self, label: impl Fn(f32) -> String + 'a) -> Self {
+        self.label = Some(Box::new(label));
+        self
+    }
+
+    /// Sets the style of the [`CircularProgress`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`CircularProgress`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Theme> Default for CircularProgress<'a, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Animation {
+    Expanding {
+        start: Instant,
+        progress: f32,
+        rotation: u32,
+        last: Instant,
+    },
+    Contracting {
+        start: Instant,
+        progress: f32,
+        rotation: u32,
+        last: Instant,
+    },
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self::Expanding {
+            start: Instant::now(),
+            progress: 0.0,
+            rotation: 0,
+            last: Instant::now(),
+        }
+    }
+}
+
+impl Animation {
+    fn next(&self, additional_rotation: u32, now: Instant) -> Self {
+        match self {
+            Self::Expanding { rotation, .. } => Self::Contracting {
+                start: now,
+                progress: 0.0,
+                rotation: rotation.wrapping_add(additional_rotation),
+                last: now,
+            },
+            Self::Contracting { rotation, .. } => Self::Expanding {
+                start: now,
+                progress: 0.0,
+                rotation: rotation.wrapping_add(
+                    BASE_ROTATION_SPEED.wrapping_add(
+                        (f64::from(WRAP_ANGLE / (2.0 * PI)) * f64::MAX) as u32,
+                    ),
+                ),
+                last: now,
+            },
+        }
+    }
+
+    fn start(&self) -> Instant {
+        match self {
+            Self::Expanding { start, .. } | Self::Contracting { start, .. } => {
+                *start
+            }
+        }
+    }
+
+    fn last(&self) -> Instant {
+        match self {
+            Self::Expanding { last, .. } | Self::Contracting { last, .. } => {
+                *last
+            }
+        }
+    }
+
+    fn timed_transition(
+        &self,
+        cycle_duration: Duration,
+        rotation_duration: Duration,
+        now: Instant,
+    ) -> Self {
+        let elapsed = now.duration_since(self.start());
+        let additional_rotation = ((now - self.last()).as_secs_f32()
+            / rotation_duration.as_secs_f32()
+            * (u32::MAX) as f32) as u32;
+
+        if elapsed > cycle_duration {
+            self.next(additional_rotation, now)
+        } else {
+            self.with_elapsed(cycle_duration, additional_rotation, elapsed, now)
+        }
+    }
+
+    fn with_elapsed(
+        &self,
+        cycle_duration: Duration,
+        additional_rotation: u32,
+        elapsed: Duration,
+        now: Instant,
+    ) -> Self {
+        let progress = elapsed.as_secs_f32() / cycle_duration.as_secs_f32();
+
+        match self {
+            Self::Expanding {
+                start, rotation, ..
+            } => Self::Expanding {
+                start: *start,
+                progress,
+                rotation: rotation.wrapping_add(additional_rotation),
+                last: now,
+            },
+            Self::Contracting {
+                start, rotation, ..
+            } => Self::Contracting {
+                start: *start,
+                progress,
+                rotation: rotation.wrapping_add(additional_rotation),
+                last: now,
+            },
+        }
+    }
+
+    fn rotation(&self) -> f32 {
+        match self {
+            Self::Expanding { rotation, .. }
+            | Self::Contracting { rotation, .. } => {
+                *rotation as f32 / u32::MAX as f32
+            }
+        }
+    }
+
+    /// Returns the start and end angles of the bar, in radians.
+    fn arc(&self, easing: &Easing) -> (f32, f32) {
+        let start = self.rotation() * 2.0 * PI;
+
+        match self {
+            Self::Expanding { progress, .. } => (
+                start,
+                start + MIN_ANGLE + WRAP_ANGLE * easing.y_at_x(*progress),
+            ),
+            Self::Contracting { progress, .. } => (
+                start + WRAP_ANGLE * easing.y_at_x(*progress),
+                start + MIN_ANGLE + WRAP_ANGLE,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    animation: Animation,
+    progress: Option<Transition>,
+    started: Instant,
+    opacity: f32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            animation: Animation::default(),
+            progress: None,
+            started: Instant::now(),
+            opacity: 1.0,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CircularProgress<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font> + mesh::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            progress: self.progress.map(Transition::new),
+            ..State::default()
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match (self.progress, &mut state.progress) {
+            (Some(progress), Some(transition)) => {
+                transition.go_to(progress, self.progress_duration);
+            }
+            (Some(progress), None) => {
+                state.progress = Some(Transition::new(progress));
+            }
+            (None, Some(_)) => {
+                // Restart the cycle, since it was frozen while determinate
+                *state = State::default();
+            }
+            (None, None) => {}
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Some(transition) = &mut state.progress {
+                if !transition.update(self.easing, self.progress_duration, now)
+                {
+                    return event::Status::Ignored;
+                }
+            } else {
+                match Motion::current() {
+                    Motion::Full => {
+                        state.animation = state.animation.timed_transition(
+                            self.cycle_duration,
+                            self.rotation_duration,
+                            now,
+                        );
+                    }
+                    Motion::Reduced => {
+                        state.opacity = easing::pulse(state.started, now);
+                    }
+                    Motion::None => return event::Status::Ignored,
+                }
+            }
+
+            shell.request_redraw(if !layout.bounds().intersects(viewport) {
+                RedrawRequest::At(now + HIDDEN_REDRAW_INTERVAL)
+            } else if let Some(interval) = self.redraw_interval {
+                RedrawRequest::At(now + interval)
+            } else {
+                RedrawRequest::NextFrame
+            });
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let center = bounds.center();
+        let radius = bounds.width.min(bounds.height) / 2.0 - self.thickness;
+        let outer_radius = radius + self.thickness / 2.0;
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: (bounds.width.min(bounds.height) / 2.0).into(),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - outer_radius,
+                    y: center.y - outer_radius,
+                    width: outer_radius * 2.0,
+                    height: outer_radius * 2.0,
+                },
+                border: Border {
+                    color: style.track_color,
+                    width: self.thickness,
+                    radius: outer_radius.into(),
+                },
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+
+        let progress = state.progress.as_ref().map(Transition::value);
+
+        if let (Some(label), Some(progress)) = (&self.label, progress) {
+            renderer.fill_text(
+                text::Text {
+                    content: label(progress),
+                    font: style.label.font,
+                    size: style.label.size,
+                    line_height: text::LineHeight::default(),
+                    bounds: bounds.size(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                    font_features: &[],
+                    shaping: text::Shaping::Basic,
+                },
+                center,
+                style.label.color,
+                *viewport,
+            );
+        }
+
+        let (start, end, opacity) = match progress {
+            Some(progress) => (-PI / 2.0, -PI / 2.0 + 2.0 * PI * progress, 1.0),
+            None => match Motion::current() {
+                Motion::Full => {
+                    let (start, end) = state.animation.arc(self.easing);
+
+                    (start, end, 1.0)
+                }
+                Motion::Reduced => (-PI / 2.0, 3.0 * PI / 2.0, state.opacity),
+                Motion::None => {
+                    (-PI / 2.0, 3.0 * PI / 2.0, easing::STILL_OPACITY)
+                }
+            },
+        };
+
+        // An empty bar has no area; a full one meets itself at the top
+        if end - start <= f32::EPSILON || radius <= 0.0 {
+            return;
+        }
+
+        let mesh = arc(
+            center - Vector::new(bounds.x, bounds.y),
+            radius,
+            self.thickness,
+            start,
+            end,
+            style.bar_color.scale_alpha(opacity),
+        );
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw_mesh(mesh);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CircularProgress<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + text::Renderer<Font = Font> + mesh::Renderer,
+{
+    fn from(
+        circular_progress: CircularProgress<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(circular_progress)
+    }
+}
+
+/// Builds a [`Mesh`] for a stroked arc, as a strip of triangles.
+fn arc(
+    center: Point,
+    radius: f32,
+    thickness: f32,
+    start: f32,
+    end: f32,
+    color: Color,
+) -> Mesh {
+    let segments = ((end - start) / (2.0 * PI) * SEGMENTS_PER_TURN)
+        .ceil()
+        .max(2.0) as u32;
+
+    let inner = radius - thickness / 2.0;
+    let outer = radius + thickness / 2.0;
+    let color = color::pack(color);
+
+    let vertices = (0..=segments)
+        .flat_map(|segment| {
+            let angle =
+                start + (end - start) * segment as f32 / segments as f32;
+            let (sin, cos) = angle.sin_cos();
+
+            [inner, outer].map(|radius| SolidVertex2D {
+                position: [center.x + radius * cos, center.y + radius * sin],
+                color,
+            })
+        })
+        .collect();
+
+    let indices = (0..segments)
+        .flat_map(|segment| {
+            let i = segment * 2;
+
+            [i, i + 1, i + 2, i + 1, i + 3, i + 2]
+        })
+        .collect();
+
+    Mesh::Solid {
+        buffers: mesh::Indexed { vertices, indices },
+        transformation: Transformation::IDENTITY,
+        clip_bounds: Rectangle::INFINITE,
+        blend: mesh::Blend::Alpha,
+    }
+}
+
+/// The appearance of a circular progress indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the progress indicator.
+    pub background: Option<Background>,
+    /// The [`Color`] of the track of the progress indicator.
+    pub track_color: Color,
+    /// The [`Color`] of the bar of the progress indicator.
+    pub bar_color: Color,
+    /// The [`Label`] of the progress indicator.
+    ///
+    /// The label is drawn inside the track, so only its `color` is used.
+    pub label: Label,
+}
+
+/// The theme catalog of a [`CircularProgress`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`CircularProgress`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`CircularProgress`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        track_color: palette.background.weak.color,
+        bar_color: palette.primary.base.color,
+        label: Label {
+            font: Font::DEFAULT,
+            size: Pixels(10.0),
+            color: palette.background.base.text,
+            bar_color: palette.primary.base.text,
+        },
+    }
+}
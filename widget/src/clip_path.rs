@@ -0,0 +1,210 @@
+//! Clip any content to an arbitrary path.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::graphics::geometry::path::lyon_path;
+use crate::graphics::geometry::{self, Path};
+
+/// A widget that clips its content to a [`Path`].
+///
+/// The [`Path`] is filled with the non-zero rule and it is relative to the
+/// top-left corner of the content. The content only receives the mouse
+/// cursor while it is inside of the [`Path`]; so clicks outside of the
+/// visible area do not reach it.
+///
+/// Renderers clip custom shader primitives only to the bounds of the
+/// [`Path`].
+#[allow(missing_debug_implementations)]
+pub struct ClipPath<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    path: Path,
+}
+
+impl<'a, Message, Theme, Renderer> ClipPath<'a, Message, Theme, Renderer> {
+    /// Creates a [`ClipPath`] with the given content and [`Path`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        path: Path,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            path,
+        }
+    }
+
+    /// Returns the mouse cursor, unless it is outside of the [`Path`] in
+    /// the given bounds.
+    fn cursor(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        match cursor.position() {
+            Some(position)
+                if self
+                    .path
+                    .contains(position - Vector::new(bounds.x, bounds.y)) =>
+            {
+                cursor
+            }
+            _ => mouse::Cursor::Unavailable,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ClipPath<'a, Message, Theme, Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let cursor = self.cursor(layout.bounds(), cursor);
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            self.cursor(layout.bounds(), cursor),
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let translation =
+            lyon_path::math::Transform::translation(bounds.x, bounds.y);
+        let path = self.path.transform(&translation);
+
+        renderer.with_clip(&path, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                self.cursor(bounds, cursor),
+                viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ClipPath<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + geometry::Renderer,
+{
+    fn from(
+        clip_path: ClipPath<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(clip_path)
+    }
+}
@@ -19,8 +19,11 @@ pub struct Column<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
     padding: Padding,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
     max_width: f32,
-    align: Alignment,
+    max_height: f32,
+    align: alignment::Horizontal,
     clip: bool,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
 }
@@ -63,8 +66,11 @@ where
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
+            min_width: 0.0,
+            min_height: 0.0,
             max_width: f32::INFINITY,
-            align: Alignment::Start,
+            max_height: f32::INFINITY,
+            align: alignment::Horizontal::Left,
             clip: false,
             children,
         }
@@ -98,15 +104,33 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Column`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Column`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = min_height.into().0;
+        self
+    }
+
     /// Sets the maximum width of the [`Column`].
     pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
         self.max_width = max_width.into().0;
         self
     }
 
+    /// Sets the maximum height of the [`Column`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max_height = max_height.into().0;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Column`] .
     pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
-        self.align = Alignment::from(align.into());
+        self.align = align.into();
         self
     }
 
@@ -201,7 +225,10 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits = limits.max_width(self.max_width);
+        let limits = limits.constrain(
+            Size::new(self.min_width, self.min_height),
+            Size::new(self.max_width, self.max_height),
+        );
 
         layout::flex::resolve(
             layout::flex::Axis::Vertical,
@@ -211,7 +238,7 @@ where
             self.height,
             self.padding,
             self.spacing,
-            self.align,
+            Alignment::from(self.align),
             &self.children,
             &mut tree.children,
         )
@@ -327,6 +354,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         overlay::from_children(
@@ -334,6 +362,7 @@ where
             tree,
             layout,
             renderer,
+            viewport,
             translation,
         )
     }
@@ -350,3 +379,114 @@ where
         Self::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layout::diagnostics::{self, Kind};
+    use crate::core::Theme;
+    use crate::{Row, Space};
+
+    fn size(column: Column<'static, (), Theme, ()>) -> Size {
+        let element = Element::from(column);
+        let mut tree = Tree::new(&element);
+
+        element
+            .as_widget()
+            .layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+            )
+            .size()
+    }
+
+    #[test]
+    fn fill_respects_the_maximum() {
+        assert_eq!(
+            size(
+                Column::with_children([Space::new(10, 10).into()])
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .max_width(200)
+                    .max_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn shrink_respects_the_minimum() {
+        assert_eq!(
+            size(
+                Column::with_children([Space::new(10, 10).into()])
+                    .min_width(200)
+                    .min_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    fn layout_twice(
+        element: Element<'static, (), Theme, ()>,
+        limits: layout::Limits,
+    ) {
+        let mut tree = Tree::new(&element);
+
+        for _ in 0..2 {
+            let _ = element.as_widget().layout(&mut tree, &(), &limits);
+        }
+    }
+
+    #[test]
+    fn fill_in_shrink_is_reported_once() {
+        layout_twice(
+            Column::with_children([Space::with_height(Length::Fill).into()])
+                .height(Length::Shrink)
+                .into(),
+            layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        let diagnostics = diagnostics::take();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "column[0]");
+        assert!(matches!(diagnostics[0].kind, Kind::FillInShrink { .. }));
+    }
+
+    #[test]
+    fn infinite_fill_is_reported_once() {
+        layout_twice(
+            Column::with_children([Space::new(10, 10).into()])
+                .height(Length::Fill)
+                .into(),
+            layout::Limits::new(Size::ZERO, Size::new(1000.0, f32::INFINITY)),
+        );
+
+        let diagnostics = diagnostics::take();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "root");
+        assert!(matches!(diagnostics[0].kind, Kind::InfiniteFill { .. }));
+    }
+
+    #[test]
+    fn collapsed_contents_are_reported_once() {
+        layout_twice(
+            Column::with_children([Row::with_children([
+                Space::new(100, 10).into(),
+                Column::with_children([Space::new(10, 10).into()]).into(),
+            ])
+            .width(50)
+            .into()])
+            .into(),
+            layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        let diagnostics = diagnostics::take();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "column[0] > row[1]");
+        assert!(matches!(diagnostics[0].kind, Kind::Collapsed { .. }));
+    }
+}
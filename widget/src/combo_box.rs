@@ -7,10 +7,13 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
-use crate::core::time::Instant;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
-    Clipboard, Element, Length, Padding, Rectangle, Shell, Size, Theme, Vector,
+    Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size, Theme,
+    Vector,
 };
 use crate::overlay::menu;
 use crate::text::LineHeight;
@@ -43,7 +46,10 @@ pub struct ComboBox<
     on_option_hovered: Option<Box<dyn Fn(T) -> Message>>,
     on_close: Option<Message>,
     on_input: Option<Box<dyn Fn(String) -> Message>>,
+    on_input_debounced: Option<(Duration, Box<dyn Fn(String) -> Message>)>,
+    is_loading: bool,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
+    render_mode: menu::RenderMode,
     padding: Padding,
     size: Option<f32>,
 }
@@ -77,8 +83,11 @@ where
             on_selected: Box::new(on_selected),
             on_option_hovered: None,
             on_input: None,
+            on_input_debounced: None,
+            is_loading: false,
             on_close: None,
             menu_class: <Theme as Catalog>::default_menu(),
+            render_mode: menu::RenderMode::default(),
             padding: text_input::DEFAULT_PADDING,
             size: None,
         }
@@ -94,6 +103,30 @@ where
         self
     }
 
+    /// Sets the message that should be produced once some text has been typed
+    /// into the [`TextInput`] of the [`ComboBox`] and the user pauses typing
+    /// for the given [`Duration`].
+    ///
+    /// Useful to load the options asynchronously as the user types; like
+    /// when searching a remote service. See [`State::set_options`].
+    pub fn on_input_debounced(
+        mut self,
+        delay: Duration,
+        on_input: impl Fn(String) -> Message + 'static,
+    ) -> Self {
+        self.on_input_debounced = Some((delay, Box::new(on_input)));
+        self
+    }
+
+    /// Sets whether the [`ComboBox`] is waiting for new options.
+    ///
+    /// While loading, the menu stays open and displays a small indicator
+    /// after the current options.
+    pub fn is_loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
     /// Sets the message that will be produced when an option of the
     /// [`ComboBox`] is hovered using the arrow keys.
     pub fn on_option_hovered(
@@ -111,6 +144,14 @@ where
         self
     }
 
+    /// Sets the [`menu::RenderMode`] of the [`ComboBox`].
+    ///
+    /// By default, the options are displayed as an overlay.
+    pub fn render_mode(mut self, render_mode: menu::RenderMode) -> Self {
+        self.render_mode = render_mode;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`ComboBox`].
     pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
         self.padding = padding.into();
@@ -204,6 +245,41 @@ where
         self.menu_class = class.into();
         self
     }
+
+    fn inline_menu<'b>(
+        &'b self,
+        options: &'b [T],
+    ) -> menu::Inline<'b, 'a, T, Theme, Renderer> {
+        menu::Inline {
+            options,
+            padding: self.padding,
+            text_size: self.size.map(Pixels),
+            text_line_height: LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: self.font,
+            is_loading: self.is_loading,
+            class: &self.menu_class,
+        }
+    }
+
+    /// Returns the [`Layout`] of the [`TextInput`] of the [`ComboBox`] and
+    /// the one of its inline menu, if any.
+    fn split<'b>(
+        &self,
+        layout: Layout<'b>,
+    ) -> (Layout<'b>, Option<Layout<'b>>) {
+        match self.render_mode {
+            menu::RenderMode::Overlay => (layout, None),
+            menu::RenderMode::Inline => {
+                let mut children = layout.children();
+
+                let input = children.next().unwrap_or(layout);
+                let menu = children.next();
+
+                (input, menu)
+            }
+        }
+    }
 }
 
 /// The local state of a [`ComboBox`].
@@ -216,12 +292,14 @@ struct Inner<T> {
     options: Vec<T>,
     option_matchers: Vec<String>,
     filtered_options: Filtered<T>,
+    filter_key: fn(&str) -> String,
 }
 
 #[derive(Debug, Clone)]
 struct Filtered<T> {
     options: Vec<T>,
     updated: Instant,
+    find: Option<fn(&[T], &T) -> Option<usize>>,
 }
 
 impl<T> State<T>
@@ -238,11 +316,13 @@ where
     pub fn with_selection(options: Vec<T>, selection: Option<&T>) -> Self {
         let value = selection.map(T::to_string).unwrap_or_default();
 
+        let filter_key: fn(&str) -> String = str::to_owned;
+
         // Pre-build "matcher" strings ahead of time so that search is fast
-        let option_matchers = build_matchers(&options);
+        let option_matchers = build_matchers(&options, filter_key);
 
         let filtered_options = Filtered::new(
-            search(&options, &option_matchers, &value)
+            search(&options, &option_matchers, &value, filter_key)
                 .cloned()
                 .collect(),
         );
@@ -252,9 +332,55 @@ where
             options,
             option_matchers,
             filtered_options,
+            filter_key,
         }))
     }
 
+    /// Sets the function that normalizes both the options and the typed
+    /// text of the [`ComboBox`] before matching them.
+    ///
+    /// This can be used to supply transliteration; for instance, folding
+    /// hiragana into katakana so either matches the other.
+    pub fn filter_key(self, filter_key: fn(&str) -> String) -> Self {
+        self.with_inner_mut(|state| {
+            state.filter_key = filter_key;
+            state.option_matchers = build_matchers(&state.options, filter_key);
+            state.filter(&state.value.clone());
+        });
+
+        self
+    }
+
+    /// Replaces the options of the [`State`]; like when they are loaded
+    /// asynchronously as the user types.
+    ///
+    /// The typed text is kept and the new options are filtered with it, so
+    /// an open [`ComboBox`] stays open. The option hovered using the arrow
+    /// keys stays hovered if it is still present in the new options.
+    pub fn set_options(&mut self, options: Vec<T>)
+    where
+        T: PartialEq,
+    {
+        self.with_inner_mut(|state| {
+            state.option_matchers = build_matchers(&options, state.filter_key);
+            state.options = options;
+
+            state.filtered_options.replace(
+                search(
+                    &state.options,
+                    &state.option_matchers,
+                    &state.value,
+                    state.filter_key,
+                )
+                .cloned()
+                .collect(),
+                |options, option| {
+                    options.iter().position(|candidate| candidate == option)
+                },
+            );
+        });
+    }
+
     fn value(&self) -> String {
         let inner = self.0.borrow();
 
@@ -273,10 +399,33 @@ where
         f(&mut inner);
     }
 
-    fn sync_filtered_options(&self, options: &mut Filtered<T>) {
+    fn sync_filtered_options(
+        &self,
+        options: &mut Filtered<T>,
+        hovered_option: &mut Option<usize>,
+    ) {
         let inner = self.0.borrow();
 
-        inner.filtered_options.sync(options);
+        inner.filtered_options.sync(options, hovered_option);
+    }
+}
+
+impl<T> Inner<T>
+where
+    T: Clone,
+{
+    /// Filters the options with the given query.
+    fn filter(&mut self, query: &str) {
+        self.filtered_options.update(
+            search(
+                &self.options,
+                &self.option_matchers,
+                query,
+                self.filter_key,
+            )
+            .cloned()
+            .collect(),
+        );
     }
 }
 
@@ -297,6 +446,7 @@ where
         Self {
             options,
             updated: Instant::now(),
+            find: None,
         }
     }
 
@@ -304,18 +454,50 @@ where
         Self {
             options: vec![],
             updated: Instant::now(),
+            find: None,
         }
     }
 
     fn update(&mut self, options: Vec<T>) {
         self.options = options;
         self.updated = Instant::now();
+        self.find = None;
+    }
+
+    /// Replaces the options, keeping track of how to find a previous option
+    /// among the new ones.
+    fn replace(
+        &mut self,
+        options: Vec<T>,
+        find: fn(&[T], &T) -> Option<usize>,
+    ) {
+        self.update(options);
+        self.find = Some(find);
     }
 
-    fn sync(&self, other: &mut Filtered<T>) {
-        if other.updated != self.updated {
-            *other = self.clone();
+    /// Syncs the given [`Filtered`] options and the option hovered in them.
+    ///
+    /// The hovered option is found again among the new options when they
+    /// were replaced, instead of updated by a new query.
+    fn sync(
+        &self,
+        other: &mut Filtered<T>,
+        hovered_option: &mut Option<usize>,
+    ) {
+        if other.updated == self.updated {
+            return;
         }
+
+        if let Some(find) = self.find {
+            if let Some(option) =
+                hovered_option.and_then(|index| other.options.get(index))
+            {
+                *hovered_option =
+                    Some(find(&self.options, option).unwrap_or(0));
+            }
+        }
+
+        *other = self.clone();
     }
 }
 
@@ -324,6 +506,8 @@ struct Menu<T> {
     hovered_option: Option<usize>,
     new_selection: Option<T>,
     filtered_options: Filtered<T>,
+    expansion: menu::Expansion,
+    pending_input: Option<(String, Instant)>,
 }
 
 #[derive(Debug, Clone)]
@@ -357,12 +541,39 @@ where
             text_input_state.is_focused()
         };
 
-        self.text_input.layout(
+        let input = self.text_input.layout(
             &mut tree.children[0],
             renderer,
             limits,
             (!is_focused).then_some(&self.selection),
-        )
+        );
+
+        match self.render_mode {
+            menu::RenderMode::Overlay => input,
+            menu::RenderMode::Inline => {
+                let menu = tree.state.downcast_mut::<Menu<T>>();
+
+                self.state.sync_filtered_options(
+                    &mut menu.filtered_options,
+                    &mut menu.hovered_option,
+                );
+
+                let size = input.size();
+                let height = self
+                    .inline_menu(&menu.filtered_options.options)
+                    .height(renderer)
+                    * menu.expansion.value();
+
+                layout::Node::with_children(
+                    Size::new(size.width, size.height + height),
+                    vec![
+                        input,
+                        layout::Node::new(Size::new(size.width, height))
+                            .move_to((0.0, size.height)),
+                    ],
+                )
+            }
+        }
     }
 
     fn tag(&self) -> widget::tree::Tag {
@@ -375,6 +586,8 @@ where
             filtered_options: Filtered::empty(),
             hovered_option: Some(0),
             new_selection: None,
+            expansion: menu::Expansion::default(),
+            pending_input: None,
         })
     }
 
@@ -393,6 +606,7 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let (layout, menu_layout) = self.split(layout);
         let menu = tree.state.downcast_mut::<Menu<T>>();
 
         let started_focused = {
@@ -406,21 +620,71 @@ where
         // since `Shell` does not expose such functionality.
         let mut published_message_to_shell = false;
 
+        let redraw_requested_at = match &event {
+            Event::Window(window::Event::RedrawRequested(now)) => Some(*now),
+            _ => None,
+        };
+
+        // With an inline menu, the options are part of the layout and must
+        // be handled before the input loses focus
+        let mut is_selecting = false;
+
+        if let Some(menu_layout) = menu_layout.filter(|_| started_focused) {
+            let option = self
+                .inline_menu(&menu.filtered_options.options)
+                .option_at(renderer, menu_layout.bounds(), cursor)
+                .and_then(|index| {
+                    Some((index, menu.filtered_options.options.get(index)?))
+                });
+
+            match (&event, option) {
+                (
+                    Event::Mouse(mouse::Event::CursorMoved { .. }),
+                    Some((index, option)),
+                ) => {
+                    if menu.hovered_option != Some(index) {
+                        if let Some(on_option_hovered) = &self.on_option_hovered
+                        {
+                            shell.publish(on_option_hovered(option.clone()));
+                            published_message_to_shell = true;
+                        }
+                    }
+
+                    menu.hovered_option = Some(index);
+                }
+                (
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    ))
+                    | Event::Touch(touch::Event::FingerPressed { .. }),
+                    Some((_, option)),
+                ) => {
+                    menu.new_selection = Some(option.clone());
+                    is_selecting = true;
+                }
+                _ => {}
+            }
+        }
+
         // Create a new list of local messages
         let mut local_messages = Vec::new();
         let mut local_shell = Shell::new(&mut local_messages);
 
         // Provide it to the widget
-        let mut event_status = self.text_input.on_event(
-            &mut tree.children[0],
-            event.clone(),
-            layout,
-            cursor,
-            renderer,
-            clipboard,
-            &mut local_shell,
-            viewport,
-        );
+        let mut event_status = if is_selecting {
+            event::Status::Captured
+        } else {
+            self.text_input.on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            )
+        };
 
         // Then finally react to them here
         for message in local_messages {
@@ -431,26 +695,55 @@ where
                 published_message_to_shell = true;
             }
 
+            if let Some((delay, _)) = &self.on_input_debounced {
+                let deadline = Instant::now() + *delay;
+
+                menu.pending_input = Some((new_value.clone(), deadline));
+                shell.request_redraw(window::RedrawRequest::At(deadline));
+            }
+
             // Couple the filtered options with the `ComboBox`
             // value and only recompute them when the value changes,
             // instead of doing it in every `view` call
             self.state.with_inner_mut(|state| {
                 menu.hovered_option = Some(0);
                 state.value = new_value;
+                state.filter(&state.value.clone());
+            });
+            shell.invalidate_layout();
+        }
 
-                state.filtered_options.update(
-                    search(
-                        &state.options,
-                        &state.option_matchers,
-                        &state.value,
-                    )
-                    .cloned()
-                    .collect(),
-                );
+        // Text being composed by an input method is provisional input; so
+        // the options are filtered with it until it is committed or cleared
+        if let Event::InputMethod(_) = &event {
+            let text_input_state = tree.children[0]
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>();
+
+            self.state.with_inner_mut(|state| {
+                let value = text_input::Value::new(&state.value);
+                let query = text_input_state.compose(&value).to_string();
+
+                menu.hovered_option = Some(0);
+                state.filter(&query);
             });
             shell.invalidate_layout();
         }
 
+        // Publish the typed text once the user pauses typing
+        if let Some((_, on_input)) = &self.on_input_debounced {
+            if let Some((_, deadline)) = &menu.pending_input {
+                if redraw_requested_at.is_some_and(|now| now >= *deadline) {
+                    if let Some((value, _)) = menu.pending_input.take() {
+                        shell.publish(on_input(value));
+                        published_message_to_shell = true;
+                    }
+                } else {
+                    shell.request_redraw(window::RedrawRequest::At(*deadline));
+                }
+            }
+        }
+
         let is_focused = {
             let text_input_state = tree.children[0]
                 .state
@@ -591,6 +884,7 @@ where
                 state.value = String::new();
                 state.filtered_options.update(state.options.clone());
                 menu.menu = menu::State::default();
+                menu.pending_input = None;
 
                 // Notify the selection
                 shell.publish((self.on_selected)(selection));
@@ -631,6 +925,25 @@ where
             shell.invalidate_widgets();
         }
 
+        if self.render_mode == menu::RenderMode::Inline {
+            let is_open = is_focused
+                && (self.is_loading
+                    || self.state.with_inner(|state| {
+                        !state.filtered_options.options.is_empty()
+                    }));
+
+            if menu.expansion.set_open(is_open) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            if let Some(now) = redraw_requested_at {
+                if menu.expansion.update(now) {
+                    shell.invalidate_layout();
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
         event_status
     }
 
@@ -642,6 +955,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        let (layout, menu_layout) = self.split(layout);
+
+        if menu_layout.is_some_and(|menu| cursor.is_over(menu.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
         self.text_input.mouse_interaction(
             &tree.children[0],
             layout,
@@ -675,6 +994,8 @@ where
             Some(&self.selection)
         };
 
+        let (layout, menu_layout) = self.split(layout);
+
         self.text_input.draw(
             &tree.children[0],
             renderer,
@@ -684,6 +1005,22 @@ where
             selection,
             viewport,
         );
+
+        if let Some(menu_layout) = menu_layout {
+            let bounds = menu_layout.bounds();
+
+            if bounds.height > 0.0 {
+                let menu = tree.state.downcast_ref::<Menu<T>>();
+
+                self.inline_menu(&menu.filtered_options.options).draw(
+                    renderer,
+                    theme,
+                    bounds,
+                    menu.hovered_option,
+                    viewport,
+                );
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -691,6 +1028,7 @@ where
         tree: &'b mut widget::Tree,
         layout: Layout<'_>,
         _renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let is_focused = {
@@ -701,7 +1039,7 @@ where
             text_input_state.is_focused()
         };
 
-        if is_focused {
+        if is_focused && self.render_mode == menu::RenderMode::Overlay {
             let Menu {
                 menu,
                 filtered_options,
@@ -709,9 +1047,10 @@ where
                 ..
             } = tree.state.downcast_mut::<Menu<T>>();
 
-            self.state.sync_filtered_options(filtered_options);
+            self.state
+                .sync_filtered_options(filtered_options, hovered_option);
 
-            if filtered_options.options.is_empty() {
+            if filtered_options.options.is_empty() && !self.is_loading {
                 None
             } else {
                 let bounds = layout.bounds();
@@ -733,7 +1072,8 @@ where
                     &self.menu_class,
                 )
                 .width(bounds.width)
-                .padding(self.padding);
+                .padding(self.padding)
+                .is_loading(self.is_loading);
 
                 if let Some(font) = self.font {
                     menu = menu.font(font);
@@ -788,14 +1128,15 @@ impl Catalog for Theme {}
 fn search<'a, T, A>(
     options: impl IntoIterator<Item = T> + 'a,
     option_matchers: impl IntoIterator<Item = &'a A> + 'a,
-    query: &'a str,
+    query: &str,
+    filter_key: fn(&str) -> String,
 ) -> impl Iterator<Item = T> + 'a
 where
     A: AsRef<str> + 'a,
 {
-    let query: Vec<String> = query
+    let query: Vec<String> = filter_key(query)
         .to_lowercase()
-        .split(|c: char| !c.is_ascii_alphanumeric())
+        .split(|c: char| !c.is_alphanumeric())
         .map(String::from)
         .collect();
 
@@ -814,6 +1155,7 @@ where
 
 fn build_matchers<'a, T>(
     options: impl IntoIterator<Item = T> + 'a,
+    filter_key: fn(&str) -> String,
 ) -> Vec<String>
 where
     T: Display + 'a,
@@ -821,9 +1163,204 @@ where
     options
         .into_iter()
         .map(|opt| {
-            let mut matcher = opt.to_string();
-            matcher.retain(|c| c.is_ascii_alphanumeric());
+            let mut matcher = filter_key(&opt.to_string());
+            matcher.retain(char::is_alphanumeric);
             matcher.to_lowercase()
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::input_method;
+
+    type ComboBox<'a> =
+        super::ComboBox<'a, &'static str, &'static str, Theme, ()>;
+
+    const OPTIONS: [&str; 3] = ["カタカナ", "ひらがな", "漢字"];
+
+    /// Folds hiragana into katakana.
+    fn katakana(text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                'ぁ'..='ゖ' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+                _ => c,
+            })
+            .collect()
+    }
+
+    fn filtered(state: &State<&'static str>) -> Vec<&'static str> {
+        state.with_inner(|state| state.filtered_options.options.clone())
+    }
+
+    const DELAY: Duration = Duration::from_millis(500);
+
+    /// Sends the given events to a focused [`ComboBox`], rebuilding it before
+    /// each one like an application would; returning the published messages.
+    fn send(
+        state: &State<&'static str>,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<&'static str> {
+        let combo_box = || {
+            Element::from(
+                ComboBox::new(state, "", None, |option| option)
+                    .on_input_debounced(DELAY, |_| "paused"),
+            )
+        };
+        let mut messages = Vec::new();
+
+        let mut tree = widget::Tree::new(&combo_box());
+
+        tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<()>>()
+            .focus();
+
+        for event in events {
+            let mut element = combo_box();
+            tree.diff(element.as_widget());
+
+            let node = element.as_widget().layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 400.0)),
+            );
+
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        messages
+    }
+
+    /// Sends the given input method events to a focused [`ComboBox`].
+    fn compose(
+        state: &State<&'static str>,
+        events: impl IntoIterator<Item = input_method::Event>,
+    ) {
+        let _ = send(state, events.into_iter().map(Event::InputMethod));
+    }
+
+    fn commit(text: &str) -> Event {
+        Event::InputMethod(input_method::Event::Commit(text.to_owned()))
+    }
+
+    fn redraw(now: Instant) -> Event {
+        Event::Window(window::Event::RedrawRequested(now))
+    }
+
+    fn preedit(text: &str) -> input_method::Event {
+        input_method::Event::Preedit(text.to_owned(), None)
+    }
+
+    #[test]
+    fn non_latin_options_are_matched() {
+        let state = State::new(OPTIONS.to_vec());
+
+        compose(&state, [input_method::Event::Commit("漢".to_owned())]);
+
+        assert_eq!(state.value(), "漢");
+        assert_eq!(filtered(&state), ["漢字"]);
+    }
+
+    #[test]
+    fn preedit_filters_provisionally() {
+        let state = State::new(OPTIONS.to_vec()).filter_key(katakana);
+
+        compose(&state, [input_method::Event::Opened, preedit("か")]);
+        assert_eq!(state.value(), "");
+        assert_eq!(filtered(&state), ["カタカナ"]);
+
+        compose(&state, [preedit("ひら")]);
+        assert_eq!(filtered(&state), ["ひらがな"]);
+    }
+
+    #[test]
+    fn committing_or_cancelling_leaves_the_filter_consistent() {
+        let state = State::new(OPTIONS.to_vec()).filter_key(katakana);
+
+        compose(
+            &state,
+            [
+                preedit("かた"),
+                input_method::Event::Commit("かた".to_owned()),
+            ],
+        );
+        assert_eq!(state.value(), "かた");
+        assert_eq!(filtered(&state), ["カタカナ"]);
+
+        let state = State::new(OPTIONS.to_vec()).filter_key(katakana);
+
+        compose(&state, [preedit("ひら"), preedit("")]);
+        assert_eq!(filtered(&state), OPTIONS);
+
+        compose(&state, [preedit("ひら"), input_method::Event::Closed]);
+        assert_eq!(state.value(), "");
+        assert_eq!(filtered(&state), OPTIONS);
+    }
+
+    #[test]
+    fn replacing_the_options_keeps_the_query() {
+        let mut state = State::new(vec!["Barcelona", "Bilbao"]);
+
+        compose(&state, [input_method::Event::Commit("ma".to_owned())]);
+        assert_eq!(filtered(&state), Vec::<&str>::new());
+
+        state.set_options(vec!["Madrid", "Málaga", "Valencia"]);
+
+        assert_eq!(state.value(), "ma");
+        assert_eq!(filtered(&state), ["Madrid"]);
+    }
+
+    #[test]
+    fn hovered_option_is_kept_when_the_options_are_replaced() {
+        let mut state = State::new(vec!["Barcelona", "Madrid", "Valencia"]);
+
+        let mut options = Filtered::empty();
+        let mut hovered_option = Some(0);
+
+        state.sync_filtered_options(&mut options, &mut hovered_option);
+        hovered_option = Some(1);
+
+        state.set_options(vec!["Bilbao", "Vigo", "Madrid", "Valencia"]);
+        state.sync_filtered_options(&mut options, &mut hovered_option);
+
+        assert_eq!(hovered_option, Some(2));
+        assert_eq!(options.options[2], "Madrid");
+
+        state.set_options(vec!["Sevilla"]);
+        state.sync_filtered_options(&mut options, &mut hovered_option);
+
+        assert_eq!(hovered_option, Some(0));
+    }
+
+    #[test]
+    fn debounced_input_waits_for_a_pause() {
+        let state = State::new(OPTIONS.to_vec());
+        let now = Instant::now();
+
+        let messages = send(&state, [commit("カ"), commit("タ"), redraw(now)]);
+        assert!(messages.is_empty());
+
+        let messages = send(
+            &state,
+            [
+                commit("カ"),
+                commit("タ"),
+                redraw(now),
+                redraw(now + DELAY * 2),
+            ],
+        );
+        assert_eq!(messages, ["paused"]);
+    }
+}
@@ -33,6 +33,8 @@ pub struct Container<
     padding: Padding,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
     max_width: f32,
     max_height: f32,
     horizontal_alignment: alignment::Horizontal,
@@ -59,6 +61,8 @@ where
             padding: Padding::ZERO,
             width: size.width.fluid(),
             height: size.height.fluid(),
+            min_width: 0.0,
+            min_height: 0.0,
             max_width: f32::INFINITY,
             max_height: f32::INFINITY,
             horizontal_alignment: alignment::Horizontal::Left,
@@ -93,6 +97,18 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Container`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Container`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = min_height.into().0;
+        self
+    }
+
     /// Sets the maximum width of the [`Container`].
     pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
         self.max_width = max_width.into().0;
@@ -231,8 +247,8 @@ where
             limits,
             self.width,
             self.height,
-            self.max_width,
-            self.max_height,
+            Size::new(self.min_width, self.min_height),
+            Size::new(self.max_width, self.max_height),
             self.padding,
             self.horizontal_alignment,
             self.vertical_alignment,
@@ -342,12 +358,14 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         self.content.as_widget_mut().overlay(
             tree,
             layout.children().next().unwrap(),
             renderer,
+            viewport,
             translation,
         )
     }
@@ -372,15 +390,15 @@ pub fn layout(
     limits: &layout::Limits,
     width: Length,
     height: Length,
-    max_width: f32,
-    max_height: f32,
+    min: Size,
+    max: Size,
     padding: Padding,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     layout_content: impl FnOnce(&layout::Limits) -> layout::Node,
 ) -> layout::Node {
     layout::positioned(
-        &limits.max_width(max_width).max_height(max_height),
+        &limits.constrain(min, max),
         width,
         height,
         padding,
@@ -436,6 +454,12 @@ impl Id {
     pub fn unique() -> Self {
         Self(widget::Id::unique())
     }
+
+    /// Returns the [`Id`] of a [`Container`] with this [`Id`] inside the scope
+    /// with the given [`Id`](widget::Id); see [`widget::Id::within`].
+    pub fn within(self, scope: impl Into<widget::Id>) -> Self {
+        Self(self.0.within(scope))
+    }
 }
 
 impl From<Id> for widget::Id {
@@ -444,6 +468,12 @@ impl From<Id> for widget::Id {
     }
 }
 
+impl From<widget::Id> for Id {
+    fn from(id: widget::Id) -> Self {
+        Self(id)
+    }
+}
+
 /// Produces a [`Task`] that queries the visible screen bounds of the
 /// [`Container`] with the given [`Id`].
 pub fn visible_bounds(id: Id) -> Task<Option<Rectangle>> {
@@ -665,3 +695,98 @@ pub fn dark(_theme: &Theme) -> Style {
         ..Style::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    fn size(container: Container<'static, (), Theme, ()>) -> Size {
+        let element = Element::from(container);
+        let mut tree = Tree::new(&element);
+
+        element
+            .as_widget()
+            .layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+            )
+            .size()
+    }
+
+    #[test]
+    fn fill_respects_the_maximum() {
+        assert_eq!(
+            size(
+                Container::new(Space::new(10, 10))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .max_width(200)
+                    .max_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn shrink_respects_the_minimum() {
+        assert_eq!(
+            size(
+                Container::new(Space::new(10, 10))
+                    .min_width(200)
+                    .min_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn minimum_wins_over_maximum() {
+        assert_eq!(
+            size(
+                Container::new(Space::new(10, 10))
+                    .min_width(300)
+                    .max_width(200)
+            ),
+            Size::new(300.0, 10.0)
+        );
+    }
+
+    fn content_x(container: Container<'static, (), Theme, ()>) -> f32 {
+        let element = Element::from(container);
+        let mut tree = Tree::new(&element);
+
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        node.children()[0].bounds().x
+    }
+
+    #[test]
+    fn logical_alignment_is_mirrored_right_to_left() {
+        let container = || {
+            Container::new(Space::new(10, 10))
+                .width(100)
+                .padding(Padding::ZERO.left(5).right(15))
+        };
+
+        assert_eq!(content_x(container().align_x(Alignment::Start)), 5.0);
+        assert_eq!(content_x(container().align_x(Alignment::End)), 75.0);
+
+        alignment::set_direction(alignment::Direction::RightToLeft);
+
+        let start = content_x(container().align_x(Alignment::Start));
+        let end = content_x(container().align_x(Alignment::End));
+        let left = content_x(container().align_x(alignment::Horizontal::Left));
+
+        alignment::set_direction(alignment::Direction::LeftToRight);
+
+        assert_eq!(start, 75.0);
+        assert_eq!(end, 5.0);
+        assert_eq!(left, 5.0);
+    }
+}
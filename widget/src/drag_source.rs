@@ -0,0 +1,461 @@
+//! Start dragging some data from a widget.
+use crate::core::drag;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::settings::Interaction;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use std::any::Any;
+
+/// A widget that starts dragging a payload when its content is dragged.
+///
+/// While dragging, a ghost of the content—or a custom drag image—follows
+/// the cursor. The payload can be dropped on any [`DropTarget`] accepting
+/// its type.
+///
+/// [`DropTarget`]: crate::DropTarget
+#[allow(missing_debug_implementations)]
+pub struct DragSource<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    drag_image: Option<Element<'a, Message, Theme, Renderer>>,
+    payload: drag::Payload,
+}
+
+impl<'a, Message, Theme, Renderer> DragSource<'a, Message, Theme, Renderer> {
+    /// Creates a [`DragSource`] with the given content and the payload it
+    /// drags.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        payload: impl Any + Clone,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            drag_image: None,
+            payload: drag::Payload::new(payload),
+        }
+    }
+
+    /// Sets the element that follows the cursor while dragging.
+    ///
+    /// By default, a ghost of the content of the [`DragSource`] is used.
+    pub fn drag_image(
+        mut self,
+        drag_image: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.drag_image = Some(drag_image.into());
+        self
+    }
+}
+
+/// The local state of a [`DragSource`].
+#[derive(Debug, Default)]
+enum State {
+    #[default]
+    Idle,
+    Pressed {
+        origin: Point,
+    },
+    Dragging {
+        payload: drag::Payload,
+        grab: Vector,
+    },
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragSource<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.drag_image)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(&self.drag_image)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // The drag may have been dropped or cancelled somewhere else
+        if let State::Dragging { payload, .. } = state {
+            if !shell.drag().is_some_and(|drag| drag.ptr_eq(payload)) {
+                *state = State::Idle;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(origin) = cursor.position_over(layout.bounds()) {
+                    if status == event::Status::Ignored {
+                        *state = State::Pressed { origin };
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => match state {
+                State::Pressed { origin } => {
+                    let Some(position) = cursor.position() else {
+                        return status;
+                    };
+
+                    if position.distance(*origin)
+                        > Interaction::current().drag_threshold
+                    {
+                        let payload = self.payload.clone();
+
+                        shell.start_drag(payload.clone());
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                        *state = State::Dragging {
+                            payload,
+                            grab: *origin - layout.position(),
+                        };
+
+                        return event::Status::Captured;
+                    }
+                }
+                State::Dragging { .. } => {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+                State::Idle => {}
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                *state = State::Idle;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                if let State::Dragging { .. } = state {
+                    *state = State::Idle;
+
+                    shell.end_drag();
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if let State::Dragging { .. } = tree.state.downcast_ref::<State>() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let State::Dragging { grab, .. } = *tree.state.downcast_ref::<State>()
+        else {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                viewport,
+                translation,
+            );
+        };
+
+        let (image, tree, anchor) = match &self.drag_image {
+            Some(drag_image) => (drag_image, &mut tree.children[1], None),
+            None => (&self.content, &mut tree.children[0], Some(grab)),
+        };
+
+        Some(
+            overlay::Element::new(Box::new(Ghost {
+                image,
+                tree,
+                size: layout.bounds().size(),
+                anchor,
+            }))
+            .top_most(),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DragSource<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        drag_source: DragSource<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(drag_source)
+    }
+}
+
+/// The image following the cursor during a drag.
+struct Ghost<'a, 'b, Message, Theme, Renderer> {
+    image: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+    /// The point of the image grabbed by the cursor; its center if `None`.
+    anchor: Option<Vector>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Ghost<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let max = match self.anchor {
+            Some(_) => self.size,
+            None => bounds,
+        };
+
+        self.image.as_widget().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, max),
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+
+        let anchor = self
+            .anchor
+            .unwrap_or(Vector::new(bounds.width / 2.0, bounds.height / 2.0));
+
+        renderer.with_translation(
+            position - bounds.position() - anchor,
+            |renderer| {
+                self.image.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    &bounds,
+                );
+            },
+        );
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        // The drop targets below the ghost must keep receiving the cursor
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Space, Theme};
+
+    /// Presses a 100x100 [`DragSource`] and moves the cursor the given
+    /// distance; returning whether a drag started.
+    fn drag(distance: f32) -> bool {
+        let mut source: Element<'static, (), Theme, ()> =
+            DragSource::new(Space::new(100, 100), 42_u32).into();
+
+        let mut tree = Tree::new(&source);
+
+        let node = source.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        let origin = Point::new(50.0, 50.0);
+        let moved = Point::new(origin.x + distance, origin.y);
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for (cursor, event) in [
+            (
+                origin,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ),
+            (
+                moved,
+                Event::Mouse(mouse::Event::CursorMoved { position: moved }),
+            ),
+        ] {
+            let _ = source.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(cursor),
+                &(),
+                &mut crate::core::clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        shell.drag().is_some()
+    }
+
+    #[test]
+    fn drags_start_past_the_drag_threshold() {
+        assert!(!drag(4.0));
+        assert!(drag(8.0));
+
+        Interaction {
+            drag_threshold: 20.0,
+            ..Interaction::default()
+        }
+        .apply();
+
+        assert!(!drag(8.0));
+        assert!(drag(25.0));
+
+        Interaction::default().apply();
+    }
+}
@@ -0,0 +1,278 @@
+//! Receive the data dragged from a [`DragSource`].
+//!
+//! [`DragSource`]: crate::DragSource
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::any::Any;
+
+/// A widget that produces a message when a payload of type `T` is dropped
+/// on its content.
+///
+/// Payloads of any other type are ignored.
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    DropTarget<'a, T, Message, Theme, Renderer>
+where
+    T: Any + Clone,
+{
+    /// Creates a [`DropTarget`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            on_enter: None,
+            on_exit: None,
+        }
+    }
+
+    /// Sets the message that will be produced when a payload is dropped on
+    /// the [`DropTarget`].
+    pub fn on_drop(mut self, on_drop: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message that will be produced when a payload is dragged
+    /// into the [`DropTarget`].
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when a payload is dragged
+    /// out of the [`DropTarget`], dropped on it, or cancelled while over it.
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`DropTarget`].
+#[derive(Debug, Default)]
+struct State {
+    is_hovered: bool,
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'a, T, Message, Theme, Renderer>
+where
+    T: Any + Clone,
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let value = shell
+            .drag()
+            .and_then(|payload| payload.downcast_ref::<T>())
+            .cloned()
+            .filter(|_| cursor.is_over(layout.bounds()));
+
+        let is_dropped = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+        );
+
+        let is_cancelled = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            })
+        );
+
+        if is_dropped {
+            if let Some(value) = &value {
+                if let Some(on_drop) = &self.on_drop {
+                    shell.publish(on_drop(value.clone()));
+                }
+
+                shell.end_drag();
+                status = event::Status::Captured;
+            }
+        }
+
+        let is_hovered = value.is_some() && !is_dropped && !is_cancelled;
+
+        if is_hovered != state.is_hovered {
+            state.is_hovered = is_hovered;
+
+            let message = if is_hovered {
+                self.on_enter.clone()
+            } else {
+                self.on_exit.clone()
+            };
+
+            if let Some(message) = message {
+                shell.publish(message);
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<DropTarget<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Any + Clone + 'a,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        drop_target: DropTarget<'a, T, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(drop_target)
+    }
+}
@@ -0,0 +1,510 @@
+//! Describe how animations progress over time.
+//!
+//! The predefined curves follow the easing tokens of Material Design 3.
+use crate::core::settings::Motion;
+use crate::core::time::{Duration, Instant};
+use crate::core::Point;
+
+use std::borrow::Cow;
+use std::f32::consts::PI;
+
+/// The duration of a full pulse of [`pulse`].
+const PULSE_DURATION: Duration = Duration::from_secs(2);
+
+/// The lowest opacity reached by [`pulse`].
+const PULSE_MIN_OPACITY: f32 = 0.4;
+
+/// The opacity of an indeterminate indicator that does not move at all.
+pub(crate) const STILL_OPACITY: f32 = 0.7;
+
+/// An emphasized [`Easing`]; for animations that should draw attention.
+pub const EMPHASIZED: Easing = Easing::from_static(&[
+    Curve::new(
+        Point::ORIGIN,
+        Point::new(0.05, 0.0),
+        Point::new(0.133333, 0.06),
+        Point::new(0.166666, 0.4),
+    ),
+    Curve::new(
+        Point::new(0.166666, 0.4),
+        Point::new(0.208333, 0.82),
+        Point::new(0.25, 1.0),
+        Point::new(1.0, 1.0),
+    ),
+]);
+
+/// An emphasized [`Easing`] that decelerates; for entering animations.
+pub const EMPHASIZED_DECELERATE: Easing = Easing::from_static(&[Curve::new(
+    Point::ORIGIN,
+    Point::new(0.05, 0.7),
+    Point::new(0.1, 1.0),
+    Point::new(1.0, 1.0),
+)]);
+
+/// An emphasized [`Easing`] that accelerates; for exiting animations.
+pub const EMPHASIZED_ACCELERATE: Easing = Easing::from_static(&[Curve::new(
+    Point::ORIGIN,
+    Point::new(0.3, 0.0),
+    Point::new(0.8, 0.15),
+    Point::new(1.0, 1.0),
+)]);
+
+/// The standard [`Easing`]; for most animations.
+pub const STANDARD: Easing = Easing::from_static(&[Curve::new(
+    Point::ORIGIN,
+    Point::new(0.2, 0.0),
+    Point::new(0.0, 1.0),
+    Point::new(1.0, 1.0),
+)]);
+
+/// A standard [`Easing`] that decelerates.
+pub const STANDARD_DECELERATE: Easing = Easing::from_static(&[Curve::new(
+    Point::ORIGIN,
+    Point::new(0.0, 0.0),
+    Point::new(0.0, 1.0),
+    Point::new(1.0, 1.0),
+)]);
+
+/// A standard [`Easing`] that accelerates.
+pub const STANDARD_ACCELERATE: Easing = Easing::from_static(&[Curve::new(
+    Point::ORIGIN,
+    Point::new(0.3, 0.0),
+    Point::new(1.0, 1.0),
+    Point::new(1.0, 1.0),
+)]);
+
+/// A linear [`Easing`]; the animation progresses at a constant rate.
+pub const LINEAR: Easing = Easing::from_static(&[Curve::new(
+    Point::ORIGIN,
+    Point::new(1.0 / 3.0, 1.0 / 3.0),
+    Point::new(2.0 / 3.0, 2.0 / 3.0),
+    Point::new(1.0, 1.0),
+)]);
+
+/// A curve mapping the progress of an animation to its output, both
+/// between `0.0` and `1.0`.
+///
+/// An [`Easing`] is made of consecutive cubic Bézier curves from `(0, 0)` to
+/// `(1, 1)`. Use a [`Builder`] to create your own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Easing {
+    curves: Cow<'static, [Curve]>,
+}
+
+impl Easing {
+    const fn from_static(curves: &'static [Curve]) -> Self {
+        Self {
+            curves: Cow::Borrowed(curves),
+        }
+    }
+
+    /// Creates a new [`Builder`] for an [`Easing`].
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    /// Creates an [`Easing`] from a single cubic Bézier curve with the given
+    /// control points; just like the `cubic-bezier` function of CSS.
+    ///
+    /// The control points are clamped between `(0, 0)` and `(1, 1)`.
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Builder::new()
+            .cubic_bezier_to([x1, y1], [x2, y2], [1.0, 1.0])
+            .build()
+    }
+
+    /// Creates an [`Easing`] that moves linearly between the given keyframes,
+    /// as `(x, y)` pairs, starting at `(0, 0)` and ending at `(1, 1)`.
+    ///
+    /// Keyframes are clamped between `(0, 0)` and `(1, 1)`, and any keyframe
+    /// moving backwards on the `x` axis is ignored.
+    pub fn keyframes(keyframes: &[(f32, f32)]) -> Self {
+        keyframes
+            .iter()
+            .fold(Builder::new(), |builder, &(x, y)| {
+                if x < builder.position.x {
+                    builder
+                } else {
+                    builder.line_to([x, y])
+                }
+            })
+            .build()
+    }
+
+    /// Returns the output of the [`Easing`] at the given progress.
+    ///
+    /// The progress is clamped between `0.0` and `1.0`.
+    pub fn y_at_x(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+
+        self.curves
+            .iter()
+            .find(|curve| x <= curve.to.x)
+            .or(self.curves.last())
+            .map_or(x, |curve| curve.y_at_x(x))
+    }
+}
+
+/// A builder of a custom [`Easing`].
+///
+/// Every point must be between `(0, 0)` and `(1, 1)`, and the curves must
+/// keep moving forward on the `x` axis.
+#[derive(Debug, Clone)]
+pub struct Builder {
+    curves: Vec<Curve>,
+    position: Point,
+}
+
+impl Builder {
+    /// Creates a new [`Builder`] starting at `(0, 0)`.
+    pub fn new() -> Self {
+        Self {
+            curves: Vec::new(),
+            position: Point::ORIGIN,
+        }
+    }
+
+    /// Adds a line segment.
+    pub fn line_to(self, to: impl Into<Point>) -> Self {
+        let from = self.position;
+        let to = clamp(to.into());
+
+        self.push(Curve::new(
+            from,
+            from + (to - from) * (1.0 / 3.0),
+            from + (to - from) * (2.0 / 3.0),
+            to,
+        ))
+    }
+
+    /// Adds a quadratic Bézier curve.
+    pub fn quadratic_bezier_to(
+        self,
+        ctrl: impl Into<Point>,
+        to: impl Into<Point>,
+    ) -> Self {
+        let from = self.position;
+        let ctrl = clamp(ctrl.into());
+        let to = clamp(to.into());
+
+        self.push(Curve::new(
+            from,
+            from + (ctrl - from) * (2.0 / 3.0),
+            to + (ctrl - to) * (2.0 / 3.0),
+            to,
+        ))
+    }
+
+    /// Adds a cubic Bézier curve.
+    pub fn cubic_bezier_to(
+        self,
+        ctrl1: impl Into<Point>,
+        ctrl2: impl Into<Point>,
+        to: impl Into<Point>,
+    ) -> Self {
+        let from = self.position;
+
+        self.push(Curve::new(
+            from,
+            clamp(ctrl1.into()),
+            clamp(ctrl2.into()),
+            clamp(to.into()),
+        ))
+    }
+
+    /// Builds the [`Easing`], ending it at `(1, 1)`.
+    pub fn build(self) -> Easing {
+        let end = Point::new(1.0, 1.0);
+
+        let builder = if self.position == end {
+            self
+        } else {
+            self.line_to(end)
+        };
+
+        Easing {
+            curves: Cow::Owned(builder.curves),
+        }
+    }
+
+    fn push(mut self, curve: Curve) -> Self {
+        self.position = curve.to;
+        self.curves.push(curve);
+        self
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A cubic Bézier curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Curve {
+    from: Point,
+    ctrl1: Point,
+    ctrl2: Point,
+    to: Point,
+}
+
+impl Curve {
+    const fn new(from: Point, ctrl1: Point, ctrl2: Point, to: Point) -> Self {
+        Self {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        }
+    }
+
+    fn y_at_x(&self, x: f32) -> f32 {
+        if self.to.x - self.from.x <= f32::EPSILON {
+            return self.to.y;
+        }
+
+        // The curve moves forward on the x axis, so `t` can be bisected.
+        let (mut low, mut high) = (0.0, 1.0);
+
+        for _ in 0..24 {
+            let t = (low + high) / 2.0;
+
+            if bezier(self.from.x, self.ctrl1.x, self.ctrl2.x, self.to.x, t) < x
+            {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+
+        bezier(
+            self.from.y,
+            self.ctrl1.y,
+            self.ctrl2.y,
+            self.to.y,
+            (low + high) / 2.0,
+        )
+    }
+}
+
+/// A value easing towards a target over time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Transition {
+    from: f32,
+    to: f32,
+    start: Instant,
+    current: f32,
+}
+
+impl Transition {
+    /// Creates a [`Transition`] resting at the given value.
+    pub(crate) fn new(value: f32) -> Self {
+        Self {
+            from: value,
+            to: value,
+            start: Instant::now(),
+            current: value,
+        }
+    }
+
+    /// Returns the current value of the [`Transition`].
+    pub(crate) fn value(&self) -> f32 {
+        self.current
+    }
+
+    /// Starts easing towards the given target from the current value.
+    ///
+    /// The value jumps to the target right away if the duration is zero or
+    /// the current [`Motion`] is [`Motion::None`].
+    pub(crate) fn go_to(&mut self, to: f32, duration: Duration) {
+        if duration.is_zero() || Motion::current() == Motion::None {
+            *self = Self::new(to);
+        } else if to != self.to {
+            *self = Self {
+                from: self.current,
+                to,
+                start: Instant::now(),
+                current: self.current,
+            };
+        }
+    }
+
+    /// Advances the [`Transition`] and returns whether it is still running.
+    pub(crate) fn update(
+        &mut self,
+        easing: &Easing,
+        duration: Duration,
+        now: Instant,
+    ) -> bool {
+        let elapsed = now.saturating_duration_since(self.start);
+
+        if duration.is_zero()
+            || elapsed >= duration
+            || Motion::current() == Motion::None
+        {
+            self.current = self.to;
+
+            return false;
+        }
+
+        let progress = elapsed.as_secs_f32() / duration.as_secs_f32();
+
+        self.current =
+            self.from + (self.to - self.from) * easing.y_at_x(progress);
+
+        true
+    }
+}
+
+/// Returns the opacity of an indeterminate indicator that pulses, instead of
+/// moving, since the given start.
+///
+/// The opacity goes smoothly from `1.0` down to a minimum and back.
+pub(crate) fn pulse(start: Instant, now: Instant) -> f32 {
+    let phase = now.saturating_duration_since(start).as_secs_f32()
+        / PULSE_DURATION.as_secs_f32();
+
+    let wave = (1.0 + (2.0 * PI * phase).cos()) / 2.0;
+
+    PULSE_MIN_OPACITY + (1.0 - PULSE_MIN_OPACITY) * wave
+}
+
+fn bezier(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+
+    u * u * u * p0
+        + 3.0 * u * u * t * p1
+        + 3.0 * u * t * t * p2
+        + t * t * t * p3
+}
+
+fn clamp(point: Point) -> Point {
+    Point::new(point.x.clamp(0.0, 1.0), point.y.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [&Easing; 7] = [
+        &EMPHASIZED,
+        &EMPHASIZED_DECELERATE,
+        &EMPHASIZED_ACCELERATE,
+        &STANDARD,
+        &STANDARD_DECELERATE,
+        &STANDARD_ACCELERATE,
+        &LINEAR,
+    ];
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for easing in ALL {
+            assert!(easing.y_at_x(0.0).abs() < 1e-3);
+            assert!((easing.y_at_x(1.0) - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn easings_never_go_backwards() {
+        for easing in ALL {
+            let mut last = 0.0;
+
+            for step in 0..=100 {
+                let y = easing.y_at_x(step as f32 / 100.0);
+
+                assert!(y >= last - 1e-3);
+                last = y;
+            }
+        }
+    }
+
+    #[test]
+    fn linear_is_the_identity() {
+        for step in 0..=10 {
+            let x = step as f32 / 10.0;
+
+            assert!((LINEAR.y_at_x(x) - x).abs() < 1e-3);
+        }
+
+        let built = Easing::builder().build();
+
+        assert!((built.y_at_x(0.25) - 0.25).abs() < 1e-3);
+    }
+
+    #[test]
+    fn cubic_bezier_matches_css() {
+        let samples = [
+            ((0.25, 0.1, 0.25, 1.0), [0.4085, 0.8024, 0.9605]),
+            ((0.42, 0.0, 1.0, 1.0), [0.0935, 0.3154, 0.6219]),
+            ((0.0, 0.0, 0.58, 1.0), [0.3781, 0.6846, 0.9065]),
+            ((0.42, 0.0, 0.58, 1.0), [0.1292, 0.5, 0.8708]),
+        ];
+
+        for ((x1, y1, x2, y2), expected) in samples {
+            let easing = Easing::cubic_bezier(x1, y1, x2, y2);
+
+            for (x, y) in [0.25, 0.5, 0.75].into_iter().zip(expected) {
+                assert!((easing.y_at_x(x) - y).abs() < 1e-3);
+            }
+
+            assert_eq!(easing.y_at_x(-1.0), easing.y_at_x(0.0));
+            assert_eq!(easing.y_at_x(2.0), easing.y_at_x(1.0));
+        }
+    }
+
+    #[test]
+    fn keyframes_interpolate_linearly() {
+        let easing = Easing::keyframes(&[(0.5, 0.8), (0.25, 0.0), (0.75, 0.9)]);
+
+        assert!((easing.y_at_x(0.25) - 0.4).abs() < 1e-3);
+        assert!((easing.y_at_x(0.5) - 0.8).abs() < 1e-3);
+        assert!((easing.y_at_x(0.625) - 0.85).abs() < 1e-3);
+        assert!((easing.y_at_x(0.875) - 0.95).abs() < 1e-3);
+        assert!(easing.y_at_x(1.5) - 1.0 < 1e-3);
+    }
+
+    #[test]
+    fn transitions_ease_towards_their_target() {
+        let duration = Duration::from_secs(1);
+        let mut transition = Transition::new(0.0);
+
+        transition.go_to(1.0, duration);
+
+        let start = transition.start;
+
+        assert!(transition.update(&LINEAR, duration, start + duration / 4));
+        assert!((transition.value() - 0.25).abs() < 1e-3);
+
+        assert!(!transition.update(&LINEAR, duration, start + duration));
+        assert_eq!(transition.value(), 1.0);
+
+        transition.go_to(0.0, Duration::ZERO);
+        assert_eq!(transition.value(), 0.0);
+    }
+
+    #[test]
+    fn transitions_jump_without_motion() {
+        let duration = Duration::from_secs(1);
+        let mut transition = Transition::new(0.0);
+
+        Motion::None.apply();
+        transition.go_to(1.0, duration);
+        Motion::Full.apply();
+
+        assert_eq!(transition.value(), 1.0);
+    }
+
+    #[test]
+    fn pulses_fade_out_and_back_in() {
+        let start = Instant::now();
+
+        assert!((pulse(start, start) - 1.0).abs() < 1e-3);
+        assert!(
+            (pulse(start, start + PULSE_DURATION / 2) - PULSE_MIN_OPACITY)
+                .abs()
+                < 1e-3
+        );
+        assert!((pulse(start, start + PULSE_DURATION) - 1.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,478 @@
+//! Coordinate the validation of multiple inputs in a form.
+//!
+//! A form [`State`] tracks named fields, each with its own value and
+//! [`Validator`]s. The helpers of this module build inputs bound to these
+//! fields, which produce the form [`Event`]s the [`State`] needs:
+//!
+//! ```no_run
+//! # type Element<'a, Message> = iced_widget::core::Element<
+//! #     'a,
+//! #     Message,
+//! #     iced_widget::Theme,
+//! #     iced_widget::Renderer,
+//! # >;
+//! use iced_widget::{button, column, form};
+//!
+//! struct Signup {
+//!     form: form::State,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Form(form::Event),
+//!     Submit,
+//! }
+//!
+//! impl Signup {
+//!     fn new() -> Self {
+//!         Self {
+//!             form: form::State::new().field(
+//!                 "name",
+//!                 form::Field::new()
+//!                     .validator(form::required("Please, tell us your name.")),
+//!             ),
+//!         }
+//!     }
+//!
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Form(event) => self.form.update(event),
+//!             Message::Submit => {
+//!                 if self.form.submit() {
+//!                     // Sign up!
+//!                 }
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         column![
+//!             form::field(
+//!                 &self.form,
+//!                 "name",
+//!                 "Name",
+//!                 form::text_input(&self.form, "name", "Ferris", Message::Form),
+//!             ),
+//!             button("Submit")
+//!                 .on_press_maybe(self.form.is_valid().then_some(Message::Submit)),
+//!         ]
+//!         .into()
+//!     }
+//! }
+//! ```
+use crate::core::text;
+use crate::core::Element;
+use crate::pick_list::PickList;
+use crate::text_input::{InputMode, TextInput};
+use crate::{Column, Text, Theme};
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The state of a form: a set of named fields with their values and
+/// validation.
+#[derive(Debug, Default)]
+pub struct State {
+    fields: Vec<(&'static str, Field)>,
+}
+
+impl State {
+    /// Creates a new empty [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [`Field`] with the given name to the [`State`].
+    pub fn field(mut self, name: &'static str, field: Field) -> Self {
+        self.fields.push((name, field));
+        self
+    }
+
+    /// Processes a form [`Event`], validating the field it belongs to if its
+    /// [`Trigger`] says so.
+    pub fn update(&mut self, event: Event) {
+        match event {
+            Event::Changed(name, value) => {
+                let Some(field) = self.get_mut(name) else {
+                    return;
+                };
+
+                field.value = value;
+
+                if field.validate_on == Trigger::Change {
+                    field.is_touched = true;
+                    field.validate();
+                }
+            }
+            Event::Blurred(name) => {
+                let Some(field) = self.get_mut(name) else {
+                    return;
+                };
+
+                field.is_touched = true;
+                field.validate();
+            }
+        }
+    }
+
+    /// Returns the current value of the field with the given name.
+    ///
+    /// Unknown fields have an empty value.
+    pub fn value(&self, name: &str) -> &str {
+        self.get(name).map_or("", |field| &field.value)
+    }
+
+    /// Sets the value of the field with the given name, without touching it.
+    pub fn set_value(&mut self, name: &str, value: impl Into<String>) {
+        if let Some(field) = self.get_mut(name) {
+            field.value = value.into();
+
+            if field.is_touched {
+                field.validate();
+            }
+        }
+    }
+
+    /// Returns the error of the field with the given name, if it has been
+    /// touched and is invalid.
+    ///
+    /// A field is touched once it loses focus, once it changes if it is
+    /// validated on [`Trigger::Change`], or once the form is submitted.
+    pub fn error(&self, name: &str) -> Option<&str> {
+        self.get(name)
+            .filter(|field| field.is_touched)
+            .and_then(|field| field.error.as_deref())
+    }
+
+    /// Returns true if the value of every field passes all of its
+    /// validators; whether the fields have been touched or not.
+    ///
+    /// It can be used to gate the submit button of the form.
+    pub fn is_valid(&self) -> bool {
+        self.fields
+            .iter()
+            .all(|(_, field)| field.check(&field.value).is_none())
+    }
+
+    /// Touches and validates every field, returning true if the form is
+    /// valid.
+    pub fn submit(&mut self) -> bool {
+        for (_, field) in &mut self.fields {
+            field.is_touched = true;
+            field.validate();
+        }
+
+        self.is_valid()
+    }
+
+    /// Clears the values and errors of every field.
+    pub fn reset(&mut self) {
+        for (_, field) in &mut self.fields {
+            field.value.clear();
+            field.is_touched = false;
+            field.error = None;
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find_map(|(field_name, field)| {
+            (*field_name == name).then_some(field)
+        })
+    }
+
+    fn get_mut(&mut self, name: &str) -> Option<&mut Field> {
+        self.fields.iter_mut().find_map(|(field_name, field)| {
+            (*field_name == name).then_some(field)
+        })
+    }
+}
+
+/// A field of a form [`State`].
+#[derive(Debug, Default)]
+pub struct Field {
+    value: String,
+    validators: Vec<Validator>,
+    validate_on: Trigger,
+    is_touched: bool,
+    error: Option<String>,
+}
+
+impl Field {
+    /// Creates a new empty [`Field`] without validators.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the initial value of the [`Field`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Adds a [`Validator`] to the [`Field`].
+    ///
+    /// Validators run in the order they were added; only the error of the
+    /// first failing one is shown.
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Sets the [`Trigger`] of the validation of the [`Field`].
+    ///
+    /// By default, fields are validated when they lose focus.
+    pub fn validate_on(mut self, trigger: Trigger) -> Self {
+        self.validate_on = trigger;
+        self
+    }
+
+    fn validate(&mut self) {
+        self.error = self.check(&self.value);
+    }
+
+    fn check(&self, value: &str) -> Option<String> {
+        self.validators
+            .iter()
+            .find_map(|validator| (validator.0)(value).err())
+    }
+}
+
+/// When the validation of a [`Field`] runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Trigger {
+    /// The field is validated every time its value changes.
+    Change,
+    /// The field is validated when it loses focus.
+    #[default]
+    Blur,
+}
+
+/// An event of a form field, produced by the inputs of this module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The value of the field with the given name changed.
+    Changed(&'static str, String),
+    /// The field with the given name lost focus.
+    Blurred(&'static str),
+}
+
+/// A rule the value of a [`Field`] must follow.
+pub struct Validator(Box<dyn Fn(&str) -> Result<(), String>>);
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validator(..)")
+    }
+}
+
+/// Creates a [`Validator`] that fails with the given message when the value
+/// is empty or only whitespace.
+pub fn required(message: impl Into<String>) -> Validator {
+    let message = message.into();
+
+    custom(move |value| {
+        if value.trim().is_empty() {
+            Err(message.clone())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Creates a [`Validator`] that fails with the given message when the value
+/// is not a number within the given range.
+///
+/// Empty values pass; combine it with [`required`] to reject them.
+pub fn range(
+    range: RangeInclusive<f64>,
+    message: impl Into<String>,
+) -> Validator {
+    let message = message.into();
+
+    custom(move |value| {
+        let value = value.trim();
+
+        if value.is_empty()
+            || value
+                .parse::<f64>()
+                .is_ok_and(|number| range.contains(&number))
+        {
+            Ok(())
+        } else {
+            Err(message.clone())
+        }
+    })
+}
+
+/// Creates a [`Validator`] that fails with the given message when the value
+/// does not match the given regular expression.
+///
+/// Empty values pass; combine it with [`required`] to reject them.
+///
+/// # Panics
+/// The pattern must be a valid regular expression.
+#[cfg(feature = "regex")]
+pub fn regex(pattern: &str, message: impl Into<String>) -> Validator {
+    let regex = regex::Regex::new(pattern).expect("Valid regular expression");
+    let message = message.into();
+
+    custom(move |value| {
+        if value.is_empty() || regex.is_match(value) {
+            Ok(())
+        } else {
+            Err(message.clone())
+        }
+    })
+}
+
+/// Creates a [`Validator`] from a closure, which returns the error message
+/// when the value is invalid.
+pub fn custom(
+    validate: impl Fn(&str) -> Result<(), String> + 'static,
+) -> Validator {
+    Validator(Box::new(validate))
+}
+
+/// Creates a [`TextInput`] bound to the field of the form [`State`] with the
+/// given name.
+///
+/// It is styled as invalid when the field has an error.
+pub fn text_input<'a, Message, Renderer>(
+    state: &'a State,
+    name: &'static str,
+    placeholder: &str,
+    on_event: impl Fn(Event) -> Message + 'a,
+) -> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    let on_blur = on_event(Event::Blurred(name));
+
+    TextInput::new(placeholder, state.value(name))
+        .on_input(move |value| on_event(Event::Changed(name, value)))
+        .on_blur(on_blur)
+        .invalid(state.error(name).is_some())
+}
+
+/// Creates a [`TextInput`] for numbers bound to the field of the form
+/// [`State`] with the given name.
+///
+/// It only accepts the characters of [`InputMode::Decimal`].
+pub fn number_input<'a, Message, Renderer>(
+    state: &'a State,
+    name: &'static str,
+    placeholder: &str,
+    on_event: impl Fn(Event) -> Message + 'a,
+) -> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    text_input(state, name, placeholder, on_event)
+        .input_mode(InputMode::Decimal)
+}
+
+/// Creates a [`PickList`] bound to the field of the form [`State`] with the
+/// given name.
+///
+/// The value of the field is the string representation of the selected
+/// option. Closing the menu of the [`PickList`] counts as losing focus.
+pub fn pick_list<'a, T, L, Message, Renderer>(
+    state: &'a State,
+    name: &'static str,
+    options: L,
+    on_event: impl Fn(Event) -> Message + 'a,
+) -> PickList<'a, T, L, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    let value = state.value(name);
+
+    let selected = options
+        .borrow()
+        .iter()
+        .find(|option| option.to_string() == value)
+        .cloned();
+
+    let is_invalid = state.error(name).is_some();
+    let on_blur = on_event(Event::Blurred(name));
+
+    PickList::new(options, selected, move |option: T| {
+        on_event(Event::Changed(name, option.to_string()))
+    })
+    .on_close(on_blur)
+    .invalid(is_invalid)
+}
+
+/// Displays an input of the field of the form [`State`] with the given name,
+/// labeled and followed by its error, if any.
+pub fn field<'a, Message, Renderer>(
+    state: &'a State,
+    name: &'static str,
+    label: impl text::IntoFragment<'a>,
+    input: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    Column::new()
+        .push(Text::new(label).size(14))
+        .push(input)
+        .push_maybe(
+            state.error(name).map(|error| {
+                Text::new(error).size(14).style(crate::text::danger)
+            }),
+        )
+        .spacing(5)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> State {
+        State::new()
+            .field("name", Field::new().validator(required("Name is required")))
+            .field(
+                "age",
+                Field::new()
+                    .validator(range(13.0..=120.0, "Invalid age"))
+                    .validate_on(Trigger::Change),
+            )
+    }
+
+    #[test]
+    fn errors_show_once_touched() {
+        let mut state = state();
+
+        state.update(Event::Changed("name", String::new()));
+        assert_eq!(state.error("name"), None);
+
+        state.update(Event::Blurred("name"));
+        assert_eq!(state.error("name"), Some("Name is required"));
+
+        state.update(Event::Changed("age", String::from("7")));
+        assert_eq!(state.error("age"), Some("Invalid age"));
+    }
+
+    #[test]
+    fn is_valid_ignores_touched_fields() {
+        let mut state = state();
+
+        assert!(!state.is_valid());
+
+        state.update(Event::Changed("name", String::from("Ferris")));
+        state.update(Event::Changed("age", String::from("42")));
+
+        assert!(state.is_valid());
+        assert!(state.submit());
+        assert_eq!(state.error("name"), None);
+    }
+}
@@ -0,0 +1,834 @@
+//! Lay out widgets in aligned rows and columns.
+//!
+//! A [`Grid`] places each of its children in a [`Cell`], at some row and
+//! column; and a [`Cell`] may span multiple of them:
+//!
+//! ```no_run
+//! # type Grid<'a, Message> = iced_widget::Grid<'a, Message>;
+//! use iced_widget::core::Length;
+//! use iced_widget::grid::Track;
+//! use iced_widget::{grid, text, text_input};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     NameChanged(String),
+//!     EmailChanged(String),
+//! }
+//!
+//! fn form<'a>(name: &'a str, email: &'a str) -> Grid<'a, Message> {
+//!     grid()
+//!         .columns([Track::Shrink, Track::from(Length::Fill)])
+//!         .spacing(10)
+//!         .push(0, 0, text("Name"))
+//!         .push(0, 1, text_input("Ferris", name).on_input(Message::NameChanged))
+//!         .push(1, 0, text("Email"))
+//!         .push(
+//!             1,
+//!             1,
+//!             text_input("ferris@example.com", email)
+//!                 .on_input(Message::EmailChanged),
+//!         )
+//! }
+//! ```
+//!
+//! Every column and row is sized by a [`Track`]. The [`Track::Shrink`]
+//! ones are measured first, fitting their cells; and then the space left is
+//! shared among the [`Track::Weight`] ones.
+use crate::core::alignment::{self, Alignment};
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+use std::ops::Range;
+
+/// A container that places its children in rows and columns.
+///
+/// A column or row without a [`Track`] is [`Track::Shrink`].
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    columns: Vec<Track>,
+    rows: Vec<Track>,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+    placements: Vec<Placement>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Grid`].
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            align_x: alignment::Horizontal::Left,
+            align_y: alignment::Vertical::Top,
+            placements: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Track`] of each column of the [`Grid`].
+    ///
+    /// If any of them is a [`Track::Weight`], the [`Grid`] fills the
+    /// available width.
+    pub fn columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<Track>>,
+    ) -> Self {
+        self.columns = columns.into_iter().map(Into::into).collect();
+
+        if self.columns.iter().any(|track| track.is_weighted()) {
+            self.width = self.width.enclose(Length::Fill);
+        }
+
+        self
+    }
+
+    /// Sets the [`Track`] of each row of the [`Grid`].
+    ///
+    /// If any of them is a [`Track::Weight`], the [`Grid`] fills the
+    /// available height.
+    pub fn rows(
+        mut self,
+        rows: impl IntoIterator<Item = impl Into<Track>>,
+    ) -> Self {
+        self.rows = rows.into_iter().map(Into::into).collect();
+
+        if self.rows.iter().any(|track| track.is_weighted()) {
+            self.height = self.height.enclose(Length::Fill);
+        }
+
+        self
+    }
+
+    /// Sets the spacing between both the columns and the rows of the
+    /// [`Grid`].
+    pub fn spacing(self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into();
+
+        self.column_spacing(amount).row_spacing(amount)
+    }
+
+    /// Sets the horizontal spacing between the columns of the [`Grid`].
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing between the rows of the [`Grid`].
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of every [`Cell`] of
+    /// the [`Grid`], unless a [`Cell`] sets its own.
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = align.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of every [`Cell`] of
+    /// the [`Grid`], unless a [`Cell`] sets its own.
+    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = align.into();
+        self
+    }
+
+    /// Adds an element to the [`Grid`], at the given row and column.
+    pub fn push(
+        self,
+        row: usize,
+        column: usize,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push_cell(Cell::new(row, column, child))
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    pub fn push_cell(
+        mut self,
+        cell: Cell<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        self.placements.push(cell.placement);
+        self.children.push(cell.content);
+        self
+    }
+
+    /// Extends the [`Grid`] with the given cells.
+    pub fn extend(
+        self,
+        cells: impl IntoIterator<Item = Cell<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        cells.into_iter().fold(self, Self::push_cell)
+    }
+
+    /// Returns the [`Track`] of every column and row of the [`Grid`],
+    /// including the implicit ones.
+    fn tracks(&self) -> (Vec<Track>, Vec<Track>) {
+        let count = |tracks: &[Track], end: fn(&Placement) -> usize| {
+            self.placements
+                .iter()
+                .map(end)
+                .max()
+                .unwrap_or(0)
+                .max(tracks.len())
+        };
+
+        let explicit = |tracks: &[Track], count| -> Vec<Track> {
+            (0..count)
+                .map(|i| tracks.get(i).copied().unwrap_or(Track::Shrink))
+                .collect()
+        };
+
+        let columns = count(&self.columns, |placement| placement.columns.end);
+        let rows = count(&self.rows, |placement| placement.rows.end);
+
+        (explicit(&self.columns, columns), explicit(&self.rows, rows))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Default
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The size of a column or a row of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Track {
+    /// Fit the contents of the cells in the track.
+    Shrink,
+
+    /// A fixed amount of logical pixels.
+    Fixed(f32),
+
+    /// A share of the space left by the other tracks, proportional to the
+    /// weight of the track.
+    ///
+    /// If the [`Grid`] has no bounded space to share, the track shrinks.
+    Weight(f32),
+}
+
+impl Track {
+    fn is_weighted(self) -> bool {
+        matches!(self, Self::Weight(_))
+    }
+}
+
+impl From<Length> for Track {
+    fn from(length: Length) -> Self {
+        match length {
+            Length::Shrink => Self::Shrink,
+            Length::Fixed(amount) => Self::Fixed(amount),
+            Length::Fill
+            | Length::FillPortion(_)
+            | Length::FillLimited { .. } => {
+                Self::Weight(f32::from(length.fill_factor()))
+            }
+        }
+    }
+}
+
+impl From<f32> for Track {
+    fn from(amount: f32) -> Self {
+        Self::Fixed(amount)
+    }
+}
+
+impl From<u16> for Track {
+    fn from(amount: u16) -> Self {
+        Self::Fixed(f32::from(amount))
+    }
+}
+
+impl From<Pixels> for Track {
+    fn from(amount: Pixels) -> Self {
+        Self::Fixed(amount.0)
+    }
+}
+
+/// An element placed in a [`Grid`], spanning some of its rows and columns.
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    placement: Placement,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Cell`] at the given row and column, with the given
+    /// content.
+    pub fn new(
+        row: usize,
+        column: usize,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            placement: Placement {
+                rows: row..row + 1,
+                columns: column..column + 1,
+                align_x: None,
+                align_y: None,
+            },
+            content: content.into(),
+        }
+    }
+
+    /// Sets the amount of rows and columns the [`Cell`] spans.
+    ///
+    /// A [`Cell`] always spans at least one row and one column.
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        let Placement {
+            rows: row,
+            columns: column,
+            ..
+        } = &mut self.placement;
+
+        row.end = row.start + rows.max(1);
+        column.end = column.start + columns.max(1);
+
+        self
+    }
+
+    /// Sets the horizontal alignment of the content of the [`Cell`].
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.placement.align_x = Some(align.into());
+        self
+    }
+
+    /// Sets the vertical alignment of the content of the [`Cell`].
+    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
+        self.placement.align_y = Some(align.into());
+        self
+    }
+}
+
+/// Creates a new [`Cell`] at the given row and column, with the given
+/// content.
+pub fn cell<'a, Message, Theme, Renderer>(
+    row: usize,
+    column: usize,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Cell<'a, Message, Theme, Renderer> {
+    Cell::new(row, column, content)
+}
+
+#[derive(Debug, Clone)]
+struct Placement {
+    rows: Range<usize>,
+    columns: Range<usize>,
+    align_x: Option<alignment::Horizontal>,
+    align_y: Option<alignment::Vertical>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .shrink(self.padding);
+
+        let max = limits.max();
+        let (columns, rows) = self.tracks();
+
+        // First, the columns; by measuring every cell in the available space
+        let widths = resolve(
+            &columns,
+            self.column_spacing,
+            max.width,
+            self.placements
+                .iter()
+                .map(|placement| placement.columns.clone()),
+            |i| {
+                if self.children[i].as_widget().size().width.is_fill() {
+                    return None;
+                }
+
+                let node = self.children[i].as_widget().layout(
+                    &mut tree.children[i],
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, max),
+                );
+
+                Some(node.size().width)
+            },
+        );
+
+        // Then, the rows; by measuring every cell with the width of its
+        // columns, since its height may depend on it (e.g. wrapped text)
+        let heights = resolve(
+            &rows,
+            self.row_spacing,
+            max.height,
+            self.placements
+                .iter()
+                .map(|placement| placement.rows.clone()),
+            |i| {
+                if self.children[i].as_widget().size().height.is_fill() {
+                    return None;
+                }
+
+                let width = span(
+                    &widths,
+                    self.column_spacing,
+                    &self.placements[i].columns,
+                );
+
+                let node = self.children[i].as_widget().layout(
+                    &mut tree.children[i],
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(width, max.height),
+                    ),
+                );
+
+                Some(node.size().height)
+            },
+        );
+
+        let nodes = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&self.placements)
+            .map(|((child, tree), placement)| {
+                let position = Point::new(
+                    self.padding.left
+                        + offset(
+                            &widths,
+                            self.column_spacing,
+                            placement.columns.start,
+                        ),
+                    self.padding.top
+                        + offset(
+                            &heights,
+                            self.row_spacing,
+                            placement.rows.start,
+                        ),
+                );
+
+                let size = Size::new(
+                    span(&widths, self.column_spacing, &placement.columns),
+                    span(&heights, self.row_spacing, &placement.rows),
+                );
+
+                child
+                    .as_widget()
+                    .layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(Size::ZERO, size),
+                    )
+                    .move_to(position)
+                    .align(
+                        Alignment::from(
+                            placement.align_x.unwrap_or(self.align_x),
+                        ),
+                        Alignment::from(
+                            placement.align_y.unwrap_or(self.align_y),
+                        ),
+                        size,
+                    )
+            })
+            .collect();
+
+        let content = Size::new(
+            span(&widths, self.column_spacing, &(0..widths.len())),
+            span(&heights, self.row_spacing, &(0..heights.len())),
+        );
+
+        let size = limits.resolve(self.width, self.height, content);
+
+        layout::Node::with_children(size.expand(self.padding), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if layout.bounds().intersection(viewport).is_none() {
+            return;
+        }
+
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
+
+/// Resolves the size of the tracks of an axis, given the span of every
+/// cell on that axis and a closure measuring a cell; which returns `None`
+/// if the cell fills its tracks.
+///
+/// The shrinking tracks are measured first, from the cells spanning the
+/// fewest tracks to the ones spanning the most. A spanning cell that does
+/// not fit its tracks distributes its extra size evenly among its
+/// shrinking tracks. Then, the weighted tracks share the space left.
+fn resolve(
+    tracks: &[Track],
+    spacing: f32,
+    available: f32,
+    spans: impl Iterator<Item = Range<usize>>,
+    mut measure: impl FnMut(usize) -> Option<f32>,
+) -> Vec<f32> {
+    let is_bounded = available.is_finite();
+
+    let is_shrink = |track: &Track| match track {
+        Track::Shrink => true,
+        Track::Fixed(_) => false,
+        Track::Weight(_) => !is_bounded,
+    };
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fixed(amount) => *amount,
+            Track::Shrink | Track::Weight(_) => 0.0,
+        })
+        .collect();
+
+    let mut spans: Vec<(usize, Range<usize>)> = spans.enumerate().collect();
+    spans.sort_by_key(|(_, span)| span.len());
+
+    for (i, range) in spans {
+        let spanned = &tracks[range.clone()];
+
+        // Cells spanning a weighted track fill whatever space it gets
+        if spanned
+            .iter()
+            .any(|track| track.is_weighted() && is_bounded)
+            || !spanned.iter().any(is_shrink)
+        {
+            continue;
+        }
+
+        let Some(size) = measure(i) else {
+            continue;
+        };
+
+        let extra = size - span(&sizes, spacing, &range);
+
+        if extra <= 0.0 {
+            continue;
+        }
+
+        let shrinking = spanned.iter().filter(|track| is_shrink(track)).count();
+
+        for (size, track) in sizes[range].iter_mut().zip(spanned) {
+            if is_shrink(track) {
+                *size += extra / shrinking as f32;
+            }
+        }
+    }
+
+    if is_bounded {
+        let weight: f32 = tracks
+            .iter()
+            .map(|track| match track {
+                Track::Weight(weight) => *weight,
+                Track::Shrink | Track::Fixed(_) => 0.0,
+            })
+            .sum();
+
+        if weight > 0.0 {
+            let remaining =
+                (available - span(&sizes, spacing, &(0..sizes.len()))).max(0.0);
+
+            for (size, track) in sizes.iter_mut().zip(tracks) {
+                if let Track::Weight(share) = track {
+                    *size = remaining * share / weight;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the total size of the given range of tracks, including the
+/// spacing between them.
+fn span(sizes: &[f32], spacing: f32, range: &Range<usize>) -> f32 {
+    sizes[range.clone()].iter().sum::<f32>()
+        + spacing * range.len().saturating_sub(1) as f32
+}
+
+/// Returns the position of the track with the given index.
+fn offset(sizes: &[f32], spacing: f32, index: usize) -> f32 {
+    sizes[..index].iter().sum::<f32>() + spacing * index as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Theme;
+    use crate::Space;
+
+    fn bounds(grid: Grid<'static, (), Theme, ()>) -> Vec<Rectangle> {
+        let element = Element::from(grid);
+        let mut tree = Tree::new(&element);
+
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        node.children().iter().map(layout::Node::bounds).collect()
+    }
+
+    #[test]
+    fn fill_columns_take_the_space_left() {
+        let cells = bounds(
+            Grid::new()
+                .columns([Track::Shrink, Track::from(Length::Fill)])
+                .width(200)
+                .spacing(10)
+                .push(0, 0, Space::new(50, 10))
+                .push(1, 0, Space::new(30, 20))
+                .push(0, 1, Space::new(Length::Fill, 10)),
+        );
+
+        assert_eq!(
+            cells[1],
+            Rectangle::new(Point::new(0.0, 20.0), Size::new(30.0, 20.0))
+        );
+        assert_eq!(
+            cells[2],
+            Rectangle::new(Point::new(60.0, 0.0), Size::new(140.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn weights_share_the_space_left() {
+        let cells = bounds(
+            Grid::new()
+                .columns([
+                    Track::Fixed(100.0),
+                    Track::Weight(1.0),
+                    Track::Weight(3.0),
+                ])
+                .width(500)
+                .push(0, 1, Space::new(Length::Fill, 10))
+                .push(0, 2, Space::new(Length::Fill, 10)),
+        );
+
+        assert_eq!(cells[0].x, 100.0);
+        assert_eq!(cells[0].width, 100.0);
+        assert_eq!(cells[1].x, 200.0);
+        assert_eq!(cells[1].width, 300.0);
+    }
+
+    #[test]
+    fn spanning_cells_distribute_their_extra_size() {
+        let cells = bounds(
+            Grid::new()
+                .spacing(10)
+                .push(0, 0, Space::new(20, 10))
+                .push(0, 1, Space::new(20, 10))
+                .push_cell(Cell::new(1, 0, Space::new(110, 10)).span(1, 2)),
+        );
+
+        // The spanning cell needs 110 - (20 + 10 + 20) = 60 more pixels
+        assert_eq!(cells[1].x, 60.0);
+        assert_eq!(cells[2].y, 20.0);
+        assert_eq!(cells[2].width, 110.0);
+    }
+
+    #[test]
+    fn cells_are_aligned_in_their_tracks() {
+        let cells = bounds(
+            Grid::new()
+                .align_y(alignment::Vertical::Bottom)
+                .push(0, 0, Space::new(100, 50))
+                .push_cell(
+                    Cell::new(1, 0, Space::new(20, 20))
+                        .align_x(alignment::Horizontal::Center),
+                )
+                .push(0, 1, Space::new(20, 20)),
+        );
+
+        assert_eq!(cells[1].position(), Point::new(40.0, 50.0));
+        assert_eq!(cells[2].position(), Point::new(100.0, 30.0));
+    }
+}
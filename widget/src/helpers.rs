@@ -1,12 +1,14 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
+use crate::circular_progress::{self, CircularProgress};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
 use crate::core;
 use crate::core::widget::operation;
 use crate::core::{Element, Length, Pixels, Widget};
 use crate::keyed;
+use crate::linear_progress::{self, LinearProgress};
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
@@ -16,13 +18,19 @@ use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::tab_bar::{self, Tab, TabBar};
+use crate::table::{self, Table};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, MouseArea, Row, Space, Stack, Themer};
+use crate::virtual_list::VirtualList;
+use crate::{
+    Column, DragSource, DropTarget, Grid, MouseArea, Row, Space, Stack,
+    Swipeable, Themer, Transform, ZoomArea,
+};
 
 use std::borrow::{Borrow, Cow};
 use std::ops::RangeInclusive;
@@ -193,6 +201,16 @@ where
     Row::with_children(children)
 }
 
+/// Creates a new empty [`Grid`].
+///
+/// [`Grid`]: crate::Grid
+pub fn grid<'a, Message, Theme, Renderer>() -> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Grid::new()
+}
+
 /// Creates a new [`Stack`] with the given children.
 ///
 /// [`Stack`]: crate::Stack
@@ -355,6 +373,7 @@ where
             state: &'b mut core::widget::Tree,
             layout: core::Layout<'_>,
             renderer: &Renderer,
+            viewport: &core::Rectangle,
             translation: core::Vector,
         ) -> Option<core::overlay::Element<'b, Message, Theme, Renderer>>
         {
@@ -362,6 +381,7 @@ where
                 state,
                 layout,
                 renderer,
+                viewport,
                 translation,
             )
         }
@@ -582,6 +602,7 @@ where
             tree: &'b mut core::widget::Tree,
             layout: core::Layout<'_>,
             renderer: &Renderer,
+            viewport: &core::Rectangle,
             translation: core::Vector,
         ) -> Option<core::overlay::Element<'b, Message, Theme, Renderer>>
         {
@@ -593,6 +614,7 @@ where
                         tree,
                         layout,
                         renderer,
+                        viewport,
                         translation,
                     )
                 });
@@ -769,6 +791,21 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`] with the given value and a closure that
+/// produces a message when the value changes.
+///
+/// [`NumberInput`]: crate::NumberInput
+#[cfg(feature = "lazy")]
+pub fn number_input<'a, T, Message>(
+    value: Option<T>,
+    on_change: impl Fn(Option<T>) -> Message + 'a,
+) -> crate::NumberInput<'a, T, Message>
+where
+    T: crate::number_input::Number,
+{
+    crate::NumberInput::new(value, on_change)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// [`TextEditor`]: crate::TextEditor
@@ -904,11 +941,33 @@ where
     ProgressBar::new(range, value)
 }
 
+/// Creates a new indeterminate [`CircularProgress`].
+///
+/// [`CircularProgress`]: crate::CircularProgress
+pub fn circular_progress<'a, Theme>() -> CircularProgress<'a, Theme>
+where
+    Theme: circular_progress::Catalog + 'a,
+{
+    CircularProgress::new()
+}
+
+/// Creates a new indeterminate [`LinearProgress`].
+///
+/// [`LinearProgress`]: crate::LinearProgress
+pub fn linear_progress<'a, Theme>() -> LinearProgress<'a, Theme>
+where
+    Theme: linear_progress::Catalog + 'a,
+{
+    LinearProgress::new()
+}
+
 /// Creates a new [`Image`].
 ///
 /// [`Image`]: crate::Image
 #[cfg(feature = "image")]
-pub fn image<Handle>(handle: impl Into<Handle>) -> crate::Image<Handle> {
+pub fn image<'a, Handle, Message, Theme, Renderer>(
+    handle: impl Into<Handle>,
+) -> crate::Image<'a, Handle, Message, Theme, Renderer> {
     crate::Image::new(handle.into())
 }
 
@@ -975,6 +1034,21 @@ where
     crate::Canvas::new(program)
 }
 
+/// Creates a new [`ClipPath`] with the given content and [`Path`].
+///
+/// [`ClipPath`]: crate::ClipPath
+/// [`Path`]: crate::canvas::Path
+#[cfg(feature = "canvas")]
+pub fn clip_path<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    path: crate::canvas::Path,
+) -> crate::ClipPath<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::graphics::geometry::Renderer,
+{
+    crate::ClipPath::new(content, path)
+}
+
 /// Creates a new [`QRCode`] widget from the given [`Data`].
 ///
 /// [`QRCode`]: crate::QRCode
@@ -1010,6 +1084,57 @@ pub fn focus_next<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_next()))
 }
 
+/// Focuses the first focusable widget inside the scope with the given
+/// [`Id`], like a keyed item or a component.
+///
+/// [`Id`]: core::widget::Id
+pub fn focus_first<T>(scope: impl Into<core::widget::Id>) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus_first(
+        scope.into(),
+    )))
+}
+
+/// Creates a new [`TabBar`] with the given tabs, the index of the active one,
+/// and the message to produce when a tab is selected.
+pub fn tab_bar<'a, Message, Theme, Renderer>(
+    tabs: impl IntoIterator<Item = impl Into<Tab<Renderer::Font>>>,
+    active: usize,
+    on_select: impl Fn(usize) -> Message + 'a,
+) -> TabBar<'a, Message, Theme, Renderer>
+where
+    Theme: tab_bar::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    TabBar::new(tabs, active, on_select)
+}
+
+/// Creates a new [`Table`] with the given columns, the amount of rows, and
+/// the closure producing the cells of a row given its index.
+pub fn table<'a, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = table::Column>,
+    rows: usize,
+    row: impl Fn(usize) -> Vec<Element<'a, Message, Theme, Renderer>> + 'a,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    Theme: table::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Table::new(columns, rows, row)
+}
+
+/// Creates a new [`VirtualList`] with the given amount of rows, their height,
+/// and the closure producing a row given its index.
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    count: usize,
+    row_height: impl Into<Pixels>,
+    row: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    VirtualList::new(count, row_height, row)
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -1020,6 +1145,61 @@ where
     MouseArea::new(widget)
 }
 
+/// A container that starts dragging the given payload when its content is
+/// dragged.
+pub fn drag_source<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    payload: impl std::any::Any + Clone,
+) -> DragSource<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    DragSource::new(content, payload)
+}
+
+/// A container that receives the payloads of type `T` dropped on its
+/// content.
+pub fn drop_target<'a, T, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropTarget<'a, T, Message, Theme, Renderer>
+where
+    T: std::any::Any + Clone,
+    Renderer: core::Renderer,
+{
+    DropTarget::new(content)
+}
+
+/// A frame that can zoom in and pan its content.
+pub fn zoom_area<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> ZoomArea<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    ZoomArea::new(content)
+}
+
+/// A wrapper that rotates, scales, and translates its content.
+pub fn transform<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Transform<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Transform::new(content)
+}
+
+/// A container that can be swiped horizontally to reveal actions.
+pub fn swipeable<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Swipeable<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::Renderer,
+{
+    Swipeable::new(content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
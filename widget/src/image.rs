@@ -2,23 +2,88 @@
 pub mod viewer;
 pub use viewer::Viewer;
 
+use crate::core::event::{self, Event};
 use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::{Operation, Tree};
 use crate::core::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Rotation, Size,
-    Vector, Widget,
+    Clipboard, ContentFit, Element, Layout, Length, Point, Rectangle, Rotation,
+    Shell, Size, Vector, Widget,
 };
+use crate::runtime::Task;
 
-pub use image::{FilterMethod, Handle};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub use image::{update_region, ColorHandling, Error, FilterMethod, Handle};
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
 }
 
+/// Loads the image at the given path and decodes it in a background thread.
+///
+/// The resulting [`Handle`] holds the decoded pixels; so the image is not
+/// decoded by the renderer—blocking a frame—the first time it is drawn.
+pub fn load(path: impl Into<PathBuf>) -> Task<Result<Handle, LoadError>> {
+    Task::future(decode_in_background(path.into()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn decode_in_background(path: PathBuf) -> Result<Handle, LoadError> {
+    use crate::runtime::futures::futures::channel::oneshot;
+
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = std::thread::Builder::new()
+        .name("iced image loader".to_owned())
+        .spawn(move || {
+            let _ = sender.send(decode(path));
+        })
+        .map_err(|error| LoadError::Io(Arc::new(error)))?;
+
+    receiver.await.unwrap_or_else(|_| {
+        Err(LoadError::Io(Arc::new(std::io::Error::other(
+            "the image loader stopped unexpectedly",
+        ))))
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn decode_in_background(path: PathBuf) -> Result<Handle, LoadError> {
+    decode(path)
+}
+
+fn decode(path: PathBuf) -> Result<Handle, LoadError> {
+    let bytes =
+        std::fs::read(&path).map_err(|error| LoadError::Io(Arc::new(error)))?;
+
+    let image = crate::graphics::image::load(&Handle::from_bytes(bytes))
+        .map_err(|error| LoadError::Decode(Arc::new(error)))?;
+
+    Ok(Handle::from_rgba(
+        image.width(),
+        image.height(),
+        image.into_raw(),
+    ))
+}
+
+/// An error produced when loading an image with [`load`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum LoadError {
+    /// The file could not be read.
+    #[error("the image could not be read: {0}")]
+    Io(Arc<std::io::Error>),
+    /// The image could not be decoded; because it is corrupt or its format
+    /// is not supported.
+    #[error("the image could not be decoded: {0}")]
+    Decode(Arc<crate::graphics::image::image_rs::ImageError>),
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -26,13 +91,21 @@ pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
 /// ```no_run
 /// # use iced_widget::image::{self, Image};
 /// #
-/// let image = Image::<image::Handle>::new("resources/ferris.png");
+/// let image: Image<'_, image::Handle, ()> =
+///     Image::new("resources/ferris.png");
 /// ```
 ///
 /// <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
-#[derive(Debug)]
-pub struct Image<Handle> {
-    handle: Handle,
+#[allow(missing_debug_implementations)]
+pub struct Image<
+    'a,
+    Handle,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    handle: Option<Handle>,
+    placeholder: Option<Element<'a, Message, Theme, Renderer>>,
     width: Length,
     height: Length,
     content_fit: ContentFit,
@@ -41,11 +114,24 @@ pub struct Image<Handle> {
     opacity: f32,
 }
 
-impl<Handle> Image<Handle> {
+impl<'a, Handle, Message, Theme, Renderer>
+    Image<'a, Handle, Message, Theme, Renderer>
+{
     /// Creates a new [`Image`] with the given path.
     pub fn new<T: Into<Handle>>(handle: T) -> Self {
+        Self::maybe(Some(handle.into()))
+    }
+
+    /// Creates a new [`Image`] with the given handle, if any.
+    ///
+    /// While there is no handle—because the image is still being
+    /// [`load`]ed, for instance—the [`placeholder`] is shown instead.
+    ///
+    /// [`placeholder`]: Self::placeholder
+    pub fn maybe(handle: Option<Handle>) -> Self {
         Image {
-            handle: handle.into(),
+            handle,
+            placeholder: None,
             width: Length::Shrink,
             height: Length::Shrink,
             content_fit: ContentFit::default(),
@@ -55,6 +141,16 @@ impl<Handle> Image<Handle> {
         }
     }
 
+    /// Sets the element shown while the [`Image`] has no handle.
+    ///
+    /// It is laid out inside the boundaries of the [`Image`].
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
     /// Sets the width of the [`Image`] boundaries.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -197,12 +293,25 @@ pub fn draw<Renderer, Handle>(
     }
 }
 
-impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
-    for Image<Handle>
+impl<'a, Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
+    for Image<'a, Handle, Message, Theme, Renderer>
 where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone,
 {
+    fn children(&self) -> Vec<Tree> {
+        self.placeholder.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.placeholder {
+            Some(placeholder) => {
+                tree.diff_children(std::slice::from_ref(placeholder));
+            }
+            None => tree.children.clear(),
+        }
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -212,14 +321,36 @@ where
 
     fn layout(
         &self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let Some(handle) = &self.handle else {
+            let limits = limits.width(self.width).height(self.height);
+
+            let Some(placeholder) = &self.placeholder else {
+                return layout::Node::new(limits.resolve(
+                    self.width,
+                    self.height,
+                    Size::ZERO,
+                ));
+            };
+
+            let content = placeholder.as_widget().layout(
+                &mut tree.children[0],
+                renderer,
+                &limits,
+            );
+
+            let size = limits.resolve(self.width, self.height, content.size());
+
+            return layout::Node::with_children(size, vec![content]);
+        };
+
         layout(
             renderer,
             limits,
-            &self.handle,
+            handle,
             self.width,
             self.height,
             self.content_fit,
@@ -227,35 +358,152 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        if let (None, Some(placeholder), Some(layout)) =
+            (&self.handle, &self.placeholder, layout.children().next())
+        {
+            placeholder.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let (None, Some(placeholder), Some(layout)) = (
+            &self.handle,
+            &mut self.placeholder,
+            layout.children().next(),
+        ) {
+            placeholder.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            )
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if let (None, Some(placeholder), Some(layout)) =
+            (&self.handle, &self.placeholder, layout.children().next())
+        {
+            placeholder.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
-        _theme: &Theme,
-        _style: &renderer::Style,
+        theme: &Theme,
+        style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
     ) {
-        draw(
-            renderer,
-            layout,
+        match (&self.handle, &self.placeholder, layout.children().next()) {
+            (Some(handle), _, _) => draw(
+                renderer,
+                layout,
+                handle,
+                self.content_fit,
+                self.filter_method,
+                self.rotation,
+                self.opacity,
+            ),
+            (None, Some(placeholder), Some(layout)) => {
+                placeholder.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    viewport,
+                );
+            }
+            (None, _, _) => {}
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (None, Some(placeholder), Some(layout)) = (
             &self.handle,
-            self.content_fit,
-            self.filter_method,
-            self.rotation,
-            self.opacity,
-        );
+            &mut self.placeholder,
+            layout.children().next(),
+        ) else {
+            return None;
+        };
+
+        placeholder.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
     }
 }
 
-impl<'a, Message, Theme, Renderer, Handle> From<Image<Handle>>
+impl<'a, Message, Theme, Renderer, Handle>
+    From<Image<'a, Handle, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Renderer: image::Renderer<Handle = Handle>,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
     Handle: Clone + 'a,
+    Message: 'a,
+    Theme: 'a,
 {
-    fn from(image: Image<Handle>) -> Element<'a, Message, Theme, Renderer> {
+    fn from(
+        image: Image<'a, Handle, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
         Element::new(image)
     }
 }
@@ -1,15 +1,22 @@
 //! Distribute content vertically.
+use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
-use crate::core::renderer;
+use crate::core::renderer::{self, Snapshot};
+use crate::core::settings::Motion;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::{self, Operation};
+use crate::core::window;
 use crate::core::{
-    Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle,
-    Shell, Size, Vector, Widget,
+    Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Transformation, Vector, Widget,
 };
+use crate::easing::Easing;
+
+use std::cell::{Cell, RefCell};
 
 /// A container that distributes its contents vertically.
 #[allow(missing_debug_implementations)]
@@ -26,10 +33,15 @@ pub struct Column<
     padding: Padding,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
     max_width: f32,
-    align_items: Alignment,
+    max_height: f32,
+    align_items: alignment::Horizontal,
     keys: Vec<Key>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    scope: Option<Box<dyn Fn(Key) -> widget::Id + 'a>>,
+    animation: Option<(Duration, Easing)>,
 }
 
 impl<'a, Key, Message, Theme, Renderer>
@@ -59,10 +71,15 @@ where
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
+            min_width: 0.0,
+            min_height: 0.0,
             max_width: f32::INFINITY,
-            align_items: Alignment::Start,
+            max_height: f32::INFINITY,
+            align_items: alignment::Horizontal::Left,
             keys,
             children,
+            scope: None,
+            animation: None,
         }
     }
 
@@ -113,15 +130,77 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Column`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Column`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = min_height.into().0;
+        self
+    }
+
     /// Sets the maximum width of the [`Column`].
     pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
         self.max_width = max_width.into().0;
         self
     }
 
+    /// Sets the maximum height of the [`Column`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max_height = max_height.into().0;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Column`] .
-    pub fn align_items(mut self, align: Alignment) -> Self {
-        self.align_items = align;
+    pub fn align_items(
+        mut self,
+        align: impl Into<alignment::Horizontal>,
+    ) -> Self {
+        self.align_items = align.into();
+        self
+    }
+
+    /// Scopes every item of the [`Column`] within the [`Id`] produced by the
+    /// given function for its key.
+    ///
+    /// The [`Id`] of every widget inside an item is then scoped within the
+    /// [`Id`] of the item during operations; see [`Id::within`].
+    ///
+    /// [`Id`]: widget::Id
+    /// [`Id::within`]: widget::Id::within
+    pub fn scoped(mut self, scope: impl Fn(Key) -> widget::Id + 'a) -> Self {
+        self.scope = Some(Box::new(scope));
+        self
+    }
+
+    /// Animates the children of the [`Column`] whenever its layout changes,
+    /// over the given [`Duration`] and following the given [`Easing`].
+    ///
+    /// Children that move are drawn sliding from their previous positions,
+    /// and children that are added scale and fade in. Only drawing is
+    /// animated; the children are laid out and interact at their final
+    /// positions right away.
+    ///
+    /// Children are correlated across layouts by their key. Removed children
+    /// fade out where they were last laid out, while their siblings slide
+    /// into the gap they leave.
+    ///
+    /// Fading relies on the snapshots of the renderer; see
+    /// [`Renderer::start_snapshot`]. Children appear and disappear right away
+    /// with renderers that cannot record them.
+    ///
+    /// [`Renderer::start_snapshot`]: crate::core::Renderer::start_snapshot
+    ///
+    /// Nothing is animated unless the current [`Motion`] is [`Motion::Full`].
+    pub fn animate_layout(
+        mut self,
+        duration: impl Into<Duration>,
+        easing: Easing,
+    ) -> Self {
+        self.animation = Some((duration.into(), easing));
         self
     }
 
@@ -183,6 +262,157 @@ where
     Key: Copy + PartialEq,
 {
     keys: Vec<Key>,
+    positions: Option<Vec<(Key, Point)>>,
+    transitions: Vec<(Key, Transition)>,
+    /// The last snapshot of every child, recorded when drawn.
+    snapshots: RefCell<Vec<(Key, Snapshot)>>,
+    /// The position of the [`Column`] when its children were last drawn.
+    origin: Cell<Point>,
+    removed: Vec<Removed>,
+    now: Instant,
+}
+
+/// A child that was removed, fading out.
+struct Removed {
+    snapshot: Snapshot,
+    origin: Point,
+    start: Instant,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    start: Instant,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Move { offset: Vector },
+    Enter,
+}
+
+impl<Key> State<Key>
+where
+    Key: Copy + PartialEq,
+{
+    /// Starts a [`Transition`] for every child whose position changed
+    /// since the last layout, and fades out the children that were removed.
+    fn animate(
+        &mut self,
+        keys: &[Key],
+        node: &layout::Node,
+        duration: Duration,
+        easing: &Easing,
+    ) {
+        let now = Instant::now();
+
+        let positions: Vec<_> = keys
+            .iter()
+            .copied()
+            .zip(
+                node.children()
+                    .iter()
+                    .map(|child| child.bounds().position()),
+            )
+            .collect();
+
+        if Motion::current() != Motion::Full {
+            // Children snap to their new positions right away
+            self.transitions.clear();
+            self.removed.clear();
+        } else if let Some(previous) = &self.positions {
+            let snapshots = self.snapshots.get_mut();
+
+            for (key, _) in previous {
+                if positions.iter().any(|(new, _)| new == key) {
+                    continue;
+                }
+
+                self.transitions.retain(|(old, _)| old != key);
+
+                if let Some(index) =
+                    snapshots.iter().position(|(old, _)| old == key)
+                {
+                    let (_, snapshot) = snapshots.swap_remove(index);
+
+                    self.removed.push(Removed {
+                        snapshot,
+                        origin: self.origin.get(),
+                        start: now,
+                    });
+                }
+            }
+
+            for (key, position) in &positions {
+                let kind = match previous.iter().find(|(old, _)| old == key) {
+                    Some((_, old)) if old != position => {
+                        let displayed =
+                            *old + self.translation(*key, duration, easing);
+
+                        Kind::Move {
+                            offset: displayed - *position,
+                        }
+                    }
+                    Some(_) => continue,
+                    None => Kind::Enter,
+                };
+
+                self.transitions.retain(|(old, _)| old != key);
+                self.transitions
+                    .push((*key, Transition { start: now, kind }));
+            }
+        }
+
+        self.positions = Some(positions);
+        self.now = now;
+    }
+
+    /// Returns the eased progress of the [`Transition`] of the child with
+    /// the given key, if any.
+    fn progress(
+        &self,
+        key: Key,
+        duration: Duration,
+        easing: &Easing,
+    ) -> Option<(Kind, f32)> {
+        let (_, transition) =
+            self.transitions.iter().find(|(other, _)| *other == key)?;
+
+        Some((
+            transition.kind,
+            self.progress_since(transition.start, duration, easing),
+        ))
+    }
+
+    /// Returns the eased progress of an animation that started at the given
+    /// [`Instant`].
+    fn progress_since(
+        &self,
+        start: Instant,
+        duration: Duration,
+        easing: &Easing,
+    ) -> f32 {
+        let elapsed = self.now.saturating_duration_since(start);
+        let x = (elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.0);
+
+        easing.y_at_x(x)
+    }
+
+    /// Returns the translation currently applied to the child with the
+    /// given key.
+    fn translation(
+        &self,
+        key: Key,
+        duration: Duration,
+        easing: &Easing,
+    ) -> Vector {
+        match self.progress(key, duration, easing) {
+            Some((Kind::Move { offset }, progress)) => {
+                offset * (1.0 - progress)
+            }
+            _ => Vector::ZERO,
+        }
+    }
 }
 
 impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -198,6 +428,12 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             keys: self.keys.clone(),
+            positions: None,
+            transitions: Vec::new(),
+            snapshots: RefCell::new(Vec::new()),
+            origin: Cell::new(Point::ORIGIN),
+            removed: Vec::new(),
+            now: Instant::now(),
         })
     }
 
@@ -212,14 +448,12 @@ where
 
         let state = state.downcast_mut::<State<Key>>();
 
-        tree::diff_children_custom_with_search(
+        tree::diff_children_keyed(
             children,
+            &state.keys,
             &self.children,
+            &self.keys,
             |tree, child| child.as_widget().diff(tree),
-            |index| {
-                self.keys.get(index).or_else(|| self.keys.last()).copied()
-                    != Some(state.keys[index])
-            },
             |child| Tree::new(child.as_widget()),
         );
 
@@ -242,11 +476,14 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let limits = limits
-            .max_width(self.max_width)
+            .constrain(
+                Size::new(self.min_width, self.min_height),
+                Size::new(self.max_width, self.max_height),
+            )
             .width(self.width)
             .height(self.height);
 
-        layout::flex::resolve(
+        let node = layout::flex::resolve(
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
@@ -254,10 +491,18 @@ where
             self.height,
             self.padding,
             self.spacing,
-            self.align_items,
+            Alignment::from(self.align_items),
             &self.children,
             &mut tree.children,
-        )
+        );
+
+        if let Some((duration, easing)) = &self.animation {
+            tree.state
+                .downcast_mut::<State<Key>>()
+                .animate(&self.keys, &node, *duration, easing);
+        }
+
+        node
     }
 
     fn operate(
@@ -268,14 +513,27 @@ where
         operation: &mut dyn Operation<()>,
     ) {
         operation.container(None, layout.bounds(), &mut |operation| {
-            self.children
+            self.keys
                 .iter()
+                .zip(&self.children)
                 .zip(&mut tree.children)
                 .zip(layout.children())
-                .for_each(|((child, state), layout)| {
-                    child
-                        .as_widget()
-                        .operate(state, layout, renderer, operation);
+                .for_each(|(((key, child), state), layout)| {
+                    let mut operate = |operation: &mut dyn Operation<()>| {
+                        child
+                            .as_widget()
+                            .operate(state, layout, renderer, operation);
+                    };
+
+                    match &self.scope {
+                        Some(scope) => widget::operation::namespace(
+                            operation,
+                            &scope(*key),
+                            layout.bounds(),
+                            &mut operate,
+                        ),
+                        None => operate(operation),
+                    }
                 });
         });
     }
@@ -291,6 +549,26 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if let (
+            Some((duration, _)),
+            Event::Window(window::Event::RedrawRequested(now)),
+        ) = (&self.animation, &event)
+        {
+            let state = tree.state.downcast_mut::<State<Key>>();
+
+            state.now = *now;
+            state.transitions.retain(|(_, transition)| {
+                now.saturating_duration_since(transition.start) < *duration
+            });
+            state.removed.retain(|removed| {
+                now.saturating_duration_since(removed.start) < *duration
+            });
+
+            if !state.transitions.is_empty() || !state.removed.is_empty() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
         self.children
             .iter_mut()
             .zip(&mut tree.children)
@@ -341,16 +619,85 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((child, state), layout) in self
-            .children
+        let Some((duration, easing)) = self
+            .animation
+            .as_ref()
+            .filter(|_| Motion::current() == Motion::Full)
+        else {
+            for ((child, state), layout) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+
+            return;
+        };
+
+        let column = tree.state.downcast_ref::<State<Key>>();
+        let origin = layout.position();
+
+        // Removed children fade out under their siblings
+        for removed in &column.removed {
+            let opacity =
+                1.0 - column.progress_since(removed.start, *duration, easing);
+
+            renderer.with_translation(origin - removed.origin, |renderer| {
+                renderer.draw_snapshot(&removed.snapshot, opacity);
+            });
+        }
+
+        let mut snapshots = Vec::with_capacity(self.children.len());
+
+        for (((key, child), state), layout) in self
+            .keys
             .iter()
+            .zip(&self.children)
             .zip(&tree.children)
             .zip(layout.children())
         {
-            child
-                .as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
+            let (transformation, opacity) = match column
+                .progress(*key, *duration, easing)
+            {
+                Some((Kind::Move { offset }, progress)) => {
+                    let offset = offset * (1.0 - progress);
+
+                    (Transformation::translate(offset.x, offset.y), 1.0)
+                }
+                Some((Kind::Enter, progress)) => {
+                    let center = layout.bounds().center();
+
+                    (
+                        Transformation::translate(center.x, center.y)
+                            * Transformation::scale(progress)
+                            * Transformation::translate(-center.x, -center.y),
+                        progress,
+                    )
+                }
+                None => (Transformation::IDENTITY, 1.0),
+            };
+
+            renderer.with_transformation(transformation, |renderer| {
+                // Every child is recorded, in case it is removed next
+                renderer.start_snapshot();
+
+                child.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+
+                if let Some(snapshot) = renderer.end_snapshot() {
+                    renderer.draw_snapshot(&snapshot, opacity);
+                    snapshots.push((*key, snapshot));
+                }
+            });
         }
+
+        *column.snapshots.borrow_mut() = snapshots;
+        column.origin.set(origin);
     }
 
     fn overlay<'b>(
@@ -358,6 +705,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         overlay::from_children(
@@ -365,6 +713,7 @@ where
             tree,
             layout,
             renderer,
+            viewport,
             translation,
         )
     }
@@ -383,3 +732,135 @@ where
         Self::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Theme;
+    use crate::easing;
+
+    type Column = super::Column<'static, u32, (), Theme, ()>;
+
+    /// A widget that remembers the label it was created with in its state.
+    struct Item(&'static str);
+
+    impl Widget<(), Theme, ()> for Item {
+        fn tag(&self) -> tree::Tag {
+            tree::Tag::of::<&'static str>()
+        }
+
+        fn state(&self) -> tree::State {
+            tree::State::new(self.0)
+        }
+
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Shrink, Length::Shrink)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::ZERO)
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn column(
+        items: &[(u32, &'static str)],
+    ) -> Element<'static, (), Theme, ()> {
+        Column::with_children(
+            items
+                .iter()
+                .map(|&(key, label)| (key, Element::new(Item(label)))),
+        )
+        .into()
+    }
+
+    /// Returns the labels in the state of the children of the given tree.
+    fn labels(tree: &Tree) -> Vec<&'static str> {
+        tree.children
+            .iter()
+            .map(|child| *child.state.downcast_ref::<&'static str>())
+            .collect()
+    }
+
+    #[test]
+    fn state_follows_keys() {
+        let mut tree = Tree::new(&column(&[(1, "a"), (2, "b"), (3, "c")]));
+
+        // The labels of the views differ, but the state is kept by key
+        tree.diff(&column(&[(3, "x"), (1, "y"), (2, "z")]));
+        assert_eq!(labels(&tree), ["c", "a", "b"]);
+
+        tree.diff(&column(&[(3, "x"), (4, "d"), (1, "y"), (2, "z")]));
+        assert_eq!(labels(&tree), ["c", "d", "a", "b"]);
+
+        tree.diff(&column(&[(3, "x"), (1, "y"), (2, "z")]));
+        assert_eq!(labels(&tree), ["c", "a", "b"]);
+
+        tree.diff(&column(&[(2, "z"), (5, "e"), (3, "x")]));
+        assert_eq!(labels(&tree), ["b", "e", "c"]);
+    }
+
+    #[test]
+    fn removed_children_fade_out_from_their_last_snapshot() {
+        let duration = Duration::from_millis(100);
+
+        let node = |count: usize| {
+            layout::Node::with_children(
+                Size::ZERO,
+                (0..count)
+                    .map(|i| {
+                        layout::Node::new(Size::ZERO)
+                            .move_to(Point::new(0.0, i as f32 * 10.0))
+                    })
+                    .collect(),
+            )
+        };
+
+        let mut state = State {
+            keys: Vec::new(),
+            positions: None,
+            transitions: Vec::new(),
+            snapshots: RefCell::new(Vec::new()),
+            origin: Cell::new(Point::ORIGIN),
+            removed: Vec::new(),
+            now: Instant::now(),
+        };
+
+        state.animate(&[1, 2, 3], &node(3), duration, &easing::LINEAR);
+
+        *state.snapshots.borrow_mut() = [1, 2, 3]
+            .into_iter()
+            .map(|key| (key, Snapshot::new(key)))
+            .collect();
+
+        state.animate(&[1, 3], &node(2), duration, &easing::LINEAR);
+
+        assert_eq!(state.removed.len(), 1);
+        assert_eq!(state.removed[0].snapshot.downcast_ref::<u32>(), Some(&2));
+        assert!(state.snapshots.borrow().iter().all(|(key, _)| *key != 2));
+    }
+
+    #[test]
+    fn repeated_keys_are_matched_in_order() {
+        let mut tree = Tree::new(&column(&[(1, "a"), (1, "b"), (2, "c")]));
+
+        tree.diff(&column(&[(2, "x"), (1, "y"), (1, "z")]));
+        assert_eq!(labels(&tree), ["c", "a", "b"]);
+    }
+}
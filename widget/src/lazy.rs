@@ -3,9 +3,11 @@ pub(crate) mod helpers;
 
 pub mod component;
 pub mod responsive;
+pub mod stateful;
 
 pub use component::Component;
 pub use responsive::Responsive;
+pub use stateful::Stateful;
 
 mod cache;
 
@@ -266,6 +268,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'_, Message, Theme, Renderer>> {
         let overlay = Overlay(Some(
@@ -283,7 +286,7 @@ where
                 overlay_builder: |element, tree| {
                     element
                         .as_widget_mut()
-                        .overlay(tree, layout, renderer, translation)
+                        .overlay(tree, layout, renderer, viewport, translation)
                         .map(|overlay| RefCell::new(Nested::new(overlay)))
                 },
             }
@@ -63,6 +63,21 @@ pub trait Component<Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     ) {
     }
 
+    /// Returns the [`Id`] of the scope established by the [`Component`], if
+    /// any.
+    ///
+    /// When present, the [`Id`] of every widget inside the [`Component`] is
+    /// scoped within it during operations; so multiple instances of the same
+    /// [`Component`] can be told apart. See [`Id::within`].
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Id`]: widget::Id
+    /// [`Id::within`]: widget::Id::within
+    fn id(&self) -> Option<widget::Id> {
+        None
+    }
+
     /// Returns a [`Size`] hint for laying out the [`Component`].
     ///
     /// This hint may be used by some widget containers to adjust their sizing strategy
@@ -303,7 +318,8 @@ where
         viewport: &Rectangle,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         let t = tree.state.downcast_mut::<Rc<RefCell<Option<Tree>>>>();
         let event_status = self.with_element_mut(|element| {
@@ -325,6 +341,13 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        if local_shell.is_drag_changed() {
+            match local_shell.drag() {
+                Some(payload) => shell.start_drag(payload.clone()),
+                None => shell.end_drag(),
+            }
+        }
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -362,14 +385,34 @@ where
     ) {
         self.rebuild_element_with_operation(operation);
 
+        let id = self
+            .state
+            .borrow()
+            .as_ref()
+            .expect("Borrow instance state")
+            .borrow_component()
+            .id();
+
         let tree = tree.state.downcast_mut::<Rc<RefCell<Option<Tree>>>>();
         self.with_element(|element| {
-            element.as_widget().operate(
-                &mut tree.borrow_mut().as_mut().unwrap().children[0],
-                layout,
-                renderer,
-                operation,
-            );
+            let mut operate = |operation: &mut dyn widget::Operation<()>| {
+                element.as_widget().operate(
+                    &mut tree.borrow_mut().as_mut().unwrap().children[0],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            };
+
+            match &id {
+                Some(id) => widget::operation::namespace(
+                    operation,
+                    id,
+                    layout.bounds(),
+                    &mut operate,
+                ),
+                None => operate(operation),
+            }
         });
     }
 
@@ -422,6 +465,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         self.rebuild_element_if_necessary();
@@ -449,6 +493,7 @@ where
                                     &mut tree.children[0],
                                     layout,
                                     renderer,
+                                    viewport,
                                     translation,
                                 )
                                 .map(|overlay| {
@@ -578,7 +623,8 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         let event_status = self
             .with_overlay_mut_maybe(|overlay| {
@@ -599,6 +645,13 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        if local_shell.is_drag_changed() {
+            match local_shell.drag() {
+                Some(payload) => shell.start_drag(payload.clone()),
+                None => shell.end_drag(),
+            }
+        }
+
         if !local_messages.is_empty() {
             let mut inner =
                 self.overlay.take().unwrap().0.take().unwrap().into_heads();
@@ -650,3 +703,184 @@ where
         .unwrap_or_default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::widget::operation::{self, Focusable, Operation};
+    use crate::core::Theme;
+    use crate::{text_input, Column};
+
+    /// A form with a single text input, identified by the same [`Id`] in
+    /// every instance.
+    struct Form {
+        id: widget::Id,
+    }
+
+    impl Component<(), Theme, ()> for Form {
+        type State = ();
+        type Event = String;
+
+        fn update(&mut self, _state: &mut (), _event: String) -> Option<()> {
+            None
+        }
+
+        fn view(&self, _state: &()) -> Element<'_, String, Theme, ()> {
+            text_input::TextInput::new("Name", "")
+                .id(text_input::Id::new("name"))
+                .on_input(|name| name)
+                .into()
+        }
+
+        fn id(&self) -> Option<widget::Id> {
+            Some(self.id.clone())
+        }
+    }
+
+    /// Records the [`Id`] and focus state of every focusable widget.
+    struct Record(Vec<(Option<widget::Id>, bool)>);
+
+    impl Operation<()> for Record {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&widget::Id>,
+            _bounds: Rectangle,
+        ) {
+            self.0.push((id.cloned(), state.is_focused()));
+        }
+    }
+
+    fn node(
+        element: &Element<'_, (), Theme, ()>,
+        tree: &mut Tree,
+    ) -> layout::Node {
+        element.as_widget().layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        )
+    }
+
+    fn perform(
+        element: &Element<'_, (), Theme, ()>,
+        tree: &mut Tree,
+        mut operation: Box<dyn Operation<()>>,
+    ) {
+        let node = node(element, tree);
+
+        loop {
+            element.as_widget().operate(
+                tree,
+                Layout::new(&node),
+                &(),
+                operation.as_mut(),
+            );
+
+            match operation.finish() {
+                operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn record(
+        element: &Element<'_, (), Theme, ()>,
+        tree: &mut Tree,
+    ) -> Vec<(Option<widget::Id>, bool)> {
+        let node = node(element, tree);
+        let mut record = Record(Vec::new());
+
+        element
+            .as_widget()
+            .operate(tree, Layout::new(&node), &(), &mut record);
+
+        record.0
+    }
+
+    /// Two instances of the same [`Form`] in a column.
+    fn forms() -> (Element<'static, (), Theme, ()>, Tree) {
+        let element: Element<'static, (), Theme, ()> =
+            Column::with_children(["first", "second"].map(|id| {
+                view(Form {
+                    id: widget::Id::new(id),
+                })
+            }))
+            .into();
+
+        let mut tree = Tree::new(&element);
+        tree.diff(&element);
+
+        (element, tree)
+    }
+
+    fn scoped(name: &'static str, form: &'static str) -> widget::Id {
+        widget::Id::new(name).within(widget::Id::new(form))
+    }
+
+    #[test]
+    fn focusing_one_instance_does_not_affect_the_other() {
+        let (element, mut tree) = forms();
+
+        perform(
+            &element,
+            &mut tree,
+            Box::new(operation::focusable::focus(scoped("name", "second"))),
+        );
+
+        assert_eq!(
+            record(&element, &mut tree),
+            vec![
+                (Some(scoped("name", "first")), false),
+                (Some(scoped("name", "second")), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn unscoped_id_does_not_match_any_instance() {
+        let (element, mut tree) = forms();
+
+        perform(
+            &element,
+            &mut tree,
+            Box::new(operation::focusable::focus(widget::Id::new("name"))),
+        );
+
+        assert!(record(&element, &mut tree)
+            .iter()
+            .all(|(_, is_focused)| !is_focused));
+    }
+
+    #[test]
+    fn focus_first_broadcasts_within_an_instance() {
+        let (element, mut tree) = forms();
+
+        perform(
+            &element,
+            &mut tree,
+            Box::new(operation::focusable::focus_first(widget::Id::new(
+                "first",
+            ))),
+        );
+
+        assert_eq!(
+            record(&element, &mut tree),
+            vec![
+                (Some(scoped("name", "first")), true),
+                (Some(scoped("name", "second")), false),
+            ]
+        );
+    }
+}
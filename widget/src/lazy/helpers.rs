@@ -1,6 +1,6 @@
 use crate::core::{self, Element, Size};
 use crate::lazy::component::{self, Component};
-use crate::lazy::{Lazy, Responsive};
+use crate::lazy::{Lazy, Responsive, Stateful};
 
 use std::hash::Hash;
 
@@ -34,6 +34,23 @@ where
     component::view(component)
 }
 
+/// Creates a new [`Stateful`] widget with the given initial `State`, an
+/// `update` function that processes its internal events, and a `view`
+/// function that produces its contents.
+///
+/// The `update` function may produce a `Message` for the parent.
+#[cfg(feature = "lazy")]
+pub fn stateful<'a, State, Event, Message, Theme, Renderer, View>(
+    state: State,
+    update: impl Fn(&mut State, Event) -> Option<Message> + 'a,
+    view: impl Fn(&State) -> View + 'a,
+) -> Stateful<'a, State, Event, Message, Theme, Renderer, View>
+where
+    View: Into<Element<'static, Event, Theme, Renderer>>,
+{
+    Stateful::new(state, update, view)
+}
+
 /// Creates a new [`Responsive`] widget with a closure that produces its
 /// contents.
 ///
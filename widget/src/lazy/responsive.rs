@@ -194,7 +194,8 @@ where
         let mut content = self.content.borrow_mut();
 
         let mut local_messages = vec![];
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         let status = content.resolve(
             &mut state.tree.borrow_mut(),
@@ -279,6 +280,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         use std::ops::DerefMut;
@@ -311,7 +313,13 @@ where
                 (
                     element
                         .as_widget_mut()
-                        .overlay(tree, content_layout, renderer, translation)
+                        .overlay(
+                            tree,
+                            content_layout,
+                            renderer,
+                            viewport,
+                            translation,
+                        )
                         .map(|overlay| RefCell::new(Nested::new(overlay))),
                     content_layout_node,
                 )
@@ -0,0 +1,587 @@
+//! Keep local state in the widget tree and only rebuild when necessary.
+use crate::core::event;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Widget};
+use crate::core::{
+    self, Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector,
+};
+use crate::runtime::overlay::Nested;
+
+use ouroboros::self_referencing;
+use rustc_hash::FxHasher;
+use std::cell::RefCell;
+use std::hash::{Hash, Hasher as H};
+use std::rc::Rc;
+
+/// A widget that owns some local `State` in the widget tree.
+///
+/// A [`Stateful`] widget produces its contents with a `view` function of its
+/// `State`. The contents of a [`Stateful`] widget produce internal events,
+/// which are processed by an `update` function that may change the `State`
+/// and, optionally, produce a `Message` for the parent.
+///
+/// The contents of a [`Stateful`] widget are only rebuilt when its `State`
+/// changes or, if a dependency is provided, when the dependency changes; just
+/// like [`Lazy`](crate::Lazy).
+///
+/// # Differences with Elm-style lifting
+/// In The Elm Architecture, the state of a reusable view lives in the state
+/// of the parent, which wraps its messages and forwards them to the `update`
+/// logic of the view. This keeps all the state of an application in a single
+/// place, but every parent needs to thread the state and messages of its
+/// children manually.
+///
+/// A [`Stateful`] widget keeps its `State` in the widget tree instead. The
+/// parent never sees its internal events, only the `Message`s it chooses to
+/// expose. As a consequence, the `State` is lost whenever the widget tree
+/// discards it—like when the [`Stateful`] widget is moved to a different
+/// position and is not keyed—and it cannot be inspected nor persisted by
+/// the application.
+#[allow(missing_debug_implementations)]
+pub struct Stateful<'a, State, Event, Message, Theme, Renderer, View> {
+    state: RefCell<Option<State>>,
+    dependency: Option<u64>,
+    update: Box<dyn Fn(&mut State, Event) -> Option<Message> + 'a>,
+    view: Box<dyn Fn(&State) -> View + 'a>,
+    element: RefCell<
+        Option<Rc<RefCell<Option<Element<'static, Event, Theme, Renderer>>>>>,
+    >,
+}
+
+impl<'a, State, Event, Message, Theme, Renderer, View>
+    Stateful<'a, State, Event, Message, Theme, Renderer, View>
+where
+    View: Into<Element<'static, Event, Theme, Renderer>>,
+{
+    /// Creates a new [`Stateful`] widget with the given initial `State`, an
+    /// `update` function, and a `view` function.
+    ///
+    /// The initial `State` is only used when the [`Stateful`] widget is first
+    /// added to the widget tree.
+    pub fn new(
+        state: State,
+        update: impl Fn(&mut State, Event) -> Option<Message> + 'a,
+        view: impl Fn(&State) -> View + 'a,
+    ) -> Self {
+        Self {
+            state: RefCell::new(Some(state)),
+            dependency: None,
+            update: Box::new(update),
+            view: Box::new(view),
+            element: RefCell::new(None),
+        }
+    }
+
+    /// Sets the data the `view` of the [`Stateful`] widget depends on
+    /// besides its `State`; normally, the inputs given by the parent.
+    ///
+    /// The contents will only be rebuilt when the `State` or the hash of the
+    /// dependency change. If no dependency is provided, the contents are
+    /// rebuilt every time the parent is.
+    pub fn dependency(mut self, dependency: impl Hash) -> Self {
+        let mut hasher = FxHasher::default();
+        dependency.hash(&mut hasher);
+
+        self.dependency = Some(hasher.finish());
+        self
+    }
+
+    fn with_element<T>(
+        &self,
+        f: impl FnOnce(&Element<'_, Event, Theme, Renderer>) -> T,
+    ) -> T {
+        f(self
+            .element
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .borrow()
+            .as_ref()
+            .unwrap())
+    }
+
+    fn with_element_mut<T>(
+        &self,
+        f: impl FnOnce(&mut Element<'_, Event, Theme, Renderer>) -> T,
+    ) -> T {
+        f(self
+            .element
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .borrow_mut()
+            .as_mut()
+            .unwrap())
+    }
+
+    fn rebuild(&self, tree: &mut Tree)
+    where
+        State: 'static,
+        Event: 'static,
+        Theme: 'static,
+        Renderer: core::Renderer + 'static,
+    {
+        let internal = tree
+            .state
+            .downcast_mut::<Internal<State, Event, Theme, Renderer>>();
+
+        internal.is_outdated = false;
+        internal.dependency = self.dependency;
+        internal.element =
+            Rc::new(RefCell::new(Some((self.view)(&internal.state).into())));
+
+        (*self.element.borrow_mut()) = Some(internal.element.clone());
+
+        self.with_element(|element| {
+            tree.diff_children(std::slice::from_ref(&element.as_widget()));
+        });
+    }
+}
+
+struct Internal<State, Event, Theme, Renderer> {
+    state: State,
+    dependency: Option<u64>,
+    is_outdated: bool,
+    element: Rc<RefCell<Option<Element<'static, Event, Theme, Renderer>>>>,
+}
+
+impl<'a, State, Event, Message, Theme, Renderer, View>
+    Widget<Message, Theme, Renderer>
+    for Stateful<'a, State, Event, Message, Theme, Renderer, View>
+where
+    State: 'static,
+    Event: 'static,
+    View: Into<Element<'static, Event, Theme, Renderer>>,
+    Message: 'static,
+    Theme: 'static,
+    Renderer: core::Renderer + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Internal<State, Event, Theme, Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let state = self
+            .state
+            .borrow_mut()
+            .take()
+            .expect("Stateful widget must only be added to the tree once");
+
+        let element = Rc::new(RefCell::new(Some((self.view)(&state).into())));
+
+        (*self.element.borrow_mut()) = Some(element.clone());
+
+        tree::State::new(Internal {
+            state,
+            dependency: self.dependency,
+            is_outdated: false,
+            element,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.with_element(|element| vec![Tree::new(element.as_widget())])
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let internal = tree
+            .state
+            .downcast_ref::<Internal<State, Event, Theme, Renderer>>();
+
+        if internal.is_outdated
+            || internal.dependency.is_none()
+            || internal.dependency != self.dependency
+        {
+            self.rebuild(tree);
+        } else {
+            (*self.element.borrow_mut()) = Some(internal.element.clone());
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.with_element(|element| element.as_widget().size())
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if tree
+            .state
+            .downcast_ref::<Internal<State, Event, Theme, Renderer>>()
+            .is_outdated
+        {
+            self.rebuild(tree);
+        }
+
+        self.with_element(|element| {
+            element
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, limits)
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<()>,
+    ) {
+        self.with_element(|element| {
+            element.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: core::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut events = Vec::new();
+        let mut local_shell =
+            Shell::new(&mut events).with_drag(shell.drag().cloned());
+
+        let status = self.with_element_mut(|element| {
+            element.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            )
+        });
+
+        merge(local_shell, shell);
+
+        let internal = tree
+            .state
+            .downcast_mut::<Internal<State, Event, Theme, Renderer>>();
+
+        update(
+            self.update.as_ref(),
+            &mut internal.state,
+            &mut internal.is_outdated,
+            events,
+            shell,
+        );
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.with_element(|element| {
+            element.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.with_element(|element| {
+            element.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let Internal {
+            state,
+            is_outdated,
+            element,
+            ..
+        } = state.downcast_mut::<Internal<State, Event, Theme, Renderer>>();
+
+        let overlay = Overlay {
+            inner: Some(
+                InnerBuilder {
+                    cell: element.clone(),
+                    element: element.borrow_mut().take().unwrap(),
+                    tree: &mut children[0],
+                    overlay_builder: |element, tree| {
+                        element
+                            .as_widget_mut()
+                            .overlay(
+                                tree,
+                                layout,
+                                renderer,
+                                viewport,
+                                translation,
+                            )
+                            .map(|overlay| RefCell::new(Nested::new(overlay)))
+                    },
+                }
+                .build(),
+            ),
+            state,
+            is_outdated,
+            update: self.update.as_ref(),
+        };
+
+        Some(overlay::Element::new(Box::new(overlay)))
+    }
+}
+
+/// Forwards the requests of the contents of a [`Stateful`] widget to the
+/// [`Shell`] of its parent.
+fn merge<Event, Message>(
+    mut local_shell: Shell<'_, Event>,
+    shell: &mut Shell<'_, Message>,
+) {
+    local_shell.revalidate_layout(|| shell.invalidate_layout());
+
+    if let Some(redraw_request) = local_shell.redraw_request() {
+        shell.request_redraw(redraw_request);
+    }
+
+    if let Some(mode) = local_shell.input_mode() {
+        shell.request_input_mode(mode);
+    }
+
+    if local_shell.are_widgets_invalid() {
+        shell.invalidate_widgets();
+    }
+
+    if local_shell.is_drag_changed() {
+        match local_shell.drag() {
+            Some(payload) => shell.start_drag(payload.clone()),
+            None => shell.end_drag(),
+        }
+    }
+}
+
+/// Processes the internal events of a [`Stateful`] widget, publishing the
+/// resulting messages and marking its contents as outdated.
+fn update<State, Event, Message>(
+    update: &dyn Fn(&mut State, Event) -> Option<Message>,
+    state: &mut State,
+    is_outdated: &mut bool,
+    events: Vec<Event>,
+    shell: &mut Shell<'_, Message>,
+) {
+    if events.is_empty() {
+        return;
+    }
+
+    for message in events.into_iter().filter_map(|event| update(state, event)) {
+        shell.publish(message);
+    }
+
+    *is_outdated = true;
+    shell.invalidate_layout();
+}
+
+#[self_referencing]
+struct Inner<'a, Event: 'a, Theme: 'a, Renderer: 'a> {
+    cell: Rc<RefCell<Option<Element<'static, Event, Theme, Renderer>>>>,
+    element: Element<'static, Event, Theme, Renderer>,
+    tree: &'a mut Tree,
+
+    #[borrows(mut element, mut tree)]
+    #[not_covariant]
+    overlay: Option<RefCell<Nested<'this, Event, Theme, Renderer>>>,
+}
+
+struct Overlay<'a, State, Event, Message, Theme, Renderer> {
+    inner: Option<Inner<'a, Event, Theme, Renderer>>,
+    state: &'a mut State,
+    is_outdated: &'a mut bool,
+    update: &'a dyn Fn(&mut State, Event) -> Option<Message>,
+}
+
+impl<'a, State, Event, Message, Theme, Renderer> Drop
+    for Overlay<'a, State, Event, Message, Theme, Renderer>
+{
+    fn drop(&mut self) {
+        let heads = self.inner.take().unwrap().into_heads();
+        (*heads.cell.borrow_mut()) = Some(heads.element);
+    }
+}
+
+impl<'a, State, Event, Message, Theme, Renderer>
+    Overlay<'a, State, Event, Message, Theme, Renderer>
+{
+    fn with_overlay_maybe<T>(
+        &self,
+        f: impl FnOnce(&mut Nested<'_, Event, Theme, Renderer>) -> T,
+    ) -> Option<T> {
+        self.inner.as_ref().unwrap().with_overlay(|overlay| {
+            overlay.as_ref().map(|nested| (f)(&mut nested.borrow_mut()))
+        })
+    }
+
+    fn with_overlay_mut_maybe<T>(
+        &mut self,
+        f: impl FnOnce(&mut Nested<'_, Event, Theme, Renderer>) -> T,
+    ) -> Option<T> {
+        self.inner.as_mut().unwrap().with_overlay_mut(|overlay| {
+            overlay.as_mut().map(|nested| (f)(nested.get_mut()))
+        })
+    }
+}
+
+impl<'a, State, Event, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, State, Event, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.with_overlay_maybe(|overlay| overlay.layout(renderer, bounds))
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let _ = self.with_overlay_maybe(|overlay| {
+            overlay.draw(renderer, theme, style, layout, cursor);
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.with_overlay_maybe(|overlay| {
+            overlay.mouse_interaction(layout, cursor, viewport, renderer)
+        })
+        .unwrap_or_default()
+    }
+
+    fn on_event(
+        &mut self,
+        event: core::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut events = Vec::new();
+        let mut local_shell =
+            Shell::new(&mut events).with_drag(shell.drag().cloned());
+
+        let status = self
+            .with_overlay_mut_maybe(|overlay| {
+                overlay.on_event(
+                    event,
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut local_shell,
+                )
+            })
+            .unwrap_or(event::Status::Ignored);
+
+        merge(local_shell, shell);
+
+        update(self.update, self.state, self.is_outdated, events, shell);
+
+        status
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        self.with_overlay_maybe(|overlay| {
+            overlay.is_over(layout, renderer, cursor_position)
+        })
+        .unwrap_or_default()
+    }
+}
+
+impl<'a, State, Event, Message, Theme, Renderer, View>
+    From<Stateful<'a, State, Event, Message, Theme, Renderer, View>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    State: 'static,
+    Event: 'static,
+    View: Into<Element<'static, Event, Theme, Renderer>> + 'a,
+    Message: 'static,
+    Theme: 'static,
+    Renderer: core::Renderer + 'static,
+{
+    fn from(
+        stateful: Stateful<'a, State, Event, Message, Theme, Renderer, View>,
+    ) -> Self {
+        Self::new(stateful)
+    }
+}
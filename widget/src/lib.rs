@@ -10,30 +10,43 @@ pub use iced_runtime::core;
 
 mod column;
 mod mouse_area;
-mod row;
 mod space;
 mod stack;
 mod themer;
 
 pub mod button;
 pub mod checkbox;
+pub mod circular_progress;
 pub mod combo_box;
 pub mod container;
+pub mod drag_source;
+pub mod drop_target;
+pub mod easing;
+pub mod form;
+pub mod grid;
 pub mod keyed;
+pub mod linear_progress;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod row;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod swipeable;
+pub mod tab_bar;
+pub mod table;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod transform;
 pub mod vertical_slider;
+pub mod virtual_list;
+pub mod zoom_area;
 
 mod helpers;
 
@@ -43,22 +56,39 @@ pub use helpers::*;
 mod lazy;
 
 #[cfg(feature = "lazy")]
-pub use crate::lazy::{Component, Lazy, Responsive};
+pub use crate::lazy::{Component, Lazy, Responsive, Stateful};
 
 #[cfg(feature = "lazy")]
 pub use crate::lazy::helpers::*;
 
+#[cfg(feature = "lazy")]
+pub mod number_input;
+
+#[cfg(feature = "lazy")]
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use circular_progress::CircularProgress;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use drag_source::DragSource;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use linear_progress::LinearProgress;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
@@ -81,6 +111,12 @@ pub use space::Space;
 #[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use swipeable::Swipeable;
+#[doc(no_inline)]
+pub use tab_bar::TabBar;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -93,7 +129,13 @@ pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use transform::Transform;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
+#[doc(no_inline)]
+pub use zoom_area::ZoomArea;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
@@ -123,6 +165,13 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "canvas")]
+pub mod clip_path;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use clip_path::ClipPath;
+
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 
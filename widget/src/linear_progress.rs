@@ -0,0 +1,463 @@
+//! Show a linear progress indicator.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::settings::Motion;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window::{self, RedrawRequest};
+use crate::core::{
+    self, Clipboard, Color, Element, Layout, Length, Rectangle, Shell, Size,
+    Theme, Widget,
+};
+use crate::easing::{self, Easing, Transition};
+
+/// The interval between redraws while the indicator is out of sight.
+const HIDDEN_REDRAW_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A linear progress indicator.
+///
+/// By default, it is indeterminate: its bar sweeps across the track
+/// following an [`Easing`]. Set its [`progress`] to make it determinate
+/// instead.
+///
+/// The indeterminate bar follows the current [`Motion`] policy: it fills the
+/// track and pulses when motion is reduced, and stays still when there is no
+/// motion at all.
+///
+/// # Example
+/// ```no_run
+/// # type LinearProgress<'a> = iced_widget::LinearProgress<'a>;
+/// #
+/// let loading = LinearProgress::new().width(200);
+/// let uploading = LinearProgress::new().progress(0.75);
+/// ```
+///
+/// [`progress`]: Self::progress
+#[allow(missing_debug_implementations)]
+pub struct LinearProgress<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    width: Length,
+    height: Length,
+    progress: Option<f32>,
+    progress_duration: Duration,
+    easing: &'a Easing,
+    cycle_duration: Duration,
+    redraw_interval: Option<Duration>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> LinearProgress<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default width of a [`LinearProgress`].
+    pub const DEFAULT_WIDTH: f32 = 100.0;
+
+    /// The default height of a [`LinearProgress`].
+    pub const DEFAULT_HEIGHT: f32 = 4.0;
+
+    /// Creates a new indeterminate [`LinearProgress`].
+    pub fn new() -> Self {
+        LinearProgress {
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            progress: None,
+            progress_duration: Duration::ZERO,
+            easing: &easing::STANDARD,
+            cycle_duration: Duration::from_millis(600),
+            redraw_interval: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`LinearProgress`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`LinearProgress`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Makes the [`LinearProgress`] determinate, showing the given progress
+    /// between `0.0` and `1.0`.
+    ///
+    /// A determinate [`LinearProgress`] does not sweep. Setting the progress
+    /// back to `None` makes it indeterminate again.
+    pub fn progress(mut self, progress: impl Into<Option<f32>>) -> Self {
+        self.progress =
+            progress.into().map(|progress| progress.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the duration of the transition of the bar of a determinate
+    /// [`LinearProgress`] towards a new progress, following its [`Easing`].
+    ///
+    /// By default, changes in progress are shown right away.
+    pub fn progress_duration(mut self, duration: Duration) -> Self {
+        self.progress_duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] of the animation of the [`LinearProgress`].
+    pub fn easing(mut self, easing: &'a Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the duration of a full cycle of the [`LinearProgress`]; that
+    /// is, the time the bar takes to expand and contract once.
+    pub fn cycle_duration(mut self, duration: Duration) -> Self {
+        self.cycle_duration = duration / 2;
+        self
+    }
+
+    /// Sets the minimum interval between redraws of the [`LinearProgress`].
+    ///
+    /// By default, the [`LinearProgress`] redraws every frame while visible.
+    pub fn redraw_interval(mut self, interval: Option<Duration>) -> Self {
+        self.redraw_interval = interval;
+        self
+    }
+
+    /// Sets the style of the [`LinearProgress`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`LinearProgress`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Theme> Default for LinearProgress<'a, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    animation: Animation,
+    progress: Option<Transition>,
+    started: Instant,
+    opacity: f32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            animation: Animation::default(),
+            progress: None,
+            started: Instant::now(),
+            opacity: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Animation {
+    Expanding { start: Instant, progress: f32 },
+    Contracting { start: Instant, progress: f32 },
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self::Expanding {
+            start: Instant::now(),
+            progress: 0.0,
+        }
+    }
+}
+
+impl Animation {
+    fn next(&self, now: Instant) -> Self {
+        match self {
+            Self::Expanding { .. } => Self::Contracting {
+                start: now,
+                progress: 0.0,
+            },
+            Self::Contracting { .. } => Self::Expanding {
+                start: now,
+                progress: 0.0,
+            },
+        }
+    }
+
+    fn start(&self) -> Instant {
+        match self {
+            Self::Expanding { start, .. } | Self::Contracting { start, .. } => {
+                *start
+            }
+        }
+    }
+
+    fn timed_transition(&self, cycle_duration: Duration, now: Instant) -> Self {
+        let elapsed = now.duration_since(self.start());
+
+        if elapsed > cycle_duration {
+            self.next(now)
+        } else {
+            self.with_elapsed(cycle_duration, elapsed)
+        }
+    }
+
+    fn with_elapsed(
+        &self,
+        cycle_duration: Duration,
+        elapsed: Duration,
+    ) -> Self {
+        let progress = elapsed.as_secs_f32() / cycle_duration.as_secs_f32();
+
+        match self {
+            Self::Expanding { start, .. } => Self::Expanding {
+                start: *start,
+                progress,
+            },
+            Self::Contracting { start, .. } => Self::Contracting {
+                start: *start,
+                progress,
+            },
+        }
+    }
+
+    /// Returns the start and end of the bar, as fractions of the track.
+    fn span(&self, easing: &Easing) -> (f32, f32) {
+        match self {
+            Self::Expanding { progress, .. } => (0.0, easing.y_at_x(*progress)),
+            Self::Contracting { progress, .. } => {
+                (easing.y_at_x(*progress), 1.0)
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for LinearProgress<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            progress: self.progress.map(Transition::new),
+            ..State::default()
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match (self.progress, &mut state.progress) {
+            (Some(progress), Some(transition)) => {
+                transition.go_to(progress, self.progress_duration);
+            }
+            (Some(progress), None) => {
+                state.progress = Some(Transition::new(progress));
+            }
+            (None, Some(_)) => {
+                // Restart the cycle, since it was frozen while determinate
+                *state = State::default();
+            }
+            (None, None) => {}
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Some(transition) = &mut state.progress {
+                if !transition.update(self.easing, self.progress_duration, now)
+                {
+                    return event::Status::Ignored;
+                }
+            } else {
+                match Motion::current() {
+                    Motion::Full => {
+                        state.animation = state
+                            .animation
+                            .timed_transition(self.cycle_duration, now);
+                    }
+                    Motion::Reduced => {
+                        state.opacity = easing::pulse(state.started, now);
+                    }
+                    Motion::None => return event::Status::Ignored,
+                }
+            }
+
+            shell.request_redraw(if !layout.bounds().intersects(viewport) {
+                RedrawRequest::At(now + HIDDEN_REDRAW_INTERVAL)
+            } else if let Some(interval) = self.redraw_interval {
+                RedrawRequest::At(now + interval)
+            } else {
+                RedrawRequest::NextFrame
+            });
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let state = tree.state.downcast_ref::<State>();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.track_color,
+        );
+
+        let (start, end, opacity) = match &state.progress {
+            Some(transition) => (0.0, transition.value(), 1.0),
+            None => match Motion::current() {
+                Motion::Full => {
+                    let (start, end) = state.animation.span(self.easing);
+
+                    (start, end, 1.0)
+                }
+                Motion::Reduced => (0.0, 1.0, state.opacity),
+                Motion::None => (0.0, 1.0, easing::STILL_OPACITY),
+            },
+        };
+
+        if end - start <= f32::EPSILON {
+            return;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + start * bounds.width,
+                    width: (end - start) * bounds.width,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            style.bar_color.scale_alpha(opacity),
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LinearProgress<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + core::Renderer,
+{
+    fn from(
+        linear_progress: LinearProgress<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(linear_progress)
+    }
+}
+
+/// The appearance of a linear progress indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the track of the progress indicator.
+    pub track_color: Color,
+    /// The [`Color`] of the bar of the progress indicator.
+    pub bar_color: Color,
+}
+
+/// The theme catalog of a [`LinearProgress`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`LinearProgress`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`LinearProgress`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        track_color: palette.background.weak.color,
+        bar_color: palette.primary.base.color,
+    }
+}
@@ -4,12 +4,22 @@
 //! in code blocks.
 //!
 //! Only the variants of [`Item`] are currently supported.
+use crate::core::alignment;
 use crate::core::font::{self, Font};
 use crate::core::padding;
 use crate::core::theme::{self, Theme};
+use crate::core::time::Duration;
+use crate::core::widget;
 use crate::core::{self, Element, Length, Pixels};
 use crate::{column, container, rich_text, row, scrollable, span, text};
 
+use rustc_hash::FxHashMap;
+use std::ops::Range;
+
+mod lazy;
+
+use lazy::Lazy;
+
 pub use pulldown_cmark::HeadingLevel;
 pub use url::Url;
 
@@ -31,6 +41,19 @@ pub enum Item {
         /// The items of the list.
         items: Vec<Vec<Item>>,
     },
+    /// An image.
+    ///
+    /// Images are laid out as blocks. You can display them by providing
+    /// a resolver to [`view_with_images`]; otherwise, their alternative
+    /// text will be shown.
+    Image {
+        /// The URL of the image, exactly as written in the Markdown source.
+        ///
+        /// It may be a relative path.
+        url: String,
+        /// The alternative text of the image.
+        alt: String,
+    },
 }
 
 /// Parse the given Markdown content.
@@ -38,248 +61,701 @@ pub fn parse(
     markdown: &str,
     palette: theme::Palette,
 ) -> impl Iterator<Item = Item> + '_ {
-    struct List {
-        start: Option<u64>,
-        items: Vec<Vec<Item>>,
-    }
+    let mut parser = Parser::new(palette);
 
-    let mut spans = Vec::new();
-    let mut strong = false;
-    let mut emphasis = false;
-    let mut metadata = false;
-    let mut table = false;
-    let mut link = None;
-    let mut lists = Vec::new();
+    pulldown_cmark::Parser::new_ext(markdown, OPTIONS)
+        .filter_map(move |event| parser.event(event))
+}
 
+/// The Markdown extensions enabled when parsing.
+const OPTIONS: pulldown_cmark::Options =
+    pulldown_cmark::Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
+        .union(pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS)
+        .union(pulldown_cmark::Options::ENABLE_TABLES);
+
+/// Turns Markdown events into [`Item`]s.
+struct Parser {
+    palette: theme::Palette,
+    spans: Vec<text::Span<'static, Url>>,
+    strong: bool,
+    emphasis: bool,
+    metadata: bool,
+    table: bool,
+    link: Option<Url>,
+    image: Option<(String, String)>,
+    lists: Vec<List>,
     #[cfg(feature = "highlighter")]
-    let mut highlighter = None;
+    highlighter: Option<iced_highlighter::Highlighter>,
+}
 
-    let parser = pulldown_cmark::Parser::new_ext(
-        markdown,
-        pulldown_cmark::Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
-            | pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
-            | pulldown_cmark::Options::ENABLE_TABLES,
-    );
+struct List {
+    start: Option<u64>,
+    items: Vec<Vec<Item>>,
+}
 
-    let produce = |lists: &mut Vec<List>, item| {
-        if lists.is_empty() {
-            Some(item)
-        } else {
-            lists
-                .last_mut()
-                .expect("list context")
-                .items
-                .last_mut()
-                .expect("item context")
-                .push(item);
-
-            None
+impl Parser {
+    fn new(palette: theme::Palette) -> Self {
+        Self {
+            palette,
+            spans: Vec::new(),
+            strong: false,
+            emphasis: false,
+            metadata: false,
+            table: false,
+            link: None,
+            image: None,
+            lists: Vec::new(),
+            #[cfg(feature = "highlighter")]
+            highlighter: None,
         }
-    };
+    }
 
     // We want to keep the `spans` capacity
     #[allow(clippy::drain_collect)]
-    parser.filter_map(move |event| match event {
-        pulldown_cmark::Event::Start(tag) => match tag {
-            pulldown_cmark::Tag::Strong if !metadata && !table => {
-                strong = true;
-                None
-            }
-            pulldown_cmark::Tag::Emphasis if !metadata && !table => {
-                emphasis = true;
-                None
-            }
-            pulldown_cmark::Tag::Link { dest_url, .. }
-                if !metadata && !table =>
-            {
-                match Url::parse(&dest_url) {
-                    Ok(url)
-                        if url.scheme() == "http"
-                            || url.scheme() == "https" =>
-                    {
-                        link = Some(url);
-                    }
-                    _ => {}
+    fn event(&mut self, event: pulldown_cmark::Event<'_>) -> Option<Item> {
+        let metadata = self.metadata;
+        let table = self.table;
+
+        match event {
+            pulldown_cmark::Event::Start(tag) => match tag {
+                pulldown_cmark::Tag::Strong if !metadata && !table => {
+                    self.strong = true;
+                    None
+                }
+                pulldown_cmark::Tag::Emphasis if !metadata && !table => {
+                    self.emphasis = true;
+                    None
                 }
+                pulldown_cmark::Tag::Link { dest_url, .. }
+                    if !metadata && !table =>
+                {
+                    if dest_url.starts_with('#') {
+                        self.link =
+                            Url::parse(&format!("{DOCUMENT}{dest_url}")).ok();
+                    } else {
+                        match Url::parse(&dest_url) {
+                            Ok(url)
+                                if url.scheme() == "http"
+                                    || url.scheme() == "https" =>
+                            {
+                                self.link = Some(url);
+                            }
+                            _ => {}
+                        }
+                    }
 
-                None
-            }
-            pulldown_cmark::Tag::List(first_item) if !metadata && !table => {
-                lists.push(List {
-                    start: first_item,
-                    items: Vec::new(),
-                });
+                    None
+                }
+                pulldown_cmark::Tag::Image { dest_url, .. }
+                    if !metadata && !table =>
+                {
+                    self.image = Some((dest_url.into_string(), String::new()));
 
-                None
-            }
-            pulldown_cmark::Tag::Item => {
-                lists
-                    .last_mut()
-                    .expect("list context")
-                    .items
-                    .push(Vec::new());
-                None
-            }
-            pulldown_cmark::Tag::CodeBlock(
-                pulldown_cmark::CodeBlockKind::Fenced(_language),
-            ) if !metadata && !table => {
-                #[cfg(feature = "highlighter")]
+                    // Images are blocks, so we break any ongoing paragraph
+                    if self.spans.is_empty() {
+                        None
+                    } else {
+                        produce(
+                            &mut self.lists,
+                            Item::Paragraph(self.spans.drain(..).collect()),
+                        )
+                    }
+                }
+                pulldown_cmark::Tag::List(first_item)
+                    if !metadata && !table =>
                 {
-                    use iced_highlighter::{self, Highlighter};
-                    use text::Highlighter as _;
+                    self.lists.push(List {
+                        start: first_item,
+                        items: Vec::new(),
+                    });
 
-                    highlighter =
-                        Some(Highlighter::new(&iced_highlighter::Settings {
-                            theme: iced_highlighter::Theme::Base16Ocean,
-                            token: _language.to_string(),
-                        }));
+                    None
+                }
+                pulldown_cmark::Tag::Item => {
+                    self.lists
+                        .last_mut()
+                        .expect("list context")
+                        .items
+                        .push(Vec::new());
+                    None
                 }
+                pulldown_cmark::Tag::CodeBlock(
+                    pulldown_cmark::CodeBlockKind::Fenced(_language),
+                ) if !metadata && !table => {
+                    #[cfg(feature = "highlighter")]
+                    {
+                        use iced_highlighter::{self, Highlighter};
+                        use text::Highlighter as _;
+
+                        self.highlighter = Some(Highlighter::new(
+                            &iced_highlighter::Settings {
+                                theme: iced_highlighter::Theme::Base16Ocean,
+                                token: _language.to_string(),
+                            },
+                        ));
+                    }
 
-                None
-            }
-            pulldown_cmark::Tag::MetadataBlock(_) => {
-                metadata = true;
-                None
-            }
-            pulldown_cmark::Tag::Table(_) => {
-                table = true;
-                None
-            }
-            _ => None,
-        },
-        pulldown_cmark::Event::End(tag) => match tag {
-            pulldown_cmark::TagEnd::Heading(level) if !metadata && !table => {
-                produce(
-                    &mut lists,
-                    Item::Heading(level, spans.drain(..).collect()),
-                )
-            }
-            pulldown_cmark::TagEnd::Emphasis if !metadata && !table => {
-                emphasis = false;
-                None
-            }
-            pulldown_cmark::TagEnd::Strong if !metadata && !table => {
-                strong = false;
-                None
-            }
-            pulldown_cmark::TagEnd::Link if !metadata && !table => {
-                link = None;
-                None
-            }
-            pulldown_cmark::TagEnd::Paragraph if !metadata && !table => {
-                produce(&mut lists, Item::Paragraph(spans.drain(..).collect()))
-            }
-            pulldown_cmark::TagEnd::Item if !metadata && !table => {
-                if spans.is_empty() {
                     None
-                } else {
+                }
+                pulldown_cmark::Tag::MetadataBlock(_) => {
+                    self.metadata = true;
+                    None
+                }
+                pulldown_cmark::Tag::Table(_) => {
+                    self.table = true;
+                    None
+                }
+                _ => None,
+            },
+            pulldown_cmark::Event::End(tag) => match tag {
+                pulldown_cmark::TagEnd::Heading(level)
+                    if !metadata && !table =>
+                {
                     produce(
-                        &mut lists,
-                        Item::Paragraph(spans.drain(..).collect()),
+                        &mut self.lists,
+                        Item::Heading(level, self.spans.drain(..).collect()),
                     )
                 }
-            }
-            pulldown_cmark::TagEnd::List(_) if !metadata && !table => {
-                let list = lists.pop().expect("list context");
-
-                produce(
-                    &mut lists,
-                    Item::List {
-                        start: list.start,
-                        items: list.items,
-                    },
-                )
-            }
-            pulldown_cmark::TagEnd::CodeBlock if !metadata && !table => {
-                #[cfg(feature = "highlighter")]
+                pulldown_cmark::TagEnd::Emphasis if !metadata && !table => {
+                    self.emphasis = false;
+                    None
+                }
+                pulldown_cmark::TagEnd::Strong if !metadata && !table => {
+                    self.strong = false;
+                    None
+                }
+                pulldown_cmark::TagEnd::Link if !metadata && !table => {
+                    self.link = None;
+                    None
+                }
+                pulldown_cmark::TagEnd::Image if !metadata && !table => {
+                    let (url, alt) = self.image.take()?;
+
+                    produce(&mut self.lists, Item::Image { url, alt })
+                }
+                pulldown_cmark::TagEnd::Paragraph
+                | pulldown_cmark::TagEnd::Item
+                    if !metadata && !table =>
                 {
-                    highlighter = None;
+                    if self.spans.is_empty() {
+                        None
+                    } else {
+                        produce(
+                            &mut self.lists,
+                            Item::Paragraph(self.spans.drain(..).collect()),
+                        )
+                    }
+                }
+                pulldown_cmark::TagEnd::List(_) if !metadata && !table => {
+                    let list = self.lists.pop().expect("list context");
+
+                    produce(
+                        &mut self.lists,
+                        Item::List {
+                            start: list.start,
+                            items: list.items,
+                        },
+                    )
+                }
+                pulldown_cmark::TagEnd::CodeBlock if !metadata && !table => {
+                    #[cfg(feature = "highlighter")]
+                    {
+                        self.highlighter = None;
+                    }
+
+                    produce(
+                        &mut self.lists,
+                        Item::CodeBlock(self.spans.drain(..).collect()),
+                    )
+                }
+                pulldown_cmark::TagEnd::MetadataBlock(_) => {
+                    self.metadata = false;
+                    None
+                }
+                pulldown_cmark::TagEnd::Table => {
+                    self.table = false;
+                    None
+                }
+                _ => None,
+            },
+            pulldown_cmark::Event::Text(text) if !metadata && !table => {
+                if let Some((_url, alt)) = &mut self.image {
+                    alt.push_str(&text);
+
+                    return None;
+                }
+
+                #[cfg(feature = "highlighter")]
+                if let Some(highlighter) = &mut self.highlighter {
+                    use text::Highlighter as _;
+
+                    for (range, highlight) in
+                        highlighter.highlight_line(text.as_ref())
+                    {
+                        let span = span(text[range].to_owned())
+                            .color_maybe(highlight.color())
+                            .font_maybe(highlight.font());
+
+                        self.spans.push(span);
+                    }
+
+                    return None;
+                }
+
+                let span = span(text.into_string());
+
+                let span = if self.strong || self.emphasis {
+                    span.font(Font {
+                        weight: if self.strong {
+                            font::Weight::Bold
+                        } else {
+                            font::Weight::Normal
+                        },
+                        style: if self.emphasis {
+                            font::Style::Italic
+                        } else {
+                            font::Style::Normal
+                        },
+                        ..Font::default()
+                    })
+                } else {
+                    span
+                };
+
+                let span = if let Some(link) = self.link.as_ref() {
+                    span.color(self.palette.primary).link(link.clone())
+                } else {
+                    span
+                };
+
+                self.spans.push(span);
+
+                None
+            }
+            pulldown_cmark::Event::Code(code) if !metadata && !table => {
+                if let Some((_url, alt)) = &mut self.image {
+                    alt.push_str(&code);
+
+                    return None;
                 }
 
-                produce(&mut lists, Item::CodeBlock(spans.drain(..).collect()))
+                let span = span(code.into_string()).font(Font::MONOSPACE);
+
+                let span = if let Some(link) = self.link.as_ref() {
+                    span.color(self.palette.primary).link(link.clone())
+                } else {
+                    span
+                };
+
+                self.spans.push(span);
+                None
             }
-            pulldown_cmark::TagEnd::MetadataBlock(_) => {
-                metadata = false;
+            pulldown_cmark::Event::SoftBreak if !metadata && !table => {
+                self.spans.push(span(" "));
                 None
             }
-            pulldown_cmark::TagEnd::Table => {
-                table = false;
+            pulldown_cmark::Event::HardBreak if !metadata && !table => {
+                self.spans.push(span("\n"));
                 None
             }
             _ => None,
-        },
-        pulldown_cmark::Event::Text(text) if !metadata && !table => {
-            #[cfg(feature = "highlighter")]
-            if let Some(highlighter) = &mut highlighter {
-                use text::Highlighter as _;
+        }
+    }
+}
 
-                for (range, highlight) in
-                    highlighter.highlight_line(text.as_ref())
-                {
-                    let span = span(text[range].to_owned())
-                        .color_maybe(highlight.color())
-                        .font_maybe(highlight.font());
+fn produce(lists: &mut [List], item: Item) -> Option<Item> {
+    if let Some(list) = lists.last_mut() {
+        list.items.last_mut().expect("item context").push(item);
 
-                    spans.push(span);
-                }
+        None
+    } else {
+        Some(item)
+    }
+}
 
-                return None;
-            }
+/// The base of the [`Url`] of in-document links, like `[Usage](#usage)`.
+const DOCUMENT: &str = "about:blank";
 
-            let span = span(text.into_string());
+/// Returns the anchor an in-document link points to, if the [`Url`] is one.
+///
+/// The anchor of a heading can be scrolled to with [`scroll_to_anchor`].
+pub fn anchor(url: &Url) -> Option<&str> {
+    if url.as_str().starts_with(DOCUMENT) {
+        url.fragment()
+    } else {
+        None
+    }
+}
 
-            let span = if strong || emphasis {
-                span.font(Font {
-                    weight: if strong {
-                        font::Weight::Bold
-                    } else {
-                        font::Weight::Normal
-                    },
-                    style: if emphasis {
-                        font::Style::Italic
-                    } else {
-                        font::Style::Normal
-                    },
-                    ..Font::default()
-                })
-            } else {
-                span
-            };
+/// Parsed Markdown that can be updated incrementally.
+///
+/// Updating a [`Content`] only parses the top-level blocks that changed—like
+/// an edited paragraph or the sections appended to a growing document—and
+/// keeps the [`Item`]s of the rest.
+///
+/// A [`Content`] can be displayed with [`view_content`].
+#[derive(Debug, Clone)]
+pub struct Content {
+    source: String,
+    palette: theme::Palette,
+    items: Vec<Item>,
+    blocks: Vec<Block>,
+    references: References,
+    anchors: Vec<(usize, String)>,
+}
 
-            let span = if let Some(link) = link.as_ref() {
-                span.color(palette.primary).link(link.clone())
-            } else {
-                span
-            };
+/// A top-level block of Markdown.
+#[derive(Debug, Clone, PartialEq)]
+struct Block {
+    /// The range of the block in the source.
+    range: Range<usize>,
+    /// The amount of [`Item`]s produced by the block.
+    items: usize,
+}
+
+/// The link reference definitions of a document; by normalized label.
+type References = FxHashMap<String, (String, String)>;
 
-            spans.push(span);
+impl Content {
+    /// Parses some Markdown into a new [`Content`].
+    pub fn parse(markdown: &str, palette: theme::Palette) -> Self {
+        let mut content = Self {
+            source: String::new(),
+            palette,
+            items: Vec::new(),
+            blocks: Vec::new(),
+            references: References::default(),
+            anchors: Vec::new(),
+        };
+
+        content.update(markdown);
+        content
+    }
 
-            None
+    /// Returns the Markdown source of the [`Content`].
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the parsed [`Item`]s of the [`Content`].
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Returns the anchor of every top-level heading of the [`Content`],
+    /// along with the index of its [`Item`].
+    ///
+    /// Anchors are derived from the text of each heading, like GitHub does.
+    pub fn anchors(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.anchors
+            .iter()
+            .map(|(index, anchor)| (*index, anchor.as_str()))
+    }
+
+    /// Replaces the Markdown of the [`Content`]; parsing only the blocks
+    /// that changed.
+    pub fn update(&mut self, markdown: &str) {
+        let prefix = self
+            .source
+            .bytes()
+            .zip(markdown.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        if prefix == markdown.len() && prefix == self.source.len() {
+            return;
         }
-        pulldown_cmark::Event::Code(code) if !metadata && !table => {
-            let span = span(code.into_string()).font(Font::MONOSPACE);
 
-            let span = if let Some(link) = link.as_ref() {
-                span.color(palette.primary).link(link.clone())
-            } else {
-                span
-            };
+        let mut suffix = self
+            .source
+            .bytes()
+            .rev()
+            .zip(markdown.bytes().rev())
+            .take(self.source.len().min(markdown.len()) - prefix)
+            .take_while(|(a, b)| a == b)
+            .count();
 
-            spans.push(span);
-            None
+        // The suffix is sliced; so it must not start in the middle of a
+        // character
+        while !markdown.is_char_boundary(markdown.len() - suffix) {
+            suffix -= 1;
         }
-        pulldown_cmark::Event::SoftBreak if !metadata && !table => {
-            spans.push(span(" "));
-            None
+
+        self.reparse(markdown.to_owned(), prefix, suffix);
+    }
+
+    /// Appends some Markdown to the [`Content`]; parsing only the last
+    /// block and the new ones.
+    pub fn push_str(&mut self, markdown: &str) {
+        if markdown.is_empty() {
+            return;
         }
-        pulldown_cmark::Event::HardBreak if !metadata && !table => {
-            spans.push(span("\n"));
-            None
+
+        let prefix = self.source.len();
+        let source = self.source.clone() + markdown;
+
+        self.reparse(source, prefix, 0);
+    }
+
+    /// Parses the blocks of the new `source` that may have changed; given
+    /// the lengths of the `prefix` and `suffix` it shares with the current
+    /// one.
+    fn reparse(&mut self, source: String, prefix: usize, suffix: usize) {
+        let old_end = self.source.len() - suffix;
+        let new_end = source.len() - suffix;
+        let delta = source.len() as isize - self.source.len() as isize;
+
+        // The block before the edit may change too; for instance, when the
+        // blank line that separates them is deleted
+        let first = self
+            .blocks
+            .partition_point(|block| block.range.end < prefix)
+            .saturating_sub(1);
+
+        // Blocks are parsed from the start of their line, so any indentation
+        // is kept
+        let start = if first == 0 {
+            0
+        } else {
+            let block = self.blocks[first].range.start;
+
+            self.source[..block].rfind('\n').map_or(0, |i| i + 1)
+        };
+
+        // The link references of a document are used by all of its blocks;
+        // so the whole document is parsed again when one may have changed
+        let is_full = start == 0
+            || has_reference_definition(&self.source[start..old_end])
+            || has_reference_definition(&source[start..new_end]);
+
+        let parsed = if is_full {
+            parse_blocks(&source, 0, self.palette, None, |_| None)
+        } else {
+            let blocks = &self.blocks[first..];
+
+            // Once a top-level block starts where an old one started after
+            // the edit, the rest of the document is unchanged
+            parse_blocks(
+                &source,
+                start,
+                self.palette,
+                Some(&self.references),
+                |offset| {
+                    if offset <= new_end {
+                        return None;
+                    }
+
+                    let old = usize::try_from(offset as isize - delta).ok()?;
+
+                    blocks
+                        .binary_search_by_key(&old, |block| block.range.start)
+                        .ok()
+                        .map(|index| first + index)
+                },
+            )
+        };
+
+        let first = if is_full { 0 } else { first };
+        let last = parsed.resync.unwrap_or(self.blocks.len());
+
+        let items_start: usize =
+            self.blocks[..first].iter().map(|block| block.items).sum();
+
+        let items_end = items_start
+            + self.blocks[first..last]
+                .iter()
+                .map(|block| block.items)
+                .sum::<usize>();
+
+        let parsed_blocks = parsed.blocks.len();
+
+        let _ = self.items.splice(items_start..items_end, parsed.items);
+        let _ = self.blocks.splice(first..last, parsed.blocks);
+
+        for block in &mut self.blocks[first + parsed_blocks..] {
+            block.range =
+                shift(block.range.start, delta)..shift(block.range.end, delta);
+        }
+
+        if let Some(references) = parsed.references {
+            self.references = references;
+        }
+
+        self.source = source;
+        self.anchors = anchors(&self.items);
+    }
+}
+
+fn shift(offset: usize, delta: isize) -> usize {
+    (offset as isize + delta) as usize
+}
+
+/// The result of [`parse_blocks`].
+struct Parsed {
+    blocks: Vec<Block>,
+    items: Vec<Item>,
+    /// The index of the old block the parser was resynchronized at.
+    resync: Option<usize>,
+    /// The link references of the document; when fully parsed.
+    references: Option<References>,
+}
+
+/// Parses the top-level blocks of the `source` starting at the given
+/// offset, until `resync` returns the index of an old block for the
+/// offset of a new one.
+///
+/// Link references are resolved with the given ones, if any; otherwise,
+/// the whole `source` is parsed and its own references are returned.
+fn parse_blocks<'a>(
+    source: &'a str,
+    start: usize,
+    palette: theme::Palette,
+    references: Option<&References>,
+    mut resync: impl FnMut(usize) -> Option<usize>,
+) -> Parsed {
+    let events = pulldown_cmark::Parser::new_with_broken_link_callback(
+        &source[start..],
+        OPTIONS,
+        Some(
+            |link: pulldown_cmark::BrokenLink<'a>| -> Option<(
+                pulldown_cmark::CowStr<'a>,
+                pulldown_cmark::CowStr<'a>,
+            )> {
+                let (url, title) =
+                    references?.get(&normalize_label(&link.reference))?;
+
+                Some((url.clone().into(), title.clone().into()))
+            },
+        ),
+    );
+
+    let own_references = references.is_none().then(|| {
+        events
+            .reference_definitions()
+            .iter()
+            .map(|(label, definition)| {
+                (
+                    normalize_label(label),
+                    (
+                        definition.dest.to_string(),
+                        definition
+                            .title
+                            .as_deref()
+                            .unwrap_or_default()
+                            .to_owned(),
+                    ),
+                )
+            })
+            .collect()
+    });
+
+    let mut parser = Parser::new(palette);
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut items = Vec::new();
+    let mut depth = 0_usize;
+
+    for (event, range) in events.into_offset_iter() {
+        if depth == 0 {
+            let offset = start + range.start;
+
+            if let Some(old) = resync(offset) {
+                return Parsed {
+                    blocks,
+                    items,
+                    resync: Some(old),
+                    references: own_references,
+                };
+            }
+
+            blocks.push(Block {
+                range: offset..start + range.end,
+                items: 0,
+            });
         }
-        _ => None,
+
+        match &event {
+            pulldown_cmark::Event::Start(_) => depth += 1,
+            pulldown_cmark::Event::End(_) => depth -= 1,
+            _ => {}
+        }
+
+        if let Some(item) = parser.event(event) {
+            items.push(item);
+
+            if let Some(block) = blocks.last_mut() {
+                block.items += 1;
+            }
+        }
+    }
+
+    Parsed {
+        blocks,
+        items,
+        resync: None,
+        references: own_references,
+    }
+}
+
+/// Normalizes the label of a link reference; like CommonMark does.
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Returns whether the Markdown may contain a link reference definition.
+fn has_reference_definition(markdown: &str) -> bool {
+    markdown.lines().any(|line| {
+        let line = line.trim_start();
+
+        line.starts_with('[') && line.contains("]:")
     })
 }
 
+/// Returns the anchor of every top-level heading; with the index of its
+/// [`Item`].
+fn anchors(items: &[Item]) -> Vec<(usize, String)> {
+    let mut anchors = Vec::new();
+    let mut seen = FxHashMap::default();
+
+    for (index, item) in items.iter().enumerate() {
+        let Item::Heading(_, spans) = item else {
+            continue;
+        };
+
+        let slug = slug(spans.iter().map(|span| span.text.as_ref()));
+
+        let count: &mut usize = seen.entry(slug.clone()).or_default();
+
+        let anchor = if *count == 0 {
+            slug
+        } else {
+            format!("{slug}-{count}")
+        };
+
+        *count += 1;
+        anchors.push((index, anchor));
+    }
+
+    anchors
+}
+
+/// Turns the text of a heading into an anchor; lowercasing it, replacing
+/// spaces with dashes, and dropping any punctuation.
+fn slug<'a>(text: impl Iterator<Item = &'a str>) -> String {
+    text.flat_map(str::chars)
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            _ if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
 /// Configuration controlling Markdown rendering in [`view`].
 #[derive(Debug, Clone, Copy)]
 pub struct Settings {
@@ -299,6 +775,11 @@ pub struct Settings {
     pub h6_size: Pixels,
     /// The text size used in code blocks.
     pub code_size: Pixels,
+    /// The horizontal alignment of paragraphs.
+    pub paragraph_alignment: alignment::Horizontal,
+    /// The maximum factor the spaces of a justified paragraph can be
+    /// stretched by.
+    pub max_space_factor: f32,
 }
 
 impl Settings {
@@ -319,6 +800,8 @@ impl Settings {
             h5_size: text_size,
             h6_size: text_size,
             code_size: text_size * 0.75,
+            paragraph_alignment: alignment::Horizontal::Left,
+            max_space_factor: core::text::DEFAULT_MAX_SPACE_FACTOR,
         }
     }
 }
@@ -332,11 +815,187 @@ impl Default for Settings {
 /// Display a bunch of Markdown items.
 ///
 /// You can obtain the items with [`parse`].
+///
+/// Any [`Item::Image`] will be displayed as its alternative text. Use
+/// [`view_with_images`] to display the actual images.
 pub fn view<'a, Message, Renderer>(
     items: impl IntoIterator<Item = &'a Item>,
     settings: Settings,
     on_link: impl Fn(Url) -> Message + Copy + 'a,
 ) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    blocks(items, settings, on_link, move |_url, alt| {
+        alternative(alt, settings)
+    })
+}
+
+/// Display a bunch of Markdown items, including images.
+///
+/// The `resolve` closure maps the URL of an image to its [`image::Handle`].
+/// It may return `None` if the image is not available yet—because it is
+/// still being fetched, for instance—or failed to load; in which case the
+/// alternative text of the image will be displayed instead.
+///
+/// Images are laid out as blocks and are scaled down, preserving their
+/// aspect ratio, to fit the available width. Clicking an image will produce
+/// the message returned by `on_image` with its URL.
+///
+/// [`image::Handle`]: core::image::Handle
+#[cfg(feature = "image")]
+pub fn view_with_images<'a, Message, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    on_link: impl Fn(Url) -> Message + Copy + 'a,
+    resolve: impl Fn(&str) -> Option<core::image::Handle> + Copy + 'a,
+    on_image: impl Fn(String) -> Message + Copy + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = Font>
+        + core::image::Renderer<Handle = core::image::Handle>
+        + 'a,
+{
+    blocks(items, settings, on_link, move |url, alt| {
+        image(url, alt, settings, resolve, on_image)
+    })
+}
+
+/// Display a [`Content`], building only the items near the viewport of
+/// the [`Scrollable`] that contains it.
+///
+/// This keeps large documents cheap to display and scroll. The headings of
+/// the [`Content`] can be scrolled to with [`scroll_to_anchor`], even if
+/// they have not been built yet.
+///
+/// Any [`Item::Image`] will be displayed as its alternative text. Use
+/// [`view_content_with_images`] to display the actual images.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub fn view_content<'a, Message, Renderer>(
+    content: &'a Content,
+    settings: Settings,
+    on_link: impl Fn(Url) -> Message + Copy + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    Element::new(Lazy::new(content, settings.text_size.0, move |i, item| {
+        block(i, item, settings, on_link, move |_url, alt| {
+            alternative(alt, settings)
+        })
+    }))
+}
+
+/// Display a [`Content`], including images; building only the items near
+/// the viewport of the [`Scrollable`] that contains it.
+///
+/// Images are resolved like in [`view_with_images`].
+///
+/// [`Scrollable`]: crate::Scrollable
+#[cfg(feature = "image")]
+pub fn view_content_with_images<'a, Message, Renderer>(
+    content: &'a Content,
+    settings: Settings,
+    on_link: impl Fn(Url) -> Message + Copy + 'a,
+    resolve: impl Fn(&str) -> Option<core::image::Handle> + Copy + 'a,
+    on_image: impl Fn(String) -> Message + Copy + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = Font>
+        + core::image::Renderer<Handle = core::image::Handle>
+        + 'a,
+{
+    Element::new(Lazy::new(content, settings.text_size.0, move |i, item| {
+        block(i, item, settings, on_link, move |url, alt| {
+            image(url, alt, settings, resolve, on_image)
+        })
+    }))
+}
+
+/// Produces a [`Task`] that scrolls the [`Scrollable`] with the given
+/// [`Id`] to the heading with the given anchor of the [`Content`] it
+/// displays with [`view_content`].
+///
+/// [`Scrollable`]: crate::Scrollable
+/// [`Id`]: scrollable::Id
+pub fn scroll_to_anchor<T>(
+    scrollable: scrollable::Id,
+    anchor: &str,
+) -> crate::runtime::Task<T> {
+    scrollable::scroll_to_child_animated(
+        scrollable,
+        heading(anchor),
+        Duration::from_millis(300),
+        scrollable::Easing::default(),
+    )
+}
+
+/// Returns the [`widget::Id`] of the heading with the given anchor.
+fn heading(anchor: &str) -> widget::Id {
+    widget::Id::new(format!("#{anchor}"))
+}
+
+#[cfg(feature = "image")]
+fn image<'a, Message, Renderer>(
+    url: &'a str,
+    alt: &'a str,
+    settings: Settings,
+    resolve: impl Fn(&str) -> Option<core::image::Handle>,
+    on_image: impl Fn(String) -> Message,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = Font>
+        + core::image::Renderer<Handle = core::image::Handle>
+        + 'a,
+{
+    match resolve(url) {
+        Some(handle) => crate::mouse_area(crate::Image::new(handle))
+            .on_press(on_image(url.to_owned()))
+            .interaction(core::mouse::Interaction::Pointer)
+            .into(),
+        None => alternative(alt, settings),
+    }
+}
+
+fn blocks<'a, Message, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    on_link: impl Fn(Url) -> Message + Copy + 'a,
+    image: impl Fn(&'a str, &'a str) -> Element<'a, Message, Theme, Renderer>
+        + Copy
+        + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    let blocks = items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| block(i, item, settings, on_link, image));
+
+    Element::new(
+        column(blocks)
+            .width(Length::Fill)
+            .spacing(settings.text_size),
+    )
+}
+
+fn block<'a, Message, Renderer>(
+    i: usize,
+    item: &'a Item,
+    settings: Settings,
+    on_link: impl Fn(Url) -> Message + Copy + 'a,
+    image: impl Fn(&'a str, &'a str) -> Element<'a, Message, Theme, Renderer>
+        + Copy
+        + 'a,
+) -> Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
     Renderer: core::text::Renderer<Font = Font> + 'a,
@@ -350,11 +1009,13 @@ where
         h5_size,
         h6_size,
         code_size,
+        paragraph_alignment,
+        max_space_factor,
     } = settings;
 
     let spacing = text_size * 0.625;
 
-    let blocks = items.into_iter().enumerate().map(|(i, item)| match item {
+    match item {
         Item::Heading(level, heading) => {
             container(rich_text(heading).on_link(on_link).size(match level {
                 pulldown_cmark::HeadingLevel::H1 => h1_size,
@@ -371,14 +1032,20 @@ where
             }))
             .into()
         }
-        Item::Paragraph(paragraph) => {
-            rich_text(paragraph).on_link(on_link).size(text_size).into()
-        }
+        Item::Paragraph(paragraph) => rich_text(paragraph)
+            .on_link(on_link)
+            .size(text_size)
+            .align_x(paragraph_alignment)
+            .max_space_factor(max_space_factor)
+            .into(),
         Item::List { start: None, items } => {
             column(items.iter().map(|items| {
-                row![text("•").size(text_size), view(items, settings, on_link)]
-                    .spacing(spacing)
-                    .into()
+                row![
+                    text("•").size(text_size),
+                    blocks(items, settings, on_link, image)
+                ]
+                .spacing(spacing)
+                .into()
             }))
             .spacing(spacing)
             .into()
@@ -389,7 +1056,7 @@ where
         } => column(items.iter().enumerate().map(|(i, items)| {
             row![
                 text!("{}.", i as u64 + *start).size(text_size),
-                view(items, settings, on_link)
+                blocks(items, settings, on_link, image)
             ]
             .spacing(spacing)
             .into()
@@ -416,7 +1083,127 @@ where
         .padding(spacing.0 / 2.0)
         .style(container::rounded_box)
         .into(),
-    });
+        Item::Image { url, alt } => image(url, alt),
+    }
+}
+
+fn alternative<'a, Message, Renderer>(
+    alt: &'a str,
+    settings: Settings,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    text(alt)
+        .size(settings.text_size)
+        .font(Font {
+            style: font::Style::Italic,
+            ..Font::default()
+        })
+        .style(text::secondary)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+# Iced
+
+A cross-platform GUI library for Rust, inspired by [Elm][elm].
+
+## Features
+
+- Simple, easy-to-use API
+- Responsive layout
+    1. Nested
+    2. Lists
+
+```rust
+fn main() {}
+```
+
+Setext heading
+--------------
+
+## Features
+
+Jump back to [the features](#features).
+
+[elm]: https://elm-lang.org/
+";
+
+    fn assert_parsed(content: &Content) {
+        let parsed = Content::parse(content.source(), Theme::Light.palette());
 
-    Element::new(column(blocks).width(Length::Fill).spacing(text_size))
+        assert_eq!(content.blocks, parsed.blocks);
+        assert_eq!(
+            format!("{:?}", content.items()),
+            format!("{:?}", parsed.items())
+        );
+        assert_eq!(content.anchors, parsed.anchors);
+    }
+
+    #[test]
+    fn updates_match_a_full_parse() {
+        let mut content = Content::parse(SOURCE, Theme::Light.palette());
+
+        let edits = [
+            ("Responsive layout", "Reactive layout"),
+            ("- Simple", "* Simple"),
+            ("fn main() {}", "fn main() {\n\n    println!();\n}"),
+            ("Setext heading\n", "Setext heading\n\n"),
+            ("## Features\n\nJump", "## Features\nJump"),
+            ("Jump back", "Jump [back][elm]"),
+            ("[elm]: https://elm-lang.org/", "[elm]: https://iced.rs/"),
+            ("Rust", "Rüst"),
+            ("Rüst", "Rũst"),
+        ];
+
+        for (from, to) in edits {
+            let source = content.source().replacen(from, to, 1);
+
+            content.update(&source);
+
+            assert_eq!(content.source(), source);
+            assert_parsed(&content);
+        }
+    }
+
+    #[test]
+    fn pushed_text_matches_a_full_parse() {
+        let mut content = Content::parse("", Theme::Light.palette());
+
+        for chunk in SOURCE.as_bytes().chunks(7) {
+            content.push_str(std::str::from_utf8(chunk).unwrap());
+
+            assert_parsed(&content);
+        }
+
+        assert_eq!(content.source(), SOURCE);
+    }
+
+    #[test]
+    fn anchors_are_unique() {
+        let content = Content::parse(SOURCE, Theme::Light.palette());
+
+        assert_eq!(
+            content.anchors().collect::<Vec<_>>(),
+            [
+                (0, "iced"),
+                (2, "features"),
+                (5, "setext-heading"),
+                (6, "features-1")
+            ]
+        );
+
+        let Item::Paragraph(spans) = &content.items()[7] else {
+            panic!("paragraph expected");
+        };
+
+        let link = spans.iter().find_map(|span| span.link.as_ref());
+
+        assert_eq!(link.and_then(anchor), Some("features"));
+    }
 }
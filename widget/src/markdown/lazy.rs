@@ -0,0 +1,320 @@
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    self, Clipboard, Element, Length, Point, Rectangle, Shell, Size, Widget,
+};
+use crate::markdown::{Content, Item};
+
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// The height of the region that is built before the [`Lazy`] widget knows
+/// its viewport.
+const INITIAL_WINDOW: f32 = 2000.0;
+
+/// A column of the items of a [`Content`] that only builds the ones near
+/// its viewport.
+#[allow(missing_debug_implementations)]
+pub struct Lazy<'a, Message, Theme, Renderer> {
+    content: &'a Content,
+    spacing: f32,
+    view: Box<
+        dyn Fn(usize, &'a Item) -> Element<'a, Message, Theme, Renderer> + 'a,
+    >,
+    built: RefCell<Vec<(usize, Element<'a, Message, Theme, Renderer>)>>,
+}
+
+impl<'a, Message, Theme, Renderer> Lazy<'a, Message, Theme, Renderer> {
+    pub fn new(
+        content: &'a Content,
+        spacing: f32,
+        view: impl Fn(usize, &'a Item) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            content,
+            spacing,
+            view: Box::new(view),
+            built: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+struct State {
+    width: f32,
+    /// The measured height of every item, if it has been laid out.
+    heights: Vec<Option<f32>>,
+    /// The vertical region of every item; measured or estimated.
+    regions: Vec<Range<f32>>,
+    /// The trees of the items that are currently built.
+    trees: FxHashMap<usize, Tree>,
+    /// The vertical region where items are built.
+    window: Range<f32>,
+}
+
+impl State {
+    /// Returns an estimate of the height of an item that has not been
+    /// laid out yet.
+    fn estimate(&self, spacing: f32) -> f32 {
+        let (total, count) = self
+            .heights
+            .iter()
+            .flatten()
+            .fold((0.0, 0), |(total, count), height| {
+                (total + height, count + 1)
+            });
+
+        if count == 0 {
+            spacing * 2.0
+        } else {
+            total / count as f32
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Lazy<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            width: 0.0,
+            heights: Vec::new(),
+            regions: Vec::new(),
+            trees: FxHashMap::default(),
+            window: 0.0..INITIAL_WINDOW,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let items = self.content.items();
+        let width = limits.width(Length::Fill).max().width;
+
+        // Text reflows when the width changes; so every height is stale
+        if state.width != width {
+            state.width = width;
+            state.heights.clear();
+        }
+
+        state.heights.resize(items.len(), None);
+        state.regions.clear();
+
+        let estimate = state.estimate(self.spacing);
+        let child_limits =
+            layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY));
+
+        let mut built = self.built.borrow_mut();
+        let mut nodes = Vec::new();
+        let mut trees = FxHashMap::default();
+        let mut y = 0.0;
+
+        built.clear();
+
+        for (i, item) in items.iter().enumerate() {
+            let mut height = state.heights[i].unwrap_or(estimate);
+
+            if y + height >= state.window.start && y <= state.window.end {
+                let element = (self.view)(i, item);
+
+                let mut tree = match state.trees.remove(&i) {
+                    Some(mut tree) => {
+                        tree.diff(&element);
+                        tree
+                    }
+                    None => Tree::new(&element),
+                };
+
+                let node = element
+                    .as_widget()
+                    .layout(&mut tree, renderer, &child_limits)
+                    .move_to(Point::new(0.0, y));
+
+                height = node.size().height;
+                state.heights[i] = Some(height);
+
+                let _ = trees.insert(i, tree);
+                nodes.push(node);
+                built.push((i, element));
+            }
+
+            state.regions.push(y..y + height);
+
+            y += height + self.spacing;
+        }
+
+        // Only the trees of the built items are kept around
+        state.trees = trees;
+
+        let height = (y - self.spacing).max(0.0);
+
+        layout::Node::with_children(Size::new(width, height), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let built = self.built.borrow();
+
+        operation.container(None, bounds, &mut |operation| {
+            // Headings must be reachable even if they are not built
+            for (i, anchor) in self.content.anchors() {
+                let Some(region) = state.regions.get(i) else {
+                    continue;
+                };
+
+                operation.container(
+                    Some(&super::heading(anchor)),
+                    Rectangle {
+                        x: bounds.x,
+                        y: bounds.y + region.start,
+                        width: bounds.width,
+                        height: region.end - region.start,
+                    },
+                    &mut |_| {},
+                );
+            }
+
+            for ((i, element), layout) in built.iter().zip(layout.children()) {
+                if let Some(tree) = state.trees.get_mut(i) {
+                    element
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let top = (viewport.y - bounds.y).max(0.0);
+        let bottom =
+            (viewport.y + viewport.height - bounds.y).min(bounds.height);
+
+        if top < bottom
+            && (top < state.window.start || bottom > state.window.end)
+        {
+            // Build a viewport of items above and below the visible ones,
+            // so scrolling does not need a new layout every frame
+            let margin = viewport.height;
+
+            state.window = (top - margin)..(bottom + margin);
+
+            shell.invalidate_layout();
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.built
+            .get_mut()
+            .iter_mut()
+            .zip(layout.children())
+            .map(|((i, element), layout)| {
+                let Some(tree) = state.trees.get_mut(i) else {
+                    return event::Status::Ignored;
+                };
+
+                element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.built
+            .borrow()
+            .iter()
+            .zip(layout.children())
+            .filter_map(|((i, element), layout)| {
+                Some(element.as_widget().mouse_interaction(
+                    state.trees.get(i)?,
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                ))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        for ((i, element), layout) in
+            self.built.borrow().iter().zip(layout.children())
+        {
+            if !layout.bounds().intersects(viewport) {
+                continue;
+            }
+
+            if let Some(tree) = state.trees.get(i) {
+                element.as_widget().draw(
+                    tree, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+}
@@ -24,6 +24,7 @@ pub struct MouseArea<
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<Message>,
     on_release: Option<Message>,
+    on_double_click: Option<Message>,
     on_right_press: Option<Message>,
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
@@ -49,6 +50,18 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit on a double click of the left button.
+    ///
+    /// Clicks must happen within the [`Interaction::double_click`] time
+    /// of the user to form a double click.
+    ///
+    /// [`Interaction::double_click`]: crate::core::settings::Interaction::double_click
+    #[must_use]
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
     /// The message to emit on a right button press.
     #[must_use]
     pub fn on_right_press(mut self, message: Message) -> Self {
@@ -113,6 +126,7 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
 #[derive(Default)]
 struct State {
     is_hovered: bool,
+    last_click: Option<mouse::Click>,
 }
 
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
@@ -124,6 +138,7 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             content: content.into(),
             on_press: None,
             on_release: None,
+            on_double_click: None,
             on_right_press: None,
             on_right_release: None,
             on_middle_press: None,
@@ -267,12 +282,14 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         self.content.as_widget_mut().overlay(
             &mut tree.children[0],
             layout,
             renderer,
+            viewport,
             translation,
         )
     }
@@ -334,13 +351,34 @@ fn update<Message: Clone, Theme, Renderer>(
         return event::Status::Ignored;
     }
 
-    if let Some(message) = widget.on_press.as_ref() {
-        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerPressed { .. }) = event
-        {
+    if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    | Event::Touch(touch::Event::FingerPressed { .. }) = event
+    {
+        let mut status = event::Status::Ignored;
+
+        if let Some(message) = widget.on_press.as_ref() {
             shell.publish(message.clone());
 
-            return event::Status::Captured;
+            status = event::Status::Captured;
+        }
+
+        if let (Some(message), Some(position)) =
+            (widget.on_double_click.as_ref(), cursor.position())
+        {
+            let state: &mut State = tree.state.downcast_mut();
+
+            let click = mouse::Click::new(position, state.last_click);
+
+            if let mouse::click::Kind::Double = click.kind() {
+                shell.publish(message.clone());
+            }
+
+            state.last_click = Some(click);
+            status = event::Status::Captured;
+        }
+
+        if status == event::Status::Captured {
+            return status;
         }
     }
 
@@ -399,3 +437,68 @@ fn update<Message: Clone, Theme, Renderer>(
 
     event::Status::Ignored
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::settings::Interaction;
+    use crate::core::time::Duration;
+    use crate::{Space, Theme};
+
+    /// Clicks a 100x100 [`MouseArea`] twice; returning the messages it
+    /// produced.
+    fn double_click() -> Vec<&'static str> {
+        let mut area: Element<'static, &'static str, Theme, ()> =
+            MouseArea::new(Space::new(100, 100))
+                .on_press("press")
+                .on_double_click("double click")
+                .into();
+
+        let mut tree = Tree::new(&area);
+
+        let node = area.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for _ in 0..2 {
+            let _ = area.as_widget_mut().on_event(
+                &mut tree,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(50.0, 50.0)),
+                &(),
+                &mut crate::core::clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn double_clicks_honor_the_double_click_time() {
+        Interaction {
+            double_click: Duration::from_secs(60),
+            ..Interaction::default()
+        }
+        .apply();
+
+        assert_eq!(double_click(), ["press", "press", "double click"]);
+
+        Interaction {
+            double_click: Duration::ZERO,
+            ..Interaction::default()
+        }
+        .apply();
+
+        assert_eq!(double_click(), ["press", "press"]);
+
+        Interaction::default().apply();
+    }
+}
@@ -0,0 +1,437 @@
+//! Number inputs let users type a number or step through a range of them.
+use crate::core::alignment;
+use crate::core::time::Duration;
+use crate::core::{self, Alignment, Element};
+use crate::{button, row, text, text_input, Stateful};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The time the buttons of a [`NumberInput`] must be held down before they
+/// start repeating.
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+/// The time between the repetitions of a button of a [`NumberInput`] held
+/// down.
+const REPEAT_INTERVAL: Duration = Duration::from_millis(80);
+
+/// A field that lets users type a number, along with buttons to increment
+/// and decrement it.
+///
+/// The [`NumberInput`] keeps the text typed by the user, so partial input
+/// like `-` or `1.` is not lost while the user is still typing. Partial
+/// input produces `None`, just like an empty one.
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message> {
+    value: Option<T>,
+    min: T,
+    max: T,
+    step: T,
+    is_strict: bool,
+    placeholder: String,
+    on_change: Box<dyn Fn(Option<T>) -> Message + 'a>,
+}
+
+impl<'a, T, Message> NumberInput<'a, T, Message>
+where
+    T: Number,
+{
+    /// Creates a new [`NumberInput`] with the given value and a closure
+    /// that produces a message when the value changes.
+    pub fn new(
+        value: Option<T>,
+        on_change: impl Fn(Option<T>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            value,
+            min: T::MIN,
+            max: T::MAX,
+            step: T::ONE,
+            is_strict: false,
+            placeholder: String::new(),
+            on_change: Box::new(on_change),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the amount the buttons of the [`NumberInput`] increment and
+    /// decrement its value by.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets whether typed values outside of the bounds are rejected
+    /// instead of clamped.
+    pub fn strict(mut self, is_strict: bool) -> Self {
+        self.is_strict = is_strict;
+        self
+    }
+
+    /// Sets the placeholder of the [`NumberInput`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    fn clamp(&self, value: T) -> T {
+        if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    fn can_increment(&self) -> bool {
+        self.value.map_or(true, |value| value < self.max)
+    }
+
+    fn can_decrement(&self) -> bool {
+        self.value.map_or(true, |value| value > self.min)
+    }
+
+    fn update(&self, state: &mut State, event: Event) -> Option<Message> {
+        let value = match event {
+            Event::InputChanged(raw) => {
+                let value = match parse::<T>(&raw) {
+                    Input::Invalid => return None,
+                    Input::Empty | Input::Partial => None,
+                    Input::Number(number)
+                        if number < self.min || number > self.max =>
+                    {
+                        if self.is_strict {
+                            return None;
+                        }
+
+                        Some(self.clamp(number))
+                    }
+                    Input::Number(number) => Some(number),
+                };
+
+                state.raw = raw;
+
+                return Some((self.on_change)(value));
+            }
+            Event::IncrementPressed => {
+                self.clamp(self.base().saturating_add(self.step))
+            }
+            Event::DecrementPressed => {
+                self.clamp(self.base().saturating_sub(self.step))
+            }
+        };
+
+        state.raw = value.to_string();
+
+        Some((self.on_change)(Some(value)))
+    }
+
+    /// Returns the value the buttons step from.
+    fn base(&self) -> T {
+        self.value.unwrap_or_else(|| self.clamp(T::ZERO))
+    }
+}
+
+/// A type of number that can be used in a [`NumberInput`].
+pub trait Number: Copy + PartialOrd + FromStr + fmt::Display + 'static {
+    /// The smallest value of the type.
+    const MIN: Self;
+
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// The zero of the type.
+    const ZERO: Self;
+
+    /// The one of the type; used as the default step.
+    const ONE: Self;
+
+    /// Whether the type can hold negative values.
+    const IS_SIGNED: bool;
+
+    /// Whether the type can hold fractional values.
+    const IS_FLOAT: bool;
+
+    /// Adds two numbers, saturating at the bounds of the type.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Subtracts two numbers, saturating at the bounds of the type.
+    fn saturating_sub(self, other: Self) -> Self;
+
+    /// Returns whether the number is finite.
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! integer {
+    ($($type:ty => $is_signed:expr),*) => {
+        $(
+            impl Number for $type {
+                const MIN: Self = <$type>::MIN;
+                const MAX: Self = <$type>::MAX;
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const IS_SIGNED: bool = $is_signed;
+                const IS_FLOAT: bool = false;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$type>::saturating_add(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$type>::saturating_sub(self, other)
+                }
+
+                fn is_finite(self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float {
+    ($($type:ty),*) => {
+        $(
+            impl Number for $type {
+                const MIN: Self = <$type>::MIN;
+                const MAX: Self = <$type>::MAX;
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const IS_SIGNED: bool = true;
+                const IS_FLOAT: bool = true;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    (self + other).clamp(Self::MIN, Self::MAX)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    (self - other).clamp(Self::MIN, Self::MAX)
+                }
+
+                fn is_finite(self) -> bool {
+                    <$type>::is_finite(self)
+                }
+            }
+        )*
+    };
+}
+
+integer!(
+    u8 => false, u16 => false, u32 => false, u64 => false, u128 => false,
+    usize => false, i8 => true, i16 => true, i32 => true, i64 => true,
+    i128 => true, isize => true
+);
+
+float!(f32, f64);
+
+#[derive(Debug, Clone)]
+enum Event {
+    InputChanged(String),
+    IncrementPressed,
+    DecrementPressed,
+}
+
+/// The internal state of a [`NumberInput`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    raw: String,
+}
+
+/// The interpretation of some raw input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Input<T> {
+    Empty,
+    Partial,
+    Number(T),
+    Invalid,
+}
+
+fn parse<T: Number>(raw: &str) -> Input<T> {
+    if raw.is_empty() {
+        return Input::Empty;
+    }
+
+    if let Ok(number) = raw.parse::<T>() {
+        return if number.is_finite() {
+            Input::Number(number)
+        } else {
+            Input::Invalid
+        };
+    }
+
+    let unsigned = if T::IS_SIGNED {
+        raw.strip_prefix('-').unwrap_or(raw)
+    } else {
+        raw
+    };
+
+    let is_partial = unsigned.is_empty()
+        || T::IS_FLOAT
+            && (unsigned == "."
+                || ["e", "e-", "e+", "E", "E-", "E+"].iter().any(|suffix| {
+                    unsigned.strip_suffix(suffix).is_some_and(|mantissa| {
+                        mantissa.parse::<T>().is_ok_and(T::is_finite)
+                    })
+                }));
+
+    if is_partial {
+        Input::Partial
+    } else {
+        Input::Invalid
+    }
+}
+
+/// Returns the text to display for the given value, preserving the raw
+/// input of the user if it still represents it.
+fn display<T: Number>(raw: &str, value: Option<T>) -> String {
+    let is_current = match (parse::<T>(raw), value) {
+        (Input::Number(number), Some(value)) => number == value,
+        (Input::Empty | Input::Partial, None) => true,
+        _ => false,
+    };
+
+    if is_current {
+        raw.to_owned()
+    } else {
+        value.as_ref().map(T::to_string).unwrap_or_default()
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<NumberInput<'a, T, Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Number,
+    Message: 'static,
+    Theme: text::Catalog + button::Catalog + text_input::Catalog + 'static,
+    Renderer: core::text::Renderer + 'static,
+{
+    fn from(number_input: NumberInput<'a, T, Message>) -> Self {
+        let value = number_input.value;
+        let can_decrement = number_input.can_decrement();
+        let can_increment = number_input.can_increment();
+        let placeholder = number_input.placeholder.clone();
+
+        let state = State {
+            raw: display("", value),
+        };
+
+        let stateful = Stateful::new(
+            state,
+            move |state, event| number_input.update(state, event),
+            move |state: &State| {
+                let button = |label, on_press| {
+                    button(
+                        text(label)
+                            .width(40)
+                            .align_x(alignment::Horizontal::Center),
+                    )
+                    .on_press_maybe(on_press)
+                    .repeat(REPEAT_DELAY, REPEAT_INTERVAL)
+                };
+
+                row![
+                    button(
+                        "-",
+                        can_decrement.then_some(Event::DecrementPressed)
+                    ),
+                    text_input(&placeholder, &display(&state.raw, value))
+                        .input_mode(text_input::InputMode::Numeric)
+                        .on_input(Event::InputChanged),
+                    button(
+                        "+",
+                        can_increment.then_some(Event::IncrementPressed)
+                    ),
+                ]
+                .align_y(Alignment::Center)
+                .spacing(10)
+            },
+        );
+
+        Element::new(stateful)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number_input<T: Number>(
+        value: Option<T>,
+    ) -> NumberInput<'static, T, Option<T>> {
+        NumberInput::new(value, std::convert::identity)
+    }
+
+    #[test]
+    fn partial_input_is_preserved() {
+        assert_eq!(parse::<i32>("-"), Input::Partial);
+        assert_eq!(parse::<u32>("-"), Input::Invalid);
+        assert_eq!(parse::<f64>("-."), Input::Partial);
+        assert_eq!(parse::<f64>("1e-"), Input::Partial);
+        assert_eq!(parse::<f64>("1."), Input::Number(1.0));
+        assert_eq!(parse::<f64>("inf"), Input::Invalid);
+        assert_eq!(parse::<u128>(""), Input::Empty);
+        assert_eq!(parse::<u128>("12a"), Input::Invalid);
+
+        assert_eq!(display("1.", Some(1.0)), "1.");
+        assert_eq!(display::<i32>("-", None), "-");
+        assert_eq!(display("1.", Some(2.5)), "2.5");
+    }
+
+    #[test]
+    fn typing_updates_the_raw_input() {
+        let mut state = State::default();
+        let input = number_input::<f64>(None);
+
+        assert_eq!(
+            input.update(&mut state, Event::InputChanged(String::from("-"))),
+            Some(None)
+        );
+        assert_eq!(state.raw, "-");
+
+        assert_eq!(
+            input.update(&mut state, Event::InputChanged(String::from("x"))),
+            None
+        );
+        assert_eq!(state.raw, "-");
+    }
+
+    #[test]
+    fn typing_outside_of_the_bounds() {
+        let mut state = State::default();
+        let typed = || Event::InputChanged(String::from("500"));
+
+        let input = number_input::<u128>(None).max(100);
+        assert_eq!(input.update(&mut state, typed()), Some(Some(100)));
+
+        let input = number_input::<u128>(None).max(100).strict(true);
+        assert_eq!(input.update(&mut state, typed()), None);
+    }
+
+    #[test]
+    fn stepping_saturates_and_disables_at_the_bounds() {
+        let mut state = State::default();
+
+        let input = number_input(Some(u128::MAX - 1)).step(10);
+        assert_eq!(
+            input.update(&mut state, Event::IncrementPressed),
+            Some(Some(u128::MAX))
+        );
+        assert_eq!(state.raw, u128::MAX.to_string());
+
+        let input = number_input(Some(-5)).min(-5);
+        assert!(!input.can_decrement());
+        assert!(input.can_increment());
+    }
+}
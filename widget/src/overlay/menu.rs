@@ -2,19 +2,26 @@
 use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
-use crate::core::widget::Tree;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::{Id, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Length, Padding, Pixels, Point, Rectangle,
     Size, Theme, Vector,
 };
 use crate::core::{Element, Shell, Widget};
-use crate::scrollable::{self, Scrollable};
+use crate::scrollable::{self, AbsoluteOffset, Scrollable};
+
+use std::cell::Cell;
 
 /// A list of selectable options.
 #[allow(missing_debug_implementations)]
@@ -41,6 +48,8 @@ pub struct Menu<
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    type_ahead: bool,
+    is_loading: bool,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -75,6 +84,8 @@ where
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
             font: None,
+            type_ahead: false,
+            is_loading: false,
             class,
         }
     }
@@ -118,6 +129,24 @@ where
         self
     }
 
+    /// Sets whether the [`Menu`] supports type-ahead search.
+    ///
+    /// When enabled, typing hovers the first option whose text starts with
+    /// the characters typed so far, ignoring case. The typed characters are
+    /// forgotten after a second without typing or when `Escape` is pressed.
+    pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+        self.type_ahead = type_ahead;
+        self
+    }
+
+    /// Sets whether the [`Menu`] is waiting for more options.
+    ///
+    /// While loading, a small indicator is displayed after the options.
+    pub fn is_loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
@@ -141,6 +170,8 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    search: Search,
+    revealed: Cell<Option<usize>>,
 }
 
 impl State {
@@ -148,8 +179,36 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            search: Search::default(),
+            revealed: Cell::new(None),
         }
     }
+
+    /// Scrolls the [`Menu`] the next time it is laid out, so the option with
+    /// the given index is visible.
+    ///
+    /// Useful to show the selected option when the [`Menu`] opens.
+    pub fn reveal(&mut self, option: usize) {
+        self.revealed.set(Some(option));
+    }
+
+    /// Processes some typed text for type-ahead search; returning the index
+    /// of the first option that matches the characters typed so far.
+    ///
+    /// See [`Menu::type_ahead`].
+    pub fn search<T: ToString>(
+        &mut self,
+        text: &str,
+        options: &[T],
+    ) -> Option<usize> {
+        self.search.push(text, Instant::now(), options)
+    }
+
+    /// Forgets the characters typed for type-ahead search; returning true if
+    /// there were any.
+    pub fn reset_search(&mut self) -> bool {
+        self.search.reset()
+    }
 }
 
 impl Default for State {
@@ -158,6 +217,23 @@ impl Default for State {
     }
 }
 
+/// The strategy used to display a [`Menu`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// The [`Menu`] is displayed as an overlay on top of any other widgets.
+    ///
+    /// This is the default.
+    #[default]
+    Overlay,
+    /// The [`Menu`] is displayed below its widget, as part of its layout;
+    /// pushing any siblings downwards while it expands.
+    ///
+    /// Useful when the user interface is drawn into a surface that clips
+    /// anything escaping the bounds of its widgets; like a texture embedded
+    /// in a game engine.
+    Inline,
+}
+
 struct Overlay<'a, 'b, Message, Theme, Renderer>
 where
     Theme: Catalog,
@@ -165,9 +241,13 @@ where
 {
     position: Point,
     state: &'a mut Tree,
+    revealed: &'a Cell<Option<usize>>,
     list: Scrollable<'a, Message, Theme, Renderer>,
     width: f32,
     target_height: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -198,9 +278,19 @@ where
             text_size,
             text_line_height,
             text_shaping,
+            type_ahead,
+            is_loading,
             class,
         } = menu;
 
+        let State {
+            tree,
+            search,
+            revealed,
+        } = state;
+
+        let revealed: &'a Cell<Option<usize>> = revealed;
+
         let list = Scrollable::new(List {
             options,
             hovered_option,
@@ -211,17 +301,24 @@ where
             text_line_height,
             text_shaping,
             padding,
+            search: type_ahead.then_some(search),
+            revealed,
+            is_loading,
             class,
         });
 
-        state.tree.diff(&list as &dyn Widget<_, _, _>);
+        tree.diff(&list as &dyn Widget<_, _, _>);
 
         Self {
             position,
-            state: &mut state.tree,
+            state: tree,
+            revealed,
             list,
             width,
             target_height,
+            padding,
+            text_size,
+            text_line_height,
             class,
         }
     }
@@ -255,6 +352,27 @@ where
         let node = self.list.layout(self.state, renderer, &limits);
         let size = node.size();
 
+        if let Some(option) = self.revealed.take() {
+            let text_size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            let option_height =
+                f32::from(self.text_line_height.to_absolute(text_size))
+                    + self.padding.vertical();
+
+            let top = option_height * option as f32;
+
+            self.list.operate(
+                self.state,
+                Layout::new(&node),
+                renderer,
+                &mut Reveal {
+                    top,
+                    bottom: top + option_height,
+                },
+            );
+        }
+
         node.move_to(if space_below > space_above {
             self.position + Vector::new(0.0, self.target_height)
         } else {
@@ -331,6 +449,9 @@ where
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    search: Option<&'a mut Search>,
+    revealed: &'a Cell<Option<usize>>,
+    is_loading: bool,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -365,7 +486,8 @@ where
             let intrinsic = Size::new(
                 0.0,
                 (f32::from(text_line_height) + self.padding.vertical())
-                    * self.options.len() as f32,
+                    * (self.options.len() + usize::from(self.is_loading))
+                        as f32,
             );
 
             limits.resolve(Length::Fill, Length::Shrink, intrinsic)
@@ -448,6 +570,45 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                let Some(search) = self.search.as_deref_mut() else {
+                    return event::Status::Ignored;
+                };
+
+                if key == keyboard::Key::Named(key::Named::Escape) {
+                    if search.reset() {
+                        return event::Status::Captured;
+                    }
+                } else if let Some(text) = text.filter(|text| {
+                    !modifiers.command() && !text.chars().any(char::is_control)
+                }) {
+                    if let Some(index) =
+                        search.push(&text, Instant::now(), self.options)
+                    {
+                        if let Some(on_option_hovered) = self.on_option_hovered
+                        {
+                            if *self.hovered_option != Some(index) {
+                                shell.publish(on_option_hovered(
+                                    self.options[index].clone(),
+                                ));
+                            }
+                        }
+
+                        *self.hovered_option = Some(index);
+                        self.revealed.set(Some(index));
+
+                        shell.invalidate_layout();
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
             _ => {}
         }
 
@@ -481,67 +642,149 @@ where
         _cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let style = Catalog::style(theme, self.class);
-        let bounds = layout.bounds();
+        draw_options(
+            renderer,
+            theme,
+            self.class,
+            self.options,
+            *self.hovered_option,
+            layout.bounds(),
+            viewport,
+            self.padding,
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+            self.text_line_height,
+            self.text_shaping,
+            self.font.unwrap_or_else(|| renderer.default_font()),
+            self.is_loading,
+        );
+    }
+}
 
-        let text_size =
-            self.text_size.unwrap_or_else(|| renderer.default_size());
-        let option_height =
-            f32::from(self.text_line_height.to_absolute(text_size))
-                + self.padding.vertical();
+#[allow(clippy::too_many_arguments)]
+fn draw_options<T, Theme, Renderer>(
+    renderer: &mut Renderer,
+    theme: &Theme,
+    class: &<Theme as Catalog>::Class<'_>,
+    options: &[T],
+    hovered_option: Option<usize>,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+    padding: Padding,
+    text_size: Pixels,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Renderer::Font,
+    is_loading: bool,
+) where
+    T: ToString,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    let style = Catalog::style(theme, class);
 
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
+    let option_height =
+        f32::from(text_line_height.to_absolute(text_size)) + padding.vertical();
 
-        let visible_options = &self.options[start..end.min(self.options.len())];
+    let offset = (viewport.y - bounds.y).max(0.0);
+    let start = (offset / option_height) as usize;
+    let end = ((offset + viewport.height) / option_height).ceil() as usize;
 
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
+    let visible_options =
+        &options[start.min(options.len())..end.min(options.len())];
 
-            let bounds = Rectangle {
-                x: bounds.x,
-                y: bounds.y + (option_height * i as f32),
-                width: bounds.width,
-                height: option_height,
-            };
+    for (i, option) in visible_options.iter().enumerate() {
+        let i = start + i;
+        let is_selected = hovered_option == Some(i);
 
-            if is_selected {
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + style.border.width,
-                            width: bounds.width - style.border.width * 2.0,
-                            ..bounds
-                        },
-                        border: border::rounded(style.border.radius),
-                        ..renderer::Quad::default()
-                    },
-                    style.selected_background,
-                );
-            }
+        let bounds = Rectangle {
+            x: bounds.x,
+            y: bounds.y + (option_height * i as f32),
+            width: bounds.width,
+            height: option_height,
+        };
 
-            renderer.fill_text(
-                Text {
-                    content: option.to_string(),
-                    bounds: Size::new(f32::INFINITY, bounds.height),
-                    size: text_size,
-                    line_height: self.text_line_height,
-                    font: self.font.unwrap_or_else(|| renderer.default_font()),
-                    horizontal_alignment: alignment::Horizontal::Left,
-                    vertical_alignment: alignment::Vertical::Center,
-                    shaping: self.text_shaping,
-                },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
+        if is_selected {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + style.border.width,
+                        width: bounds.width - style.border.width * 2.0,
+                        ..bounds
+                    },
+                    border: border::rounded(style.border.radius),
+                    ..renderer::Quad::default()
                 },
-                *viewport,
+                style.selected_background,
             );
         }
+
+        renderer.fill_text(
+            Text {
+                content: option.to_string(),
+                bounds: Size::new(f32::INFINITY, bounds.height),
+                size: text_size,
+                line_height: text_line_height,
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                font_features: &[],
+                shaping: text_shaping,
+            },
+            Point::new(bounds.x + padding.left, bounds.center_y()),
+            if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            },
+            *viewport,
+        );
+    }
+
+    if is_loading {
+        let bounds = Rectangle {
+            x: bounds.x,
+            y: bounds.y + option_height * options.len() as f32,
+            width: bounds.width,
+            height: option_height,
+        };
+
+        if bounds.intersects(viewport) {
+            draw_loading(renderer, &style, bounds, padding, text_size);
+        }
+    }
+}
+
+/// Draws the indicator of a loading [`Menu`]; three dots after its options.
+fn draw_loading<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    bounds: Rectangle,
+    padding: Padding,
+    text_size: Pixels,
+) where
+    Renderer: renderer::Renderer,
+{
+    let diameter = (text_size.0 / 4.0).max(2.0);
+    let color = Color {
+        a: style.text_color.a * 0.5,
+        ..style.text_color
+    };
+
+    for i in 0..3 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + padding.left + diameter * 2.0 * i as f32,
+                    y: bounds.center_y() - diameter / 2.0,
+                    width: diameter,
+                    height: diameter,
+                },
+                border: border::rounded(diameter / 2.0),
+                ..renderer::Quad::default()
+            },
+            color,
+        );
     }
 }
 
@@ -560,6 +803,236 @@ where
     }
 }
 
+/// The characters typed for the type-ahead search of a [`Menu`].
+#[derive(Debug, Clone, Default)]
+struct Search {
+    query: String,
+    typed_at: Option<Instant>,
+}
+
+impl Search {
+    /// The time without typing after which the typed characters are
+    /// forgotten.
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Adds the text typed at the given moment to the query; returning the
+    /// index of the first option that starts with it, if any.
+    fn push<T: ToString>(
+        &mut self,
+        text: &str,
+        now: Instant,
+        options: &[T],
+    ) -> Option<usize> {
+        if self.typed_at.is_some_and(|typed_at| {
+            now.saturating_duration_since(typed_at) > Self::TIMEOUT
+        }) {
+            self.query.clear();
+        }
+
+        self.query.push_str(&text.to_lowercase());
+        self.typed_at = Some(now);
+
+        options.iter().position(|option| {
+            option.to_string().to_lowercase().starts_with(&self.query)
+        })
+    }
+
+    /// Forgets the query; returning true if it was not empty.
+    fn reset(&mut self) -> bool {
+        self.typed_at = None;
+
+        !std::mem::take(&mut self.query).is_empty()
+    }
+}
+
+/// Scrolls a [`Scrollable`] the least possible amount, so the given vertical
+/// range of its contents is visible.
+struct Reveal {
+    top: f32,
+    bottom: f32,
+}
+
+impl Operation<()> for Reveal {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+    ) {
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let y = if self.top < translation.y {
+            self.top
+        } else if self.bottom > translation.y + bounds.height {
+            self.bottom - bounds.height
+        } else {
+            return;
+        };
+
+        state.scroll_to(AbsoluteOffset {
+            x: translation.x,
+            y,
+        });
+    }
+}
+
+/// The options of a [`Menu`] displayed with [`RenderMode::Inline`].
+pub(crate) struct Inline<'a, 'b, T, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub options: &'a [T],
+    pub padding: Padding,
+    pub text_size: Option<Pixels>,
+    pub text_line_height: text::LineHeight,
+    pub text_shaping: text::Shaping,
+    pub font: Option<Renderer::Font>,
+    pub is_loading: bool,
+    pub class: &'a <Theme as Catalog>::Class<'b>,
+}
+
+impl<'a, 'b, T, Theme, Renderer> Inline<'a, 'b, T, Theme, Renderer>
+where
+    T: ToString,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the height of a single option.
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical()
+    }
+
+    /// Returns the height of the options when fully expanded.
+    pub fn height(&self, renderer: &Renderer) -> f32 {
+        self.option_height(renderer)
+            * (self.options.len() + usize::from(self.is_loading)) as f32
+    }
+
+    /// Returns the index of the option under the given cursor, if any.
+    ///
+    /// The `bounds` are the visible bounds of the options, which may be
+    /// partially expanded.
+    pub fn option_at(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let position = cursor.position_in(bounds)?;
+        let index = (position.y / self.option_height(renderer)) as usize;
+
+        (index < self.options.len()).then_some(index)
+    }
+
+    /// Draws the options within the given visible `bounds`.
+    pub fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        hovered_option: Option<usize>,
+        viewport: &Rectangle,
+    ) {
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let style = Catalog::style(theme, self.class);
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        height: self.height(renderer),
+                        ..bounds
+                    },
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            draw_options(
+                renderer,
+                theme,
+                self.class,
+                self.options,
+                hovered_option,
+                bounds,
+                &clip_bounds,
+                self.padding,
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+                self.text_line_height,
+                self.text_shaping,
+                self.font.unwrap_or_else(|| renderer.default_font()),
+                self.is_loading,
+            );
+        });
+    }
+}
+
+/// The animated expansion of a [`Menu`] displayed with
+/// [`RenderMode::Inline`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Expansion {
+    is_open: bool,
+    progress: f32,
+    transition: Option<(Instant, f32)>,
+}
+
+impl Expansion {
+    const DURATION: Duration = Duration::from_millis(150);
+
+    /// Opens or closes the [`Menu`]; returning true if an animation started.
+    pub fn set_open(&mut self, is_open: bool) -> bool {
+        if self.is_open == is_open {
+            return false;
+        }
+
+        self.is_open = is_open;
+        self.transition = Some((Instant::now(), self.progress));
+
+        true
+    }
+
+    /// Advances the animation; returning true if it is still running.
+    pub fn update(&mut self, now: Instant) -> bool {
+        let Some((started_at, from)) = self.transition else {
+            return false;
+        };
+
+        let target = if self.is_open { 1.0 } else { 0.0 };
+        let elapsed = now.saturating_duration_since(started_at).as_secs_f32()
+            / Self::DURATION.as_secs_f32();
+
+        if elapsed >= 1.0 {
+            self.progress = target;
+            self.transition = None;
+        } else {
+            self.progress = from + (target - from) * elapsed;
+        }
+
+        true
+    }
+
+    /// Returns the expanded fraction of the [`Menu`], eased out.
+    pub fn value(&self) -> f32 {
+        1.0 - (1.0 - self.progress).powi(3)
+    }
+}
+
 /// The appearance of a [`Menu`].
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
@@ -623,3 +1096,39 @@ pub fn default(theme: &Theme) -> Style {
         selected_background: palette.primary.strong.color.into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CITIES: [&str; 5] =
+        ["Valencia", "Barcelona", "Vigo", "bilbao", "Madrid"];
+
+    #[test]
+    fn type_ahead_matches_prefixes_ignoring_case() {
+        let mut search = Search::default();
+        let now = Instant::now();
+
+        assert_eq!(search.push("B", now, &CITIES), Some(1));
+        assert_eq!(search.push("i", now, &CITIES), Some(3));
+        assert_eq!(search.push("x", now, &CITIES), None);
+
+        assert!(search.reset());
+        assert!(!search.reset());
+        assert_eq!(search.push("v", now, &CITIES), Some(0));
+        assert_eq!(search.push("I", now, &CITIES), Some(2));
+    }
+
+    #[test]
+    fn type_ahead_forgets_after_a_timeout() {
+        let mut search = Search::default();
+        let now = Instant::now();
+
+        assert_eq!(search.push("v", now, &CITIES), Some(0));
+        assert_eq!(search.push("i", now + Search::TIMEOUT, &CITIES), Some(2));
+
+        let later = now + Search::TIMEOUT * 3;
+
+        assert_eq!(search.push("m", later, &CITIES), Some(4));
+    }
+}
@@ -44,6 +44,8 @@ use crate::core::{
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 
+use rustc_hash::FxHashMap;
+
 const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
 const THICKNESS_RATIO: f32 = 25.0;
 
@@ -252,11 +254,15 @@ where
     Renderer: core::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<state::Action>()
+        tree::Tag::of::<Memory>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(state::Action::Idle)
+        tree::State::new(Memory {
+            action: state::Action::Idle,
+            order: self.contents.iter().map(|(pane, _)| pane).collect(),
+            hidden: FxHashMap::default(),
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -267,17 +273,31 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
-        match &self.contents {
-            Contents::All(contents, _) => tree.diff_children_custom(
-                contents,
-                |state, (_, content)| content.diff(state),
-                |(_, content)| content.state(),
-            ),
-            Contents::Maximized(_, content, _) => tree.diff_children_custom(
-                &[content],
-                |state, content| content.diff(state),
-                |content| content.state(),
-            ),
+        let Memory { order, hidden, .. } = tree.state.downcast_mut::<Memory>();
+
+        // Panes are not visited in a stable order; so their trees are matched
+        // by pane. The trees of the panes hidden by a maximized pane are kept
+        // until they are visible again.
+        let mut trees = std::mem::take(hidden);
+        trees.extend(order.drain(..).zip(tree.children.drain(..)));
+
+        tree.children = self
+            .contents
+            .iter()
+            .map(|(pane, content)| {
+                if let Some(mut state) = trees.remove(&pane) {
+                    content.diff(&mut state);
+                    state
+                } else {
+                    content.state()
+                }
+            })
+            .collect();
+
+        *order = self.contents.iter().map(|(pane, _)| pane).collect();
+
+        if self.contents.is_maximized() {
+            *hidden = trees;
         }
     }
 
@@ -350,10 +370,11 @@ where
     ) -> event::Status {
         let mut event_status = event::Status::Ignored;
 
-        let action = tree.state.downcast_mut::<state::Action>();
+        let Memory { action, .. } = tree.state.downcast_mut::<Memory>();
         let node = self.contents.layout();
 
-        let on_drag = if self.drag_enabled() {
+        let on_drag_enabled = self.drag_enabled();
+        let on_drag = if on_drag_enabled {
             &self.on_drag
         } else {
             &None
@@ -420,12 +441,16 @@ where
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
                 if let Some((pane, origin)) = action.picked_pane() {
-                    if let Some(on_drag) = on_drag {
-                        if let Some(cursor_position) = cursor.position() {
-                            if cursor_position.distance(origin)
-                                > DRAG_DEADBAND_DISTANCE
+                    // A pane may be hidden by a maximized pane while it is
+                    // picked; so every drag ends, even if it is disabled now
+                    if let Some(on_drag) = &self.on_drag {
+                        let event = match cursor.position() {
+                            Some(cursor_position)
+                                if on_drag_enabled
+                                    && cursor_position.distance(origin)
+                                        > DRAG_DEADBAND_DISTANCE =>
                             {
-                                let event = if let Some(edge) =
+                                if let Some(edge) =
                                     in_edge(layout, cursor_position)
                                 {
                                     DragEvent::Dropped {
@@ -458,11 +483,12 @@ where
                                         }
                                         _ => DragEvent::Canceled { pane },
                                     }
-                                };
-
-                                shell.publish(on_drag(event));
+                                }
                             }
-                        }
+                            _ => DragEvent::Canceled { pane },
+                        };
+
+                        shell.publish(on_drag(event));
                     }
 
                     event_status = event::Status::Captured;
@@ -550,7 +576,7 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let action = tree.state.downcast_ref::<state::Action>();
+        let Memory { action, .. } = tree.state.downcast_ref::<Memory>();
 
         if action.picked_pane().is_some() {
             return mouse::Interaction::Grabbing;
@@ -617,7 +643,7 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let action = tree.state.downcast_ref::<state::Action>();
+        let Memory { action, .. } = tree.state.downcast_ref::<Memory>();
         let node = self.contents.layout();
         let resize_leeway = self.on_resize.as_ref().map(|(leeway, _)| *leeway);
 
@@ -828,6 +854,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'_, Message, Theme, Renderer>> {
         let children = self
@@ -836,7 +863,7 @@ where
             .zip(&mut tree.children)
             .zip(layout.children())
             .filter_map(|(((_, content), state), layout)| {
-                content.overlay(state, layout, renderer, translation)
+                content.overlay(state, layout, renderer, viewport, translation)
             })
             .collect::<Vec<_>>();
 
@@ -1013,7 +1040,8 @@ pub enum DragEvent {
     },
 
     /// A [`Pane`] was picked and then dropped outside of other [`Pane`]
-    /// boundaries.
+    /// boundaries, without moving it, or while it was hidden by a maximized
+    /// [`Pane`].
     Canceled {
         /// The picked [`Pane`].
         pane: Pane,
@@ -1084,6 +1112,13 @@ fn hovered_split<'a>(
     })
 }
 
+/// The state of a [`PaneGrid`] kept in its widget tree.
+struct Memory {
+    action: state::Action,
+    order: Vec<Pane>,
+    hidden: FxHashMap<Pane, Tree>,
+}
+
 /// The visible contents of the [`PaneGrid`]
 #[derive(Debug)]
 pub enum Contents<'a, T> {
@@ -317,13 +317,21 @@ impl<T> State<T> {
     /// Maximize the given [`Pane`]. Only this pane will be rendered by the
     /// [`PaneGrid`] until [`Self::restore()`] is called.
     ///
+    /// The splits of the [`State`] are kept as they are; so they can be
+    /// restored. While a [`Pane`] is maximized, the other panes cannot be
+    /// dragged nor resized. Closing the maximized [`Pane`] restores the rest.
+    ///
+    /// Nothing happens if the [`Pane`] does not exist.
+    ///
     /// [`PaneGrid`]: super::PaneGrid
     pub fn maximize(&mut self, pane: Pane) {
-        self.maximized = Some(pane);
+        if self.panes.contains_key(&pane) {
+            self.maximized = Some(pane);
+        }
     }
 
     /// Restore the currently maximized [`Pane`] to it's normal size. All panes
-    /// will be rendered by the [`PaneGrid`].
+    /// will be rendered by the [`PaneGrid`] with their previous splits.
     ///
     /// [`PaneGrid`]: super::PaneGrid
     pub fn restore(&mut self) {
@@ -447,3 +455,48 @@ impl Internal {
         &self.layout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maximized_panes_restore_their_splits() {
+        let (mut state, a) = State::new("a");
+        let (b, split) = state.split(Axis::Vertical, a, "b").unwrap();
+        let (c, _) = state.split(Axis::Horizontal, b, "c").unwrap();
+
+        state.resize(split, 0.3);
+        state.maximize(b);
+
+        assert_eq!(state.maximized(), Some(b));
+
+        state.restore();
+
+        assert_eq!(state.maximized(), None);
+        assert_eq!(state.layout().splits().count(), 2);
+        assert_eq!(
+            state.layout().split_regions(0.0, Size::new(100.0, 100.0))[&split]
+                .2,
+            0.3
+        );
+
+        state.maximize(c);
+        let _ = state.close(c);
+
+        assert_eq!(state.maximized(), None);
+        assert_eq!(
+            state
+                .layout()
+                .pane_regions(0.0, Size::new(100.0, 100.0))
+                .into_keys()
+                .collect::<Vec<_>>(),
+            vec![a, b]
+        );
+
+        // Unknown panes are not maximized
+        state.maximize(c);
+
+        assert_eq!(state.maximized(), None);
+    }
+}
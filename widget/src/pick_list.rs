@@ -10,6 +10,7 @@ use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
@@ -20,6 +21,10 @@ use std::borrow::Borrow;
 use std::f32;
 
 /// A widget for selecting a single value from a list of options.
+///
+/// The menu of a [`PickList`] opens scrolled to the selected option. While
+/// open, typing hovers the first option that starts with the typed text;
+/// see [`Menu::type_ahead`].
 #[allow(missing_debug_implementations)]
 pub struct PickList<
     'a,
@@ -49,6 +54,9 @@ pub struct PickList<
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
+    render_mode: menu::RenderMode,
+    scroll_policy: overlay::ScrollPolicy,
+    is_invalid: bool,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
 }
@@ -84,6 +92,9 @@ where
             text_shaping: text::Shaping::Basic,
             font: None,
             handle: Handle::default(),
+            render_mode: menu::RenderMode::default(),
+            scroll_policy: overlay::ScrollPolicy::Close,
+            is_invalid: false,
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
         }
@@ -140,6 +151,30 @@ where
         self
     }
 
+    /// Sets the [`menu::RenderMode`] of the [`PickList`].
+    ///
+    /// By default, the options are displayed as an overlay.
+    pub fn render_mode(mut self, render_mode: menu::RenderMode) -> Self {
+        self.render_mode = render_mode;
+        self
+    }
+
+    /// Sets the [`overlay::ScrollPolicy`] of the menu of the [`PickList`];
+    /// which decides what happens when it is scrolled while open.
+    ///
+    /// By default, the menu closes.
+    pub fn scroll_policy(mut self, policy: overlay::ScrollPolicy) -> Self {
+        self.scroll_policy = policy;
+        self
+    }
+
+    /// Marks the [`PickList`] as invalid; styling it with
+    /// [`Status::Invalid`] while it is closed.
+    pub fn invalid(mut self, is_invalid: bool) -> Self {
+        self.is_invalid = is_invalid;
+        self
+    }
+
     /// Sets the message that will be produced when the [`PickList`] is opened.
     pub fn on_open(mut self, on_open: Message) -> Self {
         self.on_open = Some(on_open);
@@ -196,6 +231,38 @@ where
         self.menu_class = class.into();
         self
     }
+
+    fn inline_menu(&self) -> menu::Inline<'_, 'a, T, Theme, Renderer> {
+        menu::Inline {
+            options: self.options.borrow(),
+            padding: self.padding,
+            text_size: self.text_size,
+            text_line_height: self.text_line_height,
+            text_shaping: self.text_shaping,
+            font: self.font,
+            is_loading: false,
+            class: &self.menu_class,
+        }
+    }
+
+    /// Returns the [`Layout`] of the field of the [`PickList`] and the one
+    /// of its inline [`Menu`], if any.
+    fn split<'b>(
+        &self,
+        layout: Layout<'b>,
+    ) -> (Layout<'b>, Option<Layout<'b>>) {
+        match self.render_mode {
+            menu::RenderMode::Overlay => (layout, None),
+            menu::RenderMode::Inline => {
+                let mut children = layout.children();
+
+                let field = children.next().unwrap_or(layout);
+                let menu = children.next();
+
+                (field, menu)
+            }
+        }
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -249,6 +316,8 @@ where
             font,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
+            max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+            font_features: &[],
             shaping: self.text_shaping,
         };
 
@@ -299,7 +368,22 @@ where
                 .expand(self.padding)
         };
 
-        layout::Node::new(size)
+        match self.render_mode {
+            menu::RenderMode::Overlay => layout::Node::new(size),
+            menu::RenderMode::Inline => {
+                let height = self.inline_menu().height(renderer)
+                    * state.expansion.value();
+
+                layout::Node::with_children(
+                    Size::new(size.width, size.height + height),
+                    vec![
+                        layout::Node::new(size),
+                        layout::Node::new(Size::new(size.width, height))
+                            .move_to((0.0, size.height)),
+                    ],
+                )
+            }
+        }
     }
 
     fn on_event(
@@ -308,11 +392,24 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let (layout, menu_layout) = self.split(layout);
+
+        // The menu may have been closed by a scroll while it was laid out
+        {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+            if std::mem::take(&mut state.is_closed_by_scroll) {
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -320,18 +417,39 @@ where
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 if state.is_open {
+                    // With an inline menu, the options are part of the layout
+                    if let Some(index) = menu_layout.and_then(|menu| {
+                        self.inline_menu().option_at(
+                            renderer,
+                            menu.bounds(),
+                            cursor,
+                        )
+                    }) {
+                        let option = &self.options.borrow()[index];
+
+                        shell.publish((self.on_select)(option.clone()));
+                    } else if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
                     // Event wasn't processed by overlay, so cursor was clicked either outside its
                     // bounds or on the drop-down, either way we close the overlay.
                     state.is_open = false;
 
-                    if let Some(on_close) = &self.on_close {
-                        shell.publish(on_close.clone());
+                    if state.expansion.set_open(false) {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
                     }
 
                     event::Status::Captured
                 } else if cursor.is_over(layout.bounds()) {
                     let selected = self.selected.as_ref().map(Borrow::borrow);
 
+                    if self.render_mode == menu::RenderMode::Inline
+                        && state.expansion.set_open(true)
+                    {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+
                     state.is_open = true;
                     state.hovered_option = self
                         .options
@@ -339,6 +457,12 @@ where
                         .iter()
                         .position(|option| Some(option) == selected);
 
+                    let _ = state.menu.reset_search();
+
+                    if let Some(index) = state.hovered_option {
+                        state.menu.reveal(index);
+                    }
+
                     if let Some(on_open) = &self.on_open {
                         shell.publish(on_open.clone());
                     }
@@ -403,6 +527,67 @@ where
 
                 event::Status::Ignored
             }
+            // An overlay menu handles type-ahead search on its own
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) if self.render_mode == menu::RenderMode::Inline => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if !state.is_open {
+                    return event::Status::Ignored;
+                }
+
+                if key == keyboard::Key::Named(keyboard::key::Named::Escape) {
+                    if state.menu.reset_search() {
+                        return event::Status::Captured;
+                    }
+                } else if let Some(text) = text.filter(|text| {
+                    !modifiers.command() && !text.chars().any(char::is_control)
+                }) {
+                    if let Some(index) =
+                        state.menu.search(&text, self.options.borrow())
+                    {
+                        state.hovered_option = Some(index);
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if let Some(menu) = menu_layout.filter(|_| state.is_open) {
+                    if let Some(index) = self.inline_menu().option_at(
+                        renderer,
+                        menu.bounds(),
+                        cursor,
+                    ) {
+                        state.hovered_option = Some(index);
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if state.expansion.update(now) {
+                    shell.invalidate_layout();
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -415,8 +600,10 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        let bounds = layout.bounds();
-        let is_mouse_over = cursor.is_over(bounds);
+        let (layout, menu_layout) = self.split(layout);
+
+        let is_mouse_over = cursor.is_over(layout.bounds())
+            || menu_layout.is_some_and(|menu| cursor.is_over(menu.bounds()));
 
         if is_mouse_over {
             mouse::Interaction::Pointer
@@ -438,6 +625,7 @@ where
         let font = self.font.unwrap_or_else(|| renderer.default_font());
         let selected = self.selected.as_ref().map(Borrow::borrow);
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let (layout, menu_layout) = self.split(layout);
 
         let bounds = layout.bounds();
         let is_mouse_over = cursor.is_over(bounds);
@@ -445,6 +633,8 @@ where
 
         let status = if state.is_open {
             Status::Opened
+        } else if self.is_invalid {
+            Status::Invalid
         } else if is_mouse_over {
             Status::Hovered
         } else {
@@ -514,6 +704,8 @@ where
                     ),
                     horizontal_alignment: alignment::Horizontal::Right,
                     vertical_alignment: alignment::Vertical::Center,
+                    max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                    font_features: &[],
                     shaping,
                 },
                 Point::new(
@@ -543,6 +735,8 @@ where
                     ),
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
+                    max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                    font_features: &[],
                     shaping: self.text_shaping,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
@@ -554,6 +748,20 @@ where
                 *viewport,
             );
         }
+
+        if let Some(menu) = menu_layout {
+            let menu_bounds = menu.bounds();
+
+            if menu_bounds.height > 0.0 {
+                self.inline_menu().draw(
+                    renderer,
+                    theme,
+                    menu_bounds,
+                    state.hovered_option,
+                    viewport,
+                );
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -561,13 +769,38 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
         let font = self.font.unwrap_or_else(|| renderer.default_font());
 
-        if state.is_open {
+        if !state.is_open {
+            state.anchor = None;
+        }
+
+        if state.is_open && self.render_mode == menu::RenderMode::Overlay {
             let bounds = layout.bounds();
+            let anchor = layout.position() + translation;
+
+            let is_scrolled = match self.scroll_policy {
+                overlay::ScrollPolicy::Reposition => {
+                    !viewport.intersects(&bounds)
+                }
+                overlay::ScrollPolicy::Close => {
+                    state.anchor.is_some_and(|last| last != anchor)
+                }
+            };
+
+            if is_scrolled {
+                state.is_open = false;
+                state.is_closed_by_scroll = true;
+                state.anchor = None;
+
+                return None;
+            }
+
+            state.anchor = Some(anchor);
 
             let on_select = &self.on_select;
 
@@ -586,13 +819,14 @@ where
             .width(bounds.width)
             .padding(self.padding)
             .font(font)
-            .text_shaping(self.text_shaping);
+            .text_shaping(self.text_shaping)
+            .type_ahead(true);
 
             if let Some(text_size) = self.text_size {
                 menu = menu.text_size(text_size);
             }
 
-            Some(menu.overlay(layout.position() + translation, bounds.height))
+            Some(menu.overlay(anchor, bounds.height))
         } else {
             None
         }
@@ -622,6 +856,9 @@ struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_closed_by_scroll: bool,
+    anchor: Option<Point>,
+    expansion: menu::Expansion,
     hovered_option: Option<usize>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
@@ -634,6 +871,9 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_closed_by_scroll: false,
+            anchor: None,
+            expansion: menu::Expansion::default(),
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
@@ -700,6 +940,8 @@ pub enum Status {
     Hovered,
     /// The [`PickList`] is open.
     Opened,
+    /// The [`PickList`] holds an invalid selection.
+    Invalid,
 }
 
 /// The appearance of a pick list.
@@ -780,5 +1022,12 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             },
             ..active
         },
+        Status::Invalid => Style {
+            border: Border {
+                color: palette.danger.base.color,
+                ..active.border
+            },
+            ..active
+        },
     }
 }
@@ -1,11 +1,14 @@
 //! Provide progress feedback to your users.
+use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text;
 use crate::core::widget::Tree;
 use crate::core::{
-    self, Background, Element, Layout, Length, Rectangle, Size, Theme, Widget,
+    self, Background, Color, Element, Font, Layout, Length, Pixels, Rectangle,
+    Size, Theme, Widget,
 };
 
 use std::ops::RangeInclusive;
@@ -29,8 +32,10 @@ where
 {
     range: RangeInclusive<f32>,
     value: f32,
+    buffered: Vec<RangeInclusive<f32>>,
     width: Length,
     height: Option<Length>,
+    label: Option<Box<dyn Fn(f32) -> String + 'a>>,
     class: Theme::Class<'a>,
 }
 
@@ -50,12 +55,24 @@ where
         ProgressBar {
             value: value.clamp(*range.start(), *range.end()),
             range,
+            buffered: Vec::new(),
             width: Length::Fill,
             height: None,
+            label: None,
             class: Theme::default(),
         }
     }
 
+    /// Adds a buffered range to the [`ProgressBar`], drawn as a differently
+    /// styled segment behind its bar.
+    ///
+    /// This method can be called multiple times to show disjoint ranges.
+    /// Ranges are clamped to the range of the [`ProgressBar`].
+    pub fn buffered(mut self, range: RangeInclusive<f32>) -> Self {
+        self.buffered.push(range);
+        self
+    }
+
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -68,6 +85,16 @@ where
         self
     }
 
+    /// Sets the function that formats the current value of the
+    /// [`ProgressBar`] into a label, drawn at its center.
+    ///
+    /// The label is drawn with a different color over the bar, so it stays
+    /// readable as the bar fills up.
+    pub fn label(mut self, label: impl Fn(f32) -> String + 'a) -> Self {
+        self.label = Some(Box::new(label));
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -91,7 +118,7 @@ impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for ProgressBar<'a, Theme>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer<Font = Font>,
 {
     fn size(&self) -> Size<Length> {
         Size {
@@ -121,7 +148,7 @@ where
         _style: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
         let (range_start, range_end) = self.range.clone().into_inner();
@@ -144,6 +171,34 @@ where
             style.background,
         );
 
+        if range_start < range_end {
+            let position = |value: f32| {
+                bounds.width
+                    * (value.clamp(range_start, range_end) - range_start)
+                    / (range_end - range_start)
+            };
+
+            for range in &self.buffered {
+                let start = position(*range.start());
+                let end = position(*range.end());
+
+                if end > start {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + start,
+                                width: end - start,
+                                ..bounds
+                            },
+                            border: border::rounded(style.border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        style.buffered,
+                    );
+                }
+            }
+        }
+
         if active_progress_width > 0.0 {
             renderer.fill_quad(
                 renderer::Quad {
@@ -157,6 +212,45 @@ where
                 style.bar,
             );
         }
+
+        if let Some(label) = &self.label {
+            let text = text::Text {
+                content: label(self.value),
+                font: style.label.font,
+                size: style.label.size,
+                line_height: text::LineHeight::default(),
+                bounds: bounds.size(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                font_features: &[],
+                shaping: text::Shaping::Basic,
+            };
+
+            let bar = Rectangle {
+                width: active_progress_width,
+                ..bounds
+            };
+
+            let track = Rectangle {
+                x: bounds.x + active_progress_width,
+                width: bounds.width - active_progress_width,
+                ..bounds
+            };
+
+            for (region, color) in
+                [(bar, style.label.bar_color), (track, style.label.color)]
+            {
+                if let Some(clip_bounds) = region.intersection(viewport) {
+                    renderer.fill_text(
+                        text.clone(),
+                        bounds.center(),
+                        color,
+                        clip_bounds,
+                    );
+                }
+            }
+        }
     }
 }
 
@@ -165,7 +259,7 @@ impl<'a, Message, Theme, Renderer> From<ProgressBar<'a, Theme>>
 where
     Message: 'a,
     Theme: 'a + Catalog,
-    Renderer: 'a + core::Renderer,
+    Renderer: 'a + text::Renderer<Font = Font>,
 {
     fn from(
         progress_bar: ProgressBar<'a, Theme>,
@@ -181,8 +275,25 @@ pub struct Style {
     pub background: Background,
     /// The [`Background`] of the bar of the progress bar.
     pub bar: Background,
+    /// The [`Background`] of the buffered ranges of the progress bar.
+    pub buffered: Background,
     /// The [`Border`] of the progress bar.
     pub border: Border,
+    /// The [`Label`] of the progress bar.
+    pub label: Label,
+}
+
+/// The appearance of the label of a progress indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Label {
+    /// The [`Font`] of the label.
+    pub font: Font,
+    /// The size of the label.
+    pub size: Pixels,
+    /// The [`Color`] of the label over the track.
+    pub color: Color,
+    /// The [`Color`] of the label over the bar.
+    pub bar_color: Color,
 }
 
 /// The theme catalog of a [`ProgressBar`].
@@ -221,6 +332,8 @@ pub fn primary(theme: &Theme) -> Style {
     styled(
         palette.background.strong.color,
         palette.primary.strong.color,
+        palette.background.strong.text,
+        palette.primary.strong.text,
     )
 }
 
@@ -231,6 +344,8 @@ pub fn secondary(theme: &Theme) -> Style {
     styled(
         palette.background.strong.color,
         palette.secondary.base.color,
+        palette.background.strong.text,
+        palette.secondary.base.text,
     )
 }
 
@@ -238,23 +353,42 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    styled(palette.background.strong.color, palette.success.base.color)
+    styled(
+        palette.background.strong.color,
+        palette.success.base.color,
+        palette.background.strong.text,
+        palette.success.base.text,
+    )
 }
 
 /// The danger style of a [`ProgressBar`].
 pub fn danger(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    styled(palette.background.strong.color, palette.danger.base.color)
+    styled(
+        palette.background.strong.color,
+        palette.danger.base.color,
+        palette.background.strong.text,
+        palette.danger.base.text,
+    )
 }
 
 fn styled(
-    background: impl Into<Background>,
-    bar: impl Into<Background>,
+    background: Color,
+    bar: Color,
+    text: Color,
+    bar_text: Color,
 ) -> Style {
     Style {
         background: background.into(),
         bar: bar.into(),
+        buffered: bar.scale_alpha(0.35).into(),
         border: border::rounded(2),
+        label: Label {
+            font: Font::DEFAULT,
+            size: Pixels(14.0),
+            color: text,
+            bar_color: bar_text,
+        },
     }
 }
@@ -242,8 +242,10 @@ where
                     self.text_line_height,
                     self.text_size,
                     self.font,
+                    &[],
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
+                    text::DEFAULT_MAX_SPACE_FACTOR,
                     self.text_shaping,
                 )
             },
@@ -1,5 +1,4 @@
 //! Distribute content horizontally.
-use crate::core::alignment::{self, Alignment};
 use crate::core::event::{self, Event};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
@@ -7,8 +6,8 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::{Operation, Tree};
 use crate::core::{
-    Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size,
-    Vector, Widget,
+    Alignment, Clipboard, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
 };
 
 /// A container that distributes its contents horizontally.
@@ -18,6 +17,10 @@ pub struct Row<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     padding: Padding,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
+    max_width: f32,
+    max_height: f32,
     align: Alignment,
     clip: bool,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
@@ -61,6 +64,10 @@ where
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
+            min_width: 0.0,
+            min_height: 0.0,
+            max_width: f32::INFINITY,
+            max_height: f32::INFINITY,
             align: Alignment::Start,
             clip: false,
             children,
@@ -95,9 +102,36 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Row`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Row`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = min_height.into().0;
+        self
+    }
+
+    /// Sets the maximum width of the [`Row`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the maximum height of the [`Row`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max_height = max_height.into().0;
+        self
+    }
+
     /// Sets the vertical alignment of the contents of the [`Row`] .
-    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
-        self.align = Alignment::from(align.into());
+    ///
+    /// Use [`Alignment::Baseline`] to line up the text baselines of the
+    /// contents.
+    pub fn align_y(mut self, align: impl Into<Alignment>) -> Self {
+        self.align = align.into();
         self
     }
 
@@ -142,6 +176,18 @@ where
     ) -> Self {
         children.into_iter().fold(self, Self::push)
     }
+
+    /// Turns the [`Row`] into a [`Wrapping`] row.
+    ///
+    /// The children of a [`Wrapping`] row keep their intrinsic size, and they
+    /// wrap onto a new line when the row runs out of width.
+    pub fn wrapping(self) -> Wrapping<'a, Message, Theme, Renderer> {
+        Wrapping {
+            row: self,
+            line_spacing: None,
+            justify: Justify::Start,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Default for Row<'a, Message, Renderer>
@@ -192,10 +238,15 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let limits = limits.constrain(
+            Size::new(self.min_width, self.min_height),
+            Size::new(self.max_width, self.max_height),
+        );
+
         layout::flex::resolve(
             layout::flex::Axis::Horizontal,
             renderer,
-            limits,
+            &limits,
             self.width,
             self.height,
             self.padding,
@@ -316,6 +367,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         overlay::from_children(
@@ -323,6 +375,7 @@ where
             tree,
             layout,
             renderer,
+            viewport,
             translation,
         )
     }
@@ -339,3 +392,438 @@ where
         Self::new(row)
     }
 }
+
+/// A [`Row`] that wraps its children onto new lines when it runs out of
+/// width; also known as a flow layout.
+///
+/// The vertical alignment of the [`Row`] applies to each line separately.
+///
+/// Children cannot fill the width of a [`Wrapping`] row, since they would
+/// take a whole line each.
+#[allow(missing_debug_implementations)]
+pub struct Wrapping<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    row: Row<'a, Message, Theme, Renderer>,
+    line_spacing: Option<f32>,
+    justify: Justify,
+}
+
+impl<'a, Message, Theme, Renderer> Wrapping<'a, Message, Theme, Renderer> {
+    /// Sets the vertical spacing _between_ lines.
+    ///
+    /// By default, it is the same as the spacing of the [`Row`].
+    pub fn line_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.line_spacing = Some(amount.into().0);
+        self
+    }
+
+    /// Sets the [`Justify`] strategy of the lines of the [`Wrapping`] row.
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+}
+
+/// The horizontal distribution of the children of each line of a
+/// [`Wrapping`] row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    /// Pack the children at the start of the line.
+    #[default]
+    Start,
+    /// Pack the children at the center of the line.
+    Center,
+    /// Pack the children at the end of the line.
+    End,
+    /// Spread the free space of the line evenly between the children.
+    ///
+    /// A line with a single child is packed at its start.
+    SpaceBetween,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.row.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.row.diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.row.size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let row = &self.row;
+
+        let limits = limits
+            .constrain(
+                Size::new(row.min_width, row.min_height),
+                Size::new(row.max_width, row.max_height),
+            )
+            .width(row.width)
+            .height(row.height)
+            .shrink(row.padding);
+
+        let max_width = limits.max().width;
+        let child_limits = limits.loose();
+        let line_spacing = self.line_spacing.unwrap_or(row.spacing);
+
+        let mut nodes = Vec::with_capacity(row.children.len());
+        let mut lines = Vec::new();
+        let mut line_start = 0;
+        let mut line_width = 0.0;
+
+        for (i, (child, state)) in
+            row.children.iter().zip(&mut tree.children).enumerate()
+        {
+            debug_assert!(
+                !child.as_widget().size().width.is_fill(),
+                "The children of a wrapping row cannot fill its width"
+            );
+
+            let node = child.as_widget().layout(state, renderer, &child_limits);
+            let width = node.size().width;
+
+            if i > line_start && line_width + row.spacing + width > max_width {
+                lines.push((line_start..i, line_width));
+
+                line_start = i;
+                line_width = width;
+            } else if i > line_start {
+                line_width += row.spacing + width;
+            } else {
+                line_width = width;
+            }
+
+            nodes.push(node);
+        }
+
+        if line_start < nodes.len() {
+            lines.push((line_start..nodes.len(), line_width));
+        }
+
+        // Lines are stacked first, since justifying them needs the final width
+        let mut height = 0.0;
+        let mut metrics = Vec::with_capacity(lines.len());
+
+        for (i, (range, _)) in lines.iter().enumerate() {
+            let line = &nodes[range.clone()];
+            let ascent = line.iter().map(baseline).fold(0.0, f32::max);
+
+            let line_height = line
+                .iter()
+                .map(|node| match row.align {
+                    Alignment::Baseline => {
+                        ascent - baseline(node) + node.size().height
+                    }
+                    _ => node.size().height,
+                })
+                .fold(0.0, f32::max);
+
+            if i > 0 {
+                height += line_spacing;
+            }
+
+            metrics.push((height, line_height, ascent));
+            height += line_height;
+        }
+
+        let width = lines.iter().map(|(_, width)| *width).fold(0.0, f32::max);
+        let size =
+            limits.resolve(row.width, row.height, Size::new(width, height));
+
+        for ((range, line_width), (y, line_height, ascent)) in
+            lines.into_iter().zip(metrics)
+        {
+            let free_space = (size.width - line_width).max(0.0);
+            let count = range.len();
+
+            let (mut x, spacing) = match self.justify {
+                Justify::Start => (0.0, row.spacing),
+                Justify::Center => (free_space / 2.0, row.spacing),
+                Justify::End => (free_space, row.spacing),
+                Justify::SpaceBetween if count > 1 => {
+                    (0.0, row.spacing + free_space / (count - 1) as f32)
+                }
+                Justify::SpaceBetween => (0.0, row.spacing),
+            };
+
+            for node in &mut nodes[range] {
+                let node_size = node.size();
+
+                let offset = match row.align {
+                    Alignment::Start => 0.0,
+                    Alignment::Center => (line_height - node_size.height) / 2.0,
+                    Alignment::End => line_height - node_size.height,
+                    Alignment::Baseline => ascent - baseline(node),
+                };
+
+                node.move_to_mut(Point::new(
+                    row.padding.left + x,
+                    row.padding.top + y + offset,
+                ));
+
+                x += node_size.width + spacing;
+            }
+        }
+
+        let baseline = nodes.iter().find_map(layout::Node::parent_baseline);
+
+        layout::Node::with_children(size.expand(row.padding), nodes)
+            .with_baseline(baseline)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.row.operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.row.on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.row
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.row
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.row
+            .overlay(tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wrapping<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(row: Wrapping<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(row)
+    }
+}
+
+/// Returns the baseline of a [`layout::Node`] for the purposes of alignment;
+/// which is its bottom if it has no text.
+fn baseline(node: &layout::Node) -> f32 {
+    node.baseline().unwrap_or(node.size().height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Theme;
+    use crate::Space;
+
+    fn size(row: Row<'static, (), Theme, ()>) -> Size {
+        let element = Element::from(row);
+        let mut tree = Tree::new(&element);
+
+        element
+            .as_widget()
+            .layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+            )
+            .size()
+    }
+
+    #[test]
+    fn fill_respects_the_maximum() {
+        assert_eq!(
+            size(
+                Row::with_children([Space::new(10, 10).into()])
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .max_width(200)
+                    .max_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn shrink_respects_the_minimum() {
+        assert_eq!(
+            size(
+                Row::with_children([Space::new(10, 10).into()])
+                    .min_width(200)
+                    .min_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    /// Lays out the [`Wrapping`] row in the given width; returning its size
+    /// and the bounds of its children.
+    fn wrap(
+        row: Wrapping<'static, (), Theme, ()>,
+        width: f32,
+    ) -> (Size, Vec<Rectangle>) {
+        let element = Element::from(row);
+        let mut tree = Tree::new(&element);
+
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(width, 1000.0)),
+        );
+
+        (
+            node.size(),
+            node.children().iter().map(layout::Node::bounds).collect(),
+        )
+    }
+
+    fn spaces(
+        sizes: impl IntoIterator<Item = (u16, u16)>,
+    ) -> Row<'static, (), Theme, ()> {
+        Row::with_children(
+            sizes
+                .into_iter()
+                .map(|(width, height)| Space::new(width, height).into()),
+        )
+    }
+
+    #[test]
+    fn wrapping_fits_lines_exactly() {
+        let (size, bounds) =
+            wrap(spaces([(30, 10); 4]).spacing(5).wrapping(), 100.0);
+
+        assert_eq!(size, Size::new(100.0, 25.0));
+        assert_eq!(
+            bounds,
+            vec![
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(30.0, 10.0)),
+                Rectangle::new(Point::new(35.0, 0.0), Size::new(30.0, 10.0)),
+                Rectangle::new(Point::new(70.0, 0.0), Size::new(30.0, 10.0)),
+                Rectangle::new(Point::new(0.0, 15.0), Size::new(30.0, 10.0)),
+            ]
+        );
+
+        let (_, bounds) =
+            wrap(spaces([(30, 10); 4]).spacing(5).wrapping(), 99.0);
+
+        assert_eq!(bounds[2].position(), Point::new(0.0, 15.0));
+    }
+
+    #[test]
+    fn wrapping_gives_wide_children_a_line_of_their_own() {
+        let (size, bounds) = wrap(
+            spaces([(40, 10), (150, 20), (40, 10)])
+                .spacing(5)
+                .wrapping()
+                .line_spacing(2),
+            100.0,
+        );
+
+        assert_eq!(size, Size::new(100.0, 44.0));
+        assert_eq!(
+            bounds,
+            vec![
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(40.0, 10.0)),
+                Rectangle::new(Point::new(0.0, 12.0), Size::new(100.0, 20.0)),
+                Rectangle::new(Point::new(0.0, 34.0), Size::new(40.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrapping_justifies_and_aligns_each_line() {
+        let row = || {
+            spaces([(20, 10), (20, 20), (20, 10), (50, 10)])
+                .spacing(10)
+                .width(100)
+                .align_y(Alignment::Center)
+        };
+
+        let (_, bounds) =
+            wrap(row().wrapping().justify(Justify::Center), 100.0);
+
+        assert_eq!(
+            bounds.iter().map(Rectangle::position).collect::<Vec<_>>(),
+            vec![
+                Point::new(10.0, 5.0),
+                Point::new(40.0, 0.0),
+                Point::new(70.0, 5.0),
+                Point::new(25.0, 30.0),
+            ]
+        );
+
+        let (_, bounds) =
+            wrap(row().wrapping().justify(Justify::SpaceBetween), 100.0);
+
+        assert_eq!(
+            bounds.iter().map(Rectangle::position).collect::<Vec<_>>(),
+            vec![
+                Point::new(0.0, 5.0),
+                Point::new(40.0, 0.0),
+                Point::new(80.0, 5.0),
+                Point::new(0.0, 30.0),
+            ]
+        );
+    }
+}
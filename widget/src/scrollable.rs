@@ -7,10 +7,12 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     self, Background, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
@@ -18,7 +20,10 @@ use crate::core::{
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 
-pub use operation::scrollable::{AbsoluteOffset, RelativeOffset};
+pub use operation::scrollable::{AbsoluteOffset, Easing, RelativeOffset};
+
+/// The default distance scrolled by the arrow keys.
+const DEFAULT_LINE_SIZE: f32 = 60.0;
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
@@ -35,9 +40,20 @@ pub struct Scrollable<
     id: Option<Id>,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
+    max_width: f32,
+    max_height: f32,
     direction: Direction,
+    propagation: Propagation,
+    focusable: bool,
+    line_size: f32,
+    page_size: Option<f32>,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    on_reach_start: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_reach_end: Option<Box<dyn Fn() -> Message + 'a>>,
+    reach_threshold: f32,
     class: Theme::Class<'a>,
 }
 
@@ -54,9 +70,20 @@ where
             id: None,
             width: Length::Shrink,
             height: Length::Shrink,
+            min_width: 0.0,
+            min_height: 0.0,
+            max_width: f32::INFINITY,
+            max_height: f32::INFINITY,
             direction: Direction::default(),
+            propagation: Propagation::default(),
+            focusable: false,
+            line_size: DEFAULT_LINE_SIZE,
+            page_size: None,
             content: content.into(),
             on_scroll: None,
+            on_reach_start: None,
+            on_reach_end: None,
+            reach_threshold: 0.0,
             class: Theme::default(),
         }
         .validate()
@@ -64,28 +91,77 @@ where
 
     fn validate(mut self) -> Self {
         let size_hint = self.content.as_widget().size_hint();
+        let is_auto = matches!(self.direction, Direction::Auto { .. });
 
         debug_assert!(
-            self.direction.vertical().is_none() || !size_hint.height.is_fill(),
+            is_auto
+                || self.direction.vertical().is_none()
+                || !size_hint.height.is_fill(),
             "scrollable content must not fill its vertical scrolling axis"
         );
 
         debug_assert!(
-            self.direction.horizontal().is_none() || !size_hint.width.is_fill(),
+            is_auto
+                || self.direction.horizontal().is_none()
+                || !size_hint.width.is_fill(),
             "scrollable content must not fill its horizontal scrolling axis"
         );
 
-        if self.direction.horizontal().is_none() {
+        let (scrolls_x, scrolls_y) = self.axes();
+
+        if !scrolls_x {
             self.width = self.width.enclose(size_hint.width);
         }
 
-        if self.direction.vertical().is_none() {
+        if !scrolls_y {
             self.height = self.height.enclose(size_hint.height);
         }
 
         self
     }
 
+    /// Returns whether the content may scroll horizontally and vertically;
+    /// before knowing if it overflows.
+    ///
+    /// With [`Direction::Auto`], content that fills an axis never scrolls
+    /// on it.
+    fn axes(&self) -> (bool, bool) {
+        match self.direction {
+            Direction::Auto { .. } => {
+                let size = self.content.as_widget().size_hint();
+
+                (!size.width.is_fill(), !size.height.is_fill())
+            }
+            direction => (
+                direction.horizontal().is_some(),
+                direction.vertical().is_some(),
+            ),
+        }
+    }
+
+    /// Returns the space reserved for embedded scrollbars on the right and
+    /// bottom of the [`Scrollable`]; given where its content overflows.
+    fn gutter(&self, overflow: (bool, bool)) -> (f32, f32) {
+        fn space(scrollbar: &Scrollbar) -> f32 {
+            scrollbar.spacing.map_or(0.0, |spacing| {
+                scrollbar.width + scrollbar.margin * 2.0 + spacing
+            })
+        }
+
+        match &self.direction {
+            Direction::Vertical(vertical) => (space(vertical), 0.0),
+            Direction::Horizontal(horizontal) => (0.0, space(horizontal)),
+            Direction::Both { .. } => (0.0, 0.0),
+            Direction::Auto {
+                vertical,
+                horizontal,
+            } => (
+                if overflow.1 { space(vertical) } else { 0.0 },
+                if overflow.0 { space(horizontal) } else { 0.0 },
+            ),
+        }
+    }
+
     /// Creates a new [`Scrollable`] with the given [`Direction`].
     pub fn direction(mut self, direction: impl Into<Direction>) -> Self {
         self.direction = direction.into();
@@ -110,6 +186,70 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Scrollable`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Scrollable`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = min_height.into().0;
+        self
+    }
+
+    /// Sets the maximum width of the [`Scrollable`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the maximum height of the [`Scrollable`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max_height = max_height.into().0;
+        self
+    }
+
+    /// Sets the [`Propagation`] of the mouse wheel events the [`Scrollable`]
+    /// cannot handle; for instance, because it is at its scrolling limit.
+    ///
+    /// By default, these events bubble up to any ancestor [`Scrollable`].
+    pub fn scroll_propagation(mut self, propagation: Propagation) -> Self {
+        self.propagation = propagation;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] can be focused.
+    ///
+    /// A focused [`Scrollable`] can be scrolled with the arrow keys, as well
+    /// as `PageUp`, `PageDown`, `Home` and `End`. It is focused by clicking
+    /// on it or with any focus operation; like [`focus_next`].
+    ///
+    /// By default, a [`Scrollable`] is not focusable.
+    ///
+    /// [`focus_next`]: crate::focus_next
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.focusable = focusable;
+        self
+    }
+
+    /// Sets the distance scrolled by the arrow keys when the [`Scrollable`]
+    /// is focused.
+    pub fn line_size(mut self, line_size: impl Into<Pixels>) -> Self {
+        self.line_size = line_size.into().0;
+        self
+    }
+
+    /// Sets the distance scrolled by `PageUp` and `PageDown` when the
+    /// [`Scrollable`] is focused.
+    ///
+    /// By default, the size of the visible area of the [`Scrollable`] is
+    /// used.
+    pub fn page_size(mut self, page_size: impl Into<Pixels>) -> Self {
+        self.page_size = Some(page_size.into().0);
+        self
+    }
+
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
     /// The function takes the [`Viewport`] of the [`Scrollable`]
@@ -118,6 +258,47 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Scrollable`] is
+    /// scrolled within the [`reach_threshold`] of its start.
+    ///
+    /// The message is only produced once; until the content of the
+    /// [`Scrollable`] grows.
+    ///
+    /// [`reach_threshold`]: Self::reach_threshold
+    pub fn on_reach_start(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_reach_start = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Scrollable`] is
+    /// scrolled within the [`reach_threshold`] of its end.
+    ///
+    /// The message is only produced once; until the content of the
+    /// [`Scrollable`] grows. This is useful to load more items lazily.
+    ///
+    /// [`reach_threshold`]: Self::reach_threshold
+    pub fn on_reach_end(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_reach_end = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the distance from the start or the end of the [`Scrollable`] at
+    /// which they are considered reached.
+    ///
+    /// The start and the end follow the [`Anchor`] of each scrolling axis.
+    ///
+    /// By default, it is zero.
+    pub fn reach_threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.reach_threshold = threshold.into().0;
+        self
+    }
+
     /// Anchors the vertical [`Scrollable`] direction to the top.
     pub fn anchor_top(self) -> Self {
         self.anchor_y(Anchor::Start)
@@ -138,11 +319,18 @@ where
         self.anchor_x(Anchor::End)
     }
 
+    /// Sets the [`Anchor`] of both directions of the [`Scrollable`], if
+    /// applicable.
+    pub fn anchor(self, alignment: Anchor) -> Self {
+        self.anchor_x(alignment).anchor_y(alignment)
+    }
+
     /// Sets the [`Anchor`] of the horizontal direction of the [`Scrollable`], if applicable.
     pub fn anchor_x(mut self, alignment: Anchor) -> Self {
         match &mut self.direction {
             Direction::Horizontal(horizontal)
-            | Direction::Both { horizontal, .. } => {
+            | Direction::Both { horizontal, .. }
+            | Direction::Auto { horizontal, .. } => {
                 horizontal.alignment = alignment;
             }
             Direction::Vertical { .. } => {}
@@ -155,7 +343,8 @@ where
     pub fn anchor_y(mut self, alignment: Anchor) -> Self {
         match &mut self.direction {
             Direction::Vertical(vertical)
-            | Direction::Both { vertical, .. } => {
+            | Direction::Both { vertical, .. }
+            | Direction::Auto { vertical, .. } => {
                 vertical.alignment = alignment;
             }
             Direction::Horizontal { .. } => {}
@@ -175,6 +364,15 @@ where
             | Direction::Vertical(scrollbar) => {
                 scrollbar.spacing = Some(new_spacing.into().0);
             }
+            Direction::Auto {
+                vertical,
+                horizontal,
+            } => {
+                let spacing = Some(new_spacing.into().0);
+
+                vertical.spacing = spacing;
+                horizontal.spacing = spacing;
+            }
             Direction::Both { .. } => {}
         }
 
@@ -214,6 +412,18 @@ pub enum Direction {
         /// The properties of the horizontal scrollbar.
         horizontal: Scrollbar,
     },
+    /// Vertical and horizontal scrolling, only on the axes where the content
+    /// overflows.
+    ///
+    /// The scrollbar of an axis where the content fits is not shown and it
+    /// takes no space, even if embedded. Content that fills an axis never
+    /// overflows it.
+    Auto {
+        /// The properties of the vertical scrollbar.
+        vertical: Scrollbar,
+        /// The properties of the horizontal scrollbar.
+        horizontal: Scrollbar,
+    },
 }
 
 impl Direction {
@@ -221,7 +431,9 @@ impl Direction {
     pub fn horizontal(&self) -> Option<&Scrollbar> {
         match self {
             Self::Horizontal(scrollbar) => Some(scrollbar),
-            Self::Both { horizontal, .. } => Some(horizontal),
+            Self::Both { horizontal, .. } | Self::Auto { horizontal, .. } => {
+                Some(horizontal)
+            }
             Self::Vertical(_) => None,
         }
     }
@@ -230,10 +442,38 @@ impl Direction {
     pub fn vertical(&self) -> Option<&Scrollbar> {
         match self {
             Self::Vertical(scrollbar) => Some(scrollbar),
-            Self::Both { vertical, .. } => Some(vertical),
+            Self::Both { vertical, .. } | Self::Auto { vertical, .. } => {
+                Some(vertical)
+            }
             Self::Horizontal(_) => None,
         }
     }
+
+    /// Resolves a [`Direction::Auto`] into the [`Direction`] of the axes
+    /// where the content overflows; horizontally and vertically.
+    fn resolve(self, overflow: (bool, bool)) -> Self {
+        let Self::Auto {
+            vertical,
+            horizontal,
+        } = self
+        else {
+            return self;
+        };
+
+        match overflow {
+            (true, true) => Self::Both {
+                vertical,
+                horizontal,
+            },
+            (true, false) => Self::Horizontal(horizontal),
+            (false, true) => Self::Vertical(vertical),
+            // A floating scrollbar is hidden while the content fits
+            (false, false) => Self::Vertical(Scrollbar {
+                spacing: None,
+                ..vertical
+            }),
+        }
+    }
 }
 
 impl Default for Direction {
@@ -313,9 +553,31 @@ pub enum Anchor {
     #[default]
     Start,
     /// Content is aligned to the end of the [`Viewport`].
+    ///
+    /// When the content changes size, a [`Scrollable`] at its end stays
+    /// at its end—like a chat log. Otherwise, the visible content stays
+    /// in place; growth is assumed to be prepended when the [`Scrollable`]
+    /// is within the [`reach_threshold`] of its start, and appended
+    /// anywhere else.
+    ///
+    /// [`reach_threshold`]: Scrollable::reach_threshold
     End,
 }
 
+/// What a [`Scrollable`] does with the mouse wheel events it cannot handle.
+///
+/// A [`Scrollable`] handles a wheel event when the cursor is over it and it
+/// can scroll further along the dominant axis of the event. Since inner
+/// scrollables see events first, the innermost one that can scroll wins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Propagation {
+    /// The event is passed to the ancestors of the [`Scrollable`].
+    #[default]
+    Bubble,
+    /// The event is captured, even if the [`Scrollable`] did not scroll.
+    Stop,
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Scrollable<'a, Message, Theme, Renderer>
 where
@@ -351,55 +613,90 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (right_padding, bottom_padding) = match self.direction {
-            Direction::Vertical(Scrollbar {
-                width,
-                margin,
-                spacing: Some(spacing),
-                ..
-            }) => (width + margin * 2.0 + spacing, 0.0),
-            Direction::Horizontal(Scrollbar {
-                width,
-                margin,
-                spacing: Some(spacing),
-                ..
-            }) => (0.0, width + margin * 2.0 + spacing),
-            _ => (0.0, 0.0),
+        let limits = limits.constrain(
+            Size::new(self.min_width, self.min_height),
+            Size::new(self.max_width, self.max_height),
+        );
+
+        let (scrolls_x, scrolls_y) = self.axes();
+
+        let layout = |tree: &mut Tree, gutter: (f32, f32)| {
+            layout::padded(
+                &limits,
+                self.width,
+                self.height,
+                Padding {
+                    right: gutter.0,
+                    bottom: gutter.1,
+                    ..Padding::ZERO
+                },
+                |limits| {
+                    let child_limits = layout::Limits::new(
+                        Size::new(limits.min().width, limits.min().height),
+                        Size::new(
+                            if scrolls_x {
+                                f32::INFINITY
+                            } else {
+                                limits.max().width
+                            },
+                            if scrolls_y {
+                                f32::MAX
+                            } else {
+                                limits.max().height
+                            },
+                        ),
+                    );
+
+                    self.content.as_widget().layout(
+                        &mut tree.children[0],
+                        renderer,
+                        &child_limits,
+                    )
+                },
+            )
         };
 
-        layout::padded(
-            limits,
-            self.width,
-            self.height,
-            Padding {
-                right: right_padding,
-                bottom: bottom_padding,
-                ..Padding::ZERO
-            },
-            |limits| {
-                let child_limits = layout::Limits::new(
-                    Size::new(limits.min().width, limits.min().height),
-                    Size::new(
-                        if self.direction.horizontal().is_some() {
-                            f32::INFINITY
-                        } else {
-                            limits.max().width
-                        },
-                        if self.direction.vertical().is_some() {
-                            f32::MAX
-                        } else {
-                            limits.max().height
-                        },
-                    ),
+        let mut overflow = (false, false);
+        let mut node = layout(tree, self.gutter(overflow));
+
+        // The gutter of an embedded scrollbar can only make the content
+        // overflow more; so scrollbars are only ever enabled, and the
+        // layout settles after a couple of passes without oscillating
+        if let Direction::Auto { .. } = self.direction {
+            loop {
+                let gutter = self.gutter(overflow);
+                let viewport = node.size() - Size::new(gutter.0, gutter.1);
+                let content = node.children()[0].size();
+
+                let next = (
+                    overflow.0 || (scrolls_x && content.width > viewport.width),
+                    overflow.1
+                        || (scrolls_y && content.height > viewport.height),
                 );
 
-                self.content.as_widget().layout(
-                    &mut tree.children[0],
-                    renderer,
-                    &child_limits,
-                )
-            },
-        )
+                if next == overflow {
+                    break;
+                }
+
+                overflow = next;
+
+                if self.gutter(overflow) != gutter {
+                    node = layout(tree, self.gutter(overflow));
+                }
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        state.overflow = overflow;
+
+        state.follow_content(
+            self.direction.resolve(overflow),
+            node.size(),
+            node.children()[0].size(),
+            self.reach_threshold,
+        );
+
+        node
     }
 
     fn operate(
@@ -410,15 +707,28 @@ where
         operation: &mut dyn Operation<()>,
     ) {
         let state = tree.state.downcast_mut::<State>();
+        let direction = self.direction.resolve(state.overflow);
 
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let translation =
-            state.translation(self.direction, bounds, content_bounds);
+        let translation = state.translation(direction, bounds, content_bounds);
+
+        if self.focusable {
+            operation.focusable(
+                state,
+                self.id.as_ref().map(|id| &id.0),
+                bounds,
+            );
+        }
 
         operation.scrollable(
-            state,
+            &mut Handle {
+                state,
+                direction,
+                bounds,
+                content_bounds,
+            },
             self.id.as_ref().map(|id| &id.0),
             bounds,
             translation,
@@ -450,6 +760,7 @@ where
         _viewport: &Rectangle,
     ) -> event::Status {
         let state = tree.state.downcast_mut::<State>();
+        let direction = self.direction.resolve(state.overflow);
         let bounds = layout.bounds();
         let cursor_over_scrollable = cursor.position_over(bounds);
 
@@ -457,11 +768,30 @@ where
         let content_bounds = content.bounds();
 
         let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+            Scrollbars::new(state, direction, bounds, content_bounds);
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
 
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if state.animation.is_some() {
+                if state.animate(now, bounds, content_bounds) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                let _ =
+                    self.notify_on_scroll(state, bounds, content_bounds, shell);
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if cursor_over_scrollable.is_none() {
+                state.is_focused = false;
+            }
+        }
+
         if let Some(scroller_grabbed_at) = state.y_scroller_grabbed_at {
             match event {
                 Event::Mouse(mouse::Event::CursorMoved { .. })
@@ -480,9 +810,8 @@ where
                             content_bounds,
                         );
 
-                        let _ = notify_on_scroll(
+                        let _ = self.notify_on_scroll(
                             state,
-                            &self.on_scroll,
                             bounds,
                             content_bounds,
                             shell,
@@ -518,9 +847,8 @@ where
 
                         state.y_scroller_grabbed_at = Some(scroller_grabbed_at);
 
-                        let _ = notify_on_scroll(
+                        let _ = self.notify_on_scroll(
                             state,
-                            &self.on_scroll,
                             bounds,
                             content_bounds,
                             shell,
@@ -551,9 +879,8 @@ where
                             content_bounds,
                         );
 
-                        let _ = notify_on_scroll(
+                        let _ = self.notify_on_scroll(
                             state,
-                            &self.on_scroll,
                             bounds,
                             content_bounds,
                             shell,
@@ -589,9 +916,8 @@ where
 
                         state.x_scroller_grabbed_at = Some(scroller_grabbed_at);
 
-                        let _ = notify_on_scroll(
+                        let _ = self.notify_on_scroll(
                             state,
-                            &self.on_scroll,
                             bounds,
                             content_bounds,
                             shell,
@@ -606,23 +932,17 @@ where
 
         let mut event_status = {
             let cursor = match cursor_over_scrollable {
-                Some(cursor_position)
+                Some(_)
                     if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) =>
                 {
-                    mouse::Cursor::Available(
-                        cursor_position
-                            + state.translation(
-                                self.direction,
-                                bounds,
-                                content_bounds,
-                            ),
-                    )
+                    cursor
+                        + state.translation(direction, bounds, content_bounds)
                 }
                 _ => mouse::Cursor::Unavailable,
             };
 
             let translation =
-                state.translation(self.direction, bounds, content_bounds);
+                state.translation(direction, bounds, content_bounds);
 
             self.content.as_widget_mut().on_event(
                 &mut tree.children[0],
@@ -668,6 +988,80 @@ where
         }
 
         match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if self.focusable && cursor_over_scrollable.is_some() =>
+            {
+                state.is_focused = true;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if self.focusable && state.is_focused => {
+                let line = self.line_size;
+                let page = Size::new(
+                    self.page_size.unwrap_or(bounds.width),
+                    self.page_size.unwrap_or(bounds.height),
+                );
+
+                // Page and jump keys move along the vertical axis, unless the
+                // scrollable only scrolls horizontally
+                let along = |vertical: f32, horizontal: f32| {
+                    if direction.vertical().is_some() {
+                        Vector::new(0.0, vertical)
+                    } else {
+                        Vector::new(horizontal, 0.0)
+                    }
+                };
+
+                let delta = match key {
+                    keyboard::key::Named::ArrowUp => Vector::new(0.0, -line),
+                    keyboard::key::Named::ArrowDown => Vector::new(0.0, line),
+                    keyboard::key::Named::ArrowLeft => Vector::new(-line, 0.0),
+                    keyboard::key::Named::ArrowRight => Vector::new(line, 0.0),
+                    keyboard::key::Named::PageUp => {
+                        along(-page.height, -page.width)
+                    }
+                    keyboard::key::Named::PageDown => {
+                        along(page.height, page.width)
+                    }
+                    keyboard::key::Named::Home => {
+                        along(-content_bounds.height, -content_bounds.width)
+                    }
+                    keyboard::key::Named::End => {
+                        along(content_bounds.height, content_bounds.width)
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                let delta = Vector::new(
+                    if direction.horizontal().is_some() {
+                        delta.x
+                    } else {
+                        0.0
+                    },
+                    if direction.vertical().is_some() {
+                        delta.y
+                    } else {
+                        0.0
+                    },
+                );
+
+                if !state.can_scroll(
+                    Vector::new(-delta.x, -delta.y),
+                    direction,
+                    bounds,
+                    content_bounds,
+                ) {
+                    return event::Status::Ignored;
+                }
+
+                state.scroll_by(delta, direction, bounds, content_bounds);
+
+                let _ =
+                    self.notify_on_scroll(state, bounds, content_bounds, shell);
+
+                event_status = event::Status::Captured;
+            }
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 if cursor_over_scrollable.is_none() {
                     return event::Status::Ignored;
@@ -689,19 +1083,19 @@ where
                     mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
                 };
 
-                state.scroll(delta, self.direction, bounds, content_bounds);
+                if !state.can_scroll(delta, direction, bounds, content_bounds) {
+                    return match self.propagation {
+                        Propagation::Bubble => event::Status::Ignored,
+                        Propagation::Stop => event::Status::Captured,
+                    };
+                }
 
-                event_status = if notify_on_scroll(
-                    state,
-                    &self.on_scroll,
-                    bounds,
-                    content_bounds,
-                    shell,
-                ) {
-                    event::Status::Captured
-                } else {
-                    event::Status::Ignored
-                };
+                state.scroll(delta, direction, bounds, content_bounds);
+
+                let _ =
+                    self.notify_on_scroll(state, bounds, content_bounds, shell);
+
+                event_status = event::Status::Captured;
             }
             Event::Touch(event)
                 if state.scroll_area_touched_at.is_some()
@@ -731,7 +1125,7 @@ where
 
                             state.scroll(
                                 delta,
-                                self.direction,
+                                direction,
                                 bounds,
                                 content_bounds,
                             );
@@ -740,9 +1134,8 @@ where
                                 Some(cursor_position);
 
                             // TODO: bubble up touch movements if not consumed.
-                            let _ = notify_on_scroll(
+                            let _ = self.notify_on_scroll(
                                 state,
-                                &self.on_scroll,
                                 bounds,
                                 content_bounds,
                                 shell,
@@ -771,6 +1164,7 @@ where
         viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
+        let direction = self.direction.resolve(state.overflow);
 
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
@@ -781,20 +1175,17 @@ where
         };
 
         let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+            Scrollbars::new(state, direction, bounds, content_bounds);
 
         let cursor_over_scrollable = cursor.position_over(bounds);
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
 
-        let translation =
-            state.translation(self.direction, bounds, content_bounds);
+        let translation = state.translation(direction, bounds, content_bounds);
 
         let cursor = match cursor_over_scrollable {
-            Some(cursor_position)
-                if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) =>
-            {
-                mouse::Cursor::Available(cursor_position + translation)
+            Some(_) if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) => {
+                cursor + translation
             }
             _ => mouse::Cursor::Unavailable,
         };
@@ -958,6 +1349,7 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State>();
+        let direction = self.direction.resolve(state.overflow);
         let bounds = layout.bounds();
         let cursor_over_scrollable = cursor.position_over(bounds);
 
@@ -965,7 +1357,7 @@ where
         let content_bounds = content_layout.bounds();
 
         let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+            Scrollbars::new(state, direction, bounds, content_bounds);
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
@@ -976,13 +1368,13 @@ where
             mouse::Interaction::None
         } else {
             let translation =
-                state.translation(self.direction, bounds, content_bounds);
+                state.translation(direction, bounds, content_bounds);
 
             let cursor = match cursor_over_scrollable {
-                Some(cursor_position)
+                Some(_)
                     if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) =>
                 {
-                    mouse::Cursor::Available(cursor_position + translation)
+                    cursor + translation
                 }
                 _ => mouse::Cursor::Unavailable,
             };
@@ -1006,22 +1398,32 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
-        let offset = tree.state.downcast_ref::<State>().translation(
-            self.direction,
+        let state = tree.state.downcast_ref::<State>();
+        let offset = state.translation(
+            self.direction.resolve(state.overflow),
             bounds,
             content_bounds,
         );
 
+        let visible_bounds =
+            bounds.intersection(viewport).unwrap_or(Rectangle {
+                width: 0.0,
+                height: 0.0,
+                ..bounds
+            });
+
         self.content.as_widget_mut().overlay(
             &mut tree.children[0],
             layout.children().next().unwrap(),
             renderer,
+            &(visible_bounds + offset),
             translation - offset,
         )
     }
@@ -1043,6 +1445,16 @@ where
 }
 
 /// The identifier of a [`Scrollable`].
+///
+/// The identifiers of different widgets are different types; so an
+/// operation can only target the kind of widget it was meant for:
+///
+/// ```compile_fail
+/// use iced_widget::{scrollable, text_input};
+///
+/// let id = text_input::Id::new("search");
+/// let _ = scrollable::snap_to::<()>(id, scrollable::RelativeOffset::END);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Id(widget::Id);
 
@@ -1058,6 +1470,12 @@ impl Id {
     pub fn unique() -> Self {
         Self(widget::Id::unique())
     }
+
+    /// Returns the [`Id`] of a [`Scrollable`] with this [`Id`] inside the scope
+    /// with the given [`Id`](widget::Id); see [`widget::Id::within`].
+    pub fn within(self, scope: impl Into<widget::Id>) -> Self {
+        Self(self.0.within(scope))
+    }
 }
 
 impl From<Id> for widget::Id {
@@ -1066,6 +1484,12 @@ impl From<Id> for widget::Id {
     }
 }
 
+impl From<widget::Id> for Id {
+    fn from(id: widget::Id) -> Self {
+        Self(id)
+    }
+}
+
 /// Produces a [`Task`] that snaps the [`Scrollable`] with the given [`Id`]
 /// to the provided `percentage` along the x & y axis.
 pub fn snap_to<T>(id: Id, offset: RelativeOffset) -> Task<T> {
@@ -1080,54 +1504,188 @@ pub fn scroll_to<T>(id: Id, offset: AbsoluteOffset) -> Task<T> {
     )))
 }
 
-/// Returns [`true`] if the viewport actually changed.
-fn notify_on_scroll<Message>(
-    state: &mut State,
-    on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
-    bounds: Rectangle,
-    content_bounds: Rectangle,
-    shell: &mut Shell<'_, Message>,
-) -> bool {
-    if content_bounds.width <= bounds.width
-        && content_bounds.height <= bounds.height
-    {
-        return false;
-    }
-
-    let viewport = Viewport {
-        offset_x: state.offset_x,
-        offset_y: state.offset_y,
-        bounds,
-        content_bounds,
-    };
+/// Produces a [`Task`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// smoothly to the provided [`AbsoluteOffset`] along the x & y axis.
+///
+/// The animation is cancelled if the [`Scrollable`] is scrolled by any
+/// other means before it finishes.
+pub fn scroll_to_animated<T>(
+    id: Id,
+    offset: AbsoluteOffset,
+    duration: Duration,
+    easing: Easing,
+) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::scroll_to_animated(
+        id.0, offset, duration, easing,
+    )))
+}
 
-    // Don't publish redundant viewports to shell
-    if let Some(last_notified) = state.last_notified {
-        let last_relative_offset = last_notified.relative_offset();
-        let current_relative_offset = viewport.relative_offset();
+/// Produces a [`Task`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// smoothly until the descendant widget with the `child` [`Id`] is at the
+/// top-left corner of its viewport.
+///
+/// The `child` must be a widget that reports its bounds, like a
+/// [`Container`](crate::Container).
+pub fn scroll_to_child_animated<T>(
+    id: Id,
+    child: impl Into<widget::Id>,
+    duration: Duration,
+    easing: Easing,
+) -> Task<T> {
+    task::effect(Action::widget(
+        operation::scrollable::scroll_to_child_animated(
+            id.0,
+            child.into(),
+            duration,
+            easing,
+        ),
+    ))
+}
 
-        let last_absolute_offset = last_notified.absolute_offset();
-        let current_absolute_offset = viewport.absolute_offset();
+impl<'a, Message, Theme, Renderer> Scrollable<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    /// Returns [`true`] if the viewport actually changed.
+    fn notify_on_scroll(
+        &self,
+        state: &mut State,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        if content_bounds.width <= bounds.width
+            && content_bounds.height <= bounds.height
+        {
+            return false;
+        }
 
-        let unchanged = |a: f32, b: f32| {
-            (a - b).abs() <= f32::EPSILON || (a.is_nan() && b.is_nan())
+        let viewport = Viewport {
+            offset_x: state.offset_x,
+            offset_y: state.offset_y,
+            bounds,
+            content_bounds,
         };
 
-        if unchanged(last_relative_offset.x, current_relative_offset.x)
-            && unchanged(last_relative_offset.y, current_relative_offset.y)
-            && unchanged(last_absolute_offset.x, current_absolute_offset.x)
-            && unchanged(last_absolute_offset.y, current_absolute_offset.y)
-        {
-            return false;
+        // Don't publish redundant viewports to shell
+        if let Some(last_notified) = state.last_notified {
+            let last_relative_offset = last_notified.relative_offset();
+            let current_relative_offset = viewport.relative_offset();
+
+            let last_absolute_offset = last_notified.absolute_offset();
+            let current_absolute_offset = viewport.absolute_offset();
+
+            let unchanged = |a: f32, b: f32| {
+                (a - b).abs() <= f32::EPSILON || (a.is_nan() && b.is_nan())
+            };
+
+            if unchanged(last_relative_offset.x, current_relative_offset.x)
+                && unchanged(last_relative_offset.y, current_relative_offset.y)
+                && unchanged(last_absolute_offset.x, current_absolute_offset.x)
+                && unchanged(last_absolute_offset.y, current_absolute_offset.y)
+            {
+                return false;
+            }
+        }
+
+        if let Some(on_scroll) = &self.on_scroll {
+            shell.publish(on_scroll(viewport));
         }
+
+        self.notify_on_reach(state, viewport, shell);
+
+        state.last_notified = Some(viewport);
+
+        true
     }
 
-    if let Some(on_scroll) = on_scroll {
-        shell.publish(on_scroll(viewport));
+    /// Notifies the start or the end of the [`Scrollable`] when the given
+    /// [`Viewport`] moves within the threshold of them.
+    fn notify_on_reach(
+        &self,
+        state: &mut State,
+        viewport: Viewport,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let offset = viewport.absolute_offset();
+        let last_offset = state
+            .last_notified
+            .map(|last_notified| last_notified.absolute_offset())
+            .unwrap_or_default();
+
+        let max = Vector::new(
+            (viewport.content_bounds.width - viewport.bounds.width).max(0.0),
+            (viewport.content_bounds.height - viewport.bounds.height).max(0.0),
+        );
+
+        let direction = self.direction.resolve(state.overflow);
+
+        let axes = [
+            (direction.horizontal(), offset.x, last_offset.x, max.x),
+            (direction.vertical(), offset.y, last_offset.y, max.y),
+        ];
+
+        let reaches = |is_reached: fn(f32, f32, f32, f32) -> bool| {
+            axes.iter().any(|&(scrollbar, offset, last_offset, max)| {
+                scrollbar.is_some()
+                    && max > 0.0
+                    && is_reached(
+                        offset,
+                        last_offset,
+                        max,
+                        self.reach_threshold,
+                    )
+            })
+        };
+
+        let reaches_start = reaches(|offset, last, _max, threshold| {
+            offset < last && offset <= threshold
+        });
+
+        let reaches_end = reaches(|offset, last, max, threshold| {
+            offset > last && max - offset <= threshold
+        });
+
+        let content = viewport.content_bounds.size();
+
+        for (reached_at, is_reached, on_reach) in [
+            (
+                &mut state.reached_start_at,
+                reaches_start,
+                &self.on_reach_start,
+            ),
+            (&mut state.reached_end_at, reaches_end, &self.on_reach_end),
+        ] {
+            // An edge is only reached again once the content grows
+            if reached_at.is_some_and(|size| {
+                content.width > size.width || content.height > size.height
+            }) {
+                *reached_at = None;
+            }
+
+            if is_reached && reached_at.is_none() {
+                *reached_at = Some(content);
+
+                if let Some(on_reach) = on_reach {
+                    shell.publish(on_reach());
+                }
+            }
+        }
     }
-    state.last_notified = Some(viewport);
+}
 
-    true
+/// Flips the axes of the delta that are anchored to the end.
+fn align(delta: Vector<f32>, direction: Direction) -> Vector<f32> {
+    let align = |anchor: Option<Anchor>, delta: f32| match anchor {
+        Some(Anchor::End) => -delta,
+        Some(Anchor::Start) | None => delta,
+    };
+
+    Vector::new(
+        align(direction.horizontal().map(|p| p.alignment), delta.x),
+        align(direction.vertical().map(|p| p.alignment), delta.y),
+    )
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1139,6 +1697,25 @@ struct State {
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
+    reached_start_at: Option<Size>,
+    reached_end_at: Option<Size>,
+    content_size: Option<Size>,
+    is_focused: bool,
+    animation: Option<Animation>,
+    /// Whether the content overflows horizontally and vertically; only
+    /// tracked for [`Direction::Auto`].
+    overflow: (bool, bool),
+}
+
+/// A scroll in progress, towards some [`AbsoluteOffset`].
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    from: Vector,
+    to: AbsoluteOffset,
+    current: Vector,
+    started_at: Instant,
+    duration: Duration,
+    easing: Easing,
 }
 
 impl Default for State {
@@ -1151,19 +1728,89 @@ impl Default for State {
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
+            reached_start_at: None,
+            reached_end_at: None,
+            content_size: None,
+            is_focused: false,
+            animation: None,
+            overflow: (false, false),
         }
     }
 }
 
-impl operation::Scrollable for State {
-    fn snap_to(&mut self, offset: RelativeOffset) {
-        State::snap_to(self, offset);
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
     }
 
-    fn scroll_to(&mut self, offset: AbsoluteOffset) {
-        State::scroll_to(self, offset);
+    fn focus(&mut self) {
+        self.is_focused = true;
     }
-}
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The [`State`] of a [`Scrollable`] together with its layout; so it can be
+/// scrolled by operations.
+struct Handle<'a> {
+    state: &'a mut State,
+    direction: Direction,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl operation::Scrollable for Handle<'_> {
+    fn snap_to(&mut self, offset: RelativeOffset) {
+        self.state.snap_to(offset);
+    }
+
+    fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        self.state.scroll_to(offset);
+    }
+
+    fn scroll_by(&mut self, offset: AbsoluteOffset) {
+        self.state.scroll_by(
+            Vector::new(offset.x, offset.y),
+            self.direction,
+            self.bounds,
+            self.content_bounds,
+        );
+    }
+
+    fn scroll_to_animated(
+        &mut self,
+        offset: AbsoluteOffset,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        if duration.is_zero() {
+            self.state.scroll_to(offset);
+            return;
+        }
+
+        self.state.unsnap(self.bounds, self.content_bounds);
+
+        let from = Vector::new(
+            self.state
+                .offset_x
+                .absolute(self.bounds.width, self.content_bounds.width),
+            self.state
+                .offset_y
+                .absolute(self.bounds.height, self.content_bounds.height),
+        );
+
+        self.state.animation = Some(Animation {
+            from,
+            to: offset,
+            current: from,
+            started_at: Instant::now(),
+            duration,
+            easing,
+        });
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 enum Offset {
@@ -1261,6 +1908,53 @@ impl State {
         State::default()
     }
 
+    /// Returns whether the [`State`] can be scrolled by the given delta
+    /// along its dominant axis.
+    fn can_scroll(
+        &self,
+        delta: Vector<f32>,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        let delta = align(delta, direction);
+
+        let can_move =
+            |offset: Offset, viewport: f32, content: f32, delta: f32| {
+                if viewport >= content {
+                    return false;
+                }
+
+                let offset = offset.absolute(viewport, content);
+
+                if delta > 0.0 {
+                    offset > 0.0
+                } else if delta < 0.0 {
+                    offset < content - viewport
+                } else {
+                    false
+                }
+            };
+
+        if delta.x.abs() > delta.y.abs() {
+            direction.horizontal().is_some()
+                && can_move(
+                    self.offset_x,
+                    bounds.width,
+                    content_bounds.width,
+                    delta.x,
+                )
+        } else {
+            direction.vertical().is_some()
+                && can_move(
+                    self.offset_y,
+                    bounds.height,
+                    content_bounds.height,
+                    delta.y,
+                )
+        }
+    }
+
     /// Apply a scrolling offset to the current [`State`], given the bounds of
     /// the [`Scrollable`] and its contents.
     pub fn scroll(
@@ -1270,25 +1964,7 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        let horizontal_alignment = direction
-            .horizontal()
-            .map(|p| p.alignment)
-            .unwrap_or_default();
-
-        let vertical_alignment = direction
-            .vertical()
-            .map(|p| p.alignment)
-            .unwrap_or_default();
-
-        let align = |alignment: Anchor, delta: f32| match alignment {
-            Anchor::Start => delta,
-            Anchor::End => -delta,
-        };
-
-        let delta = Vector::new(
-            align(horizontal_alignment, delta.x),
-            align(vertical_alignment, delta.y),
-        );
+        let delta = align(delta, direction);
 
         if bounds.height < content_bounds.height {
             self.offset_y = Offset::Absolute(
@@ -1307,6 +1983,23 @@ impl State {
         }
     }
 
+    /// Moves the visible contents of the [`Scrollable`] by the given amount of
+    /// pixels, regardless of its [`Anchor`].
+    fn scroll_by(
+        &mut self,
+        delta: Vector<f32>,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.scroll(
+            Vector::new(-delta.x, -delta.y),
+            direction,
+            bounds,
+            content_bounds,
+        );
+    }
+
     /// Scrolls the [`Scrollable`] to a relative amount along the y axis.
     ///
     /// `0` represents scrollbar at the beginning, while `1` represents scrollbar at
@@ -1347,6 +2040,64 @@ impl State {
         self.offset_y = Offset::Absolute(offset.y.max(0.0));
     }
 
+    /// Moves the [`Animation`] of the [`State`], if any, to the given moment.
+    ///
+    /// The animation is cancelled if the [`Scrollable`] has been scrolled by
+    /// other means since its last step.
+    ///
+    /// Returns [`true`] if the animation is still in progress.
+    fn animate(
+        &mut self,
+        now: Instant,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        let Some(animation) = &mut self.animation else {
+            return false;
+        };
+
+        let is_untouched = matches!(
+            (self.offset_x, self.offset_y),
+            (Offset::Absolute(x), Offset::Absolute(y))
+                if x == animation.current.x && y == animation.current.y
+        );
+
+        if !is_untouched {
+            self.animation = None;
+            return false;
+        }
+
+        let progress = now
+            .saturating_duration_since(animation.started_at)
+            .as_secs_f32()
+            / animation.duration.as_secs_f32();
+
+        let t = animation.easing.apply(progress);
+
+        // The content may have shrunk since the animation started
+        let max = Vector::new(
+            (content_bounds.width - bounds.width).max(0.0),
+            (content_bounds.height - bounds.height).max(0.0),
+        );
+
+        animation.current = Vector::new(
+            (animation.from.x + (animation.to.x - animation.from.x) * t)
+                .clamp(0.0, max.x),
+            (animation.from.y + (animation.to.y - animation.from.y) * t)
+                .clamp(0.0, max.y),
+        );
+
+        self.offset_x = Offset::Absolute(animation.current.x);
+        self.offset_y = Offset::Absolute(animation.current.y);
+
+        if progress >= 1.0 {
+            self.animation = None;
+            return false;
+        }
+
+        true
+    }
+
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     pub fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
@@ -1388,6 +2139,64 @@ impl State {
         )
     }
 
+    /// Keeps the offsets anchored to the end in place after the content
+    /// of the [`Scrollable`] changes size.
+    fn follow_content(
+        &mut self,
+        direction: Direction,
+        bounds: Size,
+        content: Size,
+        threshold: f32,
+    ) {
+        let Some(previous) = self.content_size.replace(content) else {
+            return;
+        };
+
+        let follow = |offset: &mut Offset,
+                      anchor: Option<Anchor>,
+                      viewport: f32,
+                      previous: f32,
+                      content: f32| {
+            if anchor != Some(Anchor::End) || previous == content {
+                return;
+            }
+
+            // Snapped offsets already follow the content
+            let Offset::Absolute(_) = offset else {
+                return;
+            };
+
+            let from_end = offset.absolute(viewport, previous);
+            let from_start = (previous - viewport).max(0.0) - from_end;
+
+            // Stick to the end, or keep the prepended content out of view
+            if from_end <= 0.0 || from_start <= threshold {
+                return;
+            }
+
+            *offset = Offset::Absolute(
+                (from_end + content - previous)
+                    .clamp(0.0, (content - viewport).max(0.0)),
+            );
+        };
+
+        follow(
+            &mut self.offset_x,
+            direction.horizontal().map(|scrollbar| scrollbar.alignment),
+            bounds.width,
+            previous.width,
+            content.width,
+        );
+
+        follow(
+            &mut self.offset_y,
+            direction.vertical().map(|scrollbar| scrollbar.alignment),
+            bounds.height,
+            previous.height,
+            content.height,
+        );
+    }
+
     /// Returns whether any scroller is currently grabbed or not.
     pub fn scrollers_grabbed(&self) -> bool {
         self.x_scroller_grabbed_at.is_some()
@@ -1844,3 +2653,540 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::{Column, Space};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Message {
+        Outer,
+        Inner,
+        Start,
+        End,
+    }
+
+    type Scrollable = super::Scrollable<'static, Message, Theme, ()>;
+
+    struct Nested {
+        element: Element<'static, Message, Theme, ()>,
+        tree: Tree,
+    }
+
+    impl Nested {
+        /// A vertical 100x100 [`Scrollable`] with the given inner 100x50
+        /// [`Scrollable`] at its top.
+        fn new(inner: Scrollable) -> Self {
+            let inner =
+                inner.width(100).height(50).on_scroll(|_| Message::Inner);
+
+            let outer = Scrollable::new(Column::with_children([
+                inner.into(),
+                Space::new(100, 500).into(),
+            ]))
+            .width(100)
+            .height(100)
+            .on_scroll(|_| Message::Outer);
+
+            let element = Element::from(outer);
+            let tree = Tree::new(&element);
+
+            Self { element, tree }
+        }
+
+        /// Scrolls the mouse wheel over the inner [`Scrollable`].
+        fn scroll(&mut self, x: f32, y: f32) -> (event::Status, Vec<Message>) {
+            self.send(Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x, y },
+            }))
+        }
+
+        /// Presses the given named key.
+        fn press(
+            &mut self,
+            key: keyboard::key::Named,
+        ) -> (event::Status, Vec<Message>) {
+            self.send(Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text: None,
+            }))
+        }
+
+        /// Sends an event with the cursor over the inner [`Scrollable`].
+        fn send(&mut self, event: Event) -> (event::Status, Vec<Message>) {
+            let node = self.element.as_widget().layout(
+                &mut self.tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let status = self.element.as_widget_mut().on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(50.0, 25.0)),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+
+            (status, messages)
+        }
+    }
+
+    fn horizontal() -> Scrollable {
+        Scrollable::new(Space::new(200, 50))
+            .direction(Direction::Horizontal(Scrollbar::default()))
+    }
+
+    fn vertical() -> Scrollable {
+        Scrollable::new(Space::new(100, 100))
+    }
+
+    #[test]
+    fn vertical_wheel_skips_horizontal_scrollable() {
+        let mut nested = Nested::new(horizontal());
+
+        assert_eq!(
+            nested.scroll(0.0, -10.0),
+            (event::Status::Captured, vec![Message::Outer])
+        );
+    }
+
+    #[test]
+    fn horizontal_wheel_scrolls_horizontal_scrollable() {
+        let mut nested = Nested::new(horizontal());
+
+        assert_eq!(
+            nested.scroll(-10.0, 0.0),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+
+        // Mostly horizontal
+        assert_eq!(
+            nested.scroll(-10.0, -2.0),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+    }
+
+    #[test]
+    fn horizontal_wheel_at_limit_bubbles() {
+        let mut nested = Nested::new(horizontal());
+
+        // The inner scrollable is at its start and the outer one cannot
+        // scroll horizontally
+        assert_eq!(nested.scroll(10.0, 0.0), (event::Status::Ignored, vec![]));
+    }
+
+    #[test]
+    fn vertical_wheel_at_limit_bubbles() {
+        let mut nested = Nested::new(vertical());
+
+        assert_eq!(
+            nested.scroll(0.0, -30.0),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+
+        assert_eq!(
+            nested.scroll(0.0, -30.0),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+
+        // The inner scrollable is at its end
+        assert_eq!(
+            nested.scroll(0.0, -10.0),
+            (event::Status::Captured, vec![Message::Outer])
+        );
+
+        // But it can still scroll back
+        assert_eq!(
+            nested.scroll(0.0, 10.0),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+    }
+
+    #[test]
+    fn stop_propagation_at_limit() {
+        let mut nested =
+            Nested::new(vertical().scroll_propagation(Propagation::Stop));
+
+        assert_eq!(nested.scroll(0.0, 10.0), (event::Status::Captured, vec![]));
+
+        assert_eq!(
+            nested.scroll(0.0, -60.0),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+
+        assert_eq!(
+            nested.scroll(0.0, -10.0),
+            (event::Status::Captured, vec![])
+        );
+    }
+
+    #[test]
+    fn unfocused_scrollable_ignores_keys() {
+        let mut nested = Nested::new(vertical().focusable(true));
+
+        assert_eq!(
+            nested.press(keyboard::key::Named::ArrowDown),
+            (event::Status::Ignored, vec![])
+        );
+    }
+
+    #[test]
+    fn focused_scrollable_scrolls_with_keys() {
+        let mut nested = Nested::new(vertical().focusable(true));
+
+        let _ = nested.send(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+
+        assert_eq!(
+            nested.press(keyboard::key::Named::ArrowDown),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+
+        // The inner scrollable is at its end
+        assert_eq!(
+            nested.press(keyboard::key::Named::End),
+            (event::Status::Ignored, vec![])
+        );
+
+        assert_eq!(
+            nested.press(keyboard::key::Named::Home),
+            (event::Status::Captured, vec![Message::Inner])
+        );
+    }
+
+    /// Starts an animated scroll of a 100x100 viewport showing a 100x500
+    /// content to the given vertical offset; taking 100 milliseconds.
+    fn animated(y: f32) -> (State, Instant) {
+        let mut state = State::default();
+
+        operation::Scrollable::scroll_to_animated(
+            &mut Handle {
+                state: &mut state,
+                direction: Direction::default(),
+                bounds: Rectangle::with_size(Size::new(100.0, 100.0)),
+                content_bounds: Rectangle::with_size(Size::new(100.0, 500.0)),
+            },
+            AbsoluteOffset { x: 0.0, y },
+            Duration::from_millis(100),
+            Easing::Linear,
+        );
+
+        let started_at = state.animation.unwrap().started_at;
+
+        (state, started_at)
+    }
+
+    #[test]
+    fn animated_scroll_clamps_to_shrinking_content() {
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+        let (mut state, start) = animated(300.0);
+
+        assert!(state.animate(
+            start + Duration::from_millis(50),
+            bounds,
+            Rectangle::with_size(Size::new(100.0, 500.0)),
+        ));
+        assert_eq!(state.offset_y.absolute(100.0, 500.0), 150.0);
+
+        assert!(!state.animate(
+            start + Duration::from_millis(100),
+            bounds,
+            Rectangle::with_size(Size::new(100.0, 200.0)),
+        ));
+        assert_eq!(state.offset_y.absolute(100.0, 200.0), 100.0);
+        assert!(state.animation.is_none());
+    }
+
+    #[test]
+    fn animated_scroll_is_cancelled_by_other_scrolls() {
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+        let content_bounds = Rectangle::with_size(Size::new(100.0, 500.0));
+        let (mut state, start) = animated(300.0);
+
+        assert!(state.animate(
+            start + Duration::from_millis(50),
+            bounds,
+            content_bounds
+        ));
+
+        state.scroll(
+            Vector::new(0.0, 20.0),
+            Direction::default(),
+            bounds,
+            content_bounds,
+        );
+
+        assert!(!state.animate(
+            start + Duration::from_millis(60),
+            bounds,
+            content_bounds
+        ));
+        assert_eq!(state.offset_y.absolute(100.0, 500.0), 130.0);
+    }
+
+    fn size(scrollable: Scrollable) -> Size {
+        let element = Element::from(scrollable);
+        let mut tree = Tree::new(&element);
+
+        element
+            .as_widget()
+            .layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+            )
+            .size()
+    }
+
+    #[test]
+    fn fill_respects_the_maximum() {
+        assert_eq!(
+            size(
+                vertical()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .max_width(200)
+                    .max_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn shrink_respects_the_minimum() {
+        assert_eq!(
+            size(
+                Scrollable::new(Space::new(10, 10))
+                    .min_width(200)
+                    .min_height(100)
+            ),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    /// A 100x100 [`Scrollable`] producing [`Message::Start`] and
+    /// [`Message::End`] when reaching its edges.
+    struct Feed {
+        tree: Tree,
+        direction: Direction,
+        length: f32,
+    }
+
+    impl Feed {
+        fn new(direction: Direction, length: f32) -> Self {
+            let mut feed = Self {
+                tree: Tree::empty(),
+                direction,
+                length,
+            };
+
+            feed.tree = Tree::new(&feed.element());
+            feed
+        }
+
+        fn element(&self) -> Element<'static, Message, Theme, ()> {
+            let content = if self.direction.horizontal().is_some() {
+                Space::new(self.length, 100.0)
+            } else {
+                Space::new(100.0, self.length)
+            };
+
+            Scrollable::new(content)
+                .direction(self.direction)
+                .width(100)
+                .height(100)
+                .reach_threshold(50)
+                .on_reach_start(Message::Start)
+                .on_reach_end(Message::End)
+                .into()
+        }
+
+        /// Grows the content of the [`Scrollable`] to the given length.
+        fn grow(&mut self, length: f32) {
+            self.length = length;
+            self.tree.diff(&self.element());
+        }
+
+        /// Scrolls the mouse wheel over the [`Scrollable`] along its axis.
+        fn scroll(&mut self, amount: f32) -> Vec<Message> {
+            let mut element = self.element();
+
+            let node = element.as_widget().layout(
+                &mut self.tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            let delta = if self.direction.horizontal().is_some() {
+                mouse::ScrollDelta::Pixels { x: amount, y: 0.0 }
+            } else {
+                mouse::ScrollDelta::Pixels { x: 0.0, y: amount }
+            };
+
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = element.as_widget_mut().on_event(
+                &mut self.tree,
+                Event::Mouse(mouse::Event::WheelScrolled { delta }),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(50.0, 50.0)),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+
+            messages
+        }
+
+        /// Lays out the [`Scrollable`] and returns the offset of its
+        /// content from the start.
+        fn offset(&mut self) -> f32 {
+            let node = self.element().as_widget().layout(
+                &mut self.tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            let layout = Layout::new(&node);
+            let content = layout.children().next().unwrap();
+
+            let translation = self
+                .tree
+                .state
+                .downcast_ref::<State>()
+                .translation(self.direction, layout.bounds(), content.bounds());
+
+            translation.x + translation.y
+        }
+    }
+
+    #[test]
+    fn reaching_the_end_fires_once_until_the_content_grows() {
+        let mut feed = Feed::new(Direction::default(), 500.0);
+
+        assert_eq!(feed.scroll(-300.0), vec![]);
+        assert_eq!(feed.scroll(-80.0), vec![Message::End]);
+
+        // Sitting at the bottom
+        assert_eq!(feed.scroll(-10.0), vec![]);
+        assert_eq!(feed.scroll(-100.0), vec![]);
+
+        feed.grow(800.0);
+
+        assert_eq!(feed.scroll(-200.0), vec![]);
+        assert_eq!(feed.scroll(-100.0), vec![Message::End]);
+        assert_eq!(feed.scroll(-100.0), vec![]);
+    }
+
+    #[test]
+    fn reaching_the_start_of_a_horizontal_scrollable() {
+        let mut feed =
+            Feed::new(Direction::Horizontal(Scrollbar::default()), 500.0);
+
+        // Leaving the start does not reach it
+        assert_eq!(feed.scroll(-30.0), vec![]);
+        assert_eq!(feed.scroll(-370.0), vec![Message::End]);
+
+        assert_eq!(feed.scroll(300.0), vec![]);
+        assert_eq!(feed.scroll(80.0), vec![Message::Start]);
+        assert_eq!(feed.scroll(20.0), vec![]);
+
+        assert_eq!(feed.scroll(-400.0), vec![]);
+    }
+
+    #[test]
+    fn anchored_to_the_end_follows_the_content() {
+        let mut feed = Feed::new(
+            Direction::Vertical(Scrollbar::default().anchor(Anchor::End)),
+            500.0,
+        );
+
+        assert_eq!(feed.offset(), 400.0);
+
+        // Appended while at the end
+        feed.grow(600.0);
+        assert_eq!(feed.offset(), 500.0);
+
+        // Appended while in the middle
+        let _ = feed.scroll(200.0);
+        assert_eq!(feed.offset(), 300.0);
+
+        feed.grow(700.0);
+        assert_eq!(feed.offset(), 300.0);
+
+        // Prepended while at the start
+        let _ = feed.scroll(300.0);
+        assert_eq!(feed.offset(), 0.0);
+
+        feed.grow(900.0);
+        assert_eq!(feed.offset(), 200.0);
+    }
+
+    /// Lays out a 100x100 [`Scrollable`] with embedded automatic scrollbars
+    /// twice; returning where its content overflows each time.
+    fn overflow(
+        content: impl Into<Element<'static, Message, Theme, ()>>,
+    ) -> [(bool, bool); 2] {
+        let scrollbar = Scrollbar::default().spacing(5);
+
+        let element = Element::from(
+            Scrollable::new(content)
+                .direction(Direction::Auto {
+                    vertical: scrollbar,
+                    horizontal: scrollbar,
+                })
+                .width(100)
+                .height(100),
+        );
+
+        let mut tree = Tree::new(&element);
+
+        [(); 2].map(|_| {
+            let _ = element.as_widget().layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            tree.state.downcast_ref::<State>().overflow
+        })
+    }
+
+    #[test]
+    fn auto_scrollbars_only_appear_on_overflow() {
+        assert_eq!(overflow(Space::new(100, 100)), [(false, false); 2]);
+        assert_eq!(overflow(Space::new(80, 110)), [(false, true); 2]);
+        assert_eq!(overflow(Space::new(200, 50)), [(true, false); 2]);
+        assert_eq!(overflow(Space::new(200, 200)), [(true, true); 2]);
+    }
+
+    #[test]
+    fn auto_scrollbars_settle_when_a_gutter_causes_overflow() {
+        // The vertical gutter leaves 85 pixels of width; so the content
+        // overflows horizontally too, and it keeps doing so
+        assert_eq!(overflow(Space::new(90, 110)), [(true, true); 2]);
+        assert_eq!(overflow(Space::new(110, 90)), [(true, true); 2]);
+    }
+
+    #[test]
+    fn auto_scrollbars_ignore_filled_axes() {
+        let content = Column::with_children([Space::new(200, 200).into()])
+            .width(Length::Fill);
+
+        assert_eq!(overflow(content), [(false, true); 2]);
+    }
+}
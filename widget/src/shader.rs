@@ -18,6 +18,7 @@ use crate::renderer::wgpu::primitive;
 use std::marker::PhantomData;
 
 pub use crate::graphics::Viewport;
+pub use crate::renderer::wgpu::shader::Module;
 pub use crate::renderer::wgpu::wgpu;
 pub use primitive::{Primitive, Storage};
 
@@ -110,7 +111,7 @@ where
             core::Event::Window(window::Event::RedrawRequested(instant)) => {
                 Some(Event::RedrawRequested(instant))
             }
-            core::Event::Window(_) => None,
+            core::Event::Window(_) | core::Event::InputMethod(_) => None,
         };
 
         if let Some(custom_shader_event) = custom_shader_event {
@@ -1,4 +1,5 @@
 //! Display an interactive selector of a single value from a range of values.
+use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -6,19 +7,23 @@ use crate::core::keyboard::key::{self, Key};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::settings::Interaction;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    self, Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
-    Shell, Size, Theme, Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme, Widget,
 };
 
 use std::ops::RangeInclusive;
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+/// A bar and a handle that selects a single value from a range of values.
 ///
-/// A [`Slider`] will try to fill the horizontal space of its container.
+/// A [`Slider`] is horizontal by default, and it will try to fill the
+/// horizontal space of its container. A vertical [`Slider`] will try to fill
+/// the vertical space instead.
 ///
 /// The [`Slider`] range of numeric values is generic and its step size defaults
 /// to 1 unit.
@@ -50,8 +55,13 @@ where
     default: Option<T>,
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
-    width: Length,
-    height: f32,
+    buffered: Vec<RangeInclusive<T>>,
+    ticks: Vec<f32>,
+    tick_labels: Option<Box<dyn Fn(f32) -> String + 'a>>,
+    snap_to_ticks: bool,
+    orientation: Orientation,
+    width: Option<Length>,
+    height: Option<Length>,
     class: Theme::Class<'a>,
 }
 
@@ -61,9 +71,6 @@ where
     Message: Clone,
     Theme: Catalog,
 {
-    /// The default height of a [`Slider`].
-    pub const DEFAULT_HEIGHT: f32 = 16.0;
-
     /// Creates a new [`Slider`].
     ///
     /// It expects:
@@ -96,8 +103,13 @@ where
             shift_step: None,
             on_change: Box::new(on_change),
             on_release: None,
-            width: Length::Fill,
-            height: Self::DEFAULT_HEIGHT,
+            buffered: Vec::new(),
+            ticks: Vec::new(),
+            tick_labels: None,
+            snap_to_ticks: false,
+            orientation: Orientation::Horizontal,
+            width: None,
+            height: None,
             class: Theme::default(),
         }
     }
@@ -121,15 +133,62 @@ where
         self
     }
 
+    /// Adds a buffered range to the [`Slider`]; for instance, the part of a
+    /// media file that has already been downloaded.
+    ///
+    /// Buffered ranges are drawn as a differently styled segment of the rail,
+    /// behind the value of the [`Slider`]. This method can be called multiple
+    /// times to show disjoint ranges.
+    ///
+    /// Ranges are clamped to the range of the [`Slider`].
+    pub fn buffered(mut self, range: RangeInclusive<T>) -> Self {
+        self.buffered.push(range);
+        self
+    }
+
+    /// Sets the values of the tick marks drawn along the rail of the
+    /// [`Slider`].
+    ///
+    /// Ticks outside of the range of the [`Slider`] are ignored.
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = f32>) -> Self {
+        self.ticks = ticks.into_iter().collect();
+        self
+    }
+
+    /// Sets the function that produces the label of each tick of the
+    /// [`Slider`].
+    ///
+    /// Labels are drawn below a horizontal [`Slider`] and to the right of a
+    /// vertical one.
+    pub fn tick_labels(mut self, label: impl Fn(f32) -> String + 'a) -> Self {
+        self.tick_labels = Some(Box::new(label));
+        self
+    }
+
+    /// Sets whether the value of the [`Slider`] snaps to its closest tick,
+    /// instead of its step, when dragged or stepped with the keyboard.
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`Slider`].
+    ///
+    /// A vertical [`Slider`] has its start at the bottom.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
+        self.width = Some(width.into());
         self
     }
 
     /// Sets the height of the [`Slider`].
-    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
-        self.height = height.into().0;
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = Some(height.into());
         self
     }
 
@@ -166,36 +225,118 @@ where
     }
 }
 
+impl<'a, T, Message, Theme> Slider<'a, T, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a horizontal [`Slider`]; and the default width
+    /// of a vertical one.
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+
+    /// The size of the [`Slider`], with the defaults of its [`Orientation`].
+    ///
+    /// A [`Slider`] with tick labels shrinks to fit them.
+    fn dimensions(&self) -> Size<Length> {
+        let thickness = if self.tick_labels.is_some() {
+            Length::Shrink
+        } else {
+            Length::Fixed(Self::DEFAULT_HEIGHT)
+        };
+
+        let (width, height) = match self.orientation {
+            Orientation::Horizontal => (Length::Fill, thickness),
+            Orientation::Vertical => (thickness, Length::Fill),
+        };
+
+        Size::new(self.width.unwrap_or(width), self.height.unwrap_or(height))
+    }
+}
+
+impl<'a, T, Message, Theme> Slider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64>,
+    Theme: Catalog,
+{
+    /// Returns the ticks of the [`Slider`] inside its range, sorted and
+    /// without duplicates.
+    fn visible_ticks(&self) -> Vec<f32> {
+        let start: f64 = (*self.range.start()).into();
+        let end: f64 = (*self.range.end()).into();
+
+        let mut ticks: Vec<f32> = self
+            .ticks
+            .iter()
+            .copied()
+            .filter(|tick| (start..=end).contains(&f64::from(*tick)))
+            .collect();
+
+        ticks.sort_by(f32::total_cmp);
+        ticks.dedup();
+        ticks
+    }
+}
+
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Slider<'a, T, Message, Theme>
 where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: Length::Shrink,
-        }
+        self.dimensions()
     }
 
     fn layout(
         &self,
-        _tree: &mut Tree,
-        _renderer: &Renderer,
+        tree: &mut Tree,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if let Some(label) = &self.tick_labels {
+            let ticks = self.visible_ticks();
+
+            state.labels.resize_with(ticks.len(), Default::default);
+
+            for (tick, paragraph) in ticks.into_iter().zip(&mut state.labels) {
+                paragraph.update(Text {
+                    content: &label(tick),
+                    bounds: Size::INFINITY,
+                    size: renderer.default_size(),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                    font_features: &[],
+                    shaping: text::Shaping::Basic,
+                });
+            }
+        } else {
+            state.labels.clear();
+        }
+
+        let size = self.dimensions();
+        let thickness =
+            Self::DEFAULT_HEIGHT + state.label_space(self.orientation);
+
+        let intrinsic = match self.orientation {
+            Orientation::Horizontal => Size::new(0.0, thickness),
+            Orientation::Vertical => Size::new(thickness, 0.0),
+        };
+
+        layout::Node::new(limits.resolve(size.width, size.height, intrinsic))
     }
 
     fn on_event(
@@ -209,77 +350,68 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
-        let state = tree.state.downcast_mut::<State>();
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
         let is_dragging = state.is_dragging;
         let current_value = self.value;
 
-        let locate = |cursor_position: Point| -> Option<T> {
-            let bounds = layout.bounds();
-            let new_value = if cursor_position.x <= bounds.x {
-                Some(*self.range.start())
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                Some(*self.range.end())
-            } else {
-                let step = if state.keyboard_modifiers.shift() {
-                    self.shift_step.unwrap_or(self.step)
-                } else {
-                    self.step
-                }
-                .into();
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
 
-                let start = (*self.range.start()).into();
-                let end = (*self.range.end()).into();
+        let step = if state.keyboard_modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into();
 
-                let percent = f64::from(cursor_position.x - bounds.x)
-                    / f64::from(bounds.width);
+        let ticks: Vec<f64> = if self.snap_to_ticks {
+            self.visible_ticks().into_iter().map(f64::from).collect()
+        } else {
+            Vec::new()
+        };
 
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
+        let locate = |cursor_position: Point| -> Option<T> {
+            let bounds = layout.bounds();
 
-                T::from_f64(value.min(end))
+            let percent = match self.orientation {
+                Orientation::Horizontal => {
+                    f64::from(cursor_position.x - bounds.x)
+                        / f64::from(bounds.width)
+                }
+                Orientation::Vertical => {
+                    1.0 - f64::from(cursor_position.y - bounds.y)
+                        / f64::from(bounds.height)
+                }
             };
 
-            new_value
-        };
-
-        let increment = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
-                self.shift_step.unwrap_or(self.step)
+            if !ticks.is_empty() {
+                T::from_f64(nearest(
+                    start + percent.clamp(0.0, 1.0) * (end - start),
+                    &ticks,
+                ))
+            } else if percent <= 0.0 {
+                Some(*self.range.start())
+            } else if percent >= 1.0 {
+                Some(*self.range.end())
             } else {
-                self.step
+                T::from_f64(snap(
+                    start + percent * (end - start),
+                    start..=end,
+                    step,
+                ))
             }
-            .into();
-
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps + 1.0);
-
-            if new_value > (*self.range.end()).into() {
-                return Some(*self.range.end());
-            }
-
-            T::from_f64(new_value)
         };
 
-        let decrement = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
-                self.shift_step.unwrap_or(self.step)
+        let step_by = |value: T, steps: f64| -> Option<T> {
+            T::from_f64(if ticks.is_empty() {
+                advance(value.into(), start..=end, step, steps)
             } else {
-                self.step
-            }
-            .into();
-
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps - 1.0);
-
-            if new_value < (*self.range.start()).into() {
-                return Some(*self.range.start());
-            }
-
-            T::from_f64(new_value)
+                advance_ticks(value.into(), &ticks, steps)
+            })
         };
 
-        let change = |new_value: T| {
+        let mut change = |new_value: T| {
             if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
                 shell.publish((self.on_change)(new_value));
 
@@ -299,6 +431,7 @@ where
                     } else {
                         let _ = locate(cursor_position).map(change);
                         state.is_dragging = true;
+                        state.origin = Some(cursor_position);
                     }
 
                     return event::Status::Captured;
@@ -319,7 +452,22 @@ where
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if is_dragging {
-                    let _ = cursor.position().and_then(locate).map(change);
+                    let Some(cursor_position) = cursor.position() else {
+                        return event::Status::Captured;
+                    };
+
+                    // Small movements while clicking do not move the value
+                    if let Some(origin) = state.origin {
+                        if cursor_position.distance(origin)
+                            <= Interaction::current().drag_threshold
+                        {
+                            return event::Status::Captured;
+                        }
+
+                        state.origin = None;
+                    }
+
+                    let _ = locate(cursor_position).map(change);
 
                     return event::Status::Captured;
                 }
@@ -327,12 +475,34 @@ where
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
                 if cursor.position_over(layout.bounds()).is_some() {
                     match key {
-                        Key::Named(key::Named::ArrowUp) => {
-                            let _ = increment(current_value).map(change);
+                        Key::Named(
+                            key::Named::ArrowUp | key::Named::ArrowRight,
+                        ) => {
+                            let _ = step_by(current_value, 1.0).map(change);
+                        }
+                        Key::Named(
+                            key::Named::ArrowDown | key::Named::ArrowLeft,
+                        ) => {
+                            let _ = step_by(current_value, -1.0).map(change);
+                        }
+                        Key::Named(key::Named::PageUp) => {
+                            let _ = step_by(current_value, PAGE).map(change);
                         }
-                        Key::Named(key::Named::ArrowDown) => {
-                            let _ = decrement(current_value).map(change);
+                        Key::Named(key::Named::PageDown) => {
+                            let _ = step_by(current_value, -PAGE).map(change);
                         }
+                        Key::Named(key::Named::Home) => match ticks.first() {
+                            Some(&tick) => {
+                                let _ = T::from_f64(tick).map(change);
+                            }
+                            None => change(*self.range.start()),
+                        },
+                        Key::Named(key::Named::End) => match ticks.last() {
+                            Some(&tick) => {
+                                let _ = T::from_f64(tick).map(change);
+                            }
+                            None => change(*self.range.end()),
+                        },
                         _ => (),
                     }
 
@@ -356,9 +526,9 @@ where
         _style: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let bounds = layout.bounds();
         let is_mouse_over = cursor.is_over(bounds);
 
@@ -373,7 +543,36 @@ where
             },
         );
 
-        let (handle_width, handle_height, handle_border_radius) =
+        // Everything is laid out along the rail, starting from its start;
+        // and across it, starting from its left or top side.
+        let (length, thickness) = match self.orientation {
+            Orientation::Horizontal => (bounds.width, bounds.height),
+            Orientation::Vertical => (bounds.height, bounds.width),
+        };
+
+        // The tick labels take the space after the rail
+        let thickness =
+            (thickness - state.label_space(self.orientation)).max(0.0);
+
+        let rectangle =
+            |along: f32, across: f32, size_along, size_across| match self
+                .orientation
+            {
+                Orientation::Horizontal => Rectangle {
+                    x: bounds.x + along,
+                    y: bounds.y + across,
+                    width: size_along,
+                    height: size_across,
+                },
+                Orientation::Vertical => Rectangle {
+                    x: bounds.x + across,
+                    y: bounds.y + bounds.height - along - size_along,
+                    width: size_across,
+                    height: size_along,
+                },
+            };
+
+        let (handle_length, handle_thickness, handle_border_radius) =
             match style.handle.shape {
                 HandleShape::Circle { radius } => {
                     (radius * 2.0, radius * 2.0, radius.into())
@@ -381,7 +580,7 @@ where
                 HandleShape::Rectangle {
                     width,
                     border_radius,
-                } => (f32::from(width), bounds.height, border_radius),
+                } => (f32::from(width), thickness, border_radius),
             };
 
         let value = self.value.into() as f32;
@@ -394,48 +593,124 @@ where
         let offset = if range_start >= range_end {
             0.0
         } else {
-            (bounds.width - handle_width) * (value - range_start)
+            (length - handle_length) * (value - range_start)
                 / (range_end - range_start)
         };
 
-        let rail_y = bounds.y + bounds.height / 2.0;
+        let rail = thickness / 2.0 - style.rail.width / 2.0;
+        let filled = offset + handle_length / 2.0;
+
+        let position = |value: f32| {
+            handle_length / 2.0
+                + (length - handle_length)
+                    * (value.clamp(range_start, range_end) - range_start)
+                    / (range_end - range_start)
+        };
 
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y - style.rail.width / 2.0,
-                    width: offset + handle_width / 2.0,
-                    height: style.rail.width,
-                },
+                bounds: rectangle(
+                    filled,
+                    rail,
+                    length - filled,
+                    style.rail.width,
+                ),
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.0,
+            style.rail.colors.1,
         );
 
+        if range_start < range_end {
+            for range in &self.buffered {
+                let (start, end) = range.clone().into_inner();
+
+                // Buffered ranges are only visible past the value
+                let start = position(start.into() as f32).max(filled);
+                let end = position(end.into() as f32);
+
+                if end > start {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: rectangle(
+                                start,
+                                rail,
+                                end - start,
+                                style.rail.width,
+                            ),
+                            border: border::rounded(style.rail.border_radius),
+                            ..renderer::Quad::default()
+                        },
+                        style.rail.buffered,
+                    );
+                }
+            }
+        }
+
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x + offset + handle_width / 2.0,
-                    y: rail_y - style.rail.width / 2.0,
-                    width: bounds.width - offset - handle_width / 2.0,
-                    height: style.rail.width,
-                },
+                bounds: rectangle(0.0, rail, filled, style.rail.width),
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.1,
+            style.rail.colors.0,
         );
 
+        if range_start < range_end && !self.ticks.is_empty() {
+            let ticks = self.visible_ticks();
+
+            for tick in &ticks {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: rectangle(
+                            position(*tick) - style.tick.width / 2.0,
+                            thickness / 2.0 - style.tick.length / 2.0,
+                            style.tick.width,
+                            style.tick.length,
+                        ),
+                        ..renderer::Quad::default()
+                    },
+                    style.tick.color,
+                );
+            }
+
+            for (tick, label) in ticks.iter().zip(&state.labels) {
+                let size = label.min_bounds();
+
+                let (size_along, size_across) = match self.orientation {
+                    Orientation::Horizontal => (size.width, size.height),
+                    Orientation::Vertical => (size.height, size.width),
+                };
+
+                // Labels are centered on their ticks, but kept inside
+                let along = (position(*tick) - size_along / 2.0)
+                    .min(length - size_along)
+                    .max(0.0);
+
+                let label_bounds = rectangle(
+                    along,
+                    thickness + LABEL_SPACING,
+                    size_along,
+                    size_across,
+                );
+
+                renderer.fill_paragraph(
+                    label.raw(),
+                    label_bounds.position(),
+                    style.tick.label,
+                    *viewport,
+                );
+            }
+        }
+
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x + offset,
-                    y: rail_y - handle_height / 2.0,
-                    width: handle_width,
-                    height: handle_height,
-                },
+                bounds: rectangle(
+                    offset,
+                    thickness / 2.0 - handle_thickness / 2.0,
+                    handle_length,
+                    handle_thickness,
+                ),
                 border: Border {
                     radius: handle_border_radius,
                     width: style.handle.border_width,
@@ -455,7 +730,7 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let bounds = layout.bounds();
         let is_mouse_over = cursor.is_over(bounds);
 
@@ -475,7 +750,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(
         slider: Slider<'a, T, Message, Theme>,
@@ -484,10 +759,139 @@ where
     }
 }
 
+/// The orientation of a [`Slider`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-struct State {
+pub enum Orientation {
+    /// The [`Slider`] goes from left to right.
+    #[default]
+    Horizontal,
+    /// The [`Slider`] goes from bottom to top.
+    Vertical,
+}
+
+/// The amount of steps moved by the Page Up and Page Down keys.
+const PAGE: f64 = 10.0;
+
+/// Snaps a value to the closest step of a range, starting from its start.
+fn snap(value: f64, range: RangeInclusive<f64>, step: f64) -> f64 {
+    let (start, end) = range.into_inner();
+
+    if step <= 0.0 {
+        return value.min(end).max(start);
+    }
+
+    let steps = ((value - start) / step).round();
+
+    (start + steps * step).min(end).max(start)
+}
+
+/// Moves a value the given amount of steps through a range.
+///
+/// A value in between steps counts the nearest step in the direction of
+/// movement as the first one.
+fn advance(
+    value: f64,
+    range: RangeInclusive<f64>,
+    step: f64,
+    steps: f64,
+) -> f64 {
+    let (start, end) = range.into_inner();
+
+    if step <= 0.0 {
+        return value.min(end).max(start);
+    }
+
+    // Tolerate the rounding errors of values that are already on a step
+    let current = (value - start) / step;
+    let current = if steps > 0.0 {
+        (current + TOLERANCE).floor()
+    } else {
+        (current - TOLERANCE).ceil()
+    };
+
+    (start + (current + steps) * step).min(end).max(start)
+}
+
+/// Returns the tick closest to a value.
+fn nearest(value: f64, ticks: &[f64]) -> f64 {
+    ticks
+        .iter()
+        .copied()
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+        .unwrap_or(value)
+}
+
+/// Moves a value the given amount of ticks, which must be sorted.
+///
+/// A value in between ticks counts the nearest tick in the direction of
+/// movement as the first one.
+fn advance_ticks(value: f64, ticks: &[f64], steps: f64) -> f64 {
+    let Some(last) = ticks.len().checked_sub(1) else {
+        return value;
+    };
+
+    let steps = steps as isize;
+
+    let index = if steps > 0 {
+        let next = ticks.partition_point(|tick| *tick <= value + TOLERANCE);
+
+        next as isize + steps - 1
+    } else {
+        let previous = ticks.partition_point(|tick| *tick < value - TOLERANCE);
+
+        previous as isize + steps
+    };
+
+    ticks[index.clamp(0, last as isize) as usize]
+}
+
+const TOLERANCE: f64 = 1e-6;
+
+/// The space between the rail of a [`Slider`] and its tick labels.
+const LABEL_SPACING: f32 = 4.0;
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
     is_dragging: bool,
+    /// The position of the press that started dragging, until the cursor
+    /// travels the drag threshold away from it.
+    origin: Option<Point>,
     keyboard_modifiers: keyboard::Modifiers,
+    labels: Vec<paragraph::Plain<P>>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    /// Returns the space taken by the tick labels across the rail.
+    fn label_space(&self, orientation: Orientation) -> f32 {
+        if self.labels.is_empty() {
+            return 0.0;
+        }
+
+        let extent = self.labels.iter().fold(0.0, |extent, label| {
+            let size = label.min_bounds();
+
+            f32::max(
+                extent,
+                match orientation {
+                    Orientation::Horizontal => size.height,
+                    Orientation::Vertical => size.width,
+                },
+            )
+        });
+
+        LABEL_SPACING + extent
+    }
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            is_dragging: false,
+            origin: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            labels: Vec::new(),
+        }
+    }
 }
 
 /// The possible status of a [`Slider`].
@@ -508,6 +912,8 @@ pub struct Style {
     pub rail: Rail,
     /// The appearance of the [`Handle`] of the slider.
     pub handle: Handle,
+    /// The appearance of the ticks of the slider.
+    pub tick: Tick,
 }
 
 impl Style {
@@ -526,6 +932,8 @@ impl Style {
 pub struct Rail {
     /// The colors of the rail of the slider.
     pub colors: (Color, Color),
+    /// The [`Color`] of the buffered ranges of the rail.
+    pub buffered: Color,
     /// The width of the stroke of a slider rail.
     pub width: f32,
     /// The border radius of the corners of the rail.
@@ -545,6 +953,19 @@ pub struct Handle {
     pub border_color: Color,
 }
 
+/// The appearance of the ticks of a slider.
+#[derive(Debug, Clone, Copy)]
+pub struct Tick {
+    /// The [`Color`] of the tick marks.
+    pub color: Color,
+    /// The width of a tick mark, along the rail.
+    pub width: f32,
+    /// The length of a tick mark, across the rail.
+    pub length: f32,
+    /// The [`Color`] of the tick labels.
+    pub label: Color,
+}
+
 /// The shape of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
 pub enum HandleShape {
@@ -602,6 +1023,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     Style {
         rail: Rail {
             colors: (color, palette.secondary.base.color),
+            buffered: palette.secondary.strong.color,
             width: 4.0,
             border_radius: 2.0.into(),
         },
@@ -611,5 +1033,257 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             border_color: Color::TRANSPARENT,
             border_width: 0.0,
         },
+        tick: Tick {
+            color: palette.background.strong.color,
+            width: 1.0,
+            length: 10.0,
+            label: palette.background.base.text,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+
+    type Slider = super::Slider<'static, u8, u8, Theme>;
+    type Element = crate::core::Element<'static, u8, Theme, ()>;
+
+    /// Sends the events to a 100x100 vertical [`Slider`] going from 0 to
+    /// 100; with the cursor at the given position.
+    fn vertical(
+        value: u8,
+        cursor: Point,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<u8> {
+        simulate(
+            Slider::new(0..=100, value, |value| value)
+                .orientation(Orientation::Vertical)
+                .width(100)
+                .height(100)
+                .step(5),
+            cursor,
+            events,
+        )
+    }
+
+    /// Sends the events to the [`Slider`]; with the cursor at the given
+    /// position.
+    fn simulate(
+        slider: Slider,
+        cursor: Point,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<u8> {
+        simulate_moving(slider, events.into_iter().map(|event| (cursor, event)))
+    }
+
+    /// Sends the events to the [`Slider`]; each with the cursor at its own
+    /// position.
+    fn simulate_moving(
+        slider: Slider,
+        events: impl IntoIterator<Item = (Point, Event)>,
+    ) -> Vec<u8> {
+        let mut slider: Element = slider.into();
+
+        let mut tree = Tree::new(&slider);
+
+        let node = slider.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for (cursor, event) in events {
+            let _ = slider.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(cursor),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        messages
+    }
+
+    fn press(key: key::Named) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: Key::Named(key),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+        })
+    }
+
+    #[test]
+    fn snapping_is_relative_to_the_start() {
+        assert_eq!(snap(52.0, 1.0..=100.0, 5.0), 51.0);
+        assert_eq!(snap(99.0, 1.0..=100.0, 5.0), 100.0);
+        assert_eq!(snap(-10.0, 1.0..=100.0, 5.0), 1.0);
+    }
+
+    #[test]
+    fn advancing_moves_to_the_next_step() {
+        // In between steps
+        assert_eq!(advance(50.0, 1.0..=100.0, 5.0, 1.0), 51.0);
+        assert_eq!(advance(50.0, 1.0..=100.0, 5.0, -1.0), 46.0);
+
+        // On a step
+        assert_eq!(advance(51.0, 1.0..=100.0, 5.0, 1.0), 56.0);
+        assert_eq!(advance(51.0, 1.0..=100.0, 5.0, -10.0), 1.0);
+        assert_eq!(advance(96.0, 1.0..=100.0, 5.0, 1.0), 100.0);
+
+        // With rounding errors
+        assert!((advance(0.3, 0.0..=1.0, 0.1, 1.0) - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn vertical_sliders_start_at_the_bottom() {
+        let top = Point::new(50.0, 10.0);
+        let bottom = Point::new(50.0, 95.0);
+
+        let click = [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ];
+
+        assert_eq!(vertical(50, top, click.clone()), vec![90]);
+        assert_eq!(vertical(50, bottom, click), vec![5]);
+    }
+
+    #[test]
+    fn vertical_sliders_are_stepped_with_the_keyboard() {
+        let cursor = Point::new(50.0, 50.0);
+
+        assert_eq!(
+            vertical(
+                50,
+                cursor,
+                [
+                    press(key::Named::ArrowUp),
+                    press(key::Named::ArrowUp),
+                    press(key::Named::ArrowDown),
+                ]
+            ),
+            vec![55, 60, 55]
+        );
+
+        assert_eq!(
+            vertical(
+                50,
+                cursor,
+                [
+                    press(key::Named::PageDown),
+                    press(key::Named::End),
+                    press(key::Named::PageUp),
+                    press(key::Named::Home),
+                ]
+            ),
+            vec![0, 100, 0]
+        );
+    }
+
+    #[test]
+    fn ticks_out_of_range_are_ignored() {
+        let slider = Slider::new(0..=100, 50, |value| value).ticks([
+            150.0,
+            30.0,
+            -5.0,
+            0.0,
+            30.0,
+            f32::NAN,
+        ]);
+
+        assert_eq!(slider.visible_ticks(), vec![0.0, 30.0]);
+    }
+
+    #[test]
+    fn advancing_moves_to_the_next_tick() {
+        let ticks = [0.0, 30.0, 70.0, 100.0];
+
+        // In between ticks
+        assert_eq!(advance_ticks(50.0, &ticks, 1.0), 70.0);
+        assert_eq!(advance_ticks(50.0, &ticks, -1.0), 30.0);
+
+        // On a tick
+        assert_eq!(advance_ticks(30.0, &ticks, 1.0), 70.0);
+        assert_eq!(advance_ticks(30.0, &ticks, -1.0), 0.0);
+        assert_eq!(advance_ticks(30.0, &ticks, 10.0), 100.0);
+        assert_eq!(advance_ticks(30.0, &ticks, -10.0), 0.0);
+
+        assert_eq!(advance_ticks(50.0, &[], 1.0), 50.0);
+    }
+
+    #[test]
+    fn sliders_snap_to_ticks() {
+        let slider = Slider::new(0..=100, 50, |value| value)
+            .width(100)
+            .ticks([0.0, 30.0, 70.0, 100.0, 150.0])
+            .snap_to_ticks(true);
+
+        assert_eq!(
+            simulate(
+                slider,
+                Point::new(20.0, 8.0),
+                [
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left
+                    )),
+                    Event::Mouse(mouse::Event::ButtonReleased(
+                        mouse::Button::Left
+                    )),
+                    press(key::Named::ArrowRight),
+                    press(key::Named::ArrowRight),
+                    press(key::Named::ArrowRight),
+                    press(key::Named::ArrowLeft),
+                    press(key::Named::Home),
+                ]
+            ),
+            vec![30, 70, 100, 70, 0]
+        );
+    }
+
+    #[test]
+    fn small_movements_while_clicking_are_ignored() {
+        let slider = || Slider::new(0..=100, 50, |value| value).width(100);
+
+        let press =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        let moved = |x| {
+            (
+                Point::new(x, 8.0),
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: Point::new(x, 8.0),
+                }),
+            )
+        };
+
+        let drag = || {
+            [
+                (Point::new(20.0, 8.0), press.clone()),
+                moved(23.0),
+                moved(28.0),
+            ]
+        };
+
+        assert_eq!(simulate_moving(slider(), drag()), vec![20, 28]);
+
+        Interaction {
+            drag_threshold: 10.0,
+            ..Interaction::default()
+        }
+        .apply();
+
+        assert_eq!(simulate_moving(slider(), drag()), vec![20]);
+
+        Interaction::default().apply();
     }
 }
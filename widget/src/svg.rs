@@ -31,6 +31,7 @@ where
     class: Theme::Class<'a>,
     rotation: Rotation,
     opacity: f32,
+    symbolic: bool,
 }
 
 impl<'a, Theme> Svg<'a, Theme>
@@ -47,6 +48,7 @@ where
             class: Theme::default(),
             rotation: Rotation::default(),
             opacity: 1.0,
+            symbolic: false,
         }
     }
 
@@ -114,6 +116,18 @@ where
         self.opacity = opacity.into();
         self
     }
+
+    /// Sets whether the [`Svg`] is a symbolic icon.
+    ///
+    /// A symbolic icon without a [`Style::color`] is tinted with the text
+    /// color of its parent; like the one of a button, which follows its
+    /// status.
+    ///
+    /// Icons that paint with `currentColor` only have those parts tinted.
+    pub fn symbolic(mut self, symbolic: bool) -> Self {
+        self.symbolic = symbolic;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -168,7 +182,7 @@ where
         _state: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        defaults: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
@@ -209,10 +223,14 @@ where
 
         let style = theme.style(&self.class, status);
 
+        let color = style
+            .color
+            .or_else(|| self.symbolic.then_some(defaults.text_color));
+
         let render = |renderer: &mut Renderer| {
             renderer.draw_svg(
                 self.handle.clone(),
-                style.color,
+                color,
                 drawing_bounds,
                 self.rotation.radians(),
                 self.opacity,
@@ -254,7 +272,8 @@ pub enum Status {
 pub struct Style {
     /// The [`Color`] filter of an [`Svg`].
     ///
-    /// Useful for coloring a symbolic icon.
+    /// Useful for coloring a symbolic icon. If the icon paints with
+    /// `currentColor`, only those parts are colored.
     ///
     /// `None` keeps the original color.
     pub color: Option<Color>,
@@ -0,0 +1,596 @@
+//! Swipe content horizontally to reveal and trigger actions.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::Space;
+
+/// The distance a drag must travel before its axis is decided.
+const AXIS_LOCK_DISTANCE: f32 = 8.0;
+
+/// The fraction of the drag applied to the content past the threshold.
+const RESISTANCE: f32 = 0.3;
+
+/// The duration of the animation snapping the content back into place.
+const SNAP_DURATION: Duration = Duration::from_millis(200);
+
+/// A container that can be swiped horizontally to reveal actions.
+///
+/// The content follows the finger—or the mouse—while swiping, uncovering
+/// the leading or trailing action areas underneath. Releasing the content
+/// past the threshold triggers the action of the revealed [`Side`];
+/// otherwise, it snaps back into place.
+///
+/// A drag only turns into a swipe once it clearly moves horizontally. Vertical
+/// drags are left untouched, so a [`Swipeable`] can live inside a vertical
+/// [`Scrollable`](crate::Scrollable).
+///
+/// ```no_run
+/// # type Swipeable<'a, Message> = iced_widget::Swipeable<'a, Message>;
+/// # use iced_widget::swipeable::Side;
+/// # use iced_widget::text;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     Swiped(Side),
+/// }
+///
+/// let swipeable: Swipeable<'_, Message> =
+///     Swipeable::new(text("Swipe me!"))
+///         .trailing(text("Delete"))
+///         .on_action(Message::Swiped);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Swipeable<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    leading: Element<'a, Message, Theme, Renderer>,
+    trailing: Element<'a, Message, Theme, Renderer>,
+    has_leading: bool,
+    has_trailing: bool,
+    threshold: f32,
+    on_action: Option<Box<dyn Fn(Side) -> Message + 'a>>,
+}
+
+/// The side of a [`Swipeable`] revealed by a swipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The leading side, revealed by swiping to the right.
+    Leading,
+    /// The trailing side, revealed by swiping to the left.
+    Trailing,
+}
+
+impl<'a, Message, Theme, Renderer> Swipeable<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer,
+{
+    /// The default distance a [`Swipeable`] must be swiped to trigger an
+    /// action.
+    pub const DEFAULT_THRESHOLD: f32 = 80.0;
+
+    /// Creates a new [`Swipeable`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            leading: Space::new(0, 0).into(),
+            trailing: Space::new(0, 0).into(),
+            has_leading: false,
+            has_trailing: false,
+            threshold: Self::DEFAULT_THRESHOLD,
+            on_action: None,
+        }
+    }
+
+    /// Sets the action area revealed when swiping to the right.
+    pub fn leading(
+        mut self,
+        leading: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.leading = leading.into();
+        self.has_leading = true;
+        self
+    }
+
+    /// Sets the action area revealed when swiping to the left.
+    pub fn trailing(
+        mut self,
+        trailing: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.trailing = trailing.into();
+        self.has_trailing = true;
+        self
+    }
+
+    /// Sets the distance the [`Swipeable`] must be swiped to trigger an
+    /// action.
+    ///
+    /// The content moves with resistance past this distance.
+    pub fn threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.threshold = threshold.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the message produced when the [`Swipeable`] is released past
+    /// the threshold.
+    ///
+    /// If this method is not called, the [`Swipeable`] can still be swiped,
+    /// but it will always snap back.
+    pub fn on_action(
+        mut self,
+        on_action: impl Fn(Side) -> Message + 'a,
+    ) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Returns the offset of the content after dragging it the given
+    /// distance.
+    fn resist(&self, distance: f32) -> f32 {
+        let distance = if self.has_leading {
+            distance
+        } else {
+            distance.min(0.0)
+        };
+
+        let distance = if self.has_trailing {
+            distance
+        } else {
+            distance.max(0.0)
+        };
+
+        let excess = distance.abs() - self.threshold;
+
+        if excess > 0.0 {
+            (self.threshold + excess * RESISTANCE).copysign(distance)
+        } else {
+            distance
+        }
+    }
+}
+
+/// The local state of a [`Swipeable`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    offset: f32,
+    drag: Option<Drag>,
+    snap: Option<Snap>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    finger: Option<touch::Finger>,
+    axis: Axis,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Undecided,
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Snap {
+    from: f32,
+    started_at: Instant,
+}
+
+impl State {
+    fn is_swiping(&self) -> bool {
+        self.drag.is_some_and(|drag| drag.axis == Axis::Horizontal)
+    }
+
+    fn is_idle(&self) -> bool {
+        self.offset == 0.0 && !self.is_swiping()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Swipeable<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.content),
+            Tree::new(&self.leading),
+            Tree::new(&self.trailing),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.leading, &self.trailing]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let size = content.size();
+        let limits = layout::Limits::new(Size::new(0.0, size.height), size);
+
+        let leading = self.leading.as_widget().layout(
+            &mut tree.children[1],
+            renderer,
+            &limits,
+        );
+
+        let trailing = self.trailing.as_widget().layout(
+            &mut tree.children[2],
+            renderer,
+            &limits,
+        );
+
+        let trailing_x = size.width - trailing.size().width;
+
+        layout::Node::with_children(
+            size,
+            vec![content, leading, trailing.move_to((trailing_x, 0.0))],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if state.drag.is_none() =>
+            {
+                let finger = match event {
+                    Event::Touch(touch::Event::FingerPressed {
+                        id, ..
+                    }) => Some(id),
+                    _ => None,
+                };
+
+                if let Some(origin) = cursor.position_over(bounds) {
+                    state.drag = Some(Drag {
+                        origin: Point::new(origin.x - state.offset, origin.y),
+                        finger,
+                        axis: Axis::Undecided,
+                    });
+                    state.snap = None;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    if !is_same_pointer(drag, &event) {
+                        return event::Status::Ignored;
+                    }
+
+                    let delta = position - drag.origin;
+
+                    if drag.axis == Axis::Undecided {
+                        if delta.x.abs() > AXIS_LOCK_DISTANCE
+                            && delta.x.abs() > delta.y.abs()
+                        {
+                            drag.axis = Axis::Horizontal;
+                        } else if delta.y.abs() > AXIS_LOCK_DISTANCE {
+                            drag.axis = Axis::Vertical;
+                        }
+                    }
+
+                    if drag.axis == Axis::Horizontal {
+                        state.offset = self.resist(delta.x);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(
+                touch::Event::FingerLifted { .. }
+                | touch::Event::FingerLost { .. },
+            ) => {
+                if let Some(drag) = state.drag {
+                    if !is_same_pointer(&drag, &event) {
+                        return event::Status::Ignored;
+                    }
+
+                    state.drag = None;
+
+                    if drag.axis == Axis::Horizontal {
+                        if state.offset.abs() >= self.threshold {
+                            let side = if state.offset > 0.0 {
+                                Side::Leading
+                            } else {
+                                Side::Trailing
+                            };
+
+                            if let Some(on_action) = &self.on_action {
+                                shell.publish(on_action(side));
+                            }
+                        }
+
+                        state.snap = Some(Snap {
+                            from: state.offset,
+                            started_at: Instant::now(),
+                        });
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                        // Let the content know the pointer is gone, without
+                        // triggering anything under it.
+                        let _ = self.content.as_widget_mut().on_event(
+                            &mut tree.children[0],
+                            event,
+                            content_layout,
+                            mouse::Cursor::Unavailable,
+                            renderer,
+                            clipboard,
+                            shell,
+                            viewport,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(snap) = state.snap {
+                    let progress = (now - snap.started_at).as_secs_f32()
+                        / SNAP_DURATION.as_secs_f32();
+
+                    if progress >= 1.0 {
+                        state.offset = 0.0;
+                        state.snap = None;
+                    } else {
+                        state.offset = snap.from * (1.0 - ease_out(progress));
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if !state.is_idle() {
+            return event::Status::Ignored;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_swiping() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if !state.is_idle() {
+            return mouse::Interaction::None;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+
+        if state.offset == 0.0 {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+
+            return;
+        }
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let (action, action_tree, action_layout, revealed) =
+            if state.offset > 0.0 {
+                (
+                    &self.leading,
+                    &tree.children[1],
+                    children.next().unwrap(),
+                    Rectangle {
+                        width: state.offset,
+                        ..bounds
+                    },
+                )
+            } else {
+                (
+                    &self.trailing,
+                    &tree.children[2],
+                    children.nth(1).unwrap(),
+                    Rectangle {
+                        x: bounds.x + bounds.width + state.offset,
+                        width: -state.offset,
+                        ..bounds
+                    },
+                )
+            };
+
+        if let Some(revealed) = revealed.intersection(&clip) {
+            renderer.with_layer(revealed, |renderer| {
+                action.as_widget().draw(
+                    action_tree,
+                    renderer,
+                    theme,
+                    style,
+                    action_layout,
+                    mouse::Cursor::Unavailable,
+                    &revealed,
+                );
+            });
+        }
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_translation(
+                Vector::new(state.offset, 0.0),
+                |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        style,
+                        content_layout,
+                        mouse::Cursor::Unavailable,
+                        viewport,
+                    );
+                },
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Swipeable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + crate::core::Renderer,
+{
+    fn from(
+        swipeable: Swipeable<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(swipeable)
+    }
+}
+
+/// Returns whether the given [`Event`] comes from the pointer that started
+/// the [`Drag`].
+fn is_same_pointer(drag: &Drag, event: &Event) -> bool {
+    match event {
+        Event::Touch(
+            touch::Event::FingerMoved { id, .. }
+            | touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. },
+        ) => drag.finger == Some(*id),
+        Event::Mouse(_) => drag.finger.is_none(),
+        _ => false,
+    }
+}
+
+/// A cubic ease-out curve.
+fn ease_out(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
+}
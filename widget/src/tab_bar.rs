@@ -0,0 +1,1183 @@
+//! Switch between multiple views with a strip of tabs.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::gradient;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Degrees, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::overlay::menu::{self, Menu};
+
+use std::fmt;
+
+/// The width of the indicators shown at the edges of an overflowing
+/// [`TabBar`].
+const FADE_WIDTH: f32 = 24.0;
+
+/// The amount of pixels scrolled per line of a mouse wheel.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// The horizontal space between the icon, the label, and the close button
+/// of a [`Tab`].
+const GAP: f32 = 6.0;
+
+/// A horizontal strip of tabs, one of them active.
+///
+/// When the tabs do not fit, the strip can be scrolled horizontally and an
+/// overflow menu listing the hidden tabs is displayed at its end.
+///
+/// ```no_run
+/// # type TabBar<'a, Message> = iced_widget::TabBar<'a, Message>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     TabSelected(usize),
+///     TabClosed(usize),
+/// }
+///
+/// let tab_bar: TabBar<'_, Message> =
+///     TabBar::new(["main.rs", "lib.rs"], 0, Message::TabSelected)
+///         .on_close(Message::TabClosed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TabBar<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tabs: Vec<Tab<Renderer::Font>>,
+    active: usize,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    cycles_with_keyboard: bool,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TabBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a [`Tab`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 6.0,
+        right: 12.0,
+        bottom: 6.0,
+        left: 12.0,
+    };
+
+    /// Creates a new [`TabBar`] with the given tabs, the index of the active
+    /// one, and the message to produce when a tab is selected.
+    pub fn new(
+        tabs: impl IntoIterator<Item = impl Into<Tab<Renderer::Font>>>,
+        active: usize,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            active,
+            on_select: Box::new(on_select),
+            on_close: None,
+            cycles_with_keyboard: false,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 2.0,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Sets the message to produce when a [`Tab`] is closed.
+    ///
+    /// Closable tabs display a close button and can also be closed with a
+    /// middle click.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets whether the [`TabBar`] cycles through its tabs when pressing
+    /// `Ctrl+Tab` and `Ctrl+Shift+Tab`.
+    ///
+    /// The shortcuts are listened to regardless of focus. By default, they
+    /// are disabled.
+    pub fn cycle_with_keyboard(mut self, cycles: bool) -> Self {
+        self.cycles_with_keyboard = cycles;
+        self
+    }
+
+    /// Sets the width of the [`TabBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of every [`Tab`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the tabs of the [`TabBar`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`TabBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`TabBar`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`TabBar`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`TabBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TabBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the overflow menu of the [`TabBar`].
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TabBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the overflow menu of the [`TabBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn is_closable(&self, tab: &Tab<Renderer::Font>) -> bool {
+        self.on_close.is_some() && tab.is_closable
+    }
+
+    fn close_bounds(&self, tab: Rectangle, text_size: Pixels) -> Rectangle {
+        Rectangle {
+            x: tab.x + tab.width - self.padding.right - text_size.0,
+            y: tab.center_y() - text_size.0 / 2.0,
+            width: text_size.0,
+            height: text_size.0,
+        }
+    }
+
+    fn resolved_text_size(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+}
+
+/// A tab of a [`TabBar`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab<Font = crate::core::Font> {
+    label: String,
+    icon: Option<Icon<Font>>,
+    is_closable: bool,
+}
+
+impl<Font> Tab<Font> {
+    /// Creates a new closable [`Tab`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            is_closable: true,
+        }
+    }
+
+    /// Sets the [`Icon`] displayed before the label of the [`Tab`].
+    pub fn icon(mut self, icon: Icon<Font>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets whether the [`Tab`] can be closed.
+    ///
+    /// Tabs are only closable if [`TabBar::on_close`] is set.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+impl<Font> From<&str> for Tab<Font> {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl<Font> From<String> for Tab<Font> {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// The icon of a [`Tab`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Icon<Font> {
+    /// Font that will be used to display the `code_point`.
+    pub font: Font,
+    /// The unicode code point that will be used as the icon.
+    pub code_point: char,
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    labels: Vec<paragraph::Plain<P>>,
+    offset: f32,
+    content_width: f32,
+    visible_width: f32,
+    revealed: Option<usize>,
+    menu: menu::State,
+    is_menu_open: bool,
+    hovered_option: Option<usize>,
+    hidden: Vec<Hidden>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new() -> Self {
+        Self {
+            labels: Vec::new(),
+            offset: 0.0,
+            content_width: 0.0,
+            visible_width: 0.0,
+            revealed: None,
+            menu: menu::State::default(),
+            is_menu_open: false,
+            hovered_option: None,
+            hidden: Vec::new(),
+        }
+    }
+
+    fn is_overflowing(&self) -> bool {
+        self.content_width > self.visible_width
+    }
+
+    fn max_offset(&self) -> f32 {
+        (self.content_width - self.visible_width).max(0.0)
+    }
+
+    /// Returns the index and the visible bounds of the tab under the given
+    /// position.
+    fn tab_at(
+        &self,
+        layout: Layout<'_>,
+        position: Point,
+    ) -> Option<(usize, Rectangle)> {
+        if !self.strip(layout.bounds()).contains(position) {
+            return None;
+        }
+
+        layout
+            .children()
+            .map(|tab| tab.bounds() - Vector::new(self.offset, 0.0))
+            .enumerate()
+            .find(|(_, tab)| tab.contains(position))
+    }
+
+    fn strip(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            width: self.visible_width,
+            ..bounds
+        }
+    }
+
+    fn menu_button(&self, bounds: Rectangle) -> Option<Rectangle> {
+        self.is_overflowing().then(|| Rectangle {
+            x: bounds.x + self.visible_width,
+            width: bounds.width - self.visible_width,
+            ..bounds
+        })
+    }
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A hidden tab listed in the overflow menu.
+#[derive(Debug, Clone)]
+struct Hidden {
+    index: usize,
+    label: String,
+}
+
+impl fmt::Display for Hidden {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabBar<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.resolved_text_size(renderer);
+        let line_height = self.text_line_height.to_absolute(text_size).0;
+
+        state.labels.resize_with(self.tabs.len(), Default::default);
+
+        for (tab, label) in self.tabs.iter().zip(&mut state.labels) {
+            label.update(Text {
+                content: &tab.label,
+                bounds: Size::new(f32::INFINITY, line_height),
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                font_features: &[],
+                shaping: self.text_shaping,
+            });
+        }
+
+        let height = line_height + self.padding.vertical();
+        let mut x = 0.0;
+
+        let tabs: Vec<_> = self
+            .tabs
+            .iter()
+            .zip(&state.labels)
+            .map(|(tab, label)| {
+                let mut width = self.padding.horizontal() + label.min_width();
+
+                if tab.icon.is_some() {
+                    width += text_size.0 + GAP;
+                }
+
+                if self.is_closable(tab) {
+                    width += GAP + text_size.0;
+                }
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to((x, 0.0));
+
+                x += width + self.spacing;
+
+                node
+            })
+            .collect();
+
+        let content_width = (x - self.spacing).max(0.0);
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(content_width, height),
+        );
+
+        let menu_width = if content_width > size.width {
+            height
+        } else {
+            0.0
+        };
+
+        state.content_width = content_width;
+        state.visible_width = (size.width - menu_width).max(0.0);
+
+        if state.revealed != Some(self.active) {
+            if let Some(tab) = tabs.get(self.active) {
+                let tab = tab.bounds();
+
+                if tab.x < state.offset {
+                    state.offset = tab.x;
+                } else if tab.x + tab.width > state.offset + state.visible_width
+                {
+                    state.offset = tab.x + tab.width - state.visible_width;
+                }
+            }
+
+            state.revealed = Some(self.active);
+        }
+
+        state.offset = state.offset.clamp(0.0, state.max_offset());
+
+        layout::Node::with_children(size, tabs)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let was_menu_open = state.is_menu_open;
+
+                // The overlay did not process the event, so the menu can be
+                // closed.
+                state.is_menu_open = false;
+
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if state
+                    .menu_button(bounds)
+                    .is_some_and(|button| button.contains(position))
+                {
+                    state.is_menu_open = !was_menu_open;
+                    state.hovered_option = None;
+
+                    return event::Status::Captured;
+                }
+
+                if let Some((index, tab)) = state.tab_at(layout, position) {
+                    let close = self
+                        .close_bounds(tab, self.resolved_text_size(renderer));
+
+                    match &self.on_close {
+                        Some(on_close)
+                            if self.tabs[index].is_closable
+                                && close.contains(position) =>
+                        {
+                            shell.publish(on_close(index));
+                        }
+                        _ if index != self.active => {
+                            shell.publish((self.on_select)(index));
+                        }
+                        _ => {}
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                if let (Some(on_close), Some((index, _))) = (
+                    &self.on_close,
+                    cursor
+                        .position()
+                        .and_then(|position| state.tab_at(layout, position)),
+                ) {
+                    if self.tabs[index].is_closable {
+                        shell.publish(on_close(index));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.is_overflowing() && cursor.is_over(bounds) =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x * LINE_HEIGHT, y * LINE_HEIGHT)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                let delta = if x.abs() > y.abs() { x } else { y };
+
+                state.offset =
+                    (state.offset - delta).clamp(0.0, state.max_offset());
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) if self.cycles_with_keyboard
+                && modifiers.control()
+                && !self.tabs.is_empty() =>
+            {
+                let count = self.tabs.len();
+                let active = self.active.min(count - 1);
+
+                let next = if modifiers.shift() {
+                    (active + count - 1) % count
+                } else {
+                    (active + 1) % count
+                };
+
+                shell.publish((self.on_select)(next));
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let Some(position) = cursor.position() else {
+            return mouse::Interaction::default();
+        };
+
+        let is_over_menu = state
+            .menu_button(layout.bounds())
+            .is_some_and(|button| button.contains(position));
+
+        if is_over_menu || state.tab_at(layout, position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let text_size = self.resolved_text_size(renderer);
+
+        let hovered = cursor
+            .position()
+            .and_then(|position| state.tab_at(layout, position));
+
+        if let Some(clip) = state.strip(bounds).intersection(viewport) {
+            renderer.with_layer(clip, |renderer| {
+                for (index, ((tab, label), tab_layout)) in self
+                    .tabs
+                    .iter()
+                    .zip(&state.labels)
+                    .zip(layout.children())
+                    .enumerate()
+                {
+                    let tab_bounds =
+                        tab_layout.bounds() - Vector::new(state.offset, 0.0);
+
+                    if !tab_bounds.intersects(&clip) {
+                        continue;
+                    }
+
+                    let status = if index == self.active {
+                        Status::Selected
+                    } else if hovered
+                        .is_some_and(|(hovered, _)| hovered == index)
+                    {
+                        Status::Hovered
+                    } else {
+                        Status::Active
+                    };
+
+                    let style = theme.style(&self.class, status);
+
+                    draw_tab(
+                        renderer,
+                        &style,
+                        tab_bounds,
+                        label.raw(),
+                        tab.icon.as_ref(),
+                        self.padding,
+                        text_size,
+                        self.text_line_height,
+                        self.text_shaping,
+                        clip,
+                    );
+
+                    if self.is_closable(tab) {
+                        let close = self.close_bounds(tab_bounds, text_size);
+
+                        if cursor.is_over(close) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: close,
+                                    border: border::rounded(close.width / 2.0),
+                                    ..renderer::Quad::default()
+                                },
+                                style.text_color.scale_alpha(0.15),
+                            );
+                        }
+
+                        renderer.fill_text(
+                            Text {
+                                content: String::from("×"),
+                                bounds: close.size(),
+                                size: text_size,
+                                line_height: text::LineHeight::default(),
+                                font: renderer.default_font(),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Center,
+                                vertical_alignment: alignment::Vertical::Center,
+                                max_space_factor:
+                                    text::DEFAULT_MAX_SPACE_FACTOR,
+                                font_features: &[],
+                                shaping: text::Shaping::Basic,
+                            },
+                            close.center(),
+                            style.text_color,
+                            clip,
+                        );
+                    }
+                }
+
+                let fade = theme.style(&self.class, Status::Active).fade;
+
+                if state.offset > 0.0 {
+                    draw_fade(
+                        renderer,
+                        Rectangle {
+                            width: FADE_WIDTH.min(clip.width),
+                            ..clip
+                        },
+                        fade,
+                        Color::TRANSPARENT,
+                    );
+                }
+
+                if state.offset < state.max_offset() {
+                    let width = FADE_WIDTH.min(clip.width);
+
+                    draw_fade(
+                        renderer,
+                        Rectangle {
+                            x: clip.x + clip.width - width,
+                            width,
+                            ..clip
+                        },
+                        Color::TRANSPARENT,
+                        fade,
+                    );
+                }
+            });
+        }
+
+        if let Some(button) = state.menu_button(bounds) {
+            let status = if state.is_menu_open {
+                Status::Selected
+            } else if cursor.is_over(button) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = theme.style(&self.class, status);
+
+            if let Some(background) = style.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: button,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: Renderer::ARROW_DOWN_ICON.to_string(),
+                    bounds: button.size(),
+                    size: text_size * 0.75,
+                    line_height: text::LineHeight::default(),
+                    font: Renderer::ICON_FONT,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                    font_features: &[],
+                    shaping: text::Shaping::Basic,
+                },
+                button.center(),
+                style.text_color,
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if !state.is_menu_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let visible = Rectangle {
+            x: bounds.x + state.offset,
+            width: state.visible_width,
+            ..bounds
+        };
+
+        let mut width: f32 = 0.0;
+
+        state.hidden = self
+            .tabs
+            .iter()
+            .zip(&state.labels)
+            .zip(layout.children())
+            .enumerate()
+            .filter_map(|(index, ((tab, label), tab_layout))| {
+                let tab_bounds = tab_layout.bounds();
+
+                let is_visible = tab_bounds.x >= visible.x
+                    && tab_bounds.x + tab_bounds.width
+                        <= visible.x + visible.width;
+
+                if is_visible {
+                    return None;
+                }
+
+                width = width.max(label.min_width());
+
+                Some(Hidden {
+                    index,
+                    label: tab.label.clone(),
+                })
+            })
+            .collect();
+
+        let width = width + self.padding.horizontal();
+        let on_select = &self.on_select;
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &state.hidden,
+            &mut state.hovered_option,
+            |hidden| {
+                state.is_menu_open = false;
+
+                (on_select)(hidden.index)
+            },
+            None,
+            &self.menu_class,
+        )
+        .width(width)
+        .padding(self.padding)
+        .font(font)
+        .text_line_height(self.text_line_height)
+        .text_shaping(self.text_shaping);
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        let position = Point::new(
+            (bounds.x + bounds.width - width).max(bounds.x),
+            bounds.y,
+        );
+
+        Some(menu.overlay(position + translation, bounds.height))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TabBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tab_bar: TabBar<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(tab_bar)
+    }
+}
+
+fn draw_tab<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    bounds: Rectangle,
+    label: &Renderer::Paragraph,
+    icon: Option<&Icon<Renderer::Font>>,
+    padding: Padding,
+    text_size: Pixels,
+    line_height: text::LineHeight,
+    shaping: text::Shaping,
+    clip: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    if let Some(background) = style.background {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+    }
+
+    let mut x = bounds.x + padding.left;
+
+    if let Some(icon) = icon {
+        renderer.fill_text(
+            Text {
+                content: icon.code_point.to_string(),
+                bounds: Size::new(text_size.0, bounds.height),
+                size: text_size,
+                line_height,
+                font: icon.font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                font_features: &[],
+                shaping,
+            },
+            Point::new(x + text_size.0 / 2.0, bounds.center_y()),
+            style.text_color,
+            clip,
+        );
+
+        x += text_size.0 + GAP;
+    }
+
+    renderer.fill_paragraph(
+        label,
+        Point::new(x, bounds.center_y()),
+        style.text_color,
+        clip,
+    );
+}
+
+fn draw_fade<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    from: Color,
+    to: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            ..renderer::Quad::default()
+        },
+        gradient::Linear::new(Degrees(90.0))
+            .add_stop(0.0, from)
+            .add_stop(1.0, to),
+    );
+}
+
+/// The possible status of a [`Tab`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Tab`] can be interacted with.
+    Active,
+    /// The [`Tab`] is being hovered.
+    Hovered,
+    /// The [`Tab`] is the active one.
+    Selected,
+}
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The [`Background`] of the tab.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Border`] of the tab.
+    pub border: Border,
+    /// The [`Color`] the edges of an overflowing tab bar fade into.
+    ///
+    /// Only the [`Status::Active`] style is used.
+    pub fade: Color,
+}
+
+/// The theme catalog of a [`TabBar`].
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the overflow menu of the [`TabBar`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style;
+}
+
+/// A styling function for a [`TabBar`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of the tabs of a [`TabBar`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: None,
+        text_color: palette.background.base.text,
+        border: border::rounded(4),
+        fade: palette.background.base.color,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            background: Some(palette.background.weak.color.into()),
+            text_color: palette.background.weak.text,
+            ..active
+        },
+        Status::Selected => Style {
+            background: Some(palette.primary.weak.color.into()),
+            text_color: palette.primary.weak.text,
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Message {
+        Selected(usize),
+        Closed(usize),
+    }
+
+    type TabBar = super::TabBar<'static, Message, Theme, ()>;
+
+    fn tabs(active: usize) -> TabBar {
+        TabBar::new(["a", "b", "c"], active, Message::Selected)
+            .on_close(Message::Closed)
+    }
+
+    fn send(
+        tab_bar: TabBar,
+        event: Event,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Vec<Message>) {
+        let mut element = Element::from(tab_bar);
+        let mut tree = Tree::new(&element);
+
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = element.as_widget_mut().on_event(
+            &mut tree,
+            event,
+            Layout::new(&node),
+            cursor,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITY),
+        );
+
+        (status, messages)
+    }
+
+    fn ctrl_tab(tab_bar: TabBar, shift: bool) -> (event::Status, Vec<Message>) {
+        let mut modifiers = keyboard::Modifiers::CTRL;
+
+        if shift {
+            modifiers |= keyboard::Modifiers::SHIFT;
+        }
+
+        send(
+            tab_bar,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                location: keyboard::Location::Standard,
+                modifiers,
+                text: None,
+            }),
+            mouse::Cursor::Unavailable,
+        )
+    }
+
+    #[test]
+    fn keyboard_cycling_is_opt_in() {
+        assert_eq!(ctrl_tab(tabs(0), false), (event::Status::Ignored, vec![]));
+    }
+
+    #[test]
+    fn keyboard_cycling_wraps_around() {
+        assert_eq!(
+            ctrl_tab(tabs(2).cycle_with_keyboard(true), false),
+            (event::Status::Captured, vec![Message::Selected(0)])
+        );
+
+        assert_eq!(
+            ctrl_tab(tabs(0).cycle_with_keyboard(true), true),
+            (event::Status::Captured, vec![Message::Selected(2)])
+        );
+    }
+
+    #[test]
+    fn middle_click_closes_closable_tabs() {
+        let middle_click =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle));
+        let over_first = mouse::Cursor::Available(Point::new(1.0, 1.0));
+
+        assert_eq!(
+            send(tabs(1), middle_click.clone(), over_first),
+            (event::Status::Captured, vec![Message::Closed(0)])
+        );
+
+        let pinned = TabBar::new(
+            [Tab::new("a").closable(false), Tab::new("b")],
+            1,
+            Message::Selected,
+        )
+        .on_close(Message::Closed);
+
+        assert_eq!(
+            send(pinned, middle_click, over_first),
+            (event::Status::Ignored, vec![])
+        );
+    }
+}
@@ -0,0 +1,1532 @@
+//! Display rows of data under a fixed header of sortable, resizable columns.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Widget};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Vector,
+};
+use crate::runtime::task::{self, Task};
+use crate::runtime::Action;
+
+use std::any::Any;
+use std::cell::RefCell;
+
+/// The distance from the edge of a column where it can be resized.
+const RESIZE_HANDLE: f32 = 4.0;
+
+/// The thickness of the scrollbars of a [`Table`].
+const SCROLLBAR_WIDTH: f32 = 6.0;
+
+/// The minimum length of the thumb of a scrollbar.
+const MIN_THUMB: f32 = 20.0;
+
+/// A table of rows under a fixed header of columns.
+///
+/// The columns can be resized by dragging the edges of their headers, and
+/// sorted by clicking them. The header stays in place while the rows scroll
+/// horizontally and vertically underneath.
+///
+/// Rows have a fixed height, so a [`Table`] only builds the rows that are
+/// visible. The cells of a row are produced by a closure, given the index of
+/// the row, and can be any [`Element`].
+///
+/// ```no_run
+/// # type Table<'a, Message> = iced_widget::Table<'a, Message>;
+/// # use iced_widget::table::{self, Direction};
+/// # use iced_widget::text;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     Sorted(usize, Direction),
+///     Selected(usize),
+/// }
+///
+/// let names = ["Ferris", "Iced", "Wgpu"];
+///
+/// let table: Table<'_, Message> = Table::new(
+///     [table::Column::new("#").width(50), table::Column::new("Name")],
+///     names.len(),
+///     |i| vec![text(i).into(), text(names[i]).into()],
+/// )
+/// .on_sort(Message::Sorted)
+/// .on_select(Message::Selected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<Id>,
+    columns: Vec<Column>,
+    rows: usize,
+    row: Box<dyn Fn(usize) -> Vec<Element<'a, Message, Theme, Renderer>> + 'a>,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    sort: Option<(usize, Direction)>,
+    selected: Option<usize>,
+    on_sort: Option<Box<dyn Fn(usize, Direction) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+    visible: RefCell<Vec<Row<'a, Message, Theme, Renderer>>>,
+}
+
+struct Row<'a, Message, Theme, Renderer> {
+    index: usize,
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of the rows of a [`Table`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 32.0;
+
+    /// The default padding of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 8.0,
+        left: 8.0,
+    };
+
+    /// Creates a new [`Table`] with the given columns, the amount of rows,
+    /// and the closure producing the cells of a row given its index.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column>,
+        rows: usize,
+        row: impl Fn(usize) -> Vec<Element<'a, Message, Theme, Renderer>> + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            columns: columns.into_iter().collect(),
+            rows,
+            row: Box::new(row),
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            sort: None,
+            selected: None,
+            on_sort: None,
+            on_select: None,
+            class: Theme::default(),
+            visible: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Table`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the rows of the [`Table`].
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells and headers of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the headers of the [`Table`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the headers of the [`Table`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the column and [`Direction`] the rows of the [`Table`] are
+    /// currently sorted by, which is shown in its header.
+    ///
+    /// The [`Table`] does not sort the rows by itself; that is up to the
+    /// closure producing them.
+    pub fn sort(mut self, column: usize, direction: Direction) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    /// Sets the index of the selected row of the [`Table`], if any.
+    pub fn selected(mut self, row: Option<usize>) -> Self {
+        self.selected = row;
+        self
+    }
+
+    /// Sets the message produced when a sortable column is clicked.
+    ///
+    /// The new [`Direction`] is ascending, unless the [`Table`] was already
+    /// sorted ascending by the same column.
+    pub fn on_sort(
+        mut self,
+        on_sort: impl Fn(usize, Direction) -> Message + 'a,
+    ) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the message produced when a row is clicked.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn header_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        text::LineHeight::default().to_absolute(text_size).0
+            + self.padding.vertical()
+    }
+
+    fn content_size(&self, widths: &[f32]) -> Size {
+        Size::new(widths.iter().sum(), self.rows as f32 * self.row_height)
+    }
+
+    /// Returns the index of the row under the given position of the body.
+    fn row_at(
+        &self,
+        body: Rectangle,
+        content: Size,
+        offset: Vector,
+        position: Point,
+    ) -> Option<usize> {
+        if !body.contains(position) {
+            return None;
+        }
+
+        let x = position.x - body.x + offset.x;
+        let y = position.y - body.y + offset.y;
+
+        if x >= content.width {
+            return None;
+        }
+
+        let row = (y / self.row_height) as usize;
+
+        (row < self.rows).then_some(row)
+    }
+
+    /// Returns the column whose trailing edge is under the given position of
+    /// the header.
+    fn edge_at(
+        &self,
+        header: Rectangle,
+        widths: &[f32],
+        offset: Vector,
+        position: Point,
+    ) -> Option<usize> {
+        if !header.contains(position) {
+            return None;
+        }
+
+        let mut x = header.x - offset.x;
+
+        widths.iter().position(|width| {
+            x += width;
+
+            (position.x - x).abs() <= RESIZE_HANDLE
+        })
+    }
+
+    /// Returns the column under the given position of the header.
+    fn column_at(
+        &self,
+        header: Rectangle,
+        widths: &[f32],
+        offset: Vector,
+        position: Point,
+    ) -> Option<usize> {
+        if !header.contains(position) {
+            return None;
+        }
+
+        let mut x = header.x - offset.x;
+
+        widths.iter().position(|width| {
+            x += width;
+
+            position.x < x
+        })
+    }
+}
+
+/// A column of a [`Table`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    header: String,
+    width: f32,
+    min_width: f32,
+    is_sortable: bool,
+}
+
+impl Column {
+    /// The default width of a [`Column`].
+    pub const DEFAULT_WIDTH: f32 = 150.0;
+
+    /// The default minimum width of a [`Column`].
+    pub const DEFAULT_MIN_WIDTH: f32 = 40.0;
+
+    /// Creates a new sortable [`Column`] with the given header.
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            width: Self::DEFAULT_WIDTH,
+            min_width: Self::DEFAULT_MIN_WIDTH,
+            is_sortable: true,
+        }
+    }
+
+    /// Sets the initial width of the [`Column`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the width the [`Column`] cannot be resized below.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets whether the [`Column`] can be clicked to sort the rows by it.
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+
+    fn clamp(&self, width: f32) -> f32 {
+        width.max(self.min_width)
+    }
+}
+
+/// The direction the rows of a [`Table`] are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From the lowest value to the highest.
+    Ascending,
+    /// From the highest value to the lowest.
+    Descending,
+}
+
+impl Direction {
+    /// Returns the opposite [`Direction`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The local state of a [`Table`].
+#[derive(Debug)]
+struct State {
+    widths: Vec<f32>,
+    offset: Vector,
+    rows: Vec<(usize, Tree)>,
+    drag: Option<Drag>,
+    press: Option<Press>,
+    keyboard_modifiers: keyboard::Modifiers,
+    is_outdated: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Drag {
+    Resize {
+        column: usize,
+        origin: f32,
+        width: f32,
+    },
+    Scroll {
+        axis: Axis,
+        origin: f32,
+        offset: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Press {
+    Header(usize),
+    Row(usize),
+}
+
+impl State {
+    fn scroll(&mut self, delta: Vector, body: Rectangle, content: Size) {
+        self.offset = clamp(self.offset - delta, body, content);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            widths: self.columns.iter().map(|column| column.width).collect(),
+            offset: Vector::ZERO,
+            rows: Vec::new(),
+            drag: None,
+            press: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            is_outdated: false,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.widths.truncate(self.columns.len());
+        state.widths.extend(
+            self.columns[state.widths.len()..]
+                .iter()
+                .map(|column| column.width),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        for (width, column) in state.widths.iter_mut().zip(&self.columns) {
+            *width = column.clamp(*width);
+        }
+
+        let header_height = self.header_height(renderer);
+        let content = self.content_size(&state.widths);
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content.width, header_height + content.height),
+        );
+
+        let body = Rectangle::new(
+            Point::new(0.0, header_height),
+            Size::new(size.width, (size.height - header_height).max(0.0)),
+        );
+
+        state.offset = clamp(state.offset, body, content);
+        state.is_outdated = false;
+
+        let first = (state.offset.y / self.row_height) as usize;
+        let last = (((state.offset.y + body.height) / self.row_height).ceil()
+            as usize)
+            .min(self.rows);
+
+        let mut visible = self.visible.borrow_mut();
+        let mut trees = std::mem::take(&mut state.rows);
+
+        visible.clear();
+
+        let nodes = (first..last)
+            .map(|index| {
+                let cells = (self.row)(index);
+
+                let mut tree = match trees.iter().position(|(i, _)| *i == index)
+                {
+                    Some(position) => trees.swap_remove(position).1,
+                    None => Tree::empty(),
+                };
+
+                tree.diff_children(&cells);
+
+                let mut x = 0.0;
+
+                let cell_nodes = cells
+                    .iter()
+                    .zip(&mut tree.children)
+                    .zip(&state.widths)
+                    .map(|((cell, tree), width)| {
+                        let limits = layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(
+                                (width - self.padding.horizontal()).max(0.0),
+                                (self.row_height - self.padding.vertical())
+                                    .max(0.0),
+                            ),
+                        );
+
+                        let node =
+                            cell.as_widget().layout(tree, renderer, &limits);
+
+                        let y = (self.row_height - node.size().height) / 2.0;
+                        let node = node.move_to((x + self.padding.left, y));
+
+                        x += width;
+
+                        node
+                    })
+                    .collect();
+
+                state.rows.push((index, tree));
+                visible.push(Row { index, cells });
+
+                layout::Node::with_children(
+                    Size::new(content.width, self.row_height),
+                    cell_nodes,
+                )
+                .move_to((0.0, header_height + index as f32 * self.row_height))
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        operation.custom(state, id);
+
+        let visible = self.visible.borrow();
+
+        operation.container(id, layout.bounds(), &mut |operation| {
+            for ((row, (_, tree)), layout) in
+                visible.iter().zip(&mut state.rows).zip(layout.children())
+            {
+                for ((cell, tree), layout) in row
+                    .cells
+                    .iter()
+                    .zip(&mut tree.children)
+                    .zip(layout.children())
+                {
+                    cell.as_widget().operate(tree, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_outdated {
+            shell.invalidate_layout();
+        }
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.keyboard_modifiers = modifiers;
+
+            return event::Status::Ignored;
+        }
+
+        let bounds = layout.bounds();
+        let header_height = self.header_height(renderer);
+        let header = Rectangle {
+            height: header_height.min(bounds.height),
+            ..bounds
+        };
+        let body = body(bounds, header_height);
+        let content = self.content_size(&state.widths);
+
+        match (event.clone(), state.drag) {
+            (
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }),
+                Some(drag),
+            ) => {
+                match drag {
+                    Drag::Resize {
+                        column,
+                        origin,
+                        width,
+                    } => {
+                        state.widths[column] = self.columns[column]
+                            .clamp(width + position.x - origin);
+                    }
+                    Drag::Scroll {
+                        axis,
+                        origin,
+                        offset,
+                    } => {
+                        let (delta, track, viewport, length) = match axis {
+                            Axis::Horizontal => (
+                                position.x - origin,
+                                body.width,
+                                body.width,
+                                content.width,
+                            ),
+                            Axis::Vertical => (
+                                position.y - origin,
+                                body.height,
+                                body.height,
+                                content.height,
+                            ),
+                        };
+
+                        let thumb = thumb_length(track, viewport, length);
+                        let ratio =
+                            (length - viewport) / (track - thumb).max(1.0);
+
+                        let mut new_offset = state.offset;
+
+                        match axis {
+                            Axis::Horizontal => {
+                                new_offset.x = offset + delta * ratio;
+                            }
+                            Axis::Vertical => {
+                                new_offset.y = offset + delta * ratio;
+                            }
+                        }
+
+                        state.offset = clamp(new_offset, body, content);
+                    }
+                }
+
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. }
+                    | touch::Event::FingerLost { .. },
+                ),
+                Some(_),
+            ) => {
+                state.drag = None;
+
+                return event::Status::Captured;
+            }
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }),
+                None,
+            ) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(column) =
+                    self.edge_at(header, &state.widths, state.offset, position)
+                {
+                    state.drag = Some(Drag::Resize {
+                        column,
+                        origin: position.x,
+                        width: state.widths[column],
+                    });
+
+                    return event::Status::Captured;
+                }
+
+                let (vertical, horizontal) =
+                    scrollbars(body, content, state.offset);
+
+                for (scrollbar, axis, origin, offset) in [
+                    (vertical, Axis::Vertical, position.y, state.offset.y),
+                    (horizontal, Axis::Horizontal, position.x, state.offset.x),
+                ] {
+                    if scrollbar
+                        .is_some_and(|(track, _)| track.contains(position))
+                    {
+                        state.drag = Some(Drag::Scroll {
+                            axis,
+                            origin,
+                            offset,
+                        });
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some(column) = self.column_at(
+                    header,
+                    &state.widths,
+                    state.offset,
+                    position,
+                ) {
+                    if self.on_sort.is_some()
+                        && self.columns[column].is_sortable
+                    {
+                        state.press = Some(Press::Header(column));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            (Event::Mouse(mouse::Event::WheelScrolled { delta }), None) => {
+                if cursor.is_over(bounds) {
+                    let delta = match delta {
+                        mouse::ScrollDelta::Lines { x, y } => {
+                            // macOS automatically inverts the axes when Shift
+                            // is pressed
+                            let movement = if !cfg!(target_os = "macos")
+                                && state.keyboard_modifiers.shift()
+                            {
+                                Vector::new(y, x)
+                            } else {
+                                Vector::new(x, y)
+                            };
+
+                            movement * self.row_height
+                        }
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            Vector::new(x, y)
+                        }
+                    };
+
+                    let offset = state.offset;
+
+                    state.scroll(delta, body, content);
+
+                    if state.offset != offset {
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if state.drag.is_some() {
+            return event::Status::Ignored;
+        }
+
+        let translation = Vector::new(-state.offset.x, -state.offset.y);
+        let cell_cursor = match cursor.position_over(body) {
+            Some(_) => cursor - translation,
+            None => mouse::Cursor::Unavailable,
+        };
+        let cell_viewport = body - translation;
+
+        let mut status = event::Status::Ignored;
+
+        for ((row, (_, tree)), layout) in self
+            .visible
+            .get_mut()
+            .iter_mut()
+            .zip(&mut state.rows)
+            .zip(layout.children())
+        {
+            for ((cell, tree), layout) in row
+                .cells
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                status = status.merge(cell.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cell_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &cell_viewport,
+                ));
+            }
+        }
+
+        if status == event::Status::Captured {
+            state.press = None;
+
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.on_select.is_some() {
+                    if let Some(row) = cursor.position().and_then(|position| {
+                        self.row_at(body, content, state.offset, position)
+                    }) {
+                        state.press = Some(Press::Row(row));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(press) = state.press.take() else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                match press {
+                    Press::Header(column) => {
+                        if self.column_at(
+                            header,
+                            &state.widths,
+                            state.offset,
+                            position,
+                        ) == Some(column)
+                        {
+                            let direction = match self.sort {
+                                Some((sorted, direction))
+                                    if sorted == column =>
+                                {
+                                    direction.reverse()
+                                }
+                                _ => Direction::Ascending,
+                            };
+
+                            if let Some(on_sort) = &self.on_sort {
+                                shell.publish(on_sort(column, direction));
+                            }
+                        }
+                    }
+                    Press::Row(row) => {
+                        if self.row_at(body, content, state.offset, position)
+                            == Some(row)
+                        {
+                            if let Some(on_select) = &self.on_select {
+                                shell.publish(on_select(row));
+                            }
+                        }
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.press = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.drag {
+            Some(Drag::Resize { .. }) => {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+            Some(Drag::Scroll { .. }) => return mouse::Interaction::Grabbing,
+            None => {}
+        }
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        let bounds = layout.bounds();
+        let header_height = self.header_height(renderer);
+        let header = Rectangle {
+            height: header_height.min(bounds.height),
+            ..bounds
+        };
+        let body = body(bounds, header_height);
+        let content = self.content_size(&state.widths);
+
+        if self
+            .edge_at(header, &state.widths, state.offset, position)
+            .is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if let Some(column) =
+            self.column_at(header, &state.widths, state.offset, position)
+        {
+            return if self.on_sort.is_some() && self.columns[column].is_sortable
+            {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        let (vertical, horizontal) = scrollbars(body, content, state.offset);
+
+        if vertical
+            .into_iter()
+            .chain(horizontal)
+            .any(|(track, _)| track.contains(position))
+        {
+            return mouse::Interaction::default();
+        }
+
+        let translation = Vector::new(-state.offset.x, -state.offset.y);
+        let cell_cursor = match cursor.position_over(body) {
+            Some(_) => cursor - translation,
+            None => mouse::Cursor::Unavailable,
+        };
+        let cell_viewport = body - translation;
+
+        let interaction = self
+            .visible
+            .borrow()
+            .iter()
+            .zip(&state.rows)
+            .zip(layout.children())
+            .flat_map(|((row, (_, tree)), layout)| {
+                row.cells
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|((cell, tree), layout)| {
+                        cell.as_widget().mouse_interaction(
+                            tree,
+                            layout,
+                            cell_cursor,
+                            &cell_viewport,
+                            renderer,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && self.on_select.is_some()
+            && self.row_at(body, content, state.offset, position).is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+
+        let bounds = layout.bounds();
+        let header_height = self.header_height(renderer);
+        let header = Rectangle {
+            height: header_height.min(bounds.height),
+            ..bounds
+        };
+        let body = body(bounds, header_height);
+        let content = self.content_size(&state.widths);
+        let translation = Vector::new(-state.offset.x, -state.offset.y);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        let hovered = if state.drag.is_none() {
+            cursor.position().and_then(|position| {
+                self.row_at(body, content, state.offset, position)
+            })
+        } else {
+            None
+        };
+
+        let cell_cursor = match cursor.position_over(body) {
+            Some(_) if state.drag.is_none() => cursor - translation,
+            _ => mouse::Cursor::Unavailable,
+        };
+
+        if let Some(clip) = body.intersection(viewport) {
+            let cell_viewport = clip - translation;
+
+            renderer.with_layer(clip, |renderer| {
+                renderer.with_translation(translation, |renderer| {
+                    for ((row, (_, tree)), layout) in self
+                        .visible
+                        .borrow()
+                        .iter()
+                        .zip(&state.rows)
+                        .zip(layout.children())
+                    {
+                        let (background, text_color) =
+                            if self.selected == Some(row.index) {
+                                (style.selected_row, style.selected_text_color)
+                            } else if hovered == Some(row.index) {
+                                (style.hovered_row, style.text_color)
+                            } else {
+                                (None, style.text_color)
+                            };
+
+                        if let Some(background) = background {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: layout.bounds(),
+                                    ..renderer::Quad::default()
+                                },
+                                background,
+                            );
+                        }
+
+                        let defaults = renderer::Style {
+                            text_color: text_color
+                                .unwrap_or(defaults.text_color),
+                        };
+
+                        for ((cell, tree), layout) in row
+                            .cells
+                            .iter()
+                            .zip(&tree.children)
+                            .zip(layout.children())
+                        {
+                            cell.as_widget().draw(
+                                tree,
+                                renderer,
+                                theme,
+                                &defaults,
+                                layout,
+                                cell_cursor,
+                                &cell_viewport,
+                            );
+                        }
+                    }
+                });
+            });
+        }
+
+        if let Some(clip) = header.intersection(viewport) {
+            renderer.with_layer(clip, |renderer| {
+                self.draw_header(renderer, &style, state, header, cursor, clip);
+            });
+        }
+
+        let (vertical, horizontal) = scrollbars(body, content, state.offset);
+
+        for (_, thumb) in vertical.into_iter().chain(horizontal) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: thumb,
+                    border: border::rounded(SCROLLBAR_WIDTH / 2.0),
+                    ..renderer::Quad::default()
+                },
+                style.scrollbar,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn draw_header(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        state: &State,
+        header: Rectangle,
+        cursor: mouse::Cursor,
+        clip: Rectangle,
+    ) {
+        if let Some(background) = style.header {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let resizing = match state.drag {
+            Some(Drag::Resize { column, .. }) => Some(column),
+            _ => cursor.position().and_then(|position| {
+                self.edge_at(header, &state.widths, state.offset, position)
+            }),
+        };
+
+        let mut x = header.x - state.offset.x;
+
+        for (i, (column, width)) in
+            self.columns.iter().zip(&state.widths).enumerate()
+        {
+            let indicator = match self.sort {
+                Some((sorted, Direction::Ascending)) if sorted == i => {
+                    Some('▲')
+                }
+                Some((sorted, Direction::Descending)) if sorted == i => {
+                    Some('▼')
+                }
+                _ => None,
+            };
+
+            let available = (width - self.padding.horizontal()).max(0.0);
+
+            let label = if let Some(indicator) = indicator {
+                renderer.fill_text(
+                    text::Text {
+                        content: indicator.to_string(),
+                        bounds: Size::new(available, header.height),
+                        size: text_size * 0.7,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Center,
+                        max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                        font_features: &[],
+                        shaping: text::Shaping::Advanced,
+                    },
+                    Point::new(
+                        x + width - self.padding.right,
+                        header.center_y(),
+                    ),
+                    style.header_text_color,
+                    clip,
+                );
+
+                (available - text_size.0).max(0.0)
+            } else {
+                available
+            };
+
+            renderer.fill_text(
+                text::Text {
+                    content: column.header.clone(),
+                    bounds: Size::new(label, header.height),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                    font_features: &[],
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(x + self.padding.left, header.center_y()),
+                style.header_text_color,
+                Rectangle {
+                    x: x + self.padding.left,
+                    width: label,
+                    ..header
+                }
+                .intersection(&clip)
+                .unwrap_or(Rectangle::with_size(Size::ZERO)),
+            );
+
+            x += width;
+
+            let thickness = if resizing == Some(i) { 2.0 } else { 1.0 };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - thickness,
+                        y: header.y,
+                        width: thickness,
+                        height: header.height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.divider,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: header.y + header.height - 1.0,
+                    height: 1.0,
+                    ..header
+                },
+                ..renderer::Quad::default()
+            },
+            style.divider,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// Returns the bounds of the rows of a table with the given bounds.
+fn body(bounds: Rectangle, header_height: f32) -> Rectangle {
+    Rectangle {
+        y: bounds.y + header_height,
+        height: (bounds.height - header_height).max(0.0),
+        ..bounds
+    }
+}
+
+/// Clamps the given scroll offset to the contents of a table.
+fn clamp(offset: Vector, body: Rectangle, content: Size) -> Vector {
+    Vector::new(
+        offset.x.clamp(0.0, (content.width - body.width).max(0.0)),
+        offset.y.clamp(0.0, (content.height - body.height).max(0.0)),
+    )
+}
+
+fn thumb_length(track: f32, viewport: f32, content: f32) -> f32 {
+    (track * viewport / content).max(MIN_THUMB).min(track)
+}
+
+/// Returns the tracks and thumbs of the vertical and horizontal scrollbars
+/// of a table, if its contents overflow.
+#[allow(clippy::type_complexity)]
+fn scrollbars(
+    body: Rectangle,
+    content: Size,
+    offset: Vector,
+) -> (
+    Option<(Rectangle, Rectangle)>,
+    Option<(Rectangle, Rectangle)>,
+) {
+    let vertical = (content.height > body.height).then(|| {
+        let track = Rectangle {
+            x: body.x + body.width - SCROLLBAR_WIDTH,
+            width: SCROLLBAR_WIDTH,
+            ..body
+        };
+
+        let length = thumb_length(track.height, body.height, content.height);
+        let progress = offset.y / (content.height - body.height);
+
+        let thumb = Rectangle {
+            y: track.y + progress * (track.height - length),
+            height: length,
+            ..track
+        };
+
+        (track, thumb)
+    });
+
+    let horizontal = (content.width > body.width).then(|| {
+        let track = Rectangle {
+            y: body.y + body.height - SCROLLBAR_WIDTH,
+            height: SCROLLBAR_WIDTH,
+            ..body
+        };
+
+        let length = thumb_length(track.width, body.width, content.width);
+        let progress = offset.x / (content.width - body.width);
+
+        let thumb = Rectangle {
+            x: track.x + progress * (track.width - length),
+            width: length,
+            ..track
+        };
+
+        (track, thumb)
+    });
+
+    (vertical, horizontal)
+}
+
+/// The identifier of a [`Table`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+impl From<widget::Id> for Id {
+    fn from(id: widget::Id) -> Self {
+        Self(id)
+    }
+}
+
+/// Produces a [`Task`] that queries the current widths of the columns of
+/// the [`Table`] with the given [`Id`]; so they can be persisted.
+pub fn column_widths(id: Id) -> Task<Option<Vec<f32>>> {
+    struct ColumnWidths {
+        target: widget::Id,
+        widths: Option<Vec<f32>>,
+    }
+
+    impl Operation<Option<Vec<f32>>> for ColumnWidths {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Option<Vec<f32>>>,
+            ),
+        ) {
+            if self.widths.is_none() {
+                operate_on_children(self);
+            }
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_ref::<State>() {
+                self.widths = Some(state.widths.clone());
+            }
+        }
+
+        fn finish(&self) -> operation::Outcome<Option<Vec<f32>>> {
+            operation::Outcome::Some(self.widths.clone())
+        }
+    }
+
+    task::widget(ColumnWidths {
+        target: id.0,
+        widths: None,
+    })
+}
+
+/// Produces a [`Task`] that sets the widths of the columns of the [`Table`]
+/// with the given [`Id`]; for instance, to restore the widths returned by
+/// [`column_widths`].
+///
+/// Widths for columns that do not exist are ignored, and the minimum width
+/// of each column is still honored.
+pub fn set_column_widths<T>(id: Id, widths: Vec<f32>) -> Task<T> {
+    struct SetColumnWidths {
+        target: widget::Id,
+        widths: Vec<f32>,
+    }
+
+    impl<T> Operation<T> for SetColumnWidths {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<State>() {
+                for (width, new) in state.widths.iter_mut().zip(&self.widths) {
+                    *width = *new;
+                }
+
+                state.is_outdated = true;
+            }
+        }
+    }
+
+    task::effect(Action::widget(SetColumnWidths {
+        target: id.0,
+        widths,
+    }))
+}
+
+/// The appearance of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`Table`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`Table`].
+    pub border: Border,
+    /// The [`Background`] of the header.
+    pub header: Option<Background>,
+    /// The text [`Color`] of the header.
+    pub header_text_color: Color,
+    /// The text [`Color`] of the cells, if different from the default one.
+    pub text_color: Option<Color>,
+    /// The [`Background`] of the row under the cursor.
+    pub hovered_row: Option<Background>,
+    /// The [`Background`] of the selected row.
+    pub selected_row: Option<Background>,
+    /// The text [`Color`] of the cells of the selected row.
+    pub selected_text_color: Option<Color>,
+    /// The [`Color`] of the lines between the columns of the header.
+    pub divider: Color,
+    /// The [`Color`] of the scrollbars.
+    pub scrollbar: Color,
+}
+
+/// The theme catalog of a [`Table`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Table`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Table`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.base.color.into()),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        header: Some(palette.background.weak.color.into()),
+        header_text_color: palette.background.weak.text,
+        text_color: None,
+        hovered_row: Some(palette.background.weak.color.into()),
+        selected_row: Some(palette.primary.weak.color.into()),
+        selected_text_color: Some(palette.primary.weak.text),
+        divider: palette.background.strong.color,
+        scrollbar: palette.background.strong.color,
+    }
+}
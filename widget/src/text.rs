@@ -3,6 +3,7 @@ mod rich;
 
 pub use crate::core::text::{Fragment, Highlighter, IntoFragment, Span};
 pub use crate::core::widget::text::*;
+pub use crate::runtime::text::invalidate_all;
 pub use rich::Rich;
 
 /// A paragraph.
@@ -36,6 +36,7 @@ pub struct Rich<
     font: Option<Renderer::Font>,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    max_space_factor: f32,
     class: Theme::Class<'a>,
     on_link: Option<Box<dyn Fn(Link) -> Message + 'a>>,
 }
@@ -58,6 +59,7 @@ where
             font: None,
             align_x: alignment::Horizontal::Left,
             align_y: alignment::Vertical::Top,
+            max_space_factor: core::text::DEFAULT_MAX_SPACE_FACTOR,
             class: Theme::default(),
             on_link: None,
         }
@@ -127,6 +129,16 @@ where
         self
     }
 
+    /// Sets the maximum factor the spaces of the [`Rich`] text can be
+    /// stretched by when justified.
+    ///
+    /// A paragraph with lines that would need to be stretched further is
+    /// aligned to the left instead.
+    pub fn max_space_factor(mut self, max_space_factor: f32) -> Self {
+        self.max_space_factor = max_space_factor;
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -251,6 +263,7 @@ where
             self.font,
             self.align_x,
             self.align_y,
+            self.max_space_factor,
         )
     }
 
@@ -384,12 +397,15 @@ fn layout<Link, Renderer>(
     font: Option<Renderer::Font>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    max_space_factor: f32,
 ) -> layout::Node
 where
     Link: Clone,
     Renderer: core::text::Renderer,
 {
-    layout::sized(limits, width, height, |limits| {
+    let horizontal_alignment = horizontal_alignment.resolve();
+
+    let node = layout::sized(limits, width, height, |limits| {
         let bounds = limits.max();
 
         let size = size.unwrap_or_else(|| renderer.default_size());
@@ -403,6 +419,8 @@ where
             font,
             horizontal_alignment,
             vertical_alignment,
+            max_space_factor,
+            font_features: &[],
             shaping: Shaping::Advanced,
         };
 
@@ -419,6 +437,8 @@ where
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                max_space_factor,
+                font_features: &[],
                 shaping: Shaping::Advanced,
             }) {
                 core::text::Difference::None => {}
@@ -433,7 +453,11 @@ where
         }
 
         state.paragraph.min_bounds()
-    })
+    });
+
+    let baseline = text::baseline(&state.paragraph, node.size().height);
+
+    node.with_baseline(baseline)
 }
 
 impl<'a, Message, Link, Theme, Renderer>
@@ -1,6 +1,7 @@
 //! Display a multi-line text input for text editing.
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::font;
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
@@ -11,6 +12,7 @@ use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::{self, LineHeight};
 use crate::core::widget::operation;
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
     Background, Border, Color, Element, Length, Padding, Pixels, Rectangle,
     Shell, Size, Theme, Vector,
@@ -38,6 +40,7 @@ pub struct TextEditor<
 {
     content: &'a Content<Renderer>,
     font: Option<Renderer::Font>,
+    font_features: &'static [font::Feature],
     text_size: Option<Pixels>,
     line_height: LineHeight,
     width: Length,
@@ -45,6 +48,8 @@ pub struct TextEditor<
     padding: Padding,
     class: Theme::Class<'a>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_focus: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_blur: Option<Box<dyn Fn() -> Message + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -63,6 +68,7 @@ where
         Self {
             content,
             font: None,
+            font_features: &[],
             text_size: None,
             line_height: LineHeight::default(),
             width: Length::Fill,
@@ -70,6 +76,8 @@ where
             padding: Padding::new(5.0),
             class: Theme::default(),
             on_edit: None,
+            on_focus: None,
+            on_blur: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -103,6 +111,31 @@ where
         self
     }
 
+    /// Sets the message that should be produced when the [`TextEditor`] gains
+    /// keyboard focus.
+    ///
+    /// The message is produced after the [`on_blur`] message of any widget
+    /// that lost focus at the same time.
+    ///
+    /// [`on_blur`]: Self::on_blur
+    pub fn on_focus(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_focus = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextEditor`] loses
+    /// keyboard focus.
+    pub fn on_blur(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_blur = Some(Box::new(move || message.clone()));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextEditor`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -111,6 +144,19 @@ where
         self
     }
 
+    /// Sets the OpenType [`font::Feature`]s of the [`TextEditor`].
+    ///
+    /// They replace the default features of its [`Font`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font_features(
+        mut self,
+        font_features: &'static [font::Feature],
+    ) -> Self {
+        self.font_features = font_features;
+        self
+    }
+
     /// Sets the text size of the [`TextEditor`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.text_size = Some(size.into());
@@ -145,6 +191,7 @@ where
         TextEditor {
             content: self.content,
             font: self.font,
+            font_features: self.font_features,
             text_size: self.text_size,
             line_height: self.line_height,
             width: self.width,
@@ -152,6 +199,8 @@ where
             padding: self.padding,
             class: self.class,
             on_edit: self.on_edit,
+            on_focus: self.on_focus,
+            on_blur: self.on_blur,
             highlighter_settings: settings,
             highlighter_format: to_format,
         }
@@ -324,6 +373,7 @@ where
 #[derive(Debug)]
 pub struct State<Highlighter: text::Highlighter> {
     is_focused: bool,
+    reported_focus: Reported,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     partial_scroll: f32,
@@ -339,6 +389,57 @@ impl<Highlighter: text::Highlighter> State<Highlighter> {
     }
 }
 
+/// The focus changes of a [`TextEditor`] reported to the application.
+///
+/// Gaining focus is only reported on the event after it is noticed; so the
+/// blur of the widget that lost focus is always reported first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reported {
+    Blurred,
+    Pending,
+    Focused,
+}
+
+impl<'a, Highlighter, Message, Theme, Renderer>
+    TextEditor<'a, Highlighter, Message, Theme, Renderer>
+where
+    Highlighter: text::Highlighter,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn report_focus(
+        &self,
+        state: &mut State<Highlighter>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match (state.is_focused, state.reported_focus) {
+            (true, Reported::Blurred) => {
+                state.reported_focus = Reported::Pending;
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            (true, Reported::Pending) => {
+                state.reported_focus = Reported::Focused;
+
+                if let Some(on_focus) = &self.on_focus {
+                    shell.publish(on_focus());
+                }
+            }
+            (false, Reported::Focused) => {
+                state.reported_focus = Reported::Blurred;
+
+                if let Some(on_blur) = &self.on_blur {
+                    shell.publish(on_blur());
+                }
+            }
+            (false, Reported::Pending) => {
+                state.reported_focus = Reported::Blurred;
+            }
+            (true, Reported::Focused) | (false, Reported::Blurred) => {}
+        }
+    }
+}
+
 impl<Highlighter: text::Highlighter> operation::Focusable
     for State<Highlighter>
 {
@@ -369,6 +470,7 @@ where
     fn state(&self) -> widget::tree::State {
         widget::tree::State::new(State {
             is_focused: false,
+            reported_focus: Reported::Blurred,
             last_click: None,
             drag_click: None,
             partial_scroll: 0.0,
@@ -417,15 +519,17 @@ where
         internal.editor.update(
             limits.shrink(self.padding).max(),
             self.font.unwrap_or_else(|| renderer.default_font()),
+            self.font_features,
             self.text_size.unwrap_or_else(|| renderer.default_size()),
             self.line_height,
             state.highlighter.borrow_mut().deref_mut(),
         );
 
         match self.height {
-            Length::Fill | Length::FillPortion(_) | Length::Fixed(_) => {
-                layout::Node::new(limits.max())
-            }
+            Length::Fill
+            | Length::FillPortion(_)
+            | Length::FillLimited { .. }
+            | Length::Fixed(_) => layout::Node::new(limits.max()),
             Length::Shrink => {
                 let min_bounds = internal.editor.min_bounds();
 
@@ -450,6 +554,11 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        self.report_focus(
+            tree.state.downcast_mut::<State<Highlighter>>(),
+            shell,
+        );
+
         let Some(on_edit) = self.on_edit.as_ref() else {
             return event::Status::Ignored;
         };
@@ -661,13 +770,13 @@ where
     fn operate(
         &self,
         tree: &mut widget::Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn widget::Operation<()>,
     ) {
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
-        operation.focusable(state, None);
+        operation.focusable(state, None, layout.bounds());
     }
 }
 
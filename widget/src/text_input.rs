@@ -2,18 +2,24 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
 
+pub use crate::core::keyboard::InputMode;
+pub use crate::core::widget::operation::text_input::Selection;
 pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::{History, Step};
 
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::font;
+use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
@@ -34,6 +40,8 @@ use crate::core::{
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 
+use std::ops::Range;
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -69,7 +77,10 @@ pub struct TextInput<
     placeholder: String,
     value: Value,
     is_secure: bool,
+    is_invalid: bool,
+    input_mode: InputMode,
     font: Option<Renderer::Font>,
+    font_features: &'static [font::Feature],
     width: Length,
     padding: Padding,
     size: Option<Pixels>,
@@ -77,13 +88,25 @@ pub struct TextInput<
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    on_focus: Option<Message>,
+    on_blur: Option<Message>,
+    on_selection: Option<Box<dyn Fn(Range<usize>) -> Message + 'a>>,
+    filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    mask: Option<Box<dyn Fn(&str) -> Option<String> + 'a>>,
+    undo_depth: usize,
     icon: Option<Icon<Renderer::Font>>,
+    reveal_icon: Option<Icon<Renderer::Font>>,
+    reveal: Reveal,
+    allow_copy: bool,
     class: Theme::Class<'a>,
 }
 
 /// The default [`Padding`] of a [`TextInput`].
 pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
 
+/// The default amount of edits that can be undone in a [`TextInput`].
+pub const DEFAULT_UNDO_DEPTH: usize = 100;
+
 impl<'a, Message, Theme, Renderer> TextInput<'a, Message, Theme, Renderer>
 where
     Message: Clone,
@@ -98,7 +121,10 @@ where
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            is_invalid: false,
+            input_mode: InputMode::Text,
             font: None,
+            font_features: &[],
             width: Length::Fill,
             padding: DEFAULT_PADDING,
             size: None,
@@ -106,7 +132,16 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            on_focus: None,
+            on_blur: None,
+            on_selection: None,
+            filter: None,
+            mask: None,
+            undo_depth: DEFAULT_UNDO_DEPTH,
             icon: None,
+            reveal_icon: None,
+            reveal: Reveal::default(),
+            allow_copy: false,
             class: Theme::default(),
         }
     }
@@ -123,6 +158,85 @@ where
         self
     }
 
+    /// Sets the [`Icon`] that reveals the contents of a secure [`TextInput`]
+    /// when clicked.
+    ///
+    /// The [`Icon`] is only displayed while the [`TextInput`] is
+    /// [`secure`](Self::secure); clicking it does not focus the
+    /// [`TextInput`].
+    pub fn reveal_icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.reveal_icon = Some(icon);
+        self
+    }
+
+    /// Sets how the [`reveal_icon`](Self::reveal_icon) of the [`TextInput`]
+    /// reveals its contents.
+    ///
+    /// By default, the contents are only revealed while the icon is pressed.
+    pub fn reveal(mut self, reveal: Reveal) -> Self {
+        self.reveal = reveal;
+        self
+    }
+
+    /// Sets whether the contents of a secure [`TextInput`] can be copied or
+    /// cut into the clipboard.
+    ///
+    /// By default, they cannot.
+    pub fn allow_copy(mut self, allow_copy: bool) -> Self {
+        self.allow_copy = allow_copy;
+        self
+    }
+
+    /// Marks the [`TextInput`] as invalid; styling it with
+    /// [`Status::Invalid`] unless it is disabled.
+    pub fn invalid(mut self, is_invalid: bool) -> Self {
+        self.is_invalid = is_invalid;
+        self
+    }
+
+    /// Sets the [`InputMode`] of the [`TextInput`].
+    ///
+    /// Characters that do not fit the [`InputMode`] are filtered out while
+    /// typing or pasting. The platform may also use it as a hint to show a
+    /// fitting soft keyboard.
+    pub fn input_mode(mut self, input_mode: InputMode) -> Self {
+        self.input_mode = input_mode;
+        self
+    }
+
+    /// Sets the closure that decides which characters can be typed or pasted
+    /// into the [`TextInput`].
+    ///
+    /// Rejected characters are dropped before the value changes; so they
+    /// never reach [`on_input`]. Pasted text keeps its accepted characters.
+    ///
+    /// [`on_input`]: Self::on_input
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the closure that validates, and may rewrite, every edit of the
+    /// [`TextInput`].
+    ///
+    /// The closure receives the contents of the [`TextInput`] after an edit
+    /// and returns the contents that should be produced instead, or `None`
+    /// to reject the edit and keep the previous value.
+    pub fn mask(mut self, mask: impl Fn(&str) -> Option<String> + 'a) -> Self {
+        self.mask = Some(Box::new(mask));
+        self
+    }
+
+    /// Sets the maximum amount of edits that can be undone in the
+    /// [`TextInput`].
+    ///
+    /// By default, it is set to [`DEFAULT_UNDO_DEPTH`]. A depth of `0`
+    /// disables undo.
+    pub fn undo_depth(mut self, depth: usize) -> Self {
+        self.undo_depth = depth;
+        self
+    }
+
     /// Sets the message that should be produced when some text is typed into
     /// the [`TextInput`].
     ///
@@ -142,6 +256,38 @@ where
         self
     }
 
+    /// Sets the message that should be produced when the [`TextInput`] gains
+    /// keyboard focus.
+    ///
+    /// The message is produced after the [`on_blur`] message of any widget
+    /// that lost focus at the same time.
+    ///
+    /// [`on_blur`]: Self::on_blur
+    pub fn on_focus(mut self, message: Message) -> Self {
+        self.on_focus = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`] loses
+    /// keyboard focus.
+    pub fn on_blur(mut self, message: Message) -> Self {
+        self.on_blur = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the selection of the
+    /// [`TextInput`] changes.
+    ///
+    /// The selection is given as a range of `char`s, which is empty—at the
+    /// position of the cursor—when nothing is selected.
+    pub fn on_selection(
+        mut self,
+        on_selection: impl Fn(Range<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_selection = Some(Box::new(on_selection));
+        self
+    }
+
     /// Sets the message that should be produced when some text is pasted into
     /// the [`TextInput`].
     pub fn on_paste(
@@ -160,6 +306,19 @@ where
         self
     }
 
+    /// Sets the OpenType [`font::Feature`]s of the [`TextInput`].
+    ///
+    /// They replace the default features of its [`Font`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font_features(
+        mut self,
+        font_features: &'static [font::Feature],
+    ) -> Self {
+        self.font_features = font_features;
+        self
+    }
+
     /// Sets the [`Icon`] of the [`TextInput`].
     pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
         self.icon = Some(icon);
@@ -210,6 +369,28 @@ where
         self
     }
 
+    /// Returns the [`reveal_icon`](Self::reveal_icon) of the [`TextInput`],
+    /// if it is displayed.
+    fn reveal_icon(&self) -> Option<&Icon<Renderer::Font>> {
+        self.reveal_icon.as_ref().filter(|_| self.is_secure)
+    }
+
+    /// Returns the bounds of the [`reveal_icon`](Self::reveal_icon) of the
+    /// [`TextInput`], if it is displayed.
+    fn reveal_icon_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.reveal_icon()?;
+
+        let index = 1 + usize::from(self.icon.is_some());
+
+        layout.children().nth(index).map(|layout| layout.bounds())
+    }
+
+    /// Returns whether the contents of the [`TextInput`] are displayed
+    /// masked.
+    fn is_masked(&self, state: &State<Renderer::Paragraph>) -> bool {
+        self.is_secure && !state.is_revealed
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -239,23 +420,47 @@ where
             size: text_size,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
+            max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+            font_features: self.font_features,
             shaping: text::Shaping::Advanced,
         };
 
         state.placeholder.update(placeholder_text);
 
-        let secure_value = self.is_secure.then(|| value.secure());
-        let value = secure_value.as_ref().unwrap_or(value);
+        let value = state.compose(value);
+        let secure_value = self.is_masked(state).then(|| value.secure());
+        let value = secure_value.as_ref().unwrap_or(&value);
 
         state.value.update(Text {
             content: &value.to_string(),
             ..placeholder_text
         });
 
-        if let Some(icon) = &self.icon {
+        let baseline =
+            widget::text::baseline(state.value.raw(), text_bounds.height)
+                .or_else(|| {
+                    widget::text::baseline(
+                        state.placeholder.raw(),
+                        text_bounds.height,
+                    )
+                })
+                .map(|baseline| padding.top + baseline);
+
+        let mut text_x = padding.left;
+        let mut text_width = text_bounds.width;
+        let mut icons = Vec::new();
+
+        for (icon, paragraph) in [
+            (self.icon.as_ref(), &mut state.icon),
+            (self.reveal_icon(), &mut state.reveal_icon),
+        ] {
+            let Some(icon) = icon else {
+                continue;
+            };
+
             let mut content = [0; 4];
 
-            let icon_text = Text {
+            paragraph.update(Text {
                 line_height: self.line_height,
                 content: icon.code_point.encode_utf8(&mut content) as &_,
                 font: icon.font,
@@ -263,49 +468,40 @@ where
                 bounds: Size::new(f32::INFINITY, text_bounds.height),
                 horizontal_alignment: alignment::Horizontal::Center,
                 vertical_alignment: alignment::Vertical::Center,
+                max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+                font_features: &[],
                 shaping: text::Shaping::Advanced,
-            };
+            });
 
-            state.icon.update(icon_text);
-
-            let icon_width = state.icon.min_width();
-
-            let (text_position, icon_position) = match icon.side {
-                Side::Left => (
-                    Point::new(
-                        padding.left + icon_width + icon.spacing,
-                        padding.top,
-                    ),
-                    Point::new(padding.left, padding.top),
-                ),
-                Side::Right => (
-                    Point::new(padding.left, padding.top),
-                    Point::new(
-                        padding.left + text_bounds.width - icon_width,
-                        padding.top,
-                    ),
-                ),
+            let icon_width = paragraph.min_width();
+
+            let icon_x = match icon.side {
+                Side::Left => {
+                    let icon_x = text_x;
+                    text_x += icon_width + icon.spacing;
+
+                    icon_x
+                }
+                Side::Right => text_x + text_width - icon_width,
             };
 
-            let text_node = layout::Node::new(
-                text_bounds - Size::new(icon_width + icon.spacing, 0.0),
-            )
-            .move_to(text_position);
+            text_width -= icon_width + icon.spacing;
 
-            let icon_node =
+            icons.push(
                 layout::Node::new(Size::new(icon_width, text_bounds.height))
-                    .move_to(icon_position);
+                    .move_to(Point::new(icon_x, padding.top)),
+            );
+        }
 
-            layout::Node::with_children(
-                text_bounds.expand(padding),
-                vec![text_node, icon_node],
-            )
-        } else {
-            let text = layout::Node::new(text_bounds)
-                .move_to(Point::new(padding.left, padding.top));
+        let text = layout::Node::new(Size::new(text_width, text_bounds.height))
+            .move_to(Point::new(text_x, padding.top));
 
-            layout::Node::with_children(text_bounds.expand(padding), vec![text])
-        }
+        let node = layout::Node::with_children(
+            text_bounds.expand(padding),
+            std::iter::once(text).chain(icons).collect(),
+        );
+
+        node.with_baseline(baseline)
     }
 
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
@@ -326,7 +522,7 @@ where
         let value = value.unwrap_or(&self.value);
         let is_disabled = self.on_input.is_none();
 
-        let secure_value = self.is_secure.then(|| value.secure());
+        let secure_value = self.is_masked(state).then(|| value.secure());
         let value = secure_value.as_ref().unwrap_or(value);
 
         let bounds = layout.bounds();
@@ -338,6 +534,8 @@ where
 
         let status = if is_disabled {
             Status::Disabled
+        } else if self.is_invalid {
+            Status::Invalid
         } else if state.is_focused() {
             Status::Focused
         } else if is_mouse_over {
@@ -357,18 +555,23 @@ where
             style.background,
         );
 
-        if self.icon.is_some() {
-            let icon_layout = children_layout.next().unwrap();
-
-            renderer.fill_paragraph(
-                state.icon.raw(),
-                icon_layout.bounds().center(),
-                style.icon,
-                *viewport,
-            );
+        for (icon, paragraph) in [
+            (self.icon.as_ref(), &state.icon),
+            (self.reveal_icon(), &state.reveal_icon),
+        ] {
+            if icon.is_some() {
+                let icon_layout = children_layout.next().unwrap();
+
+                renderer.fill_paragraph(
+                    paragraph.raw(),
+                    icon_layout.bounds().center(),
+                    style.icon,
+                    *viewport,
+                );
+            }
         }
 
-        let text = value.to_string();
+        let text = state.compose(value).to_string();
 
         let (cursor, offset, is_selecting) = if let Some(focus) = state
             .is_focused
@@ -518,6 +721,7 @@ where
             state.is_focused = None;
             state.is_pasting = None;
             state.is_dragging = false;
+            state.preedit = None;
         }
     }
 
@@ -540,14 +744,25 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<()>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.focusable(
+            state,
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+        );
+        operation.text_input(
+            &mut Operable {
+                state,
+                value: &self.value,
+                is_secure: self.is_secure,
+            },
+            self.id.as_ref().map(|id| &id.0),
+        );
     }
 
     fn on_event(
@@ -561,6 +776,28 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        report_focus(
+            state::<Renderer>(tree),
+            self.on_focus.as_ref(),
+            self.on_blur.as_ref(),
+            shell,
+        );
+
+        if let Some(on_selection) = &self.on_selection {
+            report_selection(
+                state::<Renderer>(tree),
+                &self.value,
+                on_selection,
+                shell,
+            );
+        }
+
+        if self.on_input.is_some()
+            && state::<Renderer>(tree).is_focused.is_some()
+        {
+            shell.request_input_mode(self.input_mode);
+        }
+
         let update_cache = |state, value| {
             replace_paragraph(
                 renderer,
@@ -568,16 +805,50 @@ where
                 layout,
                 value,
                 self.font,
+                self.font_features,
                 self.size,
                 self.line_height,
             );
         };
 
+        {
+            let state = state::<Renderer>(tree);
+
+            state.history.sync(&self.value, self.undo_depth);
+
+            if let Some(pending) = state.history.take_pending() {
+                if let Some(on_input) = &self.on_input {
+                    if let Some(contents) =
+                        step(&mut self.value, state, pending)
+                    {
+                        shell.publish((on_input)(contents));
+                    }
+
+                    update_cache(state, &self.value);
+                }
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let state = state::<Renderer>(tree);
 
+                // Revealing the contents leaves the focus untouched
+                if self
+                    .reveal_icon_bounds(layout)
+                    .is_some_and(|bounds| cursor.is_over(bounds))
+                {
+                    state.is_revealed = match self.reveal {
+                        Reveal::Hold => true,
+                        Reveal::Toggle => !state.is_revealed,
+                    };
+
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+
                 let click_position = if self.on_input.is_some() {
                     cursor.position_over(layout.bounds())
                 } else {
@@ -598,6 +869,10 @@ where
                     None
                 };
 
+                if state.is_focused.is_none() {
+                    state.preedit = None;
+                }
+
                 if let Some(cursor_position) = click_position {
                     let text_layout = layout.children().next().unwrap();
                     let target = cursor_position.x - text_layout.bounds().x;
@@ -608,7 +883,7 @@ where
                     match click.kind() {
                         click::Kind::Single => {
                             let position = if target > 0.0 {
-                                let value = if self.is_secure {
+                                let value = if self.is_masked(state) {
                                     self.value.secure()
                                 } else {
                                     self.value.clone()
@@ -669,7 +944,15 @@ where
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
-                state::<Renderer>(tree).is_dragging = false;
+                let state = state::<Renderer>(tree);
+
+                state.is_dragging = false;
+
+                if self.reveal == Reveal::Hold && state.is_revealed {
+                    state.is_revealed = false;
+
+                    shell.invalidate_layout();
+                }
             }
             Event::Mouse(mouse::Event::CursorMoved { position })
             | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
@@ -679,7 +962,7 @@ where
                     let text_layout = layout.children().next().unwrap();
                     let target = position.x - text_layout.bounds().x;
 
-                    let value = if self.is_secure {
+                    let value = if self.is_masked(state) {
                         self.value.secure()
                     } else {
                         self.value.clone()
@@ -716,7 +999,7 @@ where
                     match key.as_ref() {
                         keyboard::Key::Character("c")
                             if state.keyboard_modifiers.command()
-                                && !self.is_secure =>
+                                && (!self.is_secure || self.allow_copy) =>
                         {
                             if let Some((start, end)) =
                                 state.cursor.selection(&self.value)
@@ -731,7 +1014,7 @@ where
                         }
                         keyboard::Key::Character("x")
                             if state.keyboard_modifiers.command()
-                                && !self.is_secure =>
+                                && (!self.is_secure || self.allow_copy) =>
                         {
                             if let Some((start, end)) =
                                 state.cursor.selection(&self.value)
@@ -742,12 +1025,16 @@ where
                                 );
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                self.mask.as_deref(),
+                                false,
+                                |editor| editor.delete(),
+                            ) {
+                                shell.publish((on_input)(contents));
+                            }
 
                             update_cache(state, &self.value);
 
@@ -764,24 +1051,36 @@ where
                                         .read(clipboard::Kind::Standard)
                                         .unwrap_or_default()
                                         .chars()
-                                        .filter(|c| !c.is_control())
+                                        .filter_map(|c| {
+                                            accept(
+                                                c,
+                                                self.input_mode,
+                                                self.filter.as_deref(),
+                                            )
+                                        })
                                         .collect();
 
                                     Value::new(&content)
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-
-                            editor.paste(content.clone());
-
-                            let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
-                            } else {
-                                (on_input)(editor.contents())
-                            };
-                            shell.publish(message);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                self.mask.as_deref(),
+                                false,
+                                |editor| editor.paste(content.clone()),
+                            ) {
+                                let message =
+                                    if let Some(paste) = &self.on_paste {
+                                        (paste)(contents)
+                                    } else {
+                                        (on_input)(contents)
+                                    };
+
+                                shell.publish(message);
+                            }
 
                             state.is_pasting = Some(content);
 
@@ -796,22 +1095,47 @@ where
 
                             return event::Status::Captured;
                         }
+                        keyboard::Key::Character("z" | "Z" | "y" | "Y")
+                            if state.keyboard_modifiers.command() =>
+                        {
+                            let is_redo = state.keyboard_modifiers.shift()
+                                || matches!(
+                                    key.as_ref(),
+                                    keyboard::Key::Character("y" | "Y")
+                                );
+
+                            let step_taken =
+                                if is_redo { Step::Redo } else { Step::Undo };
+
+                            if let Some(contents) =
+                                step(&mut self.value, state, step_taken)
+                            {
+                                shell.publish((on_input)(contents));
+                            }
+
+                            update_cache(state, &self.value);
+
+                            return event::Status::Captured;
+                        }
                         _ => {}
                     }
 
                     if let Some(text) = text {
                         state.is_pasting = None;
 
-                        if let Some(c) =
-                            text.chars().next().filter(|c| !c.is_control())
-                        {
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-
-                            editor.insert(c);
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                        if let Some(c) = text.chars().next().and_then(|c| {
+                            accept(c, self.input_mode, self.filter.as_deref())
+                        }) {
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                self.mask.as_deref(),
+                                true,
+                                |editor| editor.insert(c),
+                            ) {
+                                shell.publish((on_input)(contents));
+                            }
 
                             focus.updated_at = Instant::now();
 
@@ -842,12 +1166,16 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.backspace();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                self.mask.as_deref(),
+                                false,
+                                |editor| editor.backspace(),
+                            ) {
+                                shell.publish((on_input)(contents));
+                            }
 
                             update_cache(state, &self.value);
                         }
@@ -869,12 +1197,16 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                self.mask.as_deref(),
+                                false,
+                                |editor| editor.delete(),
+                            ) {
+                                shell.publish((on_input)(contents));
+                            }
 
                             update_cache(state, &self.value);
                         }
@@ -960,6 +1292,7 @@ where
                             state.is_focused = None;
                             state.is_dragging = false;
                             state.is_pasting = None;
+                            state.preedit = None;
 
                             state.keyboard_modifiers =
                                 keyboard::Modifiers::default();
@@ -977,6 +1310,61 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::InputMethod(event) => {
+                let state = state::<Renderer>(tree);
+
+                if let Some(focus) = &mut state.is_focused {
+                    let Some(on_input) = &self.on_input else {
+                        return event::Status::Ignored;
+                    };
+
+                    match event {
+                        input_method::Event::Opened => {}
+                        input_method::Event::Preedit(text, _) => {
+                            state.preedit =
+                                Some(text).filter(|text| !text.is_empty());
+                        }
+                        input_method::Event::Commit(text) => {
+                            state.preedit = None;
+
+                            let content: String = text
+                                .chars()
+                                .filter_map(|c| {
+                                    accept(
+                                        c,
+                                        self.input_mode,
+                                        self.filter.as_deref(),
+                                    )
+                                })
+                                .collect();
+
+                            if !content.is_empty() {
+                                if let Some(contents) = edit(
+                                    &mut self.value,
+                                    &mut state.cursor,
+                                    &mut state.history,
+                                    self.mask.as_deref(),
+                                    false,
+                                    |editor| {
+                                        editor.paste(Value::new(&content));
+                                    },
+                                ) {
+                                    shell.publish((on_input)(contents));
+                                }
+                            }
+
+                            focus.updated_at = Instant::now();
+                        }
+                        input_method::Event::Closed => {
+                            state.preedit = None;
+                        }
+                    }
+
+                    update_cache(state, &self.value);
+
+                    return event::Status::Captured;
+                }
+            }
             Event::Keyboard(keyboard::Event::KeyReleased { key, .. }) => {
                 let state = state::<Renderer>(tree);
 
@@ -1068,7 +1456,12 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if cursor.is_over(layout.bounds()) {
+        if self
+            .reveal_icon_bounds(layout)
+            .is_some_and(|bounds| cursor.is_over(bounds))
+        {
+            mouse::Interaction::Pointer
+        } else if cursor.is_over(layout.bounds()) {
             if self.on_input.is_none() {
                 mouse::Interaction::NotAllowed
             } else {
@@ -1109,6 +1502,19 @@ pub struct Icon<Font> {
     pub side: Side,
 }
 
+/// How the [`reveal_icon`] of a [`TextInput`] reveals its contents.
+///
+/// [`reveal_icon`]: TextInput::reveal_icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reveal {
+    /// The contents are revealed while the icon is pressed.
+    #[default]
+    Hold,
+    /// The contents are revealed or masked again every time the icon is
+    /// clicked.
+    Toggle,
+}
+
 /// The side of a [`TextInput`].
 #[derive(Debug, Clone)]
 pub enum Side {
@@ -1134,6 +1540,12 @@ impl Id {
     pub fn unique() -> Self {
         Self(widget::Id::unique())
     }
+
+    /// Returns the [`Id`] of a [`TextInput`] with this [`Id`] inside the scope
+    /// with the given [`Id`](widget::Id); see [`widget::Id::within`].
+    pub fn within(self, scope: impl Into<widget::Id>) -> Self {
+        Self(self.0.within(scope))
+    }
 }
 
 impl From<Id> for widget::Id {
@@ -1142,6 +1554,12 @@ impl From<Id> for widget::Id {
     }
 }
 
+impl From<widget::Id> for Id {
+    fn from(id: widget::Id) -> Self {
+        Self(id)
+    }
+}
+
 /// Produces a [`Task`] that focuses the [`TextInput`] with the given [`Id`].
 pub fn focus<T>(id: Id) -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus(id.0)))
@@ -1176,17 +1594,53 @@ pub fn select_all<T>(id: Id) -> Task<T> {
     task::effect(Action::widget(operation::text_input::select_all(id.0)))
 }
 
+/// Produces a [`Task`] that selects the given range of `char`s of the
+/// [`TextInput`] with the given [`Id`].
+pub fn select_range<T>(id: Id, range: Range<usize>) -> Task<T> {
+    task::effect(Action::widget(operation::text_input::select_range(
+        id.0, range,
+    )))
+}
+
+/// Produces a [`Task`] that undoes the last edit of the [`TextInput`] with
+/// the given [`Id`].
+///
+/// The restored value is produced with the [`TextInput::on_input`] message.
+pub fn undo<T>(id: Id) -> Task<T> {
+    task::effect(Action::widget(operation::text_input::undo(id.0)))
+}
+
+/// Produces a [`Task`] that redoes the last undone edit of the [`TextInput`]
+/// with the given [`Id`].
+///
+/// The restored value is produced with the [`TextInput::on_input`] message.
+pub fn redo<T>(id: Id) -> Task<T> {
+    task::effect(Action::widget(operation::text_input::redo(id.0)))
+}
+
+/// Produces a [`Task`] that returns the current [`Selection`] of the
+/// [`TextInput`] with the given [`Id`].
+pub fn selection(id: Id) -> Task<Selection> {
+    task::widget(operation::text_input::selection(id.0))
+}
+
 /// The state of a [`TextInput`].
 #[derive(Debug, Default, Clone)]
 pub struct State<P: text::Paragraph> {
     value: paragraph::Plain<P>,
     placeholder: paragraph::Plain<P>,
     icon: paragraph::Plain<P>,
+    reveal_icon: paragraph::Plain<P>,
     is_focused: Option<Focus>,
+    reported_focus: Reported,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    preedit: Option<String>,
+    is_revealed: bool,
+    history: History,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    reported_selection: Range<usize>,
     keyboard_modifiers: keyboard::Modifiers,
     // TODO: Add stateful horizontal scrolling offset
 }
@@ -1197,6 +1651,157 @@ fn state<Renderer: text::Renderer>(
     tree.state.downcast_mut::<State<Renderer::Paragraph>>()
 }
 
+/// The focus changes of a [`TextInput`] reported to the application.
+///
+/// Gaining focus is only reported on the event after it is noticed; so the
+/// blur of the widget that lost focus is always reported first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Reported {
+    #[default]
+    Blurred,
+    Pending,
+    Focused,
+}
+
+fn report_focus<P: text::Paragraph, Message: Clone>(
+    state: &mut State<P>,
+    on_focus: Option<&Message>,
+    on_blur: Option<&Message>,
+    shell: &mut Shell<'_, Message>,
+) {
+    match (state.is_focused(), state.reported_focus) {
+        (true, Reported::Blurred) => {
+            state.reported_focus = Reported::Pending;
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+        (true, Reported::Pending) => {
+            state.reported_focus = Reported::Focused;
+
+            if let Some(on_focus) = on_focus {
+                shell.publish(on_focus.clone());
+            }
+        }
+        (false, Reported::Focused) => {
+            state.reported_focus = Reported::Blurred;
+
+            if let Some(on_blur) = on_blur {
+                shell.publish(on_blur.clone());
+            }
+        }
+        (false, Reported::Pending) => {
+            state.reported_focus = Reported::Blurred;
+        }
+        (true, Reported::Focused) | (false, Reported::Blurred) => {}
+    }
+}
+
+/// Returns the character that should be inserted into a [`TextInput`] when
+/// the given one is typed or pasted, if any.
+fn accept(
+    c: char,
+    input_mode: InputMode,
+    filter: Option<&dyn Fn(char) -> bool>,
+) -> Option<char> {
+    if c.is_control() {
+        return None;
+    }
+
+    input_mode
+        .filter(c)
+        .filter(|c| filter.map_or(true, |filter| filter(*c)))
+}
+
+/// Applies an edit to the [`Value`] of a [`TextInput`] and runs its mask,
+/// returning the new contents of the [`TextInput`].
+///
+/// If the mask rejects the edit, the [`Value`] and [`Cursor`] are restored
+/// and `None` is returned. If the mask rewrites the contents, the [`Cursor`]
+/// keeps its distance to the end of the [`Value`].
+///
+/// Accepted edits are recorded in the [`History`]; coalescing them if they
+/// are typing.
+fn edit(
+    value: &mut Value,
+    cursor: &mut Cursor,
+    history: &mut History,
+    mask: Option<&dyn Fn(&str) -> Option<String>>,
+    is_typing: bool,
+    f: impl FnOnce(&mut Editor<'_>),
+) -> Option<String> {
+    let previous = (value.clone(), *cursor);
+
+    f(&mut Editor::new(value, cursor));
+
+    let mut contents = value.to_string();
+
+    if let Some(mask) = mask {
+        let Some(masked) = mask(&contents) else {
+            (*value, *cursor) = previous;
+
+            return None;
+        };
+
+        if masked != contents {
+            let distance_to_end = value.len() - cursor.end(value);
+
+            *value = Value::new(&masked);
+            cursor.move_to(value.len().saturating_sub(distance_to_end));
+        }
+
+        contents = masked;
+    }
+
+    history.record(previous, (&*value, *cursor), is_typing, Instant::now());
+
+    Some(contents)
+}
+
+/// Takes a [`Step`] through the [`History`] of a [`TextInput`], returning
+/// the restored contents, if any.
+fn step<P: text::Paragraph>(
+    value: &mut Value,
+    state: &mut State<P>,
+    step: Step,
+) -> Option<String> {
+    let (restored, cursor) = state.history.step(step, value, state.cursor)?;
+
+    *value = restored;
+    state.cursor = cursor;
+
+    Some(value.to_string())
+}
+
+/// Reports the selection of a [`TextInput`] to the application, if it
+/// changed since the last report.
+///
+/// Like focus, changes are noticed on the event after they happen.
+fn report_selection<P: text::Paragraph, Message>(
+    state: &mut State<P>,
+    value: &Value,
+    on_selection: &dyn Fn(Range<usize>) -> Message,
+    shell: &mut Shell<'_, Message>,
+) {
+    let selection = value.char_range(grapheme_selection(&state.cursor, value));
+
+    if selection != state.reported_selection {
+        state.reported_selection = selection.clone();
+
+        shell.publish(on_selection(selection));
+    }
+}
+
+/// Returns the range of graphemes selected by the [`Cursor`]; empty at its
+/// position if nothing is selected.
+fn grapheme_selection(cursor: &Cursor, value: &Value) -> Range<usize> {
+    match cursor.state(value) {
+        cursor::State::Index(index) => index..index,
+        cursor::State::Selection { start, end } => {
+            start.min(end)..start.max(end)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Focus {
     updated_at: Instant,
@@ -1216,11 +1821,17 @@ impl<P: text::Paragraph> State<P> {
             value: paragraph::Plain::default(),
             placeholder: paragraph::Plain::default(),
             icon: paragraph::Plain::default(),
+            reveal_icon: paragraph::Plain::default(),
             is_focused: None,
+            reported_focus: Reported::Blurred,
             is_dragging: false,
             is_pasting: None,
+            preedit: None,
+            is_revealed: false,
+            history: History::default(),
             last_click: None,
             cursor: Cursor::default(),
+            reported_selection: 0..0,
             keyboard_modifiers: keyboard::Modifiers::default(),
         }
     }
@@ -1251,6 +1862,28 @@ impl<P: text::Paragraph> State<P> {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
+    }
+
+    /// Returns the text being composed by an input method in the
+    /// [`TextInput`], if any.
+    ///
+    /// This text is displayed, but it is not part of the [`Value`] until it
+    /// is committed.
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
+    /// Returns the given [`Value`] with the text being composed by an input
+    /// method, if any, inserted at the [`Cursor`].
+    pub(crate) fn compose(&self, value: &Value) -> Value {
+        let mut value = value.clone();
+
+        if let Some(preedit) = &self.preedit {
+            value.insert_many(self.cursor.end(&value), Value::new(preedit));
+        }
+
+        value
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1288,21 +1921,58 @@ impl<P: text::Paragraph> operation::Focusable for State<P> {
     }
 }
 
-impl<P: text::Paragraph> operation::TextInput for State<P> {
+/// The [`State`] of a [`TextInput`] along with its [`Value`]; since
+/// operations work with `char` indices, not graphemes.
+struct Operable<'a, P: text::Paragraph> {
+    state: &'a mut State<P>,
+    value: &'a Value,
+    is_secure: bool,
+}
+
+impl<'a, P: text::Paragraph> operation::TextInput for Operable<'a, P> {
     fn move_cursor_to_front(&mut self) {
-        State::move_cursor_to_front(self);
+        self.state.move_cursor_to_front();
     }
 
     fn move_cursor_to_end(&mut self) {
-        State::move_cursor_to_end(self);
+        self.state.move_cursor_to_end();
     }
 
     fn move_cursor_to(&mut self, position: usize) {
-        State::move_cursor_to(self, position);
+        self.state.move_cursor_to(position);
     }
 
     fn select_all(&mut self) {
-        State::select_all(self);
+        self.state.select_all();
+    }
+
+    fn undo(&mut self) {
+        self.state.history.schedule(Step::Undo);
+    }
+
+    fn redo(&mut self) {
+        self.state.history.schedule(Step::Redo);
+    }
+
+    fn select_range(&mut self, range: Range<usize>) {
+        let range = self.value.grapheme_range(range);
+
+        self.state.cursor.select_range(range.start, range.end);
+    }
+
+    fn selection(&self) -> Selection {
+        let range = grapheme_selection(&self.state.cursor, self.value);
+
+        let selected = self.value.select(range.start, range.end);
+
+        Selection {
+            range: self.value.char_range(range),
+            text: if self.is_secure {
+                selected.secure().to_string()
+            } else {
+                selected.to_string()
+            },
+        }
     }
 }
 
@@ -1377,6 +2047,7 @@ fn replace_paragraph<Renderer>(
     layout: Layout<'_>,
     value: &Value,
     font: Option<Renderer::Font>,
+    font_features: &'static [font::Feature],
     text_size: Option<Pixels>,
     line_height: text::LineHeight,
 ) where
@@ -1391,11 +2062,13 @@ fn replace_paragraph<Renderer>(
     state.value = paragraph::Plain::new(Text {
         font,
         line_height,
-        content: &value.to_string(),
+        content: &state.compose(value).to_string(),
         bounds: Size::new(f32::INFINITY, text_bounds.height),
         size: text_size,
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Top,
+        max_space_factor: text::DEFAULT_MAX_SPACE_FACTOR,
+        font_features,
         shaping: text::Shaping::Advanced,
     });
 }
@@ -1413,6 +2086,8 @@ pub enum Status {
     Focused,
     /// The [`TextInput`] cannot be interacted with.
     Disabled,
+    /// The [`TextInput`] holds an invalid value.
+    Invalid,
 }
 
 /// The appearance of a text input.
@@ -1499,5 +2174,198 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             value: active.placeholder,
             ..active
         },
+        Status::Invalid => Style {
+            border: Border {
+                color: palette.danger.base.color,
+                ..active.border
+            },
+            selection: palette.danger.weak.color,
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(c: char) -> bool {
+        c.is_ascii_hexdigit()
+    }
+
+    fn paste(content: &str, filter: &dyn Fn(char) -> bool) -> Value {
+        Value::new(
+            &content
+                .chars()
+                .filter_map(|c| accept(c, InputMode::Text, Some(filter)))
+                .collect::<String>(),
+        )
+    }
+
+    #[test]
+    fn typing_is_filtered() {
+        assert_eq!(accept('f', InputMode::Text, Some(&hex)), Some('f'));
+        assert_eq!(accept('g', InputMode::Text, Some(&hex)), None);
+        assert_eq!(accept('\n', InputMode::Text, None), None);
+        assert_eq!(accept('a', InputMode::Numeric, Some(&hex)), None);
+    }
+
+    #[test]
+    fn filtering_multi_byte_characters() {
+        let alphabetic = |c: char| c.is_alphabetic();
+
+        assert_eq!(accept('é', InputMode::Text, Some(&alphabetic)), Some('é'));
+        assert_eq!(
+            accept('日', InputMode::Text, Some(&alphabetic)),
+            Some('日')
+        );
+        assert_eq!(
+            accept('\u{1F600}', InputMode::Text, Some(&alphabetic)),
+            None
+        );
+
+        assert_eq!(paste("日\u{1F600}本é!", &alphabetic).to_string(), "日本é");
+    }
+
+    #[test]
+    fn partially_rejected_paste_keeps_the_cursor_after_it() {
+        let mut value = Value::new("0x");
+        let mut cursor = Cursor::default();
+        cursor.move_to(2);
+
+        let contents = edit(
+            &mut value,
+            &mut cursor,
+            &mut History::default(),
+            None,
+            false,
+            |editor| {
+                editor.paste(paste("ca-fe!", &hex));
+            },
+        );
+
+        assert_eq!(contents.as_deref(), Some("0xcafe"));
+        assert_eq!(cursor.end(&value), 6);
+    }
+
+    #[test]
+    fn rejected_edits_are_reverted() {
+        let digits = |contents: &str| {
+            contents
+                .chars()
+                .all(|c| c.is_ascii_digit())
+                .then(|| contents.to_owned())
+        };
+
+        let mut value = Value::new("12");
+        let mut cursor = Cursor::default();
+        cursor.move_to(1);
+
+        let contents = edit(
+            &mut value,
+            &mut cursor,
+            &mut History::default(),
+            Some(&digits),
+            false,
+            |editor| {
+                editor.insert('a');
+            },
+        );
+
+        assert_eq!(contents, None);
+        assert_eq!(value.to_string(), "12");
+        assert_eq!(cursor.end(&value), 1);
+    }
+
+    #[test]
+    fn masks_can_rewrite_edits() {
+        let uppercase = |contents: &str| Some(contents.to_uppercase());
+
+        let mut value = Value::new("ab");
+        let mut cursor = Cursor::default();
+        cursor.move_to(1);
+
+        let contents = edit(
+            &mut value,
+            &mut cursor,
+            &mut History::default(),
+            Some(&uppercase),
+            false,
+            |editor| {
+                editor.paste(Value::new("ß"));
+            },
+        );
+
+        // "ß" becomes "SS"; the cursor stays before "b"
+        assert_eq!(contents.as_deref(), Some("ASSB"));
+        assert_eq!(value.to_string(), "ASSB");
+        assert_eq!(cursor.end(&value), 3);
+    }
+
+    #[derive(Default)]
+    struct Recording(Option<String>);
+
+    impl Clipboard for Recording {
+        fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+            self.0.clone()
+        }
+
+        fn write(&mut self, _kind: clipboard::Kind, contents: String) {
+            self.0 = Some(contents);
+        }
+    }
+
+    fn copy(text_input: TextInput<'static, (), Theme, ()>) -> Option<String> {
+        let mut element = Element::from(text_input);
+        let mut tree = Tree::new(&element);
+
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let state = tree.state.downcast_mut::<State<()>>();
+        state.focus();
+        state.select_all();
+
+        let mut clipboard = Recording::default();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for event in [
+            keyboard::Event::ModifiersChanged(keyboard::Modifiers::COMMAND),
+            keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character("c".into()),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::COMMAND,
+                text: None,
+            },
+        ] {
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Keyboard(event),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        clipboard.0
+    }
+
+    #[test]
+    fn secure_contents_are_only_copied_if_allowed() {
+        let input = || TextInput::new("", "hunter2").on_input(|_| ());
+
+        assert_eq!(copy(input()), Some(String::from("hunter2")));
+        assert_eq!(copy(input().secure(true)), None);
+        assert_eq!(
+            copy(input().secure(true).allow_copy(true)),
+            Some(String::from("hunter2"))
+        );
     }
 }
@@ -0,0 +1,240 @@
+use crate::core::time::{Duration, Instant};
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The time without typing after which consecutive typing is recorded as a
+/// new entry.
+const PAUSE: Duration = Duration::from_millis(1000);
+
+/// The undo and redo stacks of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: Vec<Entry>,
+    depth: usize,
+    /// The contents the text input had after its last change.
+    last: Option<String>,
+    /// The cursor after the last typed character and when it was typed.
+    typing: Option<(Cursor, Instant)>,
+    pending: Option<Step>,
+}
+
+/// A direction to move through the [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Undo,
+    Redo,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Sets the maximum amount of entries of the [`History`] and clears it
+    /// if the given [`Value`] is not the last one known; since it was then
+    /// changed by the application.
+    pub fn sync(&mut self, value: &Value, depth: usize) {
+        self.depth = depth;
+
+        let value = value.to_string();
+
+        if self.last.as_ref() != Some(&value) {
+            self.undo.clear();
+            self.redo.clear();
+            self.typing = None;
+            self.last = Some(value);
+        }
+    }
+
+    /// Records an edit, given the [`Value`] and [`Cursor`] before it.
+    ///
+    /// Consecutive typing is coalesced into a single entry, unless the
+    /// cursor moved or typing paused in between.
+    pub fn record(
+        &mut self,
+        (value, cursor): (Value, Cursor),
+        (edited, edited_cursor): (&Value, Cursor),
+        is_typing: bool,
+        now: Instant,
+    ) {
+        let contents = edited.to_string();
+
+        if value.to_string() == contents {
+            return;
+        }
+
+        let continues_typing = is_typing
+            && self.typing.is_some_and(|(typed_cursor, typed_at)| {
+                typed_cursor == cursor && now - typed_at < PAUSE
+            });
+
+        if !continues_typing {
+            self.undo.push_back(Entry { value, cursor });
+
+            while self.undo.len() > self.depth {
+                let _ = self.undo.pop_front();
+            }
+        }
+
+        self.redo.clear();
+        self.typing = is_typing.then_some((edited_cursor, now));
+        self.last = Some(contents);
+    }
+
+    /// Moves through the [`History`] from the given [`Value`] and
+    /// [`Cursor`], returning the ones to restore, if any.
+    pub fn step(
+        &mut self,
+        step: Step,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        let current = Entry {
+            value: value.clone(),
+            cursor,
+        };
+
+        let entry = match step {
+            Step::Undo => {
+                let entry = self.undo.pop_back()?;
+                self.redo.push(current);
+
+                entry
+            }
+            Step::Redo => {
+                let entry = self.redo.pop()?;
+                self.undo.push_back(current);
+
+                entry
+            }
+        };
+
+        self.typing = None;
+        self.last = Some(entry.value.to_string());
+
+        Some((entry.value, entry.cursor))
+    }
+
+    /// Schedules a [`Step`] to be taken on the next event.
+    pub fn schedule(&mut self, step: Step) {
+        self.pending = Some(step);
+    }
+
+    /// Takes the scheduled [`Step`], if any.
+    pub fn take_pending(&mut self) -> Option<Step> {
+        self.pending.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Field {
+        value: Value,
+        cursor: Cursor,
+        history: History,
+        now: Instant,
+    }
+
+    impl Field {
+        fn new() -> Self {
+            let mut history = History::default();
+            history.sync(&Value::new(""), 3);
+
+            Self {
+                value: Value::new(""),
+                cursor: Cursor::default(),
+                history,
+                now: Instant::now(),
+            }
+        }
+
+        fn edit(&mut self, is_typing: bool, f: impl FnOnce(&mut Self)) {
+            let previous = (self.value.clone(), self.cursor);
+
+            f(self);
+
+            self.history.record(
+                previous,
+                (&self.value, self.cursor),
+                is_typing,
+                self.now,
+            );
+        }
+
+        fn type_text(&mut self, text: &str) {
+            for c in text.chars() {
+                self.edit(true, |field| {
+                    field.value.insert(field.cursor.end(&field.value), c);
+                    field.cursor.move_right(&field.value);
+                });
+            }
+        }
+
+        fn step(&mut self, step: Step) -> Option<String> {
+            let (value, cursor) =
+                self.history.step(step, &self.value, self.cursor)?;
+
+            self.value = value;
+            self.cursor = cursor;
+
+            Some(self.value.to_string())
+        }
+    }
+
+    #[test]
+    fn typing_is_coalesced() {
+        let mut field = Field::new();
+
+        field.type_text("hello");
+        field.cursor.move_to(0);
+        field.type_text(">");
+
+        assert_eq!(field.step(Step::Undo).as_deref(), Some("hello"));
+        assert_eq!(field.step(Step::Undo).as_deref(), Some(""));
+        assert_eq!(field.step(Step::Undo), None);
+
+        assert_eq!(field.step(Step::Redo).as_deref(), Some("hello"));
+        assert_eq!(field.step(Step::Redo).as_deref(), Some(">hello"));
+        assert_eq!(field.step(Step::Redo), None);
+    }
+
+    #[test]
+    fn pauses_break_typing() {
+        let mut field = Field::new();
+
+        field.type_text("ab");
+        field.now += PAUSE;
+        field.type_text("c");
+
+        assert_eq!(field.step(Step::Undo).as_deref(), Some("ab"));
+    }
+
+    #[test]
+    fn history_is_bounded_and_cleared_by_the_application() {
+        let mut field = Field::new();
+
+        for _ in 0..5 {
+            field.edit(false, |field| {
+                field.value.insert(0, 'x');
+            });
+        }
+
+        assert_eq!(field.step(Step::Undo).as_deref(), Some("xxxx"));
+        assert_eq!(field.step(Step::Undo).as_deref(), Some("xxx"));
+        assert_eq!(field.step(Step::Undo).as_deref(), Some("xx"));
+        assert_eq!(field.step(Step::Undo), None);
+
+        field.history.sync(&Value::new("xx"), 3);
+        assert_eq!(field.step(Step::Redo).as_deref(), Some("xxx"));
+
+        field.history.sync(&Value::new("changed"), 3);
+        assert_eq!(field.step(Step::Undo), None);
+        assert_eq!(field.step(Step::Redo), None);
+    }
+}
@@ -1,5 +1,7 @@
 use unicode_segmentation::UnicodeSegmentation;
 
+use std::ops::Range;
+
 /// The value of a [`TextInput`].
 ///
 /// [`TextInput`]: super::TextInput
@@ -116,6 +118,47 @@ impl Value {
         let _ = self.graphemes.splice(start..end, std::iter::empty());
     }
 
+    /// Converts the given range of graphemes into a range of `char`s.
+    pub fn char_range(&self, range: Range<usize>) -> Range<usize> {
+        let chars = |index: usize| -> usize {
+            self.graphemes[..index.min(self.len())]
+                .iter()
+                .map(|grapheme| grapheme.chars().count())
+                .sum()
+        };
+
+        chars(range.start)..chars(range.end)
+    }
+
+    /// Converts the given range of `char`s into the smallest range of
+    /// graphemes containing it.
+    pub fn grapheme_range(&self, range: Range<usize>) -> Range<usize> {
+        let mut start = self.len();
+        let mut end = self.len();
+        let mut chars = 0;
+
+        for (index, grapheme) in self.graphemes.iter().enumerate() {
+            let next = chars + grapheme.chars().count();
+
+            if start == self.len() && range.start < next {
+                start = index;
+            }
+
+            if range.end <= chars {
+                end = index;
+                break;
+            }
+
+            chars = next;
+        }
+
+        if range.is_empty() {
+            start..start
+        } else {
+            start..end
+        }
+    }
+
     /// Returns a new [`Value`] with all its graphemes replaced with the
     /// dot ('•') character.
     pub fn secure(&self) -> Self {
@@ -132,3 +175,24 @@ impl std::fmt::Display for Value {
         f.write_str(&self.graphemes.concat())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_across_multi_byte_graphemes() {
+        // "e\u{301}" is a single grapheme made of two `char`s
+        let value = Value::new("ae\u{301}b\u{1F600}c");
+
+        assert_eq!(value.len(), 5);
+        assert_eq!(value.char_range(1..3), 1..4);
+        assert_eq!(value.char_range(0..5), 0..6);
+
+        assert_eq!(value.grapheme_range(1..4), 1..3);
+        assert_eq!(value.grapheme_range(2..3), 1..2);
+        assert_eq!(value.grapheme_range(4..6), 3..5);
+        assert_eq!(value.grapheme_range(2..2), 1..1);
+        assert_eq!(value.grapheme_range(3..100), 2..5);
+    }
+}
@@ -181,6 +181,7 @@ where
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         struct Overlay<'a, Message, Theme, NewTheme, Renderer> {
@@ -275,11 +276,24 @@ where
                     })
                     .map(|overlay| overlay::Element::new(Box::new(overlay)))
             }
+
+            fn top_most_overlay(
+                &mut self,
+            ) -> Option<overlay::Element<'_, Message, Theme, Renderer>>
+            {
+                self.content
+                    .top_most_overlay()
+                    .map(|content| Overlay {
+                        to_theme: &self.to_theme,
+                        content,
+                    })
+                    .map(|overlay| overlay::Element::new(Box::new(overlay)))
+            }
         }
 
         self.content
             .as_widget_mut()
-            .overlay(tree, layout, renderer, translation)
+            .overlay(tree, layout, renderer, viewport, translation)
             .map(|content| Overlay {
                 to_theme: &self.to_theme,
                 content,
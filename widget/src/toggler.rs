@@ -213,8 +213,10 @@ where
                         self.text_line_height,
                         self.text_size,
                         self.font,
+                        &[],
                         self.text_alignment,
                         alignment::Vertical::Top,
+                        text::DEFAULT_MAX_SPACE_FACTOR,
                         self.text_shaping,
                     )
                 } else {
@@ -6,12 +6,17 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
     Vector,
 };
 
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Mutex;
+
 /// An element to display a widget over another.
 #[allow(missing_debug_implementations)]
 pub struct Tooltip<
@@ -28,7 +33,11 @@ pub struct Tooltip<
     position: Position,
     gap: f32,
     padding: f32,
+    cursor_offset: Vector,
+    delay: Duration,
+    hide_delay: Duration,
     snap_within_viewport: bool,
+    scroll_policy: overlay::ScrollPolicy,
     class: Theme::Class<'a>,
 }
 
@@ -54,7 +63,11 @@ where
             position,
             gap: 0.0,
             padding: Self::DEFAULT_PADDING,
+            cursor_offset: Vector::ZERO,
+            delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
             snap_within_viewport: true,
+            scroll_policy: overlay::ScrollPolicy::Reposition,
             class: Theme::default(),
         }
     }
@@ -71,12 +84,48 @@ where
         self
     }
 
+    /// Sets the offset of the [`Tooltip`] from the cursor, when its
+    /// [`Position`] is [`Position::FollowCursor`].
+    pub fn cursor_offset(mut self, offset: impl Into<Vector>) -> Self {
+        self.cursor_offset = offset.into();
+        self
+    }
+
+    /// Sets the amount of time the cursor needs to rest over the content
+    /// before the [`Tooltip`] is shown.
+    ///
+    /// By default, the [`Tooltip`] is shown immediately.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the amount of time the [`Tooltip`] stays shown after the cursor
+    /// leaves its content.
+    ///
+    /// During this time, any other [`Tooltip`] is shown as soon as its
+    /// content is hovered; skipping its delay. This way, the tooltips of
+    /// adjacent widgets can be browsed without waiting for each one.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
     /// Sets whether the [`Tooltip`] is snapped within the viewport.
     pub fn snap_within_viewport(mut self, snap: bool) -> Self {
         self.snap_within_viewport = snap;
         self
     }
 
+    /// Sets the [`overlay::ScrollPolicy`] of the [`Tooltip`]; which decides
+    /// what happens when its content is scrolled while it is shown.
+    ///
+    /// By default, the [`Tooltip`] follows its content.
+    pub fn scroll_policy(mut self, policy: overlay::ScrollPolicy) -> Self {
+        self.scroll_policy = policy;
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     #[must_use]
     pub fn style(
@@ -159,16 +208,106 @@ where
     ) -> event::Status {
         let state = tree.state.downcast_mut::<State>();
 
-        let was_idle = *state == State::Idle;
+        let previous = *state;
 
-        *state = cursor
-            .position_over(layout.bounds())
-            .map(|cursor_position| State::Hovered { cursor_position })
-            .unwrap_or_default();
+        let (now, is_redraw) = match &event {
+            Event::Window(window::Event::RedrawRequested(now)) => (*now, true),
+            _ => (Instant::now(), false),
+        };
+
+        *state = match (previous, cursor.position_over(layout.bounds())) {
+            (
+                State::Hovered {
+                    cursor_position,
+                    anchor,
+                    generation,
+                },
+                None,
+            ) => {
+                let deadline = now + self.hide_delay;
+
+                warm_up(deadline);
+
+                if self.hide_delay.is_zero() {
+                    State::Idle
+                } else {
+                    State::Leaving {
+                        cursor_position,
+                        anchor,
+                        generation,
+                        deadline,
+                    }
+                }
+            }
+            (State::Leaving { deadline, .. }, None)
+                if now < deadline && previous.is_current() =>
+            {
+                previous
+            }
+            (_, None) => State::Idle,
+            (State::Hidden, Some(_)) => State::Hidden,
+            (State::Leaving { .. }, Some(cursor_position))
+                if !previous.is_current() =>
+            {
+                State::show(cursor_position)
+            }
+            (
+                State::Hovered {
+                    anchor, generation, ..
+                }
+                | State::Leaving {
+                    anchor, generation, ..
+                },
+                Some(cursor_position),
+            ) => State::Hovered {
+                cursor_position,
+                anchor,
+                generation,
+            },
+            (State::Idle, Some(cursor_position)) => {
+                if self.delay.is_zero() || is_warm(now) {
+                    State::show(cursor_position)
+                } else {
+                    State::Pending {
+                        cursor_position,
+                        deadline: now + self.delay,
+                    }
+                }
+            }
+            (
+                State::Pending {
+                    cursor_position: previous_position,
+                    deadline,
+                },
+                Some(cursor_position),
+            ) => {
+                if is_redraw && now >= deadline {
+                    State::show(cursor_position)
+                } else if cursor_position != previous_position {
+                    // The cursor must rest over the content
+                    State::Pending {
+                        cursor_position,
+                        deadline: now + self.delay,
+                    }
+                } else {
+                    previous
+                }
+            }
+        };
 
-        let is_idle = *state == State::Idle;
+        match *state {
+            State::Pending { deadline, .. }
+            | State::Leaving { deadline, .. } => {
+                shell.request_redraw(window::RedrawRequest::At(deadline));
+            }
+            _ => {}
+        }
 
-        if was_idle != is_idle {
+        if previous.is_shown() != state.is_shown()
+            || (state.is_shown()
+                && self.position == Position::FollowCursor
+                && previous.cursor_position() != state.cursor_position())
+        {
             shell.invalidate_layout();
         }
 
@@ -227,9 +366,10 @@ where
         tree: &'b mut widget::Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
+        viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_mut::<State>();
 
         let mut children = tree.children.iter_mut();
 
@@ -237,24 +377,56 @@ where
             children.next().unwrap(),
             layout,
             renderer,
+            viewport,
             translation,
         );
 
-        let tooltip = if let State::Hovered { cursor_position } = *state {
-            Some(overlay::Element::new(Box::new(Overlay {
-                position: layout.position() + translation,
-                tooltip: &self.tooltip,
-                state: children.next().unwrap(),
+        let position = layout.position() + translation;
+        let is_current = state.is_current();
+
+        let tooltip = match state {
+            State::Hovered { anchor, .. } | State::Leaving { anchor, .. }
+                if self.scroll_policy == overlay::ScrollPolicy::Close
+                    && anchor.is_some_and(|anchor| anchor != position) =>
+            {
+                // Keep it hidden until the cursor leaves the content
+                *state = State::Hidden;
+
+                None
+            }
+            State::Hovered { .. } | State::Leaving { .. }
+                if !viewport.intersects(&layout.bounds()) =>
+            {
+                None
+            }
+            State::Leaving { .. } if !is_current => None,
+            State::Hovered {
                 cursor_position,
-                content_bounds: layout.bounds(),
-                snap_within_viewport: self.snap_within_viewport,
-                positioning: self.position,
-                gap: self.gap,
-                padding: self.padding,
-                class: &self.class,
-            })))
-        } else {
-            None
+                anchor,
+                ..
+            }
+            | State::Leaving {
+                cursor_position,
+                anchor,
+                ..
+            } => {
+                *anchor = Some(position);
+
+                Some(overlay::Element::new(Box::new(Overlay {
+                    position,
+                    tooltip: &self.tooltip,
+                    state: children.next().unwrap(),
+                    cursor_position: *cursor_position,
+                    cursor_offset: self.cursor_offset,
+                    content_bounds: layout.bounds(),
+                    snap_within_viewport: self.snap_within_viewport,
+                    positioning: self.position,
+                    gap: self.gap,
+                    padding: self.padding,
+                    class: &self.class,
+                })))
+            }
+            State::Idle | State::Pending { .. } | State::Hidden => None,
         };
 
         if content.is_some() || tooltip.is_some() {
@@ -296,7 +468,8 @@ pub enum Position {
     Left,
     /// The tooltip will appear on the right of the widget.
     Right,
-    /// The tooltip will follow the cursor.
+    /// The tooltip will follow the cursor, at its
+    /// [`cursor_offset`](Tooltip::cursor_offset).
     FollowCursor,
 }
 
@@ -304,9 +477,98 @@ pub enum Position {
 enum State {
     #[default]
     Idle,
+    /// The cursor is resting over the content; the tooltip will be shown at
+    /// the deadline.
+    Pending {
+        cursor_position: Point,
+        deadline: Instant,
+    },
     Hovered {
         cursor_position: Point,
+        anchor: Option<Point>,
+        generation: u64,
+    },
+    /// The cursor left the content; the tooltip will be hidden at the
+    /// deadline.
+    Leaving {
+        cursor_position: Point,
+        anchor: Option<Point>,
+        generation: u64,
+        deadline: Instant,
     },
+    Hidden,
+}
+
+impl State {
+    /// Shows the tooltip; hiding any other one that is still leaving.
+    fn show(cursor_position: Point) -> Self {
+        State::Hovered {
+            cursor_position,
+            anchor: None,
+            generation: SHOWN.fetch_add(1, atomic::Ordering::Relaxed) + 1,
+        }
+    }
+
+    fn is_shown(&self) -> bool {
+        match self {
+            State::Hovered { .. } => true,
+            State::Leaving { .. } => self.is_current(),
+            State::Idle | State::Pending { .. } | State::Hidden => false,
+        }
+    }
+
+    /// Returns whether no other tooltip has been shown since this one.
+    fn is_current(&self) -> bool {
+        self.generation() == SHOWN.load(atomic::Ordering::Relaxed)
+    }
+
+    fn cursor_position(&self) -> Option<Point> {
+        match self {
+            State::Pending {
+                cursor_position, ..
+            }
+            | State::Hovered {
+                cursor_position, ..
+            }
+            | State::Leaving {
+                cursor_position, ..
+            } => Some(*cursor_position),
+            State::Idle | State::Hidden => None,
+        }
+    }
+
+    fn generation(&self) -> u64 {
+        match self {
+            State::Hovered { generation, .. }
+            | State::Leaving { generation, .. } => *generation,
+            State::Idle | State::Pending { .. } | State::Hidden => 0,
+        }
+    }
+}
+
+/// The generation of the last tooltip shown.
+///
+/// Only one tooltip is shown at a time; a tooltip that is leaving is hidden
+/// as soon as another one is shown.
+static SHOWN: AtomicU64 = AtomicU64::new(0);
+
+/// Until when tooltips are shown without waiting for their delay.
+static WARM: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Keeps tooltips warm until the given [`Instant`].
+fn warm_up(until: Instant) {
+    let mut warm = WARM
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    *warm = Some(warm.map_or(until, |warm| warm.max(until)));
+}
+
+/// Returns whether tooltips are warm at the given [`Instant`].
+fn is_warm(now: Instant) -> bool {
+    WARM.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some_and(|warm| now < warm)
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
@@ -318,6 +580,7 @@ where
     tooltip: &'b Element<'a, Message, Theme, Renderer>,
     state: &'b mut widget::Tree,
     cursor_position: Point,
+    cursor_offset: Vector,
     content_bounds: Rectangle,
     snap_within_viewport: bool,
     positioning: Position,
@@ -392,6 +655,7 @@ where
                         self.cursor_position.x,
                         self.cursor_position.y - text_bounds.height,
                     ) + translation
+                        + self.cursor_offset
                 }
             };
 
@@ -467,3 +731,104 @@ where
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::widget::Tree;
+    use crate::{Space, Theme};
+
+    struct Harness {
+        tooltip: Element<'static, (), Theme, ()>,
+        tree: Tree,
+        node: layout::Node,
+    }
+
+    impl Harness {
+        /// Creates a 100x100 [`Tooltip`] with a delay of 500ms and a hide
+        /// delay of 300ms.
+        fn new() -> Self {
+            let tooltip: Element<'static, (), Theme, ()> =
+                Tooltip::new(Space::new(100, 100), "Tip", Position::Top)
+                    .delay(Duration::from_millis(500))
+                    .hide_delay(Duration::from_millis(300))
+                    .into();
+
+            let mut tree = Tree::new(&tooltip);
+
+            let node = tooltip.as_widget().layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+            );
+
+            Self {
+                tooltip,
+                tree,
+                node,
+            }
+        }
+
+        /// Sends the event with the cursor at the given position; returning
+        /// whether the [`Tooltip`] is shown afterwards.
+        fn send(&mut self, cursor: Point, event: Event) -> bool {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = self.tooltip.as_widget_mut().on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&self.node),
+                mouse::Cursor::Available(cursor),
+                &(),
+                &mut crate::core::clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+
+            self.tree.state.downcast_ref::<State>().is_shown()
+        }
+
+        fn hover(&mut self, cursor: Point) -> bool {
+            self.send(
+                cursor,
+                Event::Mouse(mouse::Event::CursorMoved { position: cursor }),
+            )
+        }
+
+        fn redraw(&mut self, cursor: Point, now: Instant) -> bool {
+            self.send(
+                cursor,
+                Event::Window(window::Event::RedrawRequested(now)),
+            )
+        }
+    }
+
+    #[test]
+    fn tooltips_wait_for_their_delay_unless_warm() {
+        let inside = Point::new(50.0, 50.0);
+        let outside = Point::new(500.0, 500.0);
+        let start = Instant::now();
+        let after = |millis| start + Duration::from_millis(millis);
+
+        let mut first = Harness::new();
+        let mut second = Harness::new();
+
+        assert!(!first.hover(inside));
+        assert!(!first.redraw(inside, after(400)));
+        assert!(first.redraw(inside, after(600)));
+
+        // The tooltip lingers for a while after the cursor leaves
+        assert!(first.hover(outside));
+
+        // In the meantime, the second tooltip is warm; so it is shown
+        // immediately and the first one is hidden
+        assert!(second.hover(inside));
+        assert!(!first.redraw(outside, after(100)));
+
+        assert!(second.hover(outside));
+        assert!(
+            !second.redraw(outside, Instant::now() + Duration::from_secs(1))
+        );
+    }
+}
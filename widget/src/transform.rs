@@ -0,0 +1,383 @@
+//! Rotate, scale, and translate any content.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Radians, Rectangle, Shell, Size,
+    Transformation, Vector, Widget,
+};
+
+/// A widget that rotates, scales, and translates its content around its
+/// center.
+///
+/// The [`Transform`] does not change the layout of its content; only how it
+/// is drawn. Events and the mouse cursor are mapped back into the local
+/// coordinates of the content; so it stays interactive without any manual
+/// math.
+///
+/// Renderers may only honor the scale and translation of a [`Transform`]
+/// when drawing some primitives.
+#[allow(missing_debug_implementations)]
+pub struct Transform<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    rotation: Radians,
+    scale: f32,
+    translation: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Transform<'a, Message, Theme, Renderer> {
+    /// Creates a [`Transform`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            rotation: Radians(0.0),
+            scale: 1.0,
+            translation: Vector::ZERO,
+        }
+    }
+
+    /// Sets the rotation of the [`Transform`].
+    ///
+    /// Positive angles rotate the content clockwise.
+    pub fn rotate(mut self, rotation: impl Into<Radians>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the scale of the [`Transform`].
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the translation of the [`Transform`].
+    pub fn translate(mut self, translation: impl Into<Vector>) -> Self {
+        self.translation = translation.into();
+        self
+    }
+
+    /// The [`Transformation`] applied to the content in the given bounds.
+    fn transformation(&self, bounds: Rectangle) -> Transformation {
+        let center = bounds.center();
+
+        Transformation::translate(
+            center.x + self.translation.x,
+            center.y + self.translation.y,
+        ) * Transformation::rotate(self.rotation)
+            * Transformation::scale(self.scale)
+            * Transformation::translate(-center.x, -center.y)
+    }
+}
+
+/// Returns the smallest rectangle containing the given one after being
+/// transformed.
+fn bounding_box(
+    rectangle: Rectangle,
+    transformation: Transformation,
+) -> Rectangle {
+    let corners = [
+        Point::new(rectangle.x, rectangle.y),
+        Point::new(rectangle.x + rectangle.width, rectangle.y),
+        Point::new(rectangle.x, rectangle.y + rectangle.height),
+        Point::new(
+            rectangle.x + rectangle.width,
+            rectangle.y + rectangle.height,
+        ),
+    ]
+    .map(|corner| corner * transformation);
+
+    let (min, max) =
+        corners
+            .iter()
+            .fold((corners[0], corners[0]), |(min, max), corner| {
+                (
+                    Point::new(min.x.min(corner.x), min.y.min(corner.y)),
+                    Point::new(max.x.max(corner.x), max.y.max(corner.y)),
+                )
+            });
+
+    Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y))
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Transform<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let inverse = self.transformation(layout.bounds()).inverse();
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.transform(inverse),
+            layout,
+            cursor * inverse,
+            renderer,
+            clipboard,
+            shell,
+            &bounding_box(*viewport, inverse),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let inverse = self.transformation(layout.bounds()).inverse();
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor * inverse,
+            &bounding_box(*viewport, inverse),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let transformation = self.transformation(layout.bounds());
+        let inverse = transformation.inverse();
+
+        renderer.with_transformation(transformation, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor * inverse,
+                &bounding_box(*viewport, inverse),
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        // Overlays are drawn on top of everything else and cannot be
+        // transformed; so they are only shown while the content is not.
+        if self.transformation(layout.bounds()) != Transformation::IDENTITY {
+            return None;
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Transform<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + crate::core::Renderer,
+{
+    fn from(
+        transform: Transform<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(transform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::{Degrees, Theme};
+    use crate::Button;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Message {
+        Pressed,
+    }
+
+    type Element = crate::core::Element<'static, Message, Theme, ()>;
+
+    /// A 100x50 button, rotated a quarter turn and doubled in size; so it
+    /// covers the 100x200 rectangle at (200, 25) on the screen.
+    fn transformed_button() -> Element {
+        let button: Button<'static, Message, Theme, ()> =
+            Button::new(crate::Space::new(Length::Fill, Length::Fill))
+                .width(100)
+                .height(50)
+                .on_press(Message::Pressed);
+
+        Transform::new(button)
+            .rotate(Degrees(90.0))
+            .scale(2.0)
+            .translate(Vector::new(200.0, 100.0))
+            .into()
+    }
+
+    fn layout(element: &Element, tree: &mut Tree) -> layout::Node {
+        element.as_widget().layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        )
+    }
+
+    fn click(x: f32, y: f32) -> Vec<Message> {
+        let mut element = transformed_button();
+        let mut tree = Tree::new(&element);
+        let node = layout(&element, &mut tree);
+
+        let cursor = mouse::Cursor::Available(Point::new(x, y));
+        let mut messages = Vec::new();
+        let mut shell = crate::core::Shell::new(&mut messages);
+
+        for event in [
+            mouse::Event::CursorMoved {
+                position: Point::new(x, y),
+            },
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Mouse(event),
+                Layout::new(&node),
+                cursor,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        messages
+    }
+
+    fn interaction(x: f32, y: f32) -> mouse::Interaction {
+        let element = transformed_button();
+        let mut tree = Tree::new(&element);
+        let node = layout(&element, &mut tree);
+
+        element.as_widget().mouse_interaction(
+            &tree,
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::new(x, y)),
+            &Rectangle::with_size(Size::INFINITY),
+            &(),
+        )
+    }
+
+    #[test]
+    fn clicks_are_mapped_into_the_content() {
+        assert_eq!(click(250.0, 200.0), vec![Message::Pressed]);
+        assert_eq!(click(290.0, 30.0), vec![Message::Pressed]);
+
+        // Inside of the untransformed bounds of the button
+        assert_eq!(click(40.0, 20.0), vec![]);
+        assert_eq!(click(250.0, 230.0), vec![]);
+    }
+
+    #[test]
+    fn hovering_is_mapped_into_the_content() {
+        assert_eq!(interaction(210.0, 120.0), mouse::Interaction::Pointer);
+        assert_eq!(interaction(90.0, 25.0), mouse::Interaction::default());
+    }
+
+    #[test]
+    fn the_window_position_is_preserved() {
+        let transformation =
+            Transform::<Message, Theme, ()>::new(crate::Space::new(100, 50))
+                .rotate(Degrees(90.0))
+                .scale(2.0)
+                .translate(Vector::new(200.0, 100.0))
+                .transformation(Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(100.0, 50.0),
+                ));
+
+        let cursor = mouse::Cursor::Available(Point::new(250.0, 125.0))
+            * transformation.inverse();
+
+        let position = cursor.position().unwrap();
+
+        assert!((position.x - 50.0).abs() < 0.001);
+        assert!((position.y - 25.0).abs() < 0.001);
+        assert_eq!(cursor.window_position(), Some(Point::new(250.0, 125.0)));
+    }
+}
@@ -0,0 +1,652 @@
+//! Display long lists by only building the rows in view.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    self, Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Vector,
+};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// A vertical list that only builds and lays out the rows in view.
+///
+/// The rows are produced by a closure, given their index; so a
+/// [`VirtualList`] can display hundreds of thousands of rows without building
+/// all of them every frame. Only the rows visible in the viewport are built,
+/// plus a few more above and below; see [`VirtualList::overscan`].
+///
+/// A [`VirtualList`] does not scroll on its own. Place it inside a
+/// [`Scrollable`] and the rows will follow its offset.
+///
+/// The state of a row is kept by index while it is in view; so widgets like
+/// text inputs keep their contents while scrolling.
+///
+/// ```no_run
+/// # type VirtualList<'a, Message> = iced_widget::VirtualList<'a, Message>;
+/// # use iced_widget::{scrollable, text};
+/// #
+/// let list: VirtualList<'_, ()> =
+///     VirtualList::new(100_000, 30, |i| text!("Row {i}").into());
+///
+/// let content = scrollable(list);
+/// ```
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    count: usize,
+    row: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    row_height: RowHeight<'a>,
+    overscan: usize,
+    width: Length,
+    visible: RefCell<Visible<'a, Message, Theme, Renderer>>,
+}
+
+enum RowHeight<'a> {
+    Fixed(f32),
+    Measured(Box<dyn Fn(usize) -> f32 + 'a>),
+}
+
+/// The rows of a [`VirtualList`] that are currently built.
+struct Visible<'a, Message, Theme, Renderer> {
+    range: Range<usize>,
+    rows: Vec<Element<'a, Message, Theme, Renderer>>,
+    nodes: Option<Vec<layout::Node>>,
+    width: f32,
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The default amount of rows built beyond each end of the viewport.
+    pub const DEFAULT_OVERSCAN: usize = 5;
+
+    /// Creates a new [`VirtualList`] with the given amount of rows, their
+    /// height, and the closure producing a row given its index.
+    pub fn new(
+        count: usize,
+        row_height: impl Into<Pixels>,
+        row: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            row: Box::new(row),
+            row_height: RowHeight::Fixed(row_height.into().0),
+            overscan: Self::DEFAULT_OVERSCAN,
+            width: Length::Fill,
+            visible: RefCell::new(Visible {
+                range: 0..0,
+                rows: Vec::new(),
+                nodes: None,
+                width: 0.0,
+            }),
+        }
+    }
+
+    /// Sets the closure measuring the height of a row of the [`VirtualList`],
+    /// given its index.
+    ///
+    /// Every row is measured whenever the [`VirtualList`] is laid out; so the
+    /// closure should be cheap.
+    pub fn row_heights(mut self, height: impl Fn(usize) -> f32 + 'a) -> Self {
+        self.row_height = RowHeight::Measured(Box::new(height));
+        self
+    }
+
+    /// Sets the amount of rows of the [`VirtualList`] that are built beyond
+    /// each end of the viewport.
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Returns the vertical offset of the row with the given index.
+    fn offset(&self, offsets: &[f32], index: usize) -> f32 {
+        match &self.row_height {
+            RowHeight::Fixed(height) => index as f32 * height,
+            RowHeight::Measured(_) => offsets
+                .get(index)
+                .or(offsets.last())
+                .copied()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the index of the row at the given vertical offset.
+    fn index_at(&self, offsets: &[f32], y: f32) -> usize {
+        match &self.row_height {
+            RowHeight::Fixed(height) if *height > 0.0 => (y / height) as usize,
+            RowHeight::Fixed(_) => 0,
+            RowHeight::Measured(_) => offsets
+                .partition_point(|offset| *offset <= y)
+                .saturating_sub(1),
+        }
+    }
+
+    /// Returns the indices of the rows in the given viewport.
+    fn range(
+        &self,
+        offsets: &[f32],
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Range<usize> {
+        let Some(visible) = bounds.intersection(viewport) else {
+            return 0..0;
+        };
+
+        let start = self
+            .index_at(offsets, visible.y - bounds.y)
+            .saturating_sub(self.overscan);
+
+        let end = (self
+            .index_at(offsets, visible.y + visible.height - bounds.y)
+            .saturating_add(1 + self.overscan))
+        .min(self.count);
+
+        start.min(end)..end
+    }
+
+    /// Builds and lays out the rows with the given indices, unless they
+    /// already are.
+    fn resolve(&self, state: &State, range: Range<usize>, renderer: &Renderer) {
+        let mut visible = self.visible.borrow_mut();
+        let mut rows = state.rows.borrow_mut();
+
+        let visible = &mut *visible;
+        let rows = &mut *rows;
+
+        if visible.range != range
+            || visible.rows.len() != range.len()
+            || rows.range != range
+        {
+            let elements: Vec<_> = range.clone().map(&self.row).collect();
+
+            let previous = std::mem::replace(&mut rows.range, range.clone());
+            let mut trees = std::mem::take(&mut rows.trees);
+
+            rows.trees = range
+                .clone()
+                .zip(&elements)
+                .map(|(index, element)| {
+                    if previous.contains(&index)
+                        && index - previous.start < trees.len()
+                    {
+                        let mut tree = std::mem::replace(
+                            &mut trees[index - previous.start],
+                            Tree::empty(),
+                        );
+
+                        tree.diff(element);
+                        tree
+                    } else {
+                        Tree::new(element)
+                    }
+                })
+                .collect();
+
+            visible.range = range;
+            visible.rows = elements;
+            visible.nodes = None;
+        }
+
+        if visible.nodes.is_none() {
+            let width = visible.width;
+
+            visible.nodes = Some(
+                visible
+                    .range
+                    .clone()
+                    .zip(&visible.rows)
+                    .zip(&mut rows.trees)
+                    .map(|((index, row), tree)| {
+                        let y = self.offset(&state.offsets, index);
+                        let height = self.offset(&state.offsets, index + 1) - y;
+
+                        row.as_widget()
+                            .layout(
+                                tree,
+                                renderer,
+                                &layout::Limits::new(
+                                    Size::ZERO,
+                                    Size::new(width, height),
+                                ),
+                            )
+                            .move_to(Point::new(0.0, y))
+                    })
+                    .collect(),
+            );
+        }
+    }
+}
+
+/// The local state of a [`VirtualList`].
+#[derive(Debug, Default)]
+struct State {
+    /// The offsets of the measured rows, followed by the total height.
+    offsets: Vec<f32>,
+    rows: RefCell<Rows>,
+}
+
+/// The state of the rows of a [`VirtualList`] that are currently built.
+#[derive(Debug, Default)]
+struct Rows {
+    range: Range<usize>,
+    trees: Vec<Tree>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.offsets.clear();
+
+        if let RowHeight::Measured(height) = &self.row_height {
+            let mut y = 0.0;
+
+            state.offsets.reserve(self.count + 1);
+            state.offsets.push(y);
+
+            for index in 0..self.count {
+                y += height(index).max(0.0);
+                state.offsets.push(y);
+            }
+        }
+
+        let height = self.offset(&state.offsets, self.count);
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(0.0, height));
+
+        let mut visible = self.visible.borrow_mut();
+
+        visible.width = size.width;
+        visible.nodes = None;
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Operations have no viewport; so the rows in view are the last ones
+        let range = {
+            let range = &state.rows.get_mut().range;
+
+            range.start.min(self.count)..range.end.min(self.count)
+        };
+
+        self.resolve(state, range, renderer);
+
+        let visible = self.visible.borrow();
+        let rows = state.rows.get_mut();
+        let offset = layout.position() - Point::ORIGIN;
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((row, tree), node) in visible
+                .rows
+                .iter()
+                .zip(&mut rows.trees)
+                .zip(visible.nodes.iter().flatten())
+            {
+                row.as_widget().operate(
+                    tree,
+                    Layout::with_offset(offset, node),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let range = self.range(&state.offsets, layout.bounds(), viewport);
+
+        self.resolve(state, range, renderer);
+
+        let visible = self.visible.get_mut();
+        let rows = state.rows.get_mut();
+        let offset = layout.position() - Point::ORIGIN;
+
+        visible
+            .rows
+            .iter_mut()
+            .zip(&mut rows.trees)
+            .zip(visible.nodes.iter().flatten())
+            .map(|((row, tree), node)| {
+                row.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    Layout::with_offset(offset, node),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let range = self.range(&state.offsets, layout.bounds(), viewport);
+
+        self.resolve(state, range, renderer);
+
+        let visible = self.visible.borrow();
+        let rows = state.rows.borrow();
+        let offset = layout.position() - Point::ORIGIN;
+
+        visible
+            .rows
+            .iter()
+            .zip(&rows.trees)
+            .zip(visible.nodes.iter().flatten())
+            .map(|((row, tree), node)| {
+                row.as_widget().mouse_interaction(
+                    tree,
+                    Layout::with_offset(offset, node),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let range = self.range(&state.offsets, layout.bounds(), viewport);
+
+        self.resolve(state, range, renderer);
+
+        let visible = self.visible.borrow();
+        let rows = state.rows.borrow();
+        let offset = layout.position() - Point::ORIGIN;
+
+        for ((row, tree), node) in visible
+            .rows
+            .iter()
+            .zip(&rows.trees)
+            .zip(visible.nodes.iter().flatten())
+        {
+            row.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                Layout::with_offset(offset, node),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let range = self.range(&state.offsets, layout.bounds(), viewport);
+
+        self.resolve(state, range, renderer);
+
+        let visible = self.visible.get_mut();
+        let rows = state.rows.get_mut();
+        let offset = layout.position() - Point::ORIGIN;
+
+        let children = visible
+            .rows
+            .iter_mut()
+            .zip(&mut rows.trees)
+            .zip(visible.nodes.iter().flatten())
+            .filter_map(|((row, tree), node)| {
+                row.as_widget_mut().overlay(
+                    tree,
+                    Layout::with_offset(offset, node),
+                    renderer,
+                    viewport,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Color, Theme};
+
+    /// A row keeping the generation of the list that created its state.
+    struct Item(u32);
+
+    impl Widget<(), Theme, ()> for Item {
+        fn tag(&self) -> tree::Tag {
+            tree::Tag::of::<u32>()
+        }
+
+        fn state(&self) -> tree::State {
+            tree::State::new(self.0)
+        }
+
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fill, Length::Fill)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn list(
+        count: usize,
+        generation: u32,
+    ) -> VirtualList<'static, (), Theme, ()> {
+        VirtualList::new(count, 10, move |_| Element::new(Item(generation)))
+            .overscan(0)
+    }
+
+    /// Draws the list in a viewport of 100x50 units at the given offset,
+    /// returning the generation of every row built.
+    fn draw(
+        list: &VirtualList<'static, (), Theme, ()>,
+        tree: &mut Tree,
+        offset: f32,
+    ) -> Vec<(usize, u32)> {
+        let node = list.layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY)),
+        );
+
+        list.draw(
+            tree,
+            &mut (),
+            &Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::new(Point::new(0.0, offset), Size::new(100.0, 50.0)),
+        );
+
+        let rows = tree.state.downcast_ref::<State>().rows.borrow();
+
+        rows.range
+            .clone()
+            .zip(&rows.trees)
+            .map(|(index, tree)| (index, *tree.state.downcast_ref::<u32>()))
+            .collect()
+    }
+
+    #[test]
+    fn only_rows_in_view_are_built() {
+        let list = list(100_000, 0);
+        let mut tree = Tree::new(&list as &dyn Widget<_, _, _>);
+
+        let rows = draw(&list, &mut tree, 0.0);
+        assert_eq!(rows.first(), Some(&(0, 0)));
+        assert_eq!(rows.len(), 6);
+
+        let rows = draw(&list, &mut tree, 500_000.0);
+        assert_eq!(rows.first(), Some(&(50_000, 0)));
+        assert_eq!(rows.len(), 6);
+    }
+
+    #[test]
+    fn rows_keep_their_state_by_index() {
+        let mut tree = Tree::new(&list(1_000, 0) as &dyn Widget<_, _, _>);
+
+        let _ = draw(&list(1_000, 0), &mut tree, 0.0);
+        let rows = draw(&list(1_000, 1), &mut tree, 30.0);
+
+        assert_eq!(rows, vec![(3, 0), (4, 0), (5, 0), (6, 1), (7, 1), (8, 1)]);
+    }
+
+    #[test]
+    fn shrinking_below_the_viewport_drops_rows() {
+        let mut tree = Tree::new(&list(1_000, 0) as &dyn Widget<_, _, _>);
+
+        let _ = draw(&list(1_000, 0), &mut tree, 500.0);
+
+        assert!(draw(&list(20, 0), &mut tree, 500.0).is_empty());
+        assert_eq!(draw(&list(20, 0), &mut tree, 180.0).len(), 2);
+    }
+
+    #[test]
+    fn measured_rows_are_found_by_offset() {
+        let list =
+            list(100, 0).row_heights(
+                |index| {
+                    if index % 2 == 0 {
+                        10.0
+                    } else {
+                        30.0
+                    }
+                },
+            );
+
+        let mut tree = Tree::new(&list as &dyn Widget<_, _, _>);
+
+        let indices = |rows: Vec<(usize, u32)>| {
+            rows.into_iter().map(|(index, _)| index).collect::<Vec<_>>()
+        };
+
+        assert_eq!(indices(draw(&list, &mut tree, 0.0)), vec![0, 1, 2, 3]);
+        assert_eq!(indices(draw(&list, &mut tree, 45.0)), vec![2, 3, 4, 5]);
+    }
+}
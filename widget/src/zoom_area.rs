@@ -0,0 +1,493 @@
+//! Zoom and pan on any content.
+use crate::core::event::{self, Event};
+use crate::core::image::FilterMethod;
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Transformation, Vector, Widget,
+};
+
+/// A frame that can zoom in and pan its content.
+///
+/// Scrolling while holding the command key zooms around the cursor,
+/// dragging pans the content while it is zoomed in, and a double click
+/// resets it. Events are transformed back into the coordinates of the
+/// content; so it stays interactive while zoomed.
+#[allow(missing_debug_implementations)]
+pub struct ZoomArea<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    min_scale: f32,
+    max_scale: f32,
+    scale_step: f32,
+    filter_method: Option<FilterMethod>,
+}
+
+impl<'a, Message, Theme, Renderer> ZoomArea<'a, Message, Theme, Renderer> {
+    /// Creates a [`ZoomArea`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let content = content.into();
+        let size = content.as_widget().size_hint();
+
+        ZoomArea {
+            content,
+            width: size.width.fluid(),
+            height: size.height.fluid(),
+            min_scale: 1.0,
+            max_scale: 8.0,
+            scale_step: 0.10,
+            filter_method: None,
+        }
+    }
+
+    /// Sets the width of the [`ZoomArea`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ZoomArea`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the min scale applied to the content of the [`ZoomArea`].
+    ///
+    /// Default is `1.0`
+    pub fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self
+    }
+
+    /// Sets the max scale applied to the content of the [`ZoomArea`].
+    ///
+    /// Default is `8.0`
+    pub fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale;
+        self
+    }
+
+    /// Sets the percentage the content of the [`ZoomArea`] will be scaled by
+    /// when zoomed in or out.
+    ///
+    /// Default is `0.10`
+    pub fn scale_step(mut self, scale_step: f32) -> Self {
+        self.scale_step = scale_step;
+        self
+    }
+
+    /// Forces the given [`FilterMethod`] on the images of the content.
+    ///
+    /// [`FilterMethod::Nearest`] keeps the pixels of the images crisp while
+    /// zoomed in; which is useful to inspect them.
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = Some(filter_method);
+        self
+    }
+}
+
+/// The local state of a [`ZoomArea`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    scale: f32,
+    offset: Vector,
+    drag: Option<(Point, Vector)>,
+    last_click: Option<mouse::Click>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            offset: Vector::ZERO,
+            drag: None,
+            last_click: None,
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+}
+
+impl State {
+    fn is_zoomed(&self) -> bool {
+        self.scale != 1.0 || self.offset != Vector::ZERO
+    }
+
+    /// The [`Transformation`] applied to the content in the given bounds.
+    fn transformation(&self, bounds: Rectangle) -> Transformation {
+        Transformation::translate(
+            bounds.x + self.offset.x,
+            bounds.y + self.offset.y,
+        ) * Transformation::scale(self.scale)
+            * Transformation::translate(-bounds.x, -bounds.y)
+    }
+
+    /// Zooms to the given scale, keeping the content under the given
+    /// position, relative to the bounds, in place.
+    fn zoom(&mut self, scale: f32, position: Vector, size: Size) {
+        let anchor = (position - self.offset) * (1.0 / self.scale);
+
+        self.scale = scale;
+        self.offset = position - anchor * scale;
+        self.clamp(size);
+    }
+
+    /// Limits the offset so the content can never be panned out of sight.
+    fn clamp(&mut self, size: Size) {
+        let clamp = |offset: f32, length: f32| {
+            let overflow = length - length * self.scale;
+
+            offset.clamp(overflow.min(0.0), overflow.max(0.0))
+        };
+
+        self.offset = Vector::new(
+            clamp(self.offset.x, size.width),
+            clamp(self.offset.y, size.height),
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ZoomArea<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits,
+        );
+
+        let size = limits.resolve(self.width, self.height, content.size());
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.modifiers = modifiers;
+        }
+
+        if let Some((origin, offset)) = state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    state.offset = offset + (position - origin);
+                    state.clamp(bounds.size());
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.drag = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+            if let Some(position) = cursor.position_in(bounds) {
+                if state.modifiers.command() {
+                    let y = match delta {
+                        mouse::ScrollDelta::Lines { y, .. }
+                        | mouse::ScrollDelta::Pixels { y, .. } => y,
+                    };
+
+                    let scale = if y > 0.0 {
+                        state.scale * (1.0 + self.scale_step)
+                    } else if y < 0.0 {
+                        state.scale / (1.0 + self.scale_step)
+                    } else {
+                        state.scale
+                    };
+
+                    state.zoom(
+                        scale.clamp(self.min_scale, self.max_scale),
+                        Vector::new(position.x, position.y),
+                        bounds.size(),
+                    );
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let inverse = state.transformation(bounds).inverse();
+
+        let content_cursor = if cursor.is_over(bounds) {
+            cursor * inverse
+        } else {
+            mouse::Cursor::Unavailable
+        };
+
+        let content_event = event.clone().transform(inverse);
+
+        let content_viewport = bounds
+            .intersection(viewport)
+            .map(|visible| visible * inverse);
+
+        if let Some(content_viewport) = content_viewport {
+            if let event::Status::Captured =
+                self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    content_event,
+                    layout.children().next().unwrap(),
+                    content_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &content_viewport,
+                )
+            {
+                return event::Status::Captured;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                if let mouse::click::Kind::Double = click.kind() {
+                    state.scale = 1.0_f32.clamp(self.min_scale, self.max_scale);
+                    state.offset = Vector::ZERO;
+                    state.drag = None;
+                    state.clamp(bounds.size());
+
+                    return event::Status::Captured;
+                }
+
+                if state.scale > 1.0 {
+                    state.drag = Some((position, state.offset));
+
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if !cursor.is_over(bounds) {
+            return mouse::Interaction::default();
+        }
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return mouse::Interaction::default();
+        };
+
+        let inverse = state.transformation(bounds).inverse();
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor * inverse,
+            &(visible * inverse),
+            renderer,
+        );
+
+        match interaction {
+            mouse::Interaction::None | mouse::Interaction::Idle
+                if state.scale > 1.0 =>
+            {
+                mouse::Interaction::Grab
+            }
+            interaction => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let transformation = state.transformation(bounds);
+        let inverse = transformation.inverse();
+
+        let cursor = if cursor.is_over(bounds) {
+            cursor * inverse
+        } else {
+            mouse::Cursor::Unavailable
+        };
+
+        let content_viewport = visible * inverse;
+
+        let draw = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                &content_viewport,
+            );
+        };
+
+        renderer.with_layer(visible, |renderer| {
+            renderer.with_transformation(transformation, |renderer| match self
+                .filter_method
+            {
+                Some(filter_method) => {
+                    renderer.with_filter_method(filter_method, draw);
+                }
+                None => draw(renderer),
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        // Overlays are drawn on top of everything else and cannot be
+        // transformed; so they are only shown while the content is not zoomed.
+        if tree.state.downcast_ref::<State>().is_zoomed() {
+            return None;
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ZoomArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + crate::core::Renderer,
+{
+    fn from(
+        zoom_area: ZoomArea<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(zoom_area)
+    }
+}
@@ -2,20 +2,25 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.12/runtime
+use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
 use crate::core::window;
 use crate::core::{Event, Point, Size};
 
+use std::sync::atomic::{self, AtomicU64};
+
 /// Converts some [`window::Settings`] into some `WindowAttributes` from `winit`.
 pub fn window_attributes(
     settings: window::Settings,
     title: &str,
     primary_monitor: Option<winit::monitor::MonitorHandle>,
+    parent: Option<winit::raw_window_handle::RawWindowHandle>,
     _id: Option<String>,
 ) -> winit::window::WindowAttributes {
     let mut attributes = winit::window::WindowAttributes::default();
+    let backdrop = backdrop(settings.backdrop);
 
     attributes = attributes
         .with_title(title)
@@ -31,7 +36,9 @@ pub fn window_attributes(
                 | winit::window::WindowButtons::MINIMIZE
         })
         .with_decorations(settings.decorations)
-        .with_transparent(settings.transparent)
+        .with_transparent(
+            settings.transparent || backdrop != window::Backdrop::Opaque,
+        )
         .with_window_icon(settings.icon.and_then(icon))
         .with_window_level(window_level(settings.level))
         .with_visible(settings.visible);
@@ -56,6 +63,10 @@ pub fn window_attributes(
         });
     }
 
+    if let Some(parent) = parent {
+        attributes = parent_window(attributes, parent);
+    }
+
     #[cfg(any(
         target_os = "dragonfly",
         target_os = "freebsd",
@@ -79,6 +90,18 @@ pub fn window_attributes(
 
         attributes = attributes
             .with_skip_taskbar(settings.platform_specific.skip_taskbar);
+
+        attributes = attributes.with_system_backdrop(match backdrop {
+            window::Backdrop::Opaque | window::Backdrop::Transparent => {
+                winit::platform::windows::BackdropType::None
+            }
+            window::Backdrop::Blur | window::Backdrop::Acrylic => {
+                winit::platform::windows::BackdropType::TransientWindow
+            }
+            window::Backdrop::Mica => {
+                winit::platform::windows::BackdropType::MainWindow
+            }
+        });
     }
 
     #[cfg(target_os = "macos")]
@@ -92,11 +115,14 @@ pub fn window_attributes(
             )
             .with_fullsize_content_view(
                 settings.platform_specific.fullsize_content_view,
-            );
+            )
+            .with_blur(backdrop == window::Backdrop::Blur);
     }
 
     #[cfg(target_os = "linux")]
     {
+        attributes = attributes.with_blur(backdrop == window::Backdrop::Blur);
+
         #[cfg(feature = "x11")]
         {
             use winit::platform::x11::WindowAttributesExtX11;
@@ -120,6 +146,74 @@ pub fn window_attributes(
     attributes
 }
 
+/// Returns the given [`window::Backdrop`] if the current platform supports it;
+/// falling back to [`window::Backdrop::Opaque`] with a warning otherwise.
+fn backdrop(backdrop: window::Backdrop) -> window::Backdrop {
+    use window::Backdrop;
+
+    let is_supported = match backdrop {
+        Backdrop::Opaque | Backdrop::Transparent => true,
+        Backdrop::Blur => cfg!(any(
+            target_os = "windows",
+            target_os = "macos",
+            target_os = "linux"
+        )),
+        Backdrop::Acrylic | Backdrop::Mica => cfg!(target_os = "windows"),
+    };
+
+    if is_supported {
+        backdrop
+    } else {
+        log::warn!(
+            "The {backdrop:?} backdrop is not supported on this platform; \
+            falling back to an opaque window"
+        );
+
+        Backdrop::Opaque
+    }
+}
+
+/// Makes the window described by the given attributes owned by the
+/// `parent` window.
+#[cfg(target_os = "windows")]
+fn parent_window(
+    attributes: winit::window::WindowAttributes,
+    parent: winit::raw_window_handle::RawWindowHandle,
+) -> winit::window::WindowAttributes {
+    use winit::platform::windows::WindowAttributesExtWindows;
+    use winit::raw_window_handle::RawWindowHandle;
+
+    match parent {
+        RawWindowHandle::Win32(handle) => {
+            attributes.with_owner_window(handle.hwnd.get())
+        }
+        _ => attributes,
+    }
+}
+
+/// Makes the window described by the given attributes a child window of
+/// the `parent` window.
+#[cfg(target_os = "macos")]
+#[allow(unsafe_code)]
+fn parent_window(
+    attributes: winit::window::WindowAttributes,
+    parent: winit::raw_window_handle::RawWindowHandle,
+) -> winit::window::WindowAttributes {
+    // SAFETY: The parent handle is obtained from a live window owned by
+    // the shell, which outlives the creation of the child window.
+    unsafe { attributes.with_parent_window(Some(parent)) }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn parent_window(
+    attributes: winit::window::WindowAttributes,
+    _parent: winit::raw_window_handle::RawWindowHandle,
+) -> winit::window::WindowAttributes {
+    log::debug!("Parent windows are not supported on this platform");
+
+    attributes
+}
+
 /// Converts a winit window event into an iced event.
 pub fn window_event(
     event: winit::event::WindowEvent,
@@ -138,7 +232,12 @@ pub fn window_event(
             })))
         }
         WindowEvent::CloseRequested => {
-            Some(Event::Window(window::Event::CloseRequested))
+            /// The number of the last close request made to any window.
+            static REQUESTS: AtomicU64 = AtomicU64::new(0);
+
+            Some(Event::Window(window::Event::CloseRequested {
+                request: REQUESTS.fetch_add(1, atomic::Ordering::Relaxed) + 1,
+            }))
         }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
@@ -251,6 +350,19 @@ pub fn window_event(
                 }
             }
         })),
+        WindowEvent::Ime(ime) => Some(Event::InputMethod(match ime {
+            winit::event::Ime::Enabled => input_method::Event::Opened,
+            winit::event::Ime::Preedit(text, cursor) => {
+                input_method::Event::Preedit(
+                    text,
+                    cursor.map(|(start, end)| start..end),
+                )
+            }
+            winit::event::Ime::Commit(text) => {
+                input_method::Event::Commit(text)
+            }
+            winit::event::Ime::Disabled => input_method::Event::Closed,
+        })),
         WindowEvent::ModifiersChanged(new_modifiers) => {
             Some(Event::Keyboard(keyboard::Event::ModifiersChanged(
                 self::modifiers(new_modifiers.state()),
@@ -380,13 +492,51 @@ pub fn fullscreen(
         window::Mode::Fullscreen => {
             Some(winit::window::Fullscreen::Borderless(monitor))
         }
+        window::Mode::Exclusive(video_mode) => {
+            let handle = monitor.as_ref().and_then(|monitor| {
+                monitor
+                    .video_modes()
+                    .find(|handle| self::video_mode(handle) == video_mode)
+            });
+
+            match handle {
+                Some(handle) => {
+                    Some(winit::window::Fullscreen::Exclusive(handle))
+                }
+                None => {
+                    log::warn!(
+                        "Video mode not supported by the monitor: \
+                        {video_mode:?}"
+                    );
+
+                    Some(winit::window::Fullscreen::Borderless(monitor))
+                }
+            }
+        }
+    }
+}
+
+/// Converts a [`winit`] video mode to a [`window::VideoMode`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn video_mode(
+    video_mode: &winit::monitor::VideoModeHandle,
+) -> window::VideoMode {
+    let size = video_mode.size();
+
+    window::VideoMode {
+        size: Size::new(size.width, size.height),
+        bit_depth: video_mode.bit_depth(),
+        refresh_rate_millihertz: video_mode.refresh_rate_millihertz(),
     }
 }
 
 /// Converts a [`window::Mode`] to a visibility flag.
 pub fn visible(mode: window::Mode) -> bool {
     match mode {
-        window::Mode::Windowed | window::Mode::Fullscreen => true,
+        window::Mode::Windowed
+        | window::Mode::Fullscreen
+        | window::Mode::Exclusive(_) => true,
         window::Mode::Hidden => false,
     }
 }
@@ -397,7 +547,12 @@ pub fn visible(mode: window::Mode) -> bool {
 pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     match mode {
         None => window::Mode::Windowed,
-        Some(_) => window::Mode::Fullscreen,
+        Some(winit::window::Fullscreen::Borderless(_)) => {
+            window::Mode::Fullscreen
+        }
+        Some(winit::window::Fullscreen::Exclusive(video_mode)) => {
+            window::Mode::Exclusive(self::video_mode(&video_mode))
+        }
     }
 }
 
@@ -428,6 +583,23 @@ pub fn mouse_interaction(
     }
 }
 
+/// Converts a [`keyboard::InputMode`] to a [`winit`] IME purpose.
+///
+/// [`winit`] has no purposes for numeric, email, URL or telephone keyboards
+/// yet; the platform shows its regular keyboard for those.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn ime_purpose(mode: keyboard::InputMode) -> winit::window::ImePurpose {
+    match mode {
+        keyboard::InputMode::Text
+        | keyboard::InputMode::Numeric
+        | keyboard::InputMode::Decimal
+        | keyboard::InputMode::Email
+        | keyboard::InputMode::Url
+        | keyboard::InputMode::Tel => winit::window::ImePurpose::Normal,
+    }
+}
+
 /// Converts a `MouseButton` from [`winit`] to an [`iced`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
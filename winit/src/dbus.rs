@@ -0,0 +1,22 @@
+//! Share a connection to the session bus of the user.
+use std::sync::OnceLock;
+
+pub use zbus::blocking::{Connection, Proxy};
+
+/// Returns the connection to the session bus shared by the whole
+/// application, if there is one.
+///
+/// The connection is made the first time it is needed.
+pub fn session() -> Option<&'static Connection> {
+    static SESSION: OnceLock<Option<Connection>> = OnceLock::new();
+
+    SESSION
+        .get_or_init(|| {
+            Connection::session()
+                .map_err(|error| {
+                    log::warn!("Failed to connect to the session bus: {error}");
+                })
+                .ok()
+        })
+        .as_ref()
+}
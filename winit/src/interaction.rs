@@ -0,0 +1,392 @@
+//! Query the interaction preferences of the user from the platform.
+//!
+//! - On Windows, the double click time and the drag rectangle of the system
+//!   are used; and animations are reduced if client area animations are off.
+//! - On macOS, the double click interval of `NSEvent` and the "reduce motion"
+//!   setting of `NSWorkspace` are used.
+//! - On Linux, the GTK settings of the user are read; which most desktop
+//!   environments keep in sync with their own. The animation setting of
+//!   GNOME is read through the settings portal of the desktop if GTK does
+//!   not have one; and the portal signals any change of it.
+//! - On the Web, the `prefers-reduced-motion` media query is used.
+//!
+//! Any preference that cannot be queried keeps its default value.
+#![cfg_attr(not(feature = "program"), allow(dead_code))]
+use crate::core::settings::{Interaction, Motion};
+use crate::core::time::Duration;
+
+use std::thread;
+
+/// Queries the [`Interaction`] settings of the platform.
+pub(crate) fn query() -> Interaction {
+    let interaction = platform::query();
+
+    log::debug!("Interaction settings: {interaction:?}");
+
+    interaction
+}
+
+/// Queries the [`Motion`] policy matching the preference of the platform.
+pub(crate) fn motion() -> Motion {
+    let motion = Motion::from_preference(prefers_reduced_motion());
+
+    log::debug!("Motion policy: {motion:?}");
+
+    motion
+}
+
+/// Returns whether the user prefers reduced motion, according to the
+/// platform.
+pub(crate) fn prefers_reduced_motion() -> bool {
+    platform::prefers_reduced_motion()
+}
+
+/// Calls the given closure with the reduced motion preference of the user
+/// every time it may have changed, until the closure returns `false`.
+///
+/// It blocks the current thread.
+pub(crate) fn watch_reduced_motion(on_change: impl FnMut(bool) -> bool) {
+    platform::watch_reduced_motion(on_change);
+}
+
+/// Queries the reduced motion preference of the user periodically; for
+/// platforms that do not signal its changes.
+fn poll_reduced_motion(mut on_change: impl FnMut(bool) -> bool) {
+    const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        if !on_change(prefers_reduced_motion()) {
+            return;
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[allow(unsafe_code)]
+mod platform {
+    pub use super::poll_reduced_motion as watch_reduced_motion;
+
+    use crate::core::settings::Interaction;
+    use crate::core::time::Duration;
+
+    use winapi::shared::minwindef::{BOOL, FALSE};
+    use winapi::um::winuser::{
+        GetDoubleClickTime, GetSystemMetrics, SystemParametersInfoW, SM_CXDRAG,
+        SPI_GETCLIENTAREAANIMATION,
+    };
+
+    pub fn query() -> Interaction {
+        // SAFETY: Both functions only read system settings
+        let (double_click, drag) =
+            unsafe { (GetDoubleClickTime(), GetSystemMetrics(SM_CXDRAG)) };
+
+        let default = Interaction::default();
+
+        Interaction {
+            double_click: if double_click > 0 {
+                Duration::from_millis(u64::from(double_click))
+            } else {
+                default.double_click
+            },
+            drag_threshold: if drag > 0 {
+                drag as f32
+            } else {
+                default.drag_threshold
+            },
+        }
+    }
+
+    pub fn prefers_reduced_motion() -> bool {
+        let mut is_animated: BOOL = 1;
+
+        // SAFETY: `SPI_GETCLIENTAREAANIMATION` writes a `BOOL` to the
+        // given pointer
+        let result = unsafe {
+            SystemParametersInfoW(
+                SPI_GETCLIENTAREAANIMATION,
+                0,
+                std::ptr::addr_of_mut!(is_animated).cast(),
+                0,
+            )
+        };
+
+        result != FALSE && is_animated == FALSE
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[allow(unsafe_code)]
+mod platform {
+    pub use super::poll_reduced_motion as watch_reduced_motion;
+
+    use crate::core::settings::Interaction;
+    use crate::core::time::Duration;
+
+    use std::ffi::{c_char, c_void};
+
+    type Object = *mut c_void;
+    type Selector = *const c_void;
+
+    #[link(name = "objc")]
+    extern "C" {
+        fn objc_getClass(name: *const c_char) -> Object;
+        fn sel_registerName(name: *const c_char) -> Selector;
+        fn objc_msgSend();
+    }
+
+    #[link(name = "AppKit", kind = "framework")]
+    extern "C" {}
+
+    /// AppKit does not expose a drag threshold; so only the double click
+    /// interval is queried.
+    pub fn query() -> Interaction {
+        // SAFETY: `objc_msgSend` is called through a function pointer
+        // matching the signature of `+[NSEvent doubleClickInterval]`
+        let interval = unsafe {
+            let send: unsafe extern "C" fn(Object, Selector) -> f64 =
+                std::mem::transmute(objc_msgSend as unsafe extern "C" fn());
+
+            let class = objc_getClass(b"NSEvent\0".as_ptr().cast());
+
+            if class.is_null() {
+                0.0
+            } else {
+                send(
+                    class,
+                    sel_registerName(b"doubleClickInterval\0".as_ptr().cast()),
+                )
+            }
+        };
+
+        let default = Interaction::default();
+
+        Interaction {
+            double_click: if interval.is_finite() && interval > 0.0 {
+                Duration::from_secs_f64(interval)
+            } else {
+                default.double_click
+            },
+            ..default
+        }
+    }
+
+    pub fn prefers_reduced_motion() -> bool {
+        // SAFETY: `objc_msgSend` is called through function pointers
+        // matching the signatures of `+[NSWorkspace sharedWorkspace]` and
+        // `-[NSWorkspace accessibilityDisplayShouldReduceMotion]`
+        unsafe {
+            let send_object: unsafe extern "C" fn(Object, Selector) -> Object =
+                std::mem::transmute(objc_msgSend as unsafe extern "C" fn());
+
+            let send_bool: unsafe extern "C" fn(Object, Selector) -> i8 =
+                std::mem::transmute(objc_msgSend as unsafe extern "C" fn());
+
+            let class = objc_getClass(b"NSWorkspace\0".as_ptr().cast());
+
+            if class.is_null() {
+                return false;
+            }
+
+            let workspace = send_object(
+                class,
+                sel_registerName(b"sharedWorkspace\0".as_ptr().cast()),
+            );
+
+            !workspace.is_null()
+                && send_bool(
+                    workspace,
+                    sel_registerName(
+                        b"accessibilityDisplayShouldReduceMotion\0"
+                            .as_ptr()
+                            .cast(),
+                    ),
+                ) != 0
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use crate::core::settings::Interaction;
+    use crate::core::time::Duration;
+
+    use crate::dbus;
+
+    use std::path::PathBuf;
+    use zbus::zvariant::{OwnedValue, Value};
+
+    /// The namespace and the key of the animation setting of GNOME in the
+    /// settings portal.
+    const ANIMATIONS: (&str, &str) =
+        ("org.gnome.desktop.interface", "enable-animations");
+
+    pub fn query() -> Interaction {
+        let default = Interaction::default();
+
+        let Some(settings) = settings() else {
+            return default;
+        };
+
+        let value = |key: &str| {
+            settings.lines().find_map(|line| {
+                let (name, value) = line.split_once('=')?;
+
+                (name.trim() == key)
+                    .then(|| value.trim().parse::<u32>().ok())?
+            })
+        };
+
+        Interaction {
+            double_click: value("gtk-double-click-time")
+                .filter(|millis| *millis > 0)
+                .map_or(default.double_click, |millis| {
+                    Duration::from_millis(u64::from(millis))
+                }),
+            drag_threshold: value("gtk-dnd-drag-threshold")
+                .filter(|pixels| *pixels > 0)
+                .map_or(default.drag_threshold, |pixels| pixels as f32),
+        }
+    }
+
+    pub fn prefers_reduced_motion() -> bool {
+        let from_gtk = settings().and_then(|settings| {
+            settings.lines().find_map(|line| {
+                let (name, value) = line.split_once('=')?;
+
+                (name.trim() == "gtk-enable-animations")
+                    .then(|| is_disabled(value))
+            })
+        });
+
+        from_gtk.or_else(from_portal).unwrap_or(false)
+    }
+
+    pub fn watch_reduced_motion(mut on_change: impl FnMut(bool) -> bool) {
+        let signals = settings_portal().and_then(|portal| {
+            portal
+                .receive_signal("SettingChanged")
+                .map_err(|error| {
+                    log::warn!(
+                        "Failed to listen to the settings portal: {error}"
+                    );
+                })
+                .ok()
+        });
+
+        // Without the portal, only the settings of GTK can change; and
+        // reading those does not need anything else
+        let Some(signals) = signals else {
+            super::poll_reduced_motion(on_change);
+            return;
+        };
+
+        for signal in signals {
+            let Ok((namespace, key, _value)) =
+                signal.body().deserialize::<(String, String, OwnedValue)>()
+            else {
+                continue;
+            };
+
+            if (namespace.as_str(), key.as_str()) == ANIMATIONS
+                && !on_change(prefers_reduced_motion())
+            {
+                return;
+            }
+        }
+    }
+
+    fn is_disabled(value: &str) -> bool {
+        matches!(value.trim(), "0" | "false")
+    }
+
+    /// Reads the animation setting of GNOME through the settings portal.
+    fn from_portal() -> Option<bool> {
+        let portal = settings_portal()?;
+
+        // `ReadOne` is only available in newer versions of the portal
+        let value = portal
+            .call::<_, _, OwnedValue>("ReadOne", &ANIMATIONS)
+            .or_else(|_| portal.call::<_, _, OwnedValue>("Read", &ANIMATIONS))
+            .ok()?;
+
+        is_enabled(&value).map(|is_enabled| !is_enabled)
+    }
+
+    fn is_enabled(value: &Value<'_>) -> Option<bool> {
+        match value {
+            Value::Bool(is_enabled) => Some(*is_enabled),
+            // `Read` wraps the setting in another variant
+            Value::Value(value) => is_enabled(value),
+            _ => None,
+        }
+    }
+
+    fn settings_portal() -> Option<dbus::Proxy<'static>> {
+        dbus::Proxy::new(
+            dbus::session()?,
+            "org.freedesktop.portal.Desktop",
+            "/org/freedesktop/portal/desktop",
+            "org.freedesktop.portal.Settings",
+        )
+        .ok()
+    }
+
+    /// Reads the GTK settings of the user; preferring the ones of GTK 4.
+    fn settings() -> Option<String> {
+        let config = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".config"))
+            })?;
+
+        ["gtk-4.0", "gtk-3.0"].into_iter().find_map(|version| {
+            std::fs::read_to_string(config.join(version).join("settings.ini"))
+                .ok()
+        })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    pub use super::poll_reduced_motion as watch_reduced_motion;
+
+    use crate::core::settings::Interaction;
+
+    pub fn query() -> Interaction {
+        Interaction::default()
+    }
+
+    pub fn prefers_reduced_motion() -> bool {
+        web_sys::window()
+            .and_then(|window| {
+                window
+                    .match_media("(prefers-reduced-motion: reduce)")
+                    .ok()
+                    .flatten()
+            })
+            .is_some_and(|query| query.matches())
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "linux",
+    target_os = "macos",
+    target_arch = "wasm32"
+)))]
+mod platform {
+    pub use super::poll_reduced_motion as watch_reduced_motion;
+
+    use crate::core::settings::Interaction;
+
+    pub fn query() -> Interaction {
+        Interaction::default()
+    }
+
+    pub fn prefers_reduced_motion() -> bool {
+        false
+    }
+}
@@ -31,12 +31,21 @@ pub mod settings;
 #[cfg(feature = "program")]
 pub mod program;
 
-#[cfg(feature = "system")]
 pub mod system;
 
+#[cfg(feature = "single-instance")]
+pub mod single_instance;
+
 mod error;
+mod interaction;
 mod proxy;
 
+#[cfg(target_os = "linux")]
+mod dbus;
+
+#[cfg(feature = "program")]
+mod taskbar;
+
 pub use clipboard::Clipboard;
 pub use error::Error;
 pub use proxy::Proxy;
@@ -6,12 +6,13 @@ pub use state::State;
 
 use crate::conversion;
 use crate::core;
+use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::time::Instant;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
 use crate::core::window;
-use crate::core::{Color, Element, Point, Size, Theme};
+use crate::core::{Color, Element, Point, Rectangle, Size, Theme};
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::task;
@@ -42,7 +43,8 @@ use std::sync::Arc;
 /// [`Task`] in some of its methods.
 ///
 /// When using a [`Program`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. Pressing `Shift+F12` cycles between
+/// raw and smoothed measurements.
 pub trait Program
 where
     Self: Sized,
@@ -136,6 +138,29 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         1.0
     }
+
+    /// Returns the splash screen of the [`Program`] for the `window`, if any.
+    ///
+    /// The splash screen is shown instead of the [`view`](Self::view) until
+    /// the [`Task`] returned by [`new`](Self::new) finishes, or until the
+    /// [`splash_timeout`](Self::splash_timeout) is reached.
+    ///
+    /// By default, there is no splash screen.
+    #[allow(unused_variables)]
+    fn splash(
+        &self,
+        window: window::Id,
+    ) -> Option<Element<'_, Self::Message, Self::Theme, Self::Renderer>> {
+        None
+    }
+
+    /// Returns the maximum amount of time the splash screen of the
+    /// [`Program`] is shown.
+    ///
+    /// By default, it returns 5 seconds.
+    fn splash_timeout(&self) -> Duration {
+        Duration::from_secs(5)
+    }
 }
 
 /// The appearance of a program.
@@ -187,6 +212,17 @@ where
     let mut debug = Debug::new();
     debug.startup_started();
 
+    // Widgets are processed in this thread; so they read these settings
+    settings
+        .interaction
+        .unwrap_or_else(crate::interaction::query)
+        .apply();
+
+    settings
+        .motion
+        .unwrap_or_else(crate::interaction::motion)
+        .apply();
+
     let event_loop = EventLoop::with_user_event()
         .build()
         .expect("Create event loop");
@@ -213,6 +249,9 @@ where
         task
     };
 
+    // The splash screen is dismissed once the initial tasks finish
+    let task = task.chain(runtime::task::effect(Action::DismissSplash));
+
     if let Some(stream) = runtime::task::into_stream(task) {
         runtime.run(stream);
     }
@@ -234,6 +273,7 @@ where
         event_receiver,
         control_sender,
         is_daemon,
+        settings.sticky_modifiers,
     ));
 
     let context = task::Context::from_waker(task::noop_waker_ref());
@@ -482,11 +522,21 @@ where
                                 settings,
                                 title,
                                 monitor,
+                                parent,
                                 on_open,
                             } => {
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
 
+                                let fullscreen_shortcut =
+                                    settings.fullscreen_shortcut;
+
+                                let initial_background =
+                                    settings.initial_background;
+
+                                let modal_parent =
+                                    settings.parent.filter(|_| settings.modal);
+
                                 let visible = settings.visible;
 
                                 #[cfg(target_arch = "wasm32")]
@@ -501,6 +551,7 @@ where
                                             monitor
                                                 .or(event_loop
                                                     .primary_monitor()),
+                                            parent,
                                             self.id.clone(),
                                         )
                                         .with_visible(false),
@@ -559,7 +610,10 @@ where
                                         id,
                                         window,
                                         exit_on_close_request,
+                                        fullscreen_shortcut,
+                                        initial_background,
                                         make_visible: visible,
+                                        modal_parent,
                                         on_open,
                                     },
                                 );
@@ -609,7 +663,10 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: winit::window::Window,
         exit_on_close_request: bool,
+        fullscreen_shortcut: bool,
+        initial_background: Option<Color>,
         make_visible: bool,
+        modal_parent: Option<window::Id>,
         on_open: oneshot::Sender<window::Id>,
     },
     EventLoopAwakened(winit::event::Event<Message>),
@@ -623,6 +680,7 @@ enum Control {
         settings: window::Settings,
         title: String,
         monitor: Option<winit::monitor::MonitorHandle>,
+        parent: Option<winit::raw_window_handle::RawWindowHandle>,
         on_open: oneshot::Sender<window::Id>,
     },
 }
@@ -636,6 +694,7 @@ async fn run_instance<P, C>(
     mut event_receiver: mpsc::UnboundedReceiver<Event<Action<P::Message>>>,
     mut control_sender: mpsc::UnboundedSender<Control>,
     is_daemon: bool,
+    sticky_modifiers: bool,
 ) where
     P: Program + 'static,
     C: Compositor<Renderer = P::Renderer> + 'static,
@@ -655,19 +714,30 @@ async fn run_instance<P, C>(
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut actions = 0;
+    let mut sticky_modifiers =
+        sticky_modifiers.then(keyboard::StickyModifiers::new);
 
     let mut ui_caches = FxHashMap::default();
     let mut user_interfaces = ManuallyDrop::new(FxHashMap::default());
 
+    debug.warm_up_started();
+    compositor.warm_up();
+    debug.warm_up_finished();
+
     debug.startup_finished();
 
+    let mut splash_deadline = Some(Instant::now() + program.splash_timeout());
+
     'main: while let Some(event) = event_receiver.next().await {
         match event {
             Event::WindowCreated {
                 id,
                 window,
                 exit_on_close_request,
+                fullscreen_shortcut,
+                initial_background,
                 make_visible,
+                modal_parent,
                 on_open,
             } => {
                 let window = window_manager.insert(
@@ -676,6 +746,19 @@ async fn run_instance<P, C>(
                     &program,
                     &mut compositor,
                     exit_on_close_request,
+                    fullscreen_shortcut,
+                    modal_parent,
+                );
+
+                // Present a blank frame right away; so the window does not
+                // flash while its first frame is being laid out
+                let _ = compositor.present::<&str>(
+                    &mut window.renderer,
+                    &mut window.surface,
+                    window.state.viewport(),
+                    initial_background
+                        .unwrap_or_else(|| window.state.background_color()),
+                    &[],
                 );
 
                 let logical_size = window.state.logical_size();
@@ -689,6 +772,7 @@ async fn run_instance<P, C>(
                         logical_size,
                         &mut debug,
                         id,
+                        splash_deadline.is_some(),
                     ),
                 );
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
@@ -713,6 +797,21 @@ async fn run_instance<P, C>(
                         event::StartCause::Init
                         | event::StartCause::ResumeTimeReached { .. },
                     ) => {
+                        if splash_deadline
+                            .is_some_and(|deadline| deadline <= Instant::now())
+                        {
+                            log::debug!("Splash screen timed out");
+
+                            splash_deadline = None;
+
+                            dismiss_splash(
+                                &program,
+                                &mut user_interfaces,
+                                &mut window_manager,
+                                &mut debug,
+                            );
+                        }
+
                         for (_id, window) in window_manager.iter_mut() {
                             window.raw.request_redraw();
                         }
@@ -734,6 +833,7 @@ async fn run_instance<P, C>(
                         run_action(
                             action,
                             &program,
+                            &mut splash_deadline,
                             &mut compositor,
                             &mut messages,
                             &mut clipboard,
@@ -744,6 +844,17 @@ async fn run_instance<P, C>(
                             &mut ui_caches,
                         );
                         actions += 1;
+
+                        for (id, window) in window_manager.iter_mut() {
+                            if let Some(mode) = window.poll_mode() {
+                                events.push((
+                                    id,
+                                    core::Event::Window(
+                                        window::Event::ModeChanged(mode),
+                                    ),
+                                ));
+                            }
+                        }
                     }
                     event::Event::WindowEvent {
                         window_id: id,
@@ -765,7 +876,7 @@ async fn run_instance<P, C>(
                             window::Event::RedrawRequested(Instant::now()),
                         );
 
-                        let cursor = window.state.cursor();
+                        let cursor = window.cursor();
 
                         let ui = user_interfaces
                             .get_mut(&id)
@@ -788,6 +899,13 @@ async fn run_instance<P, C>(
                             },
                             cursor,
                         );
+
+                        if window.is_blocked() {
+                            draw_modal_dim(
+                                &mut window.renderer,
+                                window.state.logical_size(),
+                            );
+                        }
                         debug.draw_finished();
 
                         if new_mouse_interaction != window.mouse_interaction {
@@ -804,25 +922,38 @@ async fn run_instance<P, C>(
                             window: id,
                             event: redraw_event,
                             status: core::event::Status::Ignored,
+                            is_text_focused: false,
                         });
 
-                        let _ = control_sender.start_send(Control::ChangeFlow(
-                            match ui_state {
-                                user_interface::State::Updated {
-                                    redraw_request: Some(redraw_request),
-                                } => match redraw_request {
-                                    window::RedrawRequest::NextFrame => {
-                                        window.raw.request_redraw();
+                        let flow = match ui_state {
+                            user_interface::State::Updated {
+                                redraw_request: Some(redraw_request),
+                            } => match redraw_request {
+                                window::RedrawRequest::NextFrame => {
+                                    window.raw.request_redraw();
 
-                                        ControlFlow::Wait
-                                    }
-                                    window::RedrawRequest::At(at) => {
-                                        ControlFlow::WaitUntil(at)
-                                    }
-                                },
-                                _ => ControlFlow::Wait,
+                                    ControlFlow::Wait
+                                }
+                                window::RedrawRequest::At(at) => {
+                                    ControlFlow::WaitUntil(at)
+                                }
                             },
-                        ));
+                            _ => ControlFlow::Wait,
+                        };
+
+                        // Wake up in time to dismiss the splash screen
+                        let flow = match (flow, splash_deadline) {
+                            (ControlFlow::WaitUntil(at), Some(deadline)) => {
+                                ControlFlow::WaitUntil(at.min(deadline))
+                            }
+                            (ControlFlow::Wait, Some(deadline)) => {
+                                ControlFlow::WaitUntil(deadline)
+                            }
+                            (flow, _) => flow,
+                        };
+
+                        let _ = control_sender
+                            .start_send(Control::ChangeFlow(flow));
 
                         let physical_size = window.state.physical_size();
 
@@ -857,8 +988,15 @@ async fn run_instance<P, C>(
                                     &renderer::Style {
                                         text_color: window.state.text_color(),
                                     },
-                                    window.state.cursor(),
+                                    cursor,
                                 );
+
+                            if window.is_blocked() {
+                                draw_modal_dim(
+                                    &mut window.renderer,
+                                    logical_size,
+                                );
+                            }
                             debug.draw_finished();
 
                             if new_mouse_interaction != window.mouse_interaction
@@ -938,10 +1076,19 @@ async fn run_instance<P, C>(
                             continue;
                         };
 
-                        if matches!(
+                        // A managed window may be destroyed by the OS
+                        // directly (e.g. when its process crashes), in which
+                        // case we must clean it up as if it was closed.
+                        let is_destroyed = matches!(
                             window_event,
-                            winit::event::WindowEvent::CloseRequested
-                        ) && window.exit_on_close_request
+                            winit::event::WindowEvent::Destroyed
+                        );
+
+                        if is_destroyed
+                            || (matches!(
+                                window_event,
+                                winit::event::WindowEvent::CloseRequested
+                            ) && window.exit_on_close_request)
                         {
                             let _ = window_manager.remove(id);
                             let _ = user_interfaces.remove(&id);
@@ -951,6 +1098,13 @@ async fn run_instance<P, C>(
                                 id,
                                 core::Event::Window(window::Event::Closed),
                             ));
+                        } else if window.fullscreen_shortcut
+                            && is_fullscreen_shortcut(
+                                &window_event,
+                                window.state.modifiers(),
+                            )
+                        {
+                            window.toggle_fullscreen();
                         } else {
                             window.state.update(
                                 &window.raw,
@@ -958,12 +1112,61 @@ async fn run_instance<P, C>(
                                 &mut debug,
                             );
 
-                            if let Some(event) = conversion::window_event(
+                            // Mode changes resize the window, and the
+                            // platform may change modes on its own
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::Resized(_)
+                            ) {
+                                if let Some(mode) = window.poll_mode() {
+                                    events.push((
+                                        id,
+                                        core::Event::Window(
+                                            window::Event::ModeChanged(mode),
+                                        ),
+                                    ));
+                                }
+                            }
+
+                            let converted = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
-                            ) {
-                                events.push((id, event));
+                            );
+
+                            let converted = match &mut sticky_modifiers {
+                                Some(sticky_modifiers) => converted
+                                    .map(|event| {
+                                        sticky_modifiers.process(event)
+                                    })
+                                    .unwrap_or_default(),
+                                None => converted.into_iter().collect(),
+                            };
+
+                            for event in converted {
+                                let modal_child = window.modal_child();
+
+                                match modal_child {
+                                    Some(modal_child)
+                                        if is_user_input(&event) =>
+                                    {
+                                        if matches!(
+                                            event,
+                                            core::Event::Mouse(
+                                                mouse::Event::ButtonPressed(_)
+                                            )
+                                        ) {
+                                            if let Some(modal) = window_manager
+                                                .get_mut(modal_child)
+                                            {
+                                                modal.raw.focus_window();
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        events.push((id, event));
+                                    }
+                                }
                             }
                         }
                     }
@@ -991,16 +1194,41 @@ async fn run_instance<P, C>(
                                 continue;
                             }
 
-                            let (ui_state, statuses) = user_interfaces
+                            let user_interface = user_interfaces
                                 .get_mut(&id)
-                                .expect("Get user interface")
-                                .update(
-                                    &window_events,
-                                    window.state.cursor(),
-                                    &mut window.renderer,
-                                    &mut clipboard,
-                                    &mut messages,
-                                );
+                                .expect("Get user interface");
+
+                            let (ui_state, statuses) = user_interface.update(
+                                &window_events,
+                                window.cursor(),
+                                &mut window.renderer,
+                                &mut clipboard,
+                                &mut messages,
+                            );
+
+                            let is_text_focused =
+                                user_interface.input_mode().is_some();
+
+                            if !window_events.is_empty() {
+                                let input_mode = user_interface
+                                    .input_mode()
+                                    .unwrap_or_default();
+
+                                if input_mode != window.input_mode {
+                                    window.raw.set_ime_purpose(
+                                        conversion::ime_purpose(input_mode),
+                                    );
+
+                                    window.input_mode = input_mode;
+                                }
+
+                                // Composed text, like Japanese, is only
+                                // delivered while input methods are allowed
+                                if is_text_focused != window.is_ime_allowed {
+                                    window.raw.set_ime_allowed(is_text_focused);
+                                    window.is_ime_allowed = is_text_focused;
+                                }
+                            }
 
                             window.raw.request_redraw();
 
@@ -1020,6 +1248,7 @@ async fn run_instance<P, C>(
                                         window: id,
                                         event,
                                         status,
+                                        is_text_focused,
                                     },
                                 );
                             }
@@ -1031,6 +1260,7 @@ async fn run_instance<P, C>(
                                     window: id,
                                     event,
                                     status: core::event::Status::Ignored,
+                                    is_text_focused: false,
                                 },
                             );
                         }
@@ -1069,6 +1299,7 @@ async fn run_instance<P, C>(
                                     &mut debug,
                                     &mut window_manager,
                                     cached_interfaces,
+                                    splash_deadline.is_some(),
                                 ));
 
                             if actions > 0 {
@@ -1094,12 +1325,16 @@ fn build_user_interface<'a, P: Program>(
     size: Size,
     debug: &mut Debug,
     id: window::Id,
+    is_splashing: bool,
 ) -> UserInterface<'a, P::Message, P::Theme, P::Renderer>
 where
     P::Theme: DefaultStyle,
 {
     debug.view_started();
-    let view = program.view(id);
+    let view = is_splashing
+        .then(|| program.splash(id))
+        .flatten()
+        .unwrap_or_else(|| program.view(id));
     debug.view_finished();
 
     debug.layout_started();
@@ -1133,9 +1368,10 @@ fn update<P: Program, E: Executor>(
     runtime.track(subscription::into_recipes(subscription.map(Action::Output)));
 }
 
-fn run_action<P, C>(
+fn run_action<'a, P, C>(
     action: Action<P::Message>,
-    program: &P,
+    program: &'a P,
+    splash_deadline: &mut Option<Instant>,
     compositor: &mut C,
     messages: &mut Vec<P::Message>,
     clipboard: &mut Clipboard,
@@ -1143,7 +1379,7 @@ fn run_action<P, C>(
     debug: &mut Debug,
     interfaces: &mut FxHashMap<
         window::Id,
-        UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+        UserInterface<'a, P::Message, P::Theme, P::Renderer>,
     >,
     window_manager: &mut WindowManager<P, C>,
     ui_caches: &mut FxHashMap<window::Id, user_interface::Cache>,
@@ -1169,15 +1405,54 @@ fn run_action<P, C>(
             }
         },
         Action::Window(action) => match action {
-            window::Action::Open(id, settings, channel) => {
+            window::Action::Open(id, mut settings, channel) => {
+                use window::raw_window_handle::HasWindowHandle;
+
                 let monitor = window_manager.last_monitor();
 
+                let parent = settings
+                    .parent
+                    .and_then(|parent| window_manager.get_mut(parent));
+
+                if let Some(parent) = &parent {
+                    if matches!(
+                        settings.position,
+                        core::window::Position::Default
+                            | core::window::Position::Centered
+                    ) {
+                        if let Some(position) = parent.position() {
+                            let parent_size = parent.size();
+
+                            settings.position =
+                                core::window::Position::Specific(Point::new(
+                                    position.x
+                                        + (parent_size.width
+                                            - settings.size.width)
+                                            / 2.0,
+                                    position.y
+                                        + (parent_size.height
+                                            - settings.size.height)
+                                            / 2.0,
+                                ));
+                        }
+                    }
+                }
+
+                let parent = parent.and_then(|parent| {
+                    parent
+                        .raw
+                        .window_handle()
+                        .ok()
+                        .map(|handle| handle.as_raw())
+                });
+
                 control_sender
                     .start_send(Control::CreateWindow {
                         id,
                         settings,
                         title: program.title(id),
                         monitor,
+                        parent,
                         on_open: channel,
                     })
                     .expect("Send control action");
@@ -1271,11 +1546,28 @@ fn run_action<P, C>(
             }
             window::Action::ChangeMode(id, mode) => {
                 if let Some(window) = window_manager.get_mut(id) {
-                    window.raw.set_visible(conversion::visible(mode));
-                    window.raw.set_fullscreen(conversion::fullscreen(
-                        window.raw.current_monitor(),
-                        mode,
-                    ));
+                    window.set_mode(mode);
+                }
+            }
+            window::Action::ToggleFullscreen(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.toggle_fullscreen();
+                }
+            }
+            window::Action::GetVideoModes(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let video_modes = window
+                        .raw
+                        .current_monitor()
+                        .map(|monitor| {
+                            monitor
+                                .video_modes()
+                                .map(|handle| conversion::video_mode(&handle))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    let _ = channel.send(video_modes);
                 }
             }
             window::Action::ChangeIcon(id, icon) => {
@@ -1285,13 +1577,7 @@ fn run_action<P, C>(
             }
             window::Action::GetMode(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
-                    let mode = if window.raw.is_visible().unwrap_or(true) {
-                        conversion::mode(window.raw.fullscreen())
-                    } else {
-                        core::window::Mode::Hidden
-                    };
-
-                    let _ = channel.send(mode);
+                    let _ = channel.send(window.current_mode());
                 }
             }
             window::Action::ToggleMaximize(id) => {
@@ -1369,6 +1655,20 @@ fn run_action<P, C>(
                     ));
                 }
             }
+            window::Action::SetProgress(id, progress, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    crate::taskbar::set_progress(
+                        &window.raw,
+                        progress,
+                        channel,
+                    );
+                } else {
+                    let _ = channel.send(false);
+                }
+            }
+            window::Action::SetBadge(badge, channel) => {
+                crate::taskbar::set_badge(badge.as_deref(), channel);
+            }
         },
         Action::System(action) => match action {
             system::Action::QueryInformation(_channel) => {
@@ -1403,13 +1703,39 @@ fn run_action<P, C>(
                     }
                 }
             }
+
+            // Operations may change the state of widgets; like starting an
+            // animated scroll
+            for (_id, window) in window_manager.iter_mut() {
+                window.raw.request_redraw();
+            }
         }
         Action::LoadFont { bytes, channel } => {
             // TODO: Error handling (?)
             compositor.load_font(bytes.clone());
+            relayout_interfaces(interfaces, window_manager, debug);
 
             let _ = channel.send(Ok(()));
         }
+        Action::InvalidateText => {
+            compositor.invalidate_text();
+            relayout_interfaces(interfaces, window_manager, debug);
+        }
+        Action::PreloadGlyphs {
+            font,
+            sizes,
+            charset,
+            channel,
+        } => {
+            compositor.preload_glyphs(font, &sizes, &charset);
+
+            let _ = channel.send(());
+        }
+        Action::DismissSplash => {
+            if splash_deadline.take().is_some() {
+                dismiss_splash(program, interfaces, window_manager, debug);
+            }
+        }
         Action::Exit => {
             control_sender
                 .start_send(Control::Exit)
@@ -1418,12 +1744,79 @@ fn run_action<P, C>(
     }
 }
 
+/// Replaces the splash screen of every window with the actual view of the
+/// [`Program`].
+fn dismiss_splash<'a, P, C>(
+    program: &'a P,
+    interfaces: &mut FxHashMap<
+        window::Id,
+        UserInterface<'a, P::Message, P::Theme, P::Renderer>,
+    >,
+    window_manager: &mut WindowManager<P, C>,
+    debug: &mut Debug,
+) where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer> + 'static,
+    P::Theme: DefaultStyle,
+{
+    for (id, window) in window_manager.iter_mut() {
+        if interfaces.remove(&id).is_none() {
+            continue;
+        }
+
+        let _ = interfaces.insert(
+            id,
+            build_user_interface(
+                program,
+                user_interface::Cache::default(),
+                &mut window.renderer,
+                window.state.logical_size(),
+                debug,
+                id,
+                false,
+            ),
+        );
+
+        window.raw.request_redraw();
+    }
+}
+
+/// Lays out the user interface of every window again, and requests a redraw.
+fn relayout_interfaces<P, C>(
+    interfaces: &mut FxHashMap<
+        window::Id,
+        UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+    >,
+    window_manager: &mut WindowManager<P, C>,
+    debug: &mut Debug,
+) where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer> + 'static,
+    P::Theme: DefaultStyle,
+{
+    for (id, window) in window_manager.iter_mut() {
+        let Some(ui) = interfaces.remove(&id) else {
+            continue;
+        };
+
+        debug.layout_started();
+        let _ = interfaces.insert(
+            id,
+            ui.relayout(window.state.logical_size(), &mut window.renderer),
+        );
+        debug.layout_finished();
+
+        window.raw.request_redraw();
+    }
+}
+
 /// Build the user interface for every window.
 pub fn build_user_interfaces<'a, P: Program, C>(
     program: &'a P,
     debug: &mut Debug,
     window_manager: &mut WindowManager<P, C>,
     mut cached_user_interfaces: FxHashMap<window::Id, user_interface::Cache>,
+    is_splashing: bool,
 ) -> FxHashMap<window::Id, UserInterface<'a, P::Message, P::Theme, P::Renderer>>
 where
     C: Compositor<Renderer = P::Renderer>,
@@ -1443,12 +1836,42 @@ where
                     window.state.logical_size(),
                     debug,
                     id,
+                    is_splashing,
                 ),
             ))
         })
         .collect()
 }
 
+/// Dims the contents of a window that is blocked by a modal child window.
+fn draw_modal_dim<R: core::Renderer>(renderer: &mut R, size: Size) {
+    let bounds = Rectangle::with_size(size);
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            Color {
+                a: 0.25,
+                ..Color::BLACK
+            },
+        );
+    });
+}
+
+/// Returns true if the provided event is caused by user input, which
+/// should be ignored by windows blocked by a modal child window.
+fn is_user_input(event: &core::Event) -> bool {
+    matches!(
+        event,
+        core::Event::Mouse(_)
+            | core::Event::Keyboard(_)
+            | core::Event::Touch(_)
+    )
+}
+
 /// Returns true if the provided event should cause a [`Program`] to
 /// exit.
 pub fn user_force_quit(
@@ -1469,3 +1892,25 @@ pub fn user_force_quit(
         _ => false,
     }
 }
+
+/// Returns true if the provided event is the Alt+Enter shortcut toggling
+/// fullscreen.
+fn is_fullscreen_shortcut(
+    event: &winit::event::WindowEvent,
+    modifiers: winit::keyboard::ModifiersState,
+) -> bool {
+    matches!(
+        event,
+        winit::event::WindowEvent::KeyboardInput {
+            event: winit::event::KeyEvent {
+                logical_key: winit::keyboard::Key::Named(
+                    winit::keyboard::NamedKey::Enter
+                ),
+                state: winit::event::ElementState::Pressed,
+                repeat: false,
+                ..
+            },
+            ..
+        }
+    ) && modifiers.alt_key()
+}
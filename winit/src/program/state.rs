@@ -190,7 +190,13 @@ where
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift_key() {
+                    _debug.toggle_smoothing();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
@@ -1,5 +1,7 @@
+use crate::conversion;
+use crate::core::keyboard;
 use crate::core::mouse;
-use crate::core::window::Id;
+use crate::core::window::{self, Id};
 use crate::core::{Point, Size};
 use crate::graphics::Compositor;
 use crate::program::{DefaultStyle, Program, State};
@@ -39,6 +41,8 @@ where
         application: &P,
         compositor: &mut C,
         exit_on_close_request: bool,
+        fullscreen_shortcut: bool,
+        modal_parent: Option<Id>,
     ) -> &mut Window<P, C> {
         let state = State::new(application, id, &window);
         let viewport_version = state.viewport_version();
@@ -49,9 +53,17 @@ where
             physical_size.height,
         );
         let renderer = compositor.create_renderer();
+        let mode = conversion::mode(window.fullscreen());
 
         let _ = self.aliases.insert(window.id(), id);
 
+        if let Some(parent) =
+            modal_parent.and_then(|parent| self.entries.get_mut(&parent))
+        {
+            parent.modal_children.push(id);
+            parent.raw.request_redraw();
+        }
+
         let _ = self.entries.insert(
             id,
             Window {
@@ -59,9 +71,14 @@ where
                 state,
                 viewport_version,
                 exit_on_close_request,
+                fullscreen_shortcut,
+                mode,
+                modal_children: Vec::new(),
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
+                input_mode: keyboard::InputMode::default(),
+                is_ime_allowed: false,
             },
         );
 
@@ -101,6 +118,13 @@ where
         let window = self.entries.remove(&id)?;
         let _ = self.aliases.remove(&window.raw.id());
 
+        for parent in self.entries.values_mut() {
+            if parent.modal_children.contains(&id) {
+                parent.modal_children.retain(|child| *child != id);
+                parent.raw.request_redraw();
+            }
+        }
+
         Some(window)
     }
 }
@@ -127,7 +151,12 @@ where
     pub state: State<P>,
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
+    pub fullscreen_shortcut: bool,
+    pub mode: window::Mode,
+    pub modal_children: Vec<Id>,
     pub mouse_interaction: mouse::Interaction,
+    pub input_mode: keyboard::InputMode,
+    pub is_ime_allowed: bool,
     pub surface: C::Surface,
     pub renderer: P::Renderer,
 }
@@ -154,4 +183,79 @@ where
 
         Size::new(size.width, size.height)
     }
+
+    pub fn cursor(&self) -> mouse::Cursor {
+        if self.is_blocked() {
+            mouse::Cursor::Unavailable
+        } else {
+            self.state.cursor()
+        }
+    }
+
+    /// Returns whether the window is blocked by any of its modal children.
+    pub fn is_blocked(&self) -> bool {
+        !self.modal_children.is_empty()
+    }
+
+    /// Returns the modal child opened last that is still open, if any.
+    pub fn modal_child(&self) -> Option<Id> {
+        self.modal_children.last().copied()
+    }
+
+    pub fn current_mode(&self) -> window::Mode {
+        if self.raw.is_visible().unwrap_or(true) {
+            conversion::mode(self.raw.fullscreen())
+        } else {
+            window::Mode::Hidden
+        }
+    }
+
+    pub fn set_mode(&mut self, mode: window::Mode) {
+        self.raw.set_visible(conversion::visible(mode));
+        self.raw.set_fullscreen(conversion::fullscreen(
+            self.raw.current_monitor(),
+            mode,
+        ));
+
+        // Some platforms ignore exclusive fullscreen requests
+        if let window::Mode::Exclusive(_) = mode {
+            if !matches!(
+                self.raw.fullscreen(),
+                Some(winit::window::Fullscreen::Exclusive(_))
+            ) {
+                log::warn!("Exclusive fullscreen failed; going borderless");
+
+                self.raw.set_fullscreen(Some(
+                    winit::window::Fullscreen::Borderless(
+                        self.raw.current_monitor(),
+                    ),
+                ));
+            }
+        }
+    }
+
+    pub fn toggle_fullscreen(&mut self) {
+        self.set_mode(match self.current_mode() {
+            window::Mode::Windowed | window::Mode::Hidden => {
+                window::Mode::Fullscreen
+            }
+            window::Mode::Fullscreen | window::Mode::Exclusive(_) => {
+                window::Mode::Windowed
+            }
+        });
+    }
+
+    /// Returns the current [`window::Mode`] of the window, if it changed
+    /// since the last call.
+    pub fn poll_mode(&mut self) -> Option<window::Mode> {
+        let mode = self.current_mode();
+
+        if mode == self.mode {
+            return None;
+        }
+
+        self.mode = mode;
+
+        Some(mode)
+    }
 }
@@ -1,4 +1,6 @@
 //! Configure your application.
+use crate::core::settings::{Interaction, Motion};
+
 use std::borrow::Cow;
 
 /// The settings of an application.
@@ -12,4 +14,18 @@ pub struct Settings {
 
     /// The fonts to load on boot.
     pub fonts: Vec<Cow<'static, [u8]>>,
+
+    /// Whether modifiers that are tapped on their own stay latched until
+    /// the next key press or mouse click.
+    pub sticky_modifiers: bool,
+
+    /// The [`Interaction`] settings of the application.
+    ///
+    /// If `None`, they are queried from the platform on startup.
+    pub interaction: Option<Interaction>,
+
+    /// The [`Motion`] policy of the application.
+    ///
+    /// If `None`, it is queried from the platform on startup.
+    pub motion: Option<Motion>,
 }
@@ -0,0 +1,406 @@
+//! Enforce a single instance of an application.
+//!
+//! When an application acquires its single instance, any later invocation
+//! of the same application forwards its command-line arguments to the
+//! existing instance and exits right away. The existing instance receives
+//! the arguments through the [`listen`] subscription; normally, to open
+//! the files passed and focus its window.
+//!
+//! Instances find each other with a Unix domain socket on Unix platforms,
+//! and with a loopback TCP socket—whose port is stored in a file of the
+//! temporary directory—everywhere else.
+//!
+//! The socket lives in the runtime directory of the user or, if there is
+//! none, in a private directory only the user can access. The TCP socket
+//! is open to any local process, so both ends of a connection first prove
+//! they know a random token stored next to the port; this also keeps a
+//! stale port file from sending arguments to an unrelated process.
+use crate::futures::futures::channel::mpsc;
+use crate::futures::Subscription;
+
+use std::env;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// An event related to the single instance of an application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The application was invoked again with the given command-line
+    /// arguments.
+    ///
+    /// The arguments do not include the name of the executable. Relative
+    /// paths are relative to the working directory of the new invocation.
+    InstanceArgs(Vec<String>),
+}
+
+/// The result of trying to acquire the single instance of an application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instance {
+    /// The current process is the single instance of the application.
+    Primary,
+    /// Another instance of the application is running and the command-line
+    /// arguments of the current process have been forwarded to it.
+    ///
+    /// The current process should exit.
+    Secondary,
+}
+
+/// The amount of times the single instance is tried to be acquired before
+/// giving up.
+const ATTEMPTS: usize = 20;
+
+/// The time to wait before trying to acquire the single instance again.
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The time to wait for the other end of a connection to send anything.
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+static STATE: Mutex<State> = Mutex::new(State {
+    pending: Vec::new(),
+    subscribers: Vec::new(),
+});
+
+struct State {
+    pending: Vec<Event>,
+    subscribers: Vec<mpsc::UnboundedSender<Event>>,
+}
+
+/// Tries to acquire the single instance of the application with the given
+/// identifier.
+///
+/// If another instance is running, the command-line arguments of the
+/// current process are forwarded to it and [`Instance::Secondary`] is
+/// returned. Otherwise, the current process starts listening to later
+/// invocations and [`Instance::Primary`] is returned.
+///
+/// Acquiring the instance is the lock: if multiple processes start at
+/// the same time, only one of them will succeed and the rest will retry
+/// forwarding their arguments to it.
+pub fn acquire(app_id: &str) -> io::Result<Instance> {
+    let arguments: Vec<String> = env::args_os()
+        .skip(1)
+        .map(|argument| argument.to_string_lossy().into_owned())
+        .collect();
+
+    for _ in 0..ATTEMPTS {
+        match platform::connect(app_id) {
+            Ok(mut stream) => {
+                stream.write_all(arguments.join("\0").as_bytes())?;
+
+                return Ok(Instance::Secondary);
+            }
+            Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => {
+                // A previous instance did not clean up after itself
+                let _ = platform::remove(app_id);
+            }
+            Err(_) => {}
+        }
+
+        match platform::bind(app_id) {
+            Ok(listener) => {
+                let _ = thread::spawn(move || accept(listener));
+
+                return Ok(Instance::Primary);
+            }
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::AddrInUse | io::ErrorKind::AlreadyExists
+                ) =>
+            {
+                // Another instance is starting; give it a moment to listen
+                thread::sleep(RETRY_INTERVAL);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::TimedOut,
+        "the existing instance did not respond",
+    ))
+}
+
+/// Listens to the [`Event`]s of the single instance of the application.
+///
+/// Nothing is ever produced if the single instance was not acquired.
+pub fn listen() -> Subscription<Event> {
+    Subscription::run(|| {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let mut state = STATE.lock().expect("Lock single instance state");
+
+        for event in state.pending.drain(..) {
+            let _ = sender.unbounded_send(event);
+        }
+
+        state.subscribers.push(sender);
+
+        receiver
+    })
+}
+
+fn accept(listener: platform::Listener) {
+    for incoming in listener.incoming() {
+        let Ok(incoming) = incoming else {
+            continue;
+        };
+
+        // A connection that never finishes must not keep any other
+        // instance from forwarding its arguments
+        let _ = thread::spawn(move || receive(incoming));
+    }
+}
+
+fn receive(incoming: platform::Incoming) {
+    let mut stream = match platform::authenticate(incoming) {
+        Ok(stream) => stream,
+        Err(error) => {
+            log::warn!("Rejected a connection from another instance: {error}");
+            return;
+        }
+    };
+
+    let mut payload = String::new();
+
+    if let Err(error) = stream.read_to_string(&mut payload) {
+        log::warn!("Invalid arguments from another instance: {error}");
+        return;
+    }
+
+    let event = Event::InstanceArgs(if payload.is_empty() {
+        Vec::new()
+    } else {
+        payload.split('\0').map(String::from).collect()
+    });
+
+    let mut state = STATE.lock().expect("Lock single instance state");
+
+    state.subscribers.retain(|sender| !sender.is_closed());
+
+    if state.subscribers.is_empty() {
+        state.pending.push(event);
+    } else {
+        for sender in &state.subscribers {
+            let _ = sender.unbounded_send(event.clone());
+        }
+    }
+}
+
+/// Returns the path of the file used to find the instance with the given
+/// identifier.
+fn path(app_id: &str, extension: &str) -> io::Result<PathBuf> {
+    let name: String = app_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    Ok(platform::directory()?.join(format!("{name}.{extension}")))
+}
+
+#[cfg(unix)]
+#[allow(unsafe_code)]
+mod platform {
+    use std::env;
+    use std::fs::{self, DirBuilder};
+    use std::io;
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+
+    extern "C" {
+        fn getuid() -> u32;
+    }
+
+    pub type Listener = UnixListener;
+    pub type Incoming = UnixStream;
+
+    pub fn connect(app_id: &str) -> io::Result<UnixStream> {
+        UnixStream::connect(super::path(app_id, "sock")?)
+    }
+
+    pub fn bind(app_id: &str) -> io::Result<Listener> {
+        UnixListener::bind(super::path(app_id, "sock")?)
+    }
+
+    pub fn remove(app_id: &str) -> io::Result<()> {
+        fs::remove_file(super::path(app_id, "sock")?)
+    }
+
+    /// Only the user can connect to the socket; so there is nothing to
+    /// prove.
+    pub fn authenticate(stream: Incoming) -> io::Result<UnixStream> {
+        stream.set_read_timeout(Some(super::TIMEOUT))?;
+
+        Ok(stream)
+    }
+
+    /// Returns the runtime directory of the user; or a private directory
+    /// in the temporary one, which may be shared with other users.
+    pub fn directory() -> io::Result<PathBuf> {
+        if let Some(directory) = env::var_os("XDG_RUNTIME_DIR") {
+            return Ok(PathBuf::from(directory));
+        }
+
+        // SAFETY: `getuid` always succeeds and has no side effects.
+        let uid = unsafe { getuid() };
+
+        let directory = env::temp_dir().join(format!("iced-{uid}"));
+
+        match DirBuilder::new().mode(0o700).create(&directory) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(error) => return Err(error),
+        }
+
+        // The directory may have been planted by another user
+        let metadata = fs::symlink_metadata(&directory)?;
+
+        if !metadata.is_dir()
+            || metadata.uid() != uid
+            || metadata.mode() & 0o077 != 0
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{} is not private", directory.display()),
+            ));
+        }
+
+        Ok(directory)
+    }
+}
+
+#[cfg(not(unix))]
+mod platform {
+    use std::collections::hash_map::RandomState;
+    use std::env;
+    use std::fs::{self, OpenOptions};
+    use std::hash::{BuildHasher, Hasher};
+    use std::io::{self, Read, Write};
+    use std::net::{Ipv4Addr, TcpListener, TcpStream};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    /// A loopback listener whose connections must prove they know its
+    /// token.
+    pub struct Listener {
+        listener: TcpListener,
+        token: Arc<str>,
+    }
+
+    /// A connection to a [`Listener`] that has not authenticated yet.
+    pub struct Incoming {
+        stream: TcpStream,
+        token: Arc<str>,
+    }
+
+    impl Listener {
+        pub fn incoming(
+            &self,
+        ) -> impl Iterator<Item = io::Result<Incoming>> + '_ {
+            self.listener.incoming().map(|stream| {
+                Ok(Incoming {
+                    stream: stream?,
+                    token: self.token.clone(),
+                })
+            })
+        }
+    }
+
+    pub fn authenticate(incoming: Incoming) -> io::Result<TcpStream> {
+        let Incoming { mut stream, token } = incoming;
+
+        stream.set_read_timeout(Some(super::TIMEOUT))?;
+        stream.write_all(token.as_bytes())?;
+
+        if read_token(&mut stream, token.len())? != *token {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "the connection did not authenticate",
+            ));
+        }
+
+        Ok(stream)
+    }
+
+    pub fn connect(app_id: &str) -> io::Result<TcpStream> {
+        let contents = fs::read_to_string(super::path(app_id, "port")?)?;
+
+        let (port, token) = contents
+            .trim()
+            .split_once(' ')
+            .and_then(|(port, token)| Some((port.parse::<u16>().ok()?, token)))
+            .filter(|(_, token)| !token.is_empty())
+            // The port may not have been written yet
+            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))?;
+
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port))?;
+
+        stream.set_read_timeout(Some(super::TIMEOUT))?;
+
+        // Whoever listens must know the token; otherwise, the port file is
+        // stale and the port belongs to an unrelated process
+        if !read_token(&mut stream, token.len())
+            .is_ok_and(|received| received == token)
+        {
+            return Err(io::Error::from(io::ErrorKind::ConnectionRefused));
+        }
+
+        stream.write_all(token.as_bytes())?;
+
+        Ok(stream)
+    }
+
+    pub fn bind(app_id: &str) -> io::Result<Listener> {
+        // Creating the file works as a lock between starting instances
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(super::path(app_id, "port")?)?;
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let token = token();
+
+        write!(file, "{} {token}", listener.local_addr()?.port())?;
+
+        Ok(Listener {
+            listener,
+            token: Arc::from(token),
+        })
+    }
+
+    pub fn remove(app_id: &str) -> io::Result<()> {
+        fs::remove_file(super::path(app_id, "port")?)
+    }
+
+    /// Returns the temporary directory, which belongs to the user.
+    pub fn directory() -> io::Result<PathBuf> {
+        Ok(env::temp_dir())
+    }
+
+    /// Generates a random token of 128 bits, in hexadecimal.
+    fn token() -> String {
+        // The keys of a `RandomState` come from the randomness of the system
+        let random = || RandomState::new().build_hasher().finish();
+
+        format!("{:016x}{:016x}", random(), random())
+    }
+
+    fn read_token(stream: &mut TcpStream, length: usize) -> io::Result<String> {
+        let mut token = vec![0; length];
+
+        stream.read_exact(&mut token)?;
+
+        String::from_utf8(token)
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidData))
+    }
+}
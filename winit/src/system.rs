@@ -1,15 +1,132 @@
 //! Access the native system.
+#[cfg(feature = "power")]
+mod power_supply;
+
+use crate::futures::futures::channel::mpsc;
+use crate::futures::Subscription;
+use crate::interaction;
+use crate::runtime::Task;
+
+#[cfg(feature = "system")]
 use crate::graphics::compositor;
+#[cfg(feature = "system")]
+use crate::runtime;
+#[cfg(feature = "system")]
 use crate::runtime::system::{Action, Information};
-use crate::runtime::{self, Task};
+
+#[cfg(feature = "power")]
+use crate::runtime::system::power;
+
+use std::thread;
+use std::time::Duration;
 
 /// Query for available system information.
+#[cfg(feature = "system")]
 pub fn fetch_information() -> Task<Information> {
     runtime::task::oneshot(|channel| {
         runtime::Action::System(Action::QueryInformation(channel))
     })
 }
 
+/// Query the current power state of the system.
+///
+/// Produces `None` if the power state cannot be determined in the current
+/// platform.
+#[cfg(feature = "power")]
+pub fn power_state() -> Task<Option<power::State>> {
+    Task::future(async { power_supply::query() })
+}
+
+/// Listens to changes in the power state of the system.
+///
+/// The power state is polled periodically. Nothing is ever produced if the
+/// power state cannot be determined in the current platform.
+#[cfg(feature = "power")]
+pub fn power_events() -> Subscription<power::Event> {
+    const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+    if !power_supply::IS_SUPPORTED {
+        return Subscription::none();
+    }
+
+    Subscription::run(|| {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let _ = thread::spawn(move || {
+            let mut last = power_supply::query();
+
+            while !sender.is_closed() {
+                thread::sleep(POLL_INTERVAL);
+
+                let Some(current) = power_supply::query() else {
+                    continue;
+                };
+
+                let events =
+                    last.map(|last| last.changes(&current)).unwrap_or_default();
+
+                for event in events {
+                    if sender.unbounded_send(event).is_err() {
+                        return;
+                    }
+                }
+
+                last = Some(current);
+            }
+        });
+
+        receiver
+    })
+}
+
+/// Query whether the user prefers reduced motion, according to the
+/// "reduce motion" accessibility setting of the platform.
+///
+/// Produces `false` if the preference cannot be determined in the current
+/// platform.
+pub fn reduced_motion() -> Task<bool> {
+    Task::future(async { interaction::prefers_reduced_motion() })
+}
+
+/// Listens to changes in the "reduce motion" preference of the user.
+///
+/// On Linux, changes are signaled by the settings portal of the desktop;
+/// anywhere else, the preference is polled periodically. Nothing is ever
+/// produced on the Web.
+///
+/// Widgets keep the [`Motion`] policy they had on startup; apply a new one
+/// to make them follow the change.
+///
+/// [`Motion`]: crate::core::settings::Motion
+pub fn reduced_motion_changes() -> Subscription<bool> {
+    if cfg!(target_arch = "wasm32") {
+        return Subscription::none();
+    }
+
+    Subscription::run(|| {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let _ = thread::spawn(move || {
+            let mut last = interaction::prefers_reduced_motion();
+
+            interaction::watch_reduced_motion(|current| {
+                if current != last {
+                    if sender.unbounded_send(current).is_err() {
+                        return false;
+                    }
+
+                    last = current;
+                }
+
+                !sender.is_closed()
+            });
+        });
+
+        receiver
+    })
+}
+
+#[cfg(feature = "system")]
 pub(crate) fn information(
     graphics_info: compositor::Information,
 ) -> Information {
@@ -35,5 +152,6 @@ pub(crate) fn information(
         memory_used,
         graphics_adapter: graphics_info.adapter,
         graphics_backend: graphics_info.backend,
+        graphics_formats: graphics_info.formats,
     }
 }
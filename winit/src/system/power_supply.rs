@@ -0,0 +1,292 @@
+//! Query the power supply of the native platform.
+//!
+//! - On Linux, the `power_supply` class of `sysfs` is read directly; this is
+//!   the same kernel interface UPower is built on.
+//! - On macOS, the power sources of IOKit are queried.
+//! - On Windows, `GetSystemPowerStatus` is used.
+//!
+//! Any other platform is unsupported.
+pub(crate) use platform::{query, IS_SUPPORTED};
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use crate::runtime::system::power::{Source, State};
+
+    use std::fs;
+    use std::path::Path;
+
+    pub const IS_SUPPORTED: bool = true;
+
+    pub fn query() -> Option<State> {
+        let mut is_plugged = None;
+        let mut is_discharging = false;
+        let mut capacities = Vec::new();
+
+        for entry in fs::read_dir("/sys/class/power_supply").ok()?.flatten() {
+            let supply = entry.path();
+
+            match read(&supply, "type").as_deref() {
+                Some("Mains" | "USB") => {
+                    let is_online =
+                        read(&supply, "online").as_deref() == Some("1");
+
+                    is_plugged = Some(is_plugged.unwrap_or(false) || is_online);
+                }
+                // Batteries of peripherals (e.g. a wireless mouse) report a
+                // `Device` scope and do not power the system.
+                Some("Battery")
+                    if read(&supply, "scope").as_deref() != Some("Device") =>
+                {
+                    is_discharging |= read(&supply, "status").as_deref()
+                        == Some("Discharging");
+
+                    if let Some(capacity) = read(&supply, "capacity")
+                        .and_then(|capacity| capacity.parse::<u8>().ok())
+                    {
+                        capacities.push(capacity.min(100));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if is_plugged.is_none() && capacities.is_empty() {
+            return None;
+        }
+
+        let source = if is_plugged.unwrap_or(!is_discharging) {
+            Source::Ac
+        } else {
+            Source::Battery
+        };
+
+        let battery = (!capacities.is_empty()).then(|| {
+            let total: u32 = capacities.iter().copied().map(u32::from).sum();
+
+            (total / capacities.len() as u32) as u8
+        });
+
+        let is_low_power =
+            fs::read_to_string("/sys/firmware/acpi/platform_profile")
+                .is_ok_and(|profile| profile.trim() == "low-power");
+
+        Some(State {
+            source,
+            battery,
+            is_low_power,
+        })
+    }
+
+    fn read(supply: &Path, attribute: &str) -> Option<String> {
+        fs::read_to_string(supply.join(attribute))
+            .ok()
+            .map(|value| value.trim().to_owned())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use crate::runtime::system::power::{Source, State};
+
+    use winapi::um::winbase::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    pub const IS_SUPPORTED: bool = true;
+
+    /// The `BatteryFlag` reported when the system has no battery.
+    const NO_SYSTEM_BATTERY: u8 = 128;
+
+    /// The value reported by any field of the status when it is unknown.
+    const UNKNOWN: u8 = 255;
+
+    #[allow(unsafe_code)]
+    pub fn query() -> Option<State> {
+        // SAFETY: `SYSTEM_POWER_STATUS` is a plain C struct of integers.
+        let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+
+        // SAFETY: `status` is a valid pointer to a `SYSTEM_POWER_STATUS`.
+        if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+            return None;
+        }
+
+        let source = match status.ACLineStatus {
+            0 => Source::Battery,
+            _ => Source::Ac,
+        };
+
+        let battery = (status.BatteryFlag != NO_SYSTEM_BATTERY
+            && status.BatteryFlag != UNKNOWN
+            && status.BatteryLifePercent != UNKNOWN)
+            .then_some(status.BatteryLifePercent.min(100));
+
+        // `Reserved1` is the `SystemStatusFlag`, which is set to 1 when
+        // battery saver is on.
+        let is_low_power = status.Reserved1 == 1;
+
+        Some(State {
+            source,
+            battery,
+            is_low_power,
+        })
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[allow(unsafe_code)]
+mod platform {
+    use crate::runtime::system::power::{Source, State};
+
+    use std::ffi::{c_char, c_long, c_void};
+
+    type CFTypeRef = *const c_void;
+    type CFIndex = c_long;
+
+    const K_CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+    const K_CF_NUMBER_SINT32_TYPE: CFIndex = 3;
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        fn IOPSCopyPowerSourcesInfo() -> CFTypeRef;
+        fn IOPSCopyPowerSourcesList(blob: CFTypeRef) -> CFTypeRef;
+        fn IOPSGetPowerSourceDescription(
+            blob: CFTypeRef,
+            source: CFTypeRef,
+        ) -> CFTypeRef;
+        fn IOPSGetProvidingPowerSourceType(snapshot: CFTypeRef) -> CFTypeRef;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFRelease(value: CFTypeRef);
+        fn CFEqual(a: CFTypeRef, b: CFTypeRef) -> u8;
+        fn CFArrayGetCount(array: CFTypeRef) -> CFIndex;
+        fn CFArrayGetValueAtIndex(
+            array: CFTypeRef,
+            index: CFIndex,
+        ) -> CFTypeRef;
+        fn CFDictionaryGetValue(
+            dictionary: CFTypeRef,
+            key: CFTypeRef,
+        ) -> CFTypeRef;
+        fn CFNumberGetValue(
+            number: CFTypeRef,
+            kind: CFIndex,
+            value: *mut c_void,
+        ) -> u8;
+        fn CFStringCreateWithCString(
+            allocator: CFTypeRef,
+            string: *const c_char,
+            encoding: u32,
+        ) -> CFTypeRef;
+    }
+
+    pub const IS_SUPPORTED: bool = true;
+
+    /// Low-power mode is not detected on macOS; it is only exposed through
+    /// Objective-C APIs.
+    pub fn query() -> Option<State> {
+        // SAFETY: Every value obtained from a `Copy` or `Create` function is
+        // released exactly once, and values obtained from a `Get` function
+        // are never used after the value owning them is released.
+        unsafe {
+            let info = IOPSCopyPowerSourcesInfo();
+
+            if info.is_null() {
+                return None;
+            }
+
+            let ac_power = string(b"AC Power\0");
+            let current_capacity = string(b"Current Capacity\0");
+            let max_capacity = string(b"Max Capacity\0");
+
+            let providing = IOPSGetProvidingPowerSourceType(info);
+
+            let source =
+                if !providing.is_null() && CFEqual(providing, ac_power) != 0 {
+                    Source::Ac
+                } else {
+                    Source::Battery
+                };
+
+            let mut battery = None;
+            let sources = IOPSCopyPowerSourcesList(info);
+
+            if !sources.is_null() {
+                for i in 0..CFArrayGetCount(sources) {
+                    let description = IOPSGetPowerSourceDescription(
+                        info,
+                        CFArrayGetValueAtIndex(sources, i),
+                    );
+
+                    if description.is_null() {
+                        continue;
+                    }
+
+                    if let (Some(current), Some(max)) = (
+                        number(description, current_capacity),
+                        number(description, max_capacity),
+                    ) {
+                        if max > 0 {
+                            battery =
+                                Some((current.clamp(0, max) * 100 / max) as u8);
+                            break;
+                        }
+                    }
+                }
+
+                CFRelease(sources);
+            }
+
+            CFRelease(max_capacity);
+            CFRelease(current_capacity);
+            CFRelease(ac_power);
+            CFRelease(info);
+
+            Some(State {
+                source,
+                battery,
+                is_low_power: false,
+            })
+        }
+    }
+
+    /// Creates a `CFString` from a nul-terminated string.
+    unsafe fn string(value: &[u8]) -> CFTypeRef {
+        CFStringCreateWithCString(
+            std::ptr::null(),
+            value.as_ptr().cast::<c_char>(),
+            K_CF_STRING_ENCODING_UTF8,
+        )
+    }
+
+    unsafe fn number(dictionary: CFTypeRef, key: CFTypeRef) -> Option<i32> {
+        let value = CFDictionaryGetValue(dictionary, key);
+
+        if value.is_null() {
+            return None;
+        }
+
+        let mut number = 0i32;
+
+        (CFNumberGetValue(
+            value,
+            K_CF_NUMBER_SINT32_TYPE,
+            std::ptr::addr_of_mut!(number).cast(),
+        ) != 0)
+            .then_some(number)
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "windows",
+    target_os = "macos"
+)))]
+mod platform {
+    use crate::runtime::system::power::State;
+
+    pub const IS_SUPPORTED: bool = false;
+
+    pub fn query() -> Option<State> {
+        None
+    }
+}
@@ -0,0 +1,429 @@
+//! Show progress and badges on the taskbar or dock icon of the application.
+//!
+//! - On Windows, the `ITaskbarList3` interface of the shell is used.
+//! - On Linux, the Unity launcher entry API is used; it is a D-Bus signal
+//!   understood by most docks, broadcasted to the session bus from a
+//!   background thread. It only has an effect while a known consumer of
+//!   the signal is running.
+//! - On macOS, the badge label of the dock tile is set.
+//!
+//! Any other platform is unsupported.
+use crate::futures::futures::channel::oneshot;
+
+#[cfg(target_os = "linux")]
+pub(crate) use platform::{set_badge, set_progress};
+
+/// Sets the progress shown on the taskbar entry of the window, and sends
+/// whether it had any effect through the given channel.
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn set_progress(
+    window: &winit::window::Window,
+    progress: Option<f32>,
+    channel: oneshot::Sender<bool>,
+) {
+    let _ = channel.send(platform::set_progress(window, progress));
+}
+
+/// Sets the badge shown on the taskbar entry of the application, and sends
+/// whether it had any effect through the given channel.
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn set_badge(badge: Option<&str>, channel: oneshot::Sender<bool>) {
+    let _ = channel.send(platform::set_badge(badge));
+}
+
+#[cfg(target_os = "windows")]
+#[allow(unsafe_code)]
+mod platform {
+    use winapi::shared::windef::HWND;
+    use winapi::shared::winerror::{FAILED, SUCCEEDED};
+    use winapi::um::combaseapi::{
+        CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER,
+    };
+    use winapi::um::objbase::COINIT_APARTMENTTHREADED;
+    use winapi::um::shobjidl_core::{
+        CLSID_TaskbarList, ITaskbarList3, TBPF_NOPROGRESS, TBPF_NORMAL,
+    };
+    use winapi::Interface;
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    use std::ptr;
+
+    /// The resolution of the progress reported to the taskbar.
+    const STEPS: u64 = 1000;
+
+    pub fn set_progress(
+        window: &winit::window::Window,
+        progress: Option<f32>,
+    ) -> bool {
+        let Some(hwnd) = window.window_handle().ok().and_then(|handle| {
+            match handle.as_raw() {
+                RawWindowHandle::Win32(handle) => {
+                    Some(handle.hwnd.get() as HWND)
+                }
+                _ => None,
+            }
+        }) else {
+            return false;
+        };
+
+        // SAFETY: The taskbar list is created, used and released on the
+        // thread of the event loop, which is where its window belongs.
+        unsafe {
+            // The apartment may already be initialized; on the same mode or
+            // not, the taskbar list can still be created.
+            let _ = CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+
+            let mut taskbar: *mut ITaskbarList3 = ptr::null_mut();
+
+            let result = CoCreateInstance(
+                &CLSID_TaskbarList,
+                ptr::null_mut(),
+                CLSCTX_INPROC_SERVER,
+                &ITaskbarList3::uuidof(),
+                ptr::addr_of_mut!(taskbar).cast(),
+            );
+
+            if FAILED(result) || taskbar.is_null() {
+                return false;
+            }
+
+            let taskbar = &*taskbar;
+
+            let has_effect = SUCCEEDED(taskbar.HrInit())
+                && match progress {
+                    Some(progress) => {
+                        SUCCEEDED(taskbar.SetProgressState(hwnd, TBPF_NORMAL))
+                            && SUCCEEDED(taskbar.SetProgressValue(
+                                hwnd,
+                                (f64::from(progress) * STEPS as f64) as u64,
+                                STEPS,
+                            ))
+                    }
+                    None => SUCCEEDED(
+                        taskbar.SetProgressState(hwnd, TBPF_NOPROGRESS),
+                    ),
+                };
+
+            let _ = taskbar.Release();
+
+            has_effect
+        }
+    }
+
+    /// Windows has no badges for arbitrary labels; only overlay icons.
+    pub fn set_badge(_badge: Option<&str>) -> bool {
+        false
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::oneshot;
+    use crate::dbus;
+
+    use std::collections::HashMap;
+    use std::path::Path;
+    use std::sync::mpsc;
+    use std::sync::OnceLock;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use zbus::blocking::fdo::DBusProxy;
+    use zbus::names::BusName;
+    use zbus::zvariant::Value;
+
+    /// The bus names owned by the known consumers of the launcher entry
+    /// API; that is, Unity and the docks and panels implementing it.
+    const CONSUMERS: &[&str] = &[
+        "com.canonical.Unity",
+        "org.kde.plasmashell",
+        "org.kde.lattedock",
+        "net.launchpad.plank",
+    ];
+
+    /// The time the presence of a consumer is remembered before asking the
+    /// session bus again.
+    const CONSUMERS_TTL: Duration = Duration::from_secs(5);
+
+    /// The properties of a launcher entry, as sent in its `Update` signal.
+    type Properties = HashMap<&'static str, Value<'static>>;
+
+    /// An update of the launcher entry waiting to be broadcasted.
+    struct Update {
+        properties: Properties,
+        channel: oneshot::Sender<bool>,
+    }
+
+    pub fn set_progress(
+        _window: &winit::window::Window,
+        progress: Option<f32>,
+        channel: oneshot::Sender<bool>,
+    ) {
+        let properties = match progress {
+            Some(progress) => Properties::from([
+                ("progress", Value::from(f64::from(progress))),
+                ("progress-visible", Value::from(true)),
+            ]),
+            None => {
+                Properties::from([("progress-visible", Value::from(false))])
+            }
+        };
+
+        send(properties, channel);
+    }
+
+    pub fn set_badge(badge: Option<&str>, channel: oneshot::Sender<bool>) {
+        let properties = match badge {
+            Some(badge) => {
+                let Ok(count) = badge.trim().parse::<i64>() else {
+                    let _ = channel.send(false);
+                    return;
+                };
+
+                Properties::from([
+                    ("count", Value::from(count)),
+                    ("count-visible", Value::from(true)),
+                ])
+            }
+            None => Properties::from([("count-visible", Value::from(false))]),
+        };
+
+        send(properties, channel);
+    }
+
+    /// Queues an update for the worker thread, which is started the first
+    /// time it is needed; so the event loop never waits on the session bus.
+    fn send(properties: Properties, channel: oneshot::Sender<bool>) {
+        static WORKER: OnceLock<Option<mpsc::Sender<Update>>> = OnceLock::new();
+
+        let worker = WORKER.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel::<Update>();
+
+            thread::Builder::new()
+                .name(String::from("iced_winit::taskbar"))
+                .spawn(move || run(receiver))
+                .map_err(|error| {
+                    log::warn!("Failed to start taskbar worker: {error}");
+                })
+                .ok()
+                .map(|_| sender)
+        });
+
+        let update = Update {
+            properties,
+            channel,
+        };
+
+        match worker {
+            Some(worker) => {
+                if let Err(mpsc::SendError(update)) = worker.send(update) {
+                    let _ = update.channel.send(false);
+                }
+            }
+            None => {
+                let _ = update.channel.send(false);
+            }
+        }
+    }
+
+    /// Broadcasts the queued updates until the sender is dropped.
+    fn run(receiver: mpsc::Receiver<Update>) {
+        let mut consumers: Option<(bool, Instant)> = None;
+
+        while let Ok(Update {
+            mut properties,
+            channel,
+        }) = receiver.recv()
+        {
+            let mut channels = vec![channel];
+
+            // Updates queued in the meantime are merged into a single one;
+            // later properties replace earlier ones
+            for update in receiver.try_iter() {
+                properties.extend(update.properties);
+                channels.push(update.channel);
+            }
+
+            let has_effect = dbus::session().is_some_and(|connection| {
+                let has_consumer = match consumers {
+                    Some((has_consumer, checked_at))
+                        if checked_at.elapsed() < CONSUMERS_TTL =>
+                    {
+                        has_consumer
+                    }
+                    _ => {
+                        let has_consumer = has_consumer(connection);
+
+                        consumers = Some((has_consumer, Instant::now()));
+                        has_consumer
+                    }
+                };
+
+                has_consumer && emit(connection, &properties)
+            });
+
+            for channel in channels {
+                let _ = channel.send(has_effect);
+            }
+        }
+    }
+
+    /// Returns whether any known consumer of the launcher entry API owns a
+    /// name in the session bus.
+    fn has_consumer(connection: &dbus::Connection) -> bool {
+        let names = DBusProxy::new(connection)
+            .ok()
+            .and_then(|proxy| proxy.list_names().ok());
+
+        names.is_some_and(|names| {
+            names.iter().any(|name| CONSUMERS.contains(&name.as_str()))
+        })
+    }
+
+    /// Broadcasts the `Update` signal of the launcher entry of the
+    /// application with the given properties.
+    fn emit(connection: &dbus::Connection, properties: &Properties) -> bool {
+        let Some(desktop_entry) = desktop_entry() else {
+            return false;
+        };
+
+        let path = format!(
+            "/com/canonical/unity/launcherentry/{}",
+            std::process::id()
+        );
+
+        connection
+            .emit_signal(
+                None::<BusName<'_>>,
+                path.as_str(),
+                "com.canonical.Unity.LauncherEntry",
+                "Update",
+                &(format!("application://{desktop_entry}"), properties),
+            )
+            .map_err(|error| {
+                log::warn!("Failed to update the launcher entry: {error}");
+            })
+            .is_ok()
+    }
+
+    /// Returns the name of the desktop entry of the application.
+    ///
+    /// It is the entry the application was launched from, if known; or one
+    /// named after its executable, otherwise.
+    fn desktop_entry() -> Option<String> {
+        if let Some(path) = std::env::var_os("GIO_LAUNCHED_DESKTOP_FILE") {
+            return Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+        }
+
+        let executable = std::env::current_exe().ok()?;
+        let name = executable.file_stem()?.to_string_lossy();
+
+        Some(format!("{name}.desktop"))
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[allow(unsafe_code)]
+mod platform {
+    use std::ffi::{c_char, c_void, CString};
+
+    type Object = *mut c_void;
+    type Selector = *const c_void;
+
+    #[link(name = "objc")]
+    extern "C" {
+        fn objc_getClass(name: *const c_char) -> Object;
+        fn sel_registerName(name: *const c_char) -> Selector;
+        fn objc_msgSend();
+    }
+
+    #[link(name = "AppKit", kind = "framework")]
+    extern "C" {}
+
+    /// The dock has no progress indicator unless a custom view is drawn.
+    pub fn set_progress(
+        _window: &winit::window::Window,
+        _progress: Option<f32>,
+    ) -> bool {
+        false
+    }
+
+    pub fn set_badge(badge: Option<&str>) -> bool {
+        let label = match badge.map(CString::new) {
+            Some(Ok(label)) => Some(label),
+            Some(Err(_)) => return false,
+            None => None,
+        };
+
+        // SAFETY: `objc_msgSend` is called through function pointers matching
+        // the signatures of the methods sent; and the event loop, which runs
+        // this, lives on the main thread as AppKit requires.
+        unsafe {
+            let send: unsafe extern "C" fn(Object, Selector) -> Object =
+                std::mem::transmute(objc_msgSend as unsafe extern "C" fn());
+
+            let send_with: unsafe extern "C" fn(
+                Object,
+                Selector,
+                *const c_void,
+            ) -> Object =
+                std::mem::transmute(objc_msgSend as unsafe extern "C" fn());
+
+            let application = send(
+                objc_getClass(b"NSApplication\0".as_ptr().cast()),
+                sel_registerName(b"sharedApplication\0".as_ptr().cast()),
+            );
+
+            if application.is_null() {
+                return false;
+            }
+
+            let tile = send(
+                application,
+                sel_registerName(b"dockTile\0".as_ptr().cast()),
+            );
+
+            if tile.is_null() {
+                return false;
+            }
+
+            let label = match &label {
+                Some(label) => send_with(
+                    objc_getClass(b"NSString\0".as_ptr().cast()),
+                    sel_registerName(
+                        b"stringWithUTF8String:\0".as_ptr().cast(),
+                    ),
+                    label.as_ptr().cast(),
+                ),
+                None => std::ptr::null_mut(),
+            };
+
+            let _ = send_with(
+                tile,
+                sel_registerName(b"setBadgeLabel:\0".as_ptr().cast()),
+                label,
+            );
+
+            true
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "linux",
+    target_os = "macos"
+)))]
+mod platform {
+    pub fn set_progress(
+        _window: &winit::window::Window,
+        _progress: Option<f32>,
+    ) -> bool {
+        false
+    }
+
+    pub fn set_badge(_badge: Option<&str>) -> bool {
+        false
+    }
+}